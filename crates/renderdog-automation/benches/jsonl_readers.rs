@@ -0,0 +1,143 @@
+//! Throughput of the typed JSONL readers on synthetic exports.
+//!
+//! `cargo bench -p renderdog-automation --bench jsonl_readers`
+
+use std::{io::Write, path::PathBuf};
+
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+use renderdog_automation::{ActionsReader, BindingsReader};
+
+const RECORDS: u32 = 100_000;
+
+struct Fixture {
+    dir: PathBuf,
+    actions: PathBuf,
+    actions_zst: PathBuf,
+    bindings: PathBuf,
+}
+
+impl Fixture {
+    fn new() -> Self {
+        let dir = std::env::temp_dir().join(format!("renderdog-bench-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let actions = dir.join("bench.actions.jsonl");
+        let mut f = std::io::BufWriter::new(std::fs::File::create(&actions).unwrap());
+        for eid in 1..=RECORDS {
+            writeln!(
+                f,
+                r#"{{"event_id": {eid}, "parent_event_id": {parent}, "depth": 2, "name": "DrawIndexed({n})", "flags": 3, "flags_names": ["Drawcall", "Indexed"], "marker_path": ["Frame", "Pass {pass}"], "num_children": 0}}"#,
+                parent = eid - eid % 64,
+                n = eid * 3 % 4096,
+                pass = eid / 1000,
+            )
+            .unwrap();
+        }
+        drop(f);
+
+        let actions_zst = dir.join("bench.actions.jsonl.zst");
+        zstd::stream::copy_encode(
+            std::fs::File::open(&actions).unwrap(),
+            std::fs::File::create(&actions_zst).unwrap(),
+            0,
+        )
+        .unwrap();
+
+        let bindings = dir.join("bench.bindings.jsonl");
+        let mut f = std::io::BufWriter::new(std::fs::File::create(&bindings).unwrap());
+        for eid in 1..=RECORDS {
+            let srvs: Vec<String> = (0..8)
+                .map(|slot| {
+                    format!(
+                        r#"{{"slot": {slot}, "name": "t{slot}", "resource_id": "ResourceId::{id}", "resource_name": "Texture {id}"}}"#,
+                        id = 1000 + (eid + slot) % 512,
+                    )
+                })
+                .collect();
+            writeln!(
+                f,
+                r#"{{"event_id": {eid}, "depth": 2, "name": "DrawIndexed(36)", "marker_path": ["Frame"], "marker_path_joined": "Frame", "stages": {{"ShaderStage.Vertex": {{"shader": {{"resource_id": "ResourceId::1", "name": "vs_{vs}", "entry_point": "main"}}, "srvs": [], "uavs": [], "cbuffers": []}}, "ShaderStage.Pixel": {{"shader": {{"resource_id": "ResourceId::2", "name": "ps_{ps}", "entry_point": "main"}}, "srvs": [{srvs}], "uavs": [], "cbuffers": []}}}}, "shader_names": [], "resource_names": []}}"#,
+                vs = eid % 16,
+                ps = eid % 48,
+                srvs = srvs.join(", "),
+            )
+            .unwrap();
+        }
+        drop(f);
+
+        Self {
+            dir,
+            actions,
+            actions_zst,
+            bindings,
+        }
+    }
+}
+
+impl Drop for Fixture {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.dir);
+    }
+}
+
+fn file_len(path: &PathBuf) -> u64 {
+    std::fs::metadata(path).unwrap().len()
+}
+
+fn readers(c: &mut Criterion) {
+    let fixture = Fixture::new();
+
+    let mut group = c.benchmark_group("actions");
+    group.sample_size(10);
+    group.throughput(Throughput::Bytes(file_len(&fixture.actions)));
+    group.bench_function("read", |b| {
+        b.iter(|| {
+            ActionsReader::open(&fixture.actions)
+                .unwrap()
+                .map(Result::unwrap)
+                .count()
+        })
+    });
+    group.bench_function("read_zst", |b| {
+        b.iter(|| {
+            ActionsReader::open(&fixture.actions_zst)
+                .unwrap()
+                .map(Result::unwrap)
+                .count()
+        })
+    });
+    group.bench_function("summarize", |b| {
+        b.iter(|| {
+            ActionsReader::open(&fixture.actions)
+                .unwrap()
+                .summarize()
+                .unwrap()
+        })
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("bindings");
+    group.sample_size(10);
+    group.throughput(Throughput::Bytes(file_len(&fixture.bindings)));
+    group.bench_function("using_resource", |b| {
+        b.iter(|| {
+            BindingsReader::open(&fixture.bindings)
+                .unwrap()
+                .using_resource("Texture 1100")
+                .map(Result::unwrap)
+                .count()
+        })
+    });
+    group.bench_function("summarize", |b| {
+        b.iter(|| {
+            BindingsReader::open(&fixture.bindings)
+                .unwrap()
+                .summarize()
+                .unwrap()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, readers);
+criterion_main!(benches);
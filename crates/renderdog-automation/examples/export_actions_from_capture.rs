@@ -38,6 +38,12 @@ fn main() -> anyhow::Result<()> {
             name_contains: None,
             marker_contains: None,
             case_sensitive: false,
+            format: None,
+            query: None,
+            compress: false,
+            resolve_callstacks: false,
+            symbol_path: None,
+            replay_options: None,
         },
     )?;
 
@@ -40,6 +40,8 @@ fn main() -> anyhow::Result<()> {
             case_sensitive: false,
             include_cbuffers: false,
             include_outputs: false,
+            compress: false,
+            replay_options: None,
         },
     )?;
 
@@ -21,8 +21,14 @@ fn main() -> anyhow::Result<()> {
             event_id_max: None,
             name_contains: None,
             marker_contains,
+            name_regex: None,
+            marker_regex: None,
+            flags_any: Vec::new(),
+            flags_all: Vec::new(),
+            query: None,
             case_sensitive: false,
             max_results: Some(200),
+            replay_options: None,
         },
     )?;
 
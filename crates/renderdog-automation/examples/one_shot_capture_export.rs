@@ -76,10 +76,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             name_contains: None,
             marker_contains: None,
             case_sensitive: false,
+            format: None,
+            query: None,
+            compress: false,
+            resolve_callstacks: false,
+            symbol_path: None,
+            replay_options: None,
         },
     )?;
 
-    println!("actions_jsonl: {}", export.actions_jsonl_path);
+    println!("actions_jsonl: {}", export.actions_path);
     println!("summary_json:  {}", export.summary_json_path);
     println!(
         "actions: total={}, drawcalls={}",
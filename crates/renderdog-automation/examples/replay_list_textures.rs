@@ -15,6 +15,7 @@ fn main() -> anyhow::Result<()> {
         &renderdog::ReplayListTexturesRequest {
             capture_path,
             event_id,
+            replay_options: None,
         },
     )?;
 
@@ -30,6 +30,7 @@ fn main() -> anyhow::Result<()> {
             texture_index,
             x,
             y,
+            replay_options: None,
         },
     )?;
 
@@ -37,6 +37,8 @@ fn main() -> anyhow::Result<()> {
             output_dir: out_dir.display().to_string(),
             basename,
             include_depth: false,
+            format: None,
+            replay_options: None,
         },
     )?;
 
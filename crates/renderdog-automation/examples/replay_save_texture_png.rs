@@ -26,6 +26,18 @@ fn main() -> anyhow::Result<()> {
             event_id,
             texture_index,
             output_path,
+            format: None,
+            mip: None,
+            slice: None,
+            sample: None,
+            channels: None,
+            black_point: None,
+            white_point: None,
+            gamma: None,
+            slices: None,
+            grid_width: None,
+            all_mips: false,
+            replay_options: None,
         },
     )?;
 
@@ -12,6 +12,7 @@ fn main() {
         &renderdog_automation::GetPipelineDetailsRequest {
             capture_path: capture_path.to_string(),
             pipeline_name: "physics::compute_pipeline::update_particles".to_string(),
+            replay_options: None,
         },
     );
 
@@ -42,6 +43,7 @@ fn main() {
         &renderdog_automation::GetPipelineDetailsRequest {
             capture_path: capture_path.to_string(),
             pipeline_name: "model::pbr::pb_render_pipeline::pipeline".to_string(),
+            replay_options: None,
         },
     );
 
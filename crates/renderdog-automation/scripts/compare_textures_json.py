@@ -0,0 +1,338 @@
+"""
+compare_textures_json.py -- Compare two textures from the same or different captures.
+
+Request:
+  - a, b: {capture_path, event_id, texture, mip, slice}
+    texture: resource id ("123" / "ResourceId::123"), resource name, "rt<N>" or "depth"
+  - threshold: per-channel absolute difference above which a texel differs
+  - diff_output_path (optional): greyscale PNG of the per-texel max channel difference
+
+Texels are decoded to floats (normalized formats to [0, 1], sRGB linearized), so PSNR uses a
+peak of 1.0. SSIM is the mean over non-overlapping 8x8 blocks of Rec. 709 luminance.
+"""
+
+import array
+import json
+import math
+import os
+import struct
+import traceback
+import zlib
+
+import renderdoc as rd
+
+
+REQ_PATH = "compare_textures_json.request.json"
+RESP_PATH = "compare_textures_json.response.json"
+
+SSIM_BLOCK = 8
+SSIM_C1 = (0.01 * 1.0) ** 2
+SSIM_C2 = (0.03 * 1.0) ** 2
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def bound_resource_id(br):
+    rid = getattr(br, "resourceId", None)
+    if rid is None:
+        rid = getattr(br, "resource", None)
+    return rid
+
+
+def resolve_texture(controller, spec):
+    """Texture description for a resource id, resource name, "rt<N>" or "depth"."""
+    textures = {int(t.resourceId): t for t in controller.GetTextures()}
+    spec = str(spec).strip()
+    lowered = spec.lower()
+
+    rid = None
+    if lowered == "depth":
+        rid = bound_resource_id(controller.GetPipelineState().GetDepthTarget())
+    elif lowered.startswith("rt") and lowered[2:].isdigit():
+        targets = controller.GetPipelineState().GetOutputTargets()
+        idx = int(lowered[2:])
+        if idx < len(targets):
+            rid = bound_resource_id(targets[idx])
+    else:
+        digits = lowered.replace("resourceid::", "")
+        if digits.isdigit():
+            if int(digits) in textures:
+                return textures[int(digits)]
+            raise RuntimeError("No texture with resource id " + digits)
+        resources = controller.GetResources()
+        for exact in (True, False):
+            for r in resources:
+                name = str(r.name)
+                if (name == spec) if exact else (name.lower() == lowered):
+                    if int(r.resourceId) in textures:
+                        return textures[int(r.resourceId)]
+
+    if rid is None or rid == rd.ResourceId.Null() or int(rid) not in textures:
+        raise RuntimeError("Texture not found or not bound: " + spec)
+    return textures[int(rid)]
+
+
+def srgb_to_linear(v):
+    return v / 12.92 if v <= 0.04045 else ((v + 0.055) / 1.055) ** 2.4
+
+
+def small_float(bits, mantissa_bits):
+    exponent = bits >> mantissa_bits
+    mantissa = bits & ((1 << mantissa_bits) - 1)
+    if exponent == 0:
+        return mantissa / float(1 << mantissa_bits) * 2.0 ** -14
+    if exponent == 31:
+        return float("inf") if mantissa == 0 else float("nan")
+    return (1.0 + mantissa / float(1 << mantissa_bits)) * 2.0 ** (exponent - 15)
+
+
+def decode_texels(fmt, data):
+    """(channel count, per-channel value lists) for supported formats."""
+    if fmt.type == rd.ResourceFormatType.R10G10B10A2:
+        arr = array.array("I")
+        arr.frombytes(bytes(data[: len(data) - len(data) % 4]))
+        scale = [1023.0, 1023.0, 1023.0, 3.0] if fmt.compType == rd.CompType.UNorm else [1.0] * 4
+        shifts = [(0, 0x3FF), (10, 0x3FF), (20, 0x3FF), (30, 0x3)]
+        return 4, [[((v >> s) & m) / scale[i] for v in arr] for i, (s, m) in enumerate(shifts)]
+    if fmt.type == rd.ResourceFormatType.R11G11B10:
+        arr = array.array("I")
+        arr.frombytes(bytes(data[: len(data) - len(data) % 4]))
+        return 3, [
+            [small_float(v & 0x7FF, 6) for v in arr],
+            [small_float((v >> 11) & 0x7FF, 6) for v in arr],
+            [small_float((v >> 22) & 0x3FF, 5) for v in arr],
+        ]
+    if fmt.type != rd.ResourceFormatType.Regular or fmt.compCount == 0:
+        raise RuntimeError("Unsupported format for comparison: " + str(fmt.Name()))
+
+    width = int(fmt.compByteWidth)
+    ct = fmt.compType
+    n = int(fmt.compCount)
+    if ct == rd.CompType.Float and width == 2:
+        flat = [v[0] for v in struct.iter_unpack("<e", bytes(data[: len(data) - len(data) % 2]))]
+        return n, [flat[c::n] for c in range(n)]
+    if ct == rd.CompType.Float:
+        code = {4: "f", 8: "d"}.get(width)
+    elif ct in (rd.CompType.SInt, rd.CompType.SNorm):
+        code = {1: "b", 2: "h", 4: "i"}.get(width)
+    elif ct in (rd.CompType.UInt, rd.CompType.UNorm, rd.CompType.UNormSRGB, rd.CompType.Depth):
+        code = {1: "B", 2: "H", 4: "f" if ct == rd.CompType.Depth else "I"}.get(width)
+    else:
+        code = None
+    if code is None:
+        raise RuntimeError("Unsupported format for comparison: " + str(fmt.Name()))
+
+    arr = array.array(code)
+    arr.frombytes(bytes(data[: len(data) - len(data) % arr.itemsize]))
+    channels = [list(arr[c::n]) for c in range(n)]
+    if ct in (rd.CompType.UNorm, rd.CompType.UNormSRGB) or (ct == rd.CompType.Depth and code != "f"):
+        scale = float((1 << (width * 8)) - 1)
+        channels = [[v / scale for v in ch] for ch in channels]
+        if ct == rd.CompType.UNormSRGB:
+            channels = [[srgb_to_linear(v) for v in ch] if i < 3 else ch for i, ch in enumerate(channels)]
+    elif ct == rd.CompType.SNorm:
+        scale = float((1 << (width * 8 - 1)) - 1)
+        channels = [[max(v / scale, -1.0) for v in ch] for ch in channels]
+    if fmt.BGRAOrder() and n >= 3:
+        channels[0], channels[2] = channels[2], channels[0]
+    return n, channels
+
+
+def read_side(side, opts):
+    """Open one side's capture and return its decoded texture."""
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(side["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(opts, None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            event_id = side.get("event_id")
+            if event_id is not None:
+                controller.SetFrameEvent(int(event_id), True)
+            tex = resolve_texture(controller, side["texture"])
+            mip = int(side.get("mip", 0))
+            sub = rd.Subresource(mip, int(side.get("slice", 0)), 0)
+            n, channels = decode_texels(tex.format, controller.GetTextureData(tex.resourceId, sub))
+            return {
+                "format": str(tex.format.Name()),
+                "width": max(int(tex.width) >> mip, 1),
+                "height": max(int(tex.height) >> mip, 1),
+                "channels": channels,
+            }
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+
+
+def luminance(channels, i):
+    if len(channels) >= 3:
+        return 0.2126 * channels[0][i] + 0.7152 * channels[1][i] + 0.0722 * channels[2][i]
+    return channels[0][i]
+
+
+def block_ssim(a, b, width, height):
+    total = 0.0
+    blocks = 0
+    for by in range(0, height - SSIM_BLOCK + 1, SSIM_BLOCK):
+        for bx in range(0, width - SSIM_BLOCK + 1, SSIM_BLOCK):
+            xs = []
+            ys = []
+            for y in range(by, by + SSIM_BLOCK):
+                row = y * width
+                for x in range(bx, bx + SSIM_BLOCK):
+                    xs.append(a[row + x])
+                    ys.append(b[row + x])
+            count = float(len(xs))
+            mx = sum(xs) / count
+            my = sum(ys) / count
+            vx = sum((v - mx) ** 2 for v in xs) / count
+            vy = sum((v - my) ** 2 for v in ys) / count
+            cov = sum((xs[i] - mx) * (ys[i] - my) for i in range(len(xs))) / count
+            total += ((2 * mx * my + SSIM_C1) * (2 * cov + SSIM_C2)) / (
+                (mx * mx + my * my + SSIM_C1) * (vx + vy + SSIM_C2)
+            )
+            blocks += 1
+    if blocks == 0:
+        return 1.0 if a == b else 0.0
+    return total / blocks
+
+
+def write_grey_png(path, width, height, pixels):
+    def chunk(tag, payload):
+        body = tag + payload
+        return struct.pack(">I", len(payload)) + body + struct.pack(">I", zlib.crc32(body) & 0xFFFFFFFF)
+
+    raw = bytearray()
+    for y in range(height):
+        raw.append(0)
+        raw.extend(pixels[y * width : (y + 1) * width])
+    parent = os.path.dirname(path)
+    if parent:
+        os.makedirs(parent, exist_ok=True)
+    with open(path, "wb") as f:
+        f.write(b"\x89PNG\r\n\x1a\n")
+        f.write(chunk(b"IHDR", struct.pack(">IIBBBBB", width, height, 8, 0, 0, 0, 0)))
+        f.write(chunk(b"IDAT", zlib.compress(bytes(raw), 6)))
+        f.write(chunk(b"IEND", b""))
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    threshold = float(req.get("threshold", 0.0))
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+    try:
+        a = read_side(req["a"], replay_options(req))
+        b = read_side(req["b"], replay_options(req))
+    finally:
+        rd.ShutdownReplay()
+
+    if (a["width"], a["height"]) != (b["width"], b["height"]):
+        raise RuntimeError(
+            "Texture sizes differ: %dx%d vs %dx%d" % (a["width"], a["height"], b["width"], b["height"])
+        )
+    width, height = a["width"], a["height"]
+    texels = width * height
+    n = min(len(a["channels"]), len(b["channels"]))
+    ac = [ch[:texels] for ch in a["channels"][:n]]
+    bc = [ch[:texels] for ch in b["channels"][:n]]
+
+    deltas = [0.0] * texels
+    sq_sum = 0.0
+    diff_count = 0
+    for i in range(texels):
+        worst = 0.0
+        for c in range(n):
+            d = abs(ac[c][i] - bc[c][i])
+            if d != d:
+                d = float("inf")
+            sq_sum += d * d
+            if d > worst:
+                worst = d
+        deltas[i] = worst
+        if worst > threshold:
+            diff_count += 1
+
+    max_delta = max(deltas) if deltas else 0.0
+    mse = sq_sum / float(max(texels * n, 1))
+    psnr = None if mse == 0 else (10.0 * math.log10(1.0 / mse) if math.isfinite(mse) else 0.0)
+
+    la = [luminance(ac, i) for i in range(texels)]
+    lb = [luminance(bc, i) for i in range(texels)]
+    ssim = block_ssim(la, lb, width, height)
+
+    diff_path = req.get("diff_output_path")
+    if diff_path:
+        scale = 255.0 / max_delta if max_delta > 0 and math.isfinite(max_delta) else 0.0
+        pixels = bytes(255 if not math.isfinite(d) else min(255, int(d * scale + 0.5)) for d in deltas)
+        write_grey_png(diff_path, width, height, pixels)
+
+    write_envelope(
+        True,
+        result={
+            "a_format": a["format"],
+            "b_format": b["format"],
+            "width": width,
+            "height": height,
+            "channels": n,
+            "texel_count": texels,
+            "diff_count": diff_count,
+            "max_delta": max_delta if math.isfinite(max_delta) else 1.0e308,
+            "mse": mse if math.isfinite(mse) else 1.0e308,
+            "psnr": psnr,
+            "ssim": ssim,
+            "diff_output_path": diff_path,
+        },
+    )
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
@@ -0,0 +1,292 @@
+"""
+debug_shader_json.py -- Run RenderDoc's shader debugger headlessly and dump the trace.
+
+Request:
+  - capture_path
+  - event_id
+  - mode: "pixel" | "vertex" | "thread"
+  - pixel: x, y, sample, primitive, view (optional ones fall back to RenderDoc's defaults)
+  - vertex: vertex_id, instance, index, view
+  - thread: group [x, y, z], thread [x, y, z]
+  - max_steps: number of steps recorded in `steps` (the trace is always run to completion)
+
+Returns source-mapped steps with the variables each step changed, the shader inputs, the final
+value of every register touched, and source-level variables resolved against those registers.
+"""
+
+import json
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "debug_shader_json.request.json"
+RESP_PATH = "debug_shader_json.response.json"
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def find_action(controller, event_id):
+    def walk(actions):
+        for a in actions:
+            if a.eventId == event_id:
+                return a
+            found = walk(a.children)
+            if found is not None:
+                return found
+        return None
+
+    action = walk(controller.GetRootActions())
+    if action is None:
+        raise RuntimeError("No action with event_id %d" % event_id)
+    return action
+
+
+def enum_name(value) -> str:
+    return str(value).split(".")[-1]
+
+
+def variable_values(var):
+    count = max(int(var.rows), 1) * max(int(var.columns), 1)
+    t = var.type
+    v = var.value
+    if t == rd.VarType.Double:
+        src = v.f64v
+    elif t in (rd.VarType.SInt, rd.VarType.SShort, rd.VarType.SByte):
+        src = v.s32v
+    elif t in (rd.VarType.SLong,):
+        src = v.s64v
+    elif t in (rd.VarType.ULong, rd.VarType.GPUPointer):
+        src = v.u64v
+    elif t in (rd.VarType.UInt, rd.VarType.UShort, rd.VarType.UByte, rd.VarType.Bool, rd.VarType.Enum):
+        src = v.u32v
+    else:
+        src = v.f32v
+    return [float(x) for x in list(src)[:count]]
+
+
+def flatten_variable(var, prefix=""):
+    """ShaderVariable (possibly a struct/array) -> list of leaf dicts with dotted names."""
+    name = prefix + var.name
+    if len(var.members) > 0:
+        out = []
+        for m in var.members:
+            sep = "" if m.name.startswith("[") else "."
+            out.extend(flatten_variable(m, name + sep))
+        return out
+    return [
+        {
+            "name": name,
+            "type_name": enum_name(var.type),
+            "rows": int(var.rows),
+            "columns": int(var.columns),
+            "values": variable_values(var),
+        }
+    ]
+
+
+def start_trace(controller, req, mode):
+    no_pref = rd.ReplayController.NoPreference
+    if mode == "pixel":
+        x, y = int(req["x"]), int(req["y"])
+        sample = req.get("sample")
+        primitive = req.get("primitive")
+        view = req.get("view")
+        if hasattr(rd, "DebugPixelInputs"):
+            inputs = rd.DebugPixelInputs()
+            inputs.sample = no_pref if sample is None else int(sample)
+            inputs.primitive = no_pref if primitive is None else int(primitive)
+            inputs.view = no_pref if view is None else int(view)
+            return controller.DebugPixel(x, y, inputs)
+        return controller.DebugPixel(
+            x, y, no_pref if sample is None else int(sample), no_pref if primitive is None else int(primitive)
+        )
+    if mode == "vertex":
+        vertex_id = int(req["vertex_id"])
+        index = req.get("index")
+        index = vertex_id if index is None else int(index)
+        instance = int(req.get("instance", 0))
+        view = int(req.get("view", 0))
+        try:
+            return controller.DebugVertex(vertex_id, instance, index, view)
+        except TypeError:
+            return controller.DebugVertex(vertex_id, instance, index)
+    if mode == "thread":
+        group = tuple(int(v) for v in req["group"])
+        thread = tuple(int(v) for v in req["thread"])
+        return controller.DebugThread(group, thread)
+    raise RuntimeError("mode must be 'pixel', 'vertex' or 'thread', got: " + str(mode))
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    event_id = int(req["event_id"])
+    mode = str(req.get("mode", "pixel")).lower()
+    max_steps = int(req.get("max_steps", 10000))
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            find_action(controller, event_id)
+            controller.SetFrameEvent(event_id, True)
+
+            trace = start_trace(controller, req, mode)
+            if trace is None or trace.debugger is None:
+                if trace is not None:
+                    controller.FreeTrace(trace)
+                raise RuntimeError(
+                    "Shader debugging is not available for this %s at event %d "
+                    "(unsupported API/shader, or no invocation hit)" % (mode, event_id)
+                )
+
+            try:
+                refl = controller.GetPipelineState().GetShaderReflection(trace.stage)
+                files = []
+                if refl is not None and refl.debugInfo is not None:
+                    files = [str(f.filename) for f in refl.debugInfo.files]
+
+                inst_info = {int(i.instruction): i for i in trace.instInfo}
+
+                def source_location(instruction):
+                    info = inst_info.get(int(instruction))
+                    if info is None:
+                        return None, None
+                    line = info.lineInfo
+                    file_index = int(line.fileIndex)
+                    if file_index < 0 or file_index >= len(files) or int(line.lineStart) == 0:
+                        return None, None
+                    return files[file_index], int(line.lineStart)
+
+                inputs = []
+                for var in trace.inputs:
+                    inputs.extend(flatten_variable(var))
+
+                final = {}
+                steps = []
+                total_steps = 0
+                while True:
+                    states = controller.ContinueDebug(trace.debugger)
+                    if len(states) == 0:
+                        break
+                    for st in states:
+                        total_steps += 1
+                        changes = []
+                        for change in st.changes:
+                            for leaf in flatten_variable(change.after):
+                                final[leaf["name"]] = leaf
+                                changes.append(leaf)
+                        if len(steps) < max_steps:
+                            file, line = source_location(st.nextInstruction)
+                            steps.append(
+                                {
+                                    "step": int(st.stepIndex),
+                                    "instruction": int(st.nextInstruction),
+                                    "file": file,
+                                    "line": line,
+                                    "changes": changes,
+                                }
+                            )
+
+                registers = {leaf["name"]: leaf for leaf in inputs}
+                registers.update(final)
+
+                source_variables = []
+                for mapping in trace.sourceVars:
+                    values = []
+                    for ref in mapping.variables:
+                        reg = registers.get(ref.name)
+                        comp = int(ref.component)
+                        if reg is not None and comp < len(reg["values"]):
+                            values.append(reg["values"][comp])
+                        else:
+                            values.append(None)
+                    source_variables.append(
+                        {
+                            "name": str(mapping.name),
+                            "type_name": enum_name(mapping.type),
+                            "rows": int(mapping.rows),
+                            "columns": int(mapping.columns),
+                            "registers": [str(ref.name) for ref in mapping.variables],
+                            "values": values,
+                        }
+                    )
+
+                write_envelope(
+                    True,
+                    result={
+                        "capture_path": req["capture_path"],
+                        "event_id": event_id,
+                        "mode": mode,
+                        "stage": enum_name(trace.stage),
+                        "source_files": files,
+                        "total_steps": total_steps,
+                        "truncated": total_steps > len(steps),
+                        "steps": steps,
+                        "inputs": inputs,
+                        "variables": sorted(final.values(), key=lambda v: v["name"]),
+                        "source_variables": source_variables,
+                    },
+                )
+            finally:
+                controller.FreeTrace(trace)
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
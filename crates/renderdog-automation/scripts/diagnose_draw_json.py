@@ -0,0 +1,453 @@
+"""
+diagnose_draw_json.py -- Rank likely reasons a draw produces no visible output.
+
+Request:
+  - capture_path
+  - event_id
+
+Checks (each reported with its evidence when it trips):
+  - zero_count: no vertices / indices or zero instances
+  - no_render_targets / no_fragment_shader / rasterizer_discard
+  - viewport_empty / viewport_offscreen / scissor_empty / scissor_offscreen
+  - outside_clip_volume: every post-VS vertex lies outside one clip plane (or behind the camera)
+  - culled_by_winding / degenerate_triangles: every checked triangle is culled or has zero area
+  - depth_never / depth_equal / depth_bounds_empty
+  - color_write_mask_off / blend_noop / logic_op_noop / zero_alpha_written / blend_uses_src_alpha
+
+Severity is "high" when the check alone explains an invisible draw, "medium" when it can, and
+"low" for hints; causes are returned highest first.
+"""
+
+import json
+import struct
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "diagnose_draw_json.request.json"
+RESP_PATH = "diagnose_draw_json.response.json"
+
+MAX_TRIANGLES = 4096
+SEVERITY_RANK = {"high": 0, "medium": 1, "low": 2}
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def flatten_actions(actions):
+    out = []
+    for a in actions:
+        out.append(a)
+        out.extend(flatten_actions(a.children))
+    return out
+
+
+def enum_name(value) -> str:
+    return str(value).split(".")[-1]
+
+
+def bound_resource_id(br):
+    rid = getattr(br, "resourceId", None)
+    if rid is None:
+        rid = getattr(br, "resource", None)
+    return rid
+
+
+def api_state(controller, api):
+    """(cull mode, front CCW, rasterizer discard, depth enabled, depth func, depth bounds) per API."""
+    if api == rd.GraphicsAPI.Vulkan:
+        vk = controller.GetVulkanPipelineState()
+        ds = vk.depthStencil
+        bounds = (ds.minDepthBounds, ds.maxDepthBounds) if ds.depthBoundsEnable else None
+        return (
+            vk.rasterizer.cullMode,
+            vk.rasterizer.frontCCW,
+            vk.rasterizer.rasterizerDiscardEnable,
+            ds.depthTestEnable,
+            ds.depthFunction,
+            bounds,
+        )
+    if api == rd.GraphicsAPI.D3D11:
+        d3d = controller.GetD3D11PipelineState()
+        ds = d3d.outputMerger.depthStencilState
+        rs = d3d.rasterizer.state
+        return rs.cullMode, rs.frontCCW, False, ds.depthEnable, ds.depthFunction, None
+    if api == rd.GraphicsAPI.D3D12:
+        d3d = controller.GetD3D12PipelineState()
+        ds = d3d.outputMerger.depthStencilState
+        rs = d3d.rasterizer.state
+        bounds = (ds.minDepthBounds, ds.maxDepthBounds) if ds.depthBoundsEnable else None
+        return rs.cullMode, rs.frontCCW, False, ds.depthEnable, ds.depthFunction, bounds
+    gl = controller.GetGLPipelineState()
+    rs = gl.rasterizer.state
+    return (
+        rs.cullMode,
+        rs.frontCCW,
+        gl.vertexProcessing.discard,
+        gl.depthState.depthEnable,
+        gl.depthState.depthFunction,
+        (gl.depthState.nearBound, gl.depthState.farBound) if gl.depthState.depthBounds else None,
+    )
+
+
+def post_vs_positions(controller, pipe):
+    """(clip-space positions, indices, topology) of instance 0, or Nones when unavailable."""
+    if pipe.GetShaderReflection(rd.ShaderStage.Vertex) is None:
+        return None, None, None
+    mesh = controller.GetPostVSData(0, 0, rd.MeshDataStage.VSOut)
+    if mesh.vertexResourceId == rd.ResourceId.Null() or mesh.numIndices == 0:
+        return None, None, None
+    if mesh.indexResourceId == rd.ResourceId.Null() or mesh.indexByteStride == 0:
+        indices = list(range(mesh.numIndices))
+    else:
+        fmt = {1: "B", 2: "H", 4: "I"}[mesh.indexByteStride]
+        data = controller.GetBufferData(mesh.indexResourceId, mesh.indexByteOffset, mesh.numIndices * mesh.indexByteStride)
+        indices = [i + mesh.baseVertex for i in struct.unpack_from("<%d%s" % (mesh.numIndices, fmt), data, 0)]
+    vb = controller.GetBufferData(mesh.vertexResourceId, mesh.vertexByteOffset, 0)
+    stride = int(mesh.vertexByteStride)
+    count = min(max(indices) + 1, len(vb) // stride) if stride else 0
+    # RenderDoc stores the position first in the post-transform buffer.
+    positions = [struct.unpack_from("<4f", vb, v * stride) for v in range(count)]
+    return positions, [i for i in indices if i < count], mesh.topology
+
+
+def triangles(topology, indices):
+    T = rd.Topology
+    n = len(indices)
+    if topology == T.TriangleList:
+        return [tuple(indices[i : i + 3]) for i in range(0, n - 2, 3)]
+    if topology == T.TriangleStrip:
+        return [
+            (indices[i], indices[i + 1], indices[i + 2]) if i % 2 == 0 else (indices[i + 1], indices[i], indices[i + 2])
+            for i in range(n - 2)
+        ]
+    if topology == T.TriangleFan:
+        return [(indices[0], indices[i], indices[i + 1]) for i in range(1, n - 1)]
+    return []
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    event_id = int(req["event_id"])
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            sfile = controller.GetStructuredFile()
+            action = next(
+                (a for a in flatten_actions(controller.GetRootActions()) if int(a.eventId) == event_id), None
+            )
+            if action is None:
+                raise RuntimeError("No action at event %d" % event_id)
+            if not action.flags & rd.ActionFlags.Drawcall:
+                raise RuntimeError("Event %d is not a draw" % event_id)
+
+            controller.SetFrameEvent(event_id, True)
+            pipe = controller.GetPipelineState()
+            api = controller.GetAPIProperties().pipelineType
+            textures = {int(t.resourceId): t for t in controller.GetTextures()}
+
+            causes = []
+            checks = []
+
+            def check(name):
+                checks.append(name)
+
+            def cause(name, severity, message, evidence=None):
+                causes.append({"check": name, "severity": severity, "message": message, "evidence": evidence})
+
+            # --- Counts ---
+            check("zero_count")
+            if int(action.numIndices) == 0 or int(action.numInstances) == 0:
+                cause(
+                    "zero_count",
+                    "high",
+                    "Draw has no vertices/indices or zero instances.",
+                    {"num_indices": int(action.numIndices), "num_instances": int(action.numInstances)},
+                )
+
+            # --- Targets and rasterization ---
+            outputs = [bound_resource_id(t) for t in pipe.GetOutputTargets()]
+            outputs = [int(r) for r in outputs if r is not None and r != rd.ResourceId.Null()]
+            depth_rid = bound_resource_id(pipe.GetDepthTarget())
+            depth = int(depth_rid) if depth_rid is not None and depth_rid != rd.ResourceId.Null() else None
+
+            check("no_render_targets")
+            if not outputs and depth is None:
+                cause("no_render_targets", "high", "No color or depth target is bound.")
+
+            check("no_fragment_shader")
+            if outputs and pipe.GetShader(rd.ShaderStage.Fragment) == rd.ResourceId.Null():
+                cause(
+                    "no_fragment_shader",
+                    "medium",
+                    "Color targets are bound but no fragment/pixel shader is; only depth is written.",
+                    {"color_targets": outputs},
+                )
+
+            cull, front_ccw, discard, depth_enabled, depth_func, depth_bounds = api_state(controller, api)
+
+            check("rasterizer_discard")
+            if discard:
+                cause("rasterizer_discard", "high", "Rasterizer discard is enabled; no fragments are generated.")
+
+            # --- Viewport / scissor ---
+            target = textures.get(outputs[0] if outputs else depth) if (outputs or depth is not None) else None
+            rt_w = int(target.width) if target is not None else None
+            rt_h = int(target.height) if target is not None else None
+
+            def offscreen(x0, y0, x1, y1):
+                return rt_w is not None and (x1 <= 0 or y1 <= 0 or x0 >= rt_w or y0 >= rt_h)
+
+            vp = pipe.GetViewport(0)
+            vp_rect = [vp.x, min(vp.y, vp.y + vp.height), vp.x + vp.width, max(vp.y, vp.y + vp.height)]
+            vp_evidence = {"x": vp.x, "y": vp.y, "width": vp.width, "height": vp.height, "target": [rt_w, rt_h]}
+            check("viewport_empty")
+            check("viewport_offscreen")
+            if vp.width == 0 or vp.height == 0:
+                cause("viewport_empty", "high", "Viewport 0 has zero area.", vp_evidence)
+            elif offscreen(*vp_rect):
+                cause("viewport_offscreen", "high", "Viewport 0 lies entirely outside the render target.", vp_evidence)
+
+            sc = pipe.GetScissor(0)
+            check("scissor_empty")
+            check("scissor_offscreen")
+            if sc.enabled:
+                sc_evidence = {"x": sc.x, "y": sc.y, "width": sc.width, "height": sc.height, "target": [rt_w, rt_h]}
+                if sc.width <= 0 or sc.height <= 0:
+                    cause("scissor_empty", "high", "Scissor 0 has zero area.", sc_evidence)
+                elif offscreen(sc.x, sc.y, sc.x + sc.width, sc.y + sc.height):
+                    cause("scissor_offscreen", "high", "Scissor 0 lies entirely outside the render target.", sc_evidence)
+                elif (
+                    sc.x + sc.width <= vp_rect[0]
+                    or sc.y + sc.height <= vp_rect[1]
+                    or sc.x >= vp_rect[2]
+                    or sc.y >= vp_rect[3]
+                ):
+                    cause(
+                        "scissor_offscreen",
+                        "high",
+                        "Scissor 0 does not overlap viewport 0.",
+                        dict(sc_evidence, viewport=vp_evidence),
+                    )
+
+            # --- Geometry: clip volume, winding, degenerates ---
+            positions, indices, topology = post_vs_positions(controller, pipe)
+            if positions is not None:
+                used = sorted(set(indices))
+                z_min_clip = -1.0 if api == rd.GraphicsAPI.OpenGL else 0.0
+                planes = {
+                    "behind_camera (w <= 0)": lambda p: p[3] <= 0,
+                    "left (x < -w)": lambda p: p[0] < -p[3],
+                    "right (x > w)": lambda p: p[0] > p[3],
+                    "bottom (y < -w)": lambda p: p[1] < -p[3],
+                    "top (y > w)": lambda p: p[1] > p[3],
+                    "near (z < near)": lambda p: p[2] < z_min_clip * p[3],
+                    "far (z > w)": lambda p: p[2] > p[3],
+                }
+                check("outside_clip_volume")
+                for plane, outside in planes.items():
+                    if used and all(outside(positions[i]) for i in used):
+                        cause(
+                            "outside_clip_volume",
+                            "high",
+                            "Every vertex is outside the %s clip plane." % plane,
+                            {"plane": plane, "vertices": len(used), "first_position": list(positions[used[0]])},
+                        )
+                        break
+
+                tris = triangles(topology, indices)[:MAX_TRIANGLES]
+                if tris:
+                    check("culled_by_winding")
+                    check("degenerate_triangles")
+                    # Screen-space y points down; Vulkan keeps NDC y unless the viewport flips it.
+                    y_sign = (1.0 if vp.height >= 0 else -1.0) if api == rd.GraphicsAPI.Vulkan else -1.0
+                    cull_name = enum_name(cull)
+                    checked = culled = degenerate = 0
+                    for a, b, c in tris:
+                        pa, pb, pc = positions[a], positions[b], positions[c]
+                        if pa[3] <= 0 or pb[3] <= 0 or pc[3] <= 0:
+                            continue
+                        ax, ay = pa[0] / pa[3], y_sign * pa[1] / pa[3]
+                        bx, by = pb[0] / pb[3], y_sign * pb[1] / pb[3]
+                        cx, cy = pc[0] / pc[3], y_sign * pc[1] / pc[3]
+                        area = (bx - ax) * (cy - ay) - (cx - ax) * (by - ay)
+                        checked += 1
+                        if area == 0:
+                            degenerate += 1
+                            continue
+                        front = (area < 0) == bool(front_ccw)
+                        if (cull_name == "Back" and not front) or (cull_name == "Front" and front):
+                            culled += 1
+                    evidence = {
+                        "cull_mode": cull_name,
+                        "front_ccw": bool(front_ccw),
+                        "triangles_checked": checked,
+                        "culled": culled,
+                        "degenerate": degenerate,
+                    }
+                    if cull_name == "FrontAndBack":
+                        cause("culled_by_winding", "high", "Cull mode culls both front and back faces.", evidence)
+                    elif checked and degenerate == checked:
+                        cause("degenerate_triangles", "high", "Every checked triangle has zero area.", evidence)
+                    elif checked and culled + degenerate == checked:
+                        cause(
+                            "culled_by_winding",
+                            "high",
+                            "Every checked triangle is culled by the %s cull mode; winding may be inverted." % cull_name,
+                            evidence,
+                        )
+                    elif checked and culled * 2 > checked:
+                        cause(
+                            "culled_by_winding",
+                            "low",
+                            "Most checked triangles are culled (%d of %d)." % (culled, checked),
+                            evidence,
+                        )
+
+            # --- Depth ---
+            if depth is not None and depth_enabled:
+                check("depth_never")
+                check("depth_equal")
+                func = enum_name(depth_func)
+                if func == "Never":
+                    cause("depth_never", "high", "Depth test function is Never.", {"depth_function": func})
+                elif func == "Equal":
+                    cause(
+                        "depth_equal",
+                        "low",
+                        "Depth test function is Equal; fragments pass only on an exact depth match.",
+                        {"depth_function": func},
+                    )
+                if depth_bounds is not None:
+                    check("depth_bounds_empty")
+                    if depth_bounds[0] > depth_bounds[1]:
+                        cause(
+                            "depth_bounds_empty",
+                            "high",
+                            "Depth bounds test is enabled with min > max.",
+                            {"min": depth_bounds[0], "max": depth_bounds[1]},
+                        )
+
+            # --- Blending / write masks ---
+            if outputs:
+                blends = pipe.GetColorBlends()
+                check("color_write_mask_off")
+                check("blend_noop")
+                check("logic_op_noop")
+                check("zero_alpha_written")
+                check("blend_uses_src_alpha")
+                masked = []
+                for i, b in enumerate(blends[: len(pipe.GetOutputTargets())]):
+                    slot = "rt%d" % i
+                    if bound_resource_id(pipe.GetOutputTargets()[i]) == rd.ResourceId.Null():
+                        continue
+                    if int(b.writeMask) == 0:
+                        masked.append(slot)
+                        continue
+                    if b.logicOperationEnabled and enum_name(b.logicOperation) == "NoOp":
+                        cause("logic_op_noop", "high", "%s: logic op is NoOp." % slot, {"target": slot})
+                    if not b.enabled:
+                        continue
+                    color = b.colorBlend
+                    alpha = b.alphaBlend
+                    src, dst = enum_name(color.source), enum_name(color.destination)
+                    evidence = {
+                        "target": slot,
+                        "color": [src, dst, enum_name(color.operation)],
+                        "alpha": [enum_name(alpha.source), enum_name(alpha.destination), enum_name(alpha.operation)],
+                    }
+                    if src == "Zero" and dst == "One" and enum_name(color.operation) == "Add":
+                        cause("blend_noop", "high", "%s: blend keeps the destination color (Zero, One, Add)." % slot, evidence)
+                    if enum_name(alpha.source) == "Zero" and enum_name(alpha.destination) == "Zero":
+                        cause("zero_alpha_written", "medium", "%s: alpha blend writes zero alpha." % slot, evidence)
+                    if src in ("SrcAlpha", "InvSrcAlpha"):
+                        cause(
+                            "blend_uses_src_alpha",
+                            "low",
+                            "%s: color blend is weighted by the shader's alpha; alpha 0 makes the draw invisible." % slot,
+                            evidence,
+                        )
+                if masked:
+                    all_masked = len(masked) == len(outputs)
+                    cause(
+                        "color_write_mask_off",
+                        "high" if all_masked and depth is None else "medium",
+                        "Color write mask is 0 for %s." % ", ".join(masked),
+                        {"targets": masked},
+                    )
+
+            causes.sort(key=lambda c: SEVERITY_RANK[c["severity"]])
+
+            write_envelope(
+                True,
+                result={
+                    "capture_path": req["capture_path"],
+                    "event_id": event_id,
+                    "event_name": action.GetName(sfile),
+                    "api": enum_name(api),
+                    "checks_run": checks,
+                    "causes": causes,
+                },
+            )
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
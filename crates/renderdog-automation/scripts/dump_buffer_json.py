@@ -0,0 +1,379 @@
+"""
+dump_buffer_json.py -- Write a buffer's contents to .bin, or to CSV / JSONL rows.
+
+Request:
+  - capture_path
+  - buffer: resource id ("123" / "ResourceId::123") or resource name
+  - event_id (optional): contents as of this event; the end of the frame when unset
+  - offset, length (optional): byte range; length unset reads to the end of the buffer
+  - output_path
+  - format: "bin", "csv" or "jsonl"
+
+CSV / JSONL need a struct layout: it is taken from the reflection of a shader that binds the
+buffer as a structured / storage buffer, at event_id when it is bound there, otherwise at the
+first action that binds it. Each row is one element; columns are flattened field paths such as
+`pos[0]` or `lights[2].color[1]`.
+"""
+
+import csv
+import json
+import math
+import os
+import struct
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "dump_buffer_json.request.json"
+RESP_PATH = "dump_buffer_json.response.json"
+
+STAGES = [
+    rd.ShaderStage.Vertex,
+    rd.ShaderStage.Tess_Control,
+    rd.ShaderStage.Tess_Eval,
+    rd.ShaderStage.Geometry,
+    rd.ShaderStage.Fragment,
+    rd.ShaderStage.Task,
+    rd.ShaderStage.Mesh,
+    rd.ShaderStage.Compute,
+]
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def flatten_actions(actions):
+    out = []
+    for a in actions:
+        out.append(a)
+        out.extend(flatten_actions(a.children))
+    return out
+
+
+def resolve_buffer(controller, spec):
+    buffers = {int(b.resourceId): b for b in controller.GetBuffers()}
+    spec = str(spec).strip()
+    digits = spec.lower().replace("resourceid::", "")
+    if digits.isdigit():
+        if int(digits) in buffers:
+            return buffers[int(digits)]
+        raise RuntimeError("No buffer with resource id " + digits)
+    resources = controller.GetResources()
+    for exact in (True, False):
+        for r in resources:
+            name = str(r.name)
+            if (name == spec) if exact else (name.lower() == spec.lower()):
+                if int(r.resourceId) in buffers:
+                    return buffers[int(r.resourceId)]
+    raise RuntimeError("Buffer not found: " + spec)
+
+
+def vartype_to_struct_char(vartype):
+    """Map a renderdoc VarType enum to a Python struct format character."""
+    mapping = {
+        rd.VarType.Float:  'f',
+        rd.VarType.Half:   'e',
+        rd.VarType.Double: 'd',
+        rd.VarType.SInt:   'i',
+        rd.VarType.UInt:   'I',
+        rd.VarType.SShort: 'h',
+        rd.VarType.UShort: 'H',
+        rd.VarType.SByte:  'b',
+        rd.VarType.UByte:  'B',
+        rd.VarType.SLong:  'q',
+        rd.VarType.ULong:  'Q',
+        rd.VarType.Bool:   'I',
+    }
+    return mapping.get(vartype, None)
+
+
+def vartype_byte_size(vartype):
+    """Byte size of a single scalar for the given VarType."""
+    sizes = {
+        rd.VarType.Float:  4,
+        rd.VarType.Half:   2,
+        rd.VarType.Double: 8,
+        rd.VarType.SInt:   4,
+        rd.VarType.UInt:   4,
+        rd.VarType.SShort: 2,
+        rd.VarType.UShort: 2,
+        rd.VarType.SByte:  1,
+        rd.VarType.UByte:  1,
+        rd.VarType.SLong:  8,
+        rd.VarType.ULong:  8,
+        rd.VarType.Bool:   4,
+    }
+    return sizes.get(vartype, 4)
+
+
+class FieldDef:
+    """A single scalar column we'll read from the buffer."""
+    __slots__ = ('name', 'byte_offset', 'struct_char')
+
+    def __init__(self, name, byte_offset, struct_char):
+        self.name = name
+        self.byte_offset = byte_offset
+        self.struct_char = struct_char
+
+
+def flatten_constant_type(prefix, const, base_offset):
+    """Recursively flatten a ShaderConstant into a list of FieldDef."""
+    ctype = const.type
+    abs_offset = base_offset + const.byteOffset
+    field_name = ("%s.%s" % (prefix, const.name)) if prefix else const.name
+
+    if len(ctype.members) > 0:
+        fields = []
+        arr_count = max(ctype.elements, 1)
+        for arr_i in range(arr_count):
+            arr_prefix = ("%s[%d]" % (field_name, arr_i)) if arr_count > 1 else field_name
+            elem_offset = abs_offset + arr_i * ctype.arrayByteStride if arr_count > 1 else abs_offset
+            for member in ctype.members:
+                fields.extend(flatten_constant_type(arr_prefix, member, elem_offset))
+        return fields
+
+    char = vartype_to_struct_char(ctype.baseType)
+    if char is None:
+        return []
+
+    scalar_size = vartype_byte_size(ctype.baseType)
+    fields = []
+
+    arr_count = max(ctype.elements, 1)
+    rows = max(ctype.rows, 1)
+    cols = max(ctype.columns, 1)
+    total_scalars = rows * cols
+
+    for arr_i in range(arr_count):
+        arr_name = ("%s[%d]" % (field_name, arr_i)) if arr_count > 1 else field_name
+
+        if arr_count > 1:
+            elem_base = abs_offset + arr_i * ctype.arrayByteStride
+        else:
+            elem_base = abs_offset
+
+        if total_scalars == 1:
+            fields.append(FieldDef(arr_name, elem_base, char))
+        else:
+            for r in range(rows):
+                for c in range(cols):
+                    comp_idx = r * cols + c
+                    if rows > 1 and cols > 1:
+                        comp_name = "%s[%d][%d]" % (arr_name, r, c)
+                    else:
+                        comp_name = "%s[%d]" % (arr_name, comp_idx)
+                    comp_offset = elem_base + comp_idx * scalar_size
+                    fields.append(FieldDef(comp_name, comp_offset, char))
+
+    return fields
+
+
+def bound_layout(controller, buf_id):
+    """Layout of buf_id from a shader bound at the current event, or None."""
+    state = controller.GetPipelineState()
+    for stage in STAGES:
+        refl = state.GetShaderReflection(stage)
+        if refl is None:
+            continue
+        for used_list, refl_list in (
+            (state.GetReadWriteResources(stage), refl.readWriteResources),
+            (state.GetReadOnlyResources(stage), refl.readOnlyResources),
+        ):
+            for used in used_list:
+                if used.descriptor.resource != buf_id:
+                    continue
+                idx = int(used.access.index)
+                if idx < len(refl_list) and len(refl_list[idx].variableType.members) > 0:
+                    return extract_fields_from_resource(refl_list[idx])
+    return None
+
+
+def extract_fields_from_resource(shader_res):
+    """Given a ShaderResource from reflection, flatten its variableType into FieldDefs."""
+    var_type = shader_res.variableType
+
+    if len(var_type.members) == 0:
+        raise RuntimeError(
+            "Shader resource '%s' has no struct members in its type. "
+            "The buffer may not be a structured buffer." % shader_res.name
+        )
+
+    members = var_type.members
+
+    if len(members) == 1 and len(members[0].type.members) > 0:
+        inner = members[0]
+        members = inner.type.members
+
+    fields = []
+    for member in members:
+        fields.extend(flatten_constant_type("", member, 0))
+
+    if not fields:
+        raise RuntimeError("Failed to extract any fields from the struct layout.")
+
+    last = max(fields, key=lambda f: f.byte_offset)
+    stride = last.byte_offset + struct.calcsize(last.struct_char)
+
+    if var_type.arrayByteStride > 0:
+        stride = var_type.arrayByteStride
+    elif (len(shader_res.variableType.members) == 1
+          and shader_res.variableType.members[0].type.arrayByteStride > 0):
+        stride = shader_res.variableType.members[0].type.arrayByteStride
+
+    return fields, stride
+
+
+def json_scalar(v):
+    """JSON-safe value: non-finite floats become the strings "nan", "inf" and "-inf"."""
+    if isinstance(v, float) and not math.isfinite(v):
+        return str(v)
+    return v
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    out_format = str(req.get("format") or "bin").lower()
+    if out_format not in ("bin", "csv", "jsonl"):
+        raise RuntimeError("format must be bin, csv or jsonl")
+
+    output_path = str(req["output_path"])
+    out_dir = os.path.dirname(output_path)
+    if out_dir:
+        os.makedirs(out_dir, exist_ok=True)
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            event_id = req.get("event_id")
+            if event_id is not None:
+                controller.SetFrameEvent(int(event_id), True)
+
+            buf = resolve_buffer(controller, req["buffer"])
+            names = {int(r.resourceId): str(r.name) for r in controller.GetResources()}
+            offset = int(req.get("offset") or 0)
+            if offset > int(buf.length):
+                raise RuntimeError("offset %d is past the end of the buffer (%d bytes)" % (offset, int(buf.length)))
+            length = req.get("length")
+            length = int(buf.length) - offset if length is None else min(int(length), int(buf.length) - offset)
+            data = bytes(controller.GetBufferData(buf.resourceId, offset, length))
+
+            stride = None
+            columns = []
+            rows = None
+            if out_format == "bin":
+                with open(output_path, "wb") as f:
+                    f.write(data)
+            else:
+                layout = bound_layout(controller, buf.resourceId) if event_id is not None else None
+                if layout is None:
+                    for action in flatten_actions(controller.GetRootActions()):
+                        controller.SetFrameEvent(int(action.eventId), False)
+                        layout = bound_layout(controller, buf.resourceId)
+                        if layout is not None:
+                            break
+                    if event_id is not None:
+                        controller.SetFrameEvent(int(event_id), True)
+                if layout is None:
+                    raise RuntimeError(
+                        "No shader binds this buffer with a struct layout; use format=bin instead"
+                    )
+                fields, stride = layout
+                stride = int(stride)
+                columns = [fd.name for fd in fields]
+                rows = len(data) // stride if stride > 0 else 0
+
+                def row_values(i):
+                    base = i * stride
+                    return [struct.unpack_from("<" + fd.struct_char, data, base + fd.byte_offset)[0] for fd in fields]
+
+                if out_format == "csv":
+                    with open(output_path, "w", encoding="utf-8", newline="") as f:
+                        writer = csv.writer(f)
+                        writer.writerow(["index"] + columns)
+                        for i in range(rows):
+                            writer.writerow([i] + row_values(i))
+                else:
+                    with open(output_path, "w", encoding="utf-8") as f:
+                        for i in range(rows):
+                            row = {"index": i}
+                            row.update(zip(columns, (json_scalar(v) for v in row_values(i))))
+                            f.write(json.dumps(row, ensure_ascii=False) + "\n")
+
+            write_envelope(
+                True,
+                result={
+                    "capture_path": req["capture_path"],
+                    "event_id": event_id,
+                    "buffer_id": int(buf.resourceId),
+                    "buffer_name": names.get(int(buf.resourceId), ""),
+                    "buffer_length": int(buf.length),
+                    "offset": offset,
+                    "byte_count": len(data),
+                    "format": out_format,
+                    "output_path": output_path,
+                    "stride": stride,
+                    "row_count": rows,
+                    "columns": columns,
+                },
+            )
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
@@ -1,3 +1,5 @@
+import csv
+import fnmatch
 import json
 import os
 import traceback
@@ -8,12 +10,121 @@ import renderdoc as rd
 REQ_PATH = "export_actions_jsonl.request.json"
 RESP_PATH = "export_actions_jsonl.response.json"
 
+# Bump together with EXPORT_SCHEMA_VERSION in src/artifacts.rs when records or summaries change.
+SCHEMA_VERSION = 1
+
 
 def write_envelope(ok: bool, result=None, error: str = None) -> None:
     with open(RESP_PATH, "w", encoding="utf-8") as f:
         json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
 
 
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+# Records written between checkpoints (each one flushes the output).
+CHECKPOINT_EVERY = 1000
+
+
+class Checkpoint:
+    """Resume state kept in `<output>.checkpoint` while an export runs.
+
+    Every CHECKPOINT_EVERY records the output is flushed and the checkpoint stores how many records
+    were written, the byte offset after them and the counters so far. Re-running the same request
+    on the same (unchanged) capture truncates the output back to that offset and skips the records
+    already written instead of starting over. The checkpoint is removed once the export completes.
+    """
+
+    def __init__(self, output_path, req, counters):
+        self.output_path = output_path
+        self.path = output_path + ".checkpoint"
+        st = os.stat(req["capture_path"])
+        self.fingerprint = json.dumps(
+            {"request": req, "capture_size": st.st_size, "capture_mtime_ns": st.st_mtime_ns},
+            sort_keys=True,
+        )
+        self.counters = counters
+        self.fp = None
+        self.written = 0
+        self.skip = 0
+        self.last_event_id = None
+        self.resumed_from_event_id = None
+
+    def open(self, **kwargs):
+        """Open the output for writing, resuming from a matching checkpoint if there is one."""
+        state = None
+        try:
+            with open(self.path, "r", encoding="utf-8") as f:
+                state = json.load(f)
+        except (OSError, ValueError):
+            state = None
+        if (
+            state is not None
+            and state.get("fingerprint") == self.fingerprint
+            and os.path.exists(self.output_path)
+            and os.path.getsize(self.output_path) >= int(state["offset"])
+        ):
+            os.truncate(self.output_path, int(state["offset"]))
+            self.written = self.skip = int(state["written"])
+            self.counters.update(state["counters"])
+            self.last_event_id = self.resumed_from_event_id = state["last_event_id"]
+            self.fp = open(self.output_path, "a", **kwargs)
+        else:
+            self.fp = open(self.output_path, "w", **kwargs)
+        return self.fp
+
+    def skipping(self) -> bool:
+        """True for each record the interrupted run already wrote (call once per record)."""
+        if self.skip > 0:
+            self.skip -= 1
+            return True
+        return False
+
+    def wrote(self, event_id) -> None:
+        self.written += 1
+        self.last_event_id = int(event_id)
+        if self.written % CHECKPOINT_EVERY == 0:
+            self.fp.flush()
+            state = {
+                "fingerprint": self.fingerprint,
+                "written": self.written,
+                "offset": self.fp.buffer.tell(),
+                "last_event_id": self.last_event_id,
+                "counters": dict(self.counters),
+            }
+            tmp_path = self.path + ".tmp"
+            with open(tmp_path, "w", encoding="utf-8") as f:
+                json.dump(state, f)
+            os.replace(tmp_path, self.path)
+
+    def finish(self) -> None:
+        try:
+            os.remove(self.path)
+        except OSError:
+            pass
+
+
 FLAG_NAMES = [
     ("Clear", rd.ActionFlags.Clear),
     ("Drawcall", rd.ActionFlags.Drawcall),
@@ -60,6 +171,140 @@ def is_drawcall_like(flags: int) -> bool:
     )
 
 
+CSV_COLUMNS = [
+    "event_id",
+    "parent_event_id",
+    "depth",
+    "name",
+    "flags",
+    "marker_path",
+    "num_children",
+    "num_indices",
+    "num_instances",
+    "base_vertex",
+    "vertex_offset",
+    "index_offset",
+    "instance_offset",
+    "dispatch_x",
+    "dispatch_y",
+    "dispatch_z",
+]
+
+
+def csv_row(rec, a):
+    """Flat spreadsheet row: joined flags/markers plus draw or dispatch parameters."""
+    row = dict(rec)
+    row["flags"] = "|".join(rec["flags_names"])
+    row["marker_path"] = marker_path_join(rec["marker_path"])
+    del row["flags_names"]
+    del row["schema_version"]
+    if a.flags & rd.ActionFlags.Drawcall:
+        row["num_indices"] = int(a.numIndices)
+        row["num_instances"] = int(a.numInstances)
+        row["base_vertex"] = int(a.baseVertex)
+        row["vertex_offset"] = int(a.vertexOffset)
+        row["index_offset"] = int(a.indexOffset)
+        row["instance_offset"] = int(a.instanceOffset)
+    if a.flags & (rd.ActionFlags.Dispatch | rd.ActionFlags.MeshDispatch):
+        row["dispatch_x"], row["dispatch_y"], row["dispatch_z"] = [int(d) for d in a.dispatchDimension]
+    return row
+
+
+QUERY_COMPARE = {
+    ">": lambda a, b: a > b,
+    ">=": lambda a, b: a >= b,
+    "<": lambda a, b: a < b,
+    "<=": lambda a, b: a <= b,
+    "==": lambda a, b: a == b,
+    "!=": lambda a, b: a != b,
+}
+
+
+def query_number(a, field: str, depth: int) -> int:
+    if field == "eid":
+        return int(a.eventId)
+    if field == "depth":
+        return int(depth)
+    if field == "children":
+        return len(a.children)
+    if not (a.flags & rd.ActionFlags.Drawcall):
+        return 0
+    instances = max(int(a.numInstances), 1)
+    if field == "indices":
+        return int(a.numIndices)
+    if field == "instances":
+        return instances
+    if field == "triangles":
+        return int(a.numIndices) * instances // 3
+    raise RuntimeError("unknown query field: " + str(field))
+
+
+def query_matches(node, a, name_str: str, marker_path, depth: int) -> bool:
+    """Evaluate a `query_ast` node (parsed from `query` by the Rust EventQuery parser)."""
+    op = node["op"]
+    if op == "and":
+        return all(query_matches(n, a, name_str, marker_path, depth) for n in node["args"])
+    if op == "or":
+        return any(query_matches(n, a, name_str, marker_path, depth) for n in node["args"])
+    if op == "not":
+        return not query_matches(node["arg"], a, name_str, marker_path, depth)
+    if op == "flag":
+        return bool(int(a.flags) & int(getattr(rd.ActionFlags, node["flag"], 0)))
+    if op == "glob":
+        pattern = str(node["pattern"]).lower()
+        if node["field"] == "name":
+            return fnmatch.fnmatchcase(name_str.lower(), pattern)
+        if "/" in pattern:
+            return fnmatch.fnmatchcase("/".join(str(m) for m in marker_path).lower(), pattern)
+        return any(fnmatch.fnmatchcase(str(m).lower(), pattern) for m in marker_path)
+    if op == "compare":
+        return QUERY_COMPARE[node["cmp"]](query_number(a, node["field"], depth), int(node["value"]))
+    raise RuntimeError("unknown query op: " + str(op))
+
+
+class CallstackResolver:
+    """Symbolicated callstacks for action records (captures taken with CaptureCallstacks).
+
+    `symbol_path` is exported as `_NT_SYMBOL_PATH` before the resolver is initialized, which is
+    where dbghelp looks for PDBs on Windows. Resolved stacks are cached by address list, since
+    draws issued from the same call site share one.
+    """
+
+    def __init__(self, cap, structured_file, symbol_path):
+        if not cap.HasCallstacks():
+            raise RuntimeError(
+                "Capture has no callstacks; capture with the CaptureCallstacks option enabled"
+            )
+        if symbol_path:
+            os.environ["_NT_SYMBOL_PATH"] = str(symbol_path)
+        try:
+            result = cap.InitResolver(False, None)
+        except TypeError:
+            # Older builds take only the progress callback.
+            result = cap.InitResolver(None)
+        if result not in (None, True) and result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise callstack resolver: " + str(result))
+        self.cap = cap
+        self.structured_file = structured_file
+        self.cache = {}
+
+    def resolve(self, a):
+        chunk_index = None
+        for ev in a.events:
+            if int(ev.eventId) == int(a.eventId):
+                chunk_index = int(ev.chunkIndex)
+        if chunk_index is None or chunk_index >= len(self.structured_file.chunks):
+            return []
+        addresses = tuple(int(x) for x in self.structured_file.chunks[chunk_index].metadata.callstack)
+        if not addresses:
+            return []
+        frames = self.cache.get(addresses)
+        if frames is None:
+            frames = [str(f) for f in self.cap.GetResolve(list(addresses))]
+            self.cache[addresses] = frames
+        return frames
+
+
 def marker_path_join(marker_path) -> str:
     if not marker_path:
         return ""
@@ -73,11 +318,11 @@ def normalize(s: str, case_sensitive: bool) -> str:
     return str(s).lower()
 
 
-def iter_actions(structured_file, actions, marker_stack, parent_event_id, depth, out_fp, counters,
+def iter_actions(structured_file, actions, marker_stack, parent_event_id, depth, emit, counters, checkpoint,
                  only_drawcalls: bool, marker_prefix: str,
                  event_min, event_max,
                  name_contains: str, marker_contains: str,
-                 case_sensitive: bool):
+                 case_sensitive: bool, query):
     for a in actions:
         name = a.GetName(structured_file)
         flags = a.flags
@@ -92,18 +337,18 @@ def iter_actions(structured_file, actions, marker_stack, parent_event_id, depth,
         def recurse():
             if flags & rd.ActionFlags.PushMarker:
                 marker_stack.append(str(name))
-                iter_actions(structured_file, a.children, marker_stack, a.eventId, depth + 1, out_fp, counters,
+                iter_actions(structured_file, a.children, marker_stack, a.eventId, depth + 1, emit, counters, checkpoint,
                              only_drawcalls, marker_prefix,
                              event_min, event_max,
                              name_contains, marker_contains,
-                             case_sensitive)
+                             case_sensitive, query)
                 marker_stack.pop()
             else:
-                iter_actions(structured_file, a.children, marker_stack, a.eventId, depth + 1, out_fp, counters,
+                iter_actions(structured_file, a.children, marker_stack, a.eventId, depth + 1, emit, counters, checkpoint,
                              only_drawcalls, marker_prefix,
                              event_min, event_max,
                              name_contains, marker_contains,
-                             case_sensitive)
+                             case_sensitive, query)
 
         if marker_prefix:
             if not (joined_marker_path == marker_prefix or joined_marker_path.startswith(marker_prefix + "/")):
@@ -126,9 +371,15 @@ def iter_actions(structured_file, actions, marker_stack, parent_event_id, depth,
         if marker_contains:
             if marker_contains not in normalize(joined_marker_path, case_sensitive):
                 should_emit = False
+        if should_emit and query is not None:
+            should_emit = query_matches(query, a, name_str, effective_marker_path, depth)
+
+        if should_emit and checkpoint.skipping():
+            should_emit = False
 
         if should_emit:
             rec = {
+                "schema_version": SCHEMA_VERSION,
                 "event_id": eid,
             "parent_event_id": int(parent_event_id) if parent_event_id is not None else None,
             "depth": int(depth),
@@ -139,11 +390,12 @@ def iter_actions(structured_file, actions, marker_stack, parent_event_id, depth,
             "num_children": int(len(a.children)),
             }
 
-            out_fp.write(json.dumps(rec, ensure_ascii=False) + "\n")
+            emit(rec, a)
 
             counters["total_actions"] += 1
             if is_drawcall_like(flags):
                 counters["drawcall_actions"] += 1
+            checkpoint.wrote(eid)
 
         recurse()
 
@@ -156,7 +408,10 @@ def main() -> None:
 
     os.makedirs(req["output_dir"], exist_ok=True)
 
-    actions_path = os.path.join(req["output_dir"], f"{req['basename']}.actions.jsonl")
+    fmt = str(req.get("format") or "jsonl").lower()
+    if fmt not in ("jsonl", "csv"):
+        raise RuntimeError("format must be jsonl or csv")
+    actions_path = os.path.join(req["output_dir"], f"{req['basename']}.actions.{fmt}")
     summary_path = os.path.join(req["output_dir"], f"{req['basename']}.summary.json")
 
     cap = rd.OpenCaptureFile()
@@ -168,7 +423,7 @@ def main() -> None:
         if not cap.LocalReplaySupport():
             raise RuntimeError("Capture cannot be replayed")
 
-        result, controller = cap.OpenCapture(rd.ReplayOptions(), None)
+        result, controller = cap.OpenCapture(replay_options(req), None)
         if result != rd.ResultCode.Succeeded:
             raise RuntimeError("Couldn't initialise replay: " + str(result))
 
@@ -176,25 +431,59 @@ def main() -> None:
             structured_file = controller.GetStructuredFile()
             roots = controller.GetRootActions()
 
-            counters = {"total_actions": 0, "drawcall_actions": 0}
-            with open(actions_path, "w", encoding="utf-8") as fp:
-                iter_actions(structured_file, roots, [], None, 0, fp, counters,
+            counters = {"total_actions": 0, "drawcall_actions": 0, "callstack_actions": 0}
+            resolver = None
+            if req.get("resolve_callstacks"):
+                resolver = CallstackResolver(cap, structured_file, req.get("symbol_path"))
+            # The BOM lets spreadsheet apps detect UTF-8 marker names.
+            encoding = "utf-8-sig" if fmt == "csv" else "utf-8"
+            checkpoint = Checkpoint(actions_path, req, counters)
+            with checkpoint.open(encoding=encoding, newline="") as fp:
+                if fmt == "csv":
+                    columns = CSV_COLUMNS + (["callstack"] if resolver is not None else [])
+                    writer = csv.DictWriter(fp, fieldnames=columns)
+                    if checkpoint.resumed_from_event_id is None:
+                        writer.writeheader()
+
+                    def write(rec, a):
+                        row = csv_row(rec, a)
+                        if "callstack" in row:
+                            row["callstack"] = " | ".join(row["callstack"])
+                        writer.writerow(row)
+                else:
+
+                    def write(rec, a):
+                        fp.write(json.dumps(rec, ensure_ascii=False) + "\n")
+
+                def emit(rec, a):
+                    if resolver is not None:
+                        rec["callstack"] = resolver.resolve(a)
+                        if rec["callstack"]:
+                            counters["callstack_actions"] += 1
+                    write(rec, a)
+
+                iter_actions(structured_file, roots, [], None, 0, emit, counters, checkpoint,
                              bool(req.get("only_drawcalls", False)),
                              str(req.get("marker_prefix") or ""),
                              req.get("event_id_min", None),
                              req.get("event_id_max", None),
                              normalize(req.get("name_contains") or "", bool(req.get("case_sensitive", False))),
                              normalize(req.get("marker_contains") or "", bool(req.get("case_sensitive", False))),
-                             bool(req.get("case_sensitive", False)))
+                             bool(req.get("case_sensitive", False)),
+                             req.get("query_ast"))
+            checkpoint.finish()
 
             api = str(controller.GetAPIProperties().pipelineType)
 
             summary = {
+                "schema_version": SCHEMA_VERSION,
                 "capture_path": req["capture_path"],
                 "api": api,
                 "total_actions": int(counters["total_actions"]),
                 "drawcall_actions": int(counters["drawcall_actions"]),
-                "actions_jsonl_path": actions_path,
+                "callstack_actions": int(counters["callstack_actions"]),
+                "format": fmt,
+                "actions_path": actions_path,
             }
 
             with open(summary_path, "w", encoding="utf-8") as fp:
@@ -204,10 +493,12 @@ def main() -> None:
                 True,
                 result={
                     "capture_path": req["capture_path"],
-                    "actions_jsonl_path": actions_path,
+                    "actions_path": actions_path,
                     "summary_json_path": summary_path,
                     "total_actions": int(counters["total_actions"]),
                     "drawcall_actions": int(counters["drawcall_actions"]),
+                    "callstack_actions": int(counters["callstack_actions"]) if resolver else None,
+                    "resumed_from_event_id": checkpoint.resumed_from_event_id,
                 },
             )
             return
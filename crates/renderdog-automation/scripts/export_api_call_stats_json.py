@@ -0,0 +1,227 @@
+"""
+export_api_call_stats_json.py -- Count API calls per category, for the frame and per pass.
+
+Request:
+  - capture_path
+  - output_dir, basename
+
+Every API event in the frame is counted once, in one category:
+
+  - draws / dispatches / copies / clears: actions, classified by their ActionFlags (copies include
+    resolves and mip generation; dispatches include mesh and ray dispatches)
+  - barriers: *Barrier calls (vkCmdPipelineBarrier, ResourceBarrier, glMemoryBarrier, ...)
+  - binds: state-setting Bind* / Set* calls (pipelines, descriptors, vertex/index buffers,
+    constants, viewports, ...)
+  - markers: debug marker / label / event calls
+  - other: everything else (command buffer begin/end, submits, queries, ...)
+
+Passes are the explicit BeginPass ... EndPass ranges when the API has them, otherwise the marker
+path, the same grouping as lint_redundant_state_json.py.
+
+Writes `<basename>.api_call_stats.json`:
+  {"capture_path", "api", "total_calls", "totals": {category: n}, "calls": {chunk name: n},
+   "passes": [{"name", "first_event", "last_event", "total_calls", "totals"}]}
+"""
+
+import json
+import os
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "export_api_call_stats_json.request.json"
+RESP_PATH = "export_api_call_stats_json.response.json"
+
+CATEGORIES = ("draws", "dispatches", "copies", "clears", "barriers", "binds", "markers", "other")
+
+DISPATCH_FLAGS = rd.ActionFlags.Dispatch | rd.ActionFlags.MeshDispatch | rd.ActionFlags.DispatchRay
+COPY_FLAGS = rd.ActionFlags.Copy | rd.ActionFlags.Resolve | rd.ActionFlags.GenMips
+
+MARKER_CALLS = ("Marker", "DebugUtilsLabel", "DebugGroup", "BeginEvent", "EndEvent")
+
+# Set* calls that don't set pipeline state.
+NON_BIND_CALLS = ("SetName", "SetDebug", "SetPrivateData", "SetCheckpoint", "SetEvent")
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def new_totals():
+    return {c: 0 for c in CATEGORIES}
+
+
+def action_category(flags):
+    if flags & rd.ActionFlags.Drawcall:
+        return "draws"
+    if flags & DISPATCH_FLAGS:
+        return "dispatches"
+    if flags & COPY_FLAGS:
+        return "copies"
+    if flags & rd.ActionFlags.Clear:
+        return "clears"
+    return None
+
+
+def call_category(name):
+    short = name.split("::")[-1]
+    if "Barrier" in short:
+        return "barriers"
+    if any(x in short for x in MARKER_CALLS):
+        return "markers"
+    if any(x in short for x in NON_BIND_CALLS):
+        return "other"
+    if "Bind" in short or "Set" in short or "PushConstants" in short or "Uniform" in short:
+        return "binds"
+    return "other"
+
+
+def new_pass(name, event_id):
+    return {
+        "name": name,
+        "first_event": event_id,
+        "last_event": event_id,
+        "total_calls": 0,
+        "totals": new_totals(),
+    }
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    os.makedirs(req["output_dir"], exist_ok=True)
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            sfile = controller.GetStructuredFile()
+
+            # (event_id, chunk_index, pass label, action flags or None) in submission order.
+            calls = []
+            seen = set()
+            state = {"pass": None}
+
+            def walk(actions, markers):
+                for a in actions:
+                    label = state["pass"] or ("/".join(markers) if markers else "(no pass)")
+                    for ev in a.events:
+                        if int(ev.eventId) in seen:
+                            continue
+                        seen.add(int(ev.eventId))
+                        flags = a.flags if int(ev.eventId) == int(a.eventId) else None
+                        calls.append((int(ev.eventId), int(ev.chunkIndex), label, flags))
+                    if a.flags & rd.ActionFlags.BeginPass:
+                        state["pass"] = a.GetName(sfile)
+                    if len(a.children) > 0:
+                        walk(a.children, markers + [str(a.GetName(sfile))])
+                    if a.flags & rd.ActionFlags.EndPass:
+                        state["pass"] = None
+
+            walk(controller.GetRootActions(), [])
+            calls.sort(key=lambda c: c[0])
+
+            totals = new_totals()
+            by_name = {}
+            passes = []
+            for event_id, chunk_index, label, flags in calls:
+                name = str(sfile.chunks[chunk_index].name) if chunk_index < len(sfile.chunks) else ""
+                category = action_category(flags) if flags is not None else None
+                if category is None:
+                    category = call_category(name)
+
+                if not passes or passes[-1]["name"] != label:
+                    passes.append(new_pass(label, event_id))
+                current = passes[-1]
+                current["last_event"] = event_id
+                current["total_calls"] += 1
+                current["totals"][category] += 1
+
+                totals[category] += 1
+                if name:
+                    by_name[name] = by_name.get(name, 0) + 1
+
+            out_path = os.path.join(req["output_dir"], req["basename"] + ".api_call_stats.json")
+            with open(out_path, "w", encoding="utf-8") as f:
+                json.dump(
+                    {
+                        "capture_path": req["capture_path"],
+                        "api": str(controller.GetAPIProperties().pipelineType).split(".")[-1],
+                        "total_calls": len(calls),
+                        "totals": totals,
+                        "calls": dict(sorted(by_name.items(), key=lambda kv: (-kv[1], kv[0]))),
+                        "passes": passes,
+                    },
+                    f,
+                    ensure_ascii=False,
+                    separators=(",", ":"),
+                )
+
+            write_envelope(
+                True,
+                result={
+                    "capture_path": req["capture_path"],
+                    "api_call_stats_json_path": out_path,
+                    "total_calls": len(calls),
+                    "total_passes": len(passes),
+                    "totals": totals,
+                },
+            )
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
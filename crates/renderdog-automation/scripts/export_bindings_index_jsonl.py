@@ -8,12 +8,121 @@ import renderdoc as rd
 REQ_PATH = "export_bindings_index_jsonl.request.json"
 RESP_PATH = "export_bindings_index_jsonl.response.json"
 
+# Bump together with EXPORT_SCHEMA_VERSION in src/artifacts.rs when records or summaries change.
+SCHEMA_VERSION = 1
+
 
 def write_envelope(ok: bool, result=None, error: str = None) -> None:
     with open(RESP_PATH, "w", encoding="utf-8") as f:
         json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
 
 
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+# Records written between checkpoints (each one flushes the output).
+CHECKPOINT_EVERY = 1000
+
+
+class Checkpoint:
+    """Resume state kept in `<output>.checkpoint` while an export runs.
+
+    Every CHECKPOINT_EVERY records the output is flushed and the checkpoint stores how many records
+    were written, the byte offset after them and the counters so far. Re-running the same request
+    on the same (unchanged) capture truncates the output back to that offset and skips the records
+    already written instead of starting over. The checkpoint is removed once the export completes.
+    """
+
+    def __init__(self, output_path, req, counters):
+        self.output_path = output_path
+        self.path = output_path + ".checkpoint"
+        st = os.stat(req["capture_path"])
+        self.fingerprint = json.dumps(
+            {"request": req, "capture_size": st.st_size, "capture_mtime_ns": st.st_mtime_ns},
+            sort_keys=True,
+        )
+        self.counters = counters
+        self.fp = None
+        self.written = 0
+        self.skip = 0
+        self.last_event_id = None
+        self.resumed_from_event_id = None
+
+    def open(self, **kwargs):
+        """Open the output for writing, resuming from a matching checkpoint if there is one."""
+        state = None
+        try:
+            with open(self.path, "r", encoding="utf-8") as f:
+                state = json.load(f)
+        except (OSError, ValueError):
+            state = None
+        if (
+            state is not None
+            and state.get("fingerprint") == self.fingerprint
+            and os.path.exists(self.output_path)
+            and os.path.getsize(self.output_path) >= int(state["offset"])
+        ):
+            os.truncate(self.output_path, int(state["offset"]))
+            self.written = self.skip = int(state["written"])
+            self.counters.update(state["counters"])
+            self.last_event_id = self.resumed_from_event_id = state["last_event_id"]
+            self.fp = open(self.output_path, "a", **kwargs)
+        else:
+            self.fp = open(self.output_path, "w", **kwargs)
+        return self.fp
+
+    def skipping(self) -> bool:
+        """True for each record the interrupted run already wrote (call once per record)."""
+        if self.skip > 0:
+            self.skip -= 1
+            return True
+        return False
+
+    def wrote(self, event_id) -> None:
+        self.written += 1
+        self.last_event_id = int(event_id)
+        if self.written % CHECKPOINT_EVERY == 0:
+            self.fp.flush()
+            state = {
+                "fingerprint": self.fingerprint,
+                "written": self.written,
+                "offset": self.fp.buffer.tell(),
+                "last_event_id": self.last_event_id,
+                "counters": dict(self.counters),
+            }
+            tmp_path = self.path + ".tmp"
+            with open(tmp_path, "w", encoding="utf-8") as f:
+                json.dump(state, f)
+            os.replace(tmp_path, self.path)
+
+    def finish(self) -> None:
+        try:
+            os.remove(self.path)
+        except OSError:
+            pass
+
+
 def normalize(s: str, case_sensitive: bool) -> str:
     if s is None:
         return ""
@@ -191,7 +300,7 @@ def serialize_outputs(controller, pipe):
 
 
 def iter_actions(structured_file, controller, actions, marker_stack, depth,
-                 out_fp, counters,
+                 out_fp, counters, checkpoint,
                  marker_prefix: str,
                  event_min, event_max,
                  name_contains: str, marker_contains: str,
@@ -213,7 +322,7 @@ def iter_actions(structured_file, controller, actions, marker_stack, depth,
             if flags & rd.ActionFlags.PushMarker:
                 marker_stack.append(str(name))
                 iter_actions(structured_file, controller, a.children, marker_stack, depth + 1,
-                             out_fp, counters,
+                             out_fp, counters, checkpoint,
                              marker_prefix,
                              event_min, event_max,
                              name_contains, marker_contains,
@@ -222,7 +331,7 @@ def iter_actions(structured_file, controller, actions, marker_stack, depth,
                 marker_stack.pop()
             else:
                 iter_actions(structured_file, controller, a.children, marker_stack, depth + 1,
-                             out_fp, counters,
+                             out_fp, counters, checkpoint,
                              marker_prefix,
                              event_min, event_max,
                              name_contains, marker_contains,
@@ -249,6 +358,9 @@ def iter_actions(structured_file, controller, actions, marker_stack, depth,
             if marker_contains not in normalize(joined_marker_path, case_sensitive):
                 should_emit = False
 
+        if should_emit and checkpoint.skipping():
+            should_emit = False
+
         if should_emit:
             controller.SetFrameEvent(eid, False)
             pipe = controller.GetPipelineState()
@@ -297,6 +409,7 @@ def iter_actions(structured_file, controller, actions, marker_stack, depth,
                         resource_names.append(cb.get("resource_name"))
 
             rec = {
+                "schema_version": SCHEMA_VERSION,
                 "event_id": eid,
                 "depth": int(depth),
                 "name": name_str,
@@ -312,6 +425,7 @@ def iter_actions(structured_file, controller, actions, marker_stack, depth,
 
             out_fp.write(json.dumps(rec, ensure_ascii=False) + "\n")
             counters["total_drawcalls"] += 1
+            checkpoint.wrote(eid)
 
         recurse()
 
@@ -336,7 +450,7 @@ def main() -> None:
         if not cap.LocalReplaySupport():
             raise RuntimeError("Capture cannot be replayed")
 
-        result, controller = cap.OpenCapture(rd.ReplayOptions(), None)
+        result, controller = cap.OpenCapture(replay_options(req), None)
         if result != rd.ResultCode.Succeeded:
             raise RuntimeError("Couldn't initialise replay: " + str(result))
 
@@ -345,7 +459,8 @@ def main() -> None:
             roots = controller.GetRootActions()
 
             counters = {"total_drawcalls": 0}
-            with open(bindings_path, "w", encoding="utf-8") as fp:
+            checkpoint = Checkpoint(bindings_path, req, counters)
+            with checkpoint.open(encoding="utf-8") as fp:
                 iter_actions(
                     structured_file,
                     controller,
@@ -354,6 +469,7 @@ def main() -> None:
                     0,
                     fp,
                     counters,
+                    checkpoint,
                     str(req.get("marker_prefix") or ""),
                     req.get("event_id_min", None),
                     req.get("event_id_max", None),
@@ -363,10 +479,12 @@ def main() -> None:
                     bool(req.get("include_cbuffers", False)),
                     bool(req.get("include_outputs", False)),
                 )
+            checkpoint.finish()
 
             api = str(controller.GetAPIProperties().pipelineType)
 
             summary = {
+                "schema_version": SCHEMA_VERSION,
                 "capture_path": req["capture_path"],
                 "api": api,
                 "total_drawcalls": int(counters["total_drawcalls"]),
@@ -383,6 +501,7 @@ def main() -> None:
                     "bindings_jsonl_path": bindings_path,
                     "summary_json_path": summary_path,
                     "total_drawcalls": int(counters["total_drawcalls"]),
+                    "resumed_from_event_id": checkpoint.resumed_from_event_id,
                 },
             )
             return
@@ -0,0 +1,167 @@
+"""
+export_chrome_trace_json.py -- Export a frame as Chrome trace event JSON (Perfetto / about:tracing).
+
+Request:
+  - capture_path
+  - output_path
+
+Every action becomes a complete ("X") slice on a single GPU track. Marker regions nest their
+children, and leaf events are laid out back to back using their EventGPUDuration, so a marker's
+slice length is the GPU time of everything inside it.
+"""
+
+import json
+import os
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "export_chrome_trace_json.request.json"
+RESP_PATH = "export_chrome_trace_json.response.json"
+
+PID = 1
+TID = 1
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def fetch_gpu_durations(controller):
+    """event_id -> seconds, or None if the driver has no EventGPUDuration counter."""
+    if rd.GPUCounter.EventGPUDuration not in controller.EnumerateCounters():
+        return None
+    desc = controller.DescribeCounter(rd.GPUCounter.EventGPUDuration)
+    durations = {}
+    for r in controller.FetchCounters([rd.GPUCounter.EventGPUDuration]):
+        value = r.value.d if desc.resultByteWidth == 8 else r.value.f
+        durations[int(r.eventId)] = max(float(value), 0.0)
+    return durations
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            sfile = controller.GetStructuredFile()
+            durations = fetch_gpu_durations(controller)
+            events = []
+            cursor = [0.0]
+
+            def emit(action, depth):
+                if action.flags & rd.ActionFlags.PopMarker:
+                    return
+                start = cursor[0]
+                if len(action.children) > 0:
+                    for child in action.children:
+                        emit(child, depth + 1)
+                elif durations is not None:
+                    cursor[0] += durations.get(int(action.eventId), 0.0) * 1e6
+                events.append(
+                    {
+                        "name": action.GetName(sfile),
+                        "cat": "marker" if len(action.children) > 0 else "event",
+                        "ph": "X",
+                        "ts": start,
+                        "dur": cursor[0] - start,
+                        "pid": PID,
+                        "tid": TID,
+                        "args": {
+                            "event_id": int(action.eventId),
+                            "action_id": int(action.actionId),
+                            "flags": str(action.flags),
+                            "depth": depth,
+                        },
+                    }
+                )
+
+            for action in controller.GetRootActions():
+                emit(action, 0)
+
+            # Parents are appended after their children; sort by start (then longest first) so
+            # viewers nest slices regardless of input order.
+            events.sort(key=lambda e: (e["ts"], -e["dur"]))
+            slice_count = len(events)
+            events[:0] = [
+                {"name": "process_name", "ph": "M", "pid": PID, "args": {"name": os.path.basename(req["capture_path"])}},
+                {"name": "thread_name", "ph": "M", "pid": PID, "tid": TID, "args": {"name": "GPU"}},
+            ]
+
+            out_path = req["output_path"]
+            parent = os.path.dirname(out_path)
+            if parent:
+                os.makedirs(parent, exist_ok=True)
+            with open(out_path, "w", encoding="utf-8") as f:
+                json.dump({"traceEvents": events, "displayTimeUnit": "ns"}, f, ensure_ascii=False)
+
+            write_envelope(
+                True,
+                result={
+                    "capture_path": req["capture_path"],
+                    "output_path": out_path,
+                    "slice_count": slice_count,
+                    "total_gpu_duration_us": cursor[0],
+                    "has_gpu_durations": durations is not None,
+                },
+            )
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
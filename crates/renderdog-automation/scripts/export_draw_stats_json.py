@@ -0,0 +1,250 @@
+"""
+export_draw_stats_json.py -- Per-draw statistics plus per-marker-group totals.
+
+Request:
+  - capture_path
+  - output_dir, basename
+
+Writes `<basename>.draw_stats.jsonl` (one row per draw / dispatch) and
+`<basename>.draw_stats_summary.json` (frame totals and one aggregate per marker path; a group's
+totals include every nested group). Primitive counts are derived from the index/vertex count and
+the bound topology; triangle counts only include triangle topologies and are multiplied by the
+instance count.
+"""
+
+import json
+import os
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "export_draw_stats_json.request.json"
+RESP_PATH = "export_draw_stats_json.response.json"
+
+DISPATCH_FLAGS = rd.ActionFlags.Dispatch | rd.ActionFlags.MeshDispatch | rd.ActionFlags.DispatchRay
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def primitive_counts(topology, n):
+    """(primitives, triangles) for one instance of `n` vertices/indices."""
+    name = str(topology).split(".")[-1]
+    if name == "PointList":
+        return n, 0
+    if name == "LineList":
+        return n // 2, 0
+    if name == "LineStrip":
+        return max(n - 1, 0), 0
+    if name == "LineLoop":
+        return n if n > 2 else max(n - 1, 0), 0
+    if name == "LineList_Adj":
+        return n // 4, 0
+    if name == "LineStrip_Adj":
+        return max(n - 3, 0), 0
+    if name == "TriangleList":
+        return n // 3, n // 3
+    if name in ("TriangleStrip", "TriangleFan"):
+        return max(n - 2, 0), max(n - 2, 0)
+    if name == "TriangleList_Adj":
+        return n // 6, n // 6
+    if name == "TriangleStrip_Adj":
+        return max(n // 2 - 2, 0), max(n // 2 - 2, 0)
+    if name.startswith("PatchList_"):
+        try:
+            cp = int(name.split("_")[1].split("CPs")[0])
+        except ValueError:
+            return None, None
+        return n // cp, None
+    return None, None
+
+
+def target_resolution(action, textures):
+    rids = [o for o in action.outputs if o != rd.ResourceId.Null()]
+    if action.depthOut != rd.ResourceId.Null():
+        rids.append(action.depthOut)
+    for rid in rids:
+        tex = textures.get(int(rid))
+        if tex is not None:
+            return [int(tex.width), int(tex.height)], len(rids)
+    return None, len(rids)
+
+
+def new_group():
+    return {
+        "draws": 0,
+        "dispatches": 0,
+        "instances": 0,
+        "vertices": 0,
+        "primitives": 0,
+        "triangles": 0,
+        "dispatch_groups": 0,
+        "first_event": None,
+        "last_event": None,
+    }
+
+
+def accumulate(group, row):
+    if row["kind"] == "draw":
+        group["draws"] += 1
+        group["instances"] += row["instances"]
+        group["vertices"] += row["vertices"] * row["instances"]
+        group["primitives"] += row["primitives"] or 0
+        group["triangles"] += row["triangles"] or 0
+    else:
+        group["dispatches"] += 1
+        x, y, z = row["dispatch_dimension"]
+        group["dispatch_groups"] += x * y * z
+    if group["first_event"] is None:
+        group["first_event"] = row["event_id"]
+    group["last_event"] = row["event_id"]
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    os.makedirs(req["output_dir"], exist_ok=True)
+    base = os.path.join(req["output_dir"], req["basename"])
+    draws_path = base + ".draw_stats.jsonl"
+    summary_path = base + ".draw_stats_summary.json"
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            sfile = controller.GetStructuredFile()
+            textures = {int(t.resourceId): t for t in controller.GetTextures()}
+
+            work = []
+
+            def walk(actions, markers):
+                for a in actions:
+                    if len(a.children) > 0:
+                        walk(a.children, markers + [str(a.GetName(sfile))])
+                    elif a.flags & (rd.ActionFlags.Drawcall | DISPATCH_FLAGS):
+                        work.append((a, markers))
+
+            walk(controller.GetRootActions(), [])
+
+            frame = new_group()
+            groups = {}
+            with open(draws_path, "w", encoding="utf-8") as out:
+                for action, markers in work:
+                    resolution, target_count = target_resolution(action, textures)
+                    row = {
+                        "event_id": int(action.eventId),
+                        "name": action.GetName(sfile),
+                        "marker_path": "/".join(markers),
+                        "target_resolution": resolution,
+                        "target_count": target_count,
+                    }
+                    if action.flags & DISPATCH_FLAGS and not action.flags & rd.ActionFlags.Drawcall:
+                        row["kind"] = "dispatch"
+                        row["dispatch_dimension"] = [int(d) for d in action.dispatchDimension]
+                    else:
+                        controller.SetFrameEvent(int(action.eventId), False)
+                        topology = controller.GetPipelineState().GetPrimitiveTopology()
+                        instances = max(int(action.numInstances), 1)
+                        n = int(action.numIndices)
+                        primitives, triangles = primitive_counts(topology, n)
+                        row["kind"] = "draw"
+                        row["indexed"] = bool(action.flags & rd.ActionFlags.Indexed)
+                        row["vertices"] = n
+                        row["instances"] = instances
+                        row["topology"] = str(topology).split(".")[-1]
+                        row["primitives"] = primitives * instances if primitives is not None else None
+                        row["triangles"] = triangles * instances if triangles is not None else None
+                    out.write(json.dumps(row, ensure_ascii=False) + "\n")
+
+                    accumulate(frame, row)
+                    for depth in range(1, len(markers) + 1):
+                        path = "/".join(markers[:depth])
+                        if path not in groups:
+                            groups[path] = new_group()
+                            groups[path]["marker_path"] = path
+                            groups[path]["depth"] = depth
+                        accumulate(groups[path], row)
+
+            with open(summary_path, "w", encoding="utf-8") as f:
+                json.dump(
+                    {
+                        "capture_path": req["capture_path"],
+                        "frame": frame,
+                        "marker_groups": sorted(groups.values(), key=lambda g: g["first_event"]),
+                    },
+                    f,
+                    ensure_ascii=False,
+                    indent=2,
+                )
+
+            write_envelope(
+                True,
+                result={
+                    "capture_path": req["capture_path"],
+                    "draws_jsonl_path": draws_path,
+                    "summary_json_path": summary_path,
+                    "total_draws": frame["draws"],
+                    "total_dispatches": frame["dispatches"],
+                    "total_instances": frame["instances"],
+                    "total_triangles": frame["triangles"],
+                },
+            )
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
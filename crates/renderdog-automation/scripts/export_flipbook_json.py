@@ -0,0 +1,216 @@
+"""
+export_flipbook_json.py -- Save RT0 (and optionally depth) after every matching draw.
+
+Request:
+  - capture_path
+  - output_dir, basename
+  - marker_prefix, marker_contains, name_contains, case_sensitive, event_id_min, event_id_max:
+    filters, as in find_events
+  - include_depth
+  - max_frames: stop after this many draws
+
+Replays the frame draw by draw and writes `<basename>.<NNNN>.event<EID>.rt0.png` (plus
+`.depth.png`) for each, then `<basename>.flipbook.json` listing the frames in order, so the
+construction of the frame can be reviewed as an image sequence.
+"""
+
+import json
+import os
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "export_flipbook_json.request.json"
+RESP_PATH = "export_flipbook_json.response.json"
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def bound_resource_id(br):
+    rid = getattr(br, "resourceId", None)
+    if rid is None:
+        rid = getattr(br, "resource", None)
+    return rid
+
+
+def collect_draws(sfile, actions, markers, out):
+    """(action, marker path) for every draw, in event order."""
+    for a in actions:
+        if a.flags & rd.ActionFlags.Drawcall:
+            out.append((a, list(markers)))
+        if len(a.children) > 0:
+            pushed = bool(a.flags & rd.ActionFlags.PushMarker)
+            if pushed:
+                markers.append(str(a.GetName(sfile)))
+            collect_draws(sfile, a.children, markers, out)
+            if pushed:
+                markers.pop()
+
+
+def save_png(controller, br, out_path):
+    save = rd.TextureSave()
+    save.resourceId = bound_resource_id(br)
+    save.destType = rd.FileType.PNG
+    save.mip = int(getattr(br, "firstMip", 0))
+    save.slice.sliceIndex = int(getattr(br, "firstSlice", 0))
+    result = controller.SaveTexture(save, out_path)
+    if result != rd.ResultCode.Succeeded:
+        raise RuntimeError("SaveTexture failed: " + str(result))
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    case_sensitive = bool(req.get("case_sensitive", False))
+
+    def norm(s):
+        return str(s or "") if case_sensitive else str(s or "").lower()
+
+    marker_prefix = req.get("marker_prefix") or ""
+    marker_contains = norm(req.get("marker_contains"))
+    name_contains = norm(req.get("name_contains"))
+    event_min = req.get("event_id_min")
+    event_max = req.get("event_id_max")
+    include_depth = bool(req.get("include_depth", False))
+    max_frames = int(req.get("max_frames", 500))
+
+    output_dir = req["output_dir"]
+    basename = req["basename"]
+    os.makedirs(output_dir, exist_ok=True)
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            sfile = controller.GetStructuredFile()
+            draws = []
+            collect_draws(sfile, controller.GetRootActions(), [], draws)
+
+            matches = []
+            for action, markers in draws:
+                eid = int(action.eventId)
+                joined = "/".join(markers)
+                if marker_prefix and not (joined == marker_prefix or joined.startswith(marker_prefix + "/")):
+                    continue
+                if event_min is not None and eid < int(event_min):
+                    continue
+                if event_max is not None and eid > int(event_max):
+                    continue
+                if name_contains and name_contains not in norm(action.GetName(sfile)):
+                    continue
+                if marker_contains and marker_contains not in norm(joined):
+                    continue
+                matches.append((action, joined))
+
+            frames = []
+            for action, joined in matches[:max_frames]:
+                eid = int(action.eventId)
+                controller.SetFrameEvent(eid, True)
+                pipe = controller.GetPipelineState()
+                stem = os.path.join(output_dir, "%s.%04d.event%d" % (basename, len(frames), eid))
+
+                frame = {
+                    "index": len(frames),
+                    "event_id": eid,
+                    "name": str(action.GetName(sfile)),
+                    "marker_path": joined,
+                    "rt0_resource_id": None,
+                    "rt0_path": None,
+                    "depth_path": None,
+                }
+                targets = pipe.GetOutputTargets()
+                if len(targets) > 0:
+                    rid = bound_resource_id(targets[0])
+                    if rid is not None and rid != rd.ResourceId.Null():
+                        frame["rt0_resource_id"] = int(rid)
+                        frame["rt0_path"] = stem + ".rt0.png"
+                        save_png(controller, targets[0], frame["rt0_path"])
+                if include_depth:
+                    depth = pipe.GetDepthTarget()
+                    rid = bound_resource_id(depth)
+                    if rid is not None and rid != rd.ResourceId.Null():
+                        frame["depth_path"] = stem + ".depth.png"
+                        save_png(controller, depth, frame["depth_path"])
+                frames.append(frame)
+
+            index_path = os.path.join(output_dir, basename + ".flipbook.json")
+            with open(index_path, "w", encoding="utf-8") as f:
+                json.dump(
+                    {"capture_path": req["capture_path"], "frames": frames},
+                    f,
+                    ensure_ascii=False,
+                    indent=2,
+                )
+
+            write_envelope(
+                True,
+                result={
+                    "capture_path": req["capture_path"],
+                    "index_json_path": index_path,
+                    "total_matches": len(matches),
+                    "frame_count": len(frames),
+                    "truncated": len(matches) > len(frames),
+                    "first_event_id": frames[0]["event_id"] if frames else None,
+                    "last_event_id": frames[-1]["event_id"] if frames else None,
+                },
+            )
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
@@ -0,0 +1,285 @@
+"""
+export_frame_graph_json.py -- Export a pass / resource dependency graph of a frame (DOT + JSON).
+
+Request:
+  - capture_path
+  - output_dir, basename
+
+Passes are runs of consecutive work that share a kind and target set: draws (and clears) writing
+the same colour/depth targets, or dispatches / transfers under the same marker. Resource usages
+(GetUsage) are classified as reads or writes; every read gets an edge from the last pass that
+wrote the resource before it.
+"""
+
+import json
+import os
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "export_frame_graph_json.request.json"
+RESP_PATH = "export_frame_graph_json.response.json"
+
+WRITE_USAGES = {
+    "ColorTarget",
+    "DepthStencilTarget",
+    "StreamOut",
+    "Clear",
+    "Discard",
+    "CopyDst",
+    "ResolveDst",
+    "GenMips",
+    "CPUWrite",
+    "VS_RWResource",
+    "HS_RWResource",
+    "DS_RWResource",
+    "GS_RWResource",
+    "PS_RWResource",
+    "CS_RWResource",
+    "TS_RWResource",
+    "MS_RWResource",
+    "All_RWResource",
+}
+
+IGNORED_USAGES = {"Unused", "Barrier"}
+
+WORK_FLAGS = (
+    rd.ActionFlags.Drawcall
+    | rd.ActionFlags.Dispatch
+    | rd.ActionFlags.Clear
+    | rd.ActionFlags.Copy
+    | rd.ActionFlags.Resolve
+    | rd.ActionFlags.GenMips
+)
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def enum_name(value) -> str:
+    return str(value).split(".")[-1]
+
+
+def work_actions(controller, sfile):
+    """(action, marker_path) for every work action, in submission order."""
+    out = []
+
+    def walk(actions, markers):
+        for a in actions:
+            if len(a.children) > 0:
+                walk(a.children, markers + [a.GetName(sfile)])
+            elif a.flags & WORK_FLAGS:
+                out.append((a, "/".join(markers)))
+
+    walk(controller.GetRootActions(), [])
+    return out
+
+
+def pass_key(action, marker):
+    if action.flags & (rd.ActionFlags.Drawcall | rd.ActionFlags.Clear):
+        outputs = tuple(int(o) for o in action.outputs if o != rd.ResourceId.Null())
+        depth = int(action.depthOut)
+        if outputs or depth:
+            return ("graphics", outputs, depth)
+        return ("graphics", marker)
+    if action.flags & rd.ActionFlags.Dispatch:
+        return ("compute", marker)
+    return ("transfer", marker)
+
+
+def dot_escape(s: str) -> str:
+    return s.replace("\\", "\\\\").replace('"', '\\"')
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    os.makedirs(req["output_dir"], exist_ok=True)
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            sfile = controller.GetStructuredFile()
+
+            passes = []
+            pass_of_event = {}
+            prev_key = None
+            for action, marker in work_actions(controller, sfile):
+                key = pass_key(action, marker)
+                if key != prev_key or not passes:
+                    kind = key[0]
+                    name = marker.split("/")[-1] if marker else action.GetName(sfile)
+                    passes.append(
+                        {
+                            "id": len(passes),
+                            "name": name,
+                            "kind": kind,
+                            "marker_path": marker,
+                            "first_event": int(action.eventId),
+                            "last_event": int(action.eventId),
+                            "reads": [],
+                            "writes": [],
+                        }
+                    )
+                    prev_key = key
+                current = passes[-1]
+                current["last_event"] = int(action.eventId)
+                pass_of_event[int(action.eventId)] = current["id"]
+
+            def pass_for(event_id):
+                """Pass containing event_id, or the next pass (usages on markers/barriers)."""
+                if event_id in pass_of_event:
+                    return pass_of_event[event_id]
+                for p in passes:
+                    if p["first_event"] >= event_id:
+                        return p["id"]
+                return None
+
+            resource_ids = {int(r.resourceId): r.resourceId for r in controller.GetResources()}
+            names = {int(r.resourceId): str(r.name) for r in controller.GetResources()}
+            kinds = {}
+            for t in controller.GetTextures():
+                kinds[int(t.resourceId)] = "texture"
+            for b in controller.GetBuffers():
+                kinds[int(b.resourceId)] = "buffer"
+
+            edges = {}
+            resources = []
+            for rid_int, kind in sorted(kinds.items()):
+                rid = resource_ids.get(rid_int)
+                if rid is None:
+                    continue
+                usages = sorted(controller.GetUsage(rid), key=lambda u: u.eventId)
+                last_writer = None
+                touched = False
+                for u in usages:
+                    usage = enum_name(u.usage)
+                    if usage in IGNORED_USAGES:
+                        continue
+                    pid = pass_for(int(u.eventId))
+                    if pid is None:
+                        continue
+                    touched = True
+                    is_write = usage in WRITE_USAGES
+                    lst = passes[pid]["writes" if is_write else "reads"]
+                    if rid_int not in lst:
+                        lst.append(rid_int)
+                    if is_write:
+                        last_writer = pid
+                    elif last_writer is not None and last_writer != pid:
+                        key = (last_writer, pid, rid_int)
+                        edges.setdefault(key, set()).add(usage)
+                if touched:
+                    resources.append({"id": rid_int, "name": names.get(rid_int, str(rid_int)), "kind": kind})
+
+            edge_list = [
+                {
+                    "from": a,
+                    "to": b,
+                    "resource_id": rid_int,
+                    "resource_name": names.get(rid_int, str(rid_int)),
+                    "usages": sorted(us),
+                }
+                for (a, b, rid_int), us in sorted(edges.items())
+            ]
+
+            base = os.path.join(req["output_dir"], req["basename"])
+            json_path = base + ".framegraph.json"
+            dot_path = base + ".framegraph.dot"
+
+            with open(json_path, "w", encoding="utf-8") as f:
+                json.dump(
+                    {
+                        "capture_path": req["capture_path"],
+                        "passes": passes,
+                        "resources": resources,
+                        "edges": edge_list,
+                    },
+                    f,
+                    ensure_ascii=False,
+                    indent=2,
+                )
+
+            shapes = {"graphics": "box", "compute": "hexagon", "transfer": "ellipse"}
+            with open(dot_path, "w", encoding="utf-8") as f:
+                f.write("digraph frame {\n  rankdir=LR;\n  node [fontname=\"Helvetica\"];\n")
+                for p in passes:
+                    f.write(
+                        '  p%d [label="%s\\nEID %d-%d", shape=%s];\n'
+                        % (p["id"], dot_escape(p["name"]), p["first_event"], p["last_event"], shapes.get(p["kind"], "box"))
+                    )
+                for e in edge_list:
+                    f.write('  p%d -> p%d [label="%s"];\n' % (e["from"], e["to"], dot_escape(e["resource_name"])))
+                f.write("}\n")
+
+            write_envelope(
+                True,
+                result={
+                    "capture_path": req["capture_path"],
+                    "dot_path": dot_path,
+                    "json_path": json_path,
+                    "pass_count": len(passes),
+                    "resource_count": len(resources),
+                    "edge_count": len(edge_list),
+                },
+            )
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
@@ -0,0 +1,435 @@
+"""
+export_render_passes_json.py -- Export render pass / framebuffer structure per pass.
+
+Request:
+  - capture_path
+  - output_dir, basename
+
+Explicit passes (actions flagged BeginPass ... EndPass: Vulkan render passes and dynamic rendering,
+D3D12 BeginRenderPass) report attachments, load/store ops, subpasses and resolve targets from the
+pipeline state and the structured data of the creating / beginning call. APIs without explicit
+passes (GL, D3D11) get passes inferred from runs of draws sharing the same targets; those carry
+attachments only (load/store ops are null).
+"""
+
+import json
+import os
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "export_render_passes_json.request.json"
+RESP_PATH = "export_render_passes_json.response.json"
+
+WORK_FLAGS = rd.ActionFlags.Drawcall | rd.ActionFlags.Dispatch | rd.ActionFlags.Clear
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def flatten_actions(actions):
+    out = []
+    for a in actions:
+        out.append(a)
+        out.extend(flatten_actions(a.children))
+    return out
+
+
+def sd_to_py(obj, depth=0):
+    """Plain Python value for an SDObject (structs -> dict, arrays -> list, resources -> int)."""
+    if depth > 16:
+        return None
+    bt = obj.type.basetype
+    if bt in (rd.SDBasic.Chunk, rd.SDBasic.Struct):
+        return {obj.GetChild(i).name: sd_to_py(obj.GetChild(i), depth + 1) for i in range(obj.NumChildren())}
+    if bt == rd.SDBasic.Array:
+        return [sd_to_py(obj.GetChild(i), depth + 1) for i in range(obj.NumChildren())]
+    if bt == rd.SDBasic.Enum:
+        s = obj.AsString()
+        return s if s else obj.AsInt()
+    if bt in (rd.SDBasic.String, rd.SDBasic.Character):
+        return obj.AsString()
+    if bt == rd.SDBasic.Boolean:
+        return obj.AsBool()
+    if bt == rd.SDBasic.Float:
+        return obj.AsFloat()
+    if bt in (rd.SDBasic.UnsignedInteger, rd.SDBasic.SignedInteger):
+        return obj.AsInt()
+    if bt == rd.SDBasic.Resource:
+        return int(obj.AsResourceId())
+    return None
+
+
+def find_key(value, key):
+    """First value stored under `key` anywhere in a nested dict/list, or None."""
+    if isinstance(value, dict):
+        if key in value:
+            return value[key]
+        children = value.values()
+    elif isinstance(value, list):
+        children = value
+    else:
+        return None
+    for child in children:
+        found = find_key(child, key)
+        if found is not None:
+            return found
+    return None
+
+
+def as_list(value):
+    if value is None:
+        return []
+    return value if isinstance(value, list) else [value]
+
+
+def access_type(access):
+    """D3D12 BEGINNING/ENDING_ACCESS struct -> its Type string."""
+    if isinstance(access, dict):
+        return access.get("Type")
+    return access
+
+
+def vk_ops(att):
+    if not isinstance(att, dict):
+        return {}
+    return {
+        "load_op": att.get("loadOp"),
+        "store_op": att.get("storeOp"),
+        "stencil_load_op": att.get("stencilLoadOp"),
+        "stencil_store_op": att.get("stencilStoreOp"),
+        "initial_layout": att.get("initialLayout"),
+        "final_layout": att.get("finalLayout", att.get("imageLayout")),
+    }
+
+
+def begin_chunk_ops(begin_args):
+    """(color ops list, depth ops) from a dynamic-rendering or D3D12 BeginRenderPass chunk."""
+    colors = as_list(find_key(begin_args, "pColorAttachments"))
+    if colors:
+        depth = find_key(begin_args, "pDepthAttachment")
+        stencil = find_key(begin_args, "pStencilAttachment")
+        depth_ops = vk_ops(depth) if isinstance(depth, dict) else {}
+        if isinstance(stencil, dict):
+            depth_ops["stencil_load_op"] = stencil.get("loadOp")
+            depth_ops["stencil_store_op"] = stencil.get("storeOp")
+        return [vk_ops(c) for c in colors], (depth_ops or None)
+
+    targets = as_list(find_key(begin_args, "pRenderTargets"))
+    if targets or find_key(begin_args, "pDepthStencil") is not None:
+        colors = [
+            {
+                "load_op": access_type(t.get("BeginningAccess")),
+                "store_op": access_type(t.get("EndingAccess")),
+            }
+            for t in targets
+            if isinstance(t, dict)
+        ]
+        ds = find_key(begin_args, "pDepthStencil")
+        depth_ops = None
+        if isinstance(ds, dict):
+            depth_ops = {
+                "load_op": access_type(ds.get("DepthBeginningAccess")),
+                "store_op": access_type(ds.get("DepthEndingAccess")),
+                "stencil_load_op": access_type(ds.get("StencilBeginningAccess")),
+                "stencil_store_op": access_type(ds.get("StencilEndingAccess")),
+            }
+        return colors, depth_ops
+    return [], None
+
+
+def describe_texture(textures, names, rid_int):
+    tex = textures.get(rid_int)
+    return {
+        "resource_id": rid_int or None,
+        "resource_name": names.get(rid_int, str(rid_int)) if rid_int else None,
+        "format": str(tex.format.Name()) if tex is not None else None,
+        "width": int(tex.width) if tex is not None else None,
+        "height": int(tex.height) if tex is not None else None,
+        "samples": int(tex.msSamp) if tex is not None else None,
+    }
+
+
+def renderpass_create_info(sfile, resources, rp_id):
+    """Structured create info of a VkRenderPass, or None."""
+    res = resources.get(int(rp_id))
+    if res is None:
+        return None
+    for idx in res.initialisationChunks:
+        if idx < len(sfile.chunks) and "RenderPass" in sfile.chunks[idx].name:
+            return sd_to_py(sfile.chunks[idx])
+    return None
+
+
+def vulkan_pass(controller, sfile, resources, textures, names, begin_args):
+    vk = controller.GetVulkanPipelineState()
+    if vk is None:
+        return None
+    cur = vk.currentPass
+    rp = cur.renderpass
+    fb = cur.framebuffer
+
+    # Unbound slots stay in the list so indices match the render pass attachment indices.
+    attachments = [describe_texture(textures, names, int(att.imageResourceId)) for att in fb.attachments]
+    subpasses = []
+    rp_id = int(rp.resourceId)
+    if rp_id != 0:
+        create = renderpass_create_info(sfile, resources, rp.resourceId)
+        desc = as_list(find_key(create, "pAttachments")) if create else []
+        for i, att in enumerate(attachments):
+            att["index"] = i
+            att.update(vk_ops(desc[i] if i < len(desc) else None))
+        for i, sp in enumerate(as_list(find_key(create, "pSubpasses")) if create else []):
+            subpasses.append(
+                {
+                    "index": i,
+                    "input": [a.get("attachment") for a in as_list(sp.get("pInputAttachments"))],
+                    "color": [a.get("attachment") for a in as_list(sp.get("pColorAttachments"))],
+                    "resolve": [a.get("attachment") for a in as_list(sp.get("pResolveAttachments"))],
+                    "depth_stencil": (sp.get("pDepthStencilAttachment") or {}).get("attachment"),
+                }
+            )
+        if not subpasses:
+            subpasses.append(
+                {
+                    "index": int(rp.subpass),
+                    "input": [int(a) for a in rp.inputAttachments],
+                    "color": [int(a) for a in rp.colorAttachments],
+                    "resolve": [int(a) for a in rp.resolveAttachments],
+                    "depth_stencil": int(rp.depthstencilAttachment) if int(rp.depthstencilAttachment) >= 0 else None,
+                }
+            )
+    else:
+        # Dynamic rendering: no render pass object; ops live on the begin call.
+        colors, depth_ops = begin_chunk_ops(begin_args)
+        color_count = len(colors)
+        for i, att in enumerate(attachments):
+            att["index"] = i
+            if i < color_count:
+                att.update(colors[i])
+            elif depth_ops:
+                att.update(depth_ops)
+        subpasses.append(
+            {
+                "index": 0,
+                "input": [],
+                "color": list(range(min(color_count, len(attachments)))),
+                "resolve": [],
+                "depth_stencil": color_count if len(attachments) > color_count else None,
+            }
+        )
+
+    area = cur.renderArea
+    return {
+        "render_pass_id": rp_id or None,
+        "framebuffer_id": int(fb.resourceId) or None,
+        "width": int(fb.width),
+        "height": int(fb.height),
+        "layers": int(fb.layers),
+        "render_area": {"x": area.x, "y": area.y, "width": area.width, "height": area.height},
+        "attachments": attachments,
+        "subpasses": subpasses,
+    }
+
+
+def generic_pass(actions, textures, names, begin_args):
+    """Attachments from the draws' outputs, with ops from the begin call when it has them."""
+    colors = []
+    depth = None
+    for a in actions:
+        for i, o in enumerate(a.outputs):
+            if o != rd.ResourceId.Null():
+                while len(colors) <= i:
+                    colors.append(None)
+                colors[i] = colors[i] or int(o)
+        if a.depthOut != rd.ResourceId.Null():
+            depth = depth or int(a.depthOut)
+
+    color_ops, depth_ops = begin_chunk_ops(begin_args) if begin_args else ([], None)
+    attachments = []
+    color_slots = []
+    for i, rid in enumerate(colors):
+        if rid is None:
+            continue
+        att = describe_texture(textures, names, rid)
+        att["index"] = len(attachments)
+        att.update(color_ops[i] if i < len(color_ops) else {"load_op": None, "store_op": None})
+        color_slots.append(att["index"])
+        attachments.append(att)
+    depth_slot = None
+    if depth:
+        att = describe_texture(textures, names, depth)
+        att["index"] = depth_slot = len(attachments)
+        att.update(depth_ops or {"load_op": None, "store_op": None})
+        attachments.append(att)
+
+    first = next((attachments[i] for i in color_slots), attachments[depth_slot] if depth_slot is not None else None)
+    return {
+        "render_pass_id": None,
+        "framebuffer_id": None,
+        "width": first["width"] if first else None,
+        "height": first["height"] if first else None,
+        "layers": 1 if first else None,
+        "render_area": None,
+        "attachments": attachments,
+        "subpasses": [
+            {"index": 0, "input": [], "color": color_slots, "resolve": [], "depth_stencil": depth_slot}
+        ],
+    }
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    os.makedirs(req["output_dir"], exist_ok=True)
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            sfile = controller.GetStructuredFile()
+            api = controller.GetAPIProperties().pipelineType
+            is_vulkan = api == rd.GraphicsAPI.Vulkan
+            textures = {int(t.resourceId): t for t in controller.GetTextures()}
+            resources = {int(r.resourceId): r for r in controller.GetResources()}
+            names = {rid: str(r.name) for rid, r in resources.items()}
+            actions = flatten_actions(controller.GetRootActions())
+            explicit = any(a.flags & rd.ActionFlags.BeginPass for a in actions)
+
+            # (name, begin action or None, [work actions], end event)
+            groups = []
+            if explicit:
+                current = None
+                for a in actions:
+                    if a.flags & rd.ActionFlags.BeginPass:
+                        current = [a.GetName(sfile), a, [], int(a.eventId)]
+                    elif current is not None and a.flags & WORK_FLAGS:
+                        current[2].append(a)
+                    if current is not None and a.flags & rd.ActionFlags.EndPass:
+                        current[3] = int(a.eventId)
+                        groups.append(current)
+                        current = None
+                if current is not None:
+                    groups.append(current)
+            else:
+                prev_key = None
+                for a in actions:
+                    if not (a.flags & (rd.ActionFlags.Drawcall | rd.ActionFlags.Clear)) or a.children:
+                        continue
+                    key = (tuple(int(o) for o in a.outputs), int(a.depthOut))
+                    if key != prev_key or not groups:
+                        groups.append([a.GetName(sfile), None, [], int(a.eventId)])
+                        prev_key = key
+                    groups[-1][2].append(a)
+                    groups[-1][3] = int(a.eventId)
+
+            passes = []
+            for index, (name, begin, work, end_event) in enumerate(groups):
+                begin_args = None
+                if begin is not None and begin.events:
+                    chunk_index = begin.events[-1].chunkIndex
+                    if chunk_index < len(sfile.chunks):
+                        begin_args = sd_to_py(sfile.chunks[chunk_index])
+
+                probe = work[0] if work else begin
+                controller.SetFrameEvent(int(probe.eventId), True)
+
+                info = None
+                if is_vulkan and begin is not None:
+                    info = vulkan_pass(controller, sfile, resources, textures, names, begin_args)
+                if info is None:
+                    info = generic_pass(work, textures, names, begin_args)
+
+                entry = {
+                    "index": index,
+                    "name": name,
+                    "source": "api" if begin is not None else "inferred",
+                    "begin_event": int(begin.eventId) if begin is not None else int(work[0].eventId),
+                    "end_event": end_event,
+                    "draw_count": sum(1 for a in work if a.flags & rd.ActionFlags.Drawcall),
+                }
+                entry.update(info)
+                passes.append(entry)
+
+            out_path = os.path.join(req["output_dir"], req["basename"] + ".render_passes.json")
+            with open(out_path, "w", encoding="utf-8") as f:
+                json.dump(
+                    {
+                        "capture_path": req["capture_path"],
+                        "api": str(api).split(".")[-1],
+                        "explicit_passes": explicit,
+                        "passes": passes,
+                    },
+                    f,
+                    ensure_ascii=False,
+                    indent=2,
+                )
+
+            write_envelope(
+                True,
+                result={
+                    "capture_path": req["capture_path"],
+                    "render_passes_json_path": out_path,
+                    "total_passes": len(passes),
+                    "explicit_passes": explicit,
+                },
+            )
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
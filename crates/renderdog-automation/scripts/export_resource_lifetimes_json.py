@@ -0,0 +1,258 @@
+"""
+export_resource_lifetimes_json.py -- RenderDoc Python script that writes a lifetime timeline per resource.
+
+For every resource in the capture, records when it was created, destroyed and first/last used
+within the frame, so leaks and single-use allocations stand out:
+
+  - creation_event_id: event of the creation chunk when the resource was created inside the
+    captured frame; null for resources that existed before the capture began
+  - destruction_event_id: first in-frame Destroy/Release/Free/Delete call releasing the
+    resource (null if it outlives the frame)
+  - first_use_event_id / last_use_event_id / use_count: from the replay usage tracking
+    (GetUsage); use_count is the number of distinct events
+  - usage_counts: how often each ResourceUsage kind occurs
+  - flags: `unused`, `single_use`, `created_in_frame`, `destroyed_in_frame`, and
+    `leaked_in_frame` for resources created in the frame and never destroyed in it
+
+Request parameters:
+  - capture_path, output_dir, basename
+  - resource_types: Only these ResourceType names (e.g. ["Texture", "Buffer"]); empty = all
+
+Output:
+  - `<basename>.resource_lifetimes.json`: {"capture_path", "resources": [...]}
+"""
+
+import json
+import os
+import re
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "export_resource_lifetimes_json.request.json"
+RESP_PATH = "export_resource_lifetimes_json.response.json"
+
+DESTROY_CHUNK_RE = re.compile(r"(Destroy|Release|Free|Delete)")
+
+# Resource parameters of a destroy call sit at most this deep (e.g. inside an array).
+MAX_PARAM_DEPTH = 3
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def enum_name(value) -> str:
+    return str(value).split(".")[-1]
+
+
+def chunk_events(actions, out):
+    """Map structured chunk index -> event ID for every API event in the frame."""
+    for a in actions:
+        for ev in a.events:
+            out.setdefault(int(ev.chunkIndex), int(ev.eventId))
+        chunk_events(a.children, out)
+    return out
+
+
+def referenced_resources(obj, out, depth=0):
+    if obj.type.basetype == rd.SDBasic.Resource:
+        rid = obj.AsResourceId()
+        if rid != rd.ResourceId.Null():
+            out.add(int(rid))
+    if depth < MAX_PARAM_DEPTH:
+        for i in range(obj.NumChildren()):
+            referenced_resources(obj.GetChild(i), out, depth + 1)
+    return out
+
+
+def destroyed_by_chunk(chunk):
+    """Resources released by a destroy call: those in its last resource-typed parameter.
+
+    Earlier handles are the owning device/pool (vkDestroyImage(device, image),
+    vkFreeCommandBuffers(device, pool, count, buffers)); the released objects come last.
+    """
+    found = set()
+    for i in range(chunk.NumChildren()):
+        refs = referenced_resources(chunk.GetChild(i), set(), 1)
+        if refs:
+            found = refs
+    return found
+
+
+def destruction_events(structured_file, events_by_chunk):
+    """Resource ID -> first event whose Destroy/Release/Free/Delete chunk released it."""
+    destroyed = {}
+    for chunk_index, event_id in sorted(events_by_chunk.items(), key=lambda kv: kv[1]):
+        if chunk_index >= len(structured_file.chunks):
+            continue
+        chunk = structured_file.chunks[chunk_index]
+        if not DESTROY_CHUNK_RE.search(str(chunk.name)):
+            continue
+        for rid in destroyed_by_chunk(chunk):
+            destroyed.setdefault(rid, event_id)
+    return destroyed
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    os.makedirs(req["output_dir"], exist_ok=True)
+    wanted_types = {str(t).lower() for t in (req.get("resource_types") or [])}
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            structured_file = controller.GetStructuredFile()
+            events_by_chunk = chunk_events(controller.GetRootActions(), {})
+            destroyed = destruction_events(structured_file, events_by_chunk)
+
+            resources = []
+            totals = {
+                "unused": 0,
+                "single_use": 0,
+                "created_in_frame": 0,
+                "destroyed_in_frame": 0,
+                "leaked_in_frame": 0,
+            }
+            for res in controller.GetResources():
+                rtype = enum_name(res.type)
+                if wanted_types and rtype.lower() not in wanted_types:
+                    continue
+
+                chunks = [int(c) for c in res.initialisationChunks]
+                creation_chunk = None
+                if chunks and chunks[0] < len(structured_file.chunks):
+                    creation_chunk = str(structured_file.chunks[chunks[0]].name)
+                creation_event_id = None
+                for c in chunks:
+                    if c in events_by_chunk:
+                        creation_event_id = events_by_chunk[c]
+                        break
+
+                try:
+                    usages = sorted(controller.GetUsage(res.resourceId), key=lambda u: u.eventId)
+                except Exception:
+                    usages = []
+                usage_counts = {}
+                use_events = set()
+                for u in usages:
+                    name = enum_name(u.usage)
+                    usage_counts[name] = usage_counts.get(name, 0) + 1
+                    use_events.add(int(u.eventId))
+
+                destruction_event_id = destroyed.get(int(res.resourceId))
+
+                flags = []
+                if not use_events:
+                    flags.append("unused")
+                elif len(use_events) == 1:
+                    flags.append("single_use")
+                if creation_event_id is not None:
+                    flags.append("created_in_frame")
+                if destruction_event_id is not None:
+                    flags.append("destroyed_in_frame")
+                if creation_event_id is not None and destruction_event_id is None:
+                    flags.append("leaked_in_frame")
+                for flag in flags:
+                    totals[flag] += 1
+
+                resources.append(
+                    {
+                        "resource_id": str(res.resourceId),
+                        "name": res.name,
+                        "type": rtype,
+                        "creation_chunk": creation_chunk,
+                        "creation_event_id": creation_event_id,
+                        "destruction_event_id": destruction_event_id,
+                        "first_use_event_id": min(use_events) if use_events else None,
+                        "last_use_event_id": max(use_events) if use_events else None,
+                        "use_count": len(use_events),
+                        "usage_counts": usage_counts,
+                        "flags": flags,
+                    }
+                )
+
+            out_path = os.path.join(
+                req["output_dir"], req["basename"] + ".resource_lifetimes.json"
+            )
+            with open(out_path, "w", encoding="utf-8") as f:
+                json.dump(
+                    {"capture_path": req["capture_path"], "resources": resources},
+                    f,
+                    ensure_ascii=False,
+                    indent=2,
+                )
+
+            write_envelope(
+                True,
+                result={
+                    "capture_path": req["capture_path"],
+                    "resource_lifetimes_json_path": out_path,
+                    "total_resources": len(resources),
+                    "unused_resources": totals["unused"],
+                    "single_use_resources": totals["single_use"],
+                    "created_in_frame": totals["created_in_frame"],
+                    "destroyed_in_frame": totals["destroyed_in_frame"],
+                    "leaked_in_frame": totals["leaked_in_frame"],
+                },
+            )
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
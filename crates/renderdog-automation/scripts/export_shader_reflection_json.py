@@ -0,0 +1,266 @@
+"""
+export_shader_reflection_json.py -- RenderDoc Python script that dumps the reflection of every shader.
+
+Unlike get_shader_details_json.py (one pipeline, summarized), this reflects every entry point of
+every shader resource in the capture directly, without seeking to events, and writes everything
+RenderDoc knows about its interface:
+
+  - input_signature / output_signature: every parameter with semantic, register, system value,
+    component type/count and channel masks
+  - read_only_resources / read_write_resources: binding, array size, texture type, and the
+    variable type tree for structured buffers
+  - samplers: binding and array size
+  - constant_blocks: binding, byte size and the full member tree with byte offsets, base types,
+    rows/columns, array element counts and strides, and row/column-major layout
+  - dispatch_threads_dimension: thread group size (compute, task and mesh shaders)
+
+Request parameters:
+  - capture_path, output_dir, basename
+
+Output:
+  - `<basename>.shader_reflection.json`: {"capture_path", "shaders": [...]}
+"""
+
+import json
+import os
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "export_shader_reflection_json.request.json"
+RESP_PATH = "export_shader_reflection_json.response.json"
+
+
+_STAGE_NAMES = [
+    (rd.ShaderStage.Vertex, "Vertex"),
+    (rd.ShaderStage.Tess_Control, "TessControl"),
+    (rd.ShaderStage.Tess_Eval, "TessEval"),
+    (rd.ShaderStage.Geometry, "Geometry"),
+    (rd.ShaderStage.Task, "Task"),
+    (rd.ShaderStage.Mesh, "Mesh"),
+    (rd.ShaderStage.Fragment, "Fragment"),
+    (rd.ShaderStage.Compute, "Compute"),
+]
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def stage_name(stage) -> str:
+    for s, name in _STAGE_NAMES:
+        if s == stage:
+            return name
+    return str(stage)
+
+
+def enum_name(value) -> str:
+    return str(value).split(".")[-1]
+
+
+def iter_shader_reflections(controller):
+    """Yield (resource, entry_point, reflection) for every entry point of every shader."""
+    for res in controller.GetResources():
+        if res.type != rd.ResourceType.Shader:
+            continue
+        try:
+            entries = controller.GetShaderEntryPoints(res.resourceId)
+        except Exception:
+            continue
+        for entry in entries:
+            try:
+                refl = controller.GetShader(rd.ResourceId.Null(), res.resourceId, entry)
+            except Exception:
+                refl = None
+            if refl is not None:
+                yield res, entry, refl
+
+
+def serialize_type(t):
+    out = {
+        "name": str(t.name),
+        "base_type": enum_name(t.baseType),
+        "rows": int(t.rows),
+        "columns": int(t.columns),
+        "elements": int(t.elements),
+        "array_byte_stride": int(t.arrayByteStride),
+        "matrix_byte_stride": int(t.matrixByteStride),
+        "row_major": bool(t.flags & rd.ShaderVariableFlags.RowMajorMatrix),
+    }
+    if len(t.members) > 0:
+        out["members"] = [serialize_constant(m) for m in t.members]
+    return out
+
+
+def serialize_constant(c):
+    out = {"name": str(c.name), "byte_offset": int(c.byteOffset)}
+    if int(c.bitFieldSize) > 0:
+        out["bit_field_offset"] = int(c.bitFieldOffset)
+        out["bit_field_size"] = int(c.bitFieldSize)
+    out["type"] = serialize_type(c.type)
+    return out
+
+
+def serialize_binding(obj):
+    return {
+        "name": str(obj.name),
+        "set": int(obj.fixedBindSetOrSpace),
+        "binding": int(obj.fixedBindNumber),
+        "array_size": int(obj.bindArraySize),
+    }
+
+
+def serialize_resource(res):
+    out = serialize_binding(res)
+    out["descriptor_type"] = enum_name(res.descriptorType)
+    out["texture_type"] = enum_name(res.textureType)
+    out["is_texture"] = bool(res.isTexture)
+    out["has_sampler"] = bool(res.hasSampler)
+    if len(res.variableType.members) > 0:
+        out["variable_type"] = serialize_type(res.variableType)
+    return out
+
+
+def serialize_constant_block(cb):
+    out = serialize_binding(cb)
+    out["byte_size"] = int(cb.byteSize)
+    out["buffer_backed"] = bool(cb.bufferBacked)
+    out["compile_constants"] = bool(cb.compileConstants)
+    out["members"] = [serialize_constant(v) for v in cb.variables]
+    return out
+
+
+def serialize_signature(params):
+    return [
+        {
+            "var_name": str(p.varName),
+            "semantic": str(p.semanticName),
+            "semantic_index": int(p.semanticIndex),
+            "semantic_index_name": str(p.semanticIdxName),
+            "register": int(p.regIndex),
+            "system_value": enum_name(p.systemValue),
+            "var_type": enum_name(p.varType),
+            "components": int(p.compCount),
+            "register_channel_mask": int(p.regChannelMask),
+            "channel_used_mask": int(p.channelUsedMask),
+            "stream": int(p.stream),
+        }
+        for p in params
+    ]
+
+
+def serialize_reflection(res, entry, refl):
+    out = {
+        "resource_id": str(res.resourceId),
+        "name": res.name,
+        "stage": stage_name(entry.stage),
+        "entry_point": str(entry.name),
+        "encoding": enum_name(refl.encoding),
+        "input_signature": serialize_signature(refl.inputSignature),
+        "output_signature": serialize_signature(refl.outputSignature),
+        "read_only_resources": [serialize_resource(r) for r in refl.readOnlyResources],
+        "read_write_resources": [serialize_resource(r) for r in refl.readWriteResources],
+        "samplers": [serialize_binding(s) for s in refl.samplers],
+        "constant_blocks": [serialize_constant_block(cb) for cb in refl.constantBlocks],
+    }
+    if entry.stage in (rd.ShaderStage.Compute, rd.ShaderStage.Task, rd.ShaderStage.Mesh):
+        out["dispatch_threads_dimension"] = [int(x) for x in refl.dispatchThreadsDimension]
+    try:
+        if refl.debugInfo is not None:
+            out["source_files"] = [str(f.filename) for f in refl.debugInfo.files if f.filename]
+    except Exception:
+        pass
+    return out
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    os.makedirs(req["output_dir"], exist_ok=True)
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            shaders = [
+                serialize_reflection(res, entry, refl)
+                for res, entry, refl in iter_shader_reflections(controller)
+            ]
+
+            out_path = os.path.join(
+                req["output_dir"], req["basename"] + ".shader_reflection.json"
+            )
+            with open(out_path, "w", encoding="utf-8") as f:
+                json.dump(
+                    {"capture_path": req["capture_path"], "shaders": shaders},
+                    f,
+                    ensure_ascii=False,
+                    indent=2,
+                )
+
+            write_envelope(
+                True,
+                result={
+                    "capture_path": req["capture_path"],
+                    "shader_reflection_json_path": out_path,
+                    "total_shaders": len(shaders),
+                },
+            )
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
@@ -0,0 +1,365 @@
+"""
+export_shader_sources_json.py -- RenderDoc Python script that writes every shader's debug source to disk.
+
+Each shader entry point in the capture is reflected directly and the source files embedded in its
+debug info are written under `<output_dir>/sources/`, keeping their relative layout: absolute
+paths have the directory prefix shared by all exported files stripped (plus any drive letter).
+When two shaders embed different contents for the same path (e.g. different preprocessor
+defines), the first one keeps the canonical path and the others are written to
+`<output_dir>/variants/<shader id>/<path>`.
+
+`<output_dir>/index.json` maps every shader to the files it was compiled from and lists, per
+pipeline, the shader used by each stage (`"<Stage>/<entry point>"` keys). Pipelines are found by
+walking the draws/dispatches; APIs without pipeline objects (D3D11, GL) only get the shader list.
+
+Request parameters:
+  - capture_path: Path to the .rdc capture file (required)
+  - output_dir: Directory to write into (created if missing)
+
+Returns:
+  - output_dir, index_json_path
+  - total_shaders, shaders_without_source, files_written, variant_files
+"""
+
+import hashlib
+import json
+import os
+import posixpath
+import re
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "export_shader_sources_json.request.json"
+RESP_PATH = "export_shader_sources_json.response.json"
+
+
+_STAGE_NAMES = [
+    (rd.ShaderStage.Vertex, "Vertex"),
+    (rd.ShaderStage.Tess_Control, "TessControl"),
+    (rd.ShaderStage.Tess_Eval, "TessEval"),
+    (rd.ShaderStage.Geometry, "Geometry"),
+    (rd.ShaderStage.Task, "Task"),
+    (rd.ShaderStage.Mesh, "Mesh"),
+    (rd.ShaderStage.Fragment, "Fragment"),
+    (rd.ShaderStage.Compute, "Compute"),
+]
+
+_ENCODING_EXTENSIONS = {
+    "HLSL": ".hlsl",
+    "GLSL": ".glsl",
+    "Slang": ".slang",
+    "OpenGLSPIRVAsm": ".spvasm",
+    "SPIRVAsm": ".spvasm",
+}
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def stage_name(stage) -> str:
+    for s, name in _STAGE_NAMES:
+        if s == stage:
+            return name
+    return str(stage)
+
+
+def is_drawcall_like(flags: int) -> bool:
+    return bool(
+        (flags & rd.ActionFlags.Drawcall)
+        or (flags & rd.ActionFlags.Dispatch)
+        or (flags & rd.ActionFlags.MeshDispatch)
+        or (flags & rd.ActionFlags.DispatchRay)
+    )
+
+
+def safe_component(s: str) -> str:
+    return re.sub(r'[<>:"|?*\x00-\x1f]', "_", s).strip() or "_"
+
+
+def split_source_path(path: str):
+    """Normalize a recorded source path into (is_absolute, [components])."""
+    p = str(path).replace("\\", "/")
+    p = re.sub(r"^[A-Za-z]:", "", p)
+    absolute = p.startswith("/")
+    parts = [c for c in posixpath.normpath(p).split("/") if c not in ("", ".", "..")]
+    return absolute, [safe_component(c) for c in parts]
+
+
+def common_dir(paths):
+    """Longest shared directory prefix (as components) of the given component lists."""
+    dirs = [p[:-1] for p in paths]
+    if not dirs:
+        return []
+    prefix = dirs[0]
+    for d in dirs[1:]:
+        n = 0
+        while n < len(prefix) and n < len(d) and prefix[n] == d[n]:
+            n += 1
+        prefix = prefix[:n]
+    return prefix
+
+
+def encoding_name(refl) -> str:
+    try:
+        return str(refl.debugInfo.encoding).split(".")[-1]
+    except Exception:
+        return ""
+
+
+def iter_shader_reflections(controller):
+    """Yield (resource, entry_point, reflection) for every entry point of every shader."""
+    for res in controller.GetResources():
+        if res.type != rd.ResourceType.Shader:
+            continue
+        try:
+            entries = controller.GetShaderEntryPoints(res.resourceId)
+        except Exception:
+            continue
+        for entry in entries:
+            try:
+                refl = controller.GetShader(rd.ResourceId.Null(), res.resourceId, entry)
+            except Exception:
+                refl = None
+            if refl is not None:
+                yield res, entry, refl
+
+
+def collect_pipelines(controller, names, shaders_by_key):
+    """Map pipeline name -> {"<Stage>/<entry>": shader resource id} from the bound state."""
+    pipelines = {}
+
+    def walk(actions):
+        for a in actions:
+            if is_drawcall_like(a.flags):
+                controller.SetFrameEvent(a.eventId, False)
+                state = controller.GetPipelineState()
+                pipe = rd.ResourceId.Null()
+                try:
+                    if a.flags & rd.ActionFlags.Dispatch:
+                        pipe = state.GetComputePipelineObject()
+                    else:
+                        pipe = state.GetGraphicsPipelineObject()
+                except Exception:
+                    pass
+                pipe_name = names.get(str(pipe)) if pipe != rd.ResourceId.Null() else None
+
+                for stage, sname in _STAGE_NAMES:
+                    shader = state.GetShader(stage)
+                    if shader == rd.ResourceId.Null():
+                        continue
+                    try:
+                        entry = str(state.GetShaderEntryPoint(stage) or "")
+                    except Exception:
+                        entry = ""
+                    info = shaders_by_key.get((str(shader), entry))
+                    if info is None:
+                        continue
+                    if pipe_name is not None:
+                        if pipe_name not in info["pipelines"]:
+                            info["pipelines"].append(pipe_name)
+                        pipelines.setdefault(pipe_name, {})[sname + "/" + entry] = str(shader)
+            if len(a.children) > 0:
+                walk(a.children)
+
+    walk(controller.GetRootActions())
+    return pipelines
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    output_dir = req["output_dir"]
+    os.makedirs(output_dir, exist_ok=True)
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            names = {str(r.resourceId): r.name for r in controller.GetResources()}
+
+            # First pass: gather every (shader, entry point) and its embedded files.
+            shaders = []
+            for res, entry, refl in iter_shader_reflections(controller):
+                encoding = encoding_name(refl)
+                files = []
+                try:
+                    debug_files = list(refl.debugInfo.files) if refl.debugInfo else []
+                except Exception:
+                    debug_files = []
+                for i, f in enumerate(debug_files):
+                    contents = str(f.contents or "")
+                    if not contents:
+                        continue
+                    recorded = str(f.filename or "")
+                    if recorded:
+                        absolute, parts = split_source_path(recorded)
+                    else:
+                        absolute = False
+                        ext = _ENCODING_EXTENSIONS.get(encoding, ".txt")
+                        parts = [
+                            "unnamed",
+                            safe_component("%s_%s_%d%s" % (res.name, entry.name, i, ext)),
+                        ]
+                    if not parts:
+                        continue
+                    files.append(
+                        {
+                            "recorded_path": recorded,
+                            "absolute": absolute,
+                            "parts": parts,
+                            "contents": contents,
+                        }
+                    )
+
+                shaders.append(
+                    {
+                        "resource_id": str(res.resourceId),
+                        "name": res.name,
+                        "stage": stage_name(entry.stage),
+                        "entry_point": str(entry.name),
+                        "encoding": encoding,
+                        "files": files,
+                        "pipelines": [],
+                    }
+                )
+
+            # Second pass: strip the shared prefix of absolute paths and write the files.
+            prefix = common_dir(
+                [f["parts"] for s in shaders for f in s["files"] if f["absolute"]]
+            )
+            written = {}
+            files_written = 0
+            variant_files = 0
+            for s in shaders:
+                for f in s["files"]:
+                    parts = f["parts"]
+                    if f["absolute"] and parts[: len(prefix)] == prefix:
+                        parts = parts[len(prefix):]
+                    rel = "/".join(["sources"] + parts)
+                    digest = hashlib.sha1(f["contents"].encode("utf-8")).hexdigest()
+                    is_variant = rel in written and written[rel] != digest
+                    if is_variant:
+                        rel = "/".join(
+                            ["variants", safe_component(s["resource_id"])] + parts
+                        )
+                    if rel not in written:
+                        out_path = os.path.join(output_dir, *rel.split("/"))
+                        os.makedirs(os.path.dirname(out_path), exist_ok=True)
+                        with open(out_path, "w", encoding="utf-8", newline="") as out:
+                            out.write(f["contents"])
+                        written[rel] = digest
+                        files_written += 1
+                        if is_variant:
+                            variant_files += 1
+                    f["path"] = rel
+
+            shaders_by_key = {(s["resource_id"], s["entry_point"]): s for s in shaders}
+            pipelines = collect_pipelines(controller, names, shaders_by_key)
+
+            index = {
+                "capture_path": req["capture_path"],
+                "shaders": [
+                    {
+                        "resource_id": s["resource_id"],
+                        "name": s["name"],
+                        "stage": s["stage"],
+                        "entry_point": s["entry_point"],
+                        "encoding": s["encoding"],
+                        "files": [
+                            {"path": f["path"], "recorded_path": f["recorded_path"]}
+                            for f in s["files"]
+                        ],
+                        "pipelines": s["pipelines"],
+                    }
+                    for s in shaders
+                ],
+                "pipelines": {
+                    pipe: {
+                        key: {
+                            "resource_id": rid,
+                            "files": [
+                                f["path"]
+                                for f in shaders_by_key[(rid, key.split("/", 1)[1])]["files"]
+                            ],
+                        }
+                        for key, rid in sorted(stages.items())
+                    }
+                    for pipe, stages in sorted(pipelines.items())
+                },
+            }
+            index_path = os.path.join(output_dir, "index.json")
+            with open(index_path, "w", encoding="utf-8") as f:
+                json.dump(index, f, ensure_ascii=False, indent=2)
+
+            write_envelope(
+                True,
+                result={
+                    "capture_path": req["capture_path"],
+                    "output_dir": output_dir,
+                    "index_json_path": index_path,
+                    "total_shaders": len(shaders),
+                    "shaders_without_source": sum(1 for s in shaders if not s["files"]),
+                    "files_written": files_written,
+                    "variant_files": variant_files,
+                },
+            )
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
@@ -0,0 +1,164 @@
+"""
+fetch_counters_json.py -- Fetch GPU counters for every event of a capture.
+
+Request:
+  - capture_path
+  - counters: list of counter names; empty = GPU duration, samples passed, VS/PS invocations;
+    ["all"] = every counter exposed by the driver
+
+Returns counter metadata (name, category, description, unit) and per-event values keyed by
+counter name.
+"""
+
+import json
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "fetch_counters_json.request.json"
+RESP_PATH = "fetch_counters_json.response.json"
+
+DEFAULT_COUNTERS = ["EventGPUDuration", "SamplesPassed", "VSInvocations", "PSInvocations"]
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def enum_name(value) -> str:
+    return str(value).split(".")[-1]
+
+
+def counter_key(counter) -> str:
+    """Stable name for a counter: the GPUCounter enum name for generic counters."""
+    name = enum_name(counter)
+    if name.isdigit() or name.startswith("GPUCounter("):
+        return None
+    return name
+
+
+def counter_value(desc, value) -> float:
+    if desc.resultType == rd.CompType.Float:
+        return float(value.d if desc.resultByteWidth == 8 else value.f)
+    return float(value.u64 if desc.resultByteWidth == 8 else value.u32)
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    wanted = [str(c) for c in (req.get("counters") or [])]
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            available = []
+            by_name = {}
+            for counter in controller.EnumerateCounters():
+                desc = controller.DescribeCounter(counter)
+                name = counter_key(counter) or str(desc.name)
+                available.append((counter, desc, name))
+                by_name[name.lower()] = available[-1]
+                by_name.setdefault(str(desc.name).lower(), available[-1])
+
+            if len(wanted) == 1 and wanted[0].lower() == "all":
+                selected = list(available)
+            elif wanted:
+                selected = []
+                for name in wanted:
+                    entry = by_name.get(name.lower())
+                    if entry is None:
+                        raise RuntimeError(
+                            "Unknown or unsupported counter: %s (available: %s)"
+                            % (name, ", ".join(n for (_, _, n) in available))
+                        )
+                    if entry not in selected:
+                        selected.append(entry)
+            else:
+                selected = [by_name[n.lower()] for n in DEFAULT_COUNTERS if n.lower() in by_name]
+            if not selected:
+                raise RuntimeError("No counters selected (the replay driver may not expose any)")
+
+            descs = {int(c): (d, n) for (c, d, n) in selected}
+            events = {}
+            for r in controller.FetchCounters([c for (c, _, _) in selected]):
+                desc, name = descs[int(r.counter)]
+                values = events.setdefault(int(r.eventId), {})
+                values[name] = counter_value(desc, r.value)
+
+            write_envelope(
+                True,
+                result={
+                    "capture_path": req["capture_path"],
+                    "available": [n for (_, _, n) in available],
+                    "counters": [
+                        {
+                            "id": int(c),
+                            "name": n,
+                            "category": str(d.category),
+                            "description": str(d.description),
+                            "unit": enum_name(d.unit),
+                        }
+                        for (c, d, n) in selected
+                    ],
+                    "events": [{"event_id": e, "values": events[e]} for e in sorted(events)],
+                },
+            )
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
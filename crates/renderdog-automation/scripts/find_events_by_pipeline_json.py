@@ -0,0 +1,279 @@
+"""
+find_events_by_pipeline_json.py -- RenderDoc Python script that lists the events using a pipeline.
+
+A lighter-weight companion to get_pipeline_details_json.py: only draws/dispatches are visited,
+filters are applied before the replay seeks to an event, and nothing but the bound pipeline
+objects is read from the pipeline state.
+
+Request parameters:
+  - capture_path: Path to the .rdc capture file (required)
+  - pipeline_name: Pipeline name; exact match first, then substring (required)
+  - marker_prefix: Only visit events under this `/`-joined marker path
+  - event_id_min / event_id_max: Inclusive event ID range
+  - max_results: Maximum number of matches to return (the total is still counted)
+
+Returns:
+  - pipeline_name, pipeline_id, pipeline_type ("Graphics", "Compute" or null when unused)
+  - total_matches, truncated, first_event_id, last_event_id
+  - matches: event_id, parent_event_id, depth, name, flags, marker_path, marker_path_joined
+"""
+
+import json
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "find_events_by_pipeline_json.request.json"
+RESP_PATH = "find_events_by_pipeline_json.response.json"
+
+
+FLAG_NAMES = [
+    ("Drawcall", rd.ActionFlags.Drawcall),
+    ("Dispatch", rd.ActionFlags.Dispatch),
+    ("MeshDispatch", rd.ActionFlags.MeshDispatch),
+    ("DispatchRay", rd.ActionFlags.DispatchRay),
+    ("Indexed", rd.ActionFlags.Indexed),
+    ("Instanced", rd.ActionFlags.Instanced),
+    ("Indirect", rd.ActionFlags.Indirect),
+]
+
+
+def flags_to_names(flags):
+    return [name for name, bit in FLAG_NAMES if flags & bit]
+
+
+def is_drawcall_like(flags: int) -> bool:
+    return bool(
+        (flags & rd.ActionFlags.Drawcall)
+        or (flags & rd.ActionFlags.Dispatch)
+        or (flags & rd.ActionFlags.MeshDispatch)
+        or (flags & rd.ActionFlags.DispatchRay)
+    )
+
+
+def marker_path_join(marker_path) -> str:
+    if not marker_path:
+        return ""
+    return "/".join([str(x) for x in marker_path])
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def find_pipeline(controller, pipeline_name):
+    """Locate the pipeline by exact name, falling back to the first substring match."""
+    pipelines = [
+        r for r in controller.GetResources() if r.type == rd.ResourceType.PipelineState
+    ]
+    for res in pipelines:
+        if res.name == pipeline_name:
+            return res
+    for res in pipelines:
+        if pipeline_name in res.name:
+            return res
+
+    available = ["  %s  %s" % (r.resourceId, r.name) for r in pipelines]
+    raise RuntimeError(
+        "Pipeline '%s' not found. Available pipelines:\n%s"
+        % (pipeline_name, "\n".join(available[:30]))
+    )
+
+
+def bound_pipeline_type(controller, eid: int, pipeline_id):
+    controller.SetFrameEvent(eid, False)
+    state = controller.GetPipelineState()
+    try:
+        if state.GetGraphicsPipelineObject() == pipeline_id:
+            return "Graphics"
+    except Exception:
+        pass
+    try:
+        if state.GetComputePipelineObject() == pipeline_id:
+            return "Compute"
+    except Exception:
+        pass
+    return None
+
+
+def iter_actions(
+    controller,
+    structured_file,
+    actions,
+    marker_stack,
+    parent_event_id,
+    depth,
+    pipeline_id,
+    filters,
+    out_list,
+    counters,
+):
+    for a in actions:
+        name = str(a.GetName(structured_file))
+        flags = a.flags
+        eid = int(a.eventId)
+
+        is_marker = bool(flags & rd.ActionFlags.PushMarker)
+        if is_marker:
+            marker_stack.append(name)
+        joined_marker_path = marker_path_join(marker_stack)
+
+        marker_prefix = filters["marker_prefix"]
+        in_scope = not marker_prefix or (
+            joined_marker_path == marker_prefix
+            or joined_marker_path.startswith(marker_prefix + "/")
+        )
+        event_min = filters["event_id_min"]
+        event_max = filters["event_id_max"]
+        in_range = (event_min is None or eid >= int(event_min)) and (
+            event_max is None or eid <= int(event_max)
+        )
+
+        if in_scope and in_range and is_drawcall_like(flags):
+            pipeline_type = bound_pipeline_type(controller, eid, pipeline_id)
+            if pipeline_type is not None:
+                counters["pipeline_type"] = counters.get("pipeline_type") or pipeline_type
+                counters["total_matches"] += 1
+                if counters.get("first_event_id", None) is None:
+                    counters["first_event_id"] = eid
+                counters["last_event_id"] = eid
+                max_results = filters["max_results"]
+                if max_results is None or len(out_list) < int(max_results):
+                    out_list.append(
+                        {
+                            "event_id": eid,
+                            "parent_event_id": int(parent_event_id)
+                            if parent_event_id is not None
+                            else None,
+                            "depth": int(depth),
+                            "name": name,
+                            "flags": int(flags),
+                            "flags_names": flags_to_names(flags),
+                            "marker_path": list(marker_stack),
+                            "marker_path_joined": joined_marker_path,
+                        }
+                    )
+                else:
+                    counters["truncated"] = True
+
+        if len(a.children) > 0:
+            iter_actions(
+                controller,
+                structured_file,
+                a.children,
+                marker_stack,
+                a.eventId,
+                depth + 1,
+                pipeline_id,
+                filters,
+                out_list,
+                counters,
+            )
+
+        if is_marker:
+            marker_stack.pop()
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    filters = {
+        "marker_prefix": req.get("marker_prefix", None),
+        "event_id_min": req.get("event_id_min", None),
+        "event_id_max": req.get("event_id_max", None),
+        "max_results": req.get("max_results", None),
+    }
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            pipe_res = find_pipeline(controller, req["pipeline_name"])
+
+            out_list = []
+            counters = {"truncated": False, "total_matches": 0}
+            iter_actions(
+                controller,
+                controller.GetStructuredFile(),
+                controller.GetRootActions(),
+                [],
+                None,
+                0,
+                pipe_res.resourceId,
+                filters,
+                out_list,
+                counters,
+            )
+
+            write_envelope(
+                True,
+                result={
+                    "capture_path": req["capture_path"],
+                    "pipeline_name": pipe_res.name,
+                    "pipeline_id": int(pipe_res.resourceId),
+                    "pipeline_type": counters.get("pipeline_type", None),
+                    "total_matches": int(counters["total_matches"]),
+                    "truncated": bool(counters["truncated"]),
+                    "first_event_id": counters.get("first_event_id", None),
+                    "last_event_id": counters.get("last_event_id", None),
+                    "matches": out_list,
+                },
+            )
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
@@ -0,0 +1,312 @@
+"""
+find_events_by_shader_json.py -- RenderDoc Python script that maps a shader to the events using it.
+
+Visits every draw/dispatch, looks at the shader bound to each stage and keeps the event when a
+bound shader matches the request. Shader reflection is only inspected once per
+(shader, stage, entry point), so the cost is dominated by seeking to each event.
+
+Request parameters:
+  - capture_path: Path to the .rdc capture file (required)
+  - entry_point: Shader entry point name (exact match)
+  - source_file: Source file from the shader's debug info. Matched case-insensitively against the
+                 end of the recorded path on a path-component boundary, so "shadow.hlsl",
+                 "shaders/shadow.hlsl" and the full path all match "C:\\src\\shaders\\shadow.hlsl".
+  - marker_prefix: Only visit events under this `/`-joined marker path
+  - max_results: Maximum number of matching events to return (the total is still counted)
+
+At least one of entry_point / source_file is required; when both are given, both must match.
+
+Returns:
+  - total_matches, truncated
+  - shaders: every matching shader (resource_id, name, stage, entry_point, source_files,
+             event_count)
+  - matches: event_id, name, marker_path, marker_path_joined, stages (the matching stages)
+"""
+
+import json
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "find_events_by_shader_json.request.json"
+RESP_PATH = "find_events_by_shader_json.response.json"
+
+
+_STAGE_NAMES = [
+    (rd.ShaderStage.Vertex, "Vertex"),
+    (rd.ShaderStage.Tess_Control, "TessControl"),
+    (rd.ShaderStage.Tess_Eval, "TessEval"),
+    (rd.ShaderStage.Geometry, "Geometry"),
+    (rd.ShaderStage.Task, "Task"),
+    (rd.ShaderStage.Mesh, "Mesh"),
+    (rd.ShaderStage.Fragment, "Fragment"),
+    (rd.ShaderStage.Compute, "Compute"),
+]
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def is_drawcall_like(flags: int) -> bool:
+    return bool(
+        (flags & rd.ActionFlags.Drawcall)
+        or (flags & rd.ActionFlags.Dispatch)
+        or (flags & rd.ActionFlags.MeshDispatch)
+        or (flags & rd.ActionFlags.DispatchRay)
+    )
+
+
+def marker_path_join(marker_path) -> str:
+    if not marker_path:
+        return ""
+    return "/".join([str(x) for x in marker_path])
+
+
+def normalize_path(path: str) -> str:
+    return str(path).replace("\\", "/").lower()
+
+
+def source_file_matches(recorded: str, wanted: str) -> bool:
+    recorded = normalize_path(recorded)
+    return recorded == wanted or recorded.endswith("/" + wanted)
+
+
+def debug_source_files(refl):
+    files = []
+    try:
+        debug = refl.debugInfo
+        if debug is not None and debug.files is not None:
+            for f in debug.files:
+                fname = getattr(f, "filename", "") or ""
+                if fname:
+                    files.append(str(fname))
+    except Exception:
+        pass
+    return files
+
+
+def build_name_map(controller):
+    names = {}
+    try:
+        for res in controller.GetResources():
+            names[str(res.resourceId)] = res.name
+    except Exception:
+        pass
+    return names
+
+
+class ShaderMatcher:
+    """Caches the match decision for each (shader, stage, entry point) seen while walking."""
+
+    def __init__(self, names, entry_point, source_file):
+        self.names = names
+        self.entry_point = entry_point
+        self.source_file = normalize_path(source_file) if source_file else None
+        self.cache = {}
+        self.shaders = []
+
+    def match(self, state, stage, stage_name):
+        shader = state.GetShader(stage)
+        if shader == rd.ResourceId.Null():
+            return None
+        try:
+            entry = str(state.GetShaderEntryPoint(stage) or "")
+        except Exception:
+            entry = ""
+
+        key = (str(shader), stage_name, entry)
+        if key not in self.cache:
+            self.cache[key] = self._evaluate(state, stage, stage_name, shader, entry)
+        return self.cache[key]
+
+    def _evaluate(self, state, stage, stage_name, shader, entry):
+        if self.entry_point and entry != self.entry_point:
+            return None
+
+        refl = state.GetShaderReflection(stage)
+        files = debug_source_files(refl) if refl is not None else []
+        if self.source_file and not any(
+            source_file_matches(f, self.source_file) for f in files
+        ):
+            return None
+
+        info = {
+            "resource_id": str(shader),
+            "name": self.names.get(str(shader), str(shader)),
+            "stage": stage_name,
+            "entry_point": entry,
+            "source_files": files,
+            "event_count": 0,
+        }
+        self.shaders.append(info)
+        return info
+
+
+def iter_actions(
+    controller,
+    structured_file,
+    actions,
+    marker_stack,
+    matcher,
+    filters,
+    out_list,
+    counters,
+):
+    for a in actions:
+        name = str(a.GetName(structured_file))
+        flags = a.flags
+        eid = int(a.eventId)
+
+        is_marker = bool(flags & rd.ActionFlags.PushMarker)
+        if is_marker:
+            marker_stack.append(name)
+        joined_marker_path = marker_path_join(marker_stack)
+
+        marker_prefix = filters["marker_prefix"]
+        in_scope = not marker_prefix or (
+            joined_marker_path == marker_prefix
+            or joined_marker_path.startswith(marker_prefix + "/")
+        )
+
+        if in_scope and is_drawcall_like(flags):
+            controller.SetFrameEvent(eid, False)
+            state = controller.GetPipelineState()
+            stages = []
+            for stage, stage_name in _STAGE_NAMES:
+                info = matcher.match(state, stage, stage_name)
+                if info is not None:
+                    info["event_count"] += 1
+                    stages.append(stage_name)
+
+            if stages:
+                counters["total_matches"] += 1
+                max_results = filters["max_results"]
+                if max_results is None or len(out_list) < int(max_results):
+                    out_list.append(
+                        {
+                            "event_id": eid,
+                            "name": name,
+                            "marker_path": list(marker_stack),
+                            "marker_path_joined": joined_marker_path,
+                            "stages": stages,
+                        }
+                    )
+                else:
+                    counters["truncated"] = True
+
+        if len(a.children) > 0:
+            iter_actions(
+                controller,
+                structured_file,
+                a.children,
+                marker_stack,
+                matcher,
+                filters,
+                out_list,
+                counters,
+            )
+
+        if is_marker:
+            marker_stack.pop()
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    entry_point = req.get("entry_point", None)
+    source_file = req.get("source_file", None)
+    if not entry_point and not source_file:
+        raise RuntimeError("entry_point or source_file is required")
+
+    filters = {
+        "marker_prefix": req.get("marker_prefix", None),
+        "max_results": req.get("max_results", None),
+    }
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            matcher = ShaderMatcher(build_name_map(controller), entry_point, source_file)
+            out_list = []
+            counters = {"truncated": False, "total_matches": 0}
+            iter_actions(
+                controller,
+                controller.GetStructuredFile(),
+                controller.GetRootActions(),
+                [],
+                matcher,
+                filters,
+                out_list,
+                counters,
+            )
+
+            write_envelope(
+                True,
+                result={
+                    "capture_path": req["capture_path"],
+                    "total_matches": int(counters["total_matches"]),
+                    "truncated": bool(counters["truncated"]),
+                    "shaders": matcher.shaders,
+                    "matches": out_list,
+                },
+            )
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
@@ -1,4 +1,6 @@
+import fnmatch
 import json
+import re
 import traceback
 
 import renderdoc as rd
@@ -13,13 +15,24 @@ FLAG_NAMES = [
     ("Dispatch", rd.ActionFlags.Dispatch),
     ("MeshDispatch", rd.ActionFlags.MeshDispatch),
     ("DispatchRay", rd.ActionFlags.DispatchRay),
+    ("Clear", rd.ActionFlags.Clear),
+    ("ClearColor", rd.ActionFlags.ClearColor),
+    ("ClearDepthStencil", rd.ActionFlags.ClearDepthStencil),
+    ("Copy", rd.ActionFlags.Copy),
+    ("Resolve", rd.ActionFlags.Resolve),
+    ("GenMips", rd.ActionFlags.GenMips),
+    ("BuildAccStruct", rd.ActionFlags.BuildAccStruct),
     ("Present", rd.ActionFlags.Present),
+    ("SetMarker", rd.ActionFlags.SetMarker),
     ("PushMarker", rd.ActionFlags.PushMarker),
     ("PopMarker", rd.ActionFlags.PopMarker),
     ("PassBoundary", rd.ActionFlags.PassBoundary),
     ("BeginPass", rd.ActionFlags.BeginPass),
     ("EndPass", rd.ActionFlags.EndPass),
     ("CommandBufferBoundary", rd.ActionFlags.CommandBufferBoundary),
+    ("Indexed", rd.ActionFlags.Indexed),
+    ("Instanced", rd.ActionFlags.Instanced),
+    ("Indirect", rd.ActionFlags.Indirect),
 ]
 
 
@@ -40,6 +53,73 @@ def is_drawcall_like(flags: int) -> bool:
     )
 
 
+def flags_mask(field: str, names) -> int:
+    """OR of the named ActionFlags (names are matched case-insensitively against FLAG_NAMES)."""
+    by_name = {name.lower(): int(bit) for name, bit in FLAG_NAMES}
+    mask = 0
+    for name in names or []:
+        bit = by_name.get(str(name).lower())
+        if bit is None:
+            raise RuntimeError(
+                f"Unknown {field} flag '{name}'; expected one of: "
+                + ", ".join(n for n, _ in FLAG_NAMES)
+            )
+        mask |= bit
+    return mask
+
+
+QUERY_COMPARE = {
+    ">": lambda a, b: a > b,
+    ">=": lambda a, b: a >= b,
+    "<": lambda a, b: a < b,
+    "<=": lambda a, b: a <= b,
+    "==": lambda a, b: a == b,
+    "!=": lambda a, b: a != b,
+}
+
+
+def query_number(a, field: str, depth: int) -> int:
+    if field == "eid":
+        return int(a.eventId)
+    if field == "depth":
+        return int(depth)
+    if field == "children":
+        return len(a.children)
+    if not (a.flags & rd.ActionFlags.Drawcall):
+        return 0
+    instances = max(int(a.numInstances), 1)
+    if field == "indices":
+        return int(a.numIndices)
+    if field == "instances":
+        return instances
+    if field == "triangles":
+        return int(a.numIndices) * instances // 3
+    raise RuntimeError("unknown query field: " + str(field))
+
+
+def query_matches(node, a, name_str: str, marker_path, depth: int) -> bool:
+    """Evaluate a `query_ast` node (parsed from `query` by the Rust EventQuery parser)."""
+    op = node["op"]
+    if op == "and":
+        return all(query_matches(n, a, name_str, marker_path, depth) for n in node["args"])
+    if op == "or":
+        return any(query_matches(n, a, name_str, marker_path, depth) for n in node["args"])
+    if op == "not":
+        return not query_matches(node["arg"], a, name_str, marker_path, depth)
+    if op == "flag":
+        return bool(int(a.flags) & int(getattr(rd.ActionFlags, node["flag"], 0)))
+    if op == "glob":
+        pattern = str(node["pattern"]).lower()
+        if node["field"] == "name":
+            return fnmatch.fnmatchcase(name_str.lower(), pattern)
+        if "/" in pattern:
+            return fnmatch.fnmatchcase("/".join(str(m) for m in marker_path).lower(), pattern)
+        return any(fnmatch.fnmatchcase(str(m).lower(), pattern) for m in marker_path)
+    if op == "compare":
+        return QUERY_COMPARE[node["cmp"]](query_number(a, node["field"], depth), int(node["value"]))
+    raise RuntimeError("unknown query op: " + str(op))
+
+
 def marker_path_join(marker_path) -> str:
     if not marker_path:
         return ""
@@ -54,11 +134,44 @@ def normalize(s: str, case_sensitive: bool) -> str:
     return str(s).lower()
 
 
+def compile_regex(field: str, pattern, case_sensitive: bool):
+    if not pattern:
+        return None
+    try:
+        return re.compile(pattern, 0 if case_sensitive else re.IGNORECASE)
+    except re.error as e:
+        raise RuntimeError(f"Invalid {field} pattern '{pattern}': {e}")
+
+
 def write_envelope(ok: bool, result=None, error: str = None) -> None:
     with open(RESP_PATH, "w", encoding="utf-8") as f:
         json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
 
 
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
 def iter_actions(
     structured_file,
     actions,
@@ -73,6 +186,11 @@ def iter_actions(
     event_max,
     name_contains: str,
     marker_contains: str,
+    name_regex,
+    marker_regex,
+    flags_any: int,
+    flags_all: int,
+    query,
     case_sensitive: bool,
     max_results,
 ):
@@ -104,6 +222,11 @@ def iter_actions(
                     event_max,
                     name_contains,
                     marker_contains,
+                    name_regex,
+                    marker_regex,
+                    flags_any,
+                    flags_all,
+                    query,
                     case_sensitive,
                     max_results,
                 )
@@ -123,6 +246,11 @@ def iter_actions(
                     event_max,
                     name_contains,
                     marker_contains,
+                    name_regex,
+                    marker_regex,
+                    flags_any,
+                    flags_all,
+                    query,
                     case_sensitive,
                     max_results,
                 )
@@ -151,6 +279,16 @@ def iter_actions(
         if marker_contains:
             if marker_contains not in normalize(joined_marker_path, case_sensitive):
                 should_emit = False
+        if name_regex is not None and not name_regex.search(name_str):
+            should_emit = False
+        if marker_regex is not None and not marker_regex.search(joined_marker_path):
+            should_emit = False
+        if flags_any and not (int(flags) & flags_any):
+            should_emit = False
+        if flags_all and (int(flags) & flags_all) != flags_all:
+            should_emit = False
+        if should_emit and query is not None:
+            should_emit = query_matches(query, a, name_str, effective_marker_path, depth)
 
         if should_emit:
             counters["total_matches"] += 1
@@ -182,6 +320,12 @@ def main() -> None:
     with open(REQ_PATH, "r", encoding="utf-8") as f:
         req = json.load(f)
 
+    case_sensitive = bool(req.get("case_sensitive", False))
+    name_regex = compile_regex("name_regex", req.get("name_regex"), case_sensitive)
+    marker_regex = compile_regex("marker_regex", req.get("marker_regex"), case_sensitive)
+    flags_any = flags_mask("flags_any", req.get("flags_any"))
+    flags_all = flags_mask("flags_all", req.get("flags_all"))
+
     rd.InitialiseReplay(rd.GlobalEnvironment(), [])
 
     cap = rd.OpenCaptureFile()
@@ -193,7 +337,7 @@ def main() -> None:
         if not cap.LocalReplaySupport():
             raise RuntimeError("Capture cannot be replayed")
 
-        result, controller = cap.OpenCapture(rd.ReplayOptions(), None)
+        result, controller = cap.OpenCapture(replay_options(req), None)
         if result != rd.ResultCode.Succeeded:
             raise RuntimeError("Couldn't initialise replay: " + str(result))
 
@@ -217,7 +361,12 @@ def main() -> None:
                 req.get("event_id_max", None),
                 req.get("name_contains", None),
                 req.get("marker_contains", None),
-                bool(req.get("case_sensitive", False)),
+                name_regex,
+                marker_regex,
+                flags_any,
+                flags_all,
+                req.get("query_ast"),
+                case_sensitive,
                 req.get("max_results", None),
             )
 
@@ -475,6 +475,30 @@ def write_envelope(ok: bool, result=None, error: str = None) -> None:
         json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
 
 
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
 # ---------------------------------------------------------------------------
 # Resource name lookup
 # ---------------------------------------------------------------------------
@@ -1070,7 +1094,7 @@ def main() -> None:
         if not cap.LocalReplaySupport():
             raise RuntimeError("Capture cannot be replayed")
 
-        result, controller = cap.OpenCapture(rd.ReplayOptions(), None)
+        result, controller = cap.OpenCapture(replay_options(req), None)
         if result != rd.ResultCode.Succeeded:
             raise RuntimeError("Couldn't initialise replay: " + str(result))
 
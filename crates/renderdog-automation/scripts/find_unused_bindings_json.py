@@ -0,0 +1,289 @@
+"""
+find_unused_bindings_json.py -- Report bound descriptors no shader stage references, per pipeline.
+
+Request:
+  - capture_path
+
+At every draw / dispatch, compares the bound descriptor layout against the reflection of the
+pipeline's shaders:
+  - not_in_shader: a descriptor set layout binding that no stage declares (Vulkan only, where
+    the bound set layouts are known).
+  - statically_unused: a resource a stage declares but never accesses (any API).
+Findings are merged per pipeline; a binding is reported once with the stages involved.
+"""
+
+import json
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "find_unused_bindings_json.request.json"
+RESP_PATH = "find_unused_bindings_json.response.json"
+
+STAGES = [
+    ("Vertex", rd.ShaderStage.Vertex),
+    ("TCS", rd.ShaderStage.Tess_Control),
+    ("TES", rd.ShaderStage.Tess_Eval),
+    ("Geometry", rd.ShaderStage.Geometry),
+    ("Fragment", rd.ShaderStage.Fragment),
+    ("Task", rd.ShaderStage.Task),
+    ("Mesh", rd.ShaderStage.Mesh),
+    ("Compute", rd.ShaderStage.Compute),
+]
+
+_VK_DESCRIPTOR_TYPES = {
+    0: "SAMPLER",
+    1: "COMBINED_IMAGE_SAMPLER",
+    2: "SAMPLED_IMAGE",
+    3: "STORAGE_IMAGE",
+    4: "UNIFORM_TEXEL_BUFFER",
+    5: "STORAGE_TEXEL_BUFFER",
+    6: "UNIFORM_BUFFER",
+    7: "STORAGE_BUFFER",
+    8: "UNIFORM_BUFFER_DYNAMIC",
+    9: "STORAGE_BUFFER_DYNAMIC",
+    10: "INPUT_ATTACHMENT",
+    1000138000: "INLINE_UNIFORM_BLOCK",
+    1000150000: "ACCELERATION_STRUCTURE",
+}
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def flatten_actions(actions):
+    out = []
+    for a in actions:
+        out.append(a)
+        out.extend(flatten_actions(a.children))
+    return out
+
+
+def sd_child(obj, names):
+    for i in range(obj.NumChildren()):
+        child = obj.GetChild(i)
+        if child.name in names:
+            return child
+    return None
+
+
+def layout_bindings(sfile, resources, layout_rid, cache):
+    """[(binding, type, count)] of a VkDescriptorSetLayout from its create chunk."""
+    key = int(layout_rid)
+    if key in cache:
+        return cache[key]
+    bindings = []
+    res = resources.get(key)
+    for idx in res.initialisationChunks if res is not None else []:
+        if idx >= len(sfile.chunks) or "CreateDescriptorSetLayout" not in sfile.chunks[idx].name:
+            continue
+        info = sd_child(sfile.chunks[idx], ("CreateInfo", "pCreateInfo"))
+        arr = sd_child(info, ("pBindings", "bindings")) if info is not None else None
+        for i in range(arr.NumChildren() if arr is not None else 0):
+            b = arr.GetChild(i)
+            binding = sd_child(b, ("binding",))
+            dtype = sd_child(b, ("descriptorType",))
+            count = sd_child(b, ("descriptorCount",))
+            if binding is None:
+                continue
+            type_name = None
+            if dtype is not None:
+                type_name = dtype.AsString() or _VK_DESCRIPTOR_TYPES.get(dtype.AsInt(), str(dtype.AsInt()))
+            bindings.append((binding.AsInt(), type_name, count.AsInt() if count is not None else 1))
+    cache[key] = bindings
+    return bindings
+
+
+def reflected_bindings(refl):
+    """{(set, binding): name} for every resource a shader declares."""
+    out = {}
+    for group in (refl.constantBlocks, refl.samplers, refl.readOnlyResources, refl.readWriteResources):
+        for r in group:
+            out[(int(r.fixedBindSetOrSpace), int(r.fixedBindNumber))] = str(r.name)
+    return out
+
+
+def reflection_entry(refl, access):
+    category = rd.CategoryForDescriptorType(access.type)
+    if category == rd.DescriptorCategory.ConstantBlock:
+        group = refl.constantBlocks
+    elif category == rd.DescriptorCategory.Sampler:
+        group = refl.samplers
+    elif category == rd.DescriptorCategory.ReadOnlyResource:
+        group = refl.readOnlyResources
+    elif category == rd.DescriptorCategory.ReadWriteResource:
+        group = refl.readWriteResources
+    else:
+        return None
+    idx = int(access.index)
+    return group[idx] if 0 <= idx < len(group) else None
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            sfile = controller.GetStructuredFile()
+            resources = {int(r.resourceId): r for r in controller.GetResources()}
+            is_vulkan = controller.GetAPIProperties().pipelineType == rd.GraphicsAPI.Vulkan
+            layout_cache = {}
+            work = rd.ActionFlags.Drawcall | rd.ActionFlags.Dispatch | rd.ActionFlags.MeshDispatch
+            actions = [a for a in flatten_actions(controller.GetRootActions()) if a.flags & work]
+
+            pipelines = {}
+            for action in actions:
+                controller.SetFrameEvent(int(action.eventId), False)
+                pipe = controller.GetPipelineState()
+                is_compute = bool(action.flags & rd.ActionFlags.Dispatch)
+                pipe_obj = pipe.GetComputePipelineObject() if is_compute else pipe.GetGraphicsPipelineObject()
+                pkey = int(pipe_obj)
+                entry = pipelines.get(pkey)
+                if entry is None:
+                    entry = pipelines[pkey] = {
+                        "pipeline_id": pkey,
+                        "pipeline_name": str(resources[pkey].name) if pkey in resources else str(pkey),
+                        "kind": "compute" if is_compute else "graphics",
+                        "draws": 0,
+                        "first_event": int(action.eventId),
+                        "unused": {},
+                    }
+                entry["draws"] += 1
+
+                stages = [(n, s) for n, s in STAGES if (s == rd.ShaderStage.Compute) == is_compute]
+                declared = {}
+                refls = {}
+                for stage_name, stage in stages:
+                    refl = pipe.GetShaderReflection(stage)
+                    if refl is None:
+                        continue
+                    refls[stage] = (stage_name, refl)
+                    for key, name in reflected_bindings(refl).items():
+                        declared.setdefault(key, name)
+
+                for access in controller.GetDescriptorAccess():
+                    if not access.staticallyUnused or access.stage not in refls:
+                        continue
+                    stage_name, refl = refls[access.stage]
+                    r = reflection_entry(refl, access)
+                    if r is None:
+                        continue
+                    key = (int(r.fixedBindSetOrSpace), int(r.fixedBindNumber), "statically_unused")
+                    found = entry["unused"].setdefault(
+                        key,
+                        {
+                            "set": key[0],
+                            "binding": key[1],
+                            "descriptor_type": str(access.type).split(".")[-1],
+                            "descriptor_count": int(getattr(r, "bindArraySize", 1)),
+                            "reason": "statically_unused",
+                            "name": str(r.name),
+                            "stages": [],
+                        },
+                    )
+                    if stage_name not in found["stages"]:
+                        found["stages"].append(stage_name)
+
+                if not is_vulkan:
+                    continue
+                vk = controller.GetVulkanPipelineState()
+                vk_pipe = vk.compute if is_compute else vk.graphics
+                for set_index, ds in enumerate(vk_pipe.descriptorSets):
+                    if ds.layoutResourceId == rd.ResourceId.Null():
+                        continue
+                    for binding, type_name, count in layout_bindings(sfile, resources, ds.layoutResourceId, layout_cache):
+                        if (set_index, binding) in declared:
+                            continue
+                        entry["unused"].setdefault(
+                            (set_index, binding, "not_in_shader"),
+                            {
+                                "set": set_index,
+                                "binding": binding,
+                                "descriptor_type": type_name,
+                                "descriptor_count": count,
+                                "reason": "not_in_shader",
+                                "name": None,
+                                "stages": [],
+                            },
+                        )
+
+            out = []
+            total_unused = 0
+            for entry in sorted(pipelines.values(), key=lambda e: e["first_event"]):
+                if not entry["unused"]:
+                    continue
+                entry["unused"] = [entry["unused"][k] for k in sorted(entry["unused"])]
+                total_unused += len(entry["unused"])
+                out.append(entry)
+
+            write_envelope(
+                True,
+                result={
+                    "capture_path": req["capture_path"],
+                    "draws_checked": len(actions),
+                    "pipelines_checked": len(pipelines),
+                    "total_unused": total_unused,
+                    "pipelines": out,
+                },
+            )
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
@@ -0,0 +1,395 @@
+"""
+generate_report_html_json.py -- Bundle a capture analysis into one self-contained HTML file.
+
+Request:
+  - capture_path
+  - output_dir, basename: the report is written as `<basename>.report.html`
+  - actions_jsonl_path, bindings_jsonl_path: exports to embed (see export_bundle_jsonl)
+  - thumbnail_max_dim: longest side of the per-pass thumbnails
+  - max_rows: rows embedded per export (the rest is dropped and flagged as truncated)
+
+The page holds frame stats, the marker tree with draw counts, a thumbnail of RT0 at the end of
+every top-level marker group (PNG data URIs) and filterable action/binding tables. Nothing is
+loaded from outside the file, so it can be shared with people who don't have RenderDoc.
+"""
+
+import base64
+import datetime
+import html
+import json
+import os
+import struct
+import traceback
+import zlib
+
+import renderdoc as rd
+
+
+REQ_PATH = "generate_report_html_json.request.json"
+RESP_PATH = "generate_report_html_json.response.json"
+
+DISPATCH_FLAGS = rd.ActionFlags.Dispatch | getattr(rd.ActionFlags, "DispatchRay", 0)
+
+PAGE_STYLE = """
+body { font-family: system-ui, sans-serif; margin: 2em; color: #222; }
+table { border-collapse: collapse; font-size: 13px; }
+th, td { border: 1px solid #ccc; padding: 2px 6px; text-align: left; vertical-align: top; }
+th { background: #f0f0f0; }
+.passes { display: flex; flex-wrap: wrap; gap: 12px; }
+.pass { border: 1px solid #ccc; padding: 6px; font-size: 13px; max-width: 280px; }
+.pass img { display: block; image-rendering: pixelated; margin-bottom: 4px; }
+ul.tree { font-size: 13px; }
+input.filter { width: 30em; margin: 6px 0; }
+.note { color: #777; font-size: 12px; }
+"""
+
+PAGE_SCRIPT = """
+function renderTable(id, columns) {
+  const data = JSON.parse(document.getElementById(id + "-data").textContent);
+  const input = document.getElementById(id + "-filter");
+  const body = document.querySelector("#" + id + " tbody");
+  const note = document.getElementById(id + "-note");
+  function update() {
+    const needle = input.value.toLowerCase();
+    const rows = data.filter(r => !needle || JSON.stringify(r).toLowerCase().includes(needle));
+    body.innerHTML = "";
+    for (const r of rows.slice(0, 500)) {
+      const tr = document.createElement("tr");
+      for (const c of columns) {
+        const td = document.createElement("td");
+        const v = r[c];
+        td.textContent = Array.isArray(v) ? v.join(c === "marker_path" ? " / " : ", ") : (v === null || v === undefined ? "" : String(v));
+        tr.appendChild(td);
+      }
+      body.appendChild(tr);
+    }
+    note.textContent = rows.length + " matching rows" + (rows.length > 500 ? " (first 500 shown)" : "");
+  }
+  input.addEventListener("input", update);
+  update();
+}
+renderTable("actions", ["event_id", "name", "marker_path", "flags_names"]);
+renderTable("bindings", ["event_id", "name", "marker_path", "shader_names", "resource_names"]);
+"""
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def bound_resource_id(br):
+    rid = getattr(br, "resourceId", None)
+    if rid is None:
+        rid = getattr(br, "resource", None)
+    return rid
+
+
+def read_bmp(path):
+    """(width, height, top-down rows of RGB bytes) from an uncompressed 24/32-bit BMP."""
+    with open(path, "rb") as f:
+        data = f.read()
+    if data[:2] != b"BM":
+        raise RuntimeError("SaveTexture did not write a BMP")
+    (offset,) = struct.unpack("<I", data[10:14])
+    width, height, _, bpp, compression = struct.unpack("<iiHHI", data[18:34])
+    if bpp not in (24, 32) or compression not in (0, 3):
+        raise RuntimeError("unsupported BMP layout: %d bpp, compression %d" % (bpp, compression))
+    bottom_up = height > 0
+    height = abs(height)
+    step = bpp // 8
+    stride = (width * step + 3) & ~3
+    rows = []
+    for y in range(height):
+        start = offset + y * stride
+        row = data[start : start + width * step]
+        rgb = bytearray(width * 3)
+        rgb[0::3] = row[2::step]
+        rgb[1::3] = row[1::step]
+        rgb[2::3] = row[0::step]
+        rows.append(bytes(rgb))
+    if bottom_up:
+        rows.reverse()
+    return width, height, rows
+
+
+def downscale(width, height, rows, out_w, out_h):
+    """Average a 2x2 set of samples from each source block (cheap box filter)."""
+    out = []
+    for oy in range(out_h):
+        y0 = oy * height // out_h
+        y1 = max(min((oy + 1) * height // out_h - 1, height - 1), y0)
+        r0, r1 = rows[y0], rows[y1]
+        line = bytearray(out_w * 3)
+        for ox in range(out_w):
+            x0 = ox * width // out_w
+            x1 = max(min((ox + 1) * width // out_w - 1, width - 1), x0)
+            for c in range(3):
+                line[ox * 3 + c] = (
+                    r0[x0 * 3 + c] + r0[x1 * 3 + c] + r1[x0 * 3 + c] + r1[x1 * 3 + c] + 2
+                ) // 4
+        out.append(bytes(line))
+    return out
+
+
+def png_bytes(width, height, rows):
+    def chunk(ctype, body):
+        return struct.pack(">I", len(body)) + ctype + body + struct.pack(">I", zlib.crc32(ctype + body))
+
+    header = struct.pack(">IIBBBBB", width, height, 8, 2, 0, 0, 0)
+    raw = b"".join(b"\x00" + row for row in rows)
+    return (
+        b"\x89PNG\r\n\x1a\n"
+        + chunk(b"IHDR", header)
+        + chunk(b"IDAT", zlib.compress(raw, 9))
+        + chunk(b"IEND", b"")
+    )
+
+
+def render_thumbnail(controller, event_id, max_dim, tmp_path):
+    """PNG bytes of RT0 at `event_id`, downscaled to `max_dim`, or None without a colour target."""
+    controller.SetFrameEvent(event_id, True)
+    outs = controller.GetPipelineState().GetOutputTargets()
+    rid = bound_resource_id(outs[0]) if len(outs) > 0 else None
+    if rid is None or rid == rd.ResourceId.Null():
+        return None
+    save = rd.TextureSave()
+    save.resourceId = rid
+    save.destType = rd.FileType.BMP
+    save.mip = int(getattr(outs[0], "firstMip", 0))
+    save.slice.sliceIndex = int(getattr(outs[0], "firstSlice", 0))
+    save.alpha = rd.AlphaMapping.Discard
+    if controller.SaveTexture(save, tmp_path) != rd.ResultCode.Succeeded:
+        return None
+    try:
+        width, height, rows = read_bmp(tmp_path)
+    finally:
+        os.remove(tmp_path)
+    scale = min(1.0, float(max_dim) / max(width, height))
+    out_w = max(1, int(round(width * scale)))
+    out_h = max(1, int(round(height * scale)))
+    if (out_w, out_h) != (width, height):
+        rows = downscale(width, height, rows, out_w, out_h)
+    return png_bytes(out_w, out_h, rows)
+
+
+def read_jsonl(path, max_rows):
+    rows = []
+    truncated = False
+    with open(path, "r", encoding="utf-8") as f:
+        for line in f:
+            if not line.strip():
+                continue
+            if len(rows) >= max_rows:
+                truncated = True
+                break
+            rows.append(json.loads(line))
+    return rows, truncated
+
+
+def embed_json(obj) -> str:
+    # `</` would end the <script> element early.
+    return json.dumps(obj, ensure_ascii=False).replace("</", "<\\/")
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    max_dim = int(req.get("thumbnail_max_dim", 256))
+    max_rows = int(req.get("max_rows", 20000))
+    output_dir = req["output_dir"]
+    os.makedirs(output_dir, exist_ok=True)
+    report_path = os.path.join(output_dir, req["basename"] + ".report.html")
+
+    actions, actions_truncated = read_jsonl(req["actions_jsonl_path"], max_rows)
+    bindings, bindings_truncated = read_jsonl(req["bindings_jsonl_path"], max_rows)
+    for b in bindings:
+        # Per-stage detail is too large to embed; the tables only show the name lists.
+        b.pop("stages", None)
+        b.pop("outputs", None)
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            sfile = controller.GetStructuredFile()
+
+            nodes = []
+            frame = {"draws": 0, "dispatches": 0, "last_draw": None}
+
+            def walk(actions, markers, stack):
+                for a in actions:
+                    if len(a.children) > 0:
+                        path = markers + [str(a.GetName(sfile))]
+                        node = {"path": path, "draws": 0, "dispatches": 0, "first_event": None, "last_draw": None}
+                        nodes.append(node)
+                        walk(a.children, path, stack + [node])
+                    elif a.flags & (rd.ActionFlags.Drawcall | DISPATCH_FLAGS):
+                        is_draw = bool(a.flags & rd.ActionFlags.Drawcall)
+                        key = "draws" if is_draw else "dispatches"
+                        for node in stack + [frame]:
+                            node[key] += 1
+                            if node.get("first_event") is None:
+                                node["first_event"] = int(a.eventId)
+                            if is_draw:
+                                node["last_draw"] = int(a.eventId)
+
+            walk(controller.GetRootActions(), [], [])
+
+            # Top-level marker groups get a thumbnail; without markers, the last draw does.
+            passes = [n for n in nodes if len(n["path"]) == 1 and n["last_draw"] is not None]
+            if not passes and frame["last_draw"] is not None:
+                passes = [dict(frame, path=["Frame"])]
+            tmp_path = report_path + ".tmp.bmp"
+            thumbnails = 0
+            for node in passes:
+                png = render_thumbnail(controller, node["last_draw"], max_dim, tmp_path)
+                node["thumbnail"] = base64.b64encode(png).decode("ascii") if png else None
+                thumbnails += 1 if png else 0
+
+            info = controller.GetFrameInfo()
+            api = str(controller.GetAPIProperties().pipelineType).split(".")[-1]
+            captured = ""
+            if int(getattr(info, "captureTime", 0)) > 0:
+                captured = datetime.datetime.fromtimestamp(
+                    int(info.captureTime), datetime.timezone.utc
+                ).strftime("%Y-%m-%d %H:%M:%S UTC")
+
+            e = html.escape
+            title = "Capture report: " + os.path.basename(req["capture_path"])
+            out = [
+                "<!DOCTYPE html>",
+                '<html><head><meta charset="utf-8"><title>%s</title>' % e(title),
+                "<style>%s</style></head><body>" % PAGE_STYLE,
+                "<h1>%s</h1>" % e(title),
+                "<h2>Capture</h2><table>",
+            ]
+            stats = [
+                ("File", req["capture_path"]),
+                ("API", "%s (driver: %s)" % (api, cap.DriverName())),
+                ("Frame", str(int(info.frameNumber))),
+                ("Captured", captured),
+                ("Draws", str(frame["draws"])),
+                ("Dispatches", str(frame["dispatches"])),
+                ("Textures / buffers", "%d / %d" % (len(controller.GetTextures()), len(controller.GetBuffers()))),
+            ]
+            for label, value in stats:
+                if value:
+                    out.append("<tr><th>%s</th><td>%s</td></tr>" % (e(label), e(value)))
+            out.append("</table>")
+
+            out.append("<h2>Passes</h2><div class=\"passes\">")
+            for node in passes:
+                out.append('<div class="pass">')
+                if node.get("thumbnail"):
+                    out.append('<img src="data:image/png;base64,%s" alt="%s">' % (node["thumbnail"], e(node["path"][0])))
+                out.append(
+                    "<b>%s</b><br>%d draws, %d dispatches<br>EID %d&ndash;%d</div>"
+                    % (e(node["path"][0]), node["draws"], node["dispatches"], node["first_event"], node["last_draw"])
+                )
+            out.append("</div>")
+
+            if nodes:
+                out.append('<h2>Marker tree</h2><ul class="tree">')
+                depth = 1
+                for node in nodes:
+                    level = len(node["path"])
+                    out.append("<ul>" * (level - depth) + "</ul>" * (depth - level))
+                    depth = level
+                    out.append(
+                        "<li><b>%s</b> &mdash; %d draws, %d dispatches</li>"
+                        % (e(node["path"][-1]), node["draws"], node["dispatches"])
+                    )
+                out.append("</ul>" * depth)
+
+            for key, label, rows, truncated in (
+                ("actions", "Actions", actions, actions_truncated),
+                ("bindings", "Draw bindings", bindings, bindings_truncated),
+            ):
+                out.append("<h2>%s</h2>" % label)
+                if truncated:
+                    out.append('<p class="note">Only the first %d rows are embedded.</p>' % max_rows)
+                out.append('<input class="filter" id="%s-filter" placeholder="Filter...">' % key)
+                out.append('<div class="note" id="%s-note"></div>' % key)
+                columns = ["EID", "Name", "Marker path"]
+                columns += ["Flags"] if key == "actions" else ["Shaders", "Resources"]
+                out.append(
+                    '<table id="%s"><thead><tr>%s</tr></thead><tbody></tbody></table>'
+                    % (key, "".join("<th>%s</th>" % c for c in columns))
+                )
+                out.append('<script type="application/json" id="%s-data">%s</script>' % (key, embed_json(rows)))
+
+            out.append("<script>%s</script></body></html>" % PAGE_SCRIPT)
+
+            with open(report_path, "w", encoding="utf-8") as f:
+                f.write("\n".join(out))
+
+            write_envelope(
+                True,
+                result={
+                    "capture_path": req["capture_path"],
+                    "report_path": report_path,
+                    "thumbnail_count": thumbnails,
+                    "embedded_actions": len(actions),
+                    "embedded_bindings": len(bindings),
+                    "truncated": actions_truncated or bindings_truncated,
+                    "actions_jsonl_path": req["actions_jsonl_path"],
+                    "bindings_jsonl_path": req["bindings_jsonl_path"],
+                },
+            )
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
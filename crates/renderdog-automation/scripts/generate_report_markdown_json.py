@@ -0,0 +1,315 @@
+"""
+generate_report_markdown_json.py -- Write a human-readable Markdown summary of a capture.
+
+Request:
+  - capture_path
+  - output_dir, basename: the report is written as `<basename>.report.md`
+  - marker_depth: deepest marker level listed in the pass tree
+  - top_pipelines, top_targets: rows in the pipeline / target tables
+  - save_images: save RT0 after the last draw of every top-level marker group into
+    `<basename>.report_images/` and link them from the report
+
+Sections: capture metadata, the marker tree with draw/dispatch counts, the pipelines (pipeline
+object, or VS+PS pair on APIs without one) used by the most draws, the largest render/depth targets
+and, if requested, the images. Links are relative so the report can be attached as a PR artifact
+together with its images directory.
+"""
+
+import datetime
+import json
+import os
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "generate_report_markdown_json.request.json"
+RESP_PATH = "generate_report_markdown_json.response.json"
+
+DISPATCH_FLAGS = rd.ActionFlags.Dispatch | getattr(rd.ActionFlags, "DispatchRay", 0)
+
+TARGET_FLAGS = int(rd.TextureCategory.ColorTarget) | int(rd.TextureCategory.DepthTarget)
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def bound_resource_id(br):
+    rid = getattr(br, "resourceId", None)
+    if rid is None:
+        rid = getattr(br, "resource", None)
+    return rid
+
+
+def md_escape(text) -> str:
+    return str(text).replace("|", "\\|").replace("\n", " ")
+
+
+def fmt_bytes(n: int) -> str:
+    for unit in ("B", "KiB", "MiB"):
+        if n < 1024:
+            return "%d %s" % (n, unit) if unit == "B" else "%.1f %s" % (n, unit)
+        n /= 1024.0
+    return "%.1f GiB" % n
+
+
+def safe_name(name: str) -> str:
+    return "".join(c if c.isalnum() or c in "-_." else "_" for c in name).strip("_")[:48]
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    marker_depth = int(req.get("marker_depth", 3))
+    top_pipelines = int(req.get("top_pipelines", 10))
+    top_targets = int(req.get("top_targets", 10))
+    save_images = bool(req.get("save_images", False))
+
+    output_dir = req["output_dir"]
+    basename = req["basename"]
+    os.makedirs(output_dir, exist_ok=True)
+    report_path = os.path.join(output_dir, basename + ".report.md")
+    images_rel = basename + ".report_images"
+    images_dir = os.path.join(output_dir, images_rel)
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            sfile = controller.GetStructuredFile()
+            names = {int(r.resourceId): str(r.name) for r in controller.GetResources()}
+            textures = controller.GetTextures()
+
+            # Marker tree: one node per marker path, counting every draw / dispatch below it.
+            nodes = []
+            work = []
+            frame = {"draws": 0, "dispatches": 0}
+
+            def walk(actions, markers, stack):
+                for a in actions:
+                    if len(a.children) > 0:
+                        path = markers + [str(a.GetName(sfile))]
+                        node = {"path": path, "draws": 0, "dispatches": 0, "first_event": None, "last_draw": None}
+                        nodes.append(node)
+                        walk(a.children, path, stack + [node])
+                    elif a.flags & (rd.ActionFlags.Drawcall | DISPATCH_FLAGS):
+                        is_draw = bool(a.flags & rd.ActionFlags.Drawcall)
+                        key = "draws" if is_draw else "dispatches"
+                        frame[key] += 1
+                        work.append((a, markers, is_draw))
+                        for node in stack:
+                            node[key] += 1
+                            if node["first_event"] is None:
+                                node["first_event"] = int(a.eventId)
+                            if is_draw:
+                                node["last_draw"] = int(a.eventId)
+
+            walk(controller.GetRootActions(), [], [])
+
+            # Pipelines: group draws by pipeline object (or shader pair).
+            pipelines = {}
+            for action, markers, is_draw in work:
+                if not is_draw:
+                    continue
+                controller.SetFrameEvent(int(action.eventId), False)
+                state = controller.GetPipelineState()
+                pipe_id = rd.ResourceId.Null()
+                if hasattr(state, "GetGraphicsPipelineObject"):
+                    pipe_id = state.GetGraphicsPipelineObject()
+                vs = state.GetShader(rd.ShaderStage.Vertex)
+                ps = state.GetShader(rd.ShaderStage.Pixel)
+                if pipe_id != rd.ResourceId.Null():
+                    key = ("pipeline", int(pipe_id))
+                    label = names.get(int(pipe_id)) or "Pipeline %d" % int(pipe_id)
+                else:
+                    key = ("shaders", int(vs), int(ps))
+                    label = "VS %s / PS %s" % (
+                        names.get(int(vs)) or int(vs),
+                        names.get(int(ps)) or int(ps),
+                    )
+                entry = pipelines.setdefault(
+                    key, {"label": label, "draws": 0, "first_event": int(action.eventId), "passes": set()}
+                )
+                entry["draws"] += 1
+                if markers:
+                    entry["passes"].add(markers[0])
+
+            targets = [t for t in textures if int(t.creationFlags) & TARGET_FLAGS]
+            targets.sort(key=lambda t: int(t.byteSize), reverse=True)
+
+            images = []
+            if save_images:
+                os.makedirs(images_dir, exist_ok=True)
+                for node in nodes:
+                    if len(node["path"]) != 1 or node["last_draw"] is None:
+                        continue
+                    controller.SetFrameEvent(node["last_draw"], True)
+                    outs = controller.GetPipelineState().GetOutputTargets()
+                    rid = bound_resource_id(outs[0]) if len(outs) > 0 else None
+                    if rid is None or rid == rd.ResourceId.Null():
+                        continue
+                    save = rd.TextureSave()
+                    save.resourceId = rid
+                    save.destType = rd.FileType.PNG
+                    save.alpha = rd.AlphaMapping.Discard
+                    file_name = "%02d.event%d.%s.png" % (len(images), node["last_draw"], safe_name(node["path"][0]))
+                    if controller.SaveTexture(save, os.path.join(images_dir, file_name)) != rd.ResultCode.Succeeded:
+                        continue
+                    images.append((node, file_name))
+
+            info = controller.GetFrameInfo()
+            api = str(controller.GetAPIProperties().pipelineType).split(".")[-1]
+            captured = ""
+            if int(getattr(info, "captureTime", 0)) > 0:
+                captured = datetime.datetime.fromtimestamp(
+                    int(info.captureTime), datetime.timezone.utc
+                ).strftime("%Y-%m-%d %H:%M:%S UTC")
+
+            lines = ["# Capture report: %s" % os.path.basename(req["capture_path"]), ""]
+            lines += ["## Capture", "", "| | |", "|---|---|"]
+            lines.append("| File | `%s` (%s) |" % (req["capture_path"], fmt_bytes(os.path.getsize(req["capture_path"]))))
+            lines.append("| API | %s (driver: %s) |" % (api, md_escape(cap.DriverName())))
+            lines.append("| Frame | %d |" % int(info.frameNumber))
+            if captured:
+                lines.append("| Captured | %s |" % captured)
+            lines.append("| Draws | %d |" % frame["draws"])
+            lines.append("| Dispatches | %d |" % frame["dispatches"])
+            lines.append("| Textures / buffers | %d / %d |" % (len(textures), len(controller.GetBuffers())))
+            lines.append("")
+
+            lines += ["## Passes", ""]
+            listed = [n for n in nodes if len(n["path"]) <= marker_depth and (n["draws"] or n["dispatches"])]
+            if listed:
+                for node in listed:
+                    lines.append(
+                        "%s- **%s** — %d draws, %d dispatches (from EID %d)"
+                        % (
+                            "  " * (len(node["path"]) - 1),
+                            md_escape(node["path"][-1]),
+                            node["draws"],
+                            node["dispatches"],
+                            node["first_event"],
+                        )
+                    )
+            else:
+                lines.append("_No markers in this capture._")
+            lines.append("")
+
+            lines += ["## Top pipelines", ""]
+            ranked = sorted(pipelines.values(), key=lambda p: (-p["draws"], p["first_event"]))[:top_pipelines]
+            if ranked:
+                lines += ["| Pipeline | Draws | First EID | Passes |", "|---|---:|---:|---|"]
+                for p in ranked:
+                    lines.append(
+                        "| %s | %d | %d | %s |"
+                        % (md_escape(p["label"]), p["draws"], p["first_event"], md_escape(", ".join(sorted(p["passes"]))))
+                    )
+            else:
+                lines.append("_No draws._")
+            lines.append("")
+
+            lines += ["## Largest targets", ""]
+            if targets:
+                lines += ["| Target | Size | Format | Samples | Memory |", "|---|---|---|---:|---:|"]
+                for t in targets[:top_targets]:
+                    rid = int(t.resourceId)
+                    lines.append(
+                        "| %s | %dx%d%s | %s | %d | %s |"
+                        % (
+                            md_escape(names.get(rid) or "Texture %d" % rid),
+                            int(t.width),
+                            int(t.height),
+                            "x%d" % int(t.arraysize) if int(t.arraysize) > 1 else "",
+                            md_escape(t.format.Name()),
+                            int(t.msSamp),
+                            fmt_bytes(int(t.byteSize)),
+                        )
+                    )
+            else:
+                lines.append("_No render or depth targets._")
+            lines.append("")
+
+            if save_images:
+                lines += ["## Images", ""]
+                if images:
+                    for node, file_name in images:
+                        lines.append("### %s (EID %d)" % (md_escape(node["path"][0]), node["last_draw"]))
+                        lines.append("")
+                        lines.append("![%s](%s/%s)" % (md_escape(node["path"][0]), images_rel, file_name))
+                        lines.append("")
+                else:
+                    lines += ["_No top-level pass had a colour target to save._", ""]
+
+            with open(report_path, "w", encoding="utf-8") as f:
+                f.write("\n".join(lines))
+
+            write_envelope(
+                True,
+                result={
+                    "capture_path": req["capture_path"],
+                    "report_path": report_path,
+                    "images_dir": images_dir if save_images else None,
+                    "image_count": len(images),
+                    "total_draws": frame["draws"],
+                    "total_dispatches": frame["dispatches"],
+                    "pipeline_count": len(pipelines),
+                },
+            )
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
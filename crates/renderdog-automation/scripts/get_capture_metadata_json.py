@@ -0,0 +1,176 @@
+"""
+get_capture_metadata_json.py -- RenderDoc Python script that describes a capture before processing it.
+
+Opens the capture through the CaptureFile API and a replay controller and reports what was
+recorded: graphics API, driver, GPU, frame number, capture time and sizes.
+
+The GPU name is not part of RenderDoc's frame description; it is recovered from the device
+creation chunks (`VkPhysicalDeviceProperties::deviceName`, `DXGI_ADAPTER_DESC::Description`,
+`GL_RENDERER`) when the capture recorded them, otherwise it is null.
+
+Request parameters:
+  - capture_path: Path to the .rdc capture file (required)
+
+Returns:
+  - api, driver_name, machine_ident, gpu_vendor, driver_version, gpu_name
+  - frame_number, capture_time (unix seconds)
+  - file_size, compressed_size, uncompressed_size, persistent_size, init_data_size
+  - degraded, replay_gpus
+"""
+
+import json
+import os
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "get_capture_metadata_json.request.json"
+RESP_PATH = "get_capture_metadata_json.response.json"
+
+# Parameter names holding the adapter name in device-creation chunks, per API.
+GPU_NAME_FIELDS = ("deviceName", "Description", "GL_RENDERER", "renderer")
+
+# Device creation happens in the first few chunks; don't scan the whole frame.
+GPU_NAME_SCAN_CHUNKS = 256
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def enum_name(value) -> str:
+    return str(value).split(".")[-1]
+
+
+def find_string_field(obj, names, depth=0):
+    if depth > 8:
+        return None
+    if obj.name in names and obj.type.basetype == rd.SDBasic.String:
+        value = obj.AsString()
+        if value:
+            return value
+    for i in range(obj.NumChildren()):
+        found = find_string_field(obj.GetChild(i), names, depth + 1)
+        if found:
+            return found
+    return None
+
+
+def recorded_gpu_name(structured_file):
+    for chunk in list(structured_file.chunks)[:GPU_NAME_SCAN_CHUNKS]:
+        found = find_string_field(chunk, GPU_NAME_FIELDS)
+        if found:
+            return found
+    return None
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        replay_gpus = []
+        try:
+            replay_gpus = [
+                "%s (%s, %s)" % (g.name, enum_name(g.vendor), enum_name(g.apis))
+                for g in cap.GetAvailableGPUs()
+            ]
+        except Exception:
+            pass
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            props = controller.GetAPIProperties()
+            frame = controller.GetFrameInfo()
+
+            driver_version = None
+            try:
+                driver_version = str(controller.GetDriverInformation().version) or None
+            except Exception:
+                pass
+
+            gpu_name = None
+            try:
+                gpu_name = recorded_gpu_name(controller.GetStructuredFile())
+            except Exception:
+                pass
+
+            write_envelope(
+                True,
+                result={
+                    "capture_path": req["capture_path"],
+                    "api": enum_name(props.pipelineType),
+                    "driver_name": str(cap.DriverName()),
+                    "machine_ident": int(cap.RecordedMachineIdent()),
+                    "gpu_vendor": enum_name(props.vendor),
+                    "driver_version": driver_version,
+                    "gpu_name": gpu_name,
+                    "frame_number": int(frame.frameNumber),
+                    "capture_time": int(frame.captureTime),
+                    "file_size": os.path.getsize(req["capture_path"]),
+                    "compressed_size": int(frame.compressedFileSize),
+                    "uncompressed_size": int(frame.uncompressedFileSize),
+                    "persistent_size": int(frame.persistentSize),
+                    "init_data_size": int(frame.initDataSize),
+                    "degraded": bool(props.degraded),
+                    "replay_gpus": replay_gpus,
+                },
+            )
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
@@ -0,0 +1,286 @@
+"""
+get_constants_json.py -- Decode every bound constant block at an event into typed JSON.
+
+Request:
+  - capture_path
+  - event_id
+  - stage (optional): "Vertex", "TessControl", "TessEval", "Geometry", "Fragment", "Compute",
+    "Task", "Mesh" (case-insensitive; "pixel", "hull", "domain", "vs", "ps", ... also accepted).
+    Omitted means every bound stage.
+
+Each variable node carries its reflected type: structs have `members`, arrays have `elements`,
+leaves have `base_type`, `rows`, `columns` and a `value` shaped as scalar, vector (list) or
+matrix (list of rows). Push constants / root constants appear as blocks with
+`buffer_backed: false`.
+"""
+
+import json
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "get_constants_json.request.json"
+RESP_PATH = "get_constants_json.response.json"
+
+_STAGE_NAMES = {
+    rd.ShaderStage.Vertex: "Vertex",
+    rd.ShaderStage.Tess_Control: "TessControl",
+    rd.ShaderStage.Tess_Eval: "TessEval",
+    rd.ShaderStage.Geometry: "Geometry",
+    rd.ShaderStage.Fragment: "Fragment",
+    rd.ShaderStage.Compute: "Compute",
+    rd.ShaderStage.Task: "Task",
+    rd.ShaderStage.Mesh: "Mesh",
+}
+
+_STAGE_ALIASES = {
+    "vertex": rd.ShaderStage.Vertex,
+    "vs": rd.ShaderStage.Vertex,
+    "tesscontrol": rd.ShaderStage.Tess_Control,
+    "tcs": rd.ShaderStage.Tess_Control,
+    "hull": rd.ShaderStage.Tess_Control,
+    "hs": rd.ShaderStage.Tess_Control,
+    "tesseval": rd.ShaderStage.Tess_Eval,
+    "tes": rd.ShaderStage.Tess_Eval,
+    "domain": rd.ShaderStage.Tess_Eval,
+    "ds": rd.ShaderStage.Tess_Eval,
+    "geometry": rd.ShaderStage.Geometry,
+    "gs": rd.ShaderStage.Geometry,
+    "fragment": rd.ShaderStage.Fragment,
+    "pixel": rd.ShaderStage.Fragment,
+    "fs": rd.ShaderStage.Fragment,
+    "ps": rd.ShaderStage.Fragment,
+    "compute": rd.ShaderStage.Compute,
+    "cs": rd.ShaderStage.Compute,
+    "task": rd.ShaderStage.Task,
+    "amplification": rd.ShaderStage.Task,
+    "mesh": rd.ShaderStage.Mesh,
+}
+
+_BASE_TYPES = {
+    rd.VarType.Float: "float",
+    rd.VarType.Half: "half",
+    rd.VarType.Double: "double",
+    rd.VarType.SInt: "int",
+    rd.VarType.UInt: "uint",
+    rd.VarType.SShort: "short",
+    rd.VarType.UShort: "ushort",
+    rd.VarType.SByte: "sbyte",
+    rd.VarType.UByte: "ubyte",
+    rd.VarType.SLong: "int64",
+    rd.VarType.ULong: "uint64",
+    rd.VarType.Bool: "bool",
+    rd.VarType.GPUPointer: "pointer",
+}
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def leaf_values(var):
+    count = max(int(var.rows), 1) * max(int(var.columns), 1)
+    t = var.type
+    v = var.value
+    if t == rd.VarType.Double:
+        src = v.f64v
+    elif t in (rd.VarType.SInt, rd.VarType.SShort, rd.VarType.SByte):
+        src = v.s32v
+    elif t == rd.VarType.SLong:
+        src = v.s64v
+    elif t in (rd.VarType.ULong, rd.VarType.GPUPointer):
+        src = v.u64v
+    elif t in (rd.VarType.UInt, rd.VarType.UShort, rd.VarType.UByte, rd.VarType.Bool):
+        src = v.u32v
+    else:
+        src = v.f32v
+    values = list(src)[:count]
+    if t == rd.VarType.Bool:
+        return [bool(x) for x in values]
+    if t in (rd.VarType.Float, rd.VarType.Half, rd.VarType.Double):
+        return [float(x) for x in values]
+    return [int(x) for x in values]
+
+
+def decode(var, ctype, byte_offset, as_element=False):
+    """Typed JSON node for a ShaderVariable, guided by its reflected ShaderConstantType."""
+    node = {"name": var.name, "byte_offset": byte_offset}
+    is_array = ctype is not None and int(ctype.elements) > 1 and not as_element
+    if len(var.members) > 0 and (is_array or var.members[0].name.startswith("[")):
+        stride = int(ctype.arrayByteStride) if ctype is not None else 0
+        node["kind"] = "array"
+        if ctype is not None and ctype.name:
+            node["type_name"] = ctype.name
+        node["elements"] = [
+            decode(m, ctype, byte_offset + i * stride, as_element=True) for i, m in enumerate(var.members)
+        ]
+        return node
+    if len(var.members) > 0:
+        node["kind"] = "struct"
+        if ctype is not None and ctype.name:
+            node["type_name"] = ctype.name
+        reflected = {m.name: m for m in ctype.members} if ctype is not None else {}
+        members = []
+        for m in var.members:
+            rc = reflected.get(m.name)
+            members.append(
+                decode(m, rc.type if rc is not None else None, byte_offset + (int(rc.byteOffset) if rc else 0))
+            )
+        node["members"] = members
+        return node
+
+    rows = max(int(var.rows), 1)
+    cols = max(int(var.columns), 1)
+    values = leaf_values(var)
+    node["base_type"] = _BASE_TYPES.get(var.type, str(var.type).split(".")[-1])
+    node["rows"] = rows
+    node["columns"] = cols
+    if rows == 1 and cols == 1:
+        node["kind"] = "scalar"
+        node["value"] = values[0] if values else None
+    elif rows == 1:
+        node["kind"] = "vector"
+        node["value"] = values
+    else:
+        node["kind"] = "matrix"
+        node["row_major"] = bool(var.RowMajor()) if hasattr(var, "RowMajor") else True
+        node["value"] = [values[r * cols : (r + 1) * cols] for r in range(rows)]
+    return node
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    event_id = int(req["event_id"])
+    stage_filter = req.get("stage")
+    if stage_filter is not None:
+        key = str(stage_filter).lower().replace("_", "").replace(" ", "")
+        if key not in _STAGE_ALIASES:
+            raise RuntimeError("Unknown shader stage: " + str(stage_filter))
+        stage_filter = _STAGE_ALIASES[key]
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            controller.SetFrameEvent(event_id, True)
+            state = controller.GetPipelineState()
+            names = {int(r.resourceId): str(r.name) for r in controller.GetResources()}
+
+            blocks = []
+            for stage, stage_name in _STAGE_NAMES.items():
+                if stage_filter is not None and stage != stage_filter:
+                    continue
+                refl = state.GetShaderReflection(stage)
+                if refl is None:
+                    continue
+                if stage == rd.ShaderStage.Compute:
+                    pipe_obj = state.GetComputePipelineObject()
+                else:
+                    pipe_obj = state.GetGraphicsPipelineObject()
+                entry_point = state.GetShaderEntryPoint(stage)
+
+                for cb_idx, cb_refl in enumerate(refl.constantBlocks):
+                    used = state.GetConstantBlock(stage, cb_idx, 0)
+                    desc = used.descriptor
+                    resource = desc.resource
+                    if cb_refl.bufferBacked and resource == rd.ResourceId.Null():
+                        continue
+                    var_list = controller.GetCBufferVariableContents(
+                        pipe_obj,
+                        refl.resourceId,
+                        stage,
+                        entry_point,
+                        cb_idx,
+                        resource,
+                        int(desc.byteOffset),
+                        int(desc.byteSize),
+                    )
+                    reflected = {c.name: c for c in cb_refl.variables}
+                    variables = []
+                    for v in var_list:
+                        rc = reflected.get(v.name)
+                        variables.append(decode(v, rc.type if rc is not None else None, int(rc.byteOffset) if rc else 0))
+
+                    rid = int(resource)
+                    blocks.append(
+                        {
+                            "stage": stage_name,
+                            "name": cb_refl.name,
+                            "set": int(cb_refl.fixedBindSetOrSpace),
+                            "binding": int(cb_refl.fixedBindNumber),
+                            "buffer_backed": bool(cb_refl.bufferBacked),
+                            "resource_id": rid or None,
+                            "resource_name": names.get(rid) if rid else None,
+                            "byte_offset": int(desc.byteOffset),
+                            "byte_size": int(cb_refl.byteSize),
+                            "variables": variables,
+                        }
+                    )
+
+            write_envelope(
+                True,
+                result={
+                    "capture_path": req["capture_path"],
+                    "event_id": event_id,
+                    "blocks": blocks,
+                },
+            )
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
@@ -0,0 +1,209 @@
+"""
+get_descriptor_arrays_json.py -- Enumerate the elements of descriptor arrays bound at an event.
+
+Request:
+  - capture_path
+  - event_id
+  - name_contains (optional): only arrays whose reflected name contains this (case-insensitive)
+  - max_elements: cap per array (unbounded / bindless arrays are read up to this many elements)
+  - only_indexed: list only the elements the draw accessed
+
+Every reflected binding with an array size > 1 (or unbounded) is read from its descriptor store
+(descriptor set, descriptor heap or descriptor buffer) starting at element 0, and each element is
+resolved to its resource. `indexed` marks the elements the draw accessed according to RenderDoc's
+descriptor access list, which includes dynamically indexed elements when bindless feedback is
+available. Arrays RenderDoc reported no access for have `usage_known: false` and no elements,
+since the access is what locates the array in its descriptor store.
+"""
+
+import json
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "get_descriptor_arrays_json.request.json"
+RESP_PATH = "get_descriptor_arrays_json.response.json"
+
+_STAGE_NAMES = {
+    rd.ShaderStage.Vertex: "Vertex",
+    rd.ShaderStage.Tess_Control: "TessControl",
+    rd.ShaderStage.Tess_Eval: "TessEval",
+    rd.ShaderStage.Geometry: "Geometry",
+    rd.ShaderStage.Fragment: "Fragment",
+    rd.ShaderStage.Compute: "Compute",
+    rd.ShaderStage.Task: "Task",
+    rd.ShaderStage.Mesh: "Mesh",
+}
+
+UNBOUNDED = 0xFFFFFFFF
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def reflection_groups(refl):
+    return [
+        ("ConstantBlock", rd.DescriptorCategory.ConstantBlock, refl.constantBlocks),
+        ("Sampler", rd.DescriptorCategory.Sampler, refl.samplers),
+        ("ReadOnlyResource", rd.DescriptorCategory.ReadOnlyResource, refl.readOnlyResources),
+        ("ReadWriteResource", rd.DescriptorCategory.ReadWriteResource, refl.readWriteResources),
+    ]
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    event_id = int(req["event_id"])
+    name_filter = (req.get("name_contains") or "").lower()
+    max_elements = int(req.get("max_elements", 1024))
+    only_indexed = bool(req.get("only_indexed", False))
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            controller.SetFrameEvent(event_id, True)
+            state = controller.GetPipelineState()
+            names = {int(r.resourceId): str(r.name) for r in controller.GetResources()}
+
+            # (stage, category, reflection index) -> [DescriptorAccess]
+            accesses = {}
+            for access in controller.GetDescriptorAccess():
+                category = rd.CategoryForDescriptorType(access.type)
+                key = (access.stage, category, int(access.index))
+                accesses.setdefault(key, []).append(access)
+
+            arrays = []
+            for stage, stage_name in _STAGE_NAMES.items():
+                refl = state.GetShaderReflection(stage)
+                if refl is None:
+                    continue
+                for category_name, category, group in reflection_groups(refl):
+                    for index, res in enumerate(group):
+                        declared = int(getattr(res, "bindArraySize", 1))
+                        if declared <= 1:
+                            continue
+                        if name_filter and name_filter not in str(res.name).lower():
+                            continue
+
+                        entry = {
+                            "stage": stage_name,
+                            "category": category_name,
+                            "name": str(res.name),
+                            "set": int(res.fixedBindSetOrSpace),
+                            "binding": int(res.fixedBindNumber),
+                            "array_size": None if declared == UNBOUNDED else declared,
+                            "descriptor_store": None,
+                            "usage_known": False,
+                            "truncated": False,
+                            "elements": [],
+                        }
+                        arrays.append(entry)
+
+                        found = [
+                            a for a in accesses.get((stage, category, index), []) if not a.staticallyUnused
+                        ]
+                        if not found:
+                            continue
+                        entry["usage_known"] = True
+                        indexed = {int(a.arrayElement) for a in found}
+
+                        # Element 0 of the array sits arrayElement descriptors before any access.
+                        base = found[0]
+                        rng = rd.DescriptorRange(base)
+                        step = int(rng.descriptorSize)
+                        rng.offset = int(base.byteOffset) - int(base.arrayElement) * step
+                        count = min(declared, max_elements)
+                        entry["truncated"] = declared > max_elements
+                        rng.count = count
+                        entry["descriptor_store"] = int(base.descriptorStore)
+
+                        if category == rd.DescriptorCategory.Sampler:
+                            descriptors = controller.GetSamplerDescriptors(base.descriptorStore, [rng])
+                        else:
+                            descriptors = controller.GetDescriptors(base.descriptorStore, [rng])
+
+                        for element, desc in enumerate(descriptors):
+                            used = element in indexed
+                            if only_indexed and not used:
+                                continue
+                            rid = int(getattr(desc, "resource", rd.ResourceId.Null()))
+                            if category == rd.DescriptorCategory.Sampler:
+                                rid = int(getattr(desc, "object", rd.ResourceId.Null()))
+                            entry["elements"].append(
+                                {
+                                    "index": element,
+                                    "resource_id": rid or None,
+                                    "resource_name": names.get(rid) if rid else None,
+                                    "descriptor_type": str(getattr(desc, "type", "")).split(".")[-1] or None,
+                                    "indexed": used,
+                                }
+                            )
+
+            write_envelope(
+                True,
+                result={
+                    "capture_path": req["capture_path"],
+                    "event_id": event_id,
+                    "arrays": arrays,
+                },
+            )
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
@@ -0,0 +1,186 @@
+"""
+get_event_api_call_json.py -- RenderDoc Python script that dumps the structured chunk of one event.
+
+Returns the API call recorded for `event_id` exactly as RenderDoc serialized it: every parameter
+(including nested create-info structs, arrays, flags and handles) as a typed tree, plus the chunk
+metadata (thread, timestamp, duration). Works for any event, not only actions, so state-setting
+calls such as vkCmdBindDescriptorSets or OMSetRenderTargets can be inspected too.
+
+Request parameters:
+  - capture_path: Path to the .rdc capture file (required)
+  - event_id: Event to look up (required)
+
+Returns:
+  - event_id, chunk_index, name, action_event_id, action_name
+  - thread_id, timestamp_us, duration_us
+  - parameters: [{name, type, basetype, value?, resource_name?, enum_value?, members?}]
+"""
+
+import json
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "get_event_api_call_json.request.json"
+RESP_PATH = "get_event_api_call_json.response.json"
+
+MAX_DEPTH = 32
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def find_event(actions, event_id: int):
+    """(action, APIEvent) whose event list contains event_id, or (None, None)."""
+    for a in actions:
+        for ev in a.events:
+            if int(ev.eventId) == event_id:
+                return a, ev
+        found = find_event(a.children, event_id)
+        if found[0] is not None:
+            return found
+    return None, None
+
+
+def sd_node(obj, names, depth=0):
+    """Lossless, typed JSON tree for an SDObject."""
+    bt = obj.type.basetype
+    node = {
+        "name": str(obj.name),
+        "type": str(obj.type.name),
+        "basetype": str(bt).split(".")[-1],
+    }
+
+    if bt in (rd.SDBasic.Chunk, rd.SDBasic.Struct, rd.SDBasic.Array):
+        if depth >= MAX_DEPTH:
+            node["value"] = "<max depth>"
+        else:
+            node["members"] = [
+                sd_node(obj.GetChild(i), names, depth + 1) for i in range(obj.NumChildren())
+            ]
+    elif bt == rd.SDBasic.Enum:
+        node["value"] = obj.AsString()
+        node["enum_value"] = int(obj.AsInt())
+    elif bt in (rd.SDBasic.String, rd.SDBasic.Character):
+        node["value"] = obj.AsString()
+    elif bt == rd.SDBasic.Boolean:
+        node["value"] = bool(obj.AsBool())
+    elif bt == rd.SDBasic.Float:
+        node["value"] = float(obj.AsFloat())
+    elif bt == rd.SDBasic.UnsignedInteger:
+        node["value"] = int(obj.AsInt())
+    elif bt == rd.SDBasic.SignedInteger:
+        node["value"] = int(obj.AsInt())
+    elif bt == rd.SDBasic.Resource:
+        rid = obj.AsResourceId()
+        node["value"] = str(rid)
+        if rid != rd.ResourceId.Null():
+            node["resource_name"] = names.get(str(rid), str(rid))
+    elif bt == rd.SDBasic.Null:
+        node["value"] = None
+    elif bt == rd.SDBasic.Buffer:
+        node["value"] = "<%d byte buffer>" % int(obj.type.byteSize)
+    else:
+        node["value"] = obj.AsString()
+
+    return node
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    event_id = int(req["event_id"])
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            structured_file = controller.GetStructuredFile()
+            action, ev = find_event(controller.GetRootActions(), event_id)
+            if ev is None:
+                raise RuntimeError("Event %d not found in capture" % event_id)
+            if ev.chunkIndex >= len(structured_file.chunks):
+                raise RuntimeError(
+                    "Event %d has no structured data (chunk %d)" % (event_id, ev.chunkIndex)
+                )
+
+            names = {str(r.resourceId): r.name for r in controller.GetResources()}
+            chunk = structured_file.chunks[ev.chunkIndex]
+            meta = chunk.metadata
+
+            write_envelope(
+                True,
+                result={
+                    "capture_path": req["capture_path"],
+                    "event_id": event_id,
+                    "chunk_index": int(ev.chunkIndex),
+                    "name": str(chunk.name),
+                    "action_event_id": int(action.eventId),
+                    "action_name": str(action.GetName(structured_file)),
+                    "thread_id": int(meta.threadID),
+                    "timestamp_us": int(meta.timestampMicro),
+                    "duration_us": int(meta.durationMicro),
+                    "parameters": [
+                        sd_node(chunk.GetChild(i), names, 1) for i in range(chunk.NumChildren())
+                    ],
+                },
+            )
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
@@ -9,6 +9,8 @@ Outputs a JSON object with:
   - resources: all RO + RW resource bindings (buffers, textures, etc.)
   - uniforms: all constant/uniform buffer bindings with variable contents
   - samplers: all sampler bindings
+  - push_constants: Vulkan push constant bytes, the pipeline layout's ranges and the
+    reflected push constant blocks decoded per stage (null on other APIs)
 """
 
 import json
@@ -27,6 +29,30 @@ def write_envelope(ok: bool, result=None, error: str = None) -> None:
         json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
 
 
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
 # ---------------------------------------------------------------------------
 # Helper functions
 # ---------------------------------------------------------------------------
@@ -479,6 +505,92 @@ def _get_fragment_state(state, controller):
 # Main logic
 # ---------------------------------------------------------------------------
 
+def _push_constant_ranges(controller, layout_rid):
+    """Push constant ranges declared by a VkPipelineLayout, from its create chunk."""
+    if layout_rid is None or layout_rid == rd.ResourceId.Null():
+        return []
+    res_desc = None
+    for res in controller.GetResources():
+        if res.resourceId == layout_rid:
+            res_desc = res
+            break
+    if res_desc is None:
+        return []
+
+    sfile = controller.GetStructuredFile()
+    ranges = []
+    for chunk_idx in res_desc.initialisationChunks:
+        chunk = sfile.chunks[chunk_idx]
+        if "CreatePipelineLayout" not in chunk.name:
+            continue
+        for child in chunk.data.children:
+            if child.name not in ("CreateInfo", "pCreateInfo"):
+                continue
+            for info_child in child.data.children:
+                if info_child.name != "pPushConstantRanges":
+                    continue
+                for rng in info_child.data.children:
+                    entry = {}
+                    for prop in rng.data.children:
+                        if prop.name == "stageFlags":
+                            entry["stageFlags"] = prop.data.str or str(prop.data.basic.u)
+                        elif prop.name in ("offset", "size"):
+                            entry[prop.name] = prop.data.basic.u
+                    ranges.append(entry)
+    return ranges
+
+
+def _get_push_constants(controller, state, stages_to_check, pipe_obj, is_compute):
+    """Raw push constant bytes plus typed, reflection-decoded blocks (Vulkan only)."""
+    try:
+        vk = controller.GetVulkanPipelineState()
+    except Exception:
+        vk = None
+    if vk is None:
+        return None
+
+    raw = bytes(vk.pushconsts)
+    pipe = vk.compute if is_compute else vk.graphics
+    layout_rid = None
+    for attr in ("pipelineComputeLayoutResourceId", "pipelinePreRastLayoutResourceId",
+                 "pipelineFragmentLayoutResourceId", "pipelineLayoutResourceId"):
+        rid = getattr(pipe, attr, None)
+        if rid is not None and rid != rd.ResourceId.Null():
+            layout_rid = rid
+            break
+
+    blocks = []
+    for stage_name, stage in stages_to_check:
+        refl = state.GetShaderReflection(stage)
+        if refl is None:
+            continue
+        entry_point = state.GetShaderEntryPoint(stage)
+        for cb_idx, cb_refl in enumerate(refl.constantBlocks):
+            if cb_refl.bufferBacked:
+                continue
+            try:
+                var_list = controller.GetCBufferVariableContents(
+                    pipe_obj, refl.resourceId, stage, entry_point,
+                    cb_idx, rd.ResourceId.Null(), 0, 0
+                )
+                variables = [_shader_var_to_dict(v) for v in var_list]
+            except Exception as e:
+                variables = [{"error": str(e)}]
+            blocks.append({
+                "stage": stage_name,
+                "name": cb_refl.name,
+                "byteSize": cb_refl.byteSize,
+                "variables": variables,
+            })
+
+    return {
+        "size": len(raw),
+        "bytes": raw.hex(),
+        "ranges": _push_constant_ranges(controller, layout_rid),
+        "blocks": blocks,
+    }
+
+
 def run_on_controller(controller, event_id):
     """Core logic to extract pipeline state."""
     controller.SetFrameEvent(event_id, False)
@@ -509,6 +621,7 @@ def run_on_controller(controller, event_id):
         "resources": [],
         "uniforms": [],
         "samplers": [],
+        "push_constants": None,
     }
 
     # --- Stages ---
@@ -719,6 +832,9 @@ def run_on_controller(controller, event_id):
 
             result["uniforms"].append(uniform_entry)
 
+    # --- Push constants (Vulkan) ---
+    result["push_constants"] = _get_push_constants(controller, state, stages_to_check, pipe_obj, is_compute)
+
     # --- Samplers ---
     for stage_name, stage in stages_to_check:
         refl = state.GetShaderReflection(stage)
@@ -759,7 +875,7 @@ def main() -> None:
         if not cap.LocalReplaySupport():
             raise RuntimeError("Capture cannot be replayed")
 
-        result, controller = cap.OpenCapture(rd.ReplayOptions(), None)
+        result, controller = cap.OpenCapture(replay_options(req), None)
         if result != rd.ResultCode.Succeeded:
             raise RuntimeError("Couldn't initialise replay: " + str(result))
 
@@ -0,0 +1,178 @@
+"""
+get_indices_json.py -- Read the index buffer of an indexed draw.
+
+Request parameters:
+  - capture_path
+  - event_id
+  - first, count: index range relative to the draw's index offset
+
+Returns the raw index values plus the vertex range addressable by the bound vertex buffers, so
+out-of-range indices can be spotted without opening the UI.
+"""
+
+import json
+import struct
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "get_indices_json.request.json"
+RESP_PATH = "get_indices_json.response.json"
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def find_action(controller, event_id):
+    def walk(actions):
+        for a in actions:
+            if a.eventId == event_id:
+                return a
+            found = walk(a.children)
+            if found is not None:
+                return found
+        return None
+
+    action = walk(controller.GetRootActions())
+    if action is None:
+        raise RuntimeError("No action with event_id %d" % event_id)
+    if not (action.flags & rd.ActionFlags.Drawcall):
+        raise RuntimeError("Event %d is not a drawcall: %s" % (event_id, action.GetName(controller.GetStructuredFile())))
+    return action
+
+
+def max_addressable_vertex(controller, state):
+    """Largest vertex index every per-vertex attribute can read, or None if unknown."""
+    sizes = {b.resourceId: int(b.length) for b in controller.GetBuffers()}
+    vbs = state.GetVBuffers()
+    limit = None
+    for a in state.GetVertexInputs():
+        if not a.used or a.perInstance or a.vertexBuffer >= len(vbs):
+            continue
+        vb = vbs[a.vertexBuffer]
+        if vb.resourceId == rd.ResourceId.Null() or vb.byteStride == 0 or vb.resourceId not in sizes:
+            continue
+        size = sizes[vb.resourceId]
+        if vb.byteSize and vb.byteSize < size - vb.byteOffset:
+            size = vb.byteOffset + vb.byteSize
+        element = a.format.compCount * a.format.compByteWidth
+        if a.format.type != rd.ResourceFormatType.Regular:
+            element = 4
+        avail = size - vb.byteOffset - a.byteOffset - element
+        if avail < 0:
+            return 0
+        count = avail // vb.byteStride
+        limit = count if limit is None else min(limit, count)
+    return limit
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    event_id = int(req["event_id"])
+    first = int(req.get("first", 0))
+    count = int(req["count"])
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            action = find_action(controller, event_id)
+            if not (action.flags & rd.ActionFlags.Indexed):
+                raise RuntimeError("Event %d is not an indexed draw" % event_id)
+            controller.SetFrameEvent(event_id, True)
+            state = controller.GetPipelineState()
+
+            ib = state.GetIBuffer()
+            if ib.resourceId == rd.ResourceId.Null() or ib.byteStride not in (1, 2, 4):
+                raise RuntimeError("Indexed draw at event %d has no index buffer bound" % event_id)
+
+            stride = int(ib.byteStride)
+            total = int(action.numIndices)
+            end = min(first + count, total)
+            indices = []
+            if end > first:
+                offset = ib.byteOffset + (action.indexOffset + first) * stride
+                data = controller.GetBufferData(ib.resourceId, offset, (end - first) * stride)
+                n = min(end - first, len(data) // stride)
+                fmt = {1: "B", 2: "H", 4: "I"}[stride]
+                indices = list(struct.unpack_from("<%d%s" % (n, fmt), data, 0))
+
+            max_vertex = max_addressable_vertex(controller, state)
+            out_of_range = []
+            if max_vertex is not None:
+                out_of_range = [i for i, v in enumerate(indices) if v + action.baseVertex > max_vertex]
+
+            write_envelope(
+                True,
+                result={
+                    "capture_path": req["capture_path"],
+                    "event_id": event_id,
+                    "index_byte_width": stride,
+                    "index_offset": int(action.indexOffset),
+                    "base_vertex": int(action.baseVertex),
+                    "total_indices": total,
+                    "indices": [int(v) for v in indices],
+                    "max_vertex": max_vertex,
+                    "out_of_range": out_of_range,
+                },
+            )
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
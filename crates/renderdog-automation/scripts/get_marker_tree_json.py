@@ -0,0 +1,182 @@
+"""
+get_marker_tree_json.py -- RenderDoc Python script that returns the nested debug marker tree.
+
+Unlike the JSONL exports, which flatten every action to a marker path, this keeps the hierarchy:
+one node per marker region (PushMarker action), nested as recorded. Non-marker actions with
+children (command lists, multi-draws) are transparent; their contents count towards the
+enclosing marker.
+
+Per node:
+  - name, event_id (the marker's own event), last_event_id (last event inside the region)
+  - num_children: direct child actions (markers included), num_child_markers: direct child markers
+  - draws / dispatches / actions: totals over the whole region, nested markers included
+  - children: nested marker nodes (omitted below max_depth, but still counted)
+
+Request parameters:
+  - capture_path: Path to the .rdc capture file (required)
+  - max_depth: Deepest marker level to return as nodes (optional, 1 = top-level markers only)
+
+Returns:
+  - total_markers, max_marker_depth, unmarked_draws, unmarked_dispatches, roots
+"""
+
+import json
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "get_marker_tree_json.request.json"
+RESP_PATH = "get_marker_tree_json.response.json"
+
+DISPATCH_FLAGS = rd.ActionFlags.Dispatch | rd.ActionFlags.MeshDispatch | rd.ActionFlags.DispatchRay
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def last_event_id(a) -> int:
+    while len(a.children) > 0:
+        a = a.children[-1]
+    return int(a.eventId)
+
+
+class TreeBuilder:
+    def __init__(self, sfile, max_depth):
+        self.sfile = sfile
+        self.max_depth = max_depth
+        self.total_markers = 0
+        self.max_marker_depth = 0
+
+    def new_node(self, a, depth):
+        return {
+            "name": str(a.GetName(self.sfile)),
+            "event_id": int(a.eventId),
+            "last_event_id": last_event_id(a),
+            "depth": depth,
+            "num_children": len(a.children),
+            "num_child_markers": 0,
+            "draws": 0,
+            "dispatches": 0,
+            "actions": 0,
+            "children": [],
+        }
+
+    def walk(self, actions, node, nodes, depth):
+        """Add `actions` to `node` (None at the root); marker nodes go into `nodes`.
+
+        Returns the (draws, dispatches, actions) totals of `actions` including nested regions.
+        """
+        draws = dispatches = count = 0
+        for a in actions:
+            count += 1
+            if a.flags & rd.ActionFlags.Drawcall:
+                draws += 1
+            if a.flags & DISPATCH_FLAGS:
+                dispatches += 1
+
+            if a.flags & rd.ActionFlags.PushMarker:
+                self.total_markers += 1
+                self.max_marker_depth = max(self.max_marker_depth, depth + 1)
+                if node is not None:
+                    node["num_child_markers"] += 1
+                child = self.new_node(a, depth + 1)
+                d, x, c = self.walk(a.children, child, child["children"], depth + 1)
+                child["draws"], child["dispatches"], child["actions"] = d, x, c
+                if self.max_depth is None or depth + 1 <= self.max_depth:
+                    nodes.append(child)
+            else:
+                d, x, c = self.walk(a.children, node, nodes, depth)
+            draws += d
+            dispatches += x
+            count += c
+        return draws, dispatches, count
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    max_depth = req.get("max_depth")
+    # Top-level markers are always returned so the unmarked totals below stay exact.
+    max_depth = max(int(max_depth), 1) if max_depth is not None else None
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            builder = TreeBuilder(controller.GetStructuredFile(), max_depth)
+            roots = []
+            draws, dispatches, _ = builder.walk(controller.GetRootActions(), None, roots, 0)
+
+            unmarked_draws = draws - sum(r["draws"] for r in roots)
+            unmarked_dispatches = dispatches - sum(r["dispatches"] for r in roots)
+
+            write_envelope(
+                True,
+                result={
+                    "capture_path": req["capture_path"],
+                    "total_markers": builder.total_markers,
+                    "max_marker_depth": builder.max_marker_depth,
+                    "unmarked_draws": unmarked_draws,
+                    "unmarked_dispatches": unmarked_dispatches,
+                    "roots": roots,
+                },
+            )
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
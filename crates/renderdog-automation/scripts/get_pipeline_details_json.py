@@ -11,8 +11,19 @@ Finds a pipeline by name and returns detailed information including:
   - render_targets: For graphics pipelines, output targets info
   - event_ids: Events where this pipeline is used
 
+API-specific create info, parsed from the structured file:
+  - vulkan_create_info: VkGraphicsPipelineCreateInfo (Vulkan graphics pipelines)
+  - d3d12_create_info: the PSO desc (root signature, shader bytecode sizes, input layout,
+    rasterizer / blend / depth-stencil descs, RTV / DSV formats, sample desc)
+  - d3d11_create_info: the input layout and rasterizer / blend / depth-stencil state objects
+    bound at the first event using the pipeline, with their creation descs
+  - gl_pipeline_state: program and attached shaders, vertex array layout, draw framebuffer
+    attachments and glEnable state at the first event using the pipeline
+
 Request parameters:
-  - pipeline_name: Name of the pipeline to inspect
+  - pipeline_name: Name of the pipeline to inspect (on D3D11, which has no pipeline objects,
+    the name of a shader; on OpenGL, of a program or program pipeline; the pipeline is then
+    every action binding it)
   - capture_path: Path to the .rdc capture file
 
 Returns:
@@ -22,6 +33,7 @@ Returns:
 """
 
 import json
+import struct
 import traceback
 
 import renderdoc as rd
@@ -36,6 +48,30 @@ def write_envelope(ok: bool, result=None, error: str = None) -> None:
         json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
 
 
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
 # ---------------------------------------------------------------------------
 # Type schema helpers (for buffer layout inference)
 # ---------------------------------------------------------------------------
@@ -858,6 +894,721 @@ def parse_dynamic_state(dyn_state):
     return states if states else None
 
 
+# ---------------------------------------------------------------------------
+# D3D11 / D3D12 Create Info Extraction from Structured File
+# ---------------------------------------------------------------------------
+
+_D3D12_SHADER_FIELDS = [
+    ("VS", "Vertex"),
+    ("HS", "TessControl"),
+    ("DS", "TessEval"),
+    ("GS", "Geometry"),
+    ("PS", "Fragment"),
+    ("AS", "Task"),
+    ("MS", "Mesh"),
+    ("CS", "Compute"),
+]
+
+_D3D12_ROOT_PARAMETER_TYPES = {0: "DESCRIPTOR_TABLE", 1: "32BIT_CONSTANTS", 2: "CBV", 3: "SRV", 4: "UAV"}
+
+_D3D12_SHADER_VISIBILITY = {
+    0: "ALL",
+    1: "VERTEX",
+    2: "HULL",
+    3: "DOMAIN",
+    4: "GEOMETRY",
+    5: "PIXEL",
+    6: "AMPLIFICATION",
+    7: "MESH",
+}
+
+_D3D12_DESCRIPTOR_RANGE_TYPES = {0: "SRV", 1: "UAV", 2: "CBV", 3: "SAMPLER"}
+
+_D3D12_ROOT_SIGNATURE_FLAGS = {
+    0x1: "ALLOW_INPUT_ASSEMBLER_INPUT_LAYOUT",
+    0x2: "DENY_VERTEX_SHADER_ROOT_ACCESS",
+    0x4: "DENY_HULL_SHADER_ROOT_ACCESS",
+    0x8: "DENY_DOMAIN_SHADER_ROOT_ACCESS",
+    0x10: "DENY_GEOMETRY_SHADER_ROOT_ACCESS",
+    0x20: "DENY_PIXEL_SHADER_ROOT_ACCESS",
+    0x40: "ALLOW_STREAM_OUTPUT",
+    0x80: "LOCAL_ROOT_SIGNATURE",
+    0x100: "DENY_AMPLIFICATION_SHADER_ROOT_ACCESS",
+    0x200: "DENY_MESH_SHADER_ROOT_ACCESS",
+    0x400: "CBV_SRV_UAV_HEAP_DIRECTLY_INDEXED",
+    0x800: "SAMPLER_HEAP_DIRECTLY_INDEXED",
+}
+
+_D3D12_FILTERS = {
+    0x0: "MIN_MAG_MIP_POINT",
+    0x1: "MIN_MAG_POINT_MIP_LINEAR",
+    0x4: "MIN_POINT_MAG_LINEAR_MIP_POINT",
+    0x5: "MIN_POINT_MAG_MIP_LINEAR",
+    0x10: "MIN_LINEAR_MAG_MIP_POINT",
+    0x11: "MIN_LINEAR_MAG_POINT_MIP_LINEAR",
+    0x14: "MIN_MAG_LINEAR_MIP_POINT",
+    0x15: "MIN_MAG_MIP_LINEAR",
+    0x55: "ANISOTROPIC",
+    0x80: "COMPARISON_MIN_MAG_MIP_POINT",
+    0x95: "COMPARISON_MIN_MAG_MIP_LINEAR",
+    0xD5: "COMPARISON_ANISOTROPIC",
+}
+
+_D3D12_ADDRESS_MODES = {1: "WRAP", 2: "MIRROR", 3: "CLAMP", 4: "BORDER", 5: "MIRROR_ONCE"}
+
+_D3D12_COMPARISON_FUNCS = {
+    0: "NONE",
+    1: "NEVER",
+    2: "LESS",
+    3: "EQUAL",
+    4: "LESS_EQUAL",
+    5: "GREATER",
+    6: "NOT_EQUAL",
+    7: "GREATER_EQUAL",
+    8: "ALWAYS",
+}
+
+# D3D12_DESCRIPTOR_RANGE_OFFSET_APPEND / UINT_MAX descriptor count (unbounded range).
+_D3D12_UINT_MAX = 0xFFFFFFFF
+
+
+def d3d_enum(value, prefix):
+    """Strip the `D3D1x_<prefix>` / `DXGI_<prefix>` part of a D3D enum name."""
+    if value is None:
+        return None
+    text = str(value)
+    for api in ("D3D12_", "D3D11_", "DXGI_"):
+        if text.startswith(api + prefix):
+            return text[len(api + prefix):]
+    return text
+
+
+def d3d_bool(obj, name):
+    value = get_sd_child_value(obj, name)
+    return bool(value) if value is not None else None
+
+
+def find_desc_param(chunk, type_fragment):
+    """The first top-level chunk parameter whose type name contains `type_fragment`."""
+    for child in sd_children_list(chunk):
+        if type_fragment in str(child.type.name):
+            return child
+    return None
+
+
+def sd_buffer_bytes(sfile, obj):
+    """Bytes of a serialised buffer parameter (stored by index in the structured file)."""
+    for index in (lambda: obj.AsInt(), lambda: obj.data.basic.u):
+        try:
+            return bytes(sfile.buffers[int(index())])
+        except Exception:
+            continue
+    return None
+
+
+def parse_d3d_input_layout(elements_obj):
+    """Parse D3D11/D3D12_INPUT_ELEMENT_DESC array."""
+    elements = []
+    for el in sd_children_list(elements_obj):
+        offset = get_sd_child_value(el, "AlignedByteOffset", 0)
+        elements.append({
+            "semantic_name": str(get_sd_child_value(el, "SemanticName", "")),
+            "semantic_index": get_sd_child_value(el, "SemanticIndex", 0),
+            "format": d3d_enum(get_sd_child_value(el, "Format", ""), "FORMAT_"),
+            "input_slot": get_sd_child_value(el, "InputSlot", 0),
+            "aligned_byte_offset": None if offset == _D3D12_UINT_MAX else offset,
+            "input_slot_class": d3d_enum(get_sd_child_value(el, "InputSlotClass", ""), "INPUT_"),
+            "instance_data_step_rate": get_sd_child_value(el, "InstanceDataStepRate", 0),
+        })
+    return elements
+
+
+def parse_d3d_rasterizer_desc(desc):
+    """Parse D3D11_RASTERIZER_DESC(2) / D3D12_RASTERIZER_DESC(1/2)."""
+    result = {
+        "fill_mode": d3d_enum(get_sd_child_value(desc, "FillMode"), "FILL_MODE_"),
+        "cull_mode": d3d_enum(get_sd_child_value(desc, "CullMode"), "CULL_MODE_"),
+        "front_counter_clockwise": d3d_bool(desc, "FrontCounterClockwise"),
+        "depth_bias": get_sd_child_value(desc, "DepthBias"),
+        "depth_bias_clamp": get_sd_child_value(desc, "DepthBiasClamp"),
+        "slope_scaled_depth_bias": get_sd_child_value(desc, "SlopeScaledDepthBias"),
+        "depth_clip_enable": d3d_bool(desc, "DepthClipEnable"),
+        "scissor_enable": d3d_bool(desc, "ScissorEnable"),
+        "multisample_enable": d3d_bool(desc, "MultisampleEnable"),
+        "antialiased_line_enable": d3d_bool(desc, "AntialiasedLineEnable"),
+        "forced_sample_count": get_sd_child_value(desc, "ForcedSampleCount"),
+        "conservative_raster": d3d_enum(
+            get_sd_child_value(desc, "ConservativeRaster"), "CONSERVATIVE_RASTERIZATION_MODE_"
+        ),
+    }
+    result = {k: v for k, v in result.items() if v is not None}
+    return result if result else None
+
+
+def write_mask_to_string(mask):
+    if mask is None:
+        return None
+    return "".join(c for bit, c in ((1, "R"), (2, "G"), (4, "B"), (8, "A")) if int(mask) & bit)
+
+
+def parse_d3d_blend_desc(desc, num_render_targets=None):
+    """Parse D3D11_BLEND_DESC(1) / D3D12_BLEND_DESC.
+
+    Without independent blending only RenderTarget[0] applies; otherwise the first
+    `num_render_targets` entries (all 8 when unknown) are returned.
+    """
+    independent = d3d_bool(desc, "IndependentBlendEnable")
+    result = {
+        "alpha_to_coverage_enable": d3d_bool(desc, "AlphaToCoverageEnable"),
+        "independent_blend_enable": independent,
+        "render_targets": [],
+    }
+    targets = sd_children_list(get_sd_child(desc, "RenderTarget")) if get_sd_child(desc, "RenderTarget") else []
+    if not independent:
+        targets = targets[:1]
+    elif num_render_targets is not None:
+        targets = targets[:num_render_targets]
+    for rt in targets:
+        blend_enable = bool(get_sd_child_value(rt, "BlendEnable", False))
+        rt_info = {
+            "blend_enable": blend_enable,
+            "logic_op_enable": d3d_bool(rt, "LogicOpEnable"),
+            "render_target_write_mask": write_mask_to_string(get_sd_child_value(rt, "RenderTargetWriteMask")),
+        }
+        if blend_enable:
+            rt_info.update({
+                "src_blend": d3d_enum(get_sd_child_value(rt, "SrcBlend"), "BLEND_"),
+                "dest_blend": d3d_enum(get_sd_child_value(rt, "DestBlend"), "BLEND_"),
+                "blend_op": d3d_enum(get_sd_child_value(rt, "BlendOp"), "BLEND_OP_"),
+                "src_blend_alpha": d3d_enum(get_sd_child_value(rt, "SrcBlendAlpha"), "BLEND_"),
+                "dest_blend_alpha": d3d_enum(get_sd_child_value(rt, "DestBlendAlpha"), "BLEND_"),
+                "blend_op_alpha": d3d_enum(get_sd_child_value(rt, "BlendOpAlpha"), "BLEND_OP_"),
+            })
+        if rt_info.get("logic_op_enable"):
+            rt_info["logic_op"] = d3d_enum(get_sd_child_value(rt, "LogicOp"), "LOGIC_OP_")
+        result["render_targets"].append({k: v for k, v in rt_info.items() if v is not None})
+    return {k: v for k, v in result.items() if v is not None}
+
+
+def parse_d3d_stencil_op_desc(face):
+    result = {
+        "stencil_fail_op": d3d_enum(get_sd_child_value(face, "StencilFailOp"), "STENCIL_OP_"),
+        "stencil_depth_fail_op": d3d_enum(get_sd_child_value(face, "StencilDepthFailOp"), "STENCIL_OP_"),
+        "stencil_pass_op": d3d_enum(get_sd_child_value(face, "StencilPassOp"), "STENCIL_OP_"),
+        "stencil_func": d3d_enum(get_sd_child_value(face, "StencilFunc"), "COMPARISON_FUNC_"),
+    }
+    result = {k: v for k, v in result.items() if v is not None}
+    return result if result else None
+
+
+def parse_d3d_depth_stencil_desc(desc):
+    """Parse D3D11_DEPTH_STENCIL_DESC / D3D12_DEPTH_STENCIL_DESC(1/2)."""
+    result = {
+        "depth_enable": d3d_bool(desc, "DepthEnable"),
+        "depth_write_mask": d3d_enum(get_sd_child_value(desc, "DepthWriteMask"), "DEPTH_WRITE_MASK_"),
+        "depth_func": d3d_enum(get_sd_child_value(desc, "DepthFunc"), "COMPARISON_FUNC_"),
+        "stencil_enable": d3d_bool(desc, "StencilEnable"),
+        "stencil_read_mask": get_sd_child_value(desc, "StencilReadMask"),
+        "stencil_write_mask": get_sd_child_value(desc, "StencilWriteMask"),
+        "depth_bounds_test_enable": d3d_bool(desc, "DepthBoundsTestEnable"),
+    }
+    if result["stencil_enable"]:
+        for field, key in (("FrontFace", "front_face"), ("BackFace", "back_face")):
+            face = get_sd_child(desc, field)
+            if face is not None:
+                result[key] = parse_d3d_stencil_op_desc(face)
+    result = {k: v for k, v in result.items() if v is not None}
+    return result if result else None
+
+
+def parse_root_signature_blob(blob):
+    """Decode a serialised root signature (RTS0 part, optionally inside a DXBC container)."""
+    u32 = lambda off: struct.unpack_from("<I", blob, off)[0]
+    f32 = lambda off: struct.unpack_from("<f", blob, off)[0]
+
+    base = 0
+    if blob[:4] == b"DXBC":
+        base = None
+        for i in range(u32(28)):
+            chunk = u32(32 + 4 * i)
+            if blob[chunk:chunk + 4] == b"RTS0":
+                base = chunk + 8
+                break
+        if base is None:
+            return None
+
+    version = u32(base)
+    num_params, params_offset = u32(base + 4), u32(base + 8)
+    num_samplers, samplers_offset = u32(base + 12), u32(base + 16)
+    flags = u32(base + 20)
+
+    parameters = []
+    for i in range(num_params):
+        header = base + params_offset + 12 * i
+        ptype, visibility, payload = u32(header), u32(header + 4), base + u32(header + 8)
+        param = {
+            "index": i,
+            "parameter_type": _D3D12_ROOT_PARAMETER_TYPES.get(ptype, str(ptype)),
+            "visibility": _D3D12_SHADER_VISIBILITY.get(visibility, str(visibility)),
+        }
+        if ptype == 0:
+            ranges = []
+            range_size = 20 if version == 1 else 24
+            num_ranges, ranges_offset = u32(payload), base + u32(payload + 4)
+            for r in range(num_ranges):
+                at = ranges_offset + range_size * r
+                count = u32(at + 4)
+                offset = u32(at + range_size - 4)
+                ranges.append({
+                    "range_type": _D3D12_DESCRIPTOR_RANGE_TYPES.get(u32(at), str(u32(at))),
+                    "num_descriptors": None if count == _D3D12_UINT_MAX else count,
+                    "base_shader_register": u32(at + 8),
+                    "register_space": u32(at + 12),
+                    "offset_in_descriptors_from_table_start": None if offset == _D3D12_UINT_MAX else offset,
+                })
+            param["ranges"] = ranges
+        else:
+            param["shader_register"] = u32(payload)
+            param["register_space"] = u32(payload + 4)
+            if ptype == 1:
+                param["num_32bit_values"] = u32(payload + 8)
+        parameters.append(param)
+
+    static_samplers = []
+    sampler_size = 56 if version >= 3 else 52
+    for i in range(num_samplers):
+        at = base + samplers_offset + sampler_size * i
+        comparison = u32(at + 24)
+        static_samplers.append({
+            "shader_register": u32(at + 40),
+            "register_space": u32(at + 44),
+            "visibility": _D3D12_SHADER_VISIBILITY.get(u32(at + 48), str(u32(at + 48))),
+            "filter": _D3D12_FILTERS.get(u32(at), "0x%x" % u32(at)),
+            "address_u": _D3D12_ADDRESS_MODES.get(u32(at + 4), str(u32(at + 4))),
+            "address_v": _D3D12_ADDRESS_MODES.get(u32(at + 8), str(u32(at + 8))),
+            "address_w": _D3D12_ADDRESS_MODES.get(u32(at + 12), str(u32(at + 12))),
+            "mip_lod_bias": f32(at + 16),
+            "max_anisotropy": u32(at + 20),
+            "comparison_func": _D3D12_COMPARISON_FUNCS.get(comparison, str(comparison)),
+            "min_lod": f32(at + 32),
+            "max_lod": f32(at + 36),
+        })
+
+    return {
+        "version": {1: "1.0", 2: "1.1", 3: "1.2"}.get(version, str(version)),
+        "flags": [name for bit, name in _D3D12_ROOT_SIGNATURE_FLAGS.items() if flags & bit],
+        "parameters": parameters,
+        "static_samplers": static_samplers,
+    }
+
+
+def extract_d3d12_root_signature(controller, sfile, root_sig_id):
+    """Name and decoded layout of a root signature, from its CreateRootSignature chunk."""
+    result = {"name": get_name(controller, root_sig_id)}
+    res_desc = get_resource_by_id(controller, root_sig_id)
+    if res_desc is None:
+        return result
+    for chunk_idx in res_desc.initialisationChunks:
+        chunk = sfile.chunks[chunk_idx]
+        if "CreateRootSignature" not in chunk.name:
+            continue
+        for child in sd_children_list(chunk):
+            if child.type.basetype != rd.SDBasic.Buffer:
+                continue
+            blob = sd_buffer_bytes(sfile, child)
+            if blob:
+                try:
+                    decoded = parse_root_signature_blob(blob)
+                    if decoded:
+                        result.update(decoded)
+                except struct.error:
+                    pass
+            return result
+    return result
+
+
+def extract_d3d12_pipeline_create_info(controller, pipeline_resource_id):
+    """Extract the D3D12 PSO desc from its Create*PipelineState chunk."""
+    res_desc = get_resource_by_id(controller, pipeline_resource_id)
+    if res_desc is None or not res_desc.initialisationChunks:
+        return None
+
+    sfile = controller.GetStructuredFile()
+    for chunk_idx in res_desc.initialisationChunks:
+        chunk = sfile.chunks[chunk_idx]
+        if "PipelineState" not in chunk.name or "Create" not in chunk.name:
+            continue
+        desc = find_desc_param(chunk, "PIPELINE_STATE")
+        if desc is None:
+            continue
+
+        result = {}
+        root_sig = get_sd_child_value(desc, "pRootSignature")
+        if isinstance(root_sig, rd.ResourceId):
+            result["root_signature"] = extract_d3d12_root_signature(controller, sfile, root_sig)
+
+        stages = []
+        for field, stage in _D3D12_SHADER_FIELDS:
+            bytecode = get_sd_child(desc, field)
+            length = get_sd_child_value(bytecode, "BytecodeLength", 0) if bytecode is not None else 0
+            if length:
+                stages.append({"stage": stage, "bytecode_size": length})
+        result["shader_stages"] = stages
+
+        input_layout = get_sd_child(desc, "InputLayout")
+        if input_layout is not None:
+            elements = get_sd_child(input_layout, "pInputElementDescs")
+            if elements is not None:
+                result["input_layout"] = parse_d3d_input_layout(elements)
+
+        topology = get_sd_child_value(desc, "PrimitiveTopologyType")
+        if topology is not None:
+            result["primitive_topology_type"] = d3d_enum(topology, "PRIMITIVE_TOPOLOGY_TYPE_")
+        strip_cut = get_sd_child_value(desc, "IBStripCutValue")
+        if strip_cut is not None:
+            result["ib_strip_cut_value"] = d3d_enum(strip_cut, "INDEX_BUFFER_STRIP_CUT_VALUE_")
+
+        # Graphics desc: RTVFormats[8] + NumRenderTargets; stream desc: RTVFormats.{RTFormats, NumRenderTargets}.
+        rtv = get_sd_child(desc, "RTVFormats")
+        num_rts = get_sd_child_value(desc, "NumRenderTargets")
+        if rtv is not None and get_sd_child(rtv, "RTFormats") is not None:
+            num_rts = get_sd_child_value(rtv, "NumRenderTargets", 0)
+            rtv = get_sd_child(rtv, "RTFormats")
+        if rtv is not None and num_rts:
+            formats = []
+            for f in sd_children_list(rtv)[:num_rts]:
+                try:
+                    formats.append(d3d_enum(f.AsString() or f.AsInt(), "FORMAT_"))
+                except Exception:
+                    formats.append(None)
+            result["rtv_formats"] = [f for f in formats if f is not None]
+        dsv = get_sd_child_value(desc, "DSVFormat")
+        if dsv and d3d_enum(dsv, "FORMAT_") != "UNKNOWN":
+            result["dsv_format"] = d3d_enum(dsv, "FORMAT_")
+
+        if not any(s["stage"] == "Compute" for s in stages):
+            raster = get_sd_child(desc, "RasterizerState")
+            if raster is not None:
+                result["rasterizer"] = parse_d3d_rasterizer_desc(raster)
+            blend = get_sd_child(desc, "BlendState")
+            if blend is not None:
+                result["blend"] = parse_d3d_blend_desc(blend, num_rts)
+            ds = get_sd_child(desc, "DepthStencilState")
+            if ds is not None:
+                result["depth_stencil"] = parse_d3d_depth_stencil_desc(ds)
+            sample_mask = get_sd_child_value(desc, "SampleMask")
+            if sample_mask is not None:
+                result["sample_mask"] = sample_mask
+            sample_desc = get_sd_child(desc, "SampleDesc")
+            if sample_desc is not None:
+                result["sample_count"] = get_sd_child_value(sample_desc, "Count")
+                result["sample_quality"] = get_sd_child_value(sample_desc, "Quality")
+
+        flags = get_sd_child_value(desc, "Flags")
+        if flags:
+            result["flags"] = str(flags)
+
+        return {k: v for k, v in result.items() if v is not None}
+
+    return None
+
+
+def extract_d3d11_state_desc(controller, sfile, resource_id, type_fragment):
+    """The desc parameter of a D3D11 state object's creation chunk."""
+    res_desc = get_resource_by_id(controller, resource_id)
+    if res_desc is None:
+        return None
+    for chunk_idx in res_desc.initialisationChunks:
+        desc = find_desc_param(sfile.chunks[chunk_idx], type_fragment)
+        if desc is not None:
+            return desc
+    return None
+
+
+def extract_d3d11_pipeline_create_info(controller, event_id):
+    """Extract the D3D11 state objects bound at `event_id`, from their creation chunks.
+
+    D3D11 has no pipeline objects: the input layout, rasterizer, blend and depth-stencil state
+    objects are bound separately, so this reports the ones in use at the pipeline's first event.
+    """
+    controller.SetFrameEvent(event_id, False)
+    d3d11 = controller.GetD3D11PipelineState()
+    sfile = controller.GetStructuredFile()
+    null = rd.ResourceId.Null()
+    result = {"shader_stages": []}
+
+    for attr, stage in (
+        ("vertexShader", "Vertex"),
+        ("hullShader", "TessControl"),
+        ("domainShader", "TessEval"),
+        ("geometryShader", "Geometry"),
+        ("pixelShader", "Fragment"),
+        ("computeShader", "Compute"),
+    ):
+        shader_id = getattr(d3d11, attr).resourceId
+        if shader_id != null:
+            result["shader_stages"].append({"stage": stage, "shader": get_name(controller, shader_id)})
+
+    layout_id = d3d11.inputAssembly.resourceId
+    if layout_id != null:
+        result["input_layout_object"] = get_name(controller, layout_id)
+        elements = extract_d3d11_state_desc(controller, sfile, layout_id, "INPUT_ELEMENT_DESC")
+        if elements is not None:
+            result["input_layout"] = parse_d3d_input_layout(elements)
+    try:
+        result["primitive_topology"] = str(controller.GetPipelineState().GetPrimitiveTopology()).split(".")[-1]
+    except Exception:
+        pass
+
+    for attr, key, fragment, parse in (
+        ("rasterizer", "rasterizer", "RASTERIZER_DESC", parse_d3d_rasterizer_desc),
+        ("blendState", "blend", "BLEND_DESC", parse_d3d_blend_desc),
+        ("depthStencilState", "depth_stencil", "DEPTH_STENCIL_DESC", parse_d3d_depth_stencil_desc),
+    ):
+        state = d3d11.rasterizer.state if attr == "rasterizer" else getattr(d3d11.outputMerger, attr)
+        state_id = state.resourceId
+        if state_id == null:
+            continue
+        result[key + "_object"] = get_name(controller, state_id)
+        desc = extract_d3d11_state_desc(controller, sfile, state_id, fragment)
+        if desc is not None:
+            result[key] = parse(desc)
+
+    return {k: v for k, v in result.items() if v is not None}
+
+
+# ---------------------------------------------------------------------------
+# OpenGL Pipeline State Extraction
+# ---------------------------------------------------------------------------
+
+_GL_SHADER_FIELDS = [
+    ("vertexShader", "Vertex"),
+    ("tessControlShader", "TessControl"),
+    ("tessEvalShader", "TessEval"),
+    ("geometryShader", "Geometry"),
+    ("fragmentShader", "Fragment"),
+    ("computeShader", "Compute"),
+]
+
+
+def gl_enum(value):
+    return str(value).split(".")[-1] if value is not None else None
+
+
+def gl_attr(obj, *names):
+    """First of `names` present on `obj` (field names vary across RenderDoc versions)."""
+    for name in names:
+        try:
+            return getattr(obj, name)
+        except Exception:
+            continue
+    return None
+
+
+def gl_resource_name(controller, rid):
+    if rid is None or rid == rd.ResourceId.Null():
+        return None
+    return get_name(controller, rid)
+
+
+def is_gl_program(sfile, res):
+    """True for program and program pipeline objects (by their creation chunk)."""
+    if res.type not in (rd.ResourceType.StateObject, rd.ResourceType.PipelineState):
+        return False
+    for chunk_idx in res.initialisationChunks:
+        if chunk_idx < len(sfile.chunks) and "Program" in str(sfile.chunks[chunk_idx].name):
+            return True
+    return False
+
+
+def gl_binds_program(gl, pipeline_id):
+    """Pipeline type ("Graphics" / "Compute") if the program (pipeline) is bound, else None."""
+    if gl_attr(gl, "pipelineResourceId") == pipeline_id:
+        return "Compute" if gl.computeShader.programResourceId != rd.ResourceId.Null() else "Graphics"
+    for field, stage in _GL_SHADER_FIELDS:
+        if getattr(gl, field).programResourceId == pipeline_id:
+            return "Compute" if stage == "Compute" else "Graphics"
+    return None
+
+
+def gl_attachment(controller, att):
+    """Texture / renderbuffer bound to an FBO attachment point, or None."""
+    rid = gl_attr(att, "resource", "resourceId")
+    name = gl_resource_name(controller, rid)
+    if name is None:
+        return None
+    result = {"resource": name}
+    mip = gl_attr(att, "firstMip", "mipLevel")
+    if mip is not None:
+        result["mip_level"] = int(mip)
+    layer = gl_attr(att, "firstSlice", "slice")
+    if layer is not None:
+        result["layer"] = int(layer)
+    tex_desc = get_texture_by_id(controller, rid)
+    if tex_desc is not None:
+        try:
+            result["format"] = str(tex_desc.format.Name())
+            result["sample_count"] = tex_desc.msSamp
+        except Exception:
+            pass
+    return result
+
+
+def gl_stencil_face(face):
+    return {
+        "fail_op": gl_enum(face.failOperation),
+        "depth_fail_op": gl_enum(face.depthFailOperation),
+        "pass_op": gl_enum(face.passOperation),
+        "func": gl_enum(face.function),
+        "reference": int(face.reference),
+        "compare_mask": int(face.compareMask),
+        "write_mask": int(face.writeMask),
+    }
+
+
+def gl_blend_equation(eq):
+    return "%s(%s, %s)" % (gl_enum(eq.operation), gl_enum(eq.source), gl_enum(eq.destination))
+
+
+def extract_gl_pipeline_state(controller, event_id):
+    """OpenGL state bound at `event_id`: program, VAO layout, draw FBO and enabled state.
+
+    GL has no pipeline objects, so this is the context state at the pipeline's first event.
+    """
+    controller.SetFrameEvent(event_id, False)
+    gl = controller.GetGLPipelineState()
+    result = {}
+
+    # Program / program pipeline and the shader objects attached to it
+    programs = []
+    stages = []
+    for field, stage in _GL_SHADER_FIELDS:
+        shader = getattr(gl, field)
+        program = gl_resource_name(controller, shader.programResourceId)
+        if program is None:
+            continue
+        if program not in programs:
+            programs.append(program)
+        stages.append({
+            "stage": stage,
+            "shader": gl_resource_name(controller, shader.shaderResourceId),
+            "program": program,
+        })
+    if programs:
+        result["program"] = programs[0] if len(programs) == 1 else None
+    result["program_pipeline"] = gl_resource_name(controller, gl_attr(gl, "pipelineResourceId"))
+    result["shader_stages"] = stages
+
+    # Vertex array object
+    vi = gl.vertexInput
+    vertex_array = {
+        "name": gl_resource_name(controller, vi.vertexArrayObject),
+        "attributes": [],
+        "vertex_buffers": [],
+        "index_buffer": gl_resource_name(controller, vi.indexBuffer),
+        "primitive_restart": bool(vi.primitiveRestart),
+        "provoking_vertex_last": bool(vi.provokingVertexLast),
+    }
+    if vi.primitiveRestart:
+        vertex_array["restart_index"] = int(vi.restartIndex)
+    for i, attr in enumerate(vi.attributes):
+        if not attr.enabled:
+            continue
+        vertex_array["attributes"].append({
+            "index": i,
+            "format": str(attr.format.Name()),
+            "byte_offset": int(attr.byteOffset),
+            "vertex_buffer_slot": int(attr.vertexBufferSlot),
+        })
+    for slot, vb in enumerate(vi.vertexBuffers):
+        name = gl_resource_name(controller, vb.resourceId)
+        if name is None:
+            continue
+        vertex_array["vertex_buffers"].append({
+            "slot": slot,
+            "buffer": name,
+            "byte_stride": int(vb.byteStride),
+            "byte_offset": int(vb.byteOffset),
+            "instance_divisor": int(vb.instanceDivisor),
+        })
+    result["vertex_array"] = vertex_array
+
+    # Draw framebuffer
+    fb = gl.framebuffer
+    draw_fbo = fb.drawFBO
+    framebuffer = {
+        "name": gl_resource_name(controller, draw_fbo.resourceId),
+        "color_attachments": [],
+        "draw_buffers": [int(b) for b in draw_fbo.drawBuffers],
+        "framebuffer_srgb": bool(fb.framebufferSRGB),
+        "dither": bool(fb.dither),
+    }
+    if framebuffer["name"] is None:
+        framebuffer["name"] = "default framebuffer"
+    for i, att in enumerate(draw_fbo.colorAttachments):
+        info = gl_attachment(controller, att)
+        if info is not None:
+            info["index"] = i
+            framebuffer["color_attachments"].append(info)
+    framebuffer["depth_attachment"] = gl_attachment(controller, draw_fbo.depthAttachment)
+    framebuffer["stencil_attachment"] = gl_attachment(controller, draw_fbo.stencilAttachment)
+    result["framebuffer"] = {k: v for k, v in framebuffer.items() if v is not None}
+
+    # glEnable / glDisable state and the parameters that go with it
+    rs = gl.rasterizer.state
+    ds = gl.depthState
+    ss = gl.stencilState
+    enabled = {
+        "cull_face": gl_enum(rs.cullMode) != "NoCull",
+        "cull_mode": gl_enum(rs.cullMode),
+        "front_ccw": bool(rs.frontCCW),
+        "polygon_mode": gl_enum(rs.fillMode),
+        "polygon_offset": rs.depthBias != 0.0 or rs.slopeScaledDepthBias != 0.0,
+        "depth_clamp": bool(rs.depthClamp),
+        "multisample": bool(rs.multisampleEnable),
+        "sample_shading": bool(rs.sampleShading),
+        "sample_alpha_to_coverage": bool(rs.alphaToCoverage),
+        "sample_alpha_to_one": bool(rs.alphaToOne),
+        "sample_mask": bool(rs.sampleMask),
+        "program_point_size": bool(rs.programmablePointSize),
+        "rasterizer_discard": bool(gl.vertexProcessing.discard),
+        "clip_distances": [i for i, on in enumerate(gl.vertexProcessing.clipPlanes) if on],
+        "scissor_test": any(bool(s.enabled) for s in gl.rasterizer.scissors),
+        "depth_test": bool(ds.depthEnable),
+        "depth_write": bool(ds.depthWrites),
+        "depth_func": gl_enum(ds.depthFunction),
+        "depth_bounds_test": bool(ds.depthBounds),
+        "stencil_test": bool(ss.stencilEnable),
+        "primitive_restart": bool(vi.primitiveRestart),
+        "framebuffer_srgb": bool(fb.framebufferSRGB),
+        "line_width": float(rs.lineWidth),
+        "point_size": float(rs.pointSize),
+    }
+    if enabled["polygon_offset"]:
+        enabled["depth_bias"] = float(rs.depthBias)
+        enabled["slope_scaled_depth_bias"] = float(rs.slopeScaledDepthBias)
+        enabled["offset_clamp"] = float(rs.offsetClamp)
+    if enabled["stencil_test"]:
+        enabled["stencil_front"] = gl_stencil_face(ss.frontFace)
+        enabled["stencil_back"] = gl_stencil_face(ss.backFace)
+
+    blends = []
+    for i, blend in enumerate(fb.blendState.blends):
+        if i >= len(draw_fbo.drawBuffers) and i > 0:
+            break
+        entry = {"index": i, "enabled": bool(blend.enabled), "write_mask": write_mask_to_string(blend.writeMask)}
+        if blend.enabled:
+            entry["color_blend"] = gl_blend_equation(blend.colorBlend)
+            entry["alpha_blend"] = gl_blend_equation(blend.alphaBlend)
+        if blend.logicOperationEnabled:
+            entry["logic_op"] = gl_enum(blend.logicOperation)
+        blends.append(entry)
+    enabled["blend"] = blends
+    result["enabled_state"] = enabled
+
+    return {k: v for k, v in result.items() if v is not None}
+
+
 # ---------------------------------------------------------------------------
 # Pipeline Layout Extraction from Structured File
 # ---------------------------------------------------------------------------
@@ -1235,21 +1986,38 @@ def extract_descriptor_set_layout_bindings(controller, layout_resource_id):
 # Pipeline finding
 # ---------------------------------------------------------------------------
 
+def pipeline_resources(controller):
+    """Resources that name a pipeline: PipelineState, or the nearest equivalent on APIs without one."""
+    api = controller.GetAPIProperties().pipelineType
+    resources = controller.GetResources()
+    if api == rd.GraphicsAPI.D3D11:
+        return [r for r in resources if r.type == rd.ResourceType.Shader]
+    if api == rd.GraphicsAPI.OpenGL:
+        sfile = controller.GetStructuredFile()
+        return [r for r in resources if is_gl_program(sfile, r)]
+    return [r for r in resources if r.type == rd.ResourceType.PipelineState]
+
+
 def find_pipeline(controller, pipeline_name):
-    """Locate the target pipeline's resource description by name."""
-    for res in controller.GetResources():
-        if res.name == pipeline_name and res.type == rd.ResourceType.PipelineState:
+    """Locate the target pipeline's resource description by name.
+
+    D3D11 has no pipeline state objects, so there the name is matched against shaders and the
+    "pipeline" is every action that binds that shader. On OpenGL it is matched against program
+    and program pipeline objects.
+    """
+    candidates = pipeline_resources(controller)
+    for res in candidates:
+        if res.name == pipeline_name:
             return res
 
     # Also check if any name matches (could be partial)
-    for res in controller.GetResources():
-        if res.type == rd.ResourceType.PipelineState and pipeline_name in res.name:
+    for res in candidates:
+        if pipeline_name in res.name:
             return res
 
     available = []
-    for r in controller.GetResources():
-        if r.type == rd.ResourceType.PipelineState:
-            available.append("  %s  %s" % (r.resourceId, r.name))
+    for r in candidates:
+        available.append("  %s  %s" % (r.resourceId, r.name))
 
     raise RuntimeError(
         "Pipeline '%s' not found. Available pipelines:\n%s"
@@ -1298,6 +2066,10 @@ def extract_pipeline_details(controller, pipeline_id, pipeline_name, actions):
         "event_ids": [],
     }
 
+    api = controller.GetAPIProperties().pipelineType
+    is_d3d11 = api == rd.GraphicsAPI.D3D11
+    is_gl = api == rd.GraphicsAPI.OpenGL
+
     # First pass: find all events where this pipeline is active
     active_events = []
     for action in actions:
@@ -1327,6 +2099,23 @@ def extract_pipeline_details(controller, pipeline_id, pipeline_name, actions):
         except Exception:
             pass
 
+        # D3D11: the "pipeline" is a shader, active wherever it is bound
+        if is_d3d11:
+            for stage in _ALL_STAGES:
+                if state.GetShader(stage) == pipeline_id:
+                    details["pipeline_type"] = "Compute" if stage == rd.ShaderStage.Compute else "Graphics"
+                    active_events.append(eid)
+                    details["event_ids"].append(eid)
+                    break
+
+        # OpenGL: the "pipeline" is a program (pipeline), active wherever it is bound
+        if is_gl:
+            pipeline_type = gl_binds_program(controller.GetGLPipelineState(), pipeline_id)
+            if pipeline_type is not None:
+                details["pipeline_type"] = pipeline_type
+                active_events.append(eid)
+                details["event_ids"].append(eid)
+
     if not active_events:
         raise RuntimeError(
             "Pipeline '%s' is not used in any action in the capture." % pipeline_name
@@ -1429,6 +2218,20 @@ def extract_pipeline_details(controller, pipeline_id, pipeline_name, actions):
         if create_info:
             details["vulkan_create_info"] = create_info
 
+    # D3D12 PSO desc / D3D11 bound state objects, from the structured file
+    if api == rd.GraphicsAPI.D3D12:
+        create_info = extract_d3d12_pipeline_create_info(controller, pipeline_id)
+        if create_info:
+            details["d3d12_create_info"] = create_info
+    elif is_d3d11:
+        create_info = extract_d3d11_pipeline_create_info(controller, active_events[0])
+        if create_info:
+            details["d3d11_create_info"] = create_info
+    elif is_gl:
+        gl_state = extract_gl_pipeline_state(controller, active_events[0])
+        if gl_state:
+            details["gl_pipeline_state"] = gl_state
+
     return details
 
 
@@ -2450,7 +3253,7 @@ def main() -> None:
         if not cap.LocalReplaySupport():
             raise RuntimeError("Capture cannot be replayed")
 
-        result, controller = cap.OpenCapture(rd.ReplayOptions(), None)
+        result, controller = cap.OpenCapture(replay_options(req), None)
         if result != rd.ResultCode.Succeeded:
             raise RuntimeError("Couldn't initialise replay: " + str(result))
 
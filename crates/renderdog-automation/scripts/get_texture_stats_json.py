@@ -0,0 +1,244 @@
+"""
+get_texture_stats_json.py -- Per-channel statistics of a texture at an event.
+
+Request:
+  - capture_path
+  - event_id (optional)
+  - texture: resource id ("123" / "ResourceId::123"), resource name, "rt<N>" or "depth"
+  - mip, slice, sample
+  - histogram: include RenderDoc's 256-bucket histogram per channel
+
+min/max come from GetMinMax. The mean is computed from the raw texel data for plain
+(non-packed, non-compressed) formats and estimated from a histogram otherwise.
+"""
+
+import array
+import json
+import struct
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "get_texture_stats_json.request.json"
+RESP_PATH = "get_texture_stats_json.response.json"
+
+CHANNELS = ["r", "g", "b", "a"]
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def bound_resource_id(br):
+    rid = getattr(br, "resourceId", None)
+    if rid is None:
+        rid = getattr(br, "resource", None)
+    return rid
+
+
+def resolve_texture(controller, spec):
+    """Texture description for a resource id, resource name, "rt<N>" or "depth"."""
+    textures = {int(t.resourceId): t for t in controller.GetTextures()}
+    spec = str(spec).strip()
+    lowered = spec.lower()
+
+    rid = None
+    if lowered == "depth":
+        rid = bound_resource_id(controller.GetPipelineState().GetDepthTarget())
+    elif lowered.startswith("rt") and lowered[2:].isdigit():
+        targets = controller.GetPipelineState().GetOutputTargets()
+        idx = int(lowered[2:])
+        if idx < len(targets):
+            rid = bound_resource_id(targets[idx])
+    else:
+        digits = lowered.replace("resourceid::", "")
+        if digits.isdigit():
+            if int(digits) in textures:
+                return textures[int(digits)]
+            raise RuntimeError("No texture with resource id " + digits)
+        resources = controller.GetResources()
+        for exact in (True, False):
+            for r in resources:
+                name = str(r.name)
+                if (name == spec) if exact else (name.lower() == lowered):
+                    if int(r.resourceId) in textures:
+                        return textures[int(r.resourceId)]
+
+    if rid is None or rid == rd.ResourceId.Null() or int(rid) not in textures:
+        raise RuntimeError("Texture not found or not bound: " + spec)
+    return textures[int(rid)]
+
+
+def srgb_to_linear(v):
+    return v / 12.92 if v <= 0.04045 else ((v + 0.055) / 1.055) ** 2.4
+
+
+def decode_channels(fmt, data):
+    """Per-channel lists of texel values for plain formats, or None if unsupported."""
+    if fmt.type != rd.ResourceFormatType.Regular or fmt.compCount == 0:
+        return None
+    width = int(fmt.compByteWidth)
+    ct = fmt.compType
+    if ct == rd.CompType.Float:
+        code = {2: None, 4: "f", 8: "d"}.get(width)
+    elif ct in (rd.CompType.SInt, rd.CompType.SNorm):
+        code = {1: "b", 2: "h", 4: "i"}.get(width)
+    elif ct in (rd.CompType.UInt, rd.CompType.UNorm, rd.CompType.UNormSRGB):
+        code = {1: "B", 2: "H", 4: "I"}.get(width)
+    else:
+        code = None
+    n = int(fmt.compCount)
+    if ct == rd.CompType.Float and width == 2:
+        usable = len(data) - (len(data) % 2)
+        arr = [v[0] for v in struct.iter_unpack("<e", bytes(data[:usable]))]
+        return [arr[c::n] for c in range(n)]
+    if code is None:
+        return None
+
+    arr = array.array(code)
+    usable = len(data) - (len(data) % arr.itemsize)
+    arr.frombytes(bytes(data[:usable]))
+    channels = [list(arr[c::n]) for c in range(n)]
+
+    if ct in (rd.CompType.UNorm, rd.CompType.UNormSRGB):
+        scale = float((1 << (width * 8)) - 1)
+        channels = [[v / scale for v in ch] for ch in channels]
+        if ct == rd.CompType.UNormSRGB:
+            channels = [[srgb_to_linear(v) for v in ch] if i < 3 else ch for i, ch in enumerate(channels)]
+    elif ct == rd.CompType.SNorm:
+        scale = float((1 << (width * 8 - 1)) - 1)
+        channels = [[max(v / scale, -1.0) for v in ch] for ch in channels]
+    if fmt.BGRAOrder() and n >= 3:
+        channels[0], channels[2] = channels[2], channels[0]
+    return channels
+
+
+def histogram_mean(buckets, lo, hi):
+    total = sum(buckets)
+    if total == 0:
+        return 0.0
+    step = (hi - lo) / len(buckets)
+    return sum((lo + (i + 0.5) * step) * c for i, c in enumerate(buckets)) / total
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            event_id = req.get("event_id")
+            if event_id is not None:
+                controller.SetFrameEvent(int(event_id), True)
+
+            tex = resolve_texture(controller, req["texture"])
+            mip = int(req.get("mip", 0))
+            if mip >= tex.mips:
+                raise RuntimeError("mip %d out of range (texture has %d)" % (mip, tex.mips))
+            sub = rd.Subresource(mip, int(req.get("slice", 0)), int(req.get("sample", 0)))
+            fmt = tex.format
+            n = max(min(int(fmt.compCount), 4), 1)
+
+            lo, hi = controller.GetMinMax(tex.resourceId, sub, rd.CompType.Typeless)
+
+            decoded = decode_channels(fmt, controller.GetTextureData(tex.resourceId, sub))
+            want_histogram = bool(req.get("histogram", False))
+
+            channels = []
+            for c in range(n):
+                cmin = float(lo.floatValue[c])
+                cmax = float(hi.floatValue[c])
+                buckets = None
+                if want_histogram or decoded is None:
+                    mask = [i == c for i in range(4)]
+                    buckets = [int(b) for b in controller.GetHistogram(
+                        tex.resourceId, sub, rd.CompType.Typeless, cmin, max(cmax, cmin + 1e-6), mask
+                    )]
+                if decoded is not None:
+                    mean = float(sum(decoded[c])) / len(decoded[c]) if decoded[c] else 0.0
+                else:
+                    mean = histogram_mean(buckets, cmin, cmax)
+                stats = {"channel": CHANNELS[c], "min": cmin, "max": cmax, "mean": mean}
+                if want_histogram:
+                    stats["histogram"] = buckets
+                channels.append(stats)
+
+            name = str(tex.resourceId)
+            for r in controller.GetResources():
+                if r.resourceId == tex.resourceId:
+                    name = str(r.name)
+                    break
+
+            write_envelope(
+                True,
+                result={
+                    "capture_path": req["capture_path"],
+                    "event_id": event_id,
+                    "texture_id": int(tex.resourceId),
+                    "texture_name": name,
+                    "format": str(fmt.Name()),
+                    "width": max(int(tex.width) >> mip, 1),
+                    "height": max(int(tex.height) >> mip, 1),
+                    "channels": channels,
+                    "mean_exact": decoded is not None,
+                },
+            )
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
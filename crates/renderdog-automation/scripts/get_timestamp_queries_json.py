@@ -0,0 +1,306 @@
+"""
+get_timestamp_queries_json.py -- Application GPU timestamp queries, correlated with marker regions.
+
+Request:
+  - capture_path
+  - timestamp_period_ns: nanoseconds per tick (VkPhysicalDeviceLimits::timestampPeriod, or
+    1e9 / ID3D12CommandQueue::GetTimestampFrequency()); optional, enables the `_ns` fields
+
+Finds every timestamp the application wrote in the frame (vkCmdWriteTimestamp(2),
+ID3D12GraphicsCommandList::EndQuery with D3D12_QUERY_TYPE_TIMESTAMP, D3D11 End on a
+D3D11_QUERY_TIMESTAMP query, glQueryCounter) with the query pool / heap and index, and the marker
+path it was written in.
+
+Values are only available where the frame itself copies the results into a buffer
+(vkCmdCopyQueryPoolResults, ResolveQueryData): the script replays to each copy and reads the
+destination buffer, so values are re-measured on the replay GPU rather than the original run.
+Results read back on the CPU with vkGetQueryPoolResults / GetData are not part of the capture.
+
+Regions group the timestamps by marker path (every enclosing marker, so a pass also covers its
+sub-passes): first/last timestamp event, and the elapsed ticks between them when both resolved.
+
+Returns:
+  - api, total_timestamps, resolved_timestamps, timestamps, regions
+"""
+
+import json
+import struct
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "get_timestamp_queries_json.request.json"
+RESP_PATH = "get_timestamp_queries_json.response.json"
+
+VK_QUERY_RESULT_64_BIT = 0x1
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def params(chunk):
+    """Top-level chunk parameters by lower-case name."""
+    return {str(chunk.GetChild(i).name).lower(): chunk.GetChild(i) for i in range(chunk.NumChildren())}
+
+
+def as_int(obj, default=0):
+    if obj is None:
+        return default
+    try:
+        return int(obj.AsInt())
+    except Exception:
+        return default
+
+
+def as_string(obj):
+    if obj is None:
+        return ""
+    try:
+        return str(obj.AsString())
+    except Exception:
+        return ""
+
+
+def as_resource(obj):
+    if obj is None or obj.type.basetype != rd.SDBasic.Resource:
+        return None
+    rid = obj.AsResourceId()
+    return None if rid == rd.ResourceId.Null() else rid
+
+
+def mentions(obj, text, depth=0):
+    """True if any enum/string value under obj contains `text`."""
+    if obj.type.basetype in (rd.SDBasic.Enum, rd.SDBasic.String) and text in as_string(obj):
+        return True
+    if depth < 4:
+        return any(mentions(obj.GetChild(i), text, depth + 1) for i in range(obj.NumChildren()))
+    return False
+
+
+def timestamp_write(name, p, timestamp_queries):
+    """(pool resource, index, stage) if the call writes a timestamp, else None."""
+    short = name.split("::")[-1]
+    if short.startswith("vkCmdWriteTimestamp"):
+        stage = p.get("pipelinestage") or p.get("stage")
+        return as_resource(p.get("querypool")), as_int(p.get("query")), as_string(stage) or None
+    if short == "EndQuery" and "TIMESTAMP" in as_string(p.get("type")).upper():
+        return as_resource(p.get("pqueryheap")), as_int(p.get("index")), None
+    if name.startswith("ID3D11") and short == "End":
+        # D3D11 queries are individual objects; the type is only on their creation.
+        query = as_resource(p.get("pasync"))
+        if query is not None and str(query) in timestamp_queries:
+            return query, 0, None
+        return None
+    if short == "glQueryCounter":
+        return as_resource(p.get("id")), 0, None
+    return None
+
+
+def results_copy(name, p):
+    """(pool, first, count, buffer, offset, stride, bytes per value) for result copies, else None."""
+    short = name.split("::")[-1]
+    if short == "vkCmdCopyQueryPoolResults":
+        width = 8 if as_int(p.get("flags")) & VK_QUERY_RESULT_64_BIT else 4
+        return (
+            as_resource(p.get("querypool")),
+            as_int(p.get("firstquery")),
+            as_int(p.get("querycount")),
+            as_resource(p.get("dstbuffer")),
+            as_int(p.get("dstoffset")),
+            as_int(p.get("stride"), width),
+            width,
+        )
+    if short == "ResolveQueryData" and "TIMESTAMP" in as_string(p.get("type")).upper():
+        return (
+            as_resource(p.get("pqueryheap")),
+            as_int(p.get("startindex")),
+            as_int(p.get("numqueries")),
+            as_resource(p.get("pdestinationbuffer")),
+            as_int(p.get("aligneddestinationbufferoffset")),
+            8,
+            8,
+        )
+    return None
+
+
+def collect_events(sfile, actions, markers, out):
+    """(event_id, chunk_index, marker_path) for every API event, in submission order."""
+    for a in actions:
+        for ev in a.events:
+            out.append((int(ev.eventId), int(ev.chunkIndex), list(markers)))
+        if len(a.children) > 0:
+            inner = markers + [str(a.GetName(sfile))] if a.flags & rd.ActionFlags.PushMarker else markers
+            collect_events(sfile, a.children, inner, out)
+    return out
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    period_ns = req.get("timestamp_period_ns")
+    period_ns = float(period_ns) if period_ns else None
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            sfile = controller.GetStructuredFile()
+            resources = controller.GetResources()
+            names = {str(r.resourceId): r.name for r in resources}
+            # D3D11 queries created as D3D11_QUERY_TIMESTAMP (not TIMESTAMP_DISJOINT).
+            timestamp_queries = set()
+            for r in resources:
+                for c in r.initialisationChunks:
+                    if c < len(sfile.chunks) and "CreateQuery" in str(sfile.chunks[c].name):
+                        chunk = sfile.chunks[c]
+                        if mentions(chunk, "TIMESTAMP") and not mentions(chunk, "DISJOINT"):
+                            timestamp_queries.add(str(r.resourceId))
+            events = sorted(collect_events(sfile, controller.GetRootActions(), [], []), key=lambda e: e[0])
+
+            timestamps = []
+            copies = []
+            for event_id, chunk_index, marker_path in events:
+                if chunk_index >= len(sfile.chunks):
+                    continue
+                chunk = sfile.chunks[chunk_index]
+                name = str(chunk.name)
+                p = params(chunk)
+                write = timestamp_write(name, p, timestamp_queries)
+                if write is not None:
+                    pool, index, stage = write
+                    timestamps.append(
+                        {
+                            "event_id": event_id,
+                            "call": name,
+                            "pool": names.get(str(pool), str(pool)) if pool is not None else None,
+                            "pool_id": str(pool) if pool is not None else None,
+                            "index": index,
+                            "stage": stage,
+                            "marker_path": marker_path,
+                            "ticks": None,
+                            "resolved_event_id": None,
+                        }
+                    )
+                    continue
+                copy = results_copy(name, p)
+                if copy is not None and copy[0] is not None and copy[3] is not None:
+                    copies.append((event_id,) + copy)
+
+            # Read each copy's destination buffer right after it executes.
+            for event_id, pool, first, count, buffer, offset, stride, width in copies:
+                pending = [
+                    t
+                    for t in timestamps
+                    if t["ticks"] is None
+                    and t["event_id"] < event_id
+                    and t["pool_id"] == str(pool)
+                    and first <= t["index"] < first + count
+                ]
+                if not pending:
+                    continue
+                controller.SetFrameEvent(event_id, True)
+                data = bytes(controller.GetBufferData(buffer, offset, stride * (count - 1) + width))
+                fmt = "<Q" if width == 8 else "<I"
+                for t in pending:
+                    at = (t["index"] - first) * stride
+                    if at + width <= len(data):
+                        t["ticks"] = struct.unpack_from(fmt, data, at)[0]
+                        t["resolved_event_id"] = event_id
+
+            for t in timestamps:
+                del t["pool_id"]
+
+            regions = {}
+            for t in timestamps:
+                for depth in range(1, len(t["marker_path"]) + 1):
+                    key = "/".join(t["marker_path"][:depth])
+                    r = regions.setdefault(key, {"marker_path": key, "timestamps": []})
+                    r["timestamps"].append(t)
+            region_list = []
+            for key in sorted(regions, key=lambda k: regions[k]["timestamps"][0]["event_id"]):
+                ts = regions[key]["timestamps"]
+                first, last = ts[0], ts[-1]
+                elapsed = None
+                if len(ts) > 1 and first["ticks"] is not None and last["ticks"] is not None:
+                    elapsed = last["ticks"] - first["ticks"]
+                region = {
+                    "marker_path": key,
+                    "timestamps": len(ts),
+                    "first_event_id": first["event_id"],
+                    "last_event_id": last["event_id"],
+                    "elapsed_ticks": elapsed,
+                }
+                if period_ns is not None:
+                    region["elapsed_ns"] = elapsed * period_ns if elapsed is not None else None
+                region_list.append(region)
+
+            write_envelope(
+                True,
+                result={
+                    "capture_path": req["capture_path"],
+                    "api": str(controller.GetAPIProperties().pipelineType).split(".")[-1],
+                    "total_timestamps": len(timestamps),
+                    "resolved_timestamps": sum(1 for t in timestamps if t["ticks"] is not None),
+                    "timestamps": timestamps,
+                    "regions": region_list,
+                },
+            )
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
@@ -0,0 +1,247 @@
+"""
+get_vertex_inputs_json.py -- Decode the vertex buffer inputs of a draw.
+
+Reads the bound vertex buffers at `event_id` and decodes each vertex attribute with its
+ResourceFormat (the same attribute list `get_pipeline_details` reports).
+
+Request parameters:
+  - capture_path
+  - event_id
+  - first, count: vertex range relative to the draw (indices are resolved for indexed draws)
+  - instance: instance used for per-instance attributes
+
+Returns:
+  - attributes: name / offset / format / per_instance
+  - vertices: [{index, vertex_id, values: {attr_name: [components...]}}]
+"""
+
+import json
+import struct
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "get_vertex_inputs_json.request.json"
+RESP_PATH = "get_vertex_inputs_json.response.json"
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def find_action(controller, event_id):
+    def walk(actions):
+        for a in actions:
+            if a.eventId == event_id:
+                return a
+            found = walk(a.children)
+            if found is not None:
+                return found
+        return None
+
+    action = walk(controller.GetRootActions())
+    if action is None:
+        raise RuntimeError("No action with event_id %d" % event_id)
+    if not (action.flags & rd.ActionFlags.Drawcall):
+        raise RuntimeError("Event %d is not a drawcall: %s" % (event_id, action.GetName(controller.GetStructuredFile())))
+    return action
+
+
+def format_name(fmt):
+    try:
+        return str(fmt.Name())
+    except Exception:
+        comp_type = str(fmt.compType).replace("CompType.", "")
+        return "%s%dx%d" % (comp_type, fmt.compCount, fmt.compByteWidth * 8)
+
+
+def format_byte_size(fmt):
+    if fmt.type == rd.ResourceFormatType.R10G10B10A2 or fmt.type == rd.ResourceFormatType.R11G11B10:
+        return 4
+    return fmt.compCount * fmt.compByteWidth
+
+
+def decode_format(fmt, data):
+    """Decode one element of `fmt` from `data` into a list of numbers."""
+    if fmt.type == rd.ResourceFormatType.R10G10B10A2:
+        v = struct.unpack_from("<I", data, 0)[0]
+        comps = [v & 0x3FF, (v >> 10) & 0x3FF, (v >> 20) & 0x3FF, (v >> 30) & 0x3]
+        if fmt.compType == rd.CompType.UNorm:
+            return [comps[0] / 1023.0, comps[1] / 1023.0, comps[2] / 1023.0, comps[3] / 3.0]
+        return comps
+    if fmt.type == rd.ResourceFormatType.R11G11B10:
+        v = struct.unpack_from("<I", data, 0)[0]
+
+        def small_float(bits, mantissa_bits):
+            exponent = bits >> mantissa_bits
+            mantissa = bits & ((1 << mantissa_bits) - 1)
+            if exponent == 0:
+                return mantissa / float(1 << mantissa_bits) * 2.0 ** -14
+            if exponent == 31:
+                return float("inf") if mantissa == 0 else float("nan")
+            return (1.0 + mantissa / float(1 << mantissa_bits)) * 2.0 ** (exponent - 15)
+
+        return [small_float(v & 0x7FF, 6), small_float((v >> 11) & 0x7FF, 6), small_float((v >> 22) & 0x3FF, 5)]
+    if fmt.type != rd.ResourceFormatType.Regular:
+        raise RuntimeError("Unsupported vertex format: " + format_name(fmt))
+
+    width = fmt.compByteWidth
+    ct = fmt.compType
+    signed = ct in (rd.CompType.SInt, rd.CompType.SNorm, rd.CompType.SScaled)
+    if ct in (rd.CompType.Float, rd.CompType.Double) or (ct == rd.CompType.Typeless and width >= 4):
+        char = {2: "e", 4: "f", 8: "d"}[width]
+    else:
+        char = {1: "B", 2: "H", 4: "I", 8: "Q"}[width]
+        if signed:
+            char = char.lower()
+    values = list(struct.unpack_from("<%d%s" % (fmt.compCount, char), data, 0))
+
+    if ct == rd.CompType.UNorm:
+        scale = float((1 << (width * 8)) - 1)
+        values = [v / scale for v in values]
+    elif ct == rd.CompType.SNorm:
+        scale = float((1 << (width * 8 - 1)) - 1)
+        values = [max(v / scale, -1.0) for v in values]
+    if fmt.BGRAOrder() and len(values) >= 3:
+        values[0], values[2] = values[2], values[0]
+    return values
+
+
+def read_index(controller, ib, action, position):
+    stride = ib.byteStride
+    fmt = {1: "B", 2: "H", 4: "I"}[stride]
+    offset = ib.byteOffset + (action.indexOffset + position) * stride
+    data = controller.GetBufferData(ib.resourceId, offset, stride)
+    return struct.unpack_from("<" + fmt, data, 0)[0]
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    event_id = int(req["event_id"])
+    first = int(req.get("first", 0))
+    count = int(req["count"])
+    instance = int(req.get("instance", 0))
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            action = find_action(controller, event_id)
+            controller.SetFrameEvent(event_id, True)
+            state = controller.GetPipelineState()
+
+            attrs = [a for a in state.GetVertexInputs() if a.used]
+            vbs = state.GetVBuffers()
+            indexed = bool(action.flags & rd.ActionFlags.Indexed)
+            ib = state.GetIBuffer() if indexed else None
+            if indexed and (ib.resourceId == rd.ResourceId.Null() or ib.byteStride == 0):
+                raise RuntimeError("Indexed draw at event %d has no index buffer bound" % event_id)
+
+            total = int(action.numIndices)
+            end = min(first + count, total)
+
+            attributes = [
+                {
+                    "name": a.name or "attr_%d" % i,
+                    "offset": int(a.byteOffset),
+                    "format": format_name(a.format),
+                    "per_instance": bool(a.perInstance),
+                }
+                for i, a in enumerate(attrs)
+            ]
+
+            vertices = []
+            for position in range(first, end):
+                if indexed:
+                    vertex_id = read_index(controller, ib, action, position) + action.baseVertex
+                else:
+                    vertex_id = action.vertexOffset + position
+
+                values = {}
+                for i, a in enumerate(attrs):
+                    name = attributes[i]["name"]
+                    if a.vertexBuffer >= len(vbs) or vbs[a.vertexBuffer].resourceId == rd.ResourceId.Null():
+                        values[name] = [float(x) for x in a.genericValue.f32v[: a.format.compCount]]
+                        continue
+                    vb = vbs[a.vertexBuffer]
+                    if a.perInstance:
+                        rate = max(int(a.instanceRate), 1)
+                        element = action.instanceOffset + instance // rate
+                    else:
+                        element = vertex_id
+                    offset = vb.byteOffset + a.byteOffset + element * vb.byteStride
+                    data = controller.GetBufferData(vb.resourceId, offset, format_byte_size(a.format))
+                    values[name] = decode_format(a.format, data)
+
+                vertices.append({"index": position, "vertex_id": int(vertex_id), "values": values})
+
+            write_envelope(
+                True,
+                result={
+                    "capture_path": req["capture_path"],
+                    "event_id": event_id,
+                    "indexed": indexed,
+                    "total_vertices": total,
+                    "attributes": attributes,
+                    "vertices": vertices,
+                },
+            )
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
@@ -0,0 +1,281 @@
+"""
+lint_redundant_state_json.py -- Find redundant state-setting calls, counted per pass.
+
+Request:
+  - capture_path
+  - max_examples: example calls kept per pass
+
+Walks every API call in submission order. A state-setting call (pipeline / shader binds,
+descriptor set / root table / resource binds, push / root constants and uniforms, plus other
+Bind* / Set* calls) is redundant when its arguments are identical to the previous call of the
+same function for the same slot (firstSet, StartSlot, RootParameterIndex, location, ...) since
+the last command buffer / command list reset. Best-effort on GL, where bind targets also depend
+on the active texture unit.
+"""
+
+import hashlib
+import json
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "lint_redundant_state_json.request.json"
+RESP_PATH = "lint_redundant_state_json.response.json"
+
+# Argument names identifying the slot a call writes (compared case-insensitively).
+SLOT_ARGS = {
+    "pipelinebindpoint",
+    "firstset",
+    "firstbinding",
+    "firstviewport",
+    "firstscissor",
+    "startslot",
+    "slot",
+    "rootparameterindex",
+    "destoffsetin32bitvalues",
+    "stageflags",
+    "offset",
+    "location",
+    "target",
+    "index",
+    "unit",
+    "binding",
+    "bindingindex",
+}
+
+# Arguments naming the recording command buffer / list rather than state.
+IGNORED_ARGS = {"commandbuffer", "pcommandlist", "commandlist", "this", "context"}
+
+RESET_CALLS = ("BeginCommandBuffer", "::Reset")
+
+EXCLUDED_CALLS = ("SetEvent", "SetMarker", "SetName", "SetDebug", "SetPrivateData", "SetCheckpoint", "Signal")
+
+CATEGORIES = ("pipeline", "descriptors", "constants", "other")
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def call_category(name):
+    """Category for a state-setting call name, or None for calls that don't set state."""
+    short = name.split("::")[-1]
+    if any(x in short for x in EXCLUDED_CALLS):
+        return None
+    if any(x in short for x in ("BindPipeline", "SetPipelineState", "SetShader", "UseProgram", "BindProgramPipeline")):
+        return "pipeline"
+    if any(x in short for x in ("BindDescriptorSets", "DescriptorTable", "RootDescriptor", "DescriptorHeaps")):
+        return "descriptors"
+    if any(x in short for x in ("SetShaderResources", "SetConstantBuffers", "SetSamplers", "SetUnorderedAccessViews")):
+        return "descriptors"
+    if any(x in short for x in ("BindTexture", "BindSampler", "BindBufferBase", "BindBufferRange", "BindImageTexture")):
+        return "descriptors"
+    if any(x in short for x in ("PushConstants", "32BitConstant", "Uniform")):
+        return "constants"
+    if "Bind" in short or "Set" in short:
+        return "other"
+    return None
+
+
+def sd_to_py(sfile, obj, depth=0):
+    """Comparable Python value for an SDObject (buffers are reduced to a content hash)."""
+    if depth > 16:
+        return None
+    bt = obj.type.basetype
+    if bt in (rd.SDBasic.Chunk, rd.SDBasic.Struct):
+        return {
+            obj.GetChild(i).name: sd_to_py(sfile, obj.GetChild(i), depth + 1) for i in range(obj.NumChildren())
+        }
+    if bt == rd.SDBasic.Array:
+        return [sd_to_py(sfile, obj.GetChild(i), depth + 1) for i in range(obj.NumChildren())]
+    if bt == rd.SDBasic.Buffer:
+        idx = obj.AsInt()
+        if 0 <= idx < len(sfile.buffers):
+            return hashlib.sha1(bytes(sfile.buffers[idx])).hexdigest()
+        return None
+    if bt == rd.SDBasic.Enum:
+        s = obj.AsString()
+        return s if s else obj.AsInt()
+    if bt in (rd.SDBasic.String, rd.SDBasic.Character):
+        return obj.AsString()
+    if bt == rd.SDBasic.Boolean:
+        return obj.AsBool()
+    if bt == rd.SDBasic.Float:
+        return obj.AsFloat()
+    if bt in (rd.SDBasic.UnsignedInteger, rd.SDBasic.SignedInteger):
+        return obj.AsInt()
+    if bt == rd.SDBasic.Resource:
+        return int(obj.AsResourceId())
+    return None
+
+
+def new_pass(name, event_id):
+    p = {
+        "name": name,
+        "first_event": event_id,
+        "last_event": event_id,
+        "draws": 0,
+        "state_calls": 0,
+        "examples": [],
+    }
+    for c in CATEGORIES:
+        p["redundant_" + c] = 0
+    return p
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    max_examples = int(req.get("max_examples", 8))
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            sfile = controller.GetStructuredFile()
+
+            # (event_id, chunk_index, pass label, is_draw) in submission order.
+            calls = []
+            seen = set()
+            state = {"pass": None}
+
+            def walk(actions, markers):
+                for a in actions:
+                    label = state["pass"] or ("/".join(markers) if markers else "(no pass)")
+                    for ev in a.events:
+                        if int(ev.eventId) in seen:
+                            continue
+                        seen.add(int(ev.eventId))
+                        is_draw = int(ev.eventId) == int(a.eventId) and bool(
+                            a.flags & (rd.ActionFlags.Drawcall | rd.ActionFlags.Dispatch)
+                        )
+                        calls.append((int(ev.eventId), int(ev.chunkIndex), label, is_draw))
+                    if a.flags & rd.ActionFlags.BeginPass:
+                        state["pass"] = a.GetName(sfile)
+                    if len(a.children) > 0:
+                        walk(a.children, markers + [str(a.GetName(sfile))])
+                    if a.flags & rd.ActionFlags.EndPass:
+                        state["pass"] = None
+
+            walk(controller.GetRootActions(), [])
+            calls.sort(key=lambda c: c[0])
+
+            passes = []
+            last = {}
+            total_state_calls = 0
+            total_redundant = 0
+            for event_id, chunk_index, label, is_draw in calls:
+                if chunk_index >= len(sfile.chunks):
+                    continue
+                chunk = sfile.chunks[chunk_index]
+                name = str(chunk.name)
+
+                if not passes or passes[-1]["name"] != label:
+                    passes.append(new_pass(label, event_id))
+                current = passes[-1]
+                current["last_event"] = event_id
+
+                if any(r in name for r in RESET_CALLS):
+                    last.clear()
+                    continue
+                if is_draw:
+                    current["draws"] += 1
+                    continue
+                category = call_category(name)
+                if category is None:
+                    continue
+
+                args = sd_to_py(sfile, chunk)
+                if not isinstance(args, dict):
+                    continue
+                args = {k: v for k, v in args.items() if k.lower() not in IGNORED_ARGS}
+                slot = tuple(sorted((k, json.dumps(v)) for k, v in args.items() if k.lower() in SLOT_ARGS))
+                key = (name, slot)
+                payload = json.dumps(args, sort_keys=True)
+
+                current["state_calls"] += 1
+                total_state_calls += 1
+                previous = last.get(key)
+                last[key] = (event_id, payload)
+                if previous is None or previous[1] != payload:
+                    continue
+
+                current["redundant_" + category] += 1
+                total_redundant += 1
+                if len(current["examples"]) < max_examples:
+                    current["examples"].append(
+                        {
+                            "event_id": event_id,
+                            "call": name,
+                            "category": category,
+                            "previous_event_id": previous[0],
+                        }
+                    )
+
+            write_envelope(
+                True,
+                result={
+                    "capture_path": req["capture_path"],
+                    "total_state_calls": total_state_calls,
+                    "total_redundant": total_redundant,
+                    "passes": [p for p in passes if p["state_calls"] > 0 or p["draws"] > 0],
+                },
+            )
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
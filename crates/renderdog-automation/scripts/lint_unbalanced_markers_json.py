@@ -0,0 +1,237 @@
+"""
+lint_unbalanced_markers_json.py -- Find debug marker begin/end calls that don't pair up.
+
+Request:
+  - capture_path
+  - max_issues: issues returned (default 200; counting continues past it)
+
+RenderDoc silently balances the marker tree it shows, so a missing or extra end call only shows up
+as regions nesting in surprising places. This walks the recorded marker calls instead, in
+submission order:
+
+  - begins: vkCmdBeginDebugUtilsLabelEXT, vkCmdDebugMarkerBeginEXT, BeginEvent, glPushDebugGroup,
+    glPushGroupMarkerEXT
+  - ends: the matching End / Pop calls
+
+and reports:
+
+  - unmatched_end: an end with no open marker
+  - unclosed_begin: a marker still open when the frame ends
+  - spans_command_buffers: a marker begun in one command buffer / list and ended in another
+  - spans_submission: a marker still open at a submission boundary (vkQueueSubmit,
+    ExecuteCommandLists, Present, SwapBuffers)
+
+Queue-level labels (vkQueueBeginDebugUtilsLabelEXT, ID3D12CommandQueue::BeginEvent) are tracked on
+their own stack and may legitimately span submissions, so they are only checked for pairing.
+"""
+
+import json
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "lint_unbalanced_markers_json.request.json"
+RESP_PATH = "lint_unbalanced_markers_json.response.json"
+
+BEGIN_CALLS = ("BeginDebugUtilsLabel", "DebugMarkerBegin", "BeginEvent", "PushDebugGroup", "PushGroupMarker")
+END_CALLS = ("EndDebugUtilsLabel", "DebugMarkerEnd", "EndEvent", "PopDebugGroup", "PopGroupMarker")
+SUBMIT_CALLS = ("QueueSubmit", "ExecuteCommandLists", "Present", "SwapBuffers")
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def classify(name):
+    """("begin" | "end" | "submit" | None, is_queue_level) for a chunk name."""
+    short = name.split("::")[-1]
+    queue = "Queue" in name and "Submit" not in short
+    if any(x in short for x in BEGIN_CALLS):
+        return "begin", queue
+    if any(x in short for x in END_CALLS):
+        return "end", queue
+    if any(x in short for x in SUBMIT_CALLS):
+        return "submit", False
+    return None, False
+
+
+def first_resource(chunk):
+    """The command buffer / list a call was recorded into (its first handle parameter)."""
+    for i in range(chunk.NumChildren()):
+        child = chunk.GetChild(i)
+        if child.type.basetype == rd.SDBasic.Resource:
+            rid = child.AsResourceId()
+            return None if rid == rd.ResourceId.Null() else str(rid)
+    return None
+
+
+def first_string(obj, depth=0):
+    """Marker label: the first string parameter (pLabelName, pMarkerName, message, ...)."""
+    if obj.type.basetype == rd.SDBasic.String:
+        return obj.AsString()
+    if depth < 4:
+        for i in range(obj.NumChildren()):
+            found = first_string(obj.GetChild(i), depth + 1)
+            if found:
+                return found
+    return None
+
+
+def collect_events(actions, out):
+    for a in actions:
+        for ev in a.events:
+            out[int(ev.eventId)] = int(ev.chunkIndex)
+        collect_events(a.children, out)
+    return out
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    max_issues = int(req.get("max_issues") or 200)
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            sfile = controller.GetStructuredFile()
+            events = collect_events(controller.GetRootActions(), {})
+
+            issues = []
+            counts = {"begins": 0, "ends": 0, "issues": 0}
+
+            def report(kind, begin=None, end_event_id=None, detail=None):
+                counts["issues"] += 1
+                if len(issues) < max_issues:
+                    issues.append(
+                        {
+                            "kind": kind,
+                            "name": begin["name"] if begin else None,
+                            "begin_event_id": begin["event_id"] if begin else None,
+                            "end_event_id": end_event_id,
+                            "detail": detail,
+                        }
+                    )
+
+            # Open markers: command-buffer level and queue level.
+            stacks = {False: [], True: []}
+            for event_id, chunk_index in sorted(events.items()):
+                if chunk_index >= len(sfile.chunks):
+                    continue
+                chunk = sfile.chunks[chunk_index]
+                name = str(chunk.name)
+                kind, queue = classify(name)
+                if kind is None:
+                    continue
+
+                if kind == "submit":
+                    for m in stacks[False]:
+                        if not m["spans_submission"]:
+                            m["spans_submission"] = True
+                            report(
+                                "spans_submission",
+                                m,
+                                detail="still open at %s (event %d)" % (name, event_id),
+                            )
+                    continue
+
+                stack = stacks[queue]
+                if kind == "begin":
+                    counts["begins"] += 1
+                    stack.append(
+                        {
+                            "name": first_string(chunk),
+                            "event_id": event_id,
+                            "recorder": first_resource(chunk),
+                            "spans_submission": False,
+                        }
+                    )
+                    continue
+
+                counts["ends"] += 1
+                if not stack:
+                    report("unmatched_end", end_event_id=event_id, detail=name + " with no open marker")
+                    continue
+                m = stack.pop()
+                recorder = first_resource(chunk)
+                if not queue and m["recorder"] is not None and recorder is not None and m["recorder"] != recorder:
+                    report(
+                        "spans_command_buffers",
+                        m,
+                        end_event_id=event_id,
+                        detail="begun in %s, ended in %s" % (m["recorder"], recorder),
+                    )
+
+            for queue in (False, True):
+                for m in stacks[queue]:
+                    report("unclosed_begin", m, detail="still open at the end of the frame")
+
+            write_envelope(
+                True,
+                result={
+                    "capture_path": req["capture_path"],
+                    "total_begins": counts["begins"],
+                    "total_ends": counts["ends"],
+                    "total_issues": counts["issues"],
+                    "truncated": counts["issues"] > len(issues),
+                    "issues": issues,
+                },
+            )
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
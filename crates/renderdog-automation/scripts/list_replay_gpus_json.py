@@ -0,0 +1,91 @@
+"""
+list_replay_gpus_json.py -- RenderDoc Python script that lists the adapters available to replay a capture.
+
+Opens the capture file (without starting a replay) and reports every GPU RenderDoc can replay on
+this machine, in the order RenderDoc enumerates them. The vendor, device ID and driver name match
+the `gpu_vendor`, `gpu_device_id` and `gpu_driver_name` replay options, so an adapter listed here
+can be pinned for later replays.
+
+Request parameters:
+  - capture_path: Path to the .rdc capture file (required)
+
+Returns:
+  - driver_name: API the capture was recorded with
+  - local_replay_support: whether this machine can replay the capture at all
+  - gpus: [{index, name, vendor, device_id, driver, driver_version, apis, supports_capture_api}]
+"""
+
+import json
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "list_replay_gpus_json.request.json"
+RESP_PATH = "list_replay_gpus_json.response.json"
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def enum_name(value) -> str:
+    return str(value).split(".")[-1]
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        driver_name = str(cap.DriverName())
+        # GraphicsAPI names (D3D11, D3D12, OpenGL, Vulkan) prefix the driver names ("OpenGL ES").
+        capture_api = driver_name.replace(" ", "")
+
+        gpus = []
+        for i, g in enumerate(cap.GetAvailableGPUs()):
+            apis = [enum_name(a) for a in g.apis]
+            gpus.append(
+                {
+                    "index": i,
+                    "name": str(g.name),
+                    "vendor": enum_name(g.vendor),
+                    "device_id": int(g.deviceID),
+                    "driver": str(g.driver),
+                    "driver_version": str(g.driverVersion) or None,
+                    "apis": apis,
+                    "supports_capture_api": any(capture_api.startswith(a) for a in apis),
+                }
+            )
+
+        write_envelope(
+            True,
+            result={
+                "capture_path": req["capture_path"],
+                "driver_name": driver_name,
+                "local_replay_support": bool(cap.LocalReplaySupport()),
+                "gpus": gpus,
+            },
+        )
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
@@ -0,0 +1,97 @@
+"""
+read_capture_sections_json.py -- RenderDoc Python script that reads the named sections of a capture.
+
+Uses the CaptureFile section API, so it works for any capture RenderDoc can open (no replay).
+Sections are returned with their properties and, when requested, their contents: UTF-8 text
+(notes, JSON metadata, repro commands, ...) as `contents`, anything else base64-encoded as
+`contents_base64`.
+
+Request parameters:
+  - capture_path: Path to the .rdc capture file (required)
+  - names: Only return these sections (empty = all)
+  - include_contents: Default true
+
+Returns:
+  - sections: [{index, name, section_type, version, flags, compressed_size, uncompressed_size,
+                contents?, contents_base64?}]
+"""
+
+import base64
+import json
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "read_capture_sections_json.request.json"
+RESP_PATH = "read_capture_sections_json.response.json"
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def enum_name(value) -> str:
+    return str(value).split(".")[-1]
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    names = set(req.get("names") or [])
+    include_contents = bool(req.get("include_contents", True))
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        sections = []
+        for index in range(cap.GetSectionCount()):
+            props = cap.GetSectionProperties(index)
+            if names and props.name not in names:
+                continue
+            section = {
+                "index": index,
+                "name": str(props.name),
+                "section_type": enum_name(props.type),
+                "version": int(props.version),
+                "flags": enum_name(props.flags),
+                "compressed_size": int(props.compressedSize),
+                "uncompressed_size": int(props.uncompressedSize),
+            }
+            if include_contents:
+                data = bytes(cap.GetSectionContents(index))
+                try:
+                    section["contents"] = data.decode("utf-8")
+                except UnicodeDecodeError:
+                    section["contents_base64"] = base64.b64encode(data).decode("ascii")
+            sections.append(section)
+
+        missing = sorted(names - {s["name"] for s in sections})
+        if missing:
+            raise RuntimeError("Sections not found: " + ", ".join(missing))
+
+        write_envelope(
+            True,
+            result={"capture_path": req["capture_path"], "sections": sections},
+        )
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
@@ -0,0 +1,330 @@
+"""
+readback_rw_buffers_json.py -- Read back the read-write (storage / UAV) buffers bound at an event.
+
+Request:
+  - capture_path
+  - event_id: typically a compute dispatch; contents are read after the event executes
+  - first_element, max_elements: window of elements decoded per buffer
+
+Every buffer bound to a read-write slot of any stage at the event is read over its bound range
+and decoded with the struct layout reflected for that slot. Each element is an object keyed by
+flattened field path (`value`, `pos[0]`, `lights[2].color[1]`, ...). Typed buffers without a
+struct layout (e.g. `RWBuffer<float4>`) are reported with `decoded: false`.
+"""
+
+import json
+import math
+import struct
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "readback_rw_buffers_json.request.json"
+RESP_PATH = "readback_rw_buffers_json.response.json"
+
+STAGES = [
+    ("Vertex", rd.ShaderStage.Vertex),
+    ("TCS", rd.ShaderStage.Tess_Control),
+    ("TES", rd.ShaderStage.Tess_Eval),
+    ("Geometry", rd.ShaderStage.Geometry),
+    ("Fragment", rd.ShaderStage.Fragment),
+    ("Task", rd.ShaderStage.Task),
+    ("Mesh", rd.ShaderStage.Mesh),
+    ("Compute", rd.ShaderStage.Compute),
+]
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def vartype_to_struct_char(vartype):
+    """Map a renderdoc VarType enum to a Python struct format character."""
+    mapping = {
+        rd.VarType.Float:  'f',
+        rd.VarType.Half:   'e',
+        rd.VarType.Double: 'd',
+        rd.VarType.SInt:   'i',
+        rd.VarType.UInt:   'I',
+        rd.VarType.SShort: 'h',
+        rd.VarType.UShort: 'H',
+        rd.VarType.SByte:  'b',
+        rd.VarType.UByte:  'B',
+        rd.VarType.SLong:  'q',
+        rd.VarType.ULong:  'Q',
+        rd.VarType.Bool:   'I',
+    }
+    return mapping.get(vartype, None)
+
+
+def vartype_byte_size(vartype):
+    """Byte size of a single scalar for the given VarType."""
+    sizes = {
+        rd.VarType.Float:  4,
+        rd.VarType.Half:   2,
+        rd.VarType.Double: 8,
+        rd.VarType.SInt:   4,
+        rd.VarType.UInt:   4,
+        rd.VarType.SShort: 2,
+        rd.VarType.UShort: 2,
+        rd.VarType.SByte:  1,
+        rd.VarType.UByte:  1,
+        rd.VarType.SLong:  8,
+        rd.VarType.ULong:  8,
+        rd.VarType.Bool:   4,
+    }
+    return sizes.get(vartype, 4)
+
+
+class FieldDef:
+    """A single scalar column we'll read from the buffer."""
+    __slots__ = ('name', 'byte_offset', 'struct_char')
+
+    def __init__(self, name, byte_offset, struct_char):
+        self.name = name
+        self.byte_offset = byte_offset
+        self.struct_char = struct_char
+
+
+def flatten_constant_type(prefix, const, base_offset):
+    """Recursively flatten a ShaderConstant into a list of FieldDef."""
+    ctype = const.type
+    abs_offset = base_offset + const.byteOffset
+    field_name = ("%s.%s" % (prefix, const.name)) if prefix else const.name
+
+    if len(ctype.members) > 0:
+        fields = []
+        arr_count = max(ctype.elements, 1)
+        for arr_i in range(arr_count):
+            arr_prefix = ("%s[%d]" % (field_name, arr_i)) if arr_count > 1 else field_name
+            elem_offset = abs_offset + arr_i * ctype.arrayByteStride if arr_count > 1 else abs_offset
+            for member in ctype.members:
+                fields.extend(flatten_constant_type(arr_prefix, member, elem_offset))
+        return fields
+
+    char = vartype_to_struct_char(ctype.baseType)
+    if char is None:
+        return []
+
+    scalar_size = vartype_byte_size(ctype.baseType)
+    fields = []
+
+    arr_count = max(ctype.elements, 1)
+    rows = max(ctype.rows, 1)
+    cols = max(ctype.columns, 1)
+    total_scalars = rows * cols
+
+    for arr_i in range(arr_count):
+        arr_name = ("%s[%d]" % (field_name, arr_i)) if arr_count > 1 else field_name
+
+        if arr_count > 1:
+            elem_base = abs_offset + arr_i * ctype.arrayByteStride
+        else:
+            elem_base = abs_offset
+
+        if total_scalars == 1:
+            fields.append(FieldDef(arr_name, elem_base, char))
+        else:
+            for r in range(rows):
+                for c in range(cols):
+                    comp_idx = r * cols + c
+                    if rows > 1 and cols > 1:
+                        comp_name = "%s[%d][%d]" % (arr_name, r, c)
+                    else:
+                        comp_name = "%s[%d]" % (arr_name, comp_idx)
+                    comp_offset = elem_base + comp_idx * scalar_size
+                    fields.append(FieldDef(comp_name, comp_offset, char))
+
+    return fields
+
+
+def extract_fields_from_resource(shader_res):
+    """Given a ShaderResource from reflection, flatten its variableType into FieldDefs."""
+    var_type = shader_res.variableType
+
+    if len(var_type.members) == 0:
+        raise RuntimeError(
+            "Shader resource '%s' has no struct members in its type. "
+            "The buffer may not be a structured buffer." % shader_res.name
+        )
+
+    members = var_type.members
+
+    if len(members) == 1 and len(members[0].type.members) > 0:
+        inner = members[0]
+        members = inner.type.members
+
+    fields = []
+    for member in members:
+        fields.extend(flatten_constant_type("", member, 0))
+
+    if not fields:
+        raise RuntimeError("Failed to extract any fields from the struct layout.")
+
+    last = max(fields, key=lambda f: f.byte_offset)
+    stride = last.byte_offset + struct.calcsize(last.struct_char)
+
+    if var_type.arrayByteStride > 0:
+        stride = var_type.arrayByteStride
+    elif (len(shader_res.variableType.members) == 1
+          and shader_res.variableType.members[0].type.arrayByteStride > 0):
+        stride = shader_res.variableType.members[0].type.arrayByteStride
+
+    return fields, stride
+
+
+def json_scalar(v):
+    """JSON-safe value: non-finite floats become the strings "nan", "inf" and "-inf"."""
+    if isinstance(v, float) and not math.isfinite(v):
+        return str(v)
+    return v
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    event_id = int(req["event_id"])
+    first_element = int(req.get("first_element") or 0)
+    max_elements = int(req.get("max_elements", 256))
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            controller.SetFrameEvent(event_id, True)
+            state = controller.GetPipelineState()
+            names = {int(r.resourceId): str(r.name) for r in controller.GetResources()}
+            buffers = {int(b.resourceId): b for b in controller.GetBuffers()}
+
+            out = []
+            seen = set()
+            for stage_name, stage in STAGES:
+                refl = state.GetShaderReflection(stage)
+                if refl is None:
+                    continue
+                for used in state.GetReadWriteResources(stage):
+                    desc = used.descriptor
+                    rid = int(desc.resource)
+                    idx = int(used.access.index)
+                    if rid not in buffers or idx >= len(refl.readWriteResources):
+                        continue
+                    res = refl.readWriteResources[idx]
+                    key = (rid, int(desc.byteOffset), str(res.name))
+                    if key in seen:
+                        continue
+                    seen.add(key)
+
+                    offset = int(desc.byteOffset)
+                    size = int(desc.byteSize)
+                    if size == 0 or offset + size > int(buffers[rid].length):
+                        size = int(buffers[rid].length) - offset
+                    entry = {
+                        "stage": stage_name,
+                        "name": str(res.name),
+                        "set": int(res.fixedBindSetOrSpace),
+                        "binding": int(res.fixedBindNumber),
+                        "array_element": int(used.access.arrayElement),
+                        "resource_id": rid,
+                        "resource_name": names.get(rid, ""),
+                        "byte_offset": offset,
+                        "byte_size": size,
+                        "decoded": False,
+                        "stride": None,
+                        "element_count": None,
+                        "first_element": first_element,
+                        "elements": [],
+                        "error": None,
+                    }
+                    out.append(entry)
+
+                    try:
+                        fields, stride = extract_fields_from_resource(res)
+                    except RuntimeError as e:
+                        entry["error"] = str(e)
+                        continue
+                    stride = int(stride)
+                    count = size // stride if stride > 0 else 0
+                    entry["decoded"] = True
+                    entry["stride"] = stride
+                    entry["element_count"] = count
+
+                    last = min(count, first_element + max_elements)
+                    if last <= first_element:
+                        continue
+                    start = offset + first_element * stride
+                    data = bytes(controller.GetBufferData(desc.resource, start, (last - first_element) * stride))
+                    for i in range(last - first_element):
+                        base = i * stride
+                        entry["elements"].append(
+                            {
+                                fd.name: json_scalar(struct.unpack_from("<" + fd.struct_char, data, base + fd.byte_offset)[0])
+                                for fd in fields
+                            }
+                        )
+
+            write_envelope(
+                True,
+                result={
+                    "capture_path": req["capture_path"],
+                    "event_id": event_id,
+                    "buffers": out,
+                },
+            )
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
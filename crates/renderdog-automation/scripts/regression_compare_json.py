@@ -0,0 +1,226 @@
+"""
+regression_compare_json.py -- Compare golden PNGs against freshly replayed ones.
+
+Request:
+  - pairs: [{golden, actual, diff_output_path}]
+  - tolerances: {max_delta, max_diff_pixels, min_psnr, min_ssim}
+
+Channel values are normalized to [0, 1]. A texel differs when any channel differs by more than
+`max_delta`. Returns per-pair metrics and the tolerance checks that failed. Runs without opening
+a capture; only the PNGs are read.
+"""
+
+import json
+import math
+import os
+import struct
+import traceback
+import zlib
+
+
+REQ_PATH = "regression_compare_json.request.json"
+RESP_PATH = "regression_compare_json.response.json"
+
+SSIM_BLOCK = 8
+SSIM_C1 = 0.01**2
+SSIM_C2 = 0.03**2
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def read_png(path):
+    """(width, height, channels, [normalized values]) for non-interlaced 8/16-bit PNGs."""
+    with open(path, "rb") as f:
+        data = f.read()
+    if data[:8] != b"\x89PNG\r\n\x1a\n":
+        raise RuntimeError("Not a PNG file: " + path)
+
+    pos = 8
+    idat = bytearray()
+    width = height = depth = color = interlace = None
+    while pos < len(data):
+        length, tag = struct.unpack(">I4s", data[pos : pos + 8])
+        body = data[pos + 8 : pos + 8 + length]
+        pos += 12 + length
+        if tag == b"IHDR":
+            width, height, depth, color, _, _, interlace = struct.unpack(">IIBBBBB", body)
+        elif tag == b"IDAT":
+            idat.extend(body)
+        elif tag == b"IEND":
+            break
+
+    channels = {0: 1, 2: 3, 4: 2, 6: 4}.get(color)
+    if channels is None or depth not in (8, 16) or interlace != 0:
+        raise RuntimeError("Unsupported PNG layout (color=%s depth=%s interlace=%s): %s" % (color, depth, interlace, path))
+
+    bpp = channels * depth // 8
+    stride = width * bpp
+    raw = zlib.decompress(bytes(idat))
+    out = bytearray(stride * height)
+    prev = bytearray(stride)
+    src = 0
+    for y in range(height):
+        ftype = raw[src]
+        line = bytearray(raw[src + 1 : src + 1 + stride])
+        src += 1 + stride
+        if ftype == 1:
+            for i in range(bpp, stride):
+                line[i] = (line[i] + line[i - bpp]) & 0xFF
+        elif ftype == 2:
+            for i in range(stride):
+                line[i] = (line[i] + prev[i]) & 0xFF
+        elif ftype == 3:
+            for i in range(stride):
+                left = line[i - bpp] if i >= bpp else 0
+                line[i] = (line[i] + ((left + prev[i]) >> 1)) & 0xFF
+        elif ftype == 4:
+            for i in range(stride):
+                a = line[i - bpp] if i >= bpp else 0
+                b = prev[i]
+                c = prev[i - bpp] if i >= bpp else 0
+                p = a + b - c
+                pa, pb, pc = abs(p - a), abs(p - b), abs(p - c)
+                pred = a if pa <= pb and pa <= pc else (b if pb <= pc else c)
+                line[i] = (line[i] + pred) & 0xFF
+        out[y * stride : (y + 1) * stride] = line
+        prev = line
+
+    if depth == 8:
+        values = [v / 255.0 for v in out]
+    else:
+        values = [v / 65535.0 for v in struct.unpack(">%dH" % (len(out) // 2), bytes(out))]
+    return width, height, channels, values
+
+
+def write_grey_png(path, width, height, pixels):
+    def chunk(tag, payload):
+        body = tag + payload
+        return struct.pack(">I", len(payload)) + body + struct.pack(">I", zlib.crc32(body) & 0xFFFFFFFF)
+
+    raw = bytearray()
+    for y in range(height):
+        raw.append(0)
+        raw.extend(pixels[y * width : (y + 1) * width])
+    parent = os.path.dirname(path)
+    if parent:
+        os.makedirs(parent, exist_ok=True)
+    with open(path, "wb") as f:
+        f.write(b"\x89PNG\r\n\x1a\n")
+        f.write(chunk(b"IHDR", struct.pack(">IIBBBBB", width, height, 8, 0, 0, 0, 0)))
+        f.write(chunk(b"IDAT", zlib.compress(bytes(raw), 6)))
+        f.write(chunk(b"IEND", b""))
+
+
+def luminance(values, channels, texel):
+    base = texel * channels
+    if channels >= 3:
+        return 0.2126 * values[base] + 0.7152 * values[base + 1] + 0.0722 * values[base + 2]
+    return values[base]
+
+
+def block_ssim(a, b, width, height):
+    total = 0.0
+    blocks = 0
+    for by in range(0, height - SSIM_BLOCK + 1, SSIM_BLOCK):
+        for bx in range(0, width - SSIM_BLOCK + 1, SSIM_BLOCK):
+            xs = []
+            ys = []
+            for y in range(by, by + SSIM_BLOCK):
+                row = y * width
+                xs.extend(a[row + bx : row + bx + SSIM_BLOCK])
+                ys.extend(b[row + bx : row + bx + SSIM_BLOCK])
+            count = float(len(xs))
+            mx = sum(xs) / count
+            my = sum(ys) / count
+            vx = sum((v - mx) ** 2 for v in xs) / count
+            vy = sum((v - my) ** 2 for v in ys) / count
+            cov = sum((xs[i] - mx) * (ys[i] - my) for i in range(len(xs))) / count
+            total += ((2 * mx * my + SSIM_C1) * (2 * cov + SSIM_C2)) / (
+                (mx * mx + my * my + SSIM_C1) * (vx + vy + SSIM_C2)
+            )
+            blocks += 1
+    if blocks == 0:
+        return 1.0 if a == b else 0.0
+    return total / blocks
+
+
+def compare(pair, tol):
+    gw, gh, gc, gv = read_png(pair["golden"])
+    aw, ah, ac, av = read_png(pair["actual"])
+    if (gw, gh) != (aw, ah):
+        return {
+            "diff_pixels": gw * gh,
+            "max_delta": 1.0,
+            "psnr": None,
+            "ssim": 0.0,
+            "diff_output_path": None,
+            "failures": ["size mismatch: golden %dx%d, actual %dx%d" % (gw, gh, aw, ah)],
+        }
+
+    n = min(gc, ac)
+    texels = gw * gh
+    max_allowed = float(tol.get("max_delta", 0.0))
+    deltas = [0.0] * texels
+    sq_sum = 0.0
+    diff_pixels = 0
+    for t in range(texels):
+        worst = 0.0
+        for c in range(n):
+            d = abs(gv[t * gc + c] - av[t * ac + c])
+            sq_sum += d * d
+            if d > worst:
+                worst = d
+        deltas[t] = worst
+        if worst > max_allowed:
+            diff_pixels += 1
+
+    max_delta = max(deltas) if deltas else 0.0
+    mse = sq_sum / float(max(texels * n, 1))
+    psnr = None if mse == 0 else 10.0 * math.log10(1.0 / mse)
+    lg = [luminance(gv, gc, t) for t in range(texels)]
+    la = [luminance(av, ac, t) for t in range(texels)]
+    ssim = block_ssim(lg, la, gw, gh)
+
+    failures = []
+    if diff_pixels > int(tol.get("max_diff_pixels", 0)):
+        failures.append("%d texels differ by more than %g (allowed %d)" % (diff_pixels, max_allowed, int(tol.get("max_diff_pixels", 0))))
+    min_psnr = tol.get("min_psnr")
+    if min_psnr is not None and psnr is not None and psnr < float(min_psnr):
+        failures.append("PSNR %.2f dB below %.2f dB" % (psnr, float(min_psnr)))
+    min_ssim = tol.get("min_ssim")
+    if min_ssim is not None and ssim < float(min_ssim):
+        failures.append("SSIM %.4f below %.4f" % (ssim, float(min_ssim)))
+
+    diff_path = None
+    if failures and pair.get("diff_output_path"):
+        diff_path = pair["diff_output_path"]
+        scale = 255.0 / max_delta if max_delta > 0 else 0.0
+        write_grey_png(diff_path, gw, gh, bytes(min(255, int(d * scale + 0.5)) for d in deltas))
+
+    return {
+        "diff_pixels": diff_pixels,
+        "max_delta": max_delta,
+        "psnr": psnr,
+        "ssim": ssim,
+        "diff_output_path": diff_path,
+        "failures": failures,
+    }
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    tol = req.get("tolerances") or {}
+    write_envelope(True, result={"results": [compare(pair, tol) for pair in req["pairs"]]})
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
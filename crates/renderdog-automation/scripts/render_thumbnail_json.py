@@ -0,0 +1,213 @@
+"""
+render_thumbnail_json.py -- Replay to an event and save a downscaled RT0 as a PNG preview.
+
+Request:
+  - capture_path
+  - event_id
+  - output_path: PNG path
+  - max_dim: longest side of the thumbnail (the image is never upscaled)
+
+RT0 is saved as an 8-bit BMP next to the output (SaveTexture cannot resize), box-filtered down
+in Python and re-encoded as PNG. Alpha is dropped so previews of transparent targets stay
+readable.
+"""
+
+import json
+import os
+import struct
+import traceback
+import zlib
+
+import renderdoc as rd
+
+
+REQ_PATH = "render_thumbnail_json.request.json"
+RESP_PATH = "render_thumbnail_json.response.json"
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def bound_resource_id(br):
+    rid = getattr(br, "resourceId", None)
+    if rid is None:
+        rid = getattr(br, "resource", None)
+    return rid
+
+
+def read_bmp(path):
+    """(width, height, top-down rows of RGB bytes) from an uncompressed 24/32-bit BMP."""
+    with open(path, "rb") as f:
+        data = f.read()
+    if data[:2] != b"BM":
+        raise RuntimeError("SaveTexture did not write a BMP")
+    (offset,) = struct.unpack("<I", data[10:14])
+    width, height, _, bpp, compression = struct.unpack("<iiHHI", data[18:34])
+    if bpp not in (24, 32) or compression not in (0, 3):
+        raise RuntimeError("unsupported BMP layout: %d bpp, compression %d" % (bpp, compression))
+    bottom_up = height > 0
+    height = abs(height)
+    step = bpp // 8
+    stride = (width * step + 3) & ~3
+    rows = []
+    for y in range(height):
+        start = offset + y * stride
+        row = data[start : start + width * step]
+        rgb = bytearray(width * 3)
+        rgb[0::3] = row[2::step]
+        rgb[1::3] = row[1::step]
+        rgb[2::3] = row[0::step]
+        rows.append(bytes(rgb))
+    if bottom_up:
+        rows.reverse()
+    return width, height, rows
+
+
+def downscale(width, height, rows, out_w, out_h):
+    """Average a 2x2 set of samples from each source block (cheap box filter)."""
+    out = []
+    for oy in range(out_h):
+        y0 = oy * height // out_h
+        y1 = max(min((oy + 1) * height // out_h - 1, height - 1), y0)
+        r0, r1 = rows[y0], rows[y1]
+        line = bytearray(out_w * 3)
+        for ox in range(out_w):
+            x0 = ox * width // out_w
+            x1 = max(min((ox + 1) * width // out_w - 1, width - 1), x0)
+            for c in range(3):
+                line[ox * 3 + c] = (
+                    r0[x0 * 3 + c] + r0[x1 * 3 + c] + r1[x0 * 3 + c] + r1[x1 * 3 + c] + 2
+                ) // 4
+        out.append(bytes(line))
+    return out
+
+
+def write_png(path, width, height, rows):
+    def chunk(ctype, body):
+        return struct.pack(">I", len(body)) + ctype + body + struct.pack(">I", zlib.crc32(ctype + body))
+
+    header = struct.pack(">IIBBBBB", width, height, 8, 2, 0, 0, 0)
+    raw = b"".join(b"\x00" + row for row in rows)
+    with open(path, "wb") as f:
+        f.write(b"\x89PNG\r\n\x1a\n")
+        f.write(chunk(b"IHDR", header))
+        f.write(chunk(b"IDAT", zlib.compress(raw, 9)))
+        f.write(chunk(b"IEND", b""))
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    event_id = int(req["event_id"])
+    max_dim = int(req.get("max_dim", 256))
+    if max_dim <= 0:
+        raise RuntimeError("max_dim must be positive")
+    output_path = req["output_path"]
+    out_dir = os.path.dirname(output_path)
+    if out_dir:
+        os.makedirs(out_dir, exist_ok=True)
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            controller.SetFrameEvent(event_id, True)
+            targets = controller.GetPipelineState().GetOutputTargets()
+            rid = bound_resource_id(targets[0]) if len(targets) > 0 else None
+            if rid is None or rid == rd.ResourceId.Null():
+                raise RuntimeError("no colour target bound at event %d" % event_id)
+
+            save = rd.TextureSave()
+            save.resourceId = rid
+            save.destType = rd.FileType.BMP
+            save.mip = int(getattr(targets[0], "firstMip", 0))
+            save.slice.sliceIndex = int(getattr(targets[0], "firstSlice", 0))
+            save.alpha = rd.AlphaMapping.Discard
+
+            tmp_path = output_path + ".tmp.bmp"
+            result = controller.SaveTexture(save, tmp_path)
+            if result != rd.ResultCode.Succeeded:
+                raise RuntimeError("SaveTexture failed: " + str(result))
+            try:
+                width, height, rows = read_bmp(tmp_path)
+            finally:
+                os.remove(tmp_path)
+
+            scale = min(1.0, float(max_dim) / max(width, height))
+            out_w = max(1, int(round(width * scale)))
+            out_h = max(1, int(round(height * scale)))
+            if (out_w, out_h) != (width, height):
+                rows = downscale(width, height, rows, out_w, out_h)
+            write_png(output_path, out_w, out_h, rows)
+
+            write_envelope(
+                True,
+                result={
+                    "capture_path": req["capture_path"],
+                    "event_id": event_id,
+                    "output_path": output_path,
+                    "resource_id": int(rid),
+                    "source_width": width,
+                    "source_height": height,
+                    "width": out_w,
+                    "height": out_h,
+                },
+            )
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
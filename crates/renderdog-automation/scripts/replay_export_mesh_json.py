@@ -0,0 +1,329 @@
+"""
+replay_export_mesh_json.py -- Export post-VS / post-GS mesh data of one event to OBJ or glTF.
+
+Request:
+  - capture_path
+  - event_id (optional; defaults to the last drawcall)
+  - stage: "vs" | "gs"
+  - format: "obj" | "gltf"
+  - output_path
+  - instance, view
+  - perspective_divide: divide positions by w
+"""
+
+import base64
+import json
+import os
+import struct
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "replay_export_mesh_json.request.json"
+RESP_PATH = "replay_export_mesh_json.response.json"
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def flatten_actions(actions):
+    out = []
+    for a in actions:
+        out.append(a)
+        out.extend(flatten_actions(a.children))
+    return out
+
+
+def pick_default_event_id(controller) -> int:
+    actions = flatten_actions(controller.GetRootActions())
+    drawcalls = [a for a in actions if a.flags & rd.ActionFlags.Drawcall]
+    if not drawcalls:
+        raise RuntimeError("Capture has no drawcalls")
+    return int(max(a.eventId for a in drawcalls))
+
+
+def component_format(attr):
+    """struct format char + byte width for one component of a post-transform output."""
+    var_type = getattr(attr, "varType", None)
+    if var_type is not None:
+        if var_type == rd.VarType.Double:
+            return "d", 8
+        if var_type == rd.VarType.UInt:
+            return "I", 4
+        if var_type == rd.VarType.SInt:
+            return "i", 4
+        return "f", 4
+    comp_type = getattr(attr, "compType", None)
+    if comp_type == rd.CompType.Double:
+        return "d", 8
+    if comp_type == rd.CompType.UInt:
+        return "I", 4
+    if comp_type == rd.CompType.SInt:
+        return "i", 4
+    return "f", 4
+
+
+def output_layout(refl):
+    """List of (name, is_position, fmt_char, comp_count, byte_offset) in post-transform buffer order.
+
+    RenderDoc stores the position first and pads every output to 4 components.
+    """
+    sigs = list(refl.outputSignature)
+    sigs.sort(key=lambda s: 0 if s.systemValue == rd.ShaderBuiltin.Position else 1)
+
+    layout = []
+    offset = 0
+    for sig in sigs:
+        fmt, width = component_format(sig)
+        name = sig.varName or sig.semanticIdxName or ("out%d" % len(layout))
+        is_pos = sig.systemValue == rd.ShaderBuiltin.Position
+        layout.append((name, is_pos, fmt, int(sig.compCount), offset))
+        offset += width * 4
+    return layout
+
+
+def read_indices(controller, mesh):
+    if mesh.numIndices == 0:
+        return []
+    if mesh.indexResourceId == rd.ResourceId.Null() or mesh.indexByteStride == 0:
+        return list(range(mesh.numIndices))
+    fmt = {1: "B", 2: "H", 4: "I"}[mesh.indexByteStride]
+    data = controller.GetBufferData(
+        mesh.indexResourceId, mesh.indexByteOffset, mesh.numIndices * mesh.indexByteStride
+    )
+    return [i + mesh.baseVertex for i in struct.unpack_from("<%d%s" % (mesh.numIndices, fmt), data, 0)]
+
+
+def primitives(topology, indices):
+    """(kind, list of index tuples) for the topology; kind is "triangles", "lines" or "points"."""
+    T = rd.Topology
+    n = len(indices)
+    if topology == T.TriangleList:
+        return "triangles", [tuple(indices[i : i + 3]) for i in range(0, n - 2, 3)]
+    if topology == T.TriangleStrip:
+        tris = []
+        for i in range(n - 2):
+            a, b, c = indices[i], indices[i + 1], indices[i + 2]
+            tris.append((a, b, c) if i % 2 == 0 else (b, a, c))
+        return "triangles", tris
+    if topology == T.TriangleFan:
+        return "triangles", [(indices[0], indices[i], indices[i + 1]) for i in range(1, n - 1)]
+    if topology == T.LineList:
+        return "lines", [tuple(indices[i : i + 2]) for i in range(0, n - 1, 2)]
+    if topology == T.LineStrip:
+        return "lines", [(indices[i], indices[i + 1]) for i in range(n - 1)]
+    if topology == T.PointList:
+        return "points", [(i,) for i in indices]
+    raise RuntimeError("Unsupported topology for mesh export: " + str(topology))
+
+
+def write_obj(path, positions, prims_kind, prims, attributes):
+    with open(path, "w", encoding="utf-8") as f:
+        f.write("# exported by renderdog (post-transform mesh)\n")
+        f.write("# attributes: %s\n" % ", ".join(attributes))
+        for p in positions:
+            f.write("v %.9g %.9g %.9g\n" % (p[0], p[1], p[2]))
+        tag = {"triangles": "f", "lines": "l", "points": "p"}[prims_kind]
+        for prim in prims:
+            f.write(tag + " " + " ".join(str(i + 1) for i in prim) + "\n")
+
+
+def write_gltf(path, positions, prims_kind, prims, extra):
+    """glTF 2.0 with an embedded buffer; non-position outputs become `_NAME` attributes."""
+    blob = bytearray()
+    buffer_views = []
+    accessors = []
+
+    def add_accessor(data, comp_type, count, acc_type, target, minmax=None):
+        while len(blob) % 4:
+            blob.append(0)
+        buffer_views.append({"buffer": 0, "byteOffset": len(blob), "byteLength": len(data), "target": target})
+        blob.extend(data)
+        acc = {"bufferView": len(buffer_views) - 1, "componentType": comp_type, "count": count, "type": acc_type}
+        if minmax is not None:
+            acc["min"], acc["max"] = minmax
+        accessors.append(acc)
+        return len(accessors) - 1
+
+    pos3 = [(p[0], p[1], p[2]) for p in positions]
+    pos_bytes = b"".join(struct.pack("<3f", *p) for p in pos3)
+    bounds = None
+    if pos3:
+        bounds = ([min(p[i] for p in pos3) for i in range(3)], [max(p[i] for p in pos3) for i in range(3)])
+    attrs = {"POSITION": add_accessor(pos_bytes, 5126, len(pos3), "VEC3", 34962, bounds)}
+
+    for name, values in extra:
+        comps = len(values[0]) if values else 4
+        acc_type = {1: "SCALAR", 2: "VEC2", 3: "VEC3", 4: "VEC4"}[comps]
+        data = b"".join(struct.pack("<%df" % comps, *[float(x) for x in v]) for v in values)
+        key = "_" + "".join(c if c.isalnum() else "_" for c in name).upper()
+        attrs[key] = add_accessor(data, 5126, len(values), acc_type, 34962)
+
+    flat = [i for prim in prims for i in prim]
+    idx_acc = add_accessor(struct.pack("<%dI" % len(flat), *flat), 5125, len(flat), "SCALAR", 34963)
+    mode = {"triangles": 4, "lines": 1, "points": 0}[prims_kind]
+
+    doc = {
+        "asset": {"version": "2.0", "generator": "renderdog"},
+        "scene": 0,
+        "scenes": [{"nodes": [0]}],
+        "nodes": [{"mesh": 0}],
+        "meshes": [{"primitives": [{"attributes": attrs, "indices": idx_acc, "mode": mode}]}],
+        "buffers": [
+            {
+                "byteLength": len(blob),
+                "uri": "data:application/octet-stream;base64," + base64.b64encode(bytes(blob)).decode("ascii"),
+            }
+        ],
+        "bufferViews": buffer_views,
+        "accessors": accessors,
+    }
+    with open(path, "w", encoding="utf-8") as f:
+        json.dump(doc, f)
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    stage_name = str(req.get("stage", "vs")).lower()
+    if stage_name not in ("vs", "gs"):
+        raise RuntimeError("stage must be 'vs' or 'gs', got: " + stage_name)
+    fmt_name = str(req.get("format", "obj")).lower()
+    if fmt_name not in ("obj", "gltf"):
+        raise RuntimeError("format must be 'obj' or 'gltf', got: " + fmt_name)
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            event_id = req.get("event_id")
+            if event_id is None:
+                event_id = pick_default_event_id(controller)
+            controller.SetFrameEvent(int(event_id), True)
+
+            state = controller.GetPipelineState()
+            if stage_name == "gs":
+                shader_stage = rd.ShaderStage.Geometry
+                mesh_stage = rd.MeshDataStage.GSOut
+            else:
+                shader_stage = rd.ShaderStage.Vertex
+                mesh_stage = rd.MeshDataStage.VSOut
+
+            refl = state.GetShaderReflection(shader_stage)
+            if refl is None:
+                raise RuntimeError("No %s shader bound at event %d" % (stage_name.upper(), int(event_id)))
+
+            mesh = controller.GetPostVSData(int(req.get("instance", 0)), int(req.get("view", 0)), mesh_stage)
+            if mesh.vertexResourceId == rd.ResourceId.Null():
+                raise RuntimeError("No post-%s data at event %d" % (stage_name.upper(), int(event_id)))
+
+            layout = output_layout(refl)
+            indices = read_indices(controller, mesh)
+            vertex_count = (max(indices) + 1) if indices else 0
+
+            vb = controller.GetBufferData(mesh.vertexResourceId, mesh.vertexByteOffset, 0)
+            stride = int(mesh.vertexByteStride)
+
+            columns = {name: [] for (name, _, _, _, _) in layout}
+            for v in range(vertex_count):
+                base = v * stride
+                for name, _, fmt, count, offset in layout:
+                    columns[name].append(struct.unpack_from("<%d%s" % (count, fmt), vb, base + offset))
+
+            pos_name = next((name for (name, is_pos, _, _, _) in layout if is_pos), None)
+            if pos_name is None:
+                raise RuntimeError("Shader has no position output")
+            positions = []
+            for p in columns[pos_name]:
+                p = list(p) + [0.0] * (4 - len(p))
+                if req.get("perspective_divide") and p[3] not in (0, 0.0):
+                    p = [p[0] / p[3], p[1] / p[3], p[2] / p[3], 1.0]
+                positions.append(p)
+
+            prims_kind, prims = primitives(mesh.topology, indices)
+            attributes = [name for (name, _, _, _, _) in layout]
+
+            out_path = req["output_path"]
+            parent = os.path.dirname(out_path)
+            if parent:
+                os.makedirs(parent, exist_ok=True)
+            if fmt_name == "obj":
+                write_obj(out_path, positions, prims_kind, prims, attributes)
+            else:
+                extra = [(name, columns[name]) for (name, is_pos, _, _, _) in layout if not is_pos]
+                write_gltf(out_path, positions, prims_kind, prims, extra)
+
+            write_envelope(
+                True,
+                result={
+                    "capture_path": req["capture_path"],
+                    "event_id": int(event_id),
+                    "stage": stage_name,
+                    "format": fmt_name,
+                    "output_path": out_path,
+                    "topology": str(mesh.topology),
+                    "vertex_count": int(vertex_count),
+                    "index_count": len(indices),
+                    "primitive_count": len(prims),
+                    "attributes": attributes,
+                },
+            )
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
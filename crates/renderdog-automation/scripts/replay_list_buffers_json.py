@@ -0,0 +1,142 @@
+import json
+import os
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "replay_list_buffers_json.request.json"
+RESP_PATH = "replay_list_buffers_json.response.json"
+
+BUFFER_CATEGORIES = [
+    ("Vertex", rd.BufferCategory.Vertex),
+    ("Index", rd.BufferCategory.Index),
+    ("Constants", rd.BufferCategory.Constants),
+    ("ReadWrite", rd.BufferCategory.ReadWrite),
+    ("Indirect", rd.BufferCategory.Indirect),
+]
+
+
+def write_response(obj) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump(obj, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            event_id = req.get("event_id", None)
+            if event_id is not None:
+                controller.SetFrameEvent(int(event_id), True)
+
+            name_by_id = {}
+            try:
+                for r in controller.GetResources():
+                    rrid = int(r.resourceId)
+                    n = getattr(r, "name", None)
+                    if n is None:
+                        n = getattr(r, "resourceName", None)
+                    if n is None:
+                        continue
+                    name_by_id[rrid] = str(n or "")
+            except Exception:
+                name_by_id = {}
+
+            buffers = controller.GetBuffers()
+            out = []
+            for i, b in enumerate(buffers):
+                rid = b.resourceId
+                name = name_by_id.get(int(rid), "") or ""
+                try:
+                    desc = controller.GetResourceDescription(rid)
+                    if desc is not None:
+                        if not name:
+                            name = str(desc.name or "")
+                except Exception:
+                    pass
+
+                flags = [n for n, f in BUFFER_CATEGORIES if int(b.creationFlags) & int(f)]
+
+                out.append(
+                    {
+                        "index": int(i),
+                        "resource_id": int(rid),
+                        "name": name,
+                        "length": int(b.length),
+                        "creation_flags": flags,
+                        "gpu_address": int(getattr(b, "gpuAddress", 0)) or None,
+                    }
+                )
+
+            write_response(
+                {
+                    "capture_path": req["capture_path"],
+                    "event_id": event_id,
+                    "buffers": out,
+                }
+            )
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_response({"ok": False, "error": traceback.format_exc()})
+    else:
+        # Wrap successful output written by main().
+        with open(RESP_PATH, "r", encoding="utf-8") as f:
+            payload = json.load(f)
+        write_response({"ok": True, "result": payload})
+    raise SystemExit(0)
\ No newline at end of file
@@ -0,0 +1,117 @@
+import json
+import os
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "replay_pick_pixels_json.request.json"
+RESP_PATH = "replay_pick_pixels_json.response.json"
+
+
+def write_response(obj) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump(obj, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            event_id = req.get("event_id", None)
+            if event_id is not None:
+                controller.SetFrameEvent(int(event_id), True)
+
+            textures = controller.GetTextures()
+            pixels = []
+            for pick in req["picks"]:
+                idx = int(pick["texture_index"])
+                if idx < 0 or idx >= len(textures):
+                    raise RuntimeError("texture_index out of range: " + str(idx))
+
+                pv = controller.PickPixel(
+                    textures[idx].resourceId,
+                    int(pick["x"]),
+                    int(pick["y"]),
+                    rd.Subresource(0, 0, 0),
+                    rd.CompType.Typeless,
+                )
+                pixels.append(
+                    {
+                        "texture_index": idx,
+                        "x": int(pick["x"]),
+                        "y": int(pick["y"]),
+                        "rgba": [float(pv.floatValue[i]) for i in range(4)],
+                    }
+                )
+
+            write_response(
+                {
+                    "capture_path": req["capture_path"],
+                    "event_id": event_id,
+                    "pixels": pixels,
+                }
+            )
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_response({"ok": False, "error": traceback.format_exc()})
+    else:
+        with open(RESP_PATH, "r", encoding="utf-8") as f:
+            payload = json.load(f)
+        write_response({"ok": True, "result": payload})
+    raise SystemExit(0)
\ No newline at end of file
@@ -0,0 +1,195 @@
+import json
+import os
+import re
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "replay_save_all_textures_json.request.json"
+RESP_PATH = "replay_save_all_textures_json.response.json"
+
+FILE_TYPES = {
+    "png": rd.FileType.PNG,
+    "jpg": rd.FileType.JPG,
+    "exr": rd.FileType.EXR,
+    "hdr": rd.FileType.HDR,
+    "dds": rd.FileType.DDS,
+}
+
+TEXTURE_CATEGORIES = [
+    ("ShaderRead", rd.TextureCategory.ShaderRead),
+    ("ColorTarget", rd.TextureCategory.ColorTarget),
+    ("DepthTarget", rd.TextureCategory.DepthTarget),
+    ("ShaderReadWrite", rd.TextureCategory.ShaderReadWrite),
+    ("SwapBuffer", rd.TextureCategory.SwapBuffer),
+]
+
+
+def write_response(obj) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump(obj, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def safe_name(name: str) -> str:
+    return re.sub(r"[^A-Za-z0-9_.-]+", "_", name).strip("_")[:64]
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    fmt = str(req.get("format", "png")).lower()
+    if fmt not in FILE_TYPES:
+        raise RuntimeError("unsupported format: " + fmt + " (expected png, jpg, exr, hdr or dds)")
+
+    name_filter = (req.get("name_contains") or "").lower()
+    format_filter = (req.get("format_contains") or "").lower()
+    min_width = int(req.get("min_width") or 0)
+    min_height = int(req.get("min_height") or 0)
+    wanted_categories = set(req.get("categories") or [])
+    unknown = wanted_categories - {n for n, _ in TEXTURE_CATEGORIES}
+    if unknown:
+        raise RuntimeError("unknown texture categories: " + ", ".join(sorted(unknown)))
+
+    os.makedirs(req["output_dir"], exist_ok=True)
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            event_id = req.get("event_id", None)
+            if event_id is not None:
+                controller.SetFrameEvent(int(event_id), True)
+
+            name_by_id = {int(r.resourceId): str(r.name) for r in controller.GetResources()}
+            event_tag = f".event{int(event_id)}" if event_id is not None else ""
+
+            saved = []
+            skipped = []
+            for i, t in enumerate(controller.GetTextures()):
+                rid = int(t.resourceId)
+                name = name_by_id.get(rid, "")
+                format_name = str(t.format.Name())
+                categories = [n for n, c in TEXTURE_CATEGORIES if int(t.creationFlags) & int(c)]
+
+                if name_filter and name_filter not in name.lower():
+                    continue
+                if format_filter and format_filter not in format_name.lower():
+                    continue
+                if int(t.width) < min_width or int(t.height) < min_height:
+                    continue
+                if wanted_categories and not wanted_categories.intersection(categories):
+                    continue
+
+                stem = f"{req['basename']}{event_tag}.tex{i}"
+                if safe_name(name):
+                    stem += "." + safe_name(name)
+                out_path = os.path.join(req["output_dir"], f"{stem}.{fmt}")
+
+                save = rd.TextureSave()
+                save.resourceId = t.resourceId
+                save.destType = FILE_TYPES[fmt]
+                if fmt == "dds":
+                    # DDS keeps the whole resource: every mip and array slice.
+                    save.mip = -1
+                    save.slice.sliceIndex = -1
+                else:
+                    save.mip = 0
+                    save.slice.sliceIndex = 0
+                    save.sample.sampleIndex = 0
+
+                result = controller.SaveTexture(save, out_path)
+                if result != rd.ResultCode.Succeeded:
+                    skipped.append(
+                        {
+                            "index": int(i),
+                            "resource_id": rid,
+                            "name": name,
+                            "reason": "SaveTexture failed: " + str(result),
+                        }
+                    )
+                    continue
+
+                saved.append(
+                    {
+                        "index": int(i),
+                        "resource_id": rid,
+                        "name": name,
+                        "width": int(t.width),
+                        "height": int(t.height),
+                        "format": format_name,
+                        "categories": categories,
+                        "output_path": out_path,
+                    }
+                )
+
+            write_response(
+                {
+                    "capture_path": req["capture_path"],
+                    "event_id": event_id,
+                    "output_dir": req["output_dir"],
+                    "format": fmt,
+                    "textures": saved,
+                    "skipped": skipped,
+                }
+            )
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_response({"ok": False, "error": traceback.format_exc()})
+    else:
+        with open(RESP_PATH, "r", encoding="utf-8") as f:
+            payload = json.load(f)
+        write_response({"ok": True, "result": payload})
+    raise SystemExit(0)
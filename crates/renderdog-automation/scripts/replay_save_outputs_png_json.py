@@ -9,11 +9,43 @@ REQ_PATH = "replay_save_outputs_png_json.request.json"
 RESP_PATH = "replay_save_outputs_png_json.response.json"
 
 
+FILE_TYPES = {
+    "png": rd.FileType.PNG,
+    "jpg": rd.FileType.JPG,
+    "exr": rd.FileType.EXR,
+    "hdr": rd.FileType.HDR,
+}
+
+
 def write_response(obj) -> None:
     with open(RESP_PATH, "w", encoding="utf-8") as f:
         json.dump(obj, f, ensure_ascii=False)
 
 
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
 def flatten_actions(actions):
     out = []
     for a in actions:
@@ -96,6 +128,10 @@ def main() -> None:
     with open(REQ_PATH, "r", encoding="utf-8") as f:
         req = json.load(f)
 
+    fmt = str(req.get("format") or "png").lower()
+    if fmt not in FILE_TYPES:
+        raise RuntimeError("format must be one of: png, jpg, exr, hdr")
+
     os.makedirs(req["output_dir"], exist_ok=True)
 
     rd.InitialiseReplay(rd.GlobalEnvironment(), [])
@@ -109,7 +145,7 @@ def main() -> None:
         if not cap.LocalReplaySupport():
             raise RuntimeError("Capture cannot be replayed")
 
-        result, controller = cap.OpenCapture(rd.ReplayOptions(), None)
+        result, controller = cap.OpenCapture(replay_options(req), None)
         if result != rd.ResultCode.Succeeded:
             raise RuntimeError("Couldn't initialise replay: " + str(result))
 
@@ -129,12 +165,12 @@ def main() -> None:
                     continue
 
                 out_path = os.path.join(
-                    req["output_dir"], f"{req['basename']}.event{int(event_id)}.rt{i}.png"
+                    req["output_dir"], f"{req['basename']}.event{int(event_id)}.rt{i}.{fmt}"
                 )
 
                 save = rd.TextureSave()
                 save.resourceId = rid
-                save.destType = rd.FileType.PNG
+                save.destType = FILE_TYPES[fmt]
                 save.mip = 0
                 set_save_params_from_bound_resource(save, br)
 
@@ -156,12 +192,12 @@ def main() -> None:
                 rid = extract_resource_id(br)
                 if rid is not None and not is_null_resource_id(rid):
                     out_path = os.path.join(
-                        req["output_dir"], f"{req['basename']}.event{int(event_id)}.depth.png"
+                        req["output_dir"], f"{req['basename']}.event{int(event_id)}.depth.{fmt}"
                     )
 
                     save = rd.TextureSave()
                     save.resourceId = rid
-                    save.destType = rd.FileType.PNG
+                    save.destType = FILE_TYPES[fmt]
                     save.mip = 0
                     set_save_params_from_bound_resource(save, br)
 
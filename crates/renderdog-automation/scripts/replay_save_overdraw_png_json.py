@@ -0,0 +1,193 @@
+"""
+replay_save_overdraw_png_json.py -- Render RenderDoc's quad overdraw overlays and save them as PNG.
+
+Request:
+  - capture_path
+  - event_id (optional): a single event; when omitted, the last draw of every pass in the frame
+  - output_dir, basename
+  - modes: any of "draw" (QuadOverdrawDraw, the selected draw only) and "pass"
+    (QuadOverdrawPass, the pass up to and including the selected draw)
+"""
+
+import json
+import os
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "replay_save_overdraw_png_json.request.json"
+RESP_PATH = "replay_save_overdraw_png_json.response.json"
+
+OVERLAYS = {
+    "draw": rd.DebugOverlay.QuadOverdrawDraw,
+    "pass": rd.DebugOverlay.QuadOverdrawPass,
+}
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def flatten_actions(actions):
+    out = []
+    for a in actions:
+        out.append(a)
+        out.extend(flatten_actions(a.children))
+    return out
+
+
+def pass_end_event_ids(controller):
+    """Last drawcall of each run of consecutive draws writing the same targets."""
+    draws = [a for a in flatten_actions(controller.GetRootActions()) if a.flags & rd.ActionFlags.Drawcall]
+    ends = []
+    prev_key = None
+    for a in draws:
+        key = (tuple(int(o) for o in a.outputs), int(a.depthOut))
+        if key == prev_key:
+            ends[-1] = int(a.eventId)
+        else:
+            ends.append(int(a.eventId))
+        prev_key = key
+    if not ends:
+        raise RuntimeError("Capture has no drawcalls")
+    return ends
+
+
+def overlay_target(pipe):
+    """First bound colour target (or the depth target) to draw the overlay over."""
+    for br in pipe.GetOutputTargets():
+        rid = getattr(br, "resourceId", getattr(br, "resource", None))
+        if rid is not None and rid != rd.ResourceId.Null():
+            return rid
+    br = pipe.GetDepthTarget()
+    rid = getattr(br, "resourceId", getattr(br, "resource", None))
+    if rid is not None and rid != rd.ResourceId.Null():
+        return rid
+    return None
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    modes = [str(m).lower() for m in (req.get("modes") or ["draw", "pass"])]
+    for m in modes:
+        if m not in OVERLAYS:
+            raise RuntimeError("mode must be 'draw' or 'pass', got: " + m)
+
+    os.makedirs(req["output_dir"], exist_ok=True)
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        output = None
+        try:
+            event_id = req.get("event_id")
+            event_ids = [int(event_id)] if event_id is not None else pass_end_event_ids(controller)
+
+            output = controller.CreateOutput(
+                rd.CreateHeadlessWindowingData(16, 16), rd.ReplayOutputType.Texture
+            )
+
+            images = []
+            for eid in event_ids:
+                controller.SetFrameEvent(eid, True)
+                target = overlay_target(controller.GetPipelineState())
+                if target is None:
+                    if event_id is not None:
+                        raise RuntimeError("No output target bound at event %d" % eid)
+                    continue
+
+                for mode in modes:
+                    display = rd.TextureDisplay()
+                    display.resourceId = target
+                    display.overlay = OVERLAYS[mode]
+                    output.SetTextureDisplay(display)
+                    output.Display()
+
+                    overlay_id = output.GetDebugOverlayTexID()
+                    if overlay_id == rd.ResourceId.Null():
+                        raise RuntimeError("RenderDoc produced no overlay for event %d" % eid)
+
+                    out_path = os.path.join(
+                        req["output_dir"], "%s.event%d.overdraw_%s.png" % (req["basename"], eid, mode)
+                    )
+                    save = rd.TextureSave()
+                    save.resourceId = overlay_id
+                    save.destType = rd.FileType.PNG
+                    save.mip = 0
+                    result = controller.SaveTexture(save, out_path)
+                    if result != rd.ResultCode.Succeeded:
+                        raise RuntimeError("SaveTexture failed: " + str(result))
+
+                    images.append({"event_id": eid, "mode": mode, "output_path": out_path})
+
+            write_envelope(
+                True,
+                result={
+                    "capture_path": req["capture_path"],
+                    "images": images,
+                },
+            )
+        finally:
+            if output is not None:
+                try:
+                    output.Shutdown()
+                except Exception:
+                    pass
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
@@ -1,6 +1,9 @@
 import json
+import math
 import os
+import struct
 import traceback
+import zlib
 
 import renderdoc as rd
 
@@ -9,11 +12,130 @@ REQ_PATH = "replay_save_texture_png_json.request.json"
 RESP_PATH = "replay_save_texture_png_json.response.json"
 
 
+FILE_TYPES = {
+    "png": rd.FileType.PNG,
+    "jpg": rd.FileType.JPG,
+    "jpeg": rd.FileType.JPG,
+    "exr": rd.FileType.EXR,
+    "hdr": rd.FileType.HDR,
+}
+
+CHANNELS = {
+    "rgba": (-1, rd.AlphaMapping.Preserve),
+    "rgb": (-1, rd.AlphaMapping.Discard),
+    "r": (0, rd.AlphaMapping.Discard),
+    "g": (1, rd.AlphaMapping.Discard),
+    "b": (2, rd.AlphaMapping.Discard),
+    "a": (3, rd.AlphaMapping.Discard),
+}
+
+CUBE_FACES = ["px", "nx", "py", "ny", "pz", "nz"]
+
+# PNG colour type -> (channels, has alpha)
+PNG_LAYOUTS = {0: (1, False), 2: (3, False), 4: (2, True), 6: (4, True)}
+
+
 def write_response(obj) -> None:
     with open(RESP_PATH, "w", encoding="utf-8") as f:
         json.dump(obj, f, ensure_ascii=False)
 
 
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def paeth(a, b, c):
+    p = a + b - c
+    pa, pb, pc = abs(p - a), abs(p - b), abs(p - c)
+    if pa <= pb and pa <= pc:
+        return a
+    return b if pb <= pc else c
+
+
+def apply_png_gamma(path: str, gamma: float) -> None:
+    """Rewrite an 8-bit PNG written by SaveTexture with `out = in^(1/gamma)` on colour channels."""
+    with open(path, "rb") as f:
+        data = f.read()
+    pos = 8
+    header = None
+    idat = b""
+    others = []
+    while pos < len(data):
+        (length,) = struct.unpack(">I", data[pos : pos + 4])
+        ctype = data[pos + 4 : pos + 8]
+        body = data[pos + 8 : pos + 8 + length]
+        pos += 12 + length
+        if ctype == b"IHDR":
+            header = body
+        elif ctype == b"IDAT":
+            idat += body
+        elif ctype != b"IEND":
+            others.append((ctype, body))
+
+    width, height, depth, color_type, _, _, interlace = struct.unpack(">IIBBBBB", header)
+    if depth != 8 or interlace != 0 or color_type not in PNG_LAYOUTS:
+        raise RuntimeError("gamma only supports 8-bit non-interlaced PNG output")
+    channels, has_alpha = PNG_LAYOUTS[color_type]
+    stride = width * channels
+
+    raw = zlib.decompress(idat)
+    lut = bytes(round(255.0 * (i / 255.0) ** (1.0 / gamma)) for i in range(256))
+    rows = []
+    prev = bytearray(stride)
+    for y in range(height):
+        start = y * (stride + 1)
+        ftype = raw[start]
+        row = bytearray(raw[start + 1 : start + 1 + stride])
+        for x in range(stride):
+            a = row[x - channels] if x >= channels else 0
+            b = prev[x]
+            c = prev[x - channels] if x >= channels else 0
+            if ftype == 1:
+                row[x] = (row[x] + a) & 0xFF
+            elif ftype == 2:
+                row[x] = (row[x] + b) & 0xFF
+            elif ftype == 3:
+                row[x] = (row[x] + ((a + b) >> 1)) & 0xFF
+            elif ftype == 4:
+                row[x] = (row[x] + paeth(a, b, c)) & 0xFF
+        prev = row
+        out = bytearray(row)
+        for x in range(stride):
+            if not (has_alpha and x % channels == channels - 1):
+                out[x] = lut[out[x]]
+        rows.append(b"\x00" + bytes(out))
+
+    def chunk(ctype, body):
+        return struct.pack(">I", len(body)) + ctype + body + struct.pack(">I", zlib.crc32(ctype + body))
+
+    png = data[:8] + chunk(b"IHDR", header)
+    for ctype, body in others:
+        png += chunk(ctype, body)
+    png += chunk(b"IDAT", zlib.compress(b"".join(rows))) + chunk(b"IEND", b"")
+    with open(path, "wb") as f:
+        f.write(png)
+
+
 def main() -> None:
     with open(REQ_PATH, "r", encoding="utf-8") as f:
         req = json.load(f)
@@ -33,7 +155,7 @@ def main() -> None:
         if not cap.LocalReplaySupport():
             raise RuntimeError("Capture cannot be replayed")
 
-        result, controller = cap.OpenCapture(rd.ReplayOptions(), None)
+        result, controller = cap.OpenCapture(replay_options(req), None)
         if result != rd.ResultCode.Succeeded:
             raise RuntimeError("Couldn't initialise replay: " + str(result))
 
@@ -49,21 +171,91 @@ def main() -> None:
 
             t = textures[idx]
 
-            save = rd.TextureSave()
-            save.resourceId = t.resourceId
-            save.destType = rd.FileType.PNG
-            save.mip = 0
+            channels = str(req.get("channels") or "rgba").lower()
+            if channels not in CHANNELS:
+                raise RuntimeError("channels must be one of: " + ", ".join(CHANNELS))
+            # Resolved (and validated) on the Rust side, including the extension fallback.
+            fmt = str(req.get("format") or "png").lower()
+            if fmt not in FILE_TYPES:
+                raise RuntimeError("format must be one of: png, jpg, exr, hdr")
+            gamma = float(req.get("gamma") or 1.0)
+            if gamma <= 0.0:
+                raise RuntimeError("gamma must be positive")
+            if gamma != 1.0 and FILE_TYPES[fmt] != rd.FileType.PNG:
+                raise RuntimeError("gamma is only supported for png output")
+
+            slices_mode = str(req.get("slices") or "single").lower()
+            if slices_mode not in ("single", "all", "grid", "cross"):
+                raise RuntimeError("slices must be one of: single, all, grid, cross")
+            is_3d = int(t.depth) > 1
+            if slices_mode == "cross" and not bool(t.cubemap):
+                raise RuntimeError("slices=cross needs a cubemap")
+            all_mips = bool(req.get("all_mips", False))
+            mips = range(int(t.mips)) if all_mips else [int(req.get("mip") or 0)]
+
+            def slice_count(mip):
+                return max(int(t.depth) >> mip, 1) if is_3d else max(int(t.arraysize), 1)
+
+            def save_file(out_path, mip, slice_index, slice_label):
+                save = rd.TextureSave()
+                save.resourceId = t.resourceId
+                save.destType = FILE_TYPES[fmt]
+                save.mip = mip
+                save.slice.sliceIndex = slice_index
+                save.sample.sampleIndex = int(req.get("sample") or 0)
+                save.channelExtract, save.alpha = CHANNELS[channels]
+                if req.get("black_point") is not None:
+                    save.comp.blackPoint = float(req["black_point"])
+                if req.get("white_point") is not None:
+                    save.comp.whitePoint = float(req["white_point"])
+                if slices_mode == "grid":
+                    save.slice.slicesAsGrid = True
+                    save.slice.sliceGridWidth = int(req.get("grid_width") or math.ceil(math.sqrt(slice_count(mip))))
+                elif slices_mode == "cross":
+                    save.slice.cubeCruciform = True
+
+                result = controller.SaveTexture(save, out_path)
+                if result != rd.ResultCode.Succeeded:
+                    raise RuntimeError("SaveTexture failed: " + str(result))
+
+                if gamma != 1.0:
+                    apply_png_gamma(out_path, gamma)
+
+                return {
+                    "mip": mip,
+                    "slice": slice_index if slices_mode in ("single", "all") else None,
+                    "label": slice_label,
+                    "width": max(int(t.width) >> mip, 1),
+                    "height": max(int(t.height) >> mip, 1),
+                    "output_path": out_path,
+                }
 
-            result = controller.SaveTexture(save, str(req["output_path"]))
-            if result != rd.ResultCode.Succeeded:
-                raise RuntimeError("SaveTexture failed: " + str(result))
+            output_path = str(req["output_path"])
+            files = []
+            for mip in mips:
+                stem, ext = os.path.splitext(output_path)
+                if all_mips:
+                    w = max(int(t.width) >> mip, 1)
+                    h = max(int(t.height) >> mip, 1)
+                    stem = "%s.mip%d_%dx%d" % (stem, mip, w, h)
+                if slices_mode == "all":
+                    count = slice_count(mip)
+                    for i in range(count):
+                        label = "slice%d" % i
+                        if bool(t.cubemap):
+                            label = CUBE_FACES[i % 6] if count == 6 else "cube%d.%s" % (i // 6, CUBE_FACES[i % 6])
+                        files.append(save_file("%s.%s%s" % (stem, label, ext), mip, i, label))
+                else:
+                    slice_index = int(req.get("slice") or 0) if slices_mode == "single" else 0
+                    files.append(save_file(stem + ext, mip, slice_index, None))
 
             write_response(
                 {
                     "capture_path": req["capture_path"],
                     "event_id": event_id,
                     "texture_index": int(req["texture_index"]),
-                    "output_path": str(req["output_path"]),
+                    "output_path": files[0]["output_path"],
+                    "files": files,
                 }
             )
         finally:
@@ -0,0 +1,247 @@
+import contextlib
+import io
+import json
+import os
+import runpy
+import time
+import traceback
+
+import renderdoc as rd
+
+
+# Long-lived worker for `ReplaySession`.
+#
+# Requests are polled from `inbox/<seq>.json` (relative to the session dir this process starts in)
+# and answered in `outbox/<seq>.json`:
+#   {"script": "<path>", "run_dir": "<dir>"}  -> run an existing renderdog script in run_dir
+#   {"shutdown": true}                         -> release all captures and exit
+#
+# Only workflows called through `ReplaySessionWorkflows` are sent here, and they run unmodified:
+# replay is initialised once and every capture is opened once (and again whenever the file changes
+# on disk). Each script starts at the capture's last event with the worker's environment. While a
+# script runs, its `InitialiseReplay` / `OpenCaptureFile` / `Shutdown` calls are redirected to the
+# cache; the real module functions are restored afterwards.
+
+SESSION_DIR = os.getcwd()
+INBOX_DIR = os.path.join(SESSION_DIR, "inbox")
+OUTBOX_DIR = os.path.join(SESSION_DIR, "outbox")
+READY_PATH = os.path.join(SESSION_DIR, "ready.json")
+POLL_INTERVAL_S = 0.02
+
+_real_initialise_replay = rd.InitialiseReplay
+_real_open_capture_file = rd.OpenCaptureFile
+_real_shutdown_replay = rd.ShutdownReplay
+
+# capture path -> _CachedCapture
+_captures = {}
+
+
+def _options_key(opts):
+    return (
+        bool(opts.apiValidation),
+        int(opts.optimisation),
+        int(opts.forceGPUVendor),
+        int(opts.forceGPUDeviceID),
+        str(opts.forceGPUDriverName),
+    )
+
+
+def _file_stamp(path):
+    # A capture rewritten on disk (e.g. by `write_capture_section`) must be reopened.
+    st = os.stat(path)
+    return (st.st_size, st.st_mtime_ns)
+
+
+def _last_event_id(controller):
+    actions = controller.GetRootActions()
+    if not actions:
+        return None
+    action = actions[-1]
+    while action.children:
+        action = action.children[-1]
+    return int(action.eventId)
+
+
+class _CachedCapture:
+    def __init__(self, cap, stamp):
+        self.cap = cap
+        self.stamp = stamp
+        self.controller = None
+        self.options = None
+        self.last_event_id = None
+
+    def release(self):
+        try:
+            if self.controller is not None:
+                self.controller.Shutdown()
+        except Exception:
+            pass
+        self.controller = None
+        try:
+            self.cap.Shutdown()
+        except Exception:
+            pass
+
+
+class _SessionController:
+    def __init__(self, controller):
+        self._controller = controller
+
+    def Shutdown(self):
+        pass
+
+    def __getattr__(self, name):
+        return getattr(self._controller, name)
+
+
+class _SessionCaptureFile:
+    def __init__(self):
+        self._path = None
+        self._cap = None
+
+    def OpenFile(self, path, filetype, progress):
+        key = os.path.abspath(path)
+        try:
+            stamp = _file_stamp(key)
+        except OSError:
+            stamp = None
+        cached = _captures.get(key)
+        if cached is not None:
+            if stamp is not None and cached.stamp == stamp:
+                self._path = key
+                self._cap = cached.cap
+                return rd.ResultCode.Succeeded
+            cached.release()
+            del _captures[key]
+
+        cap = _real_open_capture_file()
+        result = cap.OpenFile(path, filetype, progress)
+        if result != rd.ResultCode.Succeeded:
+            cap.Shutdown()
+            return result
+        self._path = key
+        self._cap = cap
+        if stamp is not None:
+            _captures[key] = _CachedCapture(cap, stamp)
+        return result
+
+    def OpenCapture(self, opts, progress):
+        cached = _captures.get(self._path)
+        if cached is None:
+            # Not cacheable (the file could not be stat'ed): behave like a plain CaptureFile.
+            return self._cap.OpenCapture(opts, progress)
+        if cached.controller is not None and cached.options != _options_key(opts):
+            # Different replay options need a fresh controller.
+            cached.controller.Shutdown()
+            cached.controller = None
+        if cached.controller is None:
+            result, controller = cached.cap.OpenCapture(opts, progress)
+            if result != rd.ResultCode.Succeeded:
+                return result, None
+            cached.controller = controller
+            cached.options = _options_key(opts)
+            cached.last_event_id = _last_event_id(controller)
+        elif cached.last_event_id is not None:
+            # Start every script where a freshly opened capture would be.
+            cached.controller.SetFrameEvent(cached.last_event_id, True)
+        return rd.ResultCode.Succeeded, _SessionController(cached.controller)
+
+    def Shutdown(self):
+        if self._path not in _captures and self._cap is not None:
+            self._cap.Shutdown()
+
+    def __getattr__(self, name):
+        if self._cap is None:
+            raise AttributeError(name)
+        return getattr(self._cap, name)
+
+
+def _release_all() -> None:
+    for cached in _captures.values():
+        cached.release()
+    _captures.clear()
+
+
+def _write_json(path: str, obj) -> None:
+    tmp = path + ".tmp"
+    with open(tmp, "w", encoding="utf-8") as f:
+        json.dump(obj, f, ensure_ascii=False)
+    os.replace(tmp, path)
+
+
+@contextlib.contextmanager
+def _session_patches():
+    rd.InitialiseReplay = lambda *args, **kwargs: None
+    rd.ShutdownReplay = lambda *args, **kwargs: None
+    rd.OpenCaptureFile = _SessionCaptureFile
+    try:
+        yield
+    finally:
+        rd.InitialiseReplay = _real_initialise_replay
+        rd.ShutdownReplay = _real_shutdown_replay
+        rd.OpenCaptureFile = _real_open_capture_file
+
+
+def _run_script(req) -> dict:
+    stdout = io.StringIO()
+    prev_cwd = os.getcwd()
+    # Scripts may set process-wide state (e.g. `_NT_SYMBOL_PATH`); don't let it reach the next one.
+    prev_env = dict(os.environ)
+    try:
+        os.chdir(req["run_dir"])
+        with contextlib.redirect_stdout(stdout), _session_patches():
+            try:
+                runpy.run_path(req["script"], run_name="__main__")
+            except SystemExit as e:
+                if e.code not in (None, 0):
+                    return {"ok": False, "error": f"script exited with {e.code}", "stdout": stdout.getvalue()}
+        return {"ok": True, "error": None, "stdout": stdout.getvalue()}
+    except Exception:
+        return {"ok": False, "error": traceback.format_exc(), "stdout": stdout.getvalue()}
+    finally:
+        os.chdir(prev_cwd)
+        os.environ.clear()
+        os.environ.update(prev_env)
+
+
+def main() -> None:
+    os.makedirs(INBOX_DIR, exist_ok=True)
+    os.makedirs(OUTBOX_DIR, exist_ok=True)
+
+    _real_initialise_replay(rd.GlobalEnvironment(), [])
+
+    _write_json(READY_PATH, {"pid": os.getpid()})
+
+    try:
+        while True:
+            names = sorted(
+                (n for n in os.listdir(INBOX_DIR) if n.endswith(".json")),
+                key=lambda n: int(n[: -len(".json")]),
+            )
+            if not names:
+                time.sleep(POLL_INTERVAL_S)
+                continue
+
+            for name in names:
+                req_path = os.path.join(INBOX_DIR, name)
+                with open(req_path, "r", encoding="utf-8") as f:
+                    req = json.load(f)
+                os.remove(req_path)
+
+                if req.get("shutdown"):
+                    _write_json(os.path.join(OUTBOX_DIR, name), {"ok": True, "error": None, "stdout": ""})
+                    return
+
+                _write_json(os.path.join(OUTBOX_DIR, name), _run_script(req))
+    finally:
+        _release_all()
+        _real_shutdown_replay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        with open(os.path.join(SESSION_DIR, "worker_error.txt"), "w", encoding="utf-8") as f:
+            f.write(traceback.format_exc())
+    raise SystemExit(0)
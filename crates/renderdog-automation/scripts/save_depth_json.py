@@ -0,0 +1,354 @@
+"""
+save_depth_json.py -- Export a depth texture as 16-bit PNG, float32 TIFF or a false-colour PNG.
+
+Request:
+  - capture_path
+  - event_id (optional)
+  - texture (optional): resource id, resource name, "rt<N>" or "depth" (default)
+  - output_path
+  - format: "png16" (16-bit), "png" (8-bit) or "tiff" (32-bit float, values written unscaled)
+  - near, far (optional): linearize to view-space distance using these planes
+  - reversed_z: depth 1.0 is the near plane
+  - ramp: "gray", "turbo", "viridis" or "inferno" (PNG formats only)
+  - mip, slice, sample
+
+Texels at the clear value (the far plane: 1.0, or 0.0 with reversed_z) are left out of the
+value range. PNG output is normalized to [min, max] of the remaining texels so nearby geometry
+doesn't all end up white; cleared texels map to the ramp's far end.
+"""
+
+import array
+import json
+import os
+import struct
+import sys
+import traceback
+import zlib
+
+import renderdoc as rd
+
+
+REQ_PATH = "save_depth_json.request.json"
+RESP_PATH = "save_depth_json.response.json"
+
+# Colour ramps as evenly spaced RGB control points, sampled with linear interpolation.
+RAMPS = {
+    "gray": [(0, 0, 0), (255, 255, 255)],
+    "turbo": [
+        (48, 18, 59),
+        (70, 107, 227),
+        (40, 187, 236),
+        (49, 242, 153),
+        (162, 252, 60),
+        (237, 208, 58),
+        (251, 128, 34),
+        (208, 47, 5),
+        (122, 4, 3),
+    ],
+    "viridis": [
+        (68, 1, 84),
+        (72, 40, 120),
+        (62, 74, 137),
+        (49, 104, 142),
+        (38, 130, 142),
+        (31, 158, 137),
+        (53, 183, 121),
+        (110, 206, 88),
+        (181, 222, 43),
+        (253, 231, 37),
+    ],
+    "inferno": [
+        (0, 0, 4),
+        (31, 12, 72),
+        (85, 15, 109),
+        (136, 34, 106),
+        (186, 54, 85),
+        (227, 89, 51),
+        (249, 140, 10),
+        (249, 201, 50),
+        (252, 255, 164),
+    ],
+}
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def bound_resource_id(br):
+    rid = getattr(br, "resourceId", None)
+    if rid is None:
+        rid = getattr(br, "resource", None)
+    return rid
+
+
+def resolve_texture(controller, spec):
+    """Texture description for a resource id, resource name, "rt<N>" or "depth"."""
+    textures = {int(t.resourceId): t for t in controller.GetTextures()}
+    spec = str(spec).strip()
+    lowered = spec.lower()
+
+    rid = None
+    if lowered == "depth":
+        rid = bound_resource_id(controller.GetPipelineState().GetDepthTarget())
+    elif lowered.startswith("rt") and lowered[2:].isdigit():
+        targets = controller.GetPipelineState().GetOutputTargets()
+        idx = int(lowered[2:])
+        if idx < len(targets):
+            rid = bound_resource_id(targets[idx])
+    else:
+        digits = lowered.replace("resourceid::", "")
+        if digits.isdigit():
+            if int(digits) in textures:
+                return textures[int(digits)]
+            raise RuntimeError("No texture with resource id " + digits)
+        resources = controller.GetResources()
+        for exact in (True, False):
+            for r in resources:
+                name = str(r.name)
+                if (name == spec) if exact else (name.lower() == lowered):
+                    if int(r.resourceId) in textures:
+                        return textures[int(r.resourceId)]
+
+    if rid is None or rid == rd.ResourceId.Null() or int(rid) not in textures:
+        raise RuntimeError("Texture not found or not bound: " + spec)
+    return textures[int(rid)]
+
+
+def decode_depth(fmt, data):
+    """Depth values in [0, 1] (or raw floats for float formats) from GetTextureData bytes."""
+    kind = fmt.type
+    if kind == rd.ResourceFormatType.D24S8:
+        words = array.array("I", data)
+        return [(w & 0xFFFFFF) / 16777215.0 for w in words]
+    if kind == rd.ResourceFormatType.D32S8:
+        floats = array.array("f", data)
+        return list(floats[0::2])
+    if kind == rd.ResourceFormatType.D16S8:
+        halves = array.array("H", data)
+        return [h / 65535.0 for h in halves[0::2]]
+    if kind == rd.ResourceFormatType.Regular and int(fmt.compByteWidth) == 2:
+        return [h / 65535.0 for h in array.array("H", data)[:: max(int(fmt.compCount), 1)]]
+    if kind == rd.ResourceFormatType.Regular and int(fmt.compByteWidth) == 4:
+        if fmt.compType in (rd.CompType.UInt, rd.CompType.UNorm):
+            return [w / 4294967295.0 for w in array.array("I", data)[:: max(int(fmt.compCount), 1)]]
+        return list(array.array("f", data)[:: max(int(fmt.compCount), 1)])
+    raise RuntimeError("unsupported depth format: " + str(fmt.Name()))
+
+
+def sample_ramp(points, t):
+    t = min(max(t, 0.0), 1.0) * (len(points) - 1)
+    i = min(int(t), len(points) - 2)
+    f = t - i
+    a, b = points[i], points[i + 1]
+    return tuple(a[c] + (b[c] - a[c]) * f for c in range(3))
+
+
+def png_chunk(ctype, body):
+    return struct.pack(">I", len(body)) + ctype + body + struct.pack(">I", zlib.crc32(ctype + body))
+
+
+def write_png(path, width, height, bit_depth, channels, samples):
+    """Write non-interlaced grayscale / RGB PNG from row-major integer samples."""
+    color_type = 0 if channels == 1 else 2
+    pack = "B" if bit_depth == 8 else "H"
+    stride = width * channels
+    rows = []
+    for y in range(height):
+        row = array.array(pack, samples[y * stride : (y + 1) * stride])
+        if pack == "H" and sys.byteorder == "little":
+            row.byteswap()
+        rows.append(b"\x00" + row.tobytes())
+    header = struct.pack(">IIBBBBB", width, height, bit_depth, color_type, 0, 0, 0)
+    with open(path, "wb") as f:
+        f.write(b"\x89PNG\r\n\x1a\n")
+        f.write(png_chunk(b"IHDR", header))
+        f.write(png_chunk(b"IDAT", zlib.compress(b"".join(rows))))
+        f.write(png_chunk(b"IEND", b""))
+
+
+def write_float_tiff(path, width, height, values):
+    """Write a single-strip, uncompressed, little-endian float32 greyscale TIFF."""
+    pixels = array.array("f", values)
+    if sys.byteorder != "little":
+        pixels.byteswap()
+    data = pixels.tobytes()
+    tags = [
+        (256, 4, width),  # ImageWidth
+        (257, 4, height),  # ImageLength
+        (258, 3, 32),  # BitsPerSample
+        (259, 3, 1),  # Compression: none
+        (262, 3, 1),  # PhotometricInterpretation: black is zero
+        (273, 4, 8),  # StripOffsets: pixel data follows the header
+        (277, 3, 1),  # SamplesPerPixel
+        (278, 4, height),  # RowsPerStrip
+        (279, 4, len(data)),  # StripByteCounts
+        (284, 3, 1),  # PlanarConfiguration: chunky
+        (339, 3, 3),  # SampleFormat: IEEE float
+    ]
+    ifd = struct.pack("<H", len(tags))
+    for tag, ftype, value in tags:
+        packed = struct.pack("<HI", value, 0)[:4] if ftype == 3 else struct.pack("<I", value)
+        ifd += struct.pack("<HHI", tag, ftype, 1) + packed
+    ifd += struct.pack("<I", 0)
+    with open(path, "wb") as f:
+        f.write(b"II*\x00" + struct.pack("<I", 8 + len(data)))
+        f.write(data)
+        f.write(ifd)
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    out_format = str(req.get("format") or "png16").lower()
+    if out_format not in ("png16", "png", "tiff"):
+        raise RuntimeError("format must be png16, png or tiff")
+    ramp_name = str(req.get("ramp") or "gray").lower()
+    if ramp_name not in RAMPS:
+        raise RuntimeError("ramp must be one of: " + ", ".join(RAMPS))
+    if out_format == "tiff" and ramp_name != "gray":
+        raise RuntimeError("ramp is only supported for png output")
+    near = req.get("near")
+    far = req.get("far")
+    if (near is None) != (far is None):
+        raise RuntimeError("near and far must be given together")
+    reversed_z = bool(req.get("reversed_z", False))
+
+    output_path = str(req["output_path"])
+    out_dir = os.path.dirname(output_path)
+    if out_dir:
+        os.makedirs(out_dir, exist_ok=True)
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            event_id = req.get("event_id")
+            if event_id is not None:
+                controller.SetFrameEvent(int(event_id), True)
+
+            tex = resolve_texture(controller, req.get("texture") or "depth")
+            names = {int(r.resourceId): str(r.name) for r in controller.GetResources()}
+            mip = int(req.get("mip") or 0)
+            if mip >= int(tex.mips):
+                raise RuntimeError("mip %d out of range (texture has %d)" % (mip, int(tex.mips)))
+            sub = rd.Subresource(mip, int(req.get("slice") or 0), int(req.get("sample") or 0))
+            width = max(int(tex.width) >> mip, 1)
+            height = max(int(tex.height) >> mip, 1)
+
+            depth = decode_depth(tex.format, bytes(controller.GetTextureData(tex.resourceId, sub)))
+            depth = depth[: width * height]
+            if len(depth) < width * height:
+                raise RuntimeError("texture data is smaller than %dx%d" % (width, height))
+
+            clear_value = 0.0 if reversed_z else 1.0
+            cleared = [d == clear_value for d in depth]
+            values = depth
+            if near is not None:
+                n, fa = float(near), float(far)
+                values = []
+                for d in depth:
+                    z = 1.0 - d if reversed_z else d
+                    denom = fa - z * (fa - n)
+                    values.append(n * fa / denom if denom > 0.0 else fa)
+
+            live = [v for v, c in zip(values, cleared) if not c]
+            lo = min(live) if live else 0.0
+            hi = max(live) if live else 1.0
+
+            if out_format == "tiff":
+                write_float_tiff(output_path, width, height, values)
+            else:
+                top = 65535 if out_format == "png16" else 255
+                span = hi - lo
+                # Near is dark for raw depth and for linear distance alike, except that reversed
+                # raw depth grows towards the viewer.
+                flip = reversed_z and near is None
+                points = RAMPS[ramp_name]
+                channels = 1 if ramp_name == "gray" else 3
+                samples = []
+                for v, c in zip(values, cleared):
+                    t = 1.0 if c else ((v - lo) / span if span > 0.0 else 0.0)
+                    if flip and not c:
+                        t = 1.0 - t
+                    if channels == 1:
+                        samples.append(int(round(t * top)))
+                    else:
+                        samples.extend(int(round(x / 255.0 * top)) for x in sample_ramp(points, t))
+                write_png(output_path, width, height, 16 if out_format == "png16" else 8, channels, samples)
+
+            write_envelope(
+                True,
+                result={
+                    "capture_path": req["capture_path"],
+                    "event_id": event_id,
+                    "texture_id": int(tex.resourceId),
+                    "texture_name": names.get(int(tex.resourceId), ""),
+                    "texel_format": str(tex.format.Name()),
+                    "output_path": output_path,
+                    "format": out_format,
+                    "width": width,
+                    "height": height,
+                    "linearized": near is not None,
+                    "min_value": lo if live else None,
+                    "max_value": hi if live else None,
+                    "cleared_texels": sum(1 for c in cleared if c),
+                },
+            )
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
@@ -0,0 +1,245 @@
+"""
+save_texture_raw_json.py -- Write a texture as DDS or as raw texel bytes plus a JSON descriptor.
+
+Request:
+  - capture_path
+  - event_id (optional)
+  - texture: resource id ("123" / "ResourceId::123"), resource name, "rt<N>" or "depth"
+  - output_path
+  - format: "dds" or "raw"
+  - mip, slice, sample (optional): a single subresource; for DDS, unset means every mip / slice
+
+DDS goes through SaveTexture and keeps block-compressed formats as-is. Raw writes the bytes of
+GetTextureData for one subresource unchanged, next to `<output stem>.json` describing the
+layout (format, dimensions, row / depth pitch) needed to interpret them.
+"""
+
+import json
+import os
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "save_texture_raw_json.request.json"
+RESP_PATH = "save_texture_raw_json.response.json"
+
+# Block-compressed format types laid out as 4x4 blocks (ASTC block sizes vary per format).
+FOUR_BY_FOUR_BLOCKS = ("BC1", "BC2", "BC3", "BC4", "BC5", "BC6", "BC7", "ETC2", "EAC")
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def bound_resource_id(br):
+    rid = getattr(br, "resourceId", None)
+    if rid is None:
+        rid = getattr(br, "resource", None)
+    return rid
+
+
+def resolve_texture(controller, spec):
+    """Texture description for a resource id, resource name, "rt<N>" or "depth"."""
+    textures = {int(t.resourceId): t for t in controller.GetTextures()}
+    spec = str(spec).strip()
+    lowered = spec.lower()
+
+    rid = None
+    if lowered == "depth":
+        rid = bound_resource_id(controller.GetPipelineState().GetDepthTarget())
+    elif lowered.startswith("rt") and lowered[2:].isdigit():
+        targets = controller.GetPipelineState().GetOutputTargets()
+        idx = int(lowered[2:])
+        if idx < len(targets):
+            rid = bound_resource_id(targets[idx])
+    else:
+        digits = lowered.replace("resourceid::", "")
+        if digits.isdigit():
+            if int(digits) in textures:
+                return textures[int(digits)]
+            raise RuntimeError("No texture with resource id " + digits)
+        resources = controller.GetResources()
+        for exact in (True, False):
+            for r in resources:
+                name = str(r.name)
+                if (name == spec) if exact else (name.lower() == lowered):
+                    if int(r.resourceId) in textures:
+                        return textures[int(r.resourceId)]
+
+    if rid is None or rid == rd.ResourceId.Null() or int(rid) not in textures:
+        raise RuntimeError("Texture not found or not bound: " + spec)
+    return textures[int(rid)]
+
+
+def format_description(fmt):
+    return {
+        "name": str(fmt.Name()),
+        "type": str(fmt.type).split(".")[-1],
+        "comp_type": str(fmt.compType).split(".")[-1],
+        "comp_count": int(fmt.compCount),
+        "comp_byte_width": int(fmt.compByteWidth),
+        "bgra_order": bool(fmt.BGRAOrder()),
+        "srgb": bool(fmt.SRGBCorrected()),
+    }
+
+
+def mip_dim(size, mip):
+    return max(int(size) >> mip, 1)
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    kind = str(req.get("format", "dds")).lower()
+    if kind not in ("dds", "raw"):
+        raise RuntimeError("format must be dds or raw")
+
+    output_path = str(req["output_path"])
+    out_dir = os.path.dirname(output_path)
+    if out_dir:
+        os.makedirs(out_dir, exist_ok=True)
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            event_id = req.get("event_id")
+            if event_id is not None:
+                controller.SetFrameEvent(int(event_id), True)
+
+            tex = resolve_texture(controller, req["texture"])
+            names = {int(r.resourceId): str(r.name) for r in controller.GetResources()}
+            mip = req.get("mip")
+            slice_index = req.get("slice")
+            sample = int(req.get("sample") or 0)
+            if mip is not None and int(mip) >= int(tex.mips):
+                raise RuntimeError("mip %d out of range (texture has %d)" % (int(mip), int(tex.mips)))
+
+            out = {
+                "capture_path": req["capture_path"],
+                "event_id": event_id,
+                "texture_id": int(tex.resourceId),
+                "texture_name": names.get(int(tex.resourceId), ""),
+                "format": kind,
+                "texel_format": str(tex.format.Name()),
+                "output_path": output_path,
+                "descriptor_path": None,
+                "byte_size": 0,
+            }
+
+            if kind == "dds":
+                save = rd.TextureSave()
+                save.resourceId = tex.resourceId
+                save.destType = rd.FileType.DDS
+                save.mip = -1 if mip is None else int(mip)
+                save.slice.sliceIndex = -1 if slice_index is None else int(slice_index)
+                save.sample.sampleIndex = sample
+                result = controller.SaveTexture(save, output_path)
+                if result != rd.ResultCode.Succeeded:
+                    raise RuntimeError("SaveTexture failed: " + str(result))
+                out["byte_size"] = os.path.getsize(output_path)
+                write_envelope(True, result=out)
+                return
+
+            mip = int(mip or 0)
+            sub = rd.Subresource(mip, int(slice_index or 0), sample)
+            data = bytes(controller.GetTextureData(tex.resourceId, sub))
+            with open(output_path, "wb") as f:
+                f.write(data)
+
+            width = mip_dim(tex.width, mip)
+            height = mip_dim(tex.height, mip)
+            depth = mip_dim(tex.depth, mip)
+            type_name = str(tex.format.type).split(".")[-1]
+            compressed = bool(tex.format.BlockFormat())
+            four_by_four = compressed and type_name.startswith(FOUR_BY_FOUR_BLOCKS)
+            rows = (height + 3) // 4 if four_by_four else height
+            row_pitch = None
+            depth_pitch = None
+            if (four_by_four or not compressed) and len(data) % (rows * depth) == 0:
+                depth_pitch = len(data) // depth
+                row_pitch = depth_pitch // rows
+
+            descriptor = {
+                "texture_id": int(tex.resourceId),
+                "texture_name": out["texture_name"],
+                "format": format_description(tex.format),
+                "width": width,
+                "height": height,
+                "depth": depth,
+                "mip": mip,
+                "slice": int(slice_index or 0),
+                "sample": sample,
+                "block_compressed": compressed,
+                "block_size": [4, 4] if four_by_four else None,
+                "row_pitch": row_pitch,
+                "depth_pitch": depth_pitch,
+                "byte_size": len(data),
+            }
+            descriptor_path = os.path.splitext(output_path)[0] + ".json"
+            if os.path.abspath(descriptor_path) == os.path.abspath(output_path):
+                descriptor_path = output_path + ".layout.json"
+            with open(descriptor_path, "w", encoding="utf-8") as f:
+                json.dump(descriptor, f, ensure_ascii=False, indent=2)
+
+            out["descriptor_path"] = descriptor_path
+            out["byte_size"] = len(data)
+            write_envelope(True, result=out)
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
@@ -0,0 +1,225 @@
+"""
+scan_targets_for_invalid_values_json.py -- Find NaN/Inf texels in render targets.
+
+Request:
+  - capture_path
+  - event_id_min / event_id_max (optional)
+  - include_depth: also scan the bound depth target
+  - max_examples: example coordinates per finding
+
+Replays every draw/dispatch in range and reads back each bound target with a floating-point
+format (16/32/64-bit float channels). Integer / normalized formats cannot hold NaN/Inf and are
+skipped.
+"""
+
+import array
+import json
+import math
+import struct
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "scan_targets_for_invalid_values_json.request.json"
+RESP_PATH = "scan_targets_for_invalid_values_json.response.json"
+
+CHANNELS = ["r", "g", "b", "a"]
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def flatten_actions(actions):
+    out = []
+    for a in actions:
+        out.append(a)
+        out.extend(flatten_actions(a.children))
+    return out
+
+
+def bound_resource_id(br):
+    rid = getattr(br, "resourceId", None)
+    if rid is None:
+        rid = getattr(br, "resource", None)
+    return rid
+
+
+def float_values(fmt, data):
+    """Flat list of channel values for float formats, or None for formats without NaN/Inf."""
+    if fmt.type != rd.ResourceFormatType.Regular or fmt.compType != rd.CompType.Float:
+        return None
+    width = int(fmt.compByteWidth)
+    if width == 2:
+        usable = len(data) - (len(data) % 2)
+        return [v[0] for v in struct.iter_unpack("<e", bytes(data[:usable]))]
+    code = {4: "f", 8: "d"}.get(width)
+    if code is None:
+        return None
+    arr = array.array(code)
+    usable = len(data) - (len(data) % arr.itemsize)
+    arr.frombytes(bytes(data[:usable]))
+    return arr
+
+
+def scan(values, n, width, max_examples):
+    nan_count = 0
+    inf_count = 0
+    examples = []
+    for i, v in enumerate(values):
+        if math.isfinite(v):
+            continue
+        if math.isnan(v):
+            nan_count += 1
+            label = "nan"
+        else:
+            inf_count += 1
+            label = "+inf" if v > 0 else "-inf"
+        if len(examples) < max_examples:
+            texel = i // n
+            examples.append(
+                {"x": texel % width, "y": texel // width, "channel": CHANNELS[i % n], "value": label}
+            )
+    return nan_count, inf_count, examples
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    event_min = req.get("event_id_min")
+    event_max = req.get("event_id_max")
+    include_depth = bool(req.get("include_depth", False))
+    max_examples = int(req.get("max_examples", 8))
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            sfile = controller.GetStructuredFile()
+            textures = {int(t.resourceId): t for t in controller.GetTextures()}
+            work = rd.ActionFlags.Drawcall | rd.ActionFlags.Dispatch | rd.ActionFlags.Clear
+            actions = [
+                a
+                for a in flatten_actions(controller.GetRootActions())
+                if (a.flags & work)
+                and (event_min is None or a.eventId >= int(event_min))
+                and (event_max is None or a.eventId <= int(event_max))
+            ]
+
+            findings = []
+            last_invalid = {}
+            targets_scanned = 0
+            first_bad = None
+            for action in actions:
+                controller.SetFrameEvent(int(action.eventId), True)
+                pipe = controller.GetPipelineState()
+                bound = [("rt%d" % i, br) for i, br in enumerate(pipe.GetOutputTargets())]
+                if include_depth:
+                    bound.append(("depth", pipe.GetDepthTarget()))
+
+                for label, br in bound:
+                    rid = bound_resource_id(br)
+                    if rid is None or rid == rd.ResourceId.Null() or int(rid) not in textures:
+                        continue
+                    tex = textures[int(rid)]
+                    mip = int(getattr(br, "firstMip", 0))
+                    sub = rd.Subresource(mip, int(getattr(br, "firstSlice", 0)), 0)
+                    values = float_values(tex.format, controller.GetTextureData(rid, sub))
+                    if values is None:
+                        continue
+                    targets_scanned += 1
+
+                    n = max(int(tex.format.compCount), 1)
+                    width = max(int(tex.width) >> mip, 1)
+                    nan_count, inf_count, examples = scan(values, n, width, max_examples)
+                    key = (int(rid), mip, int(sub.slice))
+                    previous = last_invalid.get(key, 0)
+                    last_invalid[key] = nan_count + inf_count
+                    if nan_count + inf_count == 0:
+                        continue
+
+                    introduced = nan_count + inf_count > previous
+                    if introduced and first_bad is None:
+                        first_bad = int(action.eventId)
+                    findings.append(
+                        {
+                            "event_id": int(action.eventId),
+                            "event_name": action.GetName(sfile),
+                            "target": label,
+                            "texture_id": int(rid),
+                            "format": str(tex.format.Name()),
+                            "nan_count": nan_count,
+                            "inf_count": inf_count,
+                            "introduced": introduced,
+                            "examples": examples,
+                        }
+                    )
+
+            write_envelope(
+                True,
+                result={
+                    "capture_path": req["capture_path"],
+                    "events_scanned": len(actions),
+                    "targets_scanned": targets_scanned,
+                    "first_bad_event": first_bad,
+                    "findings": findings,
+                },
+            )
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
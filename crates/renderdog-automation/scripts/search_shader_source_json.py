@@ -0,0 +1,246 @@
+"""
+search_shader_source_json.py -- RenderDoc Python script that greps the shader sources in a capture.
+
+Every shader resource's entry points are reflected directly (no replay seeking) and the source
+files embedded in its debug info are searched line by line. Only when something matches are the
+draws/dispatches walked to list the events that bind each matching shader.
+
+Request parameters:
+  - capture_path: Path to the .rdc capture file (required)
+  - pattern: Regex (Python `re` syntax) searched for in each source line (required)
+  - case_sensitive: Default false
+  - max_matches: Maximum number of matching lines to return (the total is still counted)
+
+Returns:
+  - shaders_searched, shaders_without_source, total_matches, truncated
+  - shaders: one entry per matching (shader, entry point) with resource_id, name, stage,
+             entry_point, event_ids and matches (file, line, text)
+"""
+
+import json
+import re
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "search_shader_source_json.request.json"
+RESP_PATH = "search_shader_source_json.response.json"
+
+
+_STAGE_NAMES = [
+    (rd.ShaderStage.Vertex, "Vertex"),
+    (rd.ShaderStage.Tess_Control, "TessControl"),
+    (rd.ShaderStage.Tess_Eval, "TessEval"),
+    (rd.ShaderStage.Geometry, "Geometry"),
+    (rd.ShaderStage.Task, "Task"),
+    (rd.ShaderStage.Mesh, "Mesh"),
+    (rd.ShaderStage.Fragment, "Fragment"),
+    (rd.ShaderStage.Compute, "Compute"),
+]
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def stage_name(stage) -> str:
+    for s, name in _STAGE_NAMES:
+        if s == stage:
+            return name
+    return str(stage)
+
+
+def is_drawcall_like(flags: int) -> bool:
+    return bool(
+        (flags & rd.ActionFlags.Drawcall)
+        or (flags & rd.ActionFlags.Dispatch)
+        or (flags & rd.ActionFlags.MeshDispatch)
+        or (flags & rd.ActionFlags.DispatchRay)
+    )
+
+
+def iter_shader_reflections(controller):
+    """Yield (resource, entry_point, reflection) for every entry point of every shader."""
+    for res in controller.GetResources():
+        if res.type != rd.ResourceType.Shader:
+            continue
+        try:
+            entries = controller.GetShaderEntryPoints(res.resourceId)
+        except Exception:
+            continue
+        for entry in entries:
+            try:
+                refl = controller.GetShader(rd.ResourceId.Null(), res.resourceId, entry)
+            except Exception:
+                refl = None
+            if refl is not None:
+                yield res, entry, refl
+
+
+def debug_files(refl):
+    try:
+        debug = refl.debugInfo
+        if debug is None or debug.files is None:
+            return []
+        return [
+            (str(f.filename or ""), str(f.contents or ""))
+            for f in debug.files
+            if f.contents
+        ]
+    except Exception:
+        return []
+
+
+def collect_event_ids(controller, wanted):
+    """Fill wanted[(resource_id, entry_point)]["event_ids"] by walking every draw/dispatch."""
+
+    def walk(actions):
+        for a in actions:
+            if is_drawcall_like(a.flags):
+                controller.SetFrameEvent(a.eventId, False)
+                state = controller.GetPipelineState()
+                for stage, _ in _STAGE_NAMES:
+                    shader = state.GetShader(stage)
+                    if shader == rd.ResourceId.Null():
+                        continue
+                    try:
+                        entry = str(state.GetShaderEntryPoint(stage) or "")
+                    except Exception:
+                        entry = ""
+                    hit = wanted.get((str(shader), entry))
+                    if hit is not None and int(a.eventId) not in hit["event_ids"]:
+                        hit["event_ids"].append(int(a.eventId))
+            if len(a.children) > 0:
+                walk(a.children)
+
+    walk(controller.GetRootActions())
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    pattern = req.get("pattern") or ""
+    if not pattern:
+        raise RuntimeError("pattern is required")
+    try:
+        regex = re.compile(pattern, 0 if req.get("case_sensitive", False) else re.IGNORECASE)
+    except re.error as e:
+        raise RuntimeError(f"Invalid pattern '{pattern}': {e}")
+    max_matches = req.get("max_matches", None)
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        if not cap.LocalReplaySupport():
+            raise RuntimeError("Capture cannot be replayed")
+
+        result, controller = cap.OpenCapture(replay_options(req), None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't initialise replay: " + str(result))
+
+        try:
+            shaders_searched = 0
+            shaders_without_source = 0
+            total_matches = 0
+            returned = 0
+            truncated = False
+            hits = {}
+
+            for res, entry, refl in iter_shader_reflections(controller):
+                shaders_searched += 1
+                files = debug_files(refl)
+                if not files:
+                    shaders_without_source += 1
+                    continue
+
+                for filename, contents in files:
+                    for lineno, text in enumerate(contents.splitlines(), start=1):
+                        if not regex.search(text):
+                            continue
+                        total_matches += 1
+                        key = (str(res.resourceId), str(entry.name))
+                        hit = hits.get(key)
+                        if hit is None:
+                            hit = {
+                                "resource_id": str(res.resourceId),
+                                "name": res.name,
+                                "stage": stage_name(entry.stage),
+                                "entry_point": str(entry.name),
+                                "event_ids": [],
+                                "matches": [],
+                            }
+                            hits[key] = hit
+                        if max_matches is None or returned < int(max_matches):
+                            hit["matches"].append(
+                                {"file": filename, "line": lineno, "text": text.strip()}
+                            )
+                            returned += 1
+                        else:
+                            truncated = True
+
+            if hits:
+                collect_event_ids(controller, hits)
+
+            write_envelope(
+                True,
+                result={
+                    "capture_path": req["capture_path"],
+                    "pattern": pattern,
+                    "shaders_searched": shaders_searched,
+                    "shaders_without_source": shaders_without_source,
+                    "total_matches": total_matches,
+                    "truncated": truncated,
+                    "shaders": list(hits.values()),
+                },
+            )
+        finally:
+            try:
+                controller.Shutdown()
+            except Exception:
+                pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
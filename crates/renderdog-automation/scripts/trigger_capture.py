@@ -31,25 +31,38 @@ def main() -> None:
     try:
         target.TriggerCapture(int(req["num_frames"]))
 
-        # Wait for NewCapture message(s)
+        # Each frame arrives as its own NewCapture message.
+        num_frames = max(int(req["num_frames"]), 1)
+        captures = []
+        api = None
         deadline = time.time() + float(req["timeout_s"])
-        while time.time() < deadline:
+        while time.time() < deadline and len(captures) < num_frames:
             msg = target.ReceiveMessage(None)
             if msg is None:
                 continue
             if msg.type == rd.TargetControlMessageType.NewCapture:
                 cap = msg.newCapture
-                write_envelope(
-                    True,
-                    result={
-                        "capture_path": cap.path,
+                api = api or str(cap.api)
+                captures.append(
+                    {
+                        "frame_index": len(captures),
                         "frame_number": int(cap.frameNumber),
-                        "api": str(cap.api),
-                    },
+                        "capture_path": cap.path,
+                    }
                 )
-                return
 
-        raise RuntimeError("Timed out waiting for NewCapture message")
+        if not captures:
+            raise RuntimeError("Timed out waiting for NewCapture message")
+
+        write_envelope(
+            True,
+            result={
+                "capture_path": captures[0]["capture_path"],
+                "frame_number": captures[0]["frame_number"],
+                "api": api,
+                "captures": captures,
+            },
+        )
     finally:
         try:
             target.Shutdown()
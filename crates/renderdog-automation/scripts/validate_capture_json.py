@@ -0,0 +1,249 @@
+"""
+validate_capture_json.py -- RenderDoc Python script that checks a capture is intact and replayable.
+
+Runs the steps any later processing depends on and reports each as a check instead of stopping at
+the first exception:
+
+  - open_file: the container opens
+  - local_replay_support: this machine can replay the capture's API
+  - sections: every section except the frame data can be read back at its recorded size (the
+    frame data itself is exercised by the replay checks)
+  - open_capture: replay initializes
+  - enumerate_actions: the action tree loads and event IDs are strictly increasing
+  - replay_frame: the whole frame replays (seek to the last event)
+
+Checks after a failed prerequisite are reported as skipped (`ok` false, detail "skipped").
+Debug messages recorded at capture time and raised during replay are returned as well.
+
+Request parameters:
+  - capture_path: Path to the .rdc capture file (required)
+
+Returns:
+  - valid, checks, sections, total_actions, total_events, last_event_id, messages
+"""
+
+import json
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "validate_capture_json.request.json"
+RESP_PATH = "validate_capture_json.response.json"
+
+CHECKS = [
+    "open_file",
+    "local_replay_support",
+    "sections",
+    "open_capture",
+    "enumerate_actions",
+    "replay_frame",
+]
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def replay_options(req):
+    """rd.ReplayOptions from the request's optional `replay_options`; unset fields keep defaults."""
+    opts = rd.ReplayOptions()
+    ro = req.get("replay_options") or {}
+    opts.apiValidation = bool(ro.get("api_validation"))
+    if ro.get("optimisation"):
+        opts.optimisation = {
+            "no_optimisation": rd.ReplayOptimisationLevel.NoOptimisation,
+            "conservative": rd.ReplayOptimisationLevel.Conservative,
+            "balanced": rd.ReplayOptimisationLevel.Balanced,
+            "fastest": rd.ReplayOptimisationLevel.Fastest,
+        }[ro["optimisation"]]
+    if ro.get("gpu_vendor"):
+        vendors = [n for n in dir(rd.GPUVendor) if n.lower() == ro["gpu_vendor"].lower()]
+        if not vendors:
+            raise RuntimeError("Unknown GPU vendor: " + ro["gpu_vendor"])
+        opts.forceGPUVendor = getattr(rd.GPUVendor, vendors[0])
+    if ro.get("gpu_device_id") is not None:
+        opts.forceGPUDeviceID = int(ro["gpu_device_id"])
+    if ro.get("gpu_driver_name"):
+        opts.forceGPUDriverName = ro["gpu_driver_name"]
+    return opts
+
+
+def enum_name(value) -> str:
+    return str(value).split(".")[-1]
+
+
+class Report:
+    def __init__(self):
+        self.checks = {}
+
+    def record(self, name, ok, detail=None):
+        self.checks[name] = {"name": name, "ok": bool(ok), "detail": detail}
+        return ok
+
+    def run(self, name, fn):
+        """Record fn()'s (ok, detail), or the exception it raised."""
+        try:
+            ok, detail = fn()
+        except Exception as e:
+            ok, detail = False, "%s: %s" % (type(e).__name__, e)
+        return self.record(name, ok, detail)
+
+    def finish(self):
+        out = []
+        for name in CHECKS:
+            out.append(self.checks.get(name) or {"name": name, "ok": False, "detail": "skipped"})
+        return out
+
+
+def check_sections(cap, sections):
+    bad = 0
+    for index in range(cap.GetSectionCount()):
+        props = cap.GetSectionProperties(index)
+        entry = {
+            "name": str(props.name),
+            "section_type": enum_name(props.type),
+            "uncompressed_size": int(props.uncompressedSize),
+            "ok": True,
+            "detail": None,
+        }
+        if props.type != rd.SectionType.FrameCapture:
+            try:
+                size = len(bytes(cap.GetSectionContents(index)))
+                if size != int(props.uncompressedSize):
+                    entry["ok"] = False
+                    entry["detail"] = "read %d bytes, expected %d" % (
+                        size,
+                        int(props.uncompressedSize),
+                    )
+            except Exception as e:
+                entry["ok"] = False
+                entry["detail"] = "%s: %s" % (type(e).__name__, e)
+        if not entry["ok"]:
+            bad += 1
+        sections.append(entry)
+    if bad:
+        return False, "%d of %d sections unreadable" % (bad, len(sections))
+    return True, "%d sections" % len(sections)
+
+
+def enumerate_actions(controller, counters):
+    last = [0]
+    errors = []
+
+    def walk(actions):
+        for a in actions:
+            counters["total_actions"] += 1
+            for ev in a.events:
+                eid = int(ev.eventId)
+                counters["total_events"] += 1
+                if eid <= last[0] and len(errors) < 10:
+                    errors.append("event %d follows %d" % (eid, last[0]))
+                last[0] = max(last[0], eid)
+            walk(a.children)
+
+    walk(controller.GetRootActions())
+    counters["last_event_id"] = last[0] or None
+    if counters["total_actions"] == 0:
+        return False, "capture contains no actions"
+    if errors:
+        return False, "event IDs out of order: " + "; ".join(errors)
+    return True, "%d actions, %d events" % (counters["total_actions"], counters["total_events"])
+
+
+def serialize_messages(messages, source):
+    return [
+        {
+            "source": source,
+            "severity": enum_name(m.severity),
+            "category": enum_name(m.category),
+            "event_id": int(m.eventId),
+            "description": str(m.description),
+        }
+        for m in messages
+    ]
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    report = Report()
+    sections = []
+    messages = []
+    counters = {"total_actions": 0, "total_events": 0, "last_event_id": None}
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if report.record("open_file", result == rd.ResultCode.Succeeded, str(result)):
+            report.run(
+                "local_replay_support",
+                lambda: (bool(cap.LocalReplaySupport()), str(cap.DriverName())),
+            )
+            report.run("sections", lambda: check_sections(cap, sections))
+
+        controller = None
+        if report.checks.get("local_replay_support", {}).get("ok"):
+            result, controller = cap.OpenCapture(replay_options(req), None)
+            if not report.record("open_capture", result == rd.ResultCode.Succeeded, str(result)):
+                controller = None
+
+        if controller is not None:
+            try:
+                try:
+                    messages.extend(
+                        serialize_messages(controller.GetFrameInfo().debugMessages, "capture")
+                    )
+                except Exception:
+                    pass
+
+                if report.run("enumerate_actions", lambda: enumerate_actions(controller, counters)):
+
+                    def replay():
+                        controller.SetFrameEvent(counters["last_event_id"], True)
+                        return True, "replayed to event %d" % counters["last_event_id"]
+
+                    report.run("replay_frame", replay)
+
+                try:
+                    messages.extend(serialize_messages(controller.GetDebugMessages(), "replay"))
+                except Exception:
+                    pass
+            finally:
+                try:
+                    controller.Shutdown()
+                except Exception:
+                    pass
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+    checks = report.finish()
+    write_envelope(
+        True,
+        result={
+            "capture_path": req["capture_path"],
+            "valid": all(c["ok"] for c in checks),
+            "checks": checks,
+            "sections": sections,
+            "total_actions": counters["total_actions"],
+            "total_events": counters["total_events"],
+            "last_event_id": counters["last_event_id"],
+            "messages": messages,
+        },
+    )
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
@@ -0,0 +1,117 @@
+"""
+write_capture_section_json.py -- RenderDoc Python script that embeds a named section in a capture.
+
+Writes `contents` (UTF-8 text: notes, test metadata JSON, repro commands, ...) into the capture
+through the CaptureFile section API, replacing any existing section with the same name. The
+capture is modified in place; the frame data is untouched.
+
+`section_type` "Notes" writes RenderDoc's own capture comments section (shown in qrenderdoc's
+Capture Comments panel); its name is then always "renderdoc/ui/notes" and `contents` is wrapped
+as `{"comments": contents}` unless it is already a JSON object.
+
+Request parameters:
+  - capture_path: Path to the .rdc capture file (required)
+  - name: Section name (required unless section_type is "Notes")
+  - contents: Text to store (required)
+  - section_type: "Unknown" (default, custom section) or "Notes"
+  - version: Section version (default 1)
+  - compress: zstd-compress the section (default false)
+
+Returns:
+  - name, section_type, uncompressed_size, replaced
+"""
+
+import json
+import traceback
+
+import renderdoc as rd
+
+
+REQ_PATH = "write_capture_section_json.request.json"
+RESP_PATH = "write_capture_section_json.response.json"
+
+NOTES_SECTION_NAME = "renderdoc/ui/notes"
+
+
+def write_envelope(ok: bool, result=None, error: str = None) -> None:
+    with open(RESP_PATH, "w", encoding="utf-8") as f:
+        json.dump({"ok": ok, "result": result, "error": error}, f, ensure_ascii=False)
+
+
+def notes_payload(contents: str) -> str:
+    try:
+        if isinstance(json.loads(contents), dict):
+            return contents
+    except ValueError:
+        pass
+    return json.dumps({"comments": contents}, ensure_ascii=False)
+
+
+def main() -> None:
+    with open(REQ_PATH, "r", encoding="utf-8") as f:
+        req = json.load(f)
+
+    section_type = str(req.get("section_type") or "Unknown")
+    contents = str(req["contents"])
+    if section_type.lower() == "notes":
+        props_type = rd.SectionType.Notes
+        name = NOTES_SECTION_NAME
+        contents = notes_payload(contents)
+    elif section_type.lower() == "unknown":
+        props_type = rd.SectionType.Unknown
+        name = req.get("name") or ""
+        if not name:
+            raise RuntimeError("name is required for custom sections")
+    else:
+        raise RuntimeError(
+            "Unsupported section_type '%s' (expected Unknown or Notes)" % section_type
+        )
+
+    data = contents.encode("utf-8")
+
+    rd.InitialiseReplay(rd.GlobalEnvironment(), [])
+
+    cap = rd.OpenCaptureFile()
+    try:
+        result = cap.OpenFile(req["capture_path"], "", None)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't open file: " + str(result))
+
+        replaced = cap.FindSectionByName(name) >= 0
+
+        props = rd.SectionProperties()
+        props.name = name
+        props.type = props_type
+        props.version = int(req.get("version") or 1)
+        props.uncompressedSize = len(data)
+        if req.get("compress", False):
+            props.flags = rd.SectionFlags.ZstdCompressed
+
+        result = cap.WriteSection(props, data)
+        if result != rd.ResultCode.Succeeded:
+            raise RuntimeError("Couldn't write section '%s': %s" % (name, str(result)))
+
+        write_envelope(
+            True,
+            result={
+                "capture_path": req["capture_path"],
+                "name": name,
+                "section_type": str(props_type).split(".")[-1],
+                "uncompressed_size": len(data),
+                "replaced": replaced,
+            },
+        )
+    finally:
+        try:
+            cap.Shutdown()
+        except Exception:
+            pass
+        rd.ShutdownReplay()
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception:
+        write_envelope(False, error=traceback.format_exc())
+    raise SystemExit(0)
@@ -0,0 +1,703 @@
+//! Typed readers for the JSONL exports.
+//!
+//! [`ActionsReader`] reads `<basename>.actions.jsonl` (from `export_actions_jsonl`) and
+//! [`BindingsReader`] reads `<basename>.bindings.jsonl` (from `export_bindings_index_jsonl`).
+//! Both are iterators of `Result<record, ExportReadError>` and transparently decompress
+//! `.jsonl.zst` files written with `compress: true`. Records are parsed one line at a time into a
+//! reused buffer, so memory use is bounded by the largest record rather than the file size, and
+//! `summarize()` folds a whole export into counters the same way.
+//!
+//! Records and summaries carry a `schema_version` ([`EXPORT_SCHEMA_VERSION`]); the readers reject
+//! exports from a newer version, and [`export_schema`] returns the JSON Schema of each
+//! [`ExportArtifact`] for validating exports outside Rust:
+//!
+//! ```no_run
+//! # fn main() -> Result<(), renderdog_automation::ExportReadError> {
+//! use renderdog_automation::ActionsReader;
+//!
+//! for action in ActionsReader::open("exports/frame.actions.jsonl")?.drawcalls() {
+//!     let action = action?;
+//!     println!("{} {}", action.event_id, action.name);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{BufRead, BufReader},
+    marker::PhantomData,
+    path::{Path, PathBuf},
+};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use thiserror::Error;
+
+/// Version of the JSONL record and summary formats written by the export scripts. Bumped whenever
+/// a field is renamed, removed or changes meaning (new fields alone do not bump it).
+pub const EXPORT_SCHEMA_VERSION: u32 = 1;
+
+/// The artifact types described by [`export_schema`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportArtifact {
+    /// One line of `<basename>.actions.jsonl` ([`ActionRecord`]).
+    Actions,
+    /// One line of `<basename>.bindings.jsonl` ([`BindingRecord`]).
+    Bindings,
+    /// `<basename>.summary.json` ([`ActionsExportSummary`]).
+    ActionsSummary,
+    /// `<basename>.bindings_summary.json` ([`BindingsExportSummary`]).
+    BindingsSummary,
+}
+
+impl ExportArtifact {
+    pub const ALL: [ExportArtifact; 4] = [
+        Self::Actions,
+        Self::Bindings,
+        Self::ActionsSummary,
+        Self::BindingsSummary,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Actions => "actions",
+            Self::Bindings => "bindings",
+            Self::ActionsSummary => "actions_summary",
+            Self::BindingsSummary => "bindings_summary",
+        }
+    }
+}
+
+/// JSON Schema (draft 2020-12) of an export artifact at [`EXPORT_SCHEMA_VERSION`].
+pub fn export_schema(artifact: ExportArtifact) -> serde_json::Value {
+    let schema = match artifact {
+        ExportArtifact::Actions => schemars::schema_for!(ActionRecord),
+        ExportArtifact::Bindings => schemars::schema_for!(BindingRecord),
+        ExportArtifact::ActionsSummary => schemars::schema_for!(ActionsExportSummary),
+        ExportArtifact::BindingsSummary => schemars::schema_for!(BindingsExportSummary),
+    };
+    let mut value = schema.to_value();
+    if let Some(obj) = value.as_object_mut() {
+        obj.insert(
+            "$comment".into(),
+            format!("renderdog export schema version {EXPORT_SCHEMA_VERSION}").into(),
+        );
+    }
+    value
+}
+
+/// One line of `<basename>.actions.jsonl`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ActionRecord {
+    /// [`EXPORT_SCHEMA_VERSION`] of the writer; 0 for exports that predate versioning.
+    #[serde(default)]
+    pub schema_version: u32,
+    pub event_id: u32,
+    pub parent_event_id: Option<u32>,
+    pub depth: u32,
+    pub name: String,
+    /// Raw `ActionFlags` bits.
+    pub flags: u64,
+    /// `ActionFlags` names, e.g. `["Drawcall", "Indexed"]`.
+    pub flags_names: Vec<String>,
+    pub marker_path: Vec<String>,
+    pub num_children: u32,
+    /// Symbolicated callstack of the action's API call, innermost frame first. Present when
+    /// exported with `resolve_callstacks`; empty for actions recorded without a callstack.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub callstack: Vec<String>,
+}
+
+impl ActionRecord {
+    pub fn has_flag(&self, name: &str) -> bool {
+        self.flags_names.iter().any(|f| f == name)
+    }
+
+    /// Draws, dispatches (including mesh dispatches) and ray dispatches.
+    pub fn is_drawcall(&self) -> bool {
+        ["Drawcall", "Dispatch", "MeshDispatch", "DispatchRay"]
+            .iter()
+            .any(|f| self.has_flag(f))
+    }
+
+    /// Marker path joined with `/`, as matched by `marker_prefix` on the export requests.
+    pub fn marker_path_joined(&self) -> String {
+        self.marker_path.join("/")
+    }
+}
+
+/// One line of `<basename>.bindings.jsonl`: the bindings of a single draw or dispatch.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct BindingRecord {
+    /// [`EXPORT_SCHEMA_VERSION`] of the writer; 0 for exports that predate versioning.
+    #[serde(default)]
+    pub schema_version: u32,
+    pub event_id: u32,
+    pub depth: u32,
+    pub name: String,
+    pub marker_path: Vec<String>,
+    pub marker_path_joined: String,
+    /// Keyed by shader stage name; only stages with a bound shader appear.
+    pub stages: BTreeMap<String, StageBindings>,
+    pub shader_names: Vec<String>,
+    pub resource_names: Vec<String>,
+    /// Present when exported with `include_outputs`.
+    #[serde(default)]
+    pub outputs: Option<OutputBindings>,
+}
+
+impl BindingRecord {
+    /// True if any shader, SRV, UAV, constant buffer or output is the given resource, matched by
+    /// resource name, binding name or resource id (`"ResourceId::123"`).
+    pub fn uses_resource(&self, name_or_id: &str) -> bool {
+        let stage_hit = self.stages.values().any(|s| {
+            s.shader.resource_id == name_or_id
+                || s.srvs.iter().chain(&s.uavs).any(|b| b.matches(name_or_id))
+                || s.cbuffers.iter().any(|b| {
+                    b.name == name_or_id
+                        || b.resource_name == name_or_id
+                        || b.resource_id.as_deref() == Some(name_or_id)
+                })
+        });
+        let output_hit = self.outputs.as_ref().is_some_and(|o| {
+            o.render_targets
+                .iter()
+                .map(|t| (&t.resource_id, &t.resource_name))
+                .chain(
+                    o.depth_target
+                        .iter()
+                        .map(|t| (&t.resource_id, &t.resource_name)),
+                )
+                .any(|(id, name)| id == name_or_id || name == name_or_id)
+        });
+        stage_hit || output_hit
+    }
+
+    /// True if any bound shader has this name or entry point.
+    pub fn uses_shader(&self, name: &str) -> bool {
+        self.stages
+            .values()
+            .any(|s| s.shader.name == name || s.shader.entry_point == name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct StageBindings {
+    pub shader: ShaderBinding,
+    pub srvs: Vec<ResourceBinding>,
+    pub uavs: Vec<ResourceBinding>,
+    /// Only filled when exported with `include_cbuffers`.
+    pub cbuffers: Vec<ConstantBufferBinding>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ShaderBinding {
+    pub resource_id: String,
+    pub name: String,
+    pub entry_point: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ResourceBinding {
+    pub slot: u32,
+    /// Name from shader reflection (empty if unknown).
+    pub name: String,
+    pub resource_id: String,
+    pub resource_name: String,
+}
+
+impl ResourceBinding {
+    fn matches(&self, name_or_id: &str) -> bool {
+        self.resource_id == name_or_id
+            || self.resource_name == name_or_id
+            || self.name == name_or_id
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ConstantBufferBinding {
+    pub slot: u32,
+    pub name: String,
+    pub size: u64,
+    pub resource_id: Option<String>,
+    pub resource_name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct OutputBindings {
+    pub render_targets: Vec<RenderTargetBinding>,
+    pub depth_target: Option<DepthTargetBinding>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct RenderTargetBinding {
+    pub index: u32,
+    pub resource_id: String,
+    pub resource_name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct DepthTargetBinding {
+    pub resource_id: String,
+    pub resource_name: String,
+}
+
+/// `<basename>.summary.json`, written next to the actions export.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ActionsExportSummary {
+    #[serde(default)]
+    pub schema_version: u32,
+    pub capture_path: String,
+    pub api: String,
+    pub total_actions: u64,
+    pub drawcall_actions: u64,
+    /// Actions exported with a resolved callstack (0 unless `resolve_callstacks` was set).
+    #[serde(default)]
+    pub callstack_actions: u64,
+    pub actions_jsonl_path: String,
+}
+
+/// `<basename>.bindings_summary.json`, written next to the bindings export.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct BindingsExportSummary {
+    #[serde(default)]
+    pub schema_version: u32,
+    pub capture_path: String,
+    pub api: String,
+    pub total_drawcalls: u64,
+    pub bindings_jsonl_path: String,
+}
+
+impl ActionsExportSummary {
+    pub fn read(path: impl AsRef<Path>) -> Result<Self, ExportReadError> {
+        read_summary(path.as_ref(), |s: &Self| s.schema_version)
+    }
+}
+
+impl BindingsExportSummary {
+    pub fn read(path: impl AsRef<Path>) -> Result<Self, ExportReadError> {
+        read_summary(path.as_ref(), |s: &Self| s.schema_version)
+    }
+}
+
+/// Totals of an actions export (see [`ActionsReader::summarize`]).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ActionsSummary {
+    pub total_actions: u64,
+    pub drawcall_actions: u64,
+    pub max_depth: u32,
+    pub first_event_id: Option<u32>,
+    pub last_event_id: Option<u32>,
+    /// Number of actions carrying each `ActionFlags` name.
+    pub flag_counts: BTreeMap<String, u64>,
+}
+
+/// Totals of a bindings export (see [`BindingsReader::summarize`]).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct BindingsSummary {
+    pub total_drawcalls: u64,
+    /// Draws with a shader bound at each stage.
+    pub stage_counts: BTreeMap<String, u64>,
+    /// Draws per shader name (entry point when the shader is unnamed).
+    pub shader_counts: BTreeMap<String, u64>,
+}
+
+#[derive(Debug, Error)]
+pub enum ExportReadError {
+    #[error("failed to open {path}: {source}")]
+    Open {
+        path: String,
+        source: std::io::Error,
+    },
+    #[error("failed to read {path}: {source}")]
+    Read {
+        path: String,
+        source: std::io::Error,
+    },
+    #[error("failed to parse {path} line {line}: {source}")]
+    Parse {
+        path: String,
+        line: usize,
+        source: serde_json::Error,
+    },
+    #[error(
+        "{path} line {line} has schema_version {version}, newer than the supported {}",
+        EXPORT_SCHEMA_VERSION
+    )]
+    UnsupportedVersion {
+        path: String,
+        line: usize,
+        version: u32,
+    },
+}
+
+fn read_summary<T: DeserializeOwned>(
+    path: &Path,
+    version: impl Fn(&T) -> u32,
+) -> Result<T, ExportReadError> {
+    let bytes = std::fs::read(path).map_err(|source| ExportReadError::Open {
+        path: path.display().to_string(),
+        source,
+    })?;
+    let summary: T = serde_json::from_slice(&bytes).map_err(|source| ExportReadError::Parse {
+        path: path.display().to_string(),
+        line: 1,
+        source,
+    })?;
+    let version = version(&summary);
+    if version > EXPORT_SCHEMA_VERSION {
+        return Err(ExportReadError::UnsupportedVersion {
+            path: path.display().to_string(),
+            line: 1,
+            version,
+        });
+    }
+    Ok(summary)
+}
+
+/// Iterator over the records of an actions export.
+pub struct ActionsReader(JsonlReader<ActionRecord>);
+
+impl ActionsReader {
+    /// Open `<basename>.actions.jsonl` or `<basename>.actions.jsonl.zst`.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, ExportReadError> {
+        JsonlReader::open(path.as_ref()).map(Self)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.0.path
+    }
+
+    /// Only draws and dispatches (see [`ActionRecord::is_drawcall`]).
+    pub fn drawcalls(self) -> impl Iterator<Item = Result<ActionRecord, ExportReadError>> {
+        filter_ok(self, ActionRecord::is_drawcall)
+    }
+
+    /// Only actions whose `/`-joined marker path starts with `prefix`.
+    pub fn under_marker(
+        self,
+        prefix: &str,
+    ) -> impl Iterator<Item = Result<ActionRecord, ExportReadError>> {
+        let prefix = prefix.to_string();
+        filter_ok(self, move |a| a.marker_path_joined().starts_with(&prefix))
+    }
+
+    /// Only actions with `min <= event_id <= max`.
+    pub fn event_range(
+        self,
+        min: u32,
+        max: u32,
+    ) -> impl Iterator<Item = Result<ActionRecord, ExportReadError>> {
+        filter_ok(self, move |a| (min..=max).contains(&a.event_id))
+    }
+
+    /// Stream the rest of the export into an [`ActionsSummary`].
+    pub fn summarize(self) -> Result<ActionsSummary, ExportReadError> {
+        let mut summary = ActionsSummary::default();
+        for action in self {
+            let action = action?;
+            summary.total_actions += 1;
+            if action.is_drawcall() {
+                summary.drawcall_actions += 1;
+            }
+            summary.max_depth = summary.max_depth.max(action.depth);
+            summary.first_event_id.get_or_insert(action.event_id);
+            summary.last_event_id = Some(action.event_id);
+            for flag in action.flags_names {
+                *summary.flag_counts.entry(flag).or_default() += 1;
+            }
+        }
+        Ok(summary)
+    }
+}
+
+impl Iterator for ActionsReader {
+    type Item = Result<ActionRecord, ExportReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+/// Iterator over the records of a bindings export.
+pub struct BindingsReader(JsonlReader<BindingRecord>);
+
+impl BindingsReader {
+    /// Open `<basename>.bindings.jsonl` or `<basename>.bindings.jsonl.zst`.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, ExportReadError> {
+        JsonlReader::open(path.as_ref()).map(Self)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.0.path
+    }
+
+    /// Only draws that bind `name_or_id` (see [`BindingRecord::uses_resource`]).
+    pub fn using_resource(
+        self,
+        name_or_id: &str,
+    ) -> impl Iterator<Item = Result<BindingRecord, ExportReadError>> {
+        let name_or_id = name_or_id.to_string();
+        filter_ok(self, move |b| b.uses_resource(&name_or_id))
+    }
+
+    /// Only draws that bind a shader with this name or entry point.
+    pub fn using_shader(
+        self,
+        name: &str,
+    ) -> impl Iterator<Item = Result<BindingRecord, ExportReadError>> {
+        let name = name.to_string();
+        filter_ok(self, move |b| b.uses_shader(&name))
+    }
+
+    /// Only draws whose joined marker path starts with `prefix`.
+    pub fn under_marker(
+        self,
+        prefix: &str,
+    ) -> impl Iterator<Item = Result<BindingRecord, ExportReadError>> {
+        let prefix = prefix.to_string();
+        filter_ok(self, move |b| b.marker_path_joined.starts_with(&prefix))
+    }
+
+    /// Stream the rest of the export into a [`BindingsSummary`].
+    pub fn summarize(self) -> Result<BindingsSummary, ExportReadError> {
+        let mut summary = BindingsSummary::default();
+        for draw in self {
+            let draw = draw?;
+            summary.total_drawcalls += 1;
+            for (stage, bindings) in draw.stages {
+                *summary.stage_counts.entry(stage).or_default() += 1;
+                let shader = if bindings.shader.name.is_empty() {
+                    bindings.shader.entry_point
+                } else {
+                    bindings.shader.name
+                };
+                if !shader.is_empty() {
+                    *summary.shader_counts.entry(shader).or_default() += 1;
+                }
+            }
+        }
+        Ok(summary)
+    }
+}
+
+impl Iterator for BindingsReader {
+    type Item = Result<BindingRecord, ExportReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+/// Line buffers that grew past this (one huge record) are shrunk back before the next read.
+const MAX_RETAINED_LINE_BYTES: usize = 1 << 20;
+
+struct JsonlReader<T> {
+    path: PathBuf,
+    reader: Box<dyn BufRead + Send>,
+    line: usize,
+    buf: String,
+    _record: PhantomData<fn() -> T>,
+}
+
+impl<T> JsonlReader<T> {
+    fn open(path: &Path) -> Result<Self, ExportReadError> {
+        let open_err = |source| ExportReadError::Open {
+            path: path.display().to_string(),
+            source,
+        };
+        let file = File::open(path).map_err(open_err)?;
+        let reader: Box<dyn BufRead + Send> = if path.extension().is_some_and(|ext| ext == "zst") {
+            #[cfg(feature = "zstd")]
+            {
+                Box::new(BufReader::new(
+                    zstd::stream::read::Decoder::new(file).map_err(open_err)?,
+                ))
+            }
+            #[cfg(not(feature = "zstd"))]
+            {
+                return Err(open_err(zstd_unsupported()));
+            }
+        } else {
+            Box::new(BufReader::new(file))
+        };
+        Ok(Self {
+            path: path.to_path_buf(),
+            reader,
+            line: 0,
+            buf: String::new(),
+            _record: PhantomData,
+        })
+    }
+}
+
+/// Error for `.zst` reads and `compress: true` exports in builds without the `zstd` feature.
+pub(crate) fn zstd_unsupported() -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "zstd support is disabled; enable the `zstd` feature of renderdog-automation",
+    )
+}
+
+trait Versioned {
+    fn schema_version(&self) -> u32;
+}
+
+impl Versioned for ActionRecord {
+    fn schema_version(&self) -> u32 {
+        self.schema_version
+    }
+}
+
+impl Versioned for BindingRecord {
+    fn schema_version(&self) -> u32 {
+        self.schema_version
+    }
+}
+
+impl<T: DeserializeOwned + Versioned> Iterator for JsonlReader<T> {
+    type Item = Result<T, ExportReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.buf.clear();
+            if self.buf.capacity() > MAX_RETAINED_LINE_BYTES {
+                self.buf.shrink_to(64 * 1024);
+            }
+            self.line += 1;
+            match self.reader.read_line(&mut self.buf) {
+                Ok(0) => return None,
+                Ok(_) => {}
+                Err(source) => {
+                    return Some(Err(ExportReadError::Read {
+                        path: self.path.display().to_string(),
+                        source,
+                    }));
+                }
+            }
+            if self.buf.trim().is_empty() {
+                continue;
+            }
+            let record: T = match serde_json::from_str(&self.buf) {
+                Ok(record) => record,
+                Err(source) => {
+                    return Some(Err(ExportReadError::Parse {
+                        path: self.path.display().to_string(),
+                        line: self.line,
+                        source,
+                    }));
+                }
+            };
+            let version = record.schema_version();
+            if version > EXPORT_SCHEMA_VERSION {
+                return Some(Err(ExportReadError::UnsupportedVersion {
+                    path: self.path.display().to_string(),
+                    line: self.line,
+                    version,
+                }));
+            }
+            return Some(Ok(record));
+        }
+    }
+}
+
+/// Keep `Ok` records matching `pred`; errors are passed through.
+fn filter_ok<T>(
+    iter: impl Iterator<Item = Result<T, ExportReadError>>,
+    pred: impl Fn(&T) -> bool,
+) -> impl Iterator<Item = Result<T, ExportReadError>> {
+    iter.filter(move |r| r.as_ref().map_or(true, &pred))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_plain_and_compressed_exports() {
+        let dir = std::env::temp_dir().join(format!("renderdog-readers-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let actions = concat!(
+            r#"{"event_id": 1, "parent_event_id": null, "depth": 0, "name": "GBuffer", "flags": 4, "flags_names": ["PushMarker"], "marker_path": [], "num_children": 1}"#,
+            "\n",
+            r#"{"event_id": 2, "parent_event_id": 1, "depth": 1, "name": "DrawIndexed(36)", "flags": 3, "flags_names": ["Drawcall", "Indexed"], "marker_path": ["GBuffer"], "num_children": 0}"#,
+            "\n",
+        );
+        let plain = dir.join("cap.actions.jsonl");
+        std::fs::write(&plain, actions).unwrap();
+        let draws: Vec<_> = ActionsReader::open(&plain)
+            .unwrap()
+            .drawcalls()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(draws.len(), 1);
+        assert_eq!(draws[0].marker_path_joined(), "GBuffer");
+        let summary = ActionsReader::open(&plain).unwrap().summarize().unwrap();
+        assert_eq!((summary.total_actions, summary.drawcall_actions), (2, 1));
+        assert_eq!(summary.last_event_id, Some(2));
+        assert_eq!(summary.flag_counts["Drawcall"], 1);
+
+        #[cfg(feature = "zstd")]
+        {
+            let bindings = concat!(
+                r#"{"event_id": 2, "depth": 1, "name": "DrawIndexed(36)", "marker_path": ["GBuffer"], "marker_path_joined": "GBuffer", "#,
+                r#""stages": {"Pixel": {"shader": {"resource_id": "ResourceId::7", "name": "gbuffer_ps", "entry_point": "main"}, "#,
+                r#""srvs": [{"slot": 0, "name": "albedo", "resource_id": "ResourceId::9", "resource_name": "Albedo"}], "uavs": [], "cbuffers": []}}, "#,
+                r#""shader_names": ["gbuffer_ps", "main"], "resource_names": ["albedo", "Albedo"]}"#,
+                "\n",
+            );
+            let zst = dir.join("cap.bindings.jsonl.zst");
+            std::fs::write(&zst, zstd::encode_all(bindings.as_bytes(), 0).unwrap()).unwrap();
+            let hits: Vec<_> = BindingsReader::open(&zst)
+                .unwrap()
+                .using_resource("ResourceId::9")
+                .collect::<Result<_, _>>()
+                .unwrap();
+            assert_eq!(hits.len(), 1);
+            assert!(hits[0].uses_shader("gbuffer_ps"));
+            assert!(hits[0].outputs.is_none());
+            let summary = BindingsReader::open(&zst).unwrap().summarize().unwrap();
+            assert_eq!(summary.stage_counts["Pixel"], 1);
+            assert_eq!(summary.shader_counts["gbuffer_ps"], 1);
+        }
+
+        std::fs::write(&plain, "{not json}\n").unwrap();
+        let err = ActionsReader::open(&plain).unwrap().next().unwrap();
+        assert!(matches!(err, Err(ExportReadError::Parse { line: 1, .. })));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn rejects_newer_schema_versions() {
+        let dir = std::env::temp_dir().join(format!("renderdog-schema-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("cap.actions.jsonl");
+        let line = |version: u32| {
+            format!(
+                r#"{{"schema_version": {version}, "event_id": 1, "parent_event_id": null, "depth": 0, "name": "Draw", "flags": 2, "flags_names": ["Drawcall"], "marker_path": [], "num_children": 0}}"#
+            )
+        };
+        std::fs::write(
+            &path,
+            format!("{}\n{}\n", line(1), line(EXPORT_SCHEMA_VERSION + 1)),
+        )
+        .unwrap();
+
+        let mut reader = ActionsReader::open(&path).unwrap();
+        assert_eq!(reader.next().unwrap().unwrap().schema_version, 1);
+        assert!(matches!(
+            reader.next().unwrap(),
+            Err(ExportReadError::UnsupportedVersion { line: 2, .. })
+        ));
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let schema = export_schema(ExportArtifact::Actions);
+        assert!(schema["properties"]["schema_version"].is_object());
+        assert!(schema["properties"]["marker_path"].is_object());
+    }
+}
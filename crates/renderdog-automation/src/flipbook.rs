@@ -0,0 +1,310 @@
+//! Encode an [`export_flipbook`](crate::RenderDocInstallation::export_flipbook) image sequence
+//! into an animated GIF or an MP4 (requires the `encode` feature).
+//!
+//! GIFs are encoded in-process with the `image` crate. MP4s are handed to `ffmpeg` (from `PATH`
+//! unless [`EncodeFlipbookRequest::ffmpeg`] is set) through a concat list next to the output.
+//! Frames are scaled to the size of the first frame, since the bound RT0 can change size between
+//! passes.
+
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use image::{
+    Delay, Frame,
+    codecs::gif::{GifEncoder, Repeat},
+    imageops::{self, FilterType},
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{FlipbookIndex, resolve_path_string_from_cwd};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum FlipbookVideoFormat {
+    Gif,
+    Mp4,
+}
+
+impl FlipbookVideoFormat {
+    /// Guess the format from an output path's extension.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()?.to_ascii_lowercase().as_str() {
+            "gif" => Some(Self::Gif),
+            "mp4" => Some(Self::Mp4),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct EncodeFlipbookRequest {
+    /// The `<basename>.flipbook.json` written by `export_flipbook`.
+    pub index_json_path: String,
+    pub output_path: String,
+    /// Inferred from the `output_path` extension when `None`.
+    pub format: Option<FlipbookVideoFormat>,
+    pub fps: u32,
+    /// Encode the depth images instead of RT0.
+    pub use_depth: bool,
+    /// `ffmpeg` executable used for MP4 output.
+    pub ffmpeg: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct EncodeFlipbookResponse {
+    pub video_path: String,
+    pub format: FlipbookVideoFormat,
+    pub frame_count: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+#[derive(Debug, Error)]
+pub enum EncodeFlipbookError {
+    #[error("failed to read flipbook index: {0}")]
+    ReadIndex(std::io::Error),
+    #[error("failed to parse flipbook index: {0}")]
+    ParseIndex(serde_json::Error),
+    #[error("cannot infer video format from {0} (expected .gif or .mp4)")]
+    UnknownFormat(String),
+    #[error("fps must be positive")]
+    InvalidFps,
+    #[error("flipbook has no saved frames")]
+    NoFrames,
+    #[error("failed to decode frame {path}: {source}")]
+    DecodeFrame {
+        path: String,
+        source: image::ImageError,
+    },
+    #[error("failed to encode GIF: {0}")]
+    EncodeGif(image::ImageError),
+    #[error("failed to write output: {0}")]
+    WriteOutput(std::io::Error),
+    #[error("failed to run ffmpeg: {0}")]
+    SpawnFfmpeg(std::io::Error),
+    #[error("ffmpeg failed ({status}): {stderr}")]
+    Ffmpeg { status: String, stderr: String },
+}
+
+/// Encode the frames listed in a flipbook index into an animated GIF or MP4.
+///
+/// Relative `index_json_path` / `output_path` are resolved against `cwd`.
+pub fn encode_flipbook(
+    cwd: &Path,
+    req: &EncodeFlipbookRequest,
+) -> Result<EncodeFlipbookResponse, EncodeFlipbookError> {
+    let req = EncodeFlipbookRequest {
+        index_json_path: resolve_path_string_from_cwd(cwd, &req.index_json_path),
+        output_path: resolve_path_string_from_cwd(cwd, &req.output_path),
+        ..req.clone()
+    };
+    let req = &req;
+    let output_path = PathBuf::from(&req.output_path);
+    let format = match req.format {
+        Some(format) => format,
+        None => FlipbookVideoFormat::from_path(&output_path)
+            .ok_or_else(|| EncodeFlipbookError::UnknownFormat(req.output_path.clone()))?,
+    };
+    if req.fps == 0 {
+        return Err(EncodeFlipbookError::InvalidFps);
+    }
+
+    let bytes = std::fs::read(&req.index_json_path).map_err(EncodeFlipbookError::ReadIndex)?;
+    let index: FlipbookIndex =
+        serde_json::from_slice(&bytes).map_err(EncodeFlipbookError::ParseIndex)?;
+    let frames: Vec<String> = index
+        .frames
+        .into_iter()
+        .filter_map(|f| {
+            if req.use_depth {
+                f.depth_path
+            } else {
+                f.rt0_path
+            }
+        })
+        .collect();
+    let first = frames.first().ok_or(EncodeFlipbookError::NoFrames)?;
+    let (width, height) =
+        image::image_dimensions(first).map_err(|source| EncodeFlipbookError::DecodeFrame {
+            path: first.clone(),
+            source,
+        })?;
+
+    if let Some(dir) = output_path.parent() {
+        std::fs::create_dir_all(dir).map_err(EncodeFlipbookError::WriteOutput)?;
+    }
+
+    let (width, height) = match format {
+        FlipbookVideoFormat::Gif => {
+            encode_gif(&frames, &output_path, width, height, req.fps)?;
+            (width, height)
+        }
+        FlipbookVideoFormat::Mp4 => {
+            // yuv420p needs even dimensions.
+            let (width, height) = (width.max(2) & !1, height.max(2) & !1);
+            encode_mp4(req, &frames, &output_path, width, height)?;
+            (width, height)
+        }
+    };
+
+    Ok(EncodeFlipbookResponse {
+        video_path: output_path.display().to_string(),
+        format,
+        frame_count: frames.len() as u32,
+        width,
+        height,
+    })
+}
+
+fn encode_gif(
+    frames: &[String],
+    output_path: &Path,
+    width: u32,
+    height: u32,
+    fps: u32,
+) -> Result<(), EncodeFlipbookError> {
+    let file = File::create(output_path).map_err(EncodeFlipbookError::WriteOutput)?;
+    let mut writer = BufWriter::new(file);
+    let mut encoder = GifEncoder::new_with_speed(&mut writer, 10);
+    encoder
+        .set_repeat(Repeat::Infinite)
+        .map_err(EncodeFlipbookError::EncodeGif)?;
+
+    for path in frames {
+        let mut rgba = image::open(path)
+            .map_err(|source| EncodeFlipbookError::DecodeFrame {
+                path: path.clone(),
+                source,
+            })?
+            .to_rgba8();
+        if rgba.dimensions() != (width, height) {
+            rgba = imageops::resize(&rgba, width, height, FilterType::Triangle);
+        }
+        let delay = Delay::from_numer_denom_ms(1000, fps);
+        encoder
+            .encode_frame(Frame::from_parts(rgba, 0, 0, delay))
+            .map_err(EncodeFlipbookError::EncodeGif)?;
+    }
+    // Dropping the encoder writes the GIF trailer; flush and sync afterwards so write errors
+    // are reported instead of lost in `Drop`.
+    drop(encoder);
+    let file = writer
+        .into_inner()
+        .map_err(|e| EncodeFlipbookError::WriteOutput(e.into_error()))?;
+    file.sync_all().map_err(EncodeFlipbookError::WriteOutput)
+}
+
+fn encode_mp4(
+    req: &EncodeFlipbookRequest,
+    frames: &[String],
+    output_path: &Path,
+    width: u32,
+    height: u32,
+) -> Result<(), EncodeFlipbookError> {
+    let list_path = output_path.with_extension("ffconcat");
+    let mut list =
+        BufWriter::new(File::create(&list_path).map_err(EncodeFlipbookError::WriteOutput)?);
+    let duration = 1.0 / f64::from(req.fps);
+    let write_list = |list: &mut BufWriter<File>| -> std::io::Result<()> {
+        writeln!(list, "ffconcat version 1.0")?;
+        for path in frames {
+            writeln!(list, "file '{}'", path.replace('\'', r"'\''"))?;
+            writeln!(list, "duration {duration}")?;
+        }
+        // The concat demuxer ignores the last entry's duration unless the file is repeated.
+        if let Some(last) = frames.last() {
+            writeln!(list, "file '{}'", last.replace('\'', r"'\''"))?;
+        }
+        list.flush()
+    };
+    write_list(&mut list).map_err(EncodeFlipbookError::WriteOutput)?;
+    drop(list);
+
+    let output = Command::new(req.ffmpeg.as_deref().unwrap_or("ffmpeg"))
+        .args([
+            "-y",
+            "-loglevel",
+            "error",
+            "-f",
+            "concat",
+            "-safe",
+            "0",
+            "-i",
+        ])
+        .arg(&list_path)
+        .arg("-vf")
+        .arg(format!("scale={width}:{height},format=yuv420p"))
+        .args(["-r", &req.fps.to_string(), "-c:v", "libx264"])
+        .arg(output_path)
+        .output()
+        .map_err(EncodeFlipbookError::SpawnFfmpeg)?;
+    let _ = std::fs::remove_file(&list_path);
+
+    if !output.status.success() {
+        return Err(EncodeFlipbookError::Ffmpeg {
+            status: output.status.to_string(),
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FlipbookFrame;
+
+    #[test]
+    fn gif_scales_frames_to_first_frame() {
+        let dir = std::env::temp_dir().join(format!("renderdog-flipbook-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let mut frames = Vec::new();
+        for (i, (w, h)) in [(8, 4), (16, 8)].into_iter().enumerate() {
+            let path = dir.join(format!("f{i}.png"));
+            image::RgbaImage::from_pixel(w, h, image::Rgba([255, 0, 0, 255]))
+                .save(&path)
+                .unwrap();
+            frames.push(FlipbookFrame {
+                index: i as u32,
+                event_id: 10 + i as u32,
+                name: "Draw".into(),
+                marker_path: String::new(),
+                rt0_resource_id: None,
+                rt0_path: Some(path.display().to_string()),
+                depth_path: None,
+            });
+        }
+        let index_path = dir.join("cap.flipbook.json");
+        let index = FlipbookIndex {
+            capture_path: "cap.rdc".into(),
+            frames,
+        };
+        std::fs::write(&index_path, serde_json::to_vec(&index).unwrap()).unwrap();
+
+        let res = encode_flipbook(
+            &dir,
+            &EncodeFlipbookRequest {
+                index_json_path: "cap.flipbook.json".into(),
+                output_path: "cap.gif".into(),
+                format: None,
+                fps: 4,
+                use_depth: false,
+                ffmpeg: None,
+            },
+        )
+        .unwrap();
+        assert_eq!(res.format, FlipbookVideoFormat::Gif);
+        assert_eq!((res.frame_count, res.width, res.height), (2, 8, 4));
+        assert_eq!(res.video_path, dir.join("cap.gif").display().to_string());
+        assert_eq!(image::image_dimensions(&res.video_path).unwrap(), (8, 4));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
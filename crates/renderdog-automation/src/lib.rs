@@ -1,8 +1,15 @@
 //! Out-of-process automation helpers for RenderDoc.
 //!
 //! This crate drives RenderDoc tooling via external processes:
-//! - `renderdoccmd capture` for injection-based capture
+//! - `renderdoccmd capture` for injection-based capture, `renderdoccmd convert` for format conversion
 //! - `qrenderdoc --python` for replay/analysis/export (e.g. `.actions.jsonl`)
+//! - RenderDoc's target control protocol (native TCP client) for triggering captures
+//!
+//! `.rdc` metadata and thumbnails are read natively ([`capture_metadata`], [`extract_thumbnail`]).
+//! JSONL exports can be read back as typed records with [`ActionsReader`] and [`BindingsReader`].
+//!
+//! Queries normally spawn one `qrenderdoc` per call. A [`ReplaySession`] keeps a single worker
+//! (and its open captures) alive instead; see [`RenderDocInstallation::with_replay_session`].
 //!
 //! Most failures are surfaced with detailed context (args/cwd/status/stdout/stderr) to make
 //! debugging environment issues easier.
@@ -10,20 +17,46 @@
 //! To override the auto-detection of RenderDoc tools, set:
 //! - `RENDERDOG_RENDERDOC_DIR=<RenderDoc install root>`
 
+mod artifacts;
+#[cfg(feature = "parquet")]
+mod columnar;
 mod command;
 mod diagnostics;
+#[cfg(feature = "encode")]
+mod flipbook;
+mod query;
+mod rdc;
+mod regression;
 mod renderdoccmd;
 mod replay;
+mod replay_session;
 mod scripting;
+mod shader_debug;
+#[cfg(feature = "sqlite")]
+mod sqlite;
+mod target_control;
 mod toolchain;
 mod ui;
 mod workflows;
 
+pub use artifacts::*;
+#[cfg(feature = "parquet")]
+pub use columnar::*;
 pub use command::*;
 pub use diagnostics::*;
+#[cfg(feature = "encode")]
+pub use flipbook::*;
+pub use query::*;
+pub use rdc::*;
+pub use regression::*;
 pub use renderdoccmd::*;
 pub use replay::*;
+pub use replay_session::*;
 pub use scripting::*;
+pub use shader_debug::*;
+#[cfg(feature = "sqlite")]
+pub use sqlite::*;
+pub use target_control::*;
 pub use toolchain::*;
 pub use ui::*;
 pub use workflows::*;
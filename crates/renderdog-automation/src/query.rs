@@ -0,0 +1,443 @@
+//! Event query language for `find_events` / `export_actions_jsonl`.
+//!
+//! A query is parsed here into an [`EventQuery`] and sent to the replay script, which evaluates it
+//! against every action while walking the frame:
+//!
+//! ```text
+//! draw && marker:"Shadow*" && eid > 1200 && triangles > 10000
+//! (clear || copy) && !marker:"UI/*"
+//! indexed && instances >= 2 && name:"*Skinned*"
+//! ```
+//!
+//! - A bare word is an `ActionFlags` name (case-insensitive, e.g. `drawcall`, `dispatch`, `clear`,
+//!   `copy`, `present`, `indirect`); `draw` is short for `drawcall`.
+//! - `name:<glob>` matches the action name; `marker:<glob>` matches any marker on the action's
+//!   marker path, or the `/`-joined path when the glob contains `/`. Globs use `*` and `?` and are
+//!   case-insensitive; quote them when they contain spaces or operators.
+//! - `<field> <op> <integer>` compares a number, with `op` one of `>`, `>=`, `<`, `<=`, `==` (or
+//!   `=`) and `!=`. Fields: `eid`, `depth`, `children`, `indices` (index or vertex count),
+//!   `instances` and `triangles` (`indices * instances / 3`, i.e. assuming triangle lists).
+//! - `!`, `&&`, `||` and parentheses combine conditions (`&&` binds tighter than `||`).
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// `ActionFlags` names accepted as bare words.
+const FLAG_NAMES: &[&str] = &[
+    "Clear",
+    "Drawcall",
+    "Dispatch",
+    "MeshDispatch",
+    "CmdList",
+    "SetMarker",
+    "PushMarker",
+    "PopMarker",
+    "Present",
+    "MultiAction",
+    "Copy",
+    "Resolve",
+    "GenMips",
+    "PassBoundary",
+    "DispatchRay",
+    "BuildAccStruct",
+    "Indexed",
+    "Instanced",
+    "Auto",
+    "Indirect",
+    "ClearColor",
+    "ClearDepthStencil",
+    "BeginPass",
+    "EndPass",
+    "CommandBufferBoundary",
+];
+
+/// Parsed query, serialized for the replay scripts as `{"op": ...}` nodes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum EventQuery {
+    And {
+        args: Vec<EventQuery>,
+    },
+    Or {
+        args: Vec<EventQuery>,
+    },
+    Not {
+        arg: Box<EventQuery>,
+    },
+    /// The action carries this `ActionFlags` name.
+    Flag {
+        flag: String,
+    },
+    Glob {
+        field: GlobField,
+        pattern: String,
+    },
+    Compare {
+        field: NumberField,
+        cmp: CompareOp,
+        value: u64,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum GlobField {
+    Name,
+    Marker,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum NumberField {
+    Eid,
+    Depth,
+    Children,
+    Indices,
+    Instances,
+    Triangles,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum CompareOp {
+    #[serde(rename = ">")]
+    Gt,
+    #[serde(rename = ">=")]
+    Ge,
+    #[serde(rename = "<")]
+    Lt,
+    #[serde(rename = "<=")]
+    Le,
+    #[serde(rename = "==")]
+    Eq,
+    #[serde(rename = "!=")]
+    Ne,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("query error at offset {offset}: {message}")]
+pub struct QueryParseError {
+    /// Byte offset into the query string.
+    pub offset: usize,
+    pub message: String,
+}
+
+impl std::str::FromStr for EventQuery {
+    type Err = QueryParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        EventQuery::parse(s)
+    }
+}
+
+impl EventQuery {
+    pub fn parse(query: &str) -> Result<Self, QueryParseError> {
+        let tokens = tokenize(query)?;
+        let mut parser = Parser {
+            tokens,
+            pos: 0,
+            end: query.len(),
+        };
+        let expr = parser.or()?;
+        match parser.peek() {
+            None => Ok(expr),
+            Some((offset, tok)) => Err(QueryParseError {
+                offset,
+                message: format!("unexpected {}", tok.describe()),
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    And,
+    Or,
+    Not,
+    LParen,
+    RParen,
+    Colon,
+    Cmp(CompareOp),
+    Word(String),
+    Quoted(String),
+}
+
+impl Token {
+    fn describe(&self) -> String {
+        match self {
+            Token::And => "`&&`".into(),
+            Token::Or => "`||`".into(),
+            Token::Not => "`!`".into(),
+            Token::LParen => "`(`".into(),
+            Token::RParen => "`)`".into(),
+            Token::Colon => "`:`".into(),
+            Token::Cmp(_) => "comparison".into(),
+            Token::Word(w) => format!("`{w}`"),
+            Token::Quoted(q) => format!("\"{q}\""),
+        }
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '*' | '?' | '.' | '/' | '-')
+}
+
+fn tokenize(query: &str) -> Result<Vec<(usize, Token)>, QueryParseError> {
+    let mut tokens = Vec::new();
+    let mut chars = query.char_indices().peekable();
+    while let Some(&(offset, c)) = chars.peek() {
+        let two = query[offset..].get(..2).unwrap_or("");
+        let (token, len) = match (c, two) {
+            (c, _) if c.is_whitespace() => {
+                chars.next();
+                continue;
+            }
+            (_, "&&") => (Token::And, 2),
+            (_, "||") => (Token::Or, 2),
+            (_, ">=") => (Token::Cmp(CompareOp::Ge), 2),
+            (_, "<=") => (Token::Cmp(CompareOp::Le), 2),
+            (_, "==") => (Token::Cmp(CompareOp::Eq), 2),
+            (_, "!=") => (Token::Cmp(CompareOp::Ne), 2),
+            ('>', _) => (Token::Cmp(CompareOp::Gt), 1),
+            ('<', _) => (Token::Cmp(CompareOp::Lt), 1),
+            ('=', _) => (Token::Cmp(CompareOp::Eq), 1),
+            ('!', _) => (Token::Not, 1),
+            ('(', _) => (Token::LParen, 1),
+            (')', _) => (Token::RParen, 1),
+            (':', _) => (Token::Colon, 1),
+            ('"', _) => {
+                chars.next();
+                let mut text = String::new();
+                loop {
+                    match chars.next() {
+                        Some((_, '"')) => break,
+                        Some((_, '\\')) => match chars.next() {
+                            Some((_, c)) => text.push(c),
+                            None => break,
+                        },
+                        Some((_, c)) => text.push(c),
+                        None => {
+                            return Err(QueryParseError {
+                                offset,
+                                message: "unterminated string".into(),
+                            });
+                        }
+                    }
+                }
+                tokens.push((offset, Token::Quoted(text)));
+                continue;
+            }
+            (c, _) if is_word_char(c) => {
+                let mut word = String::new();
+                while let Some(&(_, c)) = chars.peek() {
+                    if !is_word_char(c) {
+                        break;
+                    }
+                    word.push(c);
+                    chars.next();
+                }
+                tokens.push((offset, Token::Word(word)));
+                continue;
+            }
+            (c, _) => {
+                return Err(QueryParseError {
+                    offset,
+                    message: format!("unexpected character `{c}`"),
+                });
+            }
+        };
+        for _ in 0..len {
+            chars.next();
+        }
+        tokens.push((offset, token));
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    pos: usize,
+    end: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<(usize, &Token)> {
+        self.tokens.get(self.pos).map(|(o, t)| (*o, t))
+    }
+
+    fn next(&mut self) -> Option<(usize, Token)> {
+        let tok = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        tok
+    }
+
+    fn error(&self, offset: usize, message: impl Into<String>) -> QueryParseError {
+        QueryParseError {
+            offset,
+            message: message.into(),
+        }
+    }
+
+    fn or(&mut self) -> Result<EventQuery, QueryParseError> {
+        let mut args = vec![self.and()?];
+        while matches!(self.peek(), Some((_, Token::Or))) {
+            self.next();
+            args.push(self.and()?);
+        }
+        Ok(if args.len() == 1 {
+            args.remove(0)
+        } else {
+            EventQuery::Or { args }
+        })
+    }
+
+    fn and(&mut self) -> Result<EventQuery, QueryParseError> {
+        let mut args = vec![self.unary()?];
+        while matches!(self.peek(), Some((_, Token::And))) {
+            self.next();
+            args.push(self.unary()?);
+        }
+        Ok(if args.len() == 1 {
+            args.remove(0)
+        } else {
+            EventQuery::And { args }
+        })
+    }
+
+    fn unary(&mut self) -> Result<EventQuery, QueryParseError> {
+        match self.next() {
+            Some((_, Token::Not)) => Ok(EventQuery::Not {
+                arg: Box::new(self.unary()?),
+            }),
+            Some((offset, Token::LParen)) => {
+                let expr = self.or()?;
+                match self.next() {
+                    Some((_, Token::RParen)) => Ok(expr),
+                    _ => Err(self.error(offset, "unclosed `(`")),
+                }
+            }
+            Some((offset, Token::Word(word))) => self.condition(offset, &word),
+            Some((offset, tok)) => Err(self.error(
+                offset,
+                format!("expected a condition, found {}", tok.describe()),
+            )),
+            None => Err(self.error(self.end, "expected a condition")),
+        }
+    }
+
+    fn condition(&mut self, offset: usize, word: &str) -> Result<EventQuery, QueryParseError> {
+        let lower = word.to_ascii_lowercase();
+        match self.peek() {
+            Some((_, Token::Colon)) => {
+                self.next();
+                let field = match lower.as_str() {
+                    "name" => GlobField::Name,
+                    "marker" => GlobField::Marker,
+                    _ => {
+                        return Err(self.error(
+                            offset,
+                            format!("unknown text field `{word}` (expected `name` or `marker`)"),
+                        ));
+                    }
+                };
+                match self.next() {
+                    Some((_, Token::Word(pattern) | Token::Quoted(pattern))) => {
+                        Ok(EventQuery::Glob { field, pattern })
+                    }
+                    _ => Err(self.error(offset, format!("expected a pattern after `{word}:`"))),
+                }
+            }
+            Some((_, Token::Cmp(cmp))) => {
+                let cmp = *cmp;
+                self.next();
+                let field = match lower.as_str() {
+                    "eid" | "event_id" => NumberField::Eid,
+                    "depth" => NumberField::Depth,
+                    "children" => NumberField::Children,
+                    "indices" | "vertices" => NumberField::Indices,
+                    "instances" => NumberField::Instances,
+                    "triangles" => NumberField::Triangles,
+                    _ => {
+                        return Err(self.error(
+                            offset,
+                            format!(
+                                "unknown numeric field `{word}` (expected eid, depth, children, \
+                                 indices, instances or triangles)"
+                            ),
+                        ));
+                    }
+                };
+                match self.next() {
+                    Some((value_offset, Token::Word(value))) => {
+                        let value = value.replace('_', "").parse().map_err(|_| {
+                            self.error(
+                                value_offset,
+                                format!("expected an integer, found `{value}`"),
+                            )
+                        })?;
+                        Ok(EventQuery::Compare { field, cmp, value })
+                    }
+                    _ => Err(self.error(offset, format!("expected a number after `{word}`"))),
+                }
+            }
+            _ => {
+                let flag = if lower == "draw" { "drawcall" } else { &lower };
+                FLAG_NAMES
+                    .iter()
+                    .find(|f| f.eq_ignore_ascii_case(flag))
+                    .map(|f| EventQuery::Flag {
+                        flag: (*f).to_string(),
+                    })
+                    .ok_or_else(|| self.error(offset, format!("unknown action flag `{word}`")))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_precedence_fields_and_errors() {
+        let q = EventQuery::parse(r#"draw && marker:"Shadow*" && eid>1200 || !clear"#).unwrap();
+        assert_eq!(
+            q,
+            EventQuery::Or {
+                args: vec![
+                    EventQuery::And {
+                        args: vec![
+                            EventQuery::Flag {
+                                flag: "Drawcall".into()
+                            },
+                            EventQuery::Glob {
+                                field: GlobField::Marker,
+                                pattern: "Shadow*".into()
+                            },
+                            EventQuery::Compare {
+                                field: NumberField::Eid,
+                                cmp: CompareOp::Gt,
+                                value: 1200
+                            },
+                        ]
+                    },
+                    EventQuery::Not {
+                        arg: Box::new(EventQuery::Flag {
+                            flag: "Clear".into()
+                        })
+                    },
+                ]
+            }
+        );
+        assert_eq!(
+            serde_json::to_value(EventQuery::parse("triangles >= 10_000").unwrap()).unwrap(),
+            serde_json::json!({"op": "compare", "field": "triangles", "cmp": ">=", "value": 10000})
+        );
+
+        let err = EventQuery::parse("draw && (copy || colour)").unwrap_err();
+        assert_eq!(err.offset, 17);
+        assert!(EventQuery::parse("draw &&").is_err());
+        assert!(EventQuery::parse("eid > lots").is_err());
+        assert!(EventQuery::parse("name:\"unterminated").is_err());
+    }
+}
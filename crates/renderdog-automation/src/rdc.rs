@@ -0,0 +1,437 @@
+//! Minimal `.rdc` container parser.
+//!
+//! Reads the file header, the embedded JPEG thumbnail, driver metadata and the section table of a
+//! capture without loading any RenderDoc library, so it works on machines without RenderDoc.
+//!
+//! Layout (little endian):
+//! - header: `u64` magic (`"RDOC"`), `u32` container version, `u32` header length,
+//!   `[u8; 16]` program version,
+//! - thumbnail: `u16` width, `u16` height, `u32` length, JPEG bytes,
+//! - metadata: `u32` driver id, `u8` name length, driver name, then (container 1.1+) `u64` machine
+//!   ident and (1.2+) `u64` time base + `f64` time frequency,
+//! - sections (starting at the header length): `u8` ASCII marker, 3 zero bytes, `u32` type,
+//!   `u64` stored length, `u64` uncompressed length, `u64` section version, `u32` flags, `u32` name
+//!   length, name, then the stored section bytes.
+//!
+//! A capture holds a single frame: capturing several frames writes one `.rdc` per frame, named
+//! `<capture file template>_frame<N>.rdc`. [`capture_frames`] enumerates those siblings.
+
+use std::{
+    fs::File,
+    io::{self, BufReader, Read, Seek, SeekFrom},
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+const MAGIC: u64 = u32::from_le_bytes(*b"RDOC") as u64;
+const FILE_HEADER_LEN: u64 = 32;
+const MIN_CONTAINER_VERSION: u32 = 0x100;
+const MAX_CONTAINER_VERSION: u32 = 0x1ff;
+
+pub const SECTION_FLAG_ASCII_STORED: u32 = 0x1;
+pub const SECTION_FLAG_LZ4_COMPRESSED: u32 = 0x2;
+pub const SECTION_FLAG_ZSTD_COMPRESSED: u32 = 0x4;
+
+#[derive(Debug, Error)]
+pub enum RdcError {
+    #[error("failed to read capture: {0}")]
+    Io(#[from] io::Error),
+    #[error("not a RenderDoc capture (bad magic)")]
+    NotRdc,
+    #[error("unsupported .rdc container version {0:#x}")]
+    UnsupportedVersion(u32),
+    #[error("truncated .rdc file while reading {0}")]
+    Truncated(&'static str),
+    #[error("capture has no embedded thumbnail")]
+    NoThumbnail,
+    #[error("frame index {frame_index} out of range (capture set has {count} frames)")]
+    FrameOutOfRange { frame_index: u32, count: usize },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct RdcThumbnail {
+    pub width: u16,
+    pub height: u16,
+    /// JPEG-encoded image bytes.
+    #[serde(skip)]
+    pub jpeg: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct RdcSection {
+    pub name: String,
+    /// RenderDoc `SectionType` (`1` = frame capture, `4` = notes, `7` = extended thumbnail, ...).
+    pub section_type: u32,
+    pub section_version: u64,
+    /// `SECTION_FLAG_*` bits.
+    pub flags: u32,
+    /// Byte offset of the section data in the file.
+    pub offset: u64,
+    pub stored_length: u64,
+    pub uncompressed_length: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct CaptureMetadata {
+    /// Container version, e.g. `0x102`.
+    pub container_version: u32,
+    /// RenderDoc version that wrote the capture, e.g. `v1.35`.
+    pub program_version: String,
+    /// RenderDoc `RDCDriver` id.
+    pub driver_id: u32,
+    pub driver_name: String,
+    pub machine_ident: Option<u64>,
+    pub thumbnail: Option<RdcThumbnail>,
+    pub sections: Vec<RdcSection>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct CaptureFrame {
+    /// Position in the capture set, ordered by frame number.
+    pub frame_index: u32,
+    /// Application frame number, from the `_frame<N>` file name suffix.
+    pub frame_number: Option<u32>,
+    pub capture_path: String,
+}
+
+impl CaptureMetadata {
+    pub fn section(&self, name: &str) -> Option<&RdcSection> {
+        self.sections.iter().find(|s| s.name == name)
+    }
+}
+
+/// Parse the header, thumbnail, driver metadata and section table of an `.rdc` file.
+pub fn capture_metadata(path: &Path) -> Result<CaptureMetadata, RdcError> {
+    let mut file = BufReader::new(File::open(path)?);
+    read_capture_metadata(&mut file)
+}
+
+/// Write the embedded JPEG thumbnail of `capture_path` to `output_path`.
+pub fn extract_thumbnail(
+    capture_path: &Path,
+    output_path: &Path,
+) -> Result<RdcThumbnail, RdcError> {
+    let thumbnail = capture_metadata(capture_path)?
+        .thumbnail
+        .ok_or(RdcError::NoThumbnail)?;
+    std::fs::write(output_path, &thumbnail.jpeg)?;
+    Ok(thumbnail)
+}
+
+/// Whether `path` has a `.jpg` / `.jpeg` extension (the embedded thumbnail format).
+pub fn is_jpeg_path(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| e.eq_ignore_ascii_case("jpg") || e.eq_ignore_ascii_case("jpeg"))
+}
+
+/// Every capture of the same capture session as `capture_path`, ordered by frame number.
+///
+/// Captures share the session's capture file template and differ only in their `_frame<N>`
+/// suffix. A capture not named that way is returned on its own as frame 0.
+pub fn capture_frames(capture_path: &Path) -> Result<Vec<CaptureFrame>, RdcError> {
+    std::fs::metadata(capture_path)?;
+    let stem = capture_path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or_default();
+    let Some((template, _)) = split_frame_suffix(stem) else {
+        return Ok(vec![CaptureFrame {
+            frame_index: 0,
+            frame_number: None,
+            capture_path: capture_path.display().to_string(),
+        }]);
+    };
+
+    let dir = capture_path
+        .parent()
+        .filter(|p| !p.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    let mut frames = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = capture_path.with_file_name(entry?.file_name());
+        if !path
+            .extension()
+            .is_some_and(|e| e.eq_ignore_ascii_case("rdc"))
+        {
+            continue;
+        }
+        let sibling = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .and_then(split_frame_suffix);
+        if let Some((t, frame_number)) = sibling
+            && t == template
+        {
+            frames.push((frame_number, path));
+        }
+    }
+    frames.sort();
+
+    Ok(frames
+        .into_iter()
+        .enumerate()
+        .map(|(i, (frame_number, path))| CaptureFrame {
+            frame_index: i as u32,
+            frame_number: Some(frame_number),
+            capture_path: path.display().to_string(),
+        })
+        .collect())
+}
+
+/// The capture holding frame `frame_index` of `capture_path`'s capture set (see [`capture_frames`]).
+pub fn capture_frame_path(capture_path: &Path, frame_index: u32) -> Result<PathBuf, RdcError> {
+    let frames = capture_frames(capture_path)?;
+    let count = frames.len();
+    frames
+        .into_iter()
+        .nth(frame_index as usize)
+        .map(|f| PathBuf::from(f.capture_path))
+        .ok_or(RdcError::FrameOutOfRange { frame_index, count })
+}
+
+/// Split `<template>_frame<N>` into the template and `N`.
+fn split_frame_suffix(stem: &str) -> Option<(&str, u32)> {
+    let (template, number) = stem.rsplit_once("_frame")?;
+    if number.is_empty() || !number.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    Some((template, number.parse().ok()?))
+}
+
+pub fn read_capture_metadata(r: &mut (impl Read + Seek)) -> Result<CaptureMetadata, RdcError> {
+    let magic = read_u64(r, "header")?;
+    if magic != MAGIC {
+        return Err(RdcError::NotRdc);
+    }
+    let container_version = read_u32(r, "header")?;
+    if !(MIN_CONTAINER_VERSION..=MAX_CONTAINER_VERSION).contains(&container_version) {
+        return Err(RdcError::UnsupportedVersion(container_version));
+    }
+    let header_len = u64::from(read_u32(r, "header")?);
+    let program_version = c_string(&read_vec(r, 16, "header")?);
+
+    let mut remaining = header_len.saturating_sub(FILE_HEADER_LEN);
+    let mut take = |n: u64, what: &'static str| {
+        remaining = remaining.checked_sub(n).ok_or(RdcError::Truncated(what))?;
+        Ok::<_, RdcError>(())
+    };
+
+    take(8, "thumbnail")?;
+    let width = read_u16(r, "thumbnail")?;
+    let height = read_u16(r, "thumbnail")?;
+    let jpeg_len = read_u32(r, "thumbnail")?;
+    take(u64::from(jpeg_len), "thumbnail")?;
+    let jpeg = read_vec(r, jpeg_len as usize, "thumbnail")?;
+    let thumbnail = (jpeg_len > 0).then_some(RdcThumbnail {
+        width,
+        height,
+        jpeg,
+    });
+
+    take(5, "metadata")?;
+    let driver_id = read_u32(r, "metadata")?;
+    let name_len = read_vec(r, 1, "metadata")?[0];
+    take(u64::from(name_len), "metadata")?;
+    let mut driver_name = c_string(&read_vec(r, name_len.into(), "metadata")?);
+    if driver_name.is_empty() {
+        driver_name = driver_name_for_id(driver_id).to_string();
+    }
+    let machine_ident = if container_version >= 0x101 && remaining >= 8 {
+        Some(read_u64(r, "metadata")?)
+    } else {
+        None
+    };
+
+    let mut sections = Vec::new();
+    let file_len = r.seek(SeekFrom::End(0))?;
+    let mut pos = r.seek(SeekFrom::Start(header_len))?;
+    while pos < file_len {
+        let Some(section) = read_section_header(r, pos)? else {
+            break;
+        };
+        pos = section.offset.saturating_add(section.stored_length);
+        if pos > file_len {
+            return Err(RdcError::Truncated("section data"));
+        }
+        sections.push(section);
+        r.seek(SeekFrom::Start(pos))?;
+    }
+
+    Ok(CaptureMetadata {
+        container_version,
+        program_version,
+        driver_id,
+        driver_name,
+        machine_ident,
+        thumbnail,
+        sections,
+    })
+}
+
+/// Read a binary section header at `start`; `None` for ASCII-stored sections (not listed).
+fn read_section_header(
+    r: &mut (impl Read + Seek),
+    start: u64,
+) -> Result<Option<RdcSection>, RdcError> {
+    let lead = read_vec(r, 4, "section header")?;
+    if lead[0] != 0 {
+        return Ok(None);
+    }
+    let section_type = read_u32(r, "section header")?;
+    let stored_length = read_u64(r, "section header")?;
+    let uncompressed_length = read_u64(r, "section header")?;
+    let section_version = read_u64(r, "section header")?;
+    let flags = read_u32(r, "section header")?;
+    let name_len = read_u32(r, "section header")?;
+    let name = c_string(&read_vec(r, name_len as usize, "section name")?);
+    let offset = start + 4 + 4 + 8 + 8 + 8 + 4 + 4 + u64::from(name_len);
+
+    Ok(Some(RdcSection {
+        name,
+        section_type,
+        section_version,
+        flags,
+        offset,
+        stored_length,
+        uncompressed_length,
+    }))
+}
+
+/// Name of a RenderDoc `RDCDriver` id.
+pub fn driver_name_for_id(id: u32) -> &'static str {
+    match id {
+        1 => "D3D11",
+        2 => "OpenGL",
+        3 => "Mantle",
+        4 => "D3D12",
+        5 => "D3D10",
+        6 => "D3D9",
+        7 => "Image",
+        8 => "Vulkan",
+        9 => "OpenGLES",
+        10 => "D3D8",
+        11 => "Metal",
+        _ => "Unknown",
+    }
+}
+
+fn c_string(bytes: &[u8]) -> String {
+    let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..end]).into_owned()
+}
+
+fn read_vec(r: &mut impl Read, len: usize, what: &'static str) -> Result<Vec<u8>, RdcError> {
+    let mut buf = Vec::new();
+    r.take(len as u64).read_to_end(&mut buf)?;
+    if buf.len() != len {
+        return Err(RdcError::Truncated(what));
+    }
+    Ok(buf)
+}
+
+fn read_u16(r: &mut impl Read, what: &'static str) -> Result<u16, RdcError> {
+    Ok(u16::from_le_bytes(
+        read_vec(r, 2, what)?.try_into().unwrap(),
+    ))
+}
+
+fn read_u32(r: &mut impl Read, what: &'static str) -> Result<u32, RdcError> {
+    Ok(u32::from_le_bytes(
+        read_vec(r, 4, what)?.try_into().unwrap(),
+    ))
+}
+
+fn read_u64(r: &mut impl Read, what: &'static str) -> Result<u64, RdcError> {
+    Ok(u64::from_le_bytes(
+        read_vec(r, 8, what)?.try_into().unwrap(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn parses_header_thumbnail_and_sections() {
+        let jpeg = [0xff, 0xd8, 0xff, 0xd9];
+        let driver = b"Vulkan\0";
+
+        let mut meta = Vec::new();
+        meta.extend_from_slice(&4u16.to_le_bytes());
+        meta.extend_from_slice(&2u16.to_le_bytes());
+        meta.extend_from_slice(&(jpeg.len() as u32).to_le_bytes());
+        meta.extend_from_slice(&jpeg);
+        meta.extend_from_slice(&8u32.to_le_bytes());
+        meta.push(driver.len() as u8);
+        meta.extend_from_slice(driver);
+        meta.extend_from_slice(&0xabcdu64.to_le_bytes());
+
+        let mut file = Vec::new();
+        file.extend_from_slice(&MAGIC.to_le_bytes());
+        file.extend_from_slice(&0x102u32.to_le_bytes());
+        file.extend_from_slice(&((FILE_HEADER_LEN as usize + meta.len()) as u32).to_le_bytes());
+        file.extend_from_slice(b"v1.35\0\0\0\0\0\0\0\0\0\0\0");
+        file.extend_from_slice(&meta);
+
+        let name = b"renderdoc/internal/framecapture";
+        file.extend_from_slice(&[0, 0, 0, 0]);
+        file.extend_from_slice(&1u32.to_le_bytes());
+        file.extend_from_slice(&3u64.to_le_bytes());
+        file.extend_from_slice(&10u64.to_le_bytes());
+        file.extend_from_slice(&0x11u64.to_le_bytes());
+        file.extend_from_slice(&SECTION_FLAG_ZSTD_COMPRESSED.to_le_bytes());
+        file.extend_from_slice(&(name.len() as u32).to_le_bytes());
+        file.extend_from_slice(name);
+        file.extend_from_slice(&[1, 2, 3]);
+
+        let meta = read_capture_metadata(&mut Cursor::new(file)).unwrap();
+        assert_eq!(meta.program_version, "v1.35");
+        assert_eq!(meta.driver_name, "Vulkan");
+        assert_eq!(meta.machine_ident, Some(0xabcd));
+        let thumb = meta.thumbnail.as_ref().unwrap();
+        assert_eq!((thumb.width, thumb.height), (4, 2));
+        assert_eq!(thumb.jpeg, jpeg);
+        let section = meta.section("renderdoc/internal/framecapture").unwrap();
+        assert_eq!(section.section_type, 1);
+        assert_eq!(section.uncompressed_length, 10);
+
+        assert!(matches!(
+            read_capture_metadata(&mut Cursor::new(b"not a capture".to_vec())),
+            Err(RdcError::NotRdc | RdcError::Truncated(_))
+        ));
+    }
+
+    #[test]
+    fn enumerates_frames_of_a_capture_set() {
+        let dir = std::env::temp_dir().join(format!("renderdog-frames-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        for name in [
+            "game_2024.05.01_10.00_frame120.rdc",
+            "game_2024.05.01_10.00_frame9.rdc",
+            "game_2024.05.01_10.00_frame121.rdc",
+            "game_2024.05.01_11.30_frame5.rdc",
+            "game_2024.05.01_10.00_frame7.png",
+        ] {
+            std::fs::write(dir.join(name), b"").unwrap();
+        }
+
+        let frames = capture_frames(&dir.join("game_2024.05.01_10.00_frame120.rdc")).unwrap();
+        let numbers: Vec<_> = frames.iter().map(|f| f.frame_number).collect();
+        assert_eq!(numbers, [Some(9), Some(120), Some(121)]);
+        assert_eq!(frames[2].frame_index, 2);
+
+        let path = capture_frame_path(&dir.join("game_2024.05.01_10.00_frame9.rdc"), 1).unwrap();
+        assert_eq!(path, dir.join("game_2024.05.01_10.00_frame120.rdc"));
+        assert!(matches!(
+            capture_frame_path(&dir.join("game_2024.05.01_11.30_frame5.rdc"), 1),
+            Err(RdcError::FrameOutOfRange { count: 1, .. })
+        ));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
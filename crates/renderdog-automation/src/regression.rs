@@ -0,0 +1,578 @@
+use std::path::{Path, PathBuf};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::resolve_path_string_from_cwd;
+use crate::scripting::{QRenderDocJsonEnvelope, create_qrenderdoc_run_dir};
+use crate::{
+    QRenderDocPythonRequest, RenderDocInstallation, ReplayOptions, ReplaySaveOutputsPngError,
+    ReplaySaveOutputsPngRequest, ReplaySession, default_scripts_dir, write_script_file,
+};
+
+/// File name of the manifest written into a baseline directory.
+pub const BASELINE_MANIFEST_FILE: &str = "baseline.json";
+
+const BASELINE_MANIFEST_VERSION: u32 = 1;
+
+/// Describes the golden images stored in a baseline directory.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BaselineManifest {
+    pub version: u32,
+    /// Capture the baseline was generated from (informational).
+    pub capture_path: String,
+    pub include_depth: bool,
+    pub entries: Vec<BaselineEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BaselineEntry {
+    pub event_id: u32,
+    /// `"rt0"`, `"rt1"`, ... or `"depth"`.
+    pub target: String,
+    /// PNG file name, relative to the baseline directory.
+    pub file: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct UpdateBaselineRequest {
+    pub capture_path: String,
+    /// Events whose outputs become golden images; empty selects the last drawcall.
+    #[serde(default)]
+    pub event_ids: Vec<u32>,
+    pub baseline_dir: String,
+    #[serde(default)]
+    pub include_depth: bool,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct UpdateBaselineResponse {
+    pub baseline_dir: String,
+    pub manifest_path: String,
+    pub manifest: BaselineManifest,
+}
+
+/// Pass/fail thresholds for [`RenderDocInstallation::verify_against_baseline`].
+///
+/// Channel values are normalized to `[0, 1]`. The defaults require an exact match.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct RegressionTolerances {
+    /// Per-channel difference a texel may have without counting as different.
+    #[serde(default)]
+    pub max_delta: f64,
+    /// Number of differing texels allowed per image.
+    #[serde(default)]
+    pub max_diff_pixels: u64,
+    #[serde(default)]
+    pub min_psnr: Option<f64>,
+    #[serde(default)]
+    pub min_ssim: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct VerifyBaselineRequest {
+    pub capture_path: String,
+    pub baseline_dir: String,
+    #[serde(default)]
+    pub tolerances: RegressionTolerances,
+    /// Where replayed images, diff images and `report.json` go; defaults to
+    /// `<baseline_dir>/report`.
+    #[serde(default)]
+    pub report_dir: Option<String>,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RegressionResult {
+    pub event_id: u32,
+    pub target: String,
+    pub golden_path: String,
+    /// `None` when the replay no longer produces this target.
+    pub actual_path: Option<String>,
+    /// Written only for failing images.
+    pub diff_path: Option<String>,
+    pub passed: bool,
+    pub diff_pixels: u64,
+    pub max_delta: f64,
+    pub psnr: Option<f64>,
+    pub ssim: f64,
+    /// Human-readable reasons for failure; empty when `passed`.
+    pub failures: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RegressionReport {
+    pub passed: bool,
+    pub capture_path: String,
+    pub baseline_dir: String,
+    pub report_path: String,
+    pub tolerances: RegressionTolerances,
+    pub results: Vec<RegressionResult>,
+}
+
+#[derive(Debug, Error)]
+pub enum RegressionError {
+    #[error("failed to create directory: {0}")]
+    CreateDir(std::io::Error),
+    #[error("failed to read baseline manifest {path}: {source}")]
+    ReadManifest {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("failed to write {path}: {source}")]
+    Write {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("unsupported baseline manifest version {0}")]
+    ManifestVersion(u32),
+    #[error("baseline manifest has no entries")]
+    EmptyBaseline,
+    #[error("replay save outputs failed: {0}")]
+    SaveOutputs(Box<ReplaySaveOutputsPngError>),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+    #[error("compare script returned {got} results for {expected} image pairs")]
+    CompareCount { expected: usize, got: usize },
+}
+
+impl From<crate::QRenderDocPythonError> for RegressionError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+impl From<ReplaySaveOutputsPngError> for RegressionError {
+    fn from(value: ReplaySaveOutputsPngError) -> Self {
+        Self::SaveOutputs(Box::new(value))
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct ComparePair {
+    golden: String,
+    actual: String,
+    diff_output_path: String,
+}
+
+#[derive(Debug, Serialize)]
+struct CompareRequest<'a> {
+    pairs: &'a [ComparePair],
+    tolerances: &'a RegressionTolerances,
+}
+
+#[derive(Debug, Deserialize)]
+struct CompareResult {
+    diff_pixels: u64,
+    max_delta: f64,
+    psnr: Option<f64>,
+    ssim: f64,
+    diff_output_path: Option<String>,
+    failures: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CompareResponse {
+    results: Vec<CompareResult>,
+}
+
+fn remove_if_exists(path: &Path) -> Result<(), std::io::Error> {
+    match std::fs::remove_file(path) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+fn output_target(kind: &str, index: Option<u32>) -> String {
+    match (kind, index) {
+        ("depth", _) => "depth".to_string(),
+        (_, Some(i)) => format!("rt{i}"),
+        (kind, None) => kind.to_string(),
+    }
+}
+
+/// Replace the entries of every event in `replayed` with `updated`, keeping the other events.
+fn merge_baseline_entries(
+    existing: Vec<BaselineEntry>,
+    replayed: &[u32],
+    updated: Vec<BaselineEntry>,
+) -> Vec<BaselineEntry> {
+    let mut entries: Vec<BaselineEntry> = existing
+        .into_iter()
+        .filter(|e| !replayed.contains(&e.event_id))
+        .chain(updated)
+        .collect();
+    entries.sort_by(|a, b| (a.event_id, &a.target).cmp(&(b.event_id, &b.target)));
+    entries
+}
+
+fn write_json<T: Serialize>(path: &Path, value: &T) -> Result<(), RegressionError> {
+    let bytes = serde_json::to_vec_pretty(value).map_err(RegressionError::ParseJson)?;
+    std::fs::write(path, bytes).map_err(|source| RegressionError::Write {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Reads `baseline.json` from a baseline directory.
+pub fn read_baseline_manifest(baseline_dir: &Path) -> Result<BaselineManifest, RegressionError> {
+    let path = baseline_dir.join(BASELINE_MANIFEST_FILE);
+    let bytes = std::fs::read(&path).map_err(|source| RegressionError::ReadManifest {
+        path: path.clone(),
+        source,
+    })?;
+    let manifest: BaselineManifest =
+        serde_json::from_slice(&bytes).map_err(RegressionError::ParseJson)?;
+    if manifest.version != BASELINE_MANIFEST_VERSION {
+        return Err(RegressionError::ManifestVersion(manifest.version));
+    }
+    Ok(manifest)
+}
+
+impl RenderDocInstallation {
+    /// Replay `events` and store their outputs as golden PNGs plus a `baseline.json` manifest.
+    ///
+    /// An existing manifest is updated in place: entries for the replayed events are replaced
+    /// (overwriting their golden images) and entries for other events are kept.
+    pub fn update_baseline(
+        &self,
+        cwd: &Path,
+        req: &UpdateBaselineRequest,
+    ) -> Result<UpdateBaselineResponse, RegressionError> {
+        self.update_baseline_in(None, cwd, req)
+    }
+
+    pub(crate) fn update_baseline_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &UpdateBaselineRequest,
+    ) -> Result<UpdateBaselineResponse, RegressionError> {
+        let capture_path = resolve_path_string_from_cwd(cwd, &req.capture_path);
+        let baseline_dir = PathBuf::from(resolve_path_string_from_cwd(cwd, &req.baseline_dir));
+        std::fs::create_dir_all(&baseline_dir).map_err(RegressionError::CreateDir)?;
+
+        let events: Vec<Option<u32>> = if req.event_ids.is_empty() {
+            vec![None]
+        } else {
+            req.event_ids.iter().copied().map(Some).collect()
+        };
+
+        let existing = match read_baseline_manifest(&baseline_dir) {
+            Ok(manifest) => Some(manifest),
+            Err(RegressionError::ReadManifest { source, .. })
+                if source.kind() == std::io::ErrorKind::NotFound =>
+            {
+                None
+            }
+            Err(e) => return Err(e),
+        };
+
+        let mut entries = Vec::new();
+        let mut replayed = Vec::new();
+        for event_id in events {
+            let res = self.replay_save_outputs_png_in(
+                session,
+                cwd,
+                &ReplaySaveOutputsPngRequest {
+                    capture_path: capture_path.clone(),
+                    event_id,
+                    output_dir: baseline_dir.display().to_string(),
+                    basename: "golden".to_string(),
+                    include_depth: req.include_depth,
+                    format: None,
+                    replay_options: req.replay_options.clone(),
+                },
+            )?;
+            replayed.push(res.event_id);
+            for output in res.outputs {
+                let file = Path::new(&output.output_path)
+                    .file_name()
+                    .map(|f| f.to_string_lossy().into_owned())
+                    .unwrap_or(output.output_path.clone());
+                entries.push(BaselineEntry {
+                    event_id: res.event_id,
+                    target: output_target(&output.kind, output.index),
+                    file,
+                });
+            }
+        }
+
+        let (include_depth, entries) = match existing {
+            Some(existing) => (
+                existing.include_depth || req.include_depth,
+                merge_baseline_entries(existing.entries, &replayed, entries),
+            ),
+            None => (req.include_depth, entries),
+        };
+        let manifest = BaselineManifest {
+            version: BASELINE_MANIFEST_VERSION,
+            capture_path,
+            include_depth,
+            entries,
+        };
+        let manifest_path = baseline_dir.join(BASELINE_MANIFEST_FILE);
+        write_json(&manifest_path, &manifest)?;
+
+        Ok(UpdateBaselineResponse {
+            baseline_dir: baseline_dir.display().to_string(),
+            manifest_path: manifest_path.display().to_string(),
+            manifest,
+        })
+    }
+
+    /// Replay the baseline's events from `capture_path`, compare against the golden PNGs and
+    /// write a `report.json` (plus diff images for failures) into the report directory.
+    ///
+    /// A failing comparison is not an error: check [`RegressionReport::passed`].
+    pub fn verify_against_baseline(
+        &self,
+        cwd: &Path,
+        req: &VerifyBaselineRequest,
+    ) -> Result<RegressionReport, RegressionError> {
+        self.verify_against_baseline_in(None, cwd, req)
+    }
+
+    pub(crate) fn verify_against_baseline_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &VerifyBaselineRequest,
+    ) -> Result<RegressionReport, RegressionError> {
+        let capture_path = resolve_path_string_from_cwd(cwd, &req.capture_path);
+        let baseline_dir = PathBuf::from(resolve_path_string_from_cwd(cwd, &req.baseline_dir));
+        let manifest = read_baseline_manifest(&baseline_dir)?;
+        if manifest.entries.is_empty() {
+            return Err(RegressionError::EmptyBaseline);
+        }
+
+        let report_dir = req
+            .report_dir
+            .as_deref()
+            .map(|p| PathBuf::from(resolve_path_string_from_cwd(cwd, p)))
+            .unwrap_or_else(|| baseline_dir.join("report"));
+        let actual_dir = report_dir.join("actual");
+        let diff_dir = report_dir.join("diff");
+        std::fs::create_dir_all(&actual_dir).map_err(RegressionError::CreateDir)?;
+
+        let mut event_ids: Vec<u32> = manifest.entries.iter().map(|e| e.event_id).collect();
+        event_ids.sort_unstable();
+        event_ids.dedup();
+
+        let mut actual = std::collections::HashMap::new();
+        for event_id in event_ids {
+            let res = self.replay_save_outputs_png_in(
+                session,
+                cwd,
+                &ReplaySaveOutputsPngRequest {
+                    capture_path: capture_path.clone(),
+                    event_id: Some(event_id),
+                    output_dir: actual_dir.display().to_string(),
+                    basename: "actual".to_string(),
+                    include_depth: manifest.include_depth,
+                    format: None,
+                    replay_options: req.replay_options.clone(),
+                },
+            )?;
+            for output in res.outputs {
+                actual.insert(
+                    (event_id, output_target(&output.kind, output.index)),
+                    output.output_path,
+                );
+            }
+        }
+
+        let mut results = Vec::with_capacity(manifest.entries.len());
+        let mut pairs = Vec::new();
+        let mut pair_slots = Vec::new();
+        for entry in &manifest.entries {
+            let golden_path = baseline_dir.join(&entry.file).display().to_string();
+            let actual_path = actual.get(&(entry.event_id, entry.target.clone())).cloned();
+            if let Some(actual_path) = &actual_path {
+                pair_slots.push(results.len());
+                pairs.push(ComparePair {
+                    golden: golden_path.clone(),
+                    actual: actual_path.clone(),
+                    diff_output_path: diff_dir
+                        .join(format!("diff.event{}.{}.png", entry.event_id, entry.target))
+                        .display()
+                        .to_string(),
+                });
+            }
+            results.push(RegressionResult {
+                event_id: entry.event_id,
+                target: entry.target.clone(),
+                golden_path,
+                passed: actual_path.is_some(),
+                failures: if actual_path.is_some() {
+                    Vec::new()
+                } else {
+                    vec!["target not produced by replay".to_string()]
+                },
+                actual_path,
+                diff_path: None,
+                diff_pixels: 0,
+                max_delta: 0.0,
+                psnr: None,
+                ssim: 0.0,
+            });
+        }
+
+        if !pairs.is_empty() {
+            let compared = self.run_regression_compare(session, cwd, &pairs, &req.tolerances)?;
+            for (slot, res) in pair_slots.into_iter().zip(compared) {
+                let r = &mut results[slot];
+                r.passed = res.failures.is_empty();
+                r.diff_path = res.diff_output_path;
+                r.diff_pixels = res.diff_pixels;
+                r.max_delta = res.max_delta;
+                r.psnr = res.psnr;
+                r.ssim = res.ssim;
+                r.failures = res.failures;
+            }
+        }
+
+        let report_path = report_dir.join("report.json");
+        let report = RegressionReport {
+            passed: results.iter().all(|r| r.passed),
+            capture_path,
+            baseline_dir: baseline_dir.display().to_string(),
+            report_path: report_path.display().to_string(),
+            tolerances: req.tolerances.clone(),
+            results,
+        };
+        write_json(&report_path, &report)?;
+        Ok(report)
+    }
+
+    fn run_regression_compare(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        pairs: &[ComparePair],
+        tolerances: &RegressionTolerances,
+    ) -> Result<Vec<CompareResult>, RegressionError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir).map_err(RegressionError::CreateDir)?;
+
+        let script_path = scripts_dir.join("regression_compare_json.py");
+        write_script_file(&script_path, REGRESSION_COMPARE_JSON_PY)
+            .map_err(RegressionError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "regression_compare")
+            .map_err(RegressionError::CreateDir)?;
+        let request_path = run_dir.join("regression_compare_json.request.json");
+        let response_path = run_dir.join("regression_compare_json.response.json");
+        remove_if_exists(&response_path).map_err(RegressionError::WriteRequest)?;
+
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&CompareRequest { pairs, tolerances })
+                .map_err(RegressionError::ParseJson)?,
+        )
+        .map_err(RegressionError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+
+        let _ = result;
+        let bytes = std::fs::read(&response_path).map_err(RegressionError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<CompareResponse> =
+            serde_json::from_slice(&bytes).map_err(RegressionError::ParseJson)?;
+        if env.ok {
+            let results = env
+                .result
+                .map(|r| r.results)
+                .ok_or_else(|| RegressionError::ScriptError("missing result".into()))?;
+            if results.len() != pairs.len() {
+                return Err(RegressionError::CompareCount {
+                    expected: pairs.len(),
+                    got: results.len(),
+                });
+            }
+            Ok(results)
+        } else {
+            Err(RegressionError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+}
+
+const REGRESSION_COMPARE_JSON_PY: &str = include_str!("../scripts/regression_compare_json.py");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manifest_round_trips_and_checks_version() {
+        let dir = std::env::temp_dir().join(format!("renderdog-baseline-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let manifest = BaselineManifest {
+            version: BASELINE_MANIFEST_VERSION,
+            capture_path: "frame.rdc".into(),
+            include_depth: false,
+            entries: vec![BaselineEntry {
+                event_id: 42,
+                target: output_target("color", Some(1)),
+                file: "golden.event42.rt1.png".into(),
+            }],
+        };
+        write_json(&dir.join(BASELINE_MANIFEST_FILE), &manifest).unwrap();
+        let read = read_baseline_manifest(&dir).unwrap();
+        assert_eq!(read.entries[0].target, "rt1");
+        assert_eq!(read.entries[0].event_id, 42);
+
+        let future = BaselineManifest {
+            version: BASELINE_MANIFEST_VERSION + 1,
+            ..manifest
+        };
+        write_json(&dir.join(BASELINE_MANIFEST_FILE), &future).unwrap();
+        assert!(matches!(
+            read_baseline_manifest(&dir),
+            Err(RegressionError::ManifestVersion(_))
+        ));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn update_merges_entries_of_other_events() {
+        let entry = |event_id: u32, target: &str| BaselineEntry {
+            event_id,
+            target: target.into(),
+            file: format!("golden.event{event_id}.{target}.png"),
+        };
+        let existing = vec![entry(10, "rt0"), entry(20, "rt0"), entry(20, "depth")];
+        let merged =
+            merge_baseline_entries(existing, &[20], vec![entry(20, "rt0"), entry(30, "rt0")]);
+        let keys: Vec<(u32, &str)> = merged
+            .iter()
+            .map(|e| (e.event_id, e.target.as_str()))
+            .collect();
+        assert_eq!(keys, [(10, "rt0"), (20, "rt0"), (30, "rt0")]);
+    }
+}
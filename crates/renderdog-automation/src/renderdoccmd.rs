@@ -1,6 +1,7 @@
 use std::{
     ffi::OsString,
     path::{Path, PathBuf},
+    str::FromStr,
 };
 
 use thiserror::Error;
@@ -37,6 +38,112 @@ impl From<CommandError> for CaptureLaunchError {
     }
 }
 
+/// File formats understood by `renderdoccmd convert`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaptureFileFormat {
+    /// Native `.rdc` capture.
+    Rdc,
+    /// Structured data as XML, with buffer contents in an accompanying zip.
+    ZipXml,
+    /// Structured data as XML only (buffer contents dropped, so not convertible back).
+    Xml,
+    /// Chrome trace of the API calls (export only).
+    ChromeJson,
+}
+
+impl CaptureFileFormat {
+    /// Name passed to `renderdoccmd convert -i/-c`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Rdc => "rdc",
+            Self::ZipXml => "zip.xml",
+            Self::Xml => "xml",
+            Self::ChromeJson => "chrome.json",
+        }
+    }
+
+    /// Infer the format from a file name: `.rdc`, `.zip.xml` / `.zip`, `.xml`, `.json`.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let name = path.file_name()?.to_string_lossy().to_ascii_lowercase();
+        if name.ends_with(".rdc") {
+            Some(Self::Rdc)
+        } else if name.ends_with(".zip.xml") || name.ends_with(".zip") {
+            Some(Self::ZipXml)
+        } else if name.ends_with(".xml") {
+            Some(Self::Xml)
+        } else if name.ends_with(".json") {
+            Some(Self::ChromeJson)
+        } else {
+            None
+        }
+    }
+}
+
+impl FromStr for CaptureFileFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "rdc" => Ok(Self::Rdc),
+            "zip.xml" | "zip" => Ok(Self::ZipXml),
+            "xml" => Ok(Self::Xml),
+            "chrome.json" | "chrome" | "json" => Ok(Self::ChromeJson),
+            other => Err(format!(
+                "unknown capture format `{other}` (expected rdc, zip.xml, xml or chrome.json)"
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CaptureConvertRequest {
+    pub input_path: PathBuf,
+    pub output_path: PathBuf,
+    /// Inferred from `input_path` when unset.
+    pub input_format: Option<CaptureFileFormat>,
+    /// Inferred from `output_path` when unset.
+    pub output_format: Option<CaptureFileFormat>,
+    /// Replace an existing `output_path`; otherwise conversion fails with
+    /// [`CaptureConvertError::OutputExists`].
+    pub overwrite: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct CaptureConvertResult {
+    pub output_path: PathBuf,
+    pub input_format: CaptureFileFormat,
+    pub output_format: CaptureFileFormat,
+    pub output_bytes: u64,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+#[derive(Debug, Error)]
+pub enum CaptureConvertError {
+    #[error(transparent)]
+    Command(Box<CommandError>),
+    #[error("cannot infer capture format from `{0}`; set it explicitly")]
+    UnknownFormat(PathBuf),
+    #[error("failed to create output dir: {0}")]
+    CreateOutputDir(std::io::Error),
+    #[error("output `{0}` already exists; set `overwrite` to replace it")]
+    OutputExists(PathBuf),
+    #[error("failed to remove existing output: {0}")]
+    RemoveOutput(std::io::Error),
+    #[error("renderdoccmd convert did not write `{path}`\nstdout:\n{stdout}\nstderr:\n{stderr}")]
+    MissingOutput {
+        path: PathBuf,
+        stdout: String,
+        stderr: String,
+    },
+}
+
+impl From<CommandError> for CaptureConvertError {
+    fn from(value: CommandError) -> Self {
+        Self::Command(Box::new(value))
+    }
+}
+
 impl RenderDocInstallation {
     pub fn launch_capture(
         &self,
@@ -80,11 +187,77 @@ impl RenderDocInstallation {
         Ok(output.stdout)
     }
 
+    /// Convert a capture between `.rdc` and the XML / zip interchange formats with
+    /// `renderdoccmd convert`, e.g. for archival or diffing the structured data externally.
+    pub fn convert_capture(
+        &self,
+        req: &CaptureConvertRequest,
+    ) -> Result<CaptureConvertResult, CaptureConvertError> {
+        let input_format = req
+            .input_format
+            .or_else(|| CaptureFileFormat::from_path(&req.input_path))
+            .ok_or_else(|| CaptureConvertError::UnknownFormat(req.input_path.clone()))?;
+        let output_format = req
+            .output_format
+            .or_else(|| CaptureFileFormat::from_path(&req.output_path))
+            .ok_or_else(|| CaptureConvertError::UnknownFormat(req.output_path.clone()))?;
+
+        crate::ensure_parent_dir(&req.output_path).map_err(CaptureConvertError::CreateOutputDir)?;
+        if req.output_path.exists() {
+            if !req.overwrite {
+                return Err(CaptureConvertError::OutputExists(req.output_path.clone()));
+            }
+            std::fs::remove_file(&req.output_path).map_err(CaptureConvertError::RemoveOutput)?;
+        }
+
+        let spec = CommandSpec::new(&self.renderdoccmd_exe)
+            .arg("convert")
+            .arg("-f")
+            .arg(req.input_path.as_os_str().to_owned())
+            .arg("-o")
+            .arg(req.output_path.as_os_str().to_owned())
+            .arg("-i")
+            .arg(input_format.as_str())
+            .arg("-c")
+            .arg(output_format.as_str());
+
+        let output = run_command_expect_success(&spec)?;
+        let output_bytes = match std::fs::metadata(&req.output_path) {
+            Ok(meta) => meta.len(),
+            Err(_) => {
+                return Err(CaptureConvertError::MissingOutput {
+                    path: req.output_path.clone(),
+                    stdout: output.stdout,
+                    stderr: output.stderr,
+                });
+            }
+        };
+
+        Ok(CaptureConvertResult {
+            output_path: req.output_path.clone(),
+            input_format,
+            output_format,
+            output_bytes,
+            stdout: output.stdout,
+            stderr: output.stderr,
+        })
+    }
+
+    /// Save the capture thumbnail to `output_path`.
+    ///
+    /// `.jpg`/`.jpeg` outputs are extracted directly from the file ([`crate::extract_thumbnail`]);
+    /// other formats are converted by `renderdoccmd thumb`.
     pub fn save_thumbnail(
         &self,
         capture_path: &Path,
         output_path: &Path,
     ) -> Result<(), std::io::Error> {
+        if crate::is_jpeg_path(output_path)
+            && crate::extract_thumbnail(capture_path, output_path).is_ok()
+        {
+            return Ok(());
+        }
+
         let spec = CommandSpec::new(&self.renderdoccmd_exe)
             .arg("thumb")
             .arg("-o")
@@ -98,3 +271,36 @@ impl RenderDocInstallation {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capture_file_format_from_path_and_str() {
+        let cases = [
+            ("frame.rdc", Some(CaptureFileFormat::Rdc)),
+            ("frame.ZIP.XML", Some(CaptureFileFormat::ZipXml)),
+            ("frame.zip", Some(CaptureFileFormat::ZipXml)),
+            ("frame.xml", Some(CaptureFileFormat::Xml)),
+            ("trace.json", Some(CaptureFileFormat::ChromeJson)),
+            ("frame.bin", None),
+        ];
+        for (name, expected) in cases {
+            assert_eq!(
+                CaptureFileFormat::from_path(Path::new(name)),
+                expected,
+                "{name}"
+            );
+        }
+        for format in [
+            CaptureFileFormat::Rdc,
+            CaptureFileFormat::ZipXml,
+            CaptureFileFormat::Xml,
+            CaptureFileFormat::ChromeJson,
+        ] {
+            assert_eq!(format.as_str().parse::<CaptureFileFormat>(), Ok(format));
+        }
+        assert!("dds".parse::<CaptureFileFormat>().is_err());
+    }
+}
@@ -7,13 +7,42 @@ use thiserror::Error;
 use crate::resolve_path_string_from_cwd;
 use crate::scripting::{QRenderDocJsonEnvelope, create_qrenderdoc_run_dir};
 use crate::{
-    QRenderDocPythonRequest, RenderDocInstallation, default_scripts_dir, write_script_file,
+    QRenderDocPythonRequest, RenderDocInstallation, ReplayOptions, ReplaySession,
+    default_scripts_dir, write_script_file,
 };
 
+/// Image file written by the texture / output save workflows.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageFileFormat {
+    #[default]
+    Png,
+    Jpg,
+    /// Floating-point, unclamped.
+    Exr,
+    /// Floating-point (Radiance RGBE), unclamped.
+    Hdr,
+}
+
+impl ImageFileFormat {
+    /// Guess the format from an output path's extension.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()?.to_ascii_lowercase().as_str() {
+            "png" => Some(Self::Png),
+            "jpg" | "jpeg" => Some(Self::Jpg),
+            "exr" => Some(Self::Exr),
+            "hdr" => Some(Self::Hdr),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ReplayListTexturesRequest {
     pub capture_path: String,
     pub event_id: Option<u32>,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -44,6 +73,8 @@ pub struct ReplayPickPixelRequest {
     pub texture_index: u32,
     pub x: u32,
     pub y: u32,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -62,6 +93,60 @@ pub struct ReplaySaveTexturePngRequest {
     pub event_id: Option<u32>,
     pub texture_index: u32,
     pub output_path: String,
+    /// `None` infers it from the `output_path` extension, falling back to PNG.
+    #[serde(default)]
+    pub format: Option<ImageFileFormat>,
+    /// Mip level to save (default 0).
+    #[serde(default)]
+    pub mip: Option<u32>,
+    /// Array slice, cube face (`+X, -X, +Y, -Y, +Z, -Z`) or 3D depth slice (default 0).
+    #[serde(default)]
+    pub slice: Option<u32>,
+    /// Sample of a multisampled texture (default 0).
+    #[serde(default)]
+    pub sample: Option<u32>,
+    /// `"rgba"` (default), `"rgb"` (alpha discarded), or a single channel `"r"`, `"g"`, `"b"`,
+    /// `"a"` saved as greyscale.
+    #[serde(default)]
+    pub channels: Option<String>,
+    /// Value mapped to black (default 0.0). Together with `white_point` this remaps HDR or depth
+    /// ranges into the visible range, e.g. `0.99..1.0` for a conventional depth buffer.
+    #[serde(default)]
+    pub black_point: Option<f32>,
+    /// Value mapped to white (default 1.0).
+    #[serde(default)]
+    pub white_point: Option<f32>,
+    /// Gamma applied after the black / white point remap (`out = in^(1/gamma)`, default 1.0).
+    /// PNG only.
+    #[serde(default)]
+    pub gamma: Option<f32>,
+    /// How array slices, cube faces and 3D depth slices are written: `"single"` (default, just
+    /// `slice`), `"all"` (one file per slice, suffixed `.slice<N>` or `.px` / `.nx` / ... for
+    /// cube faces), `"grid"` (every slice in one atlas) or `"cross"` (cubemap cross layout).
+    #[serde(default)]
+    pub slices: Option<String>,
+    /// Slices per row for `slices: "grid"`; defaults to a square-ish grid.
+    #[serde(default)]
+    pub grid_width: Option<u32>,
+    /// Write every mip level (ignoring `mip`), one file per mip named
+    /// `<stem>.mip<N>_<width>x<height>.<ext>`.
+    #[serde(default)]
+    pub all_mips: bool,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ReplaySavedSubresource {
+    pub mip: u32,
+    /// `None` for grid / cross layouts, which hold every slice.
+    pub slice: Option<u32>,
+    /// File name suffix, e.g. `"slice3"` or `"px"`.
+    pub label: Option<String>,
+    /// Dimensions of one slice at `mip`.
+    pub width: u32,
+    pub height: u32,
+    pub output_path: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -69,7 +154,11 @@ pub struct ReplaySaveTexturePngResponse {
     pub capture_path: String,
     pub event_id: Option<u32>,
     pub texture_index: u32,
+    /// The first file written.
     pub output_path: String,
+    /// Every file written, by mip then slice.
+    #[serde(default)]
+    pub files: Vec<ReplaySavedSubresource>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -79,6 +168,11 @@ pub struct ReplaySaveOutputsPngRequest {
     pub output_dir: String,
     pub basename: String,
     pub include_depth: bool,
+    /// PNG when `None`.
+    #[serde(default)]
+    pub format: Option<ImageFileFormat>,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -96,6 +190,186 @@ pub struct ReplaySaveOutputsPngResponse {
     pub outputs: Vec<ReplaySavedImage>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ReplayExportMeshRequest {
+    pub capture_path: String,
+    /// Defaults to the last drawcall.
+    pub event_id: Option<u32>,
+    /// `"vs"` (post vertex shader) or `"gs"` (post geometry shader).
+    pub stage: String,
+    /// `"obj"` or `"gltf"` (single file with an embedded buffer).
+    pub format: String,
+    pub output_path: String,
+    #[serde(default)]
+    pub instance: u32,
+    #[serde(default)]
+    pub view: u32,
+    /// Divide positions by `w` (clip space -> NDC) before writing.
+    #[serde(default)]
+    pub perspective_divide: bool,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ReplayExportMeshResponse {
+    pub capture_path: String,
+    pub event_id: u32,
+    pub stage: String,
+    pub format: String,
+    pub output_path: String,
+    pub topology: String,
+    pub vertex_count: u32,
+    pub index_count: u32,
+    pub primitive_count: u32,
+    /// Shader outputs in buffer order (position first).
+    pub attributes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ReplaySaveOverdrawPngRequest {
+    pub capture_path: String,
+    /// A single event; when unset, the last drawcall of every pass in the frame.
+    pub event_id: Option<u32>,
+    pub output_dir: String,
+    pub basename: String,
+    /// `"draw"` (quad overdraw of the selected draw) and/or `"pass"` (quad overdraw of the pass
+    /// up to the selected draw). Empty means both.
+    #[serde(default)]
+    pub modes: Vec<String>,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ReplayOverdrawImage {
+    pub event_id: u32,
+    pub mode: String,
+    pub output_path: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ReplaySaveOverdrawPngResponse {
+    pub capture_path: String,
+    pub images: Vec<ReplayOverdrawImage>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ReplayListBuffersRequest {
+    pub capture_path: String,
+    pub event_id: Option<u32>,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ReplayBufferInfo {
+    pub index: u32,
+    pub resource_id: u64,
+    pub name: String,
+    pub length: u64,
+    /// `BufferCategory` flags the buffer was created with (`Vertex`, `Index`, `Constants`,
+    /// `ReadWrite`, `Indirect`).
+    pub creation_flags: Vec<String>,
+    pub gpu_address: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ReplayListBuffersResponse {
+    pub capture_path: String,
+    pub event_id: Option<u32>,
+    pub buffers: Vec<ReplayBufferInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ReplaySaveAllTexturesRequest {
+    pub capture_path: String,
+    pub event_id: Option<u32>,
+    pub output_dir: String,
+    pub basename: String,
+    /// `"png"`, `"jpg"`, `"exr"`, `"hdr"` (mip 0 / slice 0) or `"dds"` (every mip and slice).
+    pub format: String,
+    /// Case-insensitive substring of the texture name.
+    #[serde(default)]
+    pub name_contains: Option<String>,
+    /// Case-insensitive substring of the format name, e.g. `"R16G16B16A16"` or `"D32"`.
+    #[serde(default)]
+    pub format_contains: Option<String>,
+    #[serde(default)]
+    pub min_width: Option<u32>,
+    #[serde(default)]
+    pub min_height: Option<u32>,
+    /// Keep textures created with any of these usages: `ShaderRead`, `ColorTarget`,
+    /// `DepthTarget`, `ShaderReadWrite`, `SwapBuffer`. Empty keeps every texture.
+    #[serde(default)]
+    pub categories: Vec<String>,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ReplaySavedTexture {
+    pub index: u32,
+    pub resource_id: u64,
+    pub name: String,
+    pub width: u32,
+    pub height: u32,
+    pub format: String,
+    pub categories: Vec<String>,
+    pub output_path: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ReplaySkippedTexture {
+    pub index: u32,
+    pub resource_id: u64,
+    pub name: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ReplaySaveAllTexturesResponse {
+    pub capture_path: String,
+    pub event_id: Option<u32>,
+    pub output_dir: String,
+    pub format: String,
+    pub textures: Vec<ReplaySavedTexture>,
+    /// Textures that matched the filters but could not be saved.
+    pub skipped: Vec<ReplaySkippedTexture>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ReplayPixelPick {
+    pub texture_index: u32,
+    pub x: u32,
+    pub y: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ReplayPickPixelsRequest {
+    pub capture_path: String,
+    pub event_id: Option<u32>,
+    pub picks: Vec<ReplayPixelPick>,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ReplayPickedPixel {
+    pub texture_index: u32,
+    pub x: u32,
+    pub y: u32,
+    pub rgba: [f32; 4],
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ReplayPickPixelsResponse {
+    pub capture_path: String,
+    pub event_id: Option<u32>,
+    /// One entry per pick, in request order.
+    pub pixels: Vec<ReplayPickedPixel>,
+}
+
 #[derive(Debug, Error)]
 pub enum ReplayListTexturesError {
     #[error("failed to create scripts dir: {0}")]
@@ -192,6 +466,126 @@ impl From<crate::QRenderDocPythonError> for ReplaySaveOutputsPngError {
     }
 }
 
+#[derive(Debug, Error)]
+pub enum ReplayExportMeshError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for ReplayExportMeshError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ReplaySaveOverdrawPngError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for ReplaySaveOverdrawPngError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ReplayListBuffersError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for ReplayListBuffersError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ReplaySaveAllTexturesError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for ReplaySaveAllTexturesError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ReplayPickPixelsError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for ReplayPickPixelsError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
 fn remove_if_exists(path: &Path) -> Result<(), std::io::Error> {
     match std::fs::remove_file(path) {
         Ok(()) => Ok(()),
@@ -205,6 +599,15 @@ impl RenderDocInstallation {
         &self,
         cwd: &Path,
         req: &ReplayListTexturesRequest,
+    ) -> Result<ReplayListTexturesResponse, ReplayListTexturesError> {
+        self.replay_list_textures_in(None, cwd, req)
+    }
+
+    pub(crate) fn replay_list_textures_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &ReplayListTexturesRequest,
     ) -> Result<ReplayListTexturesResponse, ReplayListTexturesError> {
         let scripts_dir = default_scripts_dir(cwd);
         std::fs::create_dir_all(&scripts_dir).map_err(ReplayListTexturesError::CreateScriptsDir)?;
@@ -229,11 +632,14 @@ impl RenderDocInstallation {
         )
         .map_err(ReplayListTexturesError::WriteRequest)?;
 
-        let result = self.run_qrenderdoc_python(&QRenderDocPythonRequest {
-            script_path: script_path.clone(),
-            args: Vec::new(),
-            working_dir: Some(run_dir.clone()),
-        })?;
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
 
         let _ = result;
         let bytes = std::fs::read(&response_path).map_err(ReplayListTexturesError::ReadResponse)?;
@@ -253,6 +659,15 @@ impl RenderDocInstallation {
         &self,
         cwd: &Path,
         req: &ReplayPickPixelRequest,
+    ) -> Result<ReplayPickPixelResponse, ReplayPickPixelError> {
+        self.replay_pick_pixel_in(None, cwd, req)
+    }
+
+    pub(crate) fn replay_pick_pixel_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &ReplayPickPixelRequest,
     ) -> Result<ReplayPickPixelResponse, ReplayPickPixelError> {
         let scripts_dir = default_scripts_dir(cwd);
         std::fs::create_dir_all(&scripts_dir).map_err(ReplayPickPixelError::CreateScriptsDir)?;
@@ -277,11 +692,14 @@ impl RenderDocInstallation {
         )
         .map_err(ReplayPickPixelError::WriteRequest)?;
 
-        let result = self.run_qrenderdoc_python(&QRenderDocPythonRequest {
-            script_path: script_path.clone(),
-            args: Vec::new(),
-            working_dir: Some(run_dir.clone()),
-        })?;
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
 
         let _ = result;
         let bytes = std::fs::read(&response_path).map_err(ReplayPickPixelError::ReadResponse)?;
@@ -301,6 +719,15 @@ impl RenderDocInstallation {
         &self,
         cwd: &Path,
         req: &ReplaySaveTexturePngRequest,
+    ) -> Result<ReplaySaveTexturePngResponse, ReplaySaveTexturePngError> {
+        self.replay_save_texture_png_in(None, cwd, req)
+    }
+
+    pub(crate) fn replay_save_texture_png_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &ReplaySaveTexturePngRequest,
     ) -> Result<ReplaySaveTexturePngResponse, ReplaySaveTexturePngError> {
         let scripts_dir = default_scripts_dir(cwd);
         std::fs::create_dir_all(&scripts_dir)
@@ -319,6 +746,11 @@ impl RenderDocInstallation {
         let req = ReplaySaveTexturePngRequest {
             capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
             output_path: resolve_path_string_from_cwd(cwd, &req.output_path),
+            format: Some(
+                req.format
+                    .or_else(|| ImageFileFormat::from_path(Path::new(&req.output_path)))
+                    .unwrap_or_default(),
+            ),
             ..req.clone()
         };
         std::fs::write(
@@ -327,11 +759,14 @@ impl RenderDocInstallation {
         )
         .map_err(ReplaySaveTexturePngError::WriteRequest)?;
 
-        let result = self.run_qrenderdoc_python(&QRenderDocPythonRequest {
-            script_path: script_path.clone(),
-            args: Vec::new(),
-            working_dir: Some(run_dir.clone()),
-        })?;
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
 
         let _ = result;
         let bytes =
@@ -352,6 +787,15 @@ impl RenderDocInstallation {
         &self,
         cwd: &Path,
         req: &ReplaySaveOutputsPngRequest,
+    ) -> Result<ReplaySaveOutputsPngResponse, ReplaySaveOutputsPngError> {
+        self.replay_save_outputs_png_in(None, cwd, req)
+    }
+
+    pub(crate) fn replay_save_outputs_png_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &ReplaySaveOutputsPngRequest,
     ) -> Result<ReplaySaveOutputsPngResponse, ReplaySaveOutputsPngError> {
         let scripts_dir = default_scripts_dir(cwd);
         std::fs::create_dir_all(&scripts_dir)
@@ -370,6 +814,7 @@ impl RenderDocInstallation {
         let req = ReplaySaveOutputsPngRequest {
             capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
             output_dir: resolve_path_string_from_cwd(cwd, &req.output_dir),
+            format: Some(req.format.unwrap_or_default()),
             ..req.clone()
         };
         std::fs::write(
@@ -378,11 +823,14 @@ impl RenderDocInstallation {
         )
         .map_err(ReplaySaveOutputsPngError::WriteRequest)?;
 
-        let result = self.run_qrenderdoc_python(&QRenderDocPythonRequest {
-            script_path: script_path.clone(),
-            args: Vec::new(),
-            working_dir: Some(run_dir.clone()),
-        })?;
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
 
         let _ = result;
         let bytes =
@@ -398,6 +846,317 @@ impl RenderDocInstallation {
             ))
         }
     }
+
+    /// Export post-VS / post-GS vertex data of one event to OBJ or glTF.
+    pub fn replay_export_mesh(
+        &self,
+        cwd: &Path,
+        req: &ReplayExportMeshRequest,
+    ) -> Result<ReplayExportMeshResponse, ReplayExportMeshError> {
+        self.replay_export_mesh_in(None, cwd, req)
+    }
+
+    pub(crate) fn replay_export_mesh_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &ReplayExportMeshRequest,
+    ) -> Result<ReplayExportMeshResponse, ReplayExportMeshError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir).map_err(ReplayExportMeshError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("replay_export_mesh_json.py");
+        write_script_file(&script_path, REPLAY_EXPORT_MESH_JSON_PY)
+            .map_err(ReplayExportMeshError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "replay_export_mesh")
+            .map_err(ReplayExportMeshError::CreateScriptsDir)?;
+        let request_path = run_dir.join("replay_export_mesh_json.request.json");
+        let response_path = run_dir.join("replay_export_mesh_json.response.json");
+        remove_if_exists(&response_path).map_err(ReplayExportMeshError::WriteRequest)?;
+
+        let req = ReplayExportMeshRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            output_path: resolve_path_string_from_cwd(cwd, &req.output_path),
+            ..req.clone()
+        };
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(ReplayExportMeshError::ParseJson)?,
+        )
+        .map_err(ReplayExportMeshError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+
+        let _ = result;
+        let bytes = std::fs::read(&response_path).map_err(ReplayExportMeshError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<ReplayExportMeshResponse> =
+            serde_json::from_slice(&bytes).map_err(ReplayExportMeshError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| ReplayExportMeshError::ScriptError("missing result".into()))
+        } else {
+            Err(ReplayExportMeshError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    /// Render RenderDoc's quad overdraw overlays (per draw and per pass) and save them as PNGs.
+    pub fn replay_save_overdraw_png(
+        &self,
+        cwd: &Path,
+        req: &ReplaySaveOverdrawPngRequest,
+    ) -> Result<ReplaySaveOverdrawPngResponse, ReplaySaveOverdrawPngError> {
+        self.replay_save_overdraw_png_in(None, cwd, req)
+    }
+
+    pub(crate) fn replay_save_overdraw_png_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &ReplaySaveOverdrawPngRequest,
+    ) -> Result<ReplaySaveOverdrawPngResponse, ReplaySaveOverdrawPngError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir)
+            .map_err(ReplaySaveOverdrawPngError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("replay_save_overdraw_png_json.py");
+        write_script_file(&script_path, REPLAY_SAVE_OVERDRAW_PNG_JSON_PY)
+            .map_err(ReplaySaveOverdrawPngError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "replay_save_overdraw_png")
+            .map_err(ReplaySaveOverdrawPngError::CreateScriptsDir)?;
+        let request_path = run_dir.join("replay_save_overdraw_png_json.request.json");
+        let response_path = run_dir.join("replay_save_overdraw_png_json.response.json");
+        remove_if_exists(&response_path).map_err(ReplaySaveOverdrawPngError::WriteRequest)?;
+
+        let req = ReplaySaveOverdrawPngRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            output_dir: resolve_path_string_from_cwd(cwd, &req.output_dir),
+            ..req.clone()
+        };
+
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(ReplaySaveOverdrawPngError::ParseJson)?,
+        )
+        .map_err(ReplaySaveOverdrawPngError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+        let _ = result;
+
+        let bytes =
+            std::fs::read(&response_path).map_err(ReplaySaveOverdrawPngError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<ReplaySaveOverdrawPngResponse> =
+            serde_json::from_slice(&bytes).map_err(ReplaySaveOverdrawPngError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| ReplaySaveOverdrawPngError::ScriptError("missing result".into()))
+        } else {
+            Err(ReplaySaveOverdrawPngError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    pub fn replay_list_buffers(
+        &self,
+        cwd: &Path,
+        req: &ReplayListBuffersRequest,
+    ) -> Result<ReplayListBuffersResponse, ReplayListBuffersError> {
+        self.replay_list_buffers_in(None, cwd, req)
+    }
+
+    pub(crate) fn replay_list_buffers_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &ReplayListBuffersRequest,
+    ) -> Result<ReplayListBuffersResponse, ReplayListBuffersError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir).map_err(ReplayListBuffersError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("replay_list_buffers_json.py");
+        write_script_file(&script_path, REPLAY_LIST_BUFFERS_JSON_PY).map_err(ReplayListBuffersError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "replay_list_buffers")
+            .map_err(ReplayListBuffersError::CreateScriptsDir)?;
+        let request_path = run_dir.join("replay_list_buffers_json.request.json");
+        let response_path = run_dir.join("replay_list_buffers_json.response.json");
+        remove_if_exists(&response_path).map_err(ReplayListBuffersError::WriteRequest)?;
+
+        let req = ReplayListBuffersRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            ..req.clone()
+        };
+
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(ReplayListBuffersError::ParseJson)?,
+        )
+        .map_err(ReplayListBuffersError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+        let _ = result;
+
+        let bytes = std::fs::read(&response_path).map_err(ReplayListBuffersError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<ReplayListBuffersResponse> =
+            serde_json::from_slice(&bytes).map_err(ReplayListBuffersError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| ReplayListBuffersError::ScriptError("missing result".into()))
+        } else {
+            Err(ReplayListBuffersError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    /// Save every texture (optionally filtered by name, format, size or usage) to PNG or DDS in one
+    /// replay.
+    pub fn replay_save_all_textures(
+        &self,
+        cwd: &Path,
+        req: &ReplaySaveAllTexturesRequest,
+    ) -> Result<ReplaySaveAllTexturesResponse, ReplaySaveAllTexturesError> {
+        self.replay_save_all_textures_in(None, cwd, req)
+    }
+
+    pub(crate) fn replay_save_all_textures_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &ReplaySaveAllTexturesRequest,
+    ) -> Result<ReplaySaveAllTexturesResponse, ReplaySaveAllTexturesError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir).map_err(ReplaySaveAllTexturesError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("replay_save_all_textures_json.py");
+        write_script_file(&script_path, REPLAY_SAVE_ALL_TEXTURES_JSON_PY).map_err(ReplaySaveAllTexturesError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "replay_save_all_textures")
+            .map_err(ReplaySaveAllTexturesError::CreateScriptsDir)?;
+        let request_path = run_dir.join("replay_save_all_textures_json.request.json");
+        let response_path = run_dir.join("replay_save_all_textures_json.response.json");
+        remove_if_exists(&response_path).map_err(ReplaySaveAllTexturesError::WriteRequest)?;
+
+        let req = ReplaySaveAllTexturesRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            output_dir: resolve_path_string_from_cwd(cwd, &req.output_dir),
+            ..req.clone()
+        };
+
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(ReplaySaveAllTexturesError::ParseJson)?,
+        )
+        .map_err(ReplaySaveAllTexturesError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+        let _ = result;
+
+        let bytes = std::fs::read(&response_path).map_err(ReplaySaveAllTexturesError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<ReplaySaveAllTexturesResponse> =
+            serde_json::from_slice(&bytes).map_err(ReplaySaveAllTexturesError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| ReplaySaveAllTexturesError::ScriptError("missing result".into()))
+        } else {
+            Err(ReplaySaveAllTexturesError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    /// Pick several pixels (possibly from different textures) in a single replay.
+    pub fn replay_pick_pixels(
+        &self,
+        cwd: &Path,
+        req: &ReplayPickPixelsRequest,
+    ) -> Result<ReplayPickPixelsResponse, ReplayPickPixelsError> {
+        self.replay_pick_pixels_in(None, cwd, req)
+    }
+
+    pub(crate) fn replay_pick_pixels_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &ReplayPickPixelsRequest,
+    ) -> Result<ReplayPickPixelsResponse, ReplayPickPixelsError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir).map_err(ReplayPickPixelsError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("replay_pick_pixels_json.py");
+        write_script_file(&script_path, REPLAY_PICK_PIXELS_JSON_PY).map_err(ReplayPickPixelsError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "replay_pick_pixels")
+            .map_err(ReplayPickPixelsError::CreateScriptsDir)?;
+        let request_path = run_dir.join("replay_pick_pixels_json.request.json");
+        let response_path = run_dir.join("replay_pick_pixels_json.response.json");
+        remove_if_exists(&response_path).map_err(ReplayPickPixelsError::WriteRequest)?;
+
+        let req = ReplayPickPixelsRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            ..req.clone()
+        };
+
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(ReplayPickPixelsError::ParseJson)?,
+        )
+        .map_err(ReplayPickPixelsError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+        let _ = result;
+
+        let bytes = std::fs::read(&response_path).map_err(ReplayPickPixelsError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<ReplayPickPixelsResponse> =
+            serde_json::from_slice(&bytes).map_err(ReplayPickPixelsError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| ReplayPickPixelsError::ScriptError("missing result".into()))
+        } else {
+            Err(ReplayPickPixelsError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
 }
 
 const REPLAY_LIST_TEXTURES_JSON_PY: &str = include_str!("../scripts/replay_list_textures_json.py");
@@ -409,3 +1168,15 @@ const REPLAY_SAVE_TEXTURE_PNG_JSON_PY: &str =
 
 const REPLAY_SAVE_OUTPUTS_PNG_JSON_PY: &str =
     include_str!("../scripts/replay_save_outputs_png_json.py");
+
+const REPLAY_EXPORT_MESH_JSON_PY: &str = include_str!("../scripts/replay_export_mesh_json.py");
+
+const REPLAY_SAVE_OVERDRAW_PNG_JSON_PY: &str =
+    include_str!("../scripts/replay_save_overdraw_png_json.py");
+
+const REPLAY_LIST_BUFFERS_JSON_PY: &str = include_str!("../scripts/replay_list_buffers_json.py");
+
+const REPLAY_SAVE_ALL_TEXTURES_JSON_PY: &str =
+    include_str!("../scripts/replay_save_all_textures_json.py");
+
+const REPLAY_PICK_PIXELS_JSON_PY: &str = include_str!("../scripts/replay_pick_pixels_json.py");
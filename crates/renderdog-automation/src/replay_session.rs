@@ -0,0 +1,389 @@
+use std::{
+    path::{Path, PathBuf},
+    process::{Child, Command, Stdio},
+    sync::{Mutex, MutexGuard},
+    thread,
+    time::{Duration, Instant},
+};
+
+use thiserror::Error;
+
+use crate::{
+    QRenderDocPythonRequest, QRenderDocPythonResult, RenderDocInstallation,
+    create_qrenderdoc_run_dir, default_scripts_dir, write_script_file,
+};
+
+const REPLAY_SESSION_WORKER_PY: &str = include_str!("../scripts/replay_session_worker.py");
+
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+const SHUTDOWN_GRACE: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Error)]
+pub enum ReplaySessionError {
+    #[error("failed to create session dir: {0}")]
+    CreateSessionDir(std::io::Error),
+    #[error("failed to write worker script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to spawn `{program}`: {source}")]
+    Spawn {
+        program: String,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("failed to write request: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("failed to read response: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse json: {0}")]
+    ParseJson(#[from] serde_json::Error),
+    #[error("replay session worker exited (status {status:?}){detail}")]
+    WorkerExited { status: Option<i32>, detail: String },
+    #[error("replay session worker did not respond within {0:?}")]
+    Timeout(Duration),
+    #[error("script failed in replay session: {0}")]
+    ScriptError(String),
+}
+
+#[derive(Debug, serde::Serialize)]
+struct WorkerRequest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    script: Option<&'a Path>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    run_dir: Option<&'a Path>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    shutdown: bool,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct WorkerResponse {
+    ok: bool,
+    error: Option<String>,
+    #[serde(default)]
+    stdout: String,
+}
+
+#[derive(Debug)]
+struct SessionWorker {
+    child: Child,
+    next_seq: u64,
+}
+
+/// A long-lived `qrenderdoc --python` worker that keeps replay initialised and captures open.
+///
+/// Queries made through [`RenderDocInstallation::with_replay_session`] run inside this worker
+/// instead of spawning a fresh `qrenderdoc`, so repeated queries against the same `.rdc` skip
+/// process startup and capture loading. Requests and responses are exchanged as
+/// JSON files under [`ReplaySession::session_dir`]; requests are served one at a time.
+///
+/// Call [`ReplaySession::shutdown`] for a clean exit; dropping the session kills the worker.
+#[derive(Debug)]
+pub struct ReplaySession {
+    session_dir: PathBuf,
+    timeout: Duration,
+    worker: Mutex<SessionWorker>,
+}
+
+impl RenderDocInstallation {
+    /// Launch a [`ReplaySession`] worker, writing its files under the default scripts dir of `cwd`.
+    ///
+    /// Waits up to `timeout` for the worker to start; the same timeout applies to each request.
+    pub fn start_replay_session(
+        &self,
+        cwd: &Path,
+        timeout: Duration,
+    ) -> Result<ReplaySession, ReplaySessionError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir).map_err(ReplaySessionError::CreateSessionDir)?;
+
+        let script_path = scripts_dir.join("replay_session_worker.py");
+        write_script_file(&script_path, REPLAY_SESSION_WORKER_PY)
+            .map_err(ReplaySessionError::WriteScript)?;
+
+        let session_dir = create_qrenderdoc_run_dir(&scripts_dir, "replay_session")
+            .map_err(ReplaySessionError::CreateSessionDir)?;
+
+        let child = Command::new(&self.qrenderdoc_exe)
+            .arg("--python")
+            .arg(&script_path)
+            .current_dir(&session_dir)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|source| ReplaySessionError::Spawn {
+                program: self.qrenderdoc_exe.display().to_string(),
+                source,
+            })?;
+
+        let session = ReplaySession {
+            session_dir,
+            timeout,
+            worker: Mutex::new(SessionWorker { child, next_seq: 0 }),
+        };
+
+        let ready_path = session.session_dir.join("ready.json");
+        {
+            let mut worker = session.lock_worker();
+            session.wait_for_file(&mut worker, &ready_path)?;
+        }
+        Ok(session)
+    }
+
+    /// Run workflows inside `session` through the returned handle.
+    ///
+    /// Only calls made through the handle use the session; calls on `self` keep spawning one
+    /// `qrenderdoc` per query.
+    pub fn with_replay_session<'a>(
+        &'a self,
+        session: &'a ReplaySession,
+    ) -> ReplaySessionWorkflows<'a> {
+        ReplaySessionWorkflows {
+            install: self,
+            session,
+        }
+    }
+}
+
+/// The capture query workflows of a [`RenderDocInstallation`], run inside a [`ReplaySession`].
+///
+/// Created with [`RenderDocInstallation::with_replay_session`]. Each method matches the
+/// installation method of the same name.
+#[derive(Debug, Clone, Copy)]
+pub struct ReplaySessionWorkflows<'a> {
+    install: &'a RenderDocInstallation,
+    session: &'a ReplaySession,
+}
+
+impl<'a> ReplaySessionWorkflows<'a> {
+    pub fn installation(&self) -> &'a RenderDocInstallation {
+        self.install
+    }
+
+    pub fn session(&self) -> &'a ReplaySession {
+        self.session
+    }
+}
+
+macro_rules! session_workflows {
+    ($($name:ident => $imp:ident($req:ident) -> Result<$resp:ident, $err:ident>;)*) => {
+        impl ReplaySessionWorkflows<'_> {
+            $(
+                #[doc = concat!(
+                    "[`RenderDocInstallation::",
+                    stringify!($name),
+                    "`] inside the session."
+                )]
+                pub fn $name(
+                    &self,
+                    cwd: &Path,
+                    req: &crate::$req,
+                ) -> Result<crate::$resp, crate::$err> {
+                    self.install.$imp(Some(self.session), cwd, req)
+                }
+            )*
+        }
+    };
+}
+
+session_workflows! {
+    export_actions_jsonl => export_actions_jsonl_in(ExportActionsRequest) -> Result<ExportActionsResponse, ExportActionsError>;
+    find_events => find_events_in(FindEventsRequest) -> Result<FindEventsResponse, FindEventsError>;
+    get_events => get_events_in(GetEventsRequest) -> Result<GetEventsResponse, GetEventsError>;
+    get_marker_tree => get_marker_tree_in(GetMarkerTreeRequest) -> Result<GetMarkerTreeResponse, GetMarkerTreeError>;
+    get_capture_metadata => get_capture_metadata_in(GetCaptureMetadataRequest) -> Result<GetCaptureMetadataResponse, GetCaptureMetadataError>;
+    list_replay_gpus => list_replay_gpus_in(ListReplayGpusRequest) -> Result<ListReplayGpusResponse, ListReplayGpusError>;
+    read_capture_sections => read_capture_sections_in(ReadCaptureSectionsRequest) -> Result<ReadCaptureSectionsResponse, ReadCaptureSectionsError>;
+    validate_capture => validate_capture_in(ValidateCaptureRequest) -> Result<ValidateCaptureResponse, ValidateCaptureError>;
+    get_shader_details => get_shader_details_in(GetShaderDetailsRequest) -> Result<GetShaderDetailsResponse, GetShaderDetailsError>;
+    find_events_by_shader => find_events_by_shader_in(FindEventsByShaderRequest) -> Result<FindEventsByShaderResponse, FindEventsByShaderError>;
+    search_shader_source => search_shader_source_in(SearchShaderSourceRequest) -> Result<SearchShaderSourceResponse, SearchShaderSourceError>;
+    export_shader_sources => export_shader_sources_in(ExportShaderSourcesRequest) -> Result<ExportShaderSourcesResponse, ExportShaderSourcesError>;
+    export_shader_reflection => export_shader_reflection_in(ExportShaderReflectionRequest) -> Result<ExportShaderReflectionResponse, ExportShaderReflectionError>;
+    export_resource_lifetimes => export_resource_lifetimes_in(ExportResourceLifetimesRequest) -> Result<ExportResourceLifetimesResponse, ExportResourceLifetimesError>;
+    get_buffer_details => get_buffer_details_in(GetBufferDetailsRequest) -> Result<GetBufferDetailsResponse, GetBufferDetailsError>;
+    get_texture_details => get_texture_details_in(GetTextureDetailsRequest) -> Result<GetTextureDetailsResponse, GetTextureDetailsError>;
+    get_buffer_changes_delta => get_buffer_changes_delta_in(GetBufferChangesDeltaRequest) -> Result<GetBufferChangesDeltaResponse, GetBufferChangesDeltaError>;
+    get_texture_changes_delta => get_texture_changes_delta_in(GetTextureChangesDeltaRequest) -> Result<GetTextureChangesDeltaResponse, GetTextureChangesDeltaError>;
+    get_pipeline_details => get_pipeline_details_in(GetPipelineDetailsRequest) -> Result<GetPipelineDetailsResponse, GetPipelineDetailsError>;
+    find_events_by_pipeline => find_events_by_pipeline_in(FindEventsByPipelineRequest) -> Result<FindEventsByPipelineResponse, FindEventsByPipelineError>;
+    get_pipeline_binding_changes_delta => get_pipeline_binding_changes_delta_in(GetPipelineBindingChangesDeltaRequest) -> Result<GetPipelineBindingChangesDeltaResponse, GetPipelineBindingChangesDeltaError>;
+    get_event_pipeline_state => get_event_pipeline_state_in(GetEventPipelineStateRequest) -> Result<GetEventPipelineStateResponse, GetEventPipelineStateError>;
+    get_event_api_call => get_event_api_call_in(GetEventApiCallRequest) -> Result<GetEventApiCallResponse, GetEventApiCallError>;
+    get_resource_changed_event_ids => get_resource_changed_event_ids_in(GetResourceChangedEventIdsRequest) -> Result<GetResourceChangedEventIdsResponse, GetResourceChangedEventIdsError>;
+    search_resources => search_resources_in(SearchResourcesRequest) -> Result<SearchResourcesResponse, SearchResourcesError>;
+    find_resource_uses => find_resource_uses_in(FindResourceUsesRequest) -> Result<FindResourceUsesResponse, FindResourceUsesError>;
+    export_bindings_index_jsonl => export_bindings_index_jsonl_in(ExportBindingsIndexRequest) -> Result<ExportBindingsIndexResponse, ExportBindingsIndexError>;
+    export_bundle_jsonl => export_bundle_jsonl_in(ExportBundleRequest) -> Result<ExportBundleResponse, ExportBundleError>;
+    get_vertex_inputs => get_vertex_inputs_in(GetVertexInputsRequest) -> Result<GetVertexInputsResponse, GetVertexInputsError>;
+    get_indices => get_indices_in(GetIndicesRequest) -> Result<GetIndicesResponse, GetIndicesError>;
+    fetch_counters => fetch_counters_in(FetchCountersRequest) -> Result<FetchCountersResponse, FetchCountersError>;
+    get_timestamp_queries => get_timestamp_queries_in(GetTimestampQueriesRequest) -> Result<GetTimestampQueriesResponse, GetTimestampQueriesError>;
+    export_chrome_trace => export_chrome_trace_in(ExportChromeTraceRequest) -> Result<ExportChromeTraceResponse, ExportChromeTraceError>;
+    get_texture_stats => get_texture_stats_in(GetTextureStatsRequest) -> Result<GetTextureStatsResponse, GetTextureStatsError>;
+    scan_targets_for_invalid_values => scan_targets_for_invalid_values_in(ScanTargetsForInvalidValuesRequest) -> Result<ScanTargetsForInvalidValuesResponse, ScanTargetsForInvalidValuesError>;
+    compare_textures => compare_textures_in(CompareTexturesRequest) -> Result<CompareTexturesResponse, CompareTexturesError>;
+    diff_event_pipeline_state => diff_event_pipeline_state_in(DiffEventPipelineStateRequest) -> Result<DiffEventPipelineStateResponse, DiffEventPipelineStateError>;
+    export_frame_graph => export_frame_graph_in(ExportFrameGraphRequest) -> Result<ExportFrameGraphResponse, ExportFrameGraphError>;
+    export_render_passes => export_render_passes_in(ExportRenderPassesRequest) -> Result<ExportRenderPassesResponse, ExportRenderPassesError>;
+    export_draw_stats => export_draw_stats_in(ExportDrawStatsRequest) -> Result<ExportDrawStatsResponse, ExportDrawStatsError>;
+    export_api_call_stats => export_api_call_stats_in(ExportApiCallStatsRequest) -> Result<ExportApiCallStatsResponse, ExportApiCallStatsError>;
+    lint_redundant_state => lint_redundant_state_in(LintRedundantStateRequest) -> Result<LintRedundantStateResponse, LintRedundantStateError>;
+    lint_unbalanced_markers => lint_unbalanced_markers_in(LintUnbalancedMarkersRequest) -> Result<LintUnbalancedMarkersResponse, LintUnbalancedMarkersError>;
+    find_unused_bindings => find_unused_bindings_in(FindUnusedBindingsRequest) -> Result<FindUnusedBindingsResponse, FindUnusedBindingsError>;
+    diagnose_draw => diagnose_draw_in(DiagnoseDrawRequest) -> Result<DiagnoseDrawResponse, DiagnoseDrawError>;
+    get_constants => get_constants_in(GetConstantsRequest) -> Result<GetConstantsResponse, GetConstantsError>;
+    get_descriptor_arrays => get_descriptor_arrays_in(GetDescriptorArraysRequest) -> Result<GetDescriptorArraysResponse, GetDescriptorArraysError>;
+    save_texture_raw => save_texture_raw_in(SaveTextureRawRequest) -> Result<SaveTextureRawResponse, SaveTextureRawError>;
+    save_depth => save_depth_in(SaveDepthRequest) -> Result<SaveDepthResponse, SaveDepthError>;
+    dump_buffer => dump_buffer_in(DumpBufferRequest) -> Result<DumpBufferResponse, DumpBufferError>;
+    readback_rw_buffers => readback_rw_buffers_in(ReadbackRwBuffersRequest) -> Result<ReadbackRwBuffersResponse, ReadbackRwBuffersError>;
+    export_flipbook => export_flipbook_in(ExportFlipbookRequest) -> Result<ExportFlipbookResponse, ExportFlipbookError>;
+    render_thumbnail => render_thumbnail_in(RenderThumbnailRequest) -> Result<RenderThumbnailResponse, RenderThumbnailError>;
+    generate_report_markdown => generate_report_markdown_in(GenerateReportMarkdownRequest) -> Result<GenerateReportMarkdownResponse, GenerateReportMarkdownError>;
+    generate_report_html => generate_report_html_in(GenerateReportHtmlRequest) -> Result<GenerateReportHtmlResponse, GenerateReportHtmlError>;
+    replay_list_textures => replay_list_textures_in(ReplayListTexturesRequest) -> Result<ReplayListTexturesResponse, ReplayListTexturesError>;
+    replay_pick_pixel => replay_pick_pixel_in(ReplayPickPixelRequest) -> Result<ReplayPickPixelResponse, ReplayPickPixelError>;
+    replay_save_texture_png => replay_save_texture_png_in(ReplaySaveTexturePngRequest) -> Result<ReplaySaveTexturePngResponse, ReplaySaveTexturePngError>;
+    replay_save_outputs_png => replay_save_outputs_png_in(ReplaySaveOutputsPngRequest) -> Result<ReplaySaveOutputsPngResponse, ReplaySaveOutputsPngError>;
+    replay_export_mesh => replay_export_mesh_in(ReplayExportMeshRequest) -> Result<ReplayExportMeshResponse, ReplayExportMeshError>;
+    replay_save_overdraw_png => replay_save_overdraw_png_in(ReplaySaveOverdrawPngRequest) -> Result<ReplaySaveOverdrawPngResponse, ReplaySaveOverdrawPngError>;
+    replay_list_buffers => replay_list_buffers_in(ReplayListBuffersRequest) -> Result<ReplayListBuffersResponse, ReplayListBuffersError>;
+    replay_save_all_textures => replay_save_all_textures_in(ReplaySaveAllTexturesRequest) -> Result<ReplaySaveAllTexturesResponse, ReplaySaveAllTexturesError>;
+    replay_pick_pixels => replay_pick_pixels_in(ReplayPickPixelsRequest) -> Result<ReplayPickPixelsResponse, ReplayPickPixelsError>;
+    debug_pixel => debug_pixel_in(DebugPixelRequest) -> Result<DebugShaderResponse, DebugShaderError>;
+    debug_vertex => debug_vertex_in(DebugVertexRequest) -> Result<DebugShaderResponse, DebugShaderError>;
+    debug_thread => debug_thread_in(DebugThreadRequest) -> Result<DebugShaderResponse, DebugShaderError>;
+    update_baseline => update_baseline_in(UpdateBaselineRequest) -> Result<UpdateBaselineResponse, RegressionError>;
+    verify_against_baseline => verify_against_baseline_in(VerifyBaselineRequest) -> Result<RegressionReport, RegressionError>;
+}
+
+impl ReplaySession {
+    pub fn session_dir(&self) -> &Path {
+        &self.session_dir
+    }
+
+    pub fn pid(&self) -> u32 {
+        self.lock_worker().child.id()
+    }
+
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Run a renderdog `qrenderdoc --python` script inside the worker.
+    ///
+    /// The script runs with `req.working_dir` as its current directory, exactly like a one-shot
+    /// `qrenderdoc --python` run; `req.args` are ignored.
+    pub fn run_script(
+        &self,
+        req: &QRenderDocPythonRequest,
+    ) -> Result<QRenderDocPythonResult, ReplaySessionError> {
+        let run_dir = req
+            .working_dir
+            .clone()
+            .unwrap_or_else(|| self.session_dir.clone());
+        let resp = self.send(&WorkerRequest {
+            script: Some(&req.script_path),
+            run_dir: Some(&run_dir),
+            shutdown: false,
+        })?;
+        if resp.ok {
+            Ok(QRenderDocPythonResult {
+                stdout: resp.stdout,
+                stderr: String::new(),
+                status: 0,
+            })
+        } else {
+            Err(ReplaySessionError::ScriptError(
+                resp.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    /// Ask the worker to release its captures and exit, waiting for it to do so.
+    pub fn shutdown(self) -> Result<(), ReplaySessionError> {
+        self.send(&WorkerRequest {
+            script: None,
+            run_dir: None,
+            shutdown: true,
+        })?;
+        let mut worker = self.lock_worker();
+        let deadline = Instant::now() + SHUTDOWN_GRACE;
+        while Instant::now() < deadline {
+            if let Ok(Some(_)) = worker.child.try_wait() {
+                return Ok(());
+            }
+            thread::sleep(POLL_INTERVAL);
+        }
+        let _ = worker.child.kill();
+        let _ = worker.child.wait();
+        Ok(())
+    }
+
+    fn lock_worker(&self) -> MutexGuard<'_, SessionWorker> {
+        self.worker.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn send(&self, req: &WorkerRequest<'_>) -> Result<WorkerResponse, ReplaySessionError> {
+        let mut worker = self.lock_worker();
+        let seq = worker.next_seq;
+        worker.next_seq += 1;
+
+        let inbox = self.session_dir.join("inbox");
+        let outbox = self.session_dir.join("outbox");
+        std::fs::create_dir_all(&inbox).map_err(ReplaySessionError::WriteRequest)?;
+
+        // Write then rename so the worker never sees a partial request.
+        let tmp_path = inbox.join(format!("{seq}.json.tmp"));
+        std::fs::write(&tmp_path, serde_json::to_vec(req)?)
+            .map_err(ReplaySessionError::WriteRequest)?;
+        std::fs::rename(&tmp_path, inbox.join(format!("{seq}.json")))
+            .map_err(ReplaySessionError::WriteRequest)?;
+
+        let response_path = outbox.join(format!("{seq}.json"));
+        self.wait_for_file(&mut worker, &response_path)?;
+        let bytes = std::fs::read(&response_path).map_err(ReplaySessionError::ReadResponse)?;
+        let _ = std::fs::remove_file(&response_path);
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+
+    fn wait_for_file(
+        &self,
+        worker: &mut SessionWorker,
+        path: &Path,
+    ) -> Result<(), ReplaySessionError> {
+        let deadline = Instant::now() + self.timeout;
+        loop {
+            if path.is_file() {
+                return Ok(());
+            }
+            if let Ok(Some(status)) = worker.child.try_wait() {
+                // The worker may have answered right before exiting (e.g. shutdown).
+                if path.is_file() {
+                    return Ok(());
+                }
+                let detail = std::fs::read_to_string(self.session_dir.join("worker_error.txt"))
+                    .map(|s| format!(":\n{s}"))
+                    .unwrap_or_default();
+                return Err(ReplaySessionError::WorkerExited {
+                    status: status.code(),
+                    detail,
+                });
+            }
+            if Instant::now() >= deadline {
+                return Err(ReplaySessionError::Timeout(self.timeout));
+            }
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+}
+
+impl Drop for ReplaySession {
+    fn drop(&mut self) {
+        let worker = self.worker.get_mut().unwrap_or_else(|e| e.into_inner());
+        if let Ok(None) = worker.child.try_wait() {
+            let _ = worker.child.kill();
+            let _ = worker.child.wait();
+        }
+    }
+}
@@ -6,8 +6,8 @@ use std::{
 
 use thiserror::Error;
 
-use crate::RenderDocInstallation;
 use crate::{CommandError, CommandSpec, run_command_expect_success};
+use crate::{RenderDocInstallation, ReplaySession, ReplaySessionError};
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub(crate) struct QRenderDocJsonEnvelope<T> {
@@ -60,6 +60,8 @@ pub enum QRenderDocPythonError {
     ScriptNotFound(PathBuf),
     #[error(transparent)]
     Command(Box<CommandError>),
+    #[error(transparent)]
+    Session(Box<ReplaySessionError>),
 }
 
 impl From<CommandError> for QRenderDocPythonError {
@@ -68,6 +70,12 @@ impl From<CommandError> for QRenderDocPythonError {
     }
 }
 
+impl From<ReplaySessionError> for QRenderDocPythonError {
+    fn from(value: ReplaySessionError) -> Self {
+        Self::Session(Box::new(value))
+    }
+}
+
 impl RenderDocInstallation {
     pub fn run_qrenderdoc_python(
         &self,
@@ -95,6 +103,25 @@ impl RenderDocInstallation {
             status: output.status,
         })
     }
+
+    /// Run `req` inside `session` when given, otherwise spawn `qrenderdoc` as usual.
+    pub(crate) fn run_qrenderdoc_python_in(
+        &self,
+        session: Option<&ReplaySession>,
+        req: &QRenderDocPythonRequest,
+    ) -> Result<QRenderDocPythonResult, QRenderDocPythonError> {
+        match session {
+            Some(session) => {
+                if !req.script_path.is_file() {
+                    return Err(QRenderDocPythonError::ScriptNotFound(
+                        req.script_path.clone(),
+                    ));
+                }
+                Ok(session.run_script(req)?)
+            }
+            None => self.run_qrenderdoc_python(req),
+        }
+    }
 }
 
 pub fn write_script_file(path: &Path, content: &str) -> Result<(), std::io::Error> {
@@ -0,0 +1,277 @@
+use std::path::Path;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::resolve_path_string_from_cwd;
+use crate::scripting::{QRenderDocJsonEnvelope, create_qrenderdoc_run_dir};
+use crate::{
+    QRenderDocPythonRequest, RenderDocInstallation, ReplayOptions, ReplaySession,
+    default_scripts_dir, write_script_file,
+};
+
+fn default_max_steps() -> u32 {
+    10_000
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DebugPixelRequest {
+    pub capture_path: String,
+    pub event_id: u32,
+    pub x: u32,
+    pub y: u32,
+    /// MSAA sample to debug; RenderDoc picks one when unset.
+    #[serde(default)]
+    pub sample: Option<u32>,
+    /// Primitive to debug when several cover the pixel; the last one written when unset.
+    #[serde(default)]
+    pub primitive: Option<u32>,
+    /// Multiview view index.
+    #[serde(default)]
+    pub view: Option<u32>,
+    /// Maximum number of steps recorded in the response (the trace always runs to completion).
+    #[serde(default = "default_max_steps")]
+    pub max_steps: u32,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DebugVertexRequest {
+    pub capture_path: String,
+    pub event_id: u32,
+    pub vertex_id: u32,
+    #[serde(default)]
+    pub instance: u32,
+    /// Index into the index buffer for indexed draws; defaults to `vertex_id`.
+    #[serde(default)]
+    pub index: Option<u32>,
+    #[serde(default)]
+    pub view: u32,
+    /// Maximum number of steps recorded in the response (the trace always runs to completion).
+    #[serde(default = "default_max_steps")]
+    pub max_steps: u32,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DebugThreadRequest {
+    pub capture_path: String,
+    pub event_id: u32,
+    /// Workgroup ID.
+    pub group: [u32; 3],
+    /// Thread ID within the workgroup.
+    pub thread: [u32; 3],
+    /// Maximum number of steps recorded in the response (the trace always runs to completion).
+    #[serde(default = "default_max_steps")]
+    pub max_steps: u32,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+/// One (flattened) shader variable; struct and array members use dotted/indexed names.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ShaderDebugVariable {
+    pub name: String,
+    pub type_name: String,
+    pub rows: u32,
+    pub columns: u32,
+    pub values: Vec<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ShaderDebugStep {
+    pub step: u32,
+    /// Instruction about to execute after this step.
+    pub instruction: u32,
+    pub file: Option<String>,
+    pub line: Option<u32>,
+    /// Variables written by this step, with their new values.
+    pub changes: Vec<ShaderDebugVariable>,
+}
+
+/// A source-level variable and its final value, resolved through the registers it maps to.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ShaderDebugSourceVariable {
+    pub name: String,
+    pub type_name: String,
+    pub rows: u32,
+    pub columns: u32,
+    pub registers: Vec<String>,
+    pub values: Vec<Option<f64>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DebugShaderResponse {
+    pub capture_path: String,
+    pub event_id: u32,
+    /// `"pixel"`, `"vertex"` or `"thread"`.
+    pub mode: String,
+    pub stage: String,
+    pub source_files: Vec<String>,
+    pub total_steps: u32,
+    /// `true` when `steps` holds fewer than `total_steps` entries.
+    pub truncated: bool,
+    pub steps: Vec<ShaderDebugStep>,
+    pub inputs: Vec<ShaderDebugVariable>,
+    /// Final value of every variable written during the trace.
+    pub variables: Vec<ShaderDebugVariable>,
+    pub source_variables: Vec<ShaderDebugSourceVariable>,
+}
+
+#[derive(Debug, Error)]
+pub enum DebugShaderError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for DebugShaderError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+fn remove_if_exists(path: &Path) -> Result<(), std::io::Error> {
+    match std::fs::remove_file(path) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+impl RenderDocInstallation {
+    /// Debug the pixel shader invocation that wrote `(x, y)` at `event_id`.
+    pub fn debug_pixel(
+        &self,
+        cwd: &Path,
+        req: &DebugPixelRequest,
+    ) -> Result<DebugShaderResponse, DebugShaderError> {
+        self.debug_pixel_in(None, cwd, req)
+    }
+
+    pub(crate) fn debug_pixel_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &DebugPixelRequest,
+    ) -> Result<DebugShaderResponse, DebugShaderError> {
+        let req = DebugPixelRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            ..req.clone()
+        };
+        self.debug_shader(session, cwd, "pixel", &req)
+    }
+
+    /// Debug one vertex shader invocation of the draw at `event_id`.
+    pub fn debug_vertex(
+        &self,
+        cwd: &Path,
+        req: &DebugVertexRequest,
+    ) -> Result<DebugShaderResponse, DebugShaderError> {
+        self.debug_vertex_in(None, cwd, req)
+    }
+
+    pub(crate) fn debug_vertex_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &DebugVertexRequest,
+    ) -> Result<DebugShaderResponse, DebugShaderError> {
+        let req = DebugVertexRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            ..req.clone()
+        };
+        self.debug_shader(session, cwd, "vertex", &req)
+    }
+
+    /// Debug one compute shader thread of the dispatch at `event_id`.
+    pub fn debug_thread(
+        &self,
+        cwd: &Path,
+        req: &DebugThreadRequest,
+    ) -> Result<DebugShaderResponse, DebugShaderError> {
+        self.debug_thread_in(None, cwd, req)
+    }
+
+    pub(crate) fn debug_thread_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &DebugThreadRequest,
+    ) -> Result<DebugShaderResponse, DebugShaderError> {
+        let req = DebugThreadRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            ..req.clone()
+        };
+        self.debug_shader(session, cwd, "thread", &req)
+    }
+
+    fn debug_shader<R: Serialize>(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        mode: &str,
+        req: &R,
+    ) -> Result<DebugShaderResponse, DebugShaderError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir).map_err(DebugShaderError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("debug_shader_json.py");
+        write_script_file(&script_path, DEBUG_SHADER_JSON_PY)
+            .map_err(DebugShaderError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, &format!("debug_{mode}"))
+            .map_err(DebugShaderError::CreateScriptsDir)?;
+        let request_path = run_dir.join("debug_shader_json.request.json");
+        let response_path = run_dir.join("debug_shader_json.response.json");
+        remove_if_exists(&response_path).map_err(DebugShaderError::WriteRequest)?;
+
+        let mut value = serde_json::to_value(req).map_err(DebugShaderError::ParseJson)?;
+        if let Some(obj) = value.as_object_mut() {
+            obj.insert("mode".into(), mode.into());
+        }
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&value).map_err(DebugShaderError::ParseJson)?,
+        )
+        .map_err(DebugShaderError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+
+        let _ = result;
+        let bytes = std::fs::read(&response_path).map_err(DebugShaderError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<DebugShaderResponse> =
+            serde_json::from_slice(&bytes).map_err(DebugShaderError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| DebugShaderError::ScriptError("missing result".into()))
+        } else {
+            Err(DebugShaderError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+}
+
+const DEBUG_SHADER_JSON_PY: &str = include_str!("../scripts/debug_shader_json.py");
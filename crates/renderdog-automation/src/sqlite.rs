@@ -0,0 +1,439 @@
+//! SQLite export backend (requires the `sqlite` feature).
+//!
+//! [`RenderDocInstallation::export_sqlite`] runs the JSONL bundle export and a resource listing,
+//! then loads them into one database so large captures can be queried with SQL:
+//!
+//! - `actions(event_id, parent_event_id, depth, name, flags, flags_names, marker_path,
+//!   num_children)`: every action (`marker_path` is `/`-joined, `flags_names` is `|`-joined),
+//! - `draws(event_id, name, marker_path, shader_names, stages_json)`: one row per draw/dispatch,
+//!   with the full per-stage binding record as JSON,
+//! - `bindings(event_id, stage, kind, slot, name, resource_id, resource_name)`: one row per bound
+//!   shader, SRV, UAV, constant buffer, render target and depth target (`stage` is `"OM"` for
+//!   targets),
+//! - `resources(resource_id, name, resource_type)`.
+//!
+//! `event_id`, `marker_path` and resource name/id columns are indexed.
+
+use std::{
+    io::{BufRead, BufReader},
+    path::{Path, PathBuf},
+};
+
+use rusqlite::{Connection, Transaction, params};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::{
+    ExportBundleError, ExportBundleRequest, RenderDocInstallation, ReplayOptions, ResourceMatch,
+    SearchResourcesError, SearchResourcesRequest, resolve_path_string_from_cwd,
+};
+
+const SCHEMA: &str = "
+CREATE TABLE actions (
+    event_id INTEGER PRIMARY KEY,
+    parent_event_id INTEGER,
+    depth INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    flags INTEGER NOT NULL,
+    flags_names TEXT NOT NULL,
+    marker_path TEXT NOT NULL,
+    num_children INTEGER NOT NULL
+);
+CREATE TABLE draws (
+    event_id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL,
+    marker_path TEXT NOT NULL,
+    shader_names TEXT NOT NULL,
+    stages_json TEXT NOT NULL
+);
+CREATE TABLE bindings (
+    event_id INTEGER NOT NULL,
+    stage TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    slot INTEGER,
+    name TEXT NOT NULL,
+    resource_id INTEGER,
+    resource_name TEXT NOT NULL
+);
+CREATE TABLE resources (
+    resource_id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL,
+    resource_type TEXT NOT NULL
+);
+CREATE INDEX actions_marker_path ON actions (marker_path);
+CREATE INDEX draws_marker_path ON draws (marker_path);
+CREATE INDEX bindings_event_id ON bindings (event_id);
+CREATE INDEX bindings_resource_id ON bindings (resource_id);
+CREATE INDEX bindings_resource_name ON bindings (resource_name);
+CREATE INDEX resources_name ON resources (name);
+";
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ExportSqliteRequest {
+    pub capture_path: String,
+    pub output_dir: String,
+    /// The database is written as `<basename>.sqlite` (replacing an existing one), next to the
+    /// JSONL exports it is built from.
+    pub basename: String,
+    pub include_cbuffers: bool,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ExportSqliteResponse {
+    pub capture_path: String,
+    pub database_path: String,
+    pub actions_jsonl_path: String,
+    pub bindings_jsonl_path: String,
+    pub actions: u64,
+    pub draws: u64,
+    pub bindings: u64,
+    pub resources: u64,
+}
+
+#[derive(Debug, Error)]
+pub enum ExportSqliteError {
+    #[error("export bundle failed: {0}")]
+    Bundle(#[from] ExportBundleError),
+    #[error("list resources failed: {0}")]
+    Resources(#[from] SearchResourcesError),
+    #[error("failed to read {path}: {source}")]
+    ReadJsonl {
+        path: String,
+        source: std::io::Error,
+    },
+    #[error("failed to parse {path}: {source}")]
+    ParseJsonl {
+        path: String,
+        source: serde_json::Error,
+    },
+    #[error("failed to remove existing database: {0}")]
+    RemoveExisting(std::io::Error),
+    #[error("sqlite error: {0}")]
+    Sqlite(#[from] rusqlite::Error),
+}
+
+impl RenderDocInstallation {
+    /// Export actions, draw bindings and resources into one indexed SQLite database.
+    pub fn export_sqlite(
+        &self,
+        cwd: &Path,
+        req: &ExportSqliteRequest,
+    ) -> Result<ExportSqliteResponse, ExportSqliteError> {
+        let capture_path = resolve_path_string_from_cwd(cwd, &req.capture_path);
+        let output_dir = resolve_path_string_from_cwd(cwd, &req.output_dir);
+
+        let bundle = self.export_bundle_jsonl(
+            cwd,
+            &ExportBundleRequest {
+                capture_path: capture_path.clone(),
+                output_dir: output_dir.clone(),
+                basename: req.basename.clone(),
+                only_drawcalls: false,
+                marker_prefix: None,
+                event_id_min: None,
+                event_id_max: None,
+                name_contains: None,
+                marker_contains: None,
+                case_sensitive: false,
+                include_cbuffers: req.include_cbuffers,
+                include_outputs: true,
+                compress: false,
+                replay_options: req.replay_options.clone(),
+            },
+        )?;
+
+        let resources = self.search_resources(
+            cwd,
+            &SearchResourcesRequest {
+                capture_path: capture_path.clone(),
+                query: None,
+                case_sensitive: false,
+                max_results: None,
+                resource_types: None,
+                replay_options: req.replay_options.clone(),
+            },
+        )?;
+
+        let database_path = PathBuf::from(&output_dir).join(format!("{}.sqlite", req.basename));
+        let counts = write_database(
+            &database_path,
+            Path::new(&bundle.actions_jsonl_path),
+            Path::new(&bundle.bindings_jsonl_path),
+            &resources.matches,
+        )?;
+
+        Ok(ExportSqliteResponse {
+            capture_path,
+            database_path: database_path.display().to_string(),
+            actions_jsonl_path: bundle.actions_jsonl_path,
+            bindings_jsonl_path: bundle.bindings_jsonl_path,
+            actions: counts.actions,
+            draws: counts.draws,
+            bindings: counts.bindings,
+            resources: counts.resources,
+        })
+    }
+}
+
+#[derive(Debug, Default)]
+struct RowCounts {
+    actions: u64,
+    draws: u64,
+    bindings: u64,
+    resources: u64,
+}
+
+fn write_database(
+    database_path: &Path,
+    actions_jsonl: &Path,
+    bindings_jsonl: &Path,
+    resources: &[ResourceMatch],
+) -> Result<RowCounts, ExportSqliteError> {
+    match std::fs::remove_file(database_path) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+            return Err(ExportSqliteError::RemoveExisting(e));
+        }
+        _ => {}
+    }
+
+    let mut conn = Connection::open(database_path)?;
+    conn.execute_batch(SCHEMA)?;
+    let tx = conn.transaction()?;
+    let mut counts = RowCounts::default();
+
+    {
+        let mut insert =
+            tx.prepare("INSERT OR REPLACE INTO actions VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)")?;
+        for_each_jsonl(actions_jsonl, |row| {
+            insert.execute(params![
+                row["event_id"].as_u64(),
+                row["parent_event_id"].as_u64(),
+                row["depth"].as_u64().unwrap_or(0),
+                row["name"].as_str().unwrap_or(""),
+                row["flags"].as_u64().unwrap_or(0),
+                join_strings(&row["flags_names"], "|"),
+                join_strings(&row["marker_path"], "/"),
+                row["num_children"].as_u64().unwrap_or(0),
+            ])?;
+            counts.actions += 1;
+            Ok(())
+        })?;
+    }
+
+    for_each_jsonl(bindings_jsonl, |row| insert_draw(&tx, &row, &mut counts))?;
+
+    {
+        let mut insert = tx.prepare("INSERT OR REPLACE INTO resources VALUES (?1, ?2, ?3)")?;
+        for r in resources {
+            insert.execute(params![r.resource_id, r.name, r.resource_type])?;
+            counts.resources += 1;
+        }
+    }
+
+    tx.commit()?;
+    Ok(counts)
+}
+
+fn insert_draw(tx: &Transaction<'_>, row: &Value, counts: &mut RowCounts) -> rusqlite::Result<()> {
+    let event_id = row["event_id"].as_u64();
+    tx.execute(
+        "INSERT OR REPLACE INTO draws VALUES (?1, ?2, ?3, ?4, ?5)",
+        params![
+            event_id,
+            row["name"].as_str().unwrap_or(""),
+            join_strings(&row["marker_path"], "/"),
+            join_strings(&row["shader_names"], "|"),
+            row["stages"].to_string(),
+        ],
+    )?;
+    counts.draws += 1;
+
+    let mut insert =
+        tx.prepare_cached("INSERT INTO bindings VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)")?;
+    let mut bind = |stage: &str, kind: &str, slot: Option<u64>, name: &str, b: &Value| {
+        counts.bindings += 1;
+        insert.execute(params![
+            event_id,
+            stage,
+            kind,
+            slot,
+            name,
+            parse_resource_id(&b["resource_id"]),
+            b["resource_name"].as_str().unwrap_or(""),
+        ])
+    };
+
+    if let Some(stages) = row["stages"].as_object() {
+        for (stage, info) in stages {
+            let shader = &info["shader"];
+            if !shader.is_null() {
+                bind(
+                    stage,
+                    "shader",
+                    None,
+                    shader["entry_point"].as_str().unwrap_or(""),
+                    &json_shader(shader),
+                )?;
+            }
+            for (kind, key) in [("srv", "srvs"), ("uav", "uavs"), ("cbuffer", "cbuffers")] {
+                for b in info[key].as_array().into_iter().flatten() {
+                    bind(
+                        stage,
+                        kind,
+                        b["slot"].as_u64(),
+                        b["name"].as_str().unwrap_or(""),
+                        b,
+                    )?;
+                }
+            }
+        }
+    }
+
+    let outputs = &row["outputs"];
+    for rt in outputs["render_targets"].as_array().into_iter().flatten() {
+        bind("OM", "rt", rt["index"].as_u64(), "", rt)?;
+    }
+    if !outputs["depth_target"].is_null() {
+        bind("OM", "depth", None, "", &outputs["depth_target"])?;
+    }
+    Ok(())
+}
+
+/// Shader records name the shader in `name`; bindings expect it in `resource_name`.
+fn json_shader(shader: &Value) -> Value {
+    serde_json::json!({
+        "resource_id": shader["resource_id"],
+        "resource_name": shader["name"],
+    })
+}
+
+fn for_each_jsonl(
+    path: &Path,
+    mut f: impl FnMut(Value) -> rusqlite::Result<()>,
+) -> Result<(), ExportSqliteError> {
+    let file = std::fs::File::open(path).map_err(|source| ExportSqliteError::ReadJsonl {
+        path: path.display().to_string(),
+        source,
+    })?;
+    for line in BufReader::new(file).lines() {
+        let line = line.map_err(|source| ExportSqliteError::ReadJsonl {
+            path: path.display().to_string(),
+            source,
+        })?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let row = serde_json::from_str(&line).map_err(|source| ExportSqliteError::ParseJsonl {
+            path: path.display().to_string(),
+            source,
+        })?;
+        f(row)?;
+    }
+    Ok(())
+}
+
+fn join_strings(v: &Value, sep: &str) -> String {
+    match v {
+        Value::Array(items) => items
+            .iter()
+            .map(|i| {
+                i.as_str()
+                    .map(str::to_string)
+                    .unwrap_or_else(|| i.to_string())
+            })
+            .collect::<Vec<_>>()
+            .join(sep),
+        Value::String(s) => s.clone(),
+        _ => String::new(),
+    }
+}
+
+/// The exports write ids as `"ResourceId::123"`; `0` (null) is stored as NULL.
+fn parse_resource_id(v: &Value) -> Option<u64> {
+    let id = match v {
+        Value::Number(n) => n.as_u64()?,
+        Value::String(s) => s.rsplit("::").next()?.trim().parse().ok()?,
+        _ => return None,
+    };
+    (id != 0).then_some(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loads_actions_and_bindings() {
+        let dir = std::env::temp_dir().join(format!("renderdog-sqlite-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let actions = dir.join("cap.actions.jsonl");
+        let bindings = dir.join("cap.bindings.jsonl");
+        std::fs::write(
+            &actions,
+            concat!(
+                r#"{"event_id":1,"parent_event_id":null,"depth":0,"name":"GBuffer","flags":8,"flags_names":["PushMarker"],"marker_path":["GBuffer"],"num_children":1}"#,
+                "\n",
+                r#"{"event_id":2,"parent_event_id":1,"depth":1,"name":"Draw(3)","flags":2,"flags_names":["Drawcall"],"marker_path":["GBuffer"],"num_children":0}"#,
+                "\n",
+            ),
+        )
+        .unwrap();
+        std::fs::write(
+            &bindings,
+            concat!(
+                r#"{"event_id":2,"name":"Draw(3)","marker_path":["GBuffer"],"shader_names":["main"],"#,
+                r#""stages":{"Pixel":{"shader":{"resource_id":"ResourceId::7","name":"gbuffer_ps","entry_point":"main"},"#,
+                r#""srvs":[{"slot":0,"name":"albedo","resource_id":"ResourceId::9","resource_name":"Albedo"}],"uavs":[],"cbuffers":[]}},"#,
+                r#""outputs":{"render_targets":[{"index":0,"resource_id":"ResourceId::11","resource_name":"GBuffer0"}],"depth_target":null}}"#,
+                "\n",
+            ),
+        )
+        .unwrap();
+        let resources = [ResourceMatch {
+            resource_id: 9,
+            name: "Albedo".into(),
+            resource_type: "Texture".into(),
+        }];
+
+        let db = dir.join("cap.sqlite");
+        let counts = write_database(&db, &actions, &bindings, &resources).unwrap();
+        assert_eq!(
+            (
+                counts.actions,
+                counts.draws,
+                counts.bindings,
+                counts.resources
+            ),
+            (2, 1, 3, 1)
+        );
+
+        let conn = Connection::open(&db).unwrap();
+        let (event_id, stage, kind): (u32, String, String) = conn
+            .query_row(
+                "SELECT b.event_id, b.stage, b.kind FROM bindings b
+                 JOIN resources r ON r.resource_id = b.resource_id WHERE r.name = 'Albedo'",
+                [],
+                |r| Ok((r.get(0)?, r.get(1)?, r.get(2)?)),
+            )
+            .unwrap();
+        assert_eq!(
+            (event_id, stage.as_str(), kind.as_str()),
+            (2, "Pixel", "srv")
+        );
+        let marker: String = conn
+            .query_row(
+                "SELECT marker_path FROM actions WHERE event_id = 2",
+                [],
+                |r| r.get(0),
+            )
+            .unwrap();
+        assert_eq!(marker, "GBuffer");
+
+        drop(conn);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
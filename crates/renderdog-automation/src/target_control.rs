@@ -0,0 +1,653 @@
+use std::{
+    io::{self, Read, Write},
+    net::{TcpStream, ToSocketAddrs},
+    time::{Duration, Instant},
+};
+
+use thiserror::Error;
+
+use crate::{CaptureFrame, TriggerCaptureRequest, TriggerCaptureResponse};
+
+/// First port RenderDoc's injected library listens on for target control connections.
+///
+/// Each injected process takes the next free port; that port is the `target_ident` reported by
+/// `renderdoccmd capture`.
+pub const DEFAULT_TARGET_CONTROL_PORT: u16 = 38920;
+
+/// Target control protocol version spoken by [`TargetControl`].
+pub const TARGET_CONTROL_PROTOCOL_VERSION: u32 = 9;
+
+/// Oldest protocol version [`TargetControl`] accepts from the target.
+const MIN_PROTOCOL_VERSION: u32 = 2;
+
+const IO_TIMEOUT: Duration = Duration::from_secs(10);
+const MAX_CHUNK_SIZE: u64 = 256 * 1024 * 1024;
+
+// Chunk header flags (upper 16 bits of the chunk id).
+const CHUNK_INDEX_MASK: u32 = 0x0000_ffff;
+const CHUNK_CALLSTACK: u32 = 0x0001_0000;
+const CHUNK_THREAD_ID: u32 = 0x0002_0000;
+const CHUNK_DURATION: u32 = 0x0004_0000;
+const CHUNK_TIMESTAMP: u32 = 0x0008_0000;
+const CHUNK_64BIT_SIZE: u32 = 0x0010_0000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+enum Packet {
+    Noop = 1,
+    Handshake,
+    Busy,
+    NewCapture,
+    ApiUse,
+    TriggerCapture,
+    CopyCapture,
+    DeleteCapture,
+    QueueCapture,
+    NewChild,
+    CaptureProgress,
+    CycleActiveWindow,
+    CapturableWindowCount,
+    RequestShow,
+}
+
+impl Packet {
+    fn from_u32(v: u32) -> Option<Self> {
+        use Packet::*;
+        Some(match v {
+            1 => Noop,
+            2 => Handshake,
+            3 => Busy,
+            4 => NewCapture,
+            5 => ApiUse,
+            6 => TriggerCapture,
+            7 => CopyCapture,
+            8 => DeleteCapture,
+            9 => QueueCapture,
+            10 => NewChild,
+            11 => CaptureProgress,
+            12 => CycleActiveWindow,
+            13 => CapturableWindowCount,
+            14 => RequestShow,
+            _ => return None,
+        })
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum TargetControlError {
+    #[error("failed to connect to {addr}: {source}")]
+    Connect {
+        addr: String,
+        #[source]
+        source: io::Error,
+    },
+    #[error("target control connection failed: {0}")]
+    Io(#[from] io::Error),
+    #[error("target is busy with another client: {0}")]
+    Busy(String),
+    #[error("target speaks unsupported target control protocol version {0}")]
+    UnsupportedVersion(u32),
+    #[error("target control protocol error: {0}")]
+    Protocol(String),
+    #[error("target disconnected")]
+    Disconnected,
+    #[error("timed out after {0:?} waiting for a new capture")]
+    Timeout(Duration),
+}
+
+impl TargetControlError {
+    /// Whether the target answered with something this client does not understand (as opposed to
+    /// being unreachable or busy).
+    pub fn is_protocol_mismatch(&self) -> bool {
+        matches!(self, Self::UnsupportedVersion(_) | Self::Protocol(_))
+    }
+}
+
+/// A capture reported by the target.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TargetCapture {
+    pub capture_id: u32,
+    pub timestamp: u64,
+    pub path: String,
+    pub frame_number: u32,
+    /// Encoded thumbnail bytes as sent by the target (usually JPEG); empty if none.
+    pub thumbnail: Vec<u8>,
+}
+
+/// A message received from the target.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TargetControlMessage {
+    Noop,
+    Busy {
+        client: String,
+    },
+    NewCapture(TargetCapture),
+    ApiUse {
+        name: String,
+        presenting: bool,
+        supported: bool,
+    },
+    CaptureProgress(f32),
+    NewChild {
+        pid: u32,
+        ident: u32,
+    },
+    CapturableWindowCount(u32),
+    RequestShow,
+    /// A packet this client does not decode (kept for forward compatibility).
+    Unknown(u32),
+}
+
+/// Native client for RenderDoc's target control protocol.
+///
+/// Talks directly to the TCP server inside an injected process, so triggering captures needs
+/// neither `qrenderdoc` nor `renderdoccmd`.
+///
+/// **Experimental:** the wire format follows RenderDoc's serialiser layout but is only covered by
+/// hand-written byte fixtures, not traffic recorded from a real target, and may not match every
+/// RenderDoc release.
+///
+/// ```no_run
+/// # fn main() -> Result<(), renderdog_automation::TargetControlError> {
+/// use std::time::Duration;
+///
+/// let mut tc = renderdog_automation::TargetControl::connect("localhost", 38920, "my-tool", true)?;
+/// tc.trigger_capture(1)?;
+/// let capture = tc.wait_for_new_capture(Duration::from_secs(30), |msg| eprintln!("{msg:?}"))?;
+/// println!("{}", capture.path);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct TargetControl {
+    stream: TcpStream,
+    version: u32,
+    target: String,
+    pid: u32,
+    api: Option<String>,
+}
+
+impl TargetControl {
+    /// Connect to the target listening on `host:ident` and perform the handshake.
+    ///
+    /// With `force_connection`, an existing client connection is replaced instead of failing with
+    /// [`TargetControlError::Busy`].
+    pub fn connect(
+        host: &str,
+        ident: u32,
+        client_name: &str,
+        force_connection: bool,
+    ) -> Result<Self, TargetControlError> {
+        let addr = format!("{host}:{ident}");
+        let stream = addr
+            .to_socket_addrs()
+            .and_then(|mut addrs| {
+                addrs
+                    .next()
+                    .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "host did not resolve"))
+            })
+            .and_then(|sock| TcpStream::connect_timeout(&sock, IO_TIMEOUT))
+            .map_err(|source| TargetControlError::Connect {
+                addr: addr.clone(),
+                source,
+            })?;
+        stream.set_nodelay(true)?;
+        stream.set_read_timeout(Some(IO_TIMEOUT))?;
+        stream.set_write_timeout(Some(IO_TIMEOUT))?;
+
+        let mut tc = Self {
+            stream,
+            version: 0,
+            target: String::new(),
+            pid: 0,
+            api: None,
+        };
+
+        let mut payload = ChunkWriter::default();
+        payload.u32(TARGET_CONTROL_PROTOCOL_VERSION);
+        payload.string(client_name);
+        payload.bool(force_connection);
+        tc.send(Packet::Handshake, payload)?;
+
+        let (id, data) = read_chunk(&mut tc.stream)?;
+        let mut r = ChunkReader::new(&data);
+        match Packet::from_u32(id) {
+            Some(Packet::Handshake) => {
+                tc.version = r.u32()?;
+                if !(MIN_PROTOCOL_VERSION..=TARGET_CONTROL_PROTOCOL_VERSION).contains(&tc.version) {
+                    return Err(TargetControlError::UnsupportedVersion(tc.version));
+                }
+                tc.target = r.string()?;
+                tc.pid = r.u32()?;
+                Ok(tc)
+            }
+            Some(Packet::Busy) => {
+                let _version = r.u32()?;
+                Err(TargetControlError::Busy(r.string()?))
+            }
+            _ => Err(TargetControlError::Protocol(format!(
+                "expected handshake, got packet {id}"
+            ))),
+        }
+    }
+
+    /// Protocol version negotiated with the target.
+    pub fn protocol_version(&self) -> u32 {
+        self.version
+    }
+
+    /// Target executable name as reported by the target.
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+
+    pub fn pid(&self) -> u32 {
+        self.pid
+    }
+
+    /// Graphics API last reported in use by the target, if any.
+    pub fn api(&self) -> Option<&str> {
+        self.api.as_deref()
+    }
+
+    /// Capture the next `num_frames` frames.
+    pub fn trigger_capture(&mut self, num_frames: u32) -> Result<(), TargetControlError> {
+        let mut payload = ChunkWriter::default();
+        payload.u32(num_frames);
+        self.send(Packet::TriggerCapture, payload)
+    }
+
+    /// Capture `num_frames` frames starting at `frame_number`.
+    pub fn queue_capture(
+        &mut self,
+        frame_number: u32,
+        num_frames: u32,
+    ) -> Result<(), TargetControlError> {
+        let mut payload = ChunkWriter::default();
+        payload.u32(frame_number);
+        payload.u32(num_frames);
+        self.send(Packet::QueueCapture, payload)
+    }
+
+    pub fn cycle_active_window(&mut self) -> Result<(), TargetControlError> {
+        self.send(Packet::CycleActiveWindow, ChunkWriter::default())
+    }
+
+    /// Wait up to `timeout` for the next message.
+    ///
+    /// Returns `Ok(None)` if nothing arrived in time (a keep-alive is sent in that case).
+    pub fn receive_message(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<Option<TargetControlMessage>, TargetControlError> {
+        self.stream
+            .set_read_timeout(Some(timeout.max(Duration::from_millis(1))))?;
+        let mut probe = [0u8; 1];
+        let peeked = self.stream.peek(&mut probe);
+        self.stream.set_read_timeout(Some(IO_TIMEOUT))?;
+        match peeked {
+            Ok(0) => return Err(TargetControlError::Disconnected),
+            Ok(_) => {}
+            Err(e)
+                if matches!(
+                    e.kind(),
+                    io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+                ) =>
+            {
+                self.send(Packet::Noop, ChunkWriter::default())?;
+                return Ok(None);
+            }
+            Err(e) => return Err(e.into()),
+        }
+
+        let (id, data) = read_chunk(&mut self.stream)?;
+        let msg = decode_message(id, &data)?;
+        if let TargetControlMessage::ApiUse { name, .. } = &msg {
+            self.api = Some(name.clone());
+        }
+        Ok(Some(msg))
+    }
+
+    /// Wait for the target to report a new capture, passing every message to `on_message`.
+    pub fn wait_for_new_capture(
+        &mut self,
+        timeout: Duration,
+        mut on_message: impl FnMut(&TargetControlMessage),
+    ) -> Result<TargetCapture, TargetControlError> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(TargetControlError::Timeout(timeout));
+            }
+            let Some(msg) = self.receive_message(remaining.min(Duration::from_secs(1)))? else {
+                continue;
+            };
+            on_message(&msg);
+            if let TargetControlMessage::NewCapture(capture) = msg {
+                return Ok(capture);
+            }
+        }
+    }
+
+    fn send(&mut self, packet: Packet, payload: ChunkWriter) -> Result<(), TargetControlError> {
+        let mut buf = Vec::with_capacity(8 + payload.buf.len());
+        buf.extend_from_slice(&(packet as u32).to_le_bytes());
+        buf.extend_from_slice(&(payload.buf.len() as u32).to_le_bytes());
+        buf.extend_from_slice(&payload.buf);
+        self.stream.write_all(&buf)?;
+        Ok(())
+    }
+}
+
+/// Connect to `req.host:req.target_ident`, trigger a capture and wait for it, without spawning any
+/// RenderDoc tool.
+///
+/// Each of the `req.num_frames` frames arrives as its own capture; all of them are collected. If
+/// the timeout expires after at least one arrived, the frames received so far are returned.
+///
+/// Experimental, like [`TargetControl`].
+pub fn trigger_capture_native(
+    req: &TriggerCaptureRequest,
+) -> Result<TriggerCaptureResponse, TargetControlError> {
+    let mut tc = TargetControl::connect(&req.host, req.target_ident, "renderdog", true)?;
+    tc.trigger_capture(req.num_frames)?;
+
+    let deadline = Instant::now() + Duration::from_secs(req.timeout_s.into());
+    let mut captures = Vec::new();
+    while captures.len() < req.num_frames.max(1) as usize {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        match tc.wait_for_new_capture(remaining, |_| {}) {
+            Ok(capture) => captures.push(capture),
+            Err(TargetControlError::Timeout(_)) if !captures.is_empty() => break,
+            Err(e) => return Err(e),
+        }
+    }
+
+    let first = &captures[0];
+    Ok(TriggerCaptureResponse {
+        capture_path: first.path.clone(),
+        frame_number: first.frame_number,
+        api: tc.api().unwrap_or_default().to_string(),
+        captures: captures
+            .iter()
+            .enumerate()
+            .map(|(i, c)| CaptureFrame {
+                frame_index: i as u32,
+                frame_number: Some(c.frame_number),
+                capture_path: c.path.clone(),
+            })
+            .collect(),
+    })
+}
+
+fn decode_message(id: u32, data: &[u8]) -> Result<TargetControlMessage, TargetControlError> {
+    let mut r = ChunkReader::new(data);
+    Ok(match Packet::from_u32(id) {
+        Some(Packet::Noop) => TargetControlMessage::Noop,
+        Some(Packet::Busy) => {
+            let _version = r.u32()?;
+            TargetControlMessage::Busy {
+                client: r.string()?,
+            }
+        }
+        Some(Packet::NewCapture) => {
+            let capture_id = r.u32()?;
+            let timestamp = r.u64()?;
+            let path = r.string()?;
+            let frame_number = r.u32()?;
+            // Later fields (thumbnail, size, title) vary across versions; take what is there.
+            let thumbnail = r.bytes().unwrap_or_default();
+            TargetControlMessage::NewCapture(TargetCapture {
+                capture_id,
+                timestamp,
+                path,
+                frame_number,
+                thumbnail,
+            })
+        }
+        Some(Packet::ApiUse) => TargetControlMessage::ApiUse {
+            name: r.string()?,
+            presenting: r.bool().unwrap_or(false),
+            supported: r.bool().unwrap_or(true),
+        },
+        Some(Packet::CaptureProgress) => TargetControlMessage::CaptureProgress(r.f32()?),
+        Some(Packet::NewChild) => TargetControlMessage::NewChild {
+            pid: r.u32()?,
+            ident: r.u32()?,
+        },
+        Some(Packet::CapturableWindowCount) => {
+            TargetControlMessage::CapturableWindowCount(r.u32()?)
+        }
+        Some(Packet::RequestShow) => TargetControlMessage::RequestShow,
+        _ => TargetControlMessage::Unknown(id),
+    })
+}
+
+/// Read one serialised chunk: `u32` id + flags, optional metadata, `u32`/`u64` size, payload.
+fn read_chunk(stream: &mut impl Read) -> Result<(u32, Vec<u8>), TargetControlError> {
+    fn read_u32(stream: &mut impl Read) -> Result<u32, TargetControlError> {
+        let mut b = [0u8; 4];
+        stream.read_exact(&mut b).map_err(eof_as_disconnect)?;
+        Ok(u32::from_le_bytes(b))
+    }
+    fn read_u64(stream: &mut impl Read) -> Result<u64, TargetControlError> {
+        let mut b = [0u8; 8];
+        stream.read_exact(&mut b).map_err(eof_as_disconnect)?;
+        Ok(u64::from_le_bytes(b))
+    }
+
+    let header = read_u32(stream)?;
+    if header & CHUNK_CALLSTACK != 0 {
+        let frames = read_u32(stream)?;
+        for _ in 0..frames {
+            read_u64(stream)?;
+        }
+    }
+    if header & CHUNK_THREAD_ID != 0 {
+        read_u64(stream)?;
+    }
+    if header & CHUNK_DURATION != 0 {
+        read_u64(stream)?;
+    }
+    if header & CHUNK_TIMESTAMP != 0 {
+        read_u64(stream)?;
+    }
+    let size = if header & CHUNK_64BIT_SIZE != 0 {
+        read_u64(stream)?
+    } else {
+        u64::from(read_u32(stream)?)
+    };
+    if size > MAX_CHUNK_SIZE {
+        return Err(TargetControlError::Protocol(format!(
+            "chunk size {size} exceeds limit"
+        )));
+    }
+
+    let mut data = vec![0u8; size as usize];
+    stream.read_exact(&mut data).map_err(eof_as_disconnect)?;
+    Ok((header & CHUNK_INDEX_MASK, data))
+}
+
+fn eof_as_disconnect(e: io::Error) -> TargetControlError {
+    if e.kind() == io::ErrorKind::UnexpectedEof {
+        TargetControlError::Disconnected
+    } else {
+        e.into()
+    }
+}
+
+#[derive(Default)]
+struct ChunkWriter {
+    buf: Vec<u8>,
+}
+
+impl ChunkWriter {
+    fn u32(&mut self, v: u32) {
+        self.buf.extend_from_slice(&v.to_le_bytes());
+    }
+
+    fn bool(&mut self, v: bool) {
+        self.buf.push(v as u8);
+    }
+
+    fn string(&mut self, v: &str) {
+        self.u32(v.len() as u32);
+        self.buf.extend_from_slice(v.as_bytes());
+    }
+}
+
+struct ChunkReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> ChunkReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    fn take(&mut self, n: usize) -> Result<&'a [u8], TargetControlError> {
+        if self.data.len() < n {
+            return Err(TargetControlError::Protocol("truncated packet".into()));
+        }
+        let (head, rest) = self.data.split_at(n);
+        self.data = rest;
+        Ok(head)
+    }
+
+    fn u32(&mut self) -> Result<u32, TargetControlError> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Result<u64, TargetControlError> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn f32(&mut self) -> Result<f32, TargetControlError> {
+        Ok(f32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn bool(&mut self) -> Result<bool, TargetControlError> {
+        Ok(self.take(1)?[0] != 0)
+    }
+
+    fn string(&mut self) -> Result<String, TargetControlError> {
+        let len = self.u32()? as usize;
+        Ok(String::from_utf8_lossy(self.take(len)?).into_owned())
+    }
+
+    fn bytes(&mut self) -> Result<Vec<u8>, TargetControlError> {
+        let len = self.u64()?;
+        let len = usize::try_from(len)
+            .map_err(|_| TargetControlError::Protocol("byte buffer too large".into()))?;
+        Ok(self.take(len)?.to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::TcpListener;
+
+    use super::*;
+
+    fn write_chunk(stream: &mut TcpStream, packet: Packet, payload: ChunkWriter) {
+        stream.write_all(&(packet as u32).to_le_bytes()).unwrap();
+        stream
+            .write_all(&(payload.buf.len() as u32).to_le_bytes())
+            .unwrap();
+        stream.write_all(&payload.buf).unwrap();
+    }
+
+    #[test]
+    fn target_control_handshake_and_new_capture() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+
+            let (id, data) = read_chunk(&mut stream).unwrap();
+            assert_eq!(id, Packet::Handshake as u32);
+            let mut r = ChunkReader::new(&data);
+            assert_eq!(r.u32().unwrap(), TARGET_CONTROL_PROTOCOL_VERSION);
+            assert_eq!(r.string().unwrap(), "renderdog");
+            assert!(r.bool().unwrap());
+
+            let mut hs = ChunkWriter::default();
+            hs.u32(TARGET_CONTROL_PROTOCOL_VERSION);
+            hs.string("game.exe");
+            hs.u32(4242);
+            write_chunk(&mut stream, Packet::Handshake, hs);
+
+            let (id, data) = read_chunk(&mut stream).unwrap();
+            assert_eq!(id, Packet::TriggerCapture as u32);
+            assert_eq!(ChunkReader::new(&data).u32().unwrap(), 2);
+
+            let mut api = ChunkWriter::default();
+            api.string("Vulkan");
+            api.bool(true);
+            api.bool(true);
+            write_chunk(&mut stream, Packet::ApiUse, api);
+
+            for (id, frame) in [(0u32, 12u32), (1, 13)] {
+                let mut cap = ChunkWriter::default();
+                cap.u32(id);
+                cap.buf.extend_from_slice(&1_700_000_000u64.to_le_bytes());
+                cap.string(&format!("/tmp/game_frame{frame}.rdc"));
+                cap.u32(frame);
+                write_chunk(&mut stream, Packet::NewCapture, cap);
+            }
+        });
+
+        let res = trigger_capture_native(&TriggerCaptureRequest {
+            host: "127.0.0.1".to_string(),
+            target_ident: port.into(),
+            num_frames: 2,
+            timeout_s: 10,
+        })
+        .unwrap();
+        server.join().unwrap();
+
+        assert_eq!(res.capture_path, "/tmp/game_frame12.rdc");
+        assert_eq!(res.frame_number, 12);
+        assert_eq!(res.api, "Vulkan");
+        assert_eq!(res.captures.len(), 2);
+        assert_eq!(res.captures[1].capture_path, "/tmp/game_frame13.rdc");
+        assert_eq!(res.captures[1].frame_number, Some(13));
+    }
+
+    #[test]
+    fn target_control_chunk_layout() {
+        // Handshake as sent on the wire: packet id, u32 payload size, then version (u32), client
+        // name (u32 length + UTF-8 bytes) and force flag (one byte).
+        let mut payload = ChunkWriter::default();
+        payload.u32(TARGET_CONTROL_PROTOCOL_VERSION);
+        payload.string("rd");
+        payload.bool(true);
+        assert_eq!(
+            payload.buf,
+            [9, 0, 0, 0, 2, 0, 0, 0, b'r', b'd', 1],
+            "handshake payload"
+        );
+
+        // A chunk carrying thread id and timestamp metadata with a 64-bit size: the metadata is
+        // skipped and the flags are masked off the packet id.
+        let header = Packet::NewChild as u32 | CHUNK_THREAD_ID | CHUNK_TIMESTAMP | CHUNK_64BIT_SIZE;
+        let mut bytes = header.to_le_bytes().to_vec();
+        bytes.extend_from_slice(&7u64.to_le_bytes());
+        bytes.extend_from_slice(&1_700_000_000u64.to_le_bytes());
+        bytes.extend_from_slice(&8u64.to_le_bytes());
+        bytes.extend_from_slice(&4242u32.to_le_bytes());
+        bytes.extend_from_slice(&38921u32.to_le_bytes());
+
+        let (id, data) = read_chunk(&mut bytes.as_slice()).unwrap();
+        assert_eq!(id, Packet::NewChild as u32);
+        assert_eq!(
+            decode_message(id, &data).unwrap(),
+            TargetControlMessage::NewChild {
+                pid: 4242,
+                ident: 38921,
+            }
+        );
+    }
+}
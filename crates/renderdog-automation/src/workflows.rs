@@ -17,9 +17,51 @@ mod any_json_schema {
 }
 use crate::scripting::{QRenderDocJsonEnvelope, create_qrenderdoc_run_dir};
 use crate::{
-    QRenderDocPythonRequest, RenderDocInstallation, default_scripts_dir, write_script_file,
+    EventQuery, QRenderDocPythonRequest, QueryParseError, RenderDocInstallation, ReplaySession,
+    default_scripts_dir, write_script_file,
 };
 
+// ---------------------------------------------------------------------------
+// Replay options types
+// ---------------------------------------------------------------------------
+
+/// RenderDoc `ReplayOptions` used when a workflow opens a capture for replay.
+///
+/// Every replay-based request takes these as an optional `replay_options`; unset fields keep
+/// RenderDoc's defaults. GPU selection is a preference: RenderDoc falls back to its usual choice
+/// when no adapter matches.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ReplayOptions {
+    /// Replay with the API's validation layer / debug device enabled. Its messages are reported
+    /// alongside the capture's own debug messages.
+    #[serde(default)]
+    pub api_validation: bool,
+    #[serde(default)]
+    pub optimisation: Option<ReplayOptimisationLevel>,
+    /// Prefer an adapter from this vendor, by RenderDoc `GPUVendor` name (`"AMD"`, `"nVidia"`,
+    /// `"Intel"`, `"Software"`, ...; case-insensitive).
+    #[serde(default)]
+    pub gpu_vendor: Option<String>,
+    /// Prefer the adapter with this PCI device ID.
+    #[serde(default)]
+    pub gpu_device_id: Option<u32>,
+    /// Prefer the adapter whose driver has this name (e.g. to pick between two Vulkan drivers for
+    /// the same GPU).
+    #[serde(default)]
+    pub gpu_driver_name: Option<String>,
+}
+
+/// RenderDoc `ReplayOptimisationLevel`: how aggressively replay may skip work that doesn't affect
+/// the final frame (initial-state restores, clears of untouched resources).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReplayOptimisationLevel {
+    NoOptimisation,
+    Conservative,
+    Balanced,
+    Fastest,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct TriggerCaptureRequest {
     pub host: String,
@@ -30,9 +72,23 @@ pub struct TriggerCaptureRequest {
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct TriggerCaptureResponse {
+    /// First captured frame; every frame is listed in `captures`.
     pub capture_path: String,
     pub frame_number: u32,
     pub api: String,
+    /// One capture per frame (`num_frames > 1` writes one `.rdc` per frame), in capture order.
+    #[serde(default)]
+    pub captures: Vec<crate::CaptureFrame>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ActionsExportFormat {
+    #[default]
+    Jsonl,
+    /// One flat row per action (flags and marker path joined, plus draw / dispatch parameters)
+    /// for spreadsheet triage.
+    Csv,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -47,15 +103,44 @@ pub struct ExportActionsRequest {
     pub name_contains: Option<String>,
     pub marker_contains: Option<String>,
     pub case_sensitive: bool,
+    /// JSONL when `None`.
+    #[serde(default)]
+    pub format: Option<ActionsExportFormat>,
+    /// Event query ([`EventQuery`] syntax), e.g. `draw && marker:"Shadow*" && triangles > 10000`;
+    /// combined with the other filters.
+    #[serde(default)]
+    pub query: Option<String>,
+    /// Compress the export with zstd (`.jsonl.zst`) and point the summary at it.
+    #[serde(default)]
+    pub compress: bool,
+    /// Attach each action's symbolicated callstack (`callstack` on every record). Requires a
+    /// capture taken with `CaptureCallstacks`; resolving loads symbols and can take a while.
+    #[serde(default)]
+    pub resolve_callstacks: bool,
+    /// Symbol search path for the resolver, in dbghelp `_NT_SYMBOL_PATH` syntax (e.g.
+    /// `C:\build\pdb;srv*C:\symbols*https://msdl.microsoft.com/download/symbols`).
+    #[serde(default)]
+    pub symbol_path: Option<String>,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ExportActionsResponse {
     pub capture_path: String,
-    pub actions_jsonl_path: String,
+    /// `<basename>.actions.jsonl`, or `<basename>.actions.csv` for `format: "csv"`; `.zst` is
+    /// appended when `compress` is set.
+    pub actions_path: String,
     pub summary_json_path: String,
     pub total_actions: u64,
     pub drawcall_actions: u64,
+    /// Actions with a resolved callstack, when exported with `resolve_callstacks`.
+    #[serde(default)]
+    pub callstack_actions: Option<u64>,
+    /// Set when an interrupted export of the same request was resumed (the scripts checkpoint
+    /// every 1000 records to `<output>.checkpoint`): the last event written before resuming.
+    #[serde(default)]
+    pub resumed_from_event_id: Option<u32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -67,8 +152,30 @@ pub struct FindEventsRequest {
     pub event_id_max: Option<u32>,
     pub name_contains: Option<String>,
     pub marker_contains: Option<String>,
+    /// Regex (Python `re.search` syntax, unanchored unless the pattern uses `^`/`$`) matched against
+    /// the action name, e.g. `"^Draw(Indexed)?Instanced"` or `"Shadow|Cascade"`.
+    #[serde(default)]
+    pub name_regex: Option<String>,
+    /// Regex matched against the `/`-joined marker path, e.g. `"^Frame/GBuffer(/|$)"`.
+    #[serde(default)]
+    pub marker_regex: Option<String>,
+    /// Keep actions with at least one of these `ActionFlags` (case-insensitive names such as
+    /// `Drawcall`, `Dispatch`, `Clear`, `Copy`, `Resolve`, `Present`, `PassBoundary`), e.g.
+    /// `["Clear"]` for every clear.
+    #[serde(default)]
+    pub flags_any: Vec<String>,
+    /// Keep actions carrying all of these flags, e.g. `["Drawcall", "Indirect"]`.
+    #[serde(default)]
+    pub flags_all: Vec<String>,
+    /// Event query ([`EventQuery`] syntax), e.g. `draw && marker:"Shadow*" && triangles > 10000`;
+    /// combined with the other filters.
+    #[serde(default)]
+    pub query: Option<String>,
+    /// Applies to the `contains` and regex filters.
     pub case_sensitive: bool,
     pub max_results: Option<u32>,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -96,6 +203,8 @@ pub struct FindEventsResponse {
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GetEventsRequest {
     pub capture_path: String,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -112,6 +221,245 @@ pub struct GetEventsResponse {
     pub events: Vec<EventInfo>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GetMarkerTreeRequest {
+    pub capture_path: String,
+    /// Deepest marker level returned as nodes (1 = top-level markers only); deeper markers are
+    /// still counted in their ancestors' totals. `None` returns the whole tree.
+    #[serde(default)]
+    pub max_depth: Option<u32>,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+/// One debug marker region (a `PushMarker` action) and the regions nested in it.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct MarkerNode {
+    pub name: String,
+    /// The marker's own event.
+    pub event_id: u32,
+    /// Last event inside the region.
+    pub last_event_id: u32,
+    /// 1 for top-level markers.
+    pub depth: u32,
+    /// Direct child actions, markers included.
+    pub num_children: u32,
+    /// Direct child markers.
+    pub num_child_markers: u32,
+    /// Draws in the whole region, nested markers included.
+    pub draws: u64,
+    pub dispatches: u64,
+    pub actions: u64,
+    #[serde(default)]
+    pub children: Vec<MarkerNode>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GetMarkerTreeResponse {
+    pub capture_path: String,
+    pub total_markers: u64,
+    pub max_marker_depth: u32,
+    /// Draws outside any marker region.
+    pub unmarked_draws: u64,
+    pub unmarked_dispatches: u64,
+    pub roots: Vec<MarkerNode>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GetCaptureMetadataRequest {
+    pub capture_path: String,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GetCaptureMetadataResponse {
+    pub capture_path: String,
+    /// `Vulkan`, `D3D12`, `D3D11` or `OpenGL`.
+    pub api: String,
+    pub driver_name: String,
+    /// RenderDoc version that wrote the capture (from the `.rdc` header).
+    #[serde(default)]
+    pub program_version: Option<String>,
+    pub machine_ident: u64,
+    pub gpu_vendor: String,
+    #[serde(default)]
+    pub driver_version: Option<String>,
+    /// Adapter name recorded in the device creation chunks, when present.
+    #[serde(default)]
+    pub gpu_name: Option<String>,
+    pub frame_number: u32,
+    /// Unix time (seconds) of the capture.
+    pub capture_time: u64,
+    pub file_size: u64,
+    pub compressed_size: u64,
+    pub uncompressed_size: u64,
+    pub persistent_size: u64,
+    pub init_data_size: u64,
+    /// Replay is running in a degraded mode (e.g. missing features on this GPU).
+    pub degraded: bool,
+    /// GPUs available for replay on this machine.
+    #[serde(default)]
+    pub replay_gpus: Vec<String>,
+}
+
+// ---------------------------------------------------------------------------
+// List Replay GPUs types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ListReplayGpusRequest {
+    pub capture_path: String,
+}
+
+/// An adapter RenderDoc can replay on; `vendor`, `device_id` and `driver` are the values to put in
+/// [`ReplayOptions`] to pin it.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ReplayGpu {
+    /// Position in RenderDoc's enumeration order.
+    pub index: u32,
+    pub name: String,
+    /// RenderDoc `GPUVendor` name (`AMD`, `nVidia`, `Intel`, `Software`, ...).
+    pub vendor: String,
+    /// PCI device ID.
+    pub device_id: u32,
+    pub driver: String,
+    #[serde(default)]
+    pub driver_version: Option<String>,
+    /// Graphics APIs RenderDoc can replay on this adapter.
+    pub apis: Vec<String>,
+    /// Whether `apis` covers the API the capture was recorded with.
+    pub supports_capture_api: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ListReplayGpusResponse {
+    pub capture_path: String,
+    pub driver_name: String,
+    pub local_replay_support: bool,
+    pub gpus: Vec<ReplayGpu>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ReadCaptureSectionsRequest {
+    pub capture_path: String,
+    /// Only return these sections; empty returns every section.
+    #[serde(default)]
+    pub names: Vec<String>,
+    #[serde(default = "default_include_contents")]
+    pub include_contents: bool,
+}
+
+fn default_include_contents() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CaptureSection {
+    pub index: u32,
+    pub name: String,
+    /// RenderDoc `SectionType`, e.g. `FrameCapture`, `Notes`, `Bookmarks` or `Unknown` for custom
+    /// sections.
+    pub section_type: String,
+    pub version: u64,
+    pub flags: String,
+    pub compressed_size: u64,
+    pub uncompressed_size: u64,
+    /// Section contents when they are valid UTF-8.
+    #[serde(default)]
+    pub contents: Option<String>,
+    /// Base64 section contents otherwise.
+    #[serde(default)]
+    pub contents_base64: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ReadCaptureSectionsResponse {
+    pub capture_path: String,
+    pub sections: Vec<CaptureSection>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct WriteCaptureSectionRequest {
+    pub capture_path: String,
+    /// Section name, e.g. `"renderdog/test_metadata"`; ignored for `Notes`.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// UTF-8 text to store (notes, JSON metadata, repro commands, ...).
+    pub contents: String,
+    /// `"Unknown"` (custom section, default) or `"Notes"` (the capture comments shown by
+    /// qrenderdoc).
+    #[serde(default)]
+    pub section_type: Option<String>,
+    #[serde(default)]
+    pub version: Option<u64>,
+    /// zstd-compress the section.
+    #[serde(default)]
+    pub compress: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct WriteCaptureSectionResponse {
+    pub capture_path: String,
+    pub name: String,
+    pub section_type: String,
+    pub uncompressed_size: u64,
+    /// A section with the same name existed and was overwritten.
+    pub replaced: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ValidateCaptureRequest {
+    pub capture_path: String,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ValidationCheck {
+    /// `container_header`, `open_file`, `local_replay_support`, `sections`, `open_capture`,
+    /// `enumerate_actions` or `replay_frame`.
+    pub name: String,
+    pub ok: bool,
+    /// Result code, counts or the error; `"skipped"` when a prerequisite check failed.
+    #[serde(default)]
+    pub detail: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SectionValidation {
+    pub name: String,
+    pub section_type: String,
+    pub uncompressed_size: u64,
+    pub ok: bool,
+    #[serde(default)]
+    pub detail: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ValidationMessage {
+    /// `capture` (recorded by the API validation layers at capture time) or `replay`.
+    pub source: String,
+    pub severity: String,
+    pub category: String,
+    pub event_id: u32,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ValidateCaptureResponse {
+    pub capture_path: String,
+    /// Every check passed.
+    pub valid: bool,
+    pub checks: Vec<ValidationCheck>,
+    pub sections: Vec<SectionValidation>,
+    pub total_actions: u64,
+    pub total_events: u64,
+    #[serde(default)]
+    pub last_event_id: Option<u32>,
+    pub messages: Vec<ValidationMessage>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GetShaderDetailsRequest {
     pub capture_path: String,
@@ -119,6 +467,8 @@ pub struct GetShaderDetailsRequest {
     /// Optional list of entry points to filter by. If not provided, returns all entry points.
     #[serde(default)]
     pub entry_points: Option<Vec<String>>,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -199,6 +549,196 @@ pub struct GetShaderDetailsResponse {
     pub shaders: Vec<ShaderInfo>,
 }
 
+// ---------------------------------------------------------------------------
+// Find Events By Shader types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FindEventsByShaderRequest {
+    pub capture_path: String,
+    /// Shader entry point (exact match).
+    #[serde(default)]
+    pub entry_point: Option<String>,
+    /// Source file from the shader debug info, matched case-insensitively against the end of the
+    /// recorded path (`"shadow.hlsl"` and `"shaders/shadow.hlsl"` both match
+    /// `C:\src\shaders\shadow.hlsl`). At least one of `entry_point`/`source_file` is required.
+    #[serde(default)]
+    pub source_file: Option<String>,
+    #[serde(default)]
+    pub marker_prefix: Option<String>,
+    #[serde(default)]
+    pub max_results: Option<u32>,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct MatchedShader {
+    pub resource_id: String,
+    pub name: String,
+    pub stage: String,
+    pub entry_point: String,
+    /// Source files recorded in the shader debug info (empty without debug info).
+    #[serde(default)]
+    pub source_files: Vec<String>,
+    pub event_count: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ShaderEventMatch {
+    pub event_id: u32,
+    pub name: String,
+    pub marker_path: Vec<String>,
+    pub marker_path_joined: String,
+    /// Stages whose bound shader matched.
+    pub stages: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FindEventsByShaderResponse {
+    pub capture_path: String,
+    pub total_matches: u64,
+    pub truncated: bool,
+    pub shaders: Vec<MatchedShader>,
+    pub matches: Vec<ShaderEventMatch>,
+}
+
+// ---------------------------------------------------------------------------
+// Search Shader Source types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SearchShaderSourceRequest {
+    pub capture_path: String,
+    /// Regex (Python `re` syntax) searched for in every line of the embedded debug source.
+    pub pattern: String,
+    #[serde(default)]
+    pub case_sensitive: bool,
+    /// Maximum number of matching lines to return; `total_matches` still counts all of them.
+    #[serde(default)]
+    pub max_matches: Option<u32>,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ShaderSourceLineMatch {
+    pub file: String,
+    /// 1-based line number.
+    pub line: u32,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ShaderSourceSearchHit {
+    pub resource_id: String,
+    pub name: String,
+    pub stage: String,
+    pub entry_point: String,
+    /// Draws/dispatches that bind this shader entry point.
+    pub event_ids: Vec<u32>,
+    pub matches: Vec<ShaderSourceLineMatch>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SearchShaderSourceResponse {
+    pub capture_path: String,
+    pub pattern: String,
+    pub shaders_searched: u64,
+    /// Shaders compiled without embedded source (not searchable).
+    pub shaders_without_source: u64,
+    pub total_matches: u64,
+    pub truncated: bool,
+    pub shaders: Vec<ShaderSourceSearchHit>,
+}
+
+// ---------------------------------------------------------------------------
+// Export Shader Sources types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ExportShaderSourcesRequest {
+    pub capture_path: String,
+    /// Sources go under `<output_dir>/sources/` (conflicting variants of the same path under
+    /// `<output_dir>/variants/<shader id>/`), plus `<output_dir>/index.json`.
+    pub output_dir: String,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ExportShaderSourcesResponse {
+    pub capture_path: String,
+    pub output_dir: String,
+    /// Index of every shader (stage, entry point, source files, pipelines) and, per pipeline, the
+    /// shader bound to each `"<Stage>/<entry point>"`.
+    pub index_json_path: String,
+    pub total_shaders: u64,
+    /// Shaders compiled without embedded source.
+    pub shaders_without_source: u64,
+    pub files_written: u64,
+    /// Files written under `variants/` because another shader embedded different contents for
+    /// the same path.
+    pub variant_files: u64,
+}
+
+// ---------------------------------------------------------------------------
+// Export Shader Reflection types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ExportShaderReflectionRequest {
+    pub capture_path: String,
+    pub output_dir: String,
+    /// Output is written as `<basename>.shader_reflection.json`.
+    pub basename: String,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ExportShaderReflectionResponse {
+    pub capture_path: String,
+    /// Reflection of every shader entry point: IO signatures, read-only/read-write resources,
+    /// samplers, constant blocks with member offsets/layouts, and thread group size.
+    pub shader_reflection_json_path: String,
+    pub total_shaders: u64,
+}
+
+// ---------------------------------------------------------------------------
+// Export Resource Lifetimes types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ExportResourceLifetimesRequest {
+    pub capture_path: String,
+    pub output_dir: String,
+    /// Output is written as `<basename>.resource_lifetimes.json`.
+    pub basename: String,
+    /// Only these RenderDoc `ResourceType` names, e.g. `["Texture", "Buffer"]`; empty exports
+    /// every resource.
+    #[serde(default)]
+    pub resource_types: Vec<String>,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ExportResourceLifetimesResponse {
+    pub capture_path: String,
+    /// Per resource: creation / destruction event (when inside the frame), first and last use,
+    /// use count, usage kinds and flags (`unused`, `single_use`, `created_in_frame`,
+    /// `destroyed_in_frame`, `leaked_in_frame`).
+    pub resource_lifetimes_json_path: String,
+    pub total_resources: u64,
+    pub unused_resources: u64,
+    pub single_use_resources: u64,
+    pub created_in_frame: u64,
+    pub destroyed_in_frame: u64,
+    /// Created inside the frame and not destroyed before it ended.
+    pub leaked_in_frame: u64,
+}
+
 // ---------------------------------------------------------------------------
 // Get Buffer Details types
 // ---------------------------------------------------------------------------
@@ -207,6 +747,8 @@ pub struct GetShaderDetailsResponse {
 pub struct GetBufferDetailsRequest {
     pub capture_path: String,
     pub buffer_name: String,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -242,6 +784,8 @@ pub struct GetBufferDetailsResponse {
 pub struct GetTextureDetailsRequest {
     pub capture_path: String,
     pub texture_name: String,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -286,6 +830,8 @@ pub struct GetBufferChangesDeltaRequest {
     pub buffer_name: String,
     #[serde(default = "default_tracked_indices")]
     pub tracked_indices: Vec<u32>,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
 }
 
 fn default_tracked_indices() -> Vec<u32> {
@@ -337,6 +883,8 @@ pub struct GetTextureChangesDeltaRequest {
     pub texture_name: String,
     #[serde(default = "default_tracked_texels")]
     pub tracked_texels: Vec<TexelCoord>,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
 }
 
 fn default_tracked_texels() -> Vec<TexelCoord> {
@@ -383,6 +931,8 @@ pub struct GetTextureChangesDeltaResponse {
 pub struct GetPipelineDetailsRequest {
     pub capture_path: String,
     pub pipeline_name: String,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -668,6 +1218,15 @@ pub struct GetPipelineDetailsResponse {
     /// Vulkan pipeline create info extracted from structured file (graphics pipelines only)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub vulkan_create_info: Option<VulkanPipelineCreateInfo>,
+    /// D3D12 pipeline state object desc extracted from structured file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub d3d12_create_info: Option<D3D12PipelineCreateInfo>,
+    /// D3D11 state objects bound with the pipeline's shader, with their creation descs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub d3d11_create_info: Option<D3D11PipelineCreateInfo>,
+    /// OpenGL program, vertex array, framebuffer and enabled state at the first active event
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gl_pipeline_state: Option<GLPipelineState>,
     pub event_ids: Vec<u32>,
     /// Debug info for resource scanning (temporary)
     #[serde(default, rename = "_debug_resource_scan", skip_serializing_if = "Vec::is_empty")]
@@ -860,6 +1419,485 @@ pub struct VulkanColorBlendState {
     pub attachments: Vec<VulkanColorBlendAttachment>,
 }
 
+/// D3D12 pipeline state object desc extracted from the structured file.
+/// Covers graphics and compute PSO descs as well as pipeline state streams.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct D3D12PipelineCreateInfo {
+    /// Root signature the PSO was created with
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub root_signature: Option<D3D12RootSignature>,
+    /// Shader stages with non-empty bytecode
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub shader_stages: Vec<D3DShaderStageInfo>,
+    /// Input layout elements
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub input_layout: Vec<D3DInputElement>,
+    /// Primitive topology type (POINT, LINE, TRIANGLE, PATCH)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primitive_topology_type: Option<String>,
+    /// Index buffer strip cut value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ib_strip_cut_value: Option<String>,
+    /// Render target formats, one per render target
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rtv_formats: Vec<String>,
+    /// Depth-stencil view format
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dsv_format: Option<String>,
+    /// Rasterizer desc
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rasterizer: Option<D3DRasterizerDesc>,
+    /// Blend desc
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blend: Option<D3DBlendDesc>,
+    /// Depth-stencil desc
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depth_stencil: Option<D3DDepthStencilDesc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sample_mask: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sample_count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sample_quality: Option<u32>,
+    /// Pipeline state flags
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags: Option<String>,
+}
+
+/// D3D11 state objects bound at the first event using the pipeline, with their creation descs.
+/// D3D11 has no pipeline objects, so this is the nearest equivalent of a create info.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct D3D11PipelineCreateInfo {
+    /// Bound shaders
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub shader_stages: Vec<D3DShaderStageInfo>,
+    /// Input layout object name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_layout_object: Option<String>,
+    /// Input layout elements
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub input_layout: Vec<D3DInputElement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primitive_topology: Option<String>,
+    /// Rasterizer state object name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rasterizer_object: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rasterizer: Option<D3DRasterizerDesc>,
+    /// Blend state object name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blend_object: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blend: Option<D3DBlendDesc>,
+    /// Depth-stencil state object name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depth_stencil_object: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depth_stencil: Option<D3DDepthStencilDesc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct D3DShaderStageInfo {
+    pub stage: String,
+    /// Shader object name (D3D11)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shader: Option<String>,
+    /// Bytecode length in bytes (D3D12)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bytecode_size: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct D3DInputElement {
+    pub semantic_name: String,
+    pub semantic_index: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    pub input_slot: u32,
+    /// None for D3D*_APPEND_ALIGNED_ELEMENT
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aligned_byte_offset: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_slot_class: Option<String>,
+    pub instance_data_step_rate: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct D3DRasterizerDesc {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fill_mode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cull_mode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub front_counter_clockwise: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depth_bias: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depth_bias_clamp: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slope_scaled_depth_bias: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depth_clip_enable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scissor_enable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multisample_enable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub antialiased_line_enable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forced_sample_count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conservative_raster: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct D3DRenderTargetBlend {
+    pub blend_enable: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logic_op_enable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_blend: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dest_blend: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blend_op: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src_blend_alpha: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dest_blend_alpha: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blend_op_alpha: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logic_op: Option<String>,
+    /// Written channels, e.g. "RGBA"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub render_target_write_mask: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct D3DBlendDesc {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alpha_to_coverage_enable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub independent_blend_enable: Option<bool>,
+    /// Only the first entry applies unless independent blending is enabled
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub render_targets: Vec<D3DRenderTargetBlend>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct D3DStencilOpDesc {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stencil_fail_op: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stencil_depth_fail_op: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stencil_pass_op: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stencil_func: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct D3DDepthStencilDesc {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depth_enable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depth_write_mask: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depth_func: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stencil_enable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stencil_read_mask: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stencil_write_mask: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depth_bounds_test_enable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub front_face: Option<D3DStencilOpDesc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub back_face: Option<D3DStencilOpDesc>,
+}
+
+/// Root signature decoded from its serialized blob.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct D3D12RootSignature {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Serialized version ("1.0", "1.1", "1.2")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub flags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parameters: Vec<D3D12RootParameter>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub static_samplers: Vec<D3D12StaticSampler>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct D3D12RootParameter {
+    pub index: u32,
+    /// DESCRIPTOR_TABLE, 32BIT_CONSTANTS, CBV, SRV or UAV
+    pub parameter_type: String,
+    pub visibility: String,
+    /// Root constants and root descriptors only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shader_register: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub register_space: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_32bit_values: Option<u32>,
+    /// Descriptor tables only
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ranges: Vec<D3D12DescriptorRange>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct D3D12DescriptorRange {
+    /// SRV, UAV, CBV or SAMPLER
+    pub range_type: String,
+    /// None for an unbounded range
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_descriptors: Option<u32>,
+    pub base_shader_register: u32,
+    pub register_space: u32,
+    /// None for D3D12_DESCRIPTOR_RANGE_OFFSET_APPEND
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset_in_descriptors_from_table_start: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct D3D12StaticSampler {
+    pub shader_register: u32,
+    pub register_space: u32,
+    pub visibility: String,
+    pub filter: String,
+    pub address_u: String,
+    pub address_v: String,
+    pub address_w: String,
+    pub mip_lod_bias: f32,
+    pub max_anisotropy: u32,
+    pub comparison_func: String,
+    pub min_lod: f32,
+    pub max_lod: f32,
+}
+
+/// OpenGL state bound at the first event using the pipeline.
+/// GL has no pipeline objects, so this is read from the context state rather than a create info.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GLPipelineState {
+    /// Program name (unset when separable programs are bound through a program pipeline)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub program: Option<String>,
+    /// Program pipeline name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub program_pipeline: Option<String>,
+    /// Shader objects per stage and the program they are linked into
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub shader_stages: Vec<GLShaderStage>,
+    /// Bound vertex array object layout
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vertex_array: Option<GLVertexArray>,
+    /// Bound draw framebuffer and its attachments
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub framebuffer: Option<GLFramebuffer>,
+    /// glEnable / glDisable state and its parameters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled_state: Option<GLEnabledState>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GLShaderStage {
+    pub stage: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shader: Option<String>,
+    pub program: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GLVertexArray {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Enabled attributes only
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attributes: Vec<GLVertexAttribute>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub vertex_buffers: Vec<GLVertexBuffer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index_buffer: Option<String>,
+    pub primitive_restart: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restart_index: Option<u32>,
+    pub provoking_vertex_last: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GLVertexAttribute {
+    pub index: u32,
+    pub format: String,
+    pub byte_offset: u32,
+    pub vertex_buffer_slot: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GLVertexBuffer {
+    pub slot: u32,
+    pub buffer: String,
+    pub byte_stride: u32,
+    pub byte_offset: u64,
+    pub instance_divisor: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GLFramebuffer {
+    /// FBO name, or "default framebuffer"
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub color_attachments: Vec<GLAttachment>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depth_attachment: Option<GLAttachment>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stencil_attachment: Option<GLAttachment>,
+    /// Color attachment index written by each draw buffer (-1 for GL_NONE)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub draw_buffers: Vec<i32>,
+    pub framebuffer_srgb: bool,
+    pub dither: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GLAttachment {
+    /// Color attachment index (unset for depth / stencil)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<u32>,
+    /// Texture or renderbuffer name
+    pub resource: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mip_level: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub layer: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sample_count: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GLEnabledState {
+    pub cull_face: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cull_mode: Option<String>,
+    pub front_ccw: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub polygon_mode: Option<String>,
+    pub polygon_offset: bool,
+    /// Polygon offset parameters, when enabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depth_bias: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slope_scaled_depth_bias: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset_clamp: Option<f32>,
+    pub depth_clamp: bool,
+    pub multisample: bool,
+    pub sample_shading: bool,
+    pub sample_alpha_to_coverage: bool,
+    pub sample_alpha_to_one: bool,
+    pub sample_mask: bool,
+    pub program_point_size: bool,
+    pub rasterizer_discard: bool,
+    /// Enabled GL_CLIP_DISTANCEi indices
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub clip_distances: Vec<u32>,
+    pub scissor_test: bool,
+    pub depth_test: bool,
+    pub depth_write: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depth_func: Option<String>,
+    pub depth_bounds_test: bool,
+    pub stencil_test: bool,
+    /// Stencil faces, when the stencil test is enabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stencil_front: Option<GLStencilFace>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stencil_back: Option<GLStencilFace>,
+    pub primitive_restart: bool,
+    pub framebuffer_srgb: bool,
+    pub line_width: f32,
+    pub point_size: f32,
+    /// Per draw buffer blend state
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub blend: Vec<GLBlend>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GLStencilFace {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fail_op: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depth_fail_op: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pass_op: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub func: Option<String>,
+    pub reference: u32,
+    pub compare_mask: u32,
+    pub write_mask: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GLBlend {
+    pub index: u32,
+    pub enabled: bool,
+    /// Written channels, e.g. "RGBA"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub write_mask: Option<String>,
+    /// "Operation(source, destination)", when blending is enabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color_blend: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alpha_blend: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logic_op: Option<String>,
+}
+
+// ---------------------------------------------------------------------------
+// Find Events By Pipeline types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FindEventsByPipelineRequest {
+    pub capture_path: String,
+    /// Pipeline name; an exact match wins, otherwise the first pipeline whose name contains it.
+    pub pipeline_name: String,
+    #[serde(default)]
+    pub marker_prefix: Option<String>,
+    #[serde(default)]
+    pub event_id_min: Option<u32>,
+    #[serde(default)]
+    pub event_id_max: Option<u32>,
+    #[serde(default)]
+    pub max_results: Option<u32>,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FindEventsByPipelineResponse {
+    pub capture_path: String,
+    /// Name of the resolved pipeline.
+    pub pipeline_name: String,
+    pub pipeline_id: u64,
+    /// `"Graphics"` or `"Compute"`; `None` when no event in range binds the pipeline.
+    pub pipeline_type: Option<String>,
+    pub total_matches: u64,
+    pub truncated: bool,
+    pub first_event_id: Option<u32>,
+    pub last_event_id: Option<u32>,
+    pub matches: Vec<FoundEvent>,
+}
+
 // ---------------------------------------------------------------------------
 // Get Pipeline Binding Changes Delta types
 // ---------------------------------------------------------------------------
@@ -868,6 +1906,8 @@ pub struct VulkanColorBlendState {
 pub struct GetPipelineBindingChangesDeltaRequest {
     pub capture_path: String,
     pub pipeline_name: String,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -908,6 +1948,8 @@ pub struct GetPipelineBindingChangesDeltaResponse {
 pub struct GetEventPipelineStateRequest {
     pub capture_path: String,
     pub event_id: u32,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -984,21 +2026,105 @@ pub struct PipelineSampler {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
-pub struct GetEventPipelineStateResponse {
-    pub capture_path: String,
-    pub pipeline: String,
-    pub event_id: u32,
-    pub is_compute: bool,
-    pub stages: Vec<PipelineStage>,
-    pub resources: Vec<PipelineResource>,
-    pub uniforms: Vec<PipelineUniform>,
-    pub samplers: Vec<PipelineSampler>,
+pub struct PushConstantRange {
+    #[serde(rename = "stageFlags")]
+    pub stage_flags: String,
+    pub offset: u32,
+    pub size: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PushConstantBlock {
+    pub stage: String,
+    pub name: String,
+    #[serde(rename = "byteSize")]
+    pub byte_size: u32,
+    #[schemars(schema_with = "any_json_schema::schema")]
+    pub variables: Vec<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PipelinePushConstants {
+    /// Size of the raw push constant data, in bytes.
+    pub size: u32,
+    /// Raw push constant data as hex.
+    pub bytes: String,
+    /// Ranges declared by the pipeline layout.
+    pub ranges: Vec<PushConstantRange>,
+    /// Push constant blocks declared by each stage, decoded through reflection.
+    pub blocks: Vec<PushConstantBlock>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GetEventPipelineStateResponse {
+    pub capture_path: String,
+    pub pipeline: String,
+    pub event_id: u32,
+    pub is_compute: bool,
+    pub stages: Vec<PipelineStage>,
+    pub resources: Vec<PipelineResource>,
+    pub uniforms: Vec<PipelineUniform>,
+    pub samplers: Vec<PipelineSampler>,
+    /// Push constant contents (Vulkan only).
+    #[serde(default)]
+    pub push_constants: Option<PipelinePushConstants>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GetEventApiCallRequest {
+    pub capture_path: String,
+    /// Any event ID, including state-setting calls that are not actions.
+    pub event_id: u32,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+/// One node of a structured chunk: a parameter, struct member or array element.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ApiCallParameter {
+    pub name: String,
+    /// Type as serialized by RenderDoc, e.g. `VkRenderPassBeginInfo` or `D3D12_VIEWPORT`.
+    #[serde(rename = "type")]
+    pub type_name: String,
+    /// `Chunk`, `Struct`, `Array`, `Null`, `Buffer`, `String`, `Enum`, `UnsignedInteger`,
+    /// `SignedInteger`, `Float`, `Boolean`, `Character` or `Resource`.
+    pub basetype: String,
+    /// Scalar value; enums and flags use their string form, resources `"ResourceId::N"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(schema_with = "any_json_schema::schema")]
+    pub value: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resource_name: Option<String>,
+    /// Numeric value of an enum or flags parameter.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enum_value: Option<i64>,
+    /// Struct members / array elements.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub members: Vec<ApiCallParameter>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GetEventApiCallResponse {
+    pub capture_path: String,
+    pub event_id: u32,
+    pub chunk_index: u32,
+    /// API call name, e.g. `vkCmdDrawIndexed`.
+    pub name: String,
+    /// Action whose event list contains this event.
+    pub action_event_id: u32,
+    pub action_name: String,
+    pub thread_id: u64,
+    pub timestamp_us: i64,
+    pub duration_us: i64,
+    pub parameters: Vec<ApiCallParameter>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GetResourceChangedEventIdsRequest {
     pub capture_path: String,
     pub resource_name: String,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -1059,6 +2185,8 @@ pub struct SearchResourcesRequest {
     /// - `DescriptorStore` - Descriptor heaps/sets
     #[serde(default)]
     pub resource_types: Option<Vec<String>>,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
 }
 
 fn default_max_search_results() -> Option<u32> {
@@ -1100,6 +2228,8 @@ pub struct FindResourceUsesRequest {
     /// Filter results by delta presence: "all" (default), "with_delta", "without_delta".
     #[serde(default = "default_delta_filter")]
     pub delta_filter: Option<String>,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
 }
 
 fn default_delta_filter() -> Option<String> {
@@ -1167,6 +2297,11 @@ pub struct ExportBindingsIndexRequest {
     pub case_sensitive: bool,
     pub include_cbuffers: bool,
     pub include_outputs: bool,
+    /// Compress the export with zstd (`.jsonl.zst`) and point the summary at it.
+    #[serde(default)]
+    pub compress: bool,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -1175,6 +2310,9 @@ pub struct ExportBindingsIndexResponse {
     pub bindings_jsonl_path: String,
     pub summary_json_path: String,
     pub total_drawcalls: u64,
+    /// See [`ExportActionsResponse::resumed_from_event_id`].
+    #[serde(default)]
+    pub resumed_from_event_id: Option<u32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -1193,6 +2331,12 @@ pub struct ExportBundleRequest {
 
     pub include_cbuffers: bool,
     pub include_outputs: bool,
+
+    /// Write `.jsonl.zst` instead of plain JSONL for both exports.
+    #[serde(default)]
+    pub compress: bool,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -1207,470 +2351,5272 @@ pub struct ExportBundleResponse {
     pub bindings_jsonl_path: String,
     pub bindings_summary_json_path: String,
     pub total_drawcalls: u64,
+
+    /// See [`ExportActionsResponse::resumed_from_event_id`].
+    #[serde(default)]
+    pub actions_resumed_from_event_id: Option<u32>,
+    #[serde(default)]
+    pub bindings_resumed_from_event_id: Option<u32>,
 }
 
-#[derive(Debug, Error)]
-pub enum TriggerCaptureError {
-    #[error("failed to create artifacts dir: {0}")]
-    CreateArtifactsDir(std::io::Error),
-    #[error("failed to write python script: {0}")]
-    WriteScript(std::io::Error),
-    #[error("failed to write request JSON: {0}")]
-    WriteRequest(std::io::Error),
-    #[error("qrenderdoc python failed: {0}")]
-    QRenderDocPython(Box<crate::QRenderDocPythonError>),
-    #[error("failed to parse capture JSON: {0}")]
-    ParseJson(serde_json::Error),
-    #[error("failed to read response JSON: {0}")]
-    ReadResponse(std::io::Error),
-    #[error("qrenderdoc script error: {0}")]
-    ScriptError(String),
+// ---------------------------------------------------------------------------
+// Get Vertex Inputs types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GetVertexInputsRequest {
+    pub capture_path: String,
+    pub event_id: u32,
+    /// First vertex to return, relative to the draw (0 = the draw's first vertex/index).
+    #[serde(default)]
+    pub first: u32,
+    /// Number of vertices to return (clamped to the draw's vertex/index count).
+    pub count: u32,
+    /// Instance used for per-instance attributes.
+    #[serde(default)]
+    pub instance: u32,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
 }
 
-impl From<crate::QRenderDocPythonError> for TriggerCaptureError {
-    fn from(value: crate::QRenderDocPythonError) -> Self {
-        Self::QRenderDocPython(Box::new(value))
-    }
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct VertexInputRecord {
+    /// Position within the draw (`first` + n).
+    pub index: u32,
+    /// Vertex index fetched from (after the index buffer and base vertex are applied).
+    pub vertex_id: u32,
+    /// Decoded attribute values keyed by attribute name.
+    pub values: std::collections::BTreeMap<String, Vec<f64>>,
 }
 
-#[derive(Debug, Error)]
-pub enum ExportActionsError {
-    #[error("failed to create output dir: {0}")]
-    CreateOutputDir(std::io::Error),
-    #[error("failed to write python script: {0}")]
-    WriteScript(std::io::Error),
-    #[error("failed to write request JSON: {0}")]
-    WriteRequest(std::io::Error),
-    #[error("qrenderdoc python failed: {0}")]
-    QRenderDocPython(Box<crate::QRenderDocPythonError>),
-    #[error("failed to parse export JSON: {0}")]
-    ParseJson(serde_json::Error),
-    #[error("failed to read response JSON: {0}")]
-    ReadResponse(std::io::Error),
-    #[error("qrenderdoc script error: {0}")]
-    ScriptError(String),
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GetVertexInputsResponse {
+    pub capture_path: String,
+    pub event_id: u32,
+    pub indexed: bool,
+    /// Vertex (or index) count of the draw.
+    pub total_vertices: u32,
+    /// Attribute layouts used for decoding (same shape as `GetPipelineDetails`).
+    pub attributes: Vec<VertexAttribute>,
+    pub vertices: Vec<VertexInputRecord>,
 }
 
-#[derive(Debug, Error)]
-pub enum FindEventsError {
-    #[error("failed to create scripts dir: {0}")]
-    CreateScriptsDir(std::io::Error),
-    #[error("failed to write python script: {0}")]
-    WriteScript(std::io::Error),
-    #[error("failed to write request JSON: {0}")]
-    WriteRequest(std::io::Error),
-    #[error("qrenderdoc python failed: {0}")]
-    QRenderDocPython(Box<crate::QRenderDocPythonError>),
-    #[error("failed to read response JSON: {0}")]
-    ReadResponse(std::io::Error),
-    #[error("failed to parse JSON: {0}")]
-    ParseJson(serde_json::Error),
-    #[error("qrenderdoc script error: {0}")]
-    ScriptError(String),
+// ---------------------------------------------------------------------------
+// Get Indices types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GetIndicesRequest {
+    pub capture_path: String,
+    pub event_id: u32,
+    #[serde(default)]
+    pub first: u32,
+    pub count: u32,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
 }
 
-impl From<crate::QRenderDocPythonError> for ExportActionsError {
-    fn from(value: crate::QRenderDocPythonError) -> Self {
-        Self::QRenderDocPython(Box::new(value))
-    }
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GetIndicesResponse {
+    pub capture_path: String,
+    pub event_id: u32,
+    /// Index width in bytes (2 for Uint16, 4 for Uint32).
+    pub index_byte_width: u32,
+    pub index_offset: u32,
+    pub base_vertex: i32,
+    /// Number of indices the draw consumes.
+    pub total_indices: u32,
+    /// Raw index values, as stored in the index buffer.
+    pub indices: Vec<u32>,
+    /// Largest vertex (`index + base_vertex`) addressable in the bound vertex buffers, if known.
+    pub max_vertex: Option<u32>,
+    /// Positions (relative to `first`) of indices whose vertex exceeds `max_vertex`.
+    pub out_of_range: Vec<u32>,
 }
 
-#[derive(Debug, Error)]
-pub enum ExportBindingsIndexError {
-    #[error("failed to create output dir: {0}")]
-    CreateOutputDir(std::io::Error),
-    #[error("failed to write python script: {0}")]
-    WriteScript(std::io::Error),
-    #[error("failed to write request JSON: {0}")]
-    WriteRequest(std::io::Error),
-    #[error("qrenderdoc python failed: {0}")]
-    QRenderDocPython(Box<crate::QRenderDocPythonError>),
-    #[error("failed to parse export JSON: {0}")]
-    ParseJson(serde_json::Error),
-    #[error("failed to read response JSON: {0}")]
-    ReadResponse(std::io::Error),
-    #[error("qrenderdoc script error: {0}")]
-    ScriptError(String),
+// ---------------------------------------------------------------------------
+// Fetch Counters types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FetchCountersRequest {
+    pub capture_path: String,
+    /// Counter names (`"EventGPUDuration"`, `"SamplesPassed"`, or a vendor counter's display
+    /// name, case-insensitive). Empty selects GPU duration, samples passed and VS/PS invocations;
+    /// `["all"]` selects every counter the driver exposes.
+    #[serde(default)]
+    pub counters: Vec<String>,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
 }
 
-#[derive(Debug, Error)]
-pub enum ExportBundleError {
-    #[error("export actions failed: {0}")]
-    Actions(#[from] ExportActionsError),
-    #[error("export bindings index failed: {0}")]
-    Bindings(#[from] ExportBindingsIndexError),
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CounterInfo {
+    pub id: u32,
+    pub name: String,
+    pub category: String,
+    pub description: String,
+    /// `"Absolute"`, `"Seconds"`, `"Percentage"`, `"Ratio"`, `"Bytes"`, `"Cycles"`, `"Hertz"`, ...
+    pub unit: String,
 }
 
-fn remove_if_exists(path: &Path) -> Result<(), std::io::Error> {
-    match std::fs::remove_file(path) {
-        Ok(()) => Ok(()),
-        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
-        Err(e) => Err(e),
-    }
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct EventCounterValues {
+    pub event_id: u32,
+    /// Counter name -> value.
+    pub values: std::collections::BTreeMap<String, f64>,
 }
 
-impl From<crate::QRenderDocPythonError> for ExportBindingsIndexError {
-    fn from(value: crate::QRenderDocPythonError) -> Self {
-        Self::QRenderDocPython(Box::new(value))
-    }
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FetchCountersResponse {
+    pub capture_path: String,
+    /// Names of every counter the replay driver exposes.
+    pub available: Vec<String>,
+    /// Metadata for the counters that were fetched.
+    pub counters: Vec<CounterInfo>,
+    pub events: Vec<EventCounterValues>,
 }
 
-impl From<crate::QRenderDocPythonError> for FindEventsError {
-    fn from(value: crate::QRenderDocPythonError) -> Self {
-        Self::QRenderDocPython(Box::new(value))
-    }
+// ---------------------------------------------------------------------------
+// Get Timestamp Queries types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GetTimestampQueriesRequest {
+    pub capture_path: String,
+    /// Nanoseconds per timestamp tick (`VkPhysicalDeviceLimits::timestampPeriod`, or
+    /// `1e9 / GetTimestampFrequency()` on D3D12); enables `elapsed_ns` on the regions.
+    #[serde(default)]
+    pub timestamp_period_ns: Option<f64>,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
 }
 
-#[derive(Debug, Error)]
-pub enum GetEventsError {
-    #[error("failed to create scripts dir: {0}")]
-    CreateScriptsDir(std::io::Error),
-    #[error("failed to write python script: {0}")]
-    WriteScript(std::io::Error),
-    #[error("failed to write request JSON: {0}")]
-    WriteRequest(std::io::Error),
-    #[error("qrenderdoc python failed: {0}")]
-    QRenderDocPython(Box<crate::QRenderDocPythonError>),
-    #[error("failed to read response JSON: {0}")]
-    ReadResponse(std::io::Error),
-    #[error("failed to parse JSON: {0}")]
-    ParseJson(serde_json::Error),
-    #[error("qrenderdoc script error: {0}")]
-    ScriptError(String),
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TimestampQuery {
+    pub event_id: u32,
+    /// e.g. `vkCmdWriteTimestamp`, `ID3D12GraphicsCommandList::EndQuery`, `glQueryCounter`.
+    pub call: String,
+    /// Query pool / heap (or query object) name.
+    #[serde(default)]
+    pub pool: Option<String>,
+    pub index: u32,
+    /// Pipeline stage of Vulkan timestamps.
+    #[serde(default)]
+    pub stage: Option<String>,
+    pub marker_path: Vec<String>,
+    /// Timestamp value, when the frame copies the query results into a buffer
+    /// (`vkCmdCopyQueryPoolResults`, `ResolveQueryData`); re-measured during replay.
+    #[serde(default)]
+    pub ticks: Option<u64>,
+    /// Event of the copy the value was read after.
+    #[serde(default)]
+    pub resolved_event_id: Option<u32>,
 }
 
-impl From<crate::QRenderDocPythonError> for GetEventsError {
-    fn from(value: crate::QRenderDocPythonError) -> Self {
-        Self::QRenderDocPython(Box::new(value))
-    }
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TimestampRegion {
+    /// `/`-joined marker path; a region also covers the timestamps of its nested markers.
+    pub marker_path: String,
+    pub timestamps: u64,
+    pub first_event_id: u32,
+    pub last_event_id: u32,
+    /// Last minus first timestamp, when both were resolved.
+    #[serde(default)]
+    pub elapsed_ticks: Option<u64>,
+    #[serde(default)]
+    pub elapsed_ns: Option<f64>,
 }
 
-#[derive(Debug, Error)]
-pub enum GetShaderDetailsError {
-    #[error("failed to create scripts dir: {0}")]
-    CreateScriptsDir(std::io::Error),
-    #[error("failed to write python script: {0}")]
-    WriteScript(std::io::Error),
-    #[error("failed to write request JSON: {0}")]
-    WriteRequest(std::io::Error),
-    #[error("qrenderdoc python failed: {0}")]
-    QRenderDocPython(Box<crate::QRenderDocPythonError>),
-    #[error("failed to read response JSON: {0}")]
-    ReadResponse(std::io::Error),
-    #[error("failed to parse JSON: {0}")]
-    ParseJson(serde_json::Error),
-    #[error("qrenderdoc script error: {0}")]
-    ScriptError(String),
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GetTimestampQueriesResponse {
+    pub capture_path: String,
+    pub api: String,
+    pub total_timestamps: u64,
+    pub resolved_timestamps: u64,
+    pub timestamps: Vec<TimestampQuery>,
+    pub regions: Vec<TimestampRegion>,
 }
 
-impl From<crate::QRenderDocPythonError> for GetShaderDetailsError {
-    fn from(value: crate::QRenderDocPythonError) -> Self {
-        Self::QRenderDocPython(Box::new(value))
-    }
+// ---------------------------------------------------------------------------
+// Export Chrome Trace types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ExportChromeTraceRequest {
+    pub capture_path: String,
+    /// Trace JSON to write (loadable in Perfetto / `about:tracing`).
+    pub output_path: String,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
 }
 
-#[derive(Debug, Error)]
-pub enum GetBufferDetailsError {
-    #[error("failed to create scripts dir: {0}")]
-    CreateScriptsDir(std::io::Error),
-    #[error("failed to write python script: {0}")]
-    WriteScript(std::io::Error),
-    #[error("failed to write request JSON: {0}")]
-    WriteRequest(std::io::Error),
-    #[error("qrenderdoc python failed: {0}")]
-    QRenderDocPython(Box<crate::QRenderDocPythonError>),
-    #[error("failed to read response JSON: {0}")]
-    ReadResponse(std::io::Error),
-    #[error("failed to parse JSON: {0}")]
-    ParseJson(serde_json::Error),
-    #[error("qrenderdoc script error: {0}")]
-    ScriptError(String),
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ExportChromeTraceResponse {
+    pub capture_path: String,
+    pub output_path: String,
+    /// Number of slices written (one per action, markers included).
+    pub slice_count: u64,
+    /// Sum of the per-event GPU durations, in microseconds.
+    pub total_gpu_duration_us: f64,
+    /// `false` when the replay driver exposes no `EventGPUDuration` counter; slices then have
+    /// zero length and only convey the marker hierarchy.
+    pub has_gpu_durations: bool,
 }
 
-impl From<crate::QRenderDocPythonError> for GetBufferDetailsError {
-    fn from(value: crate::QRenderDocPythonError) -> Self {
-        Self::QRenderDocPython(Box::new(value))
-    }
-}
+// ---------------------------------------------------------------------------
+// Get Texture Stats types
+// ---------------------------------------------------------------------------
 
-#[derive(Debug, Error)]
-pub enum GetTextureDetailsError {
-    #[error("failed to create scripts dir: {0}")]
-    CreateScriptsDir(std::io::Error),
-    #[error("failed to write python script: {0}")]
-    WriteScript(std::io::Error),
-    #[error("failed to write request JSON: {0}")]
-    WriteRequest(std::io::Error),
-    #[error("qrenderdoc python failed: {0}")]
-    QRenderDocPython(Box<crate::QRenderDocPythonError>),
-    #[error("failed to read response JSON: {0}")]
-    ReadResponse(std::io::Error),
-    #[error("failed to parse JSON: {0}")]
-    ParseJson(serde_json::Error),
-    #[error("qrenderdoc script error: {0}")]
-    ScriptError(String),
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GetTextureStatsRequest {
+    pub capture_path: String,
+    /// Event to replay to before reading; the end of the frame when unset.
+    #[serde(default)]
+    pub event_id: Option<u32>,
+    /// Resource ID (`"123"` / `"ResourceId::123"`), resource name, or a target bound at
+    /// `event_id`: `"rt0"`, `"rt1"`, ... or `"depth"`.
+    pub texture: String,
+    #[serde(default)]
+    pub mip: u32,
+    #[serde(default)]
+    pub slice: u32,
+    #[serde(default)]
+    pub sample: u32,
+    /// Include a 256-bucket histogram per channel, spanning that channel's [min, max].
+    #[serde(default)]
+    pub histogram: bool,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
 }
 
-impl From<crate::QRenderDocPythonError> for GetTextureDetailsError {
-    fn from(value: crate::QRenderDocPythonError) -> Self {
-        Self::QRenderDocPython(Box::new(value))
-    }
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TextureChannelStats {
+    /// `"r"`, `"g"`, `"b"` or `"a"`.
+    pub channel: String,
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub histogram: Option<Vec<u32>>,
 }
 
-#[derive(Debug, Error)]
-pub enum GetBufferChangesDeltaError {
-    #[error("failed to create scripts dir: {0}")]
-    CreateScriptsDir(std::io::Error),
-    #[error("failed to write python script: {0}")]
-    WriteScript(std::io::Error),
-    #[error("failed to write request JSON: {0}")]
-    WriteRequest(std::io::Error),
-    #[error("qrenderdoc python failed: {0}")]
-    QRenderDocPython(Box<crate::QRenderDocPythonError>),
-    #[error("failed to read response JSON: {0}")]
-    ReadResponse(std::io::Error),
-    #[error("failed to parse JSON: {0}")]
-    ParseJson(serde_json::Error),
-    #[error("qrenderdoc script error: {0}")]
-    ScriptError(String),
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GetTextureStatsResponse {
+    pub capture_path: String,
+    pub event_id: Option<u32>,
+    pub texture_id: u64,
+    pub texture_name: String,
+    pub format: String,
+    /// Dimensions of the selected mip.
+    pub width: u32,
+    pub height: u32,
+    pub channels: Vec<TextureChannelStats>,
+    /// `false` when the format could not be decoded directly and `mean` was estimated from a
+    /// histogram.
+    pub mean_exact: bool,
 }
 
-impl From<crate::QRenderDocPythonError> for GetBufferChangesDeltaError {
-    fn from(value: crate::QRenderDocPythonError) -> Self {
-        Self::QRenderDocPython(Box::new(value))
-    }
+// ---------------------------------------------------------------------------
+// Scan Targets For Invalid Values types
+// ---------------------------------------------------------------------------
+
+fn default_max_invalid_examples() -> u32 {
+    8
 }
 
-#[derive(Debug, Error)]
-pub enum GetTextureChangesDeltaError {
-    #[error("failed to create scripts dir: {0}")]
-    CreateScriptsDir(std::io::Error),
-    #[error("failed to write python script: {0}")]
-    WriteScript(std::io::Error),
-    #[error("failed to write request JSON: {0}")]
-    WriteRequest(std::io::Error),
-    #[error("qrenderdoc python failed: {0}")]
-    QRenderDocPython(Box<crate::QRenderDocPythonError>),
-    #[error("failed to read response JSON: {0}")]
-    ReadResponse(std::io::Error),
-    #[error("failed to parse JSON: {0}")]
-    ParseJson(serde_json::Error),
-    #[error("qrenderdoc script error: {0}")]
-    ScriptError(String),
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ScanTargetsForInvalidValuesRequest {
+    pub capture_path: String,
+    #[serde(default)]
+    pub event_id_min: Option<u32>,
+    #[serde(default)]
+    pub event_id_max: Option<u32>,
+    /// Also scan the bound depth target.
+    #[serde(default)]
+    pub include_depth: bool,
+    /// Example coordinates reported per finding.
+    #[serde(default = "default_max_invalid_examples")]
+    pub max_examples: u32,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
 }
 
-impl From<crate::QRenderDocPythonError> for GetTextureChangesDeltaError {
-    fn from(value: crate::QRenderDocPythonError) -> Self {
-        Self::QRenderDocPython(Box::new(value))
-    }
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct InvalidTexel {
+    pub x: u32,
+    pub y: u32,
+    /// `"r"`, `"g"`, `"b"` or `"a"`.
+    pub channel: String,
+    /// `"nan"`, `"+inf"` or `"-inf"`.
+    pub value: String,
 }
 
-#[derive(Debug, Error)]
-pub enum GetPipelineDetailsError {
-    #[error("failed to create scripts dir: {0}")]
-    CreateScriptsDir(std::io::Error),
-    #[error("failed to write python script: {0}")]
-    WriteScript(std::io::Error),
-    #[error("failed to write request JSON: {0}")]
-    WriteRequest(std::io::Error),
-    #[error("qrenderdoc python failed: {0}")]
-    QRenderDocPython(Box<crate::QRenderDocPythonError>),
-    #[error("failed to read response JSON: {0}")]
-    ReadResponse(std::io::Error),
-    #[error("failed to parse JSON: {0}")]
-    ParseJson(serde_json::Error),
-    #[error("qrenderdoc script error: {0}")]
-    ScriptError(String),
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct InvalidValueFinding {
+    pub event_id: u32,
+    pub event_name: String,
+    /// `"rt0"`, `"rt1"`, ... or `"depth"`.
+    pub target: String,
+    pub texture_id: u64,
+    pub format: String,
+    pub nan_count: u64,
+    pub inf_count: u64,
+    /// `true` when this event increased the number of invalid texels in the target (as opposed
+    /// to inheriting them from an earlier event).
+    pub introduced: bool,
+    pub examples: Vec<InvalidTexel>,
 }
 
-impl From<crate::QRenderDocPythonError> for GetPipelineDetailsError {
-    fn from(value: crate::QRenderDocPythonError) -> Self {
-        Self::QRenderDocPython(Box::new(value))
-    }
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ScanTargetsForInvalidValuesResponse {
+    pub capture_path: String,
+    pub events_scanned: u32,
+    pub targets_scanned: u32,
+    /// First event that introduced NaN/Inf texels, if any.
+    pub first_bad_event: Option<u32>,
+    pub findings: Vec<InvalidValueFinding>,
 }
 
-#[derive(Debug, Error)]
-pub enum GetPipelineBindingChangesDeltaError {
-    #[error("failed to create scripts dir: {0}")]
-    CreateScriptsDir(std::io::Error),
-    #[error("failed to write python script: {0}")]
-    WriteScript(std::io::Error),
-    #[error("failed to write request JSON: {0}")]
-    WriteRequest(std::io::Error),
-    #[error("qrenderdoc python failed: {0}")]
-    QRenderDocPython(Box<crate::QRenderDocPythonError>),
-    #[error("failed to read response JSON: {0}")]
-    ReadResponse(std::io::Error),
-    #[error("failed to parse JSON: {0}")]
-    ParseJson(serde_json::Error),
-    #[error("qrenderdoc script error: {0}")]
-    ScriptError(String),
+// ---------------------------------------------------------------------------
+// Compare Textures types
+// ---------------------------------------------------------------------------
+
+/// One side of a texture comparison.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TextureRef {
+    pub capture_path: String,
+    /// Event to replay to before reading; the end of the frame when unset.
+    #[serde(default)]
+    pub event_id: Option<u32>,
+    /// Resource ID, resource name, or a target bound at `event_id`: `"rt0"`, ... or `"depth"`.
+    pub texture: String,
+    #[serde(default)]
+    pub mip: u32,
+    #[serde(default)]
+    pub slice: u32,
 }
 
-impl From<crate::QRenderDocPythonError> for GetPipelineBindingChangesDeltaError {
-    fn from(value: crate::QRenderDocPythonError) -> Self {
-        Self::QRenderDocPython(Box::new(value))
-    }
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CompareTexturesRequest {
+    pub a: TextureRef,
+    pub b: TextureRef,
+    /// Per-channel absolute difference above which a texel counts as different.
+    #[serde(default)]
+    pub threshold: f64,
+    /// Write a greyscale PNG of the per-texel difference (scaled to `max_delta`).
+    #[serde(default)]
+    pub diff_output_path: Option<String>,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
 }
 
-#[derive(Debug, Error)]
-pub enum GetEventPipelineStateError {
-    #[error("failed to create scripts dir: {0}")]
-    CreateScriptsDir(std::io::Error),
-    #[error("failed to write python script: {0}")]
-    WriteScript(std::io::Error),
-    #[error("failed to write request JSON: {0}")]
-    WriteRequest(std::io::Error),
-    #[error("qrenderdoc python failed: {0}")]
-    QRenderDocPython(Box<crate::QRenderDocPythonError>),
-    #[error("failed to read response JSON: {0}")]
-    ReadResponse(std::io::Error),
-    #[error("failed to parse JSON: {0}")]
-    ParseJson(serde_json::Error),
-    #[error("qrenderdoc script error: {0}")]
-    ScriptError(String),
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CompareTexturesResponse {
+    pub a_format: String,
+    pub b_format: String,
+    pub width: u32,
+    pub height: u32,
+    /// Channels compared (the fewer of the two formats).
+    pub channels: u32,
+    pub texel_count: u64,
+    pub diff_count: u64,
+    pub max_delta: f64,
+    pub mse: f64,
+    /// Peak signal-to-noise ratio in dB against a peak of 1.0; `None` when the textures match.
+    pub psnr: Option<f64>,
+    /// Mean SSIM over 8x8 luminance blocks.
+    pub ssim: f64,
+    pub diff_output_path: Option<String>,
 }
 
-impl From<crate::QRenderDocPythonError> for GetEventPipelineStateError {
-    fn from(value: crate::QRenderDocPythonError) -> Self {
-        Self::QRenderDocPython(Box::new(value))
-    }
+// ---------------------------------------------------------------------------
+// Diff Event Pipeline State types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DiffEventPipelineStateRequest {
+    pub capture_path: String,
+    pub event_a: u32,
+    pub event_b: u32,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
 }
 
-#[derive(Debug, Error)]
-pub enum GetResourceChangedEventIdsError {
-    #[error("failed to create scripts dir: {0}")]
-    CreateScriptsDir(std::io::Error),
-    #[error("failed to write python script: {0}")]
-    WriteScript(std::io::Error),
-    #[error("failed to write request JSON: {0}")]
-    WriteRequest(std::io::Error),
-    #[error("qrenderdoc python failed: {0}")]
-    QRenderDocPython(Box<crate::QRenderDocPythonError>),
-    #[error("failed to read response JSON: {0}")]
-    ReadResponse(std::io::Error),
-    #[error("failed to parse JSON: {0}")]
-    ParseJson(serde_json::Error),
-    #[error("qrenderdoc script error: {0}")]
-    ScriptError(String),
+/// One field that differs between the two events.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PipelineStateChange {
+    /// Location in the `get_event_pipeline_state` output, e.g.
+    /// `stages[Pixel].blendState.blends[0].enabled` or `resources[Pixel:0:1:albedo].resource`.
+    pub path: String,
+    /// Value at `event_a`; `None` when the field only exists at `event_b`.
+    #[schemars(schema_with = "any_json_schema::schema")]
+    pub a: Option<serde_json::Value>,
+    /// Value at `event_b`; `None` when the field only exists at `event_a`.
+    #[schemars(schema_with = "any_json_schema::schema")]
+    pub b: Option<serde_json::Value>,
 }
 
-impl From<crate::QRenderDocPythonError> for GetResourceChangedEventIdsError {
-    fn from(value: crate::QRenderDocPythonError) -> Self {
-        Self::QRenderDocPython(Box::new(value))
-    }
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DiffEventPipelineStateResponse {
+    pub capture_path: String,
+    pub event_a: u32,
+    pub event_b: u32,
+    pub identical: bool,
+    pub changes: Vec<PipelineStateChange>,
 }
 
-#[derive(Debug, Error)]
-pub enum SearchResourcesError {
-    #[error("failed to create scripts dir: {0}")]
-    CreateScriptsDir(std::io::Error),
-    #[error("failed to write python script: {0}")]
-    WriteScript(std::io::Error),
-    #[error("failed to write request JSON: {0}")]
-    WriteRequest(std::io::Error),
-    #[error("qrenderdoc python failed: {0}")]
-    QRenderDocPython(Box<crate::QRenderDocPythonError>),
-    #[error("failed to read response JSON: {0}")]
-    ReadResponse(std::io::Error),
-    #[error("failed to parse JSON: {0}")]
-    ParseJson(serde_json::Error),
-    #[error("qrenderdoc script error: {0}")]
-    ScriptError(String),
+// ---------------------------------------------------------------------------
+// Export Frame Graph types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ExportFrameGraphRequest {
+    pub capture_path: String,
+    pub output_dir: String,
+    /// Files are written as `<basename>.framegraph.dot` and `<basename>.framegraph.json`.
+    pub basename: String,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
 }
 
-impl From<crate::QRenderDocPythonError> for SearchResourcesError {
-    fn from(value: crate::QRenderDocPythonError) -> Self {
-        Self::QRenderDocPython(Box::new(value))
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ExportFrameGraphResponse {
+    pub capture_path: String,
+    /// Graphviz graph: passes as nodes, one edge per resource flowing from writer to reader.
+    pub dot_path: String,
+    /// Same graph as JSON (`passes`, `resources`, `edges`), with per-pass read/write sets.
+    pub json_path: String,
+    pub pass_count: u64,
+    pub resource_count: u64,
+    pub edge_count: u64,
+}
+
+// ---------------------------------------------------------------------------
+// Export Render Passes types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ExportRenderPassesRequest {
+    pub capture_path: String,
+    pub output_dir: String,
+    /// Output is written as `<basename>.render_passes.json`.
+    pub basename: String,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ExportRenderPassesResponse {
+    pub capture_path: String,
+    pub render_passes_json_path: String,
+    pub total_passes: u64,
+    /// `false` when the API has no explicit passes (GL, D3D11) and passes were inferred from runs
+    /// of draws sharing the same targets; load/store ops are then unknown.
+    pub explicit_passes: bool,
+}
+
+// ---------------------------------------------------------------------------
+// Export Draw Stats types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ExportDrawStatsRequest {
+    pub capture_path: String,
+    pub output_dir: String,
+    /// Files are written as `<basename>.draw_stats.jsonl` and `<basename>.draw_stats_summary.json`.
+    pub basename: String,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ExportDrawStatsResponse {
+    pub capture_path: String,
+    /// One row per draw / dispatch: vertex, instance, primitive and triangle counts, dispatch
+    /// dimensions and bound target resolution.
+    pub draws_jsonl_path: String,
+    /// Frame totals plus one aggregate per marker path (nested groups included).
+    pub summary_json_path: String,
+    pub total_draws: u64,
+    pub total_dispatches: u64,
+    pub total_instances: u64,
+    /// Triangles across all instances; non-triangle topologies contribute zero.
+    pub total_triangles: u64,
+}
+
+// ---------------------------------------------------------------------------
+// Export API Call Stats types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ExportApiCallStatsRequest {
+    pub capture_path: String,
+    pub output_dir: String,
+    /// Output is written as `<basename>.api_call_stats.json`.
+    pub basename: String,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+/// API calls per category; every call is counted in exactly one.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ApiCallCategoryCounts {
+    pub draws: u64,
+    /// Compute, mesh and ray dispatches.
+    pub dispatches: u64,
+    /// Copies, resolves and mip generation.
+    pub copies: u64,
+    pub clears: u64,
+    pub barriers: u64,
+    /// State-setting calls: pipeline, descriptor, vertex/index buffer, constant and viewport
+    /// binds.
+    pub binds: u64,
+    pub markers: u64,
+    pub other: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ExportApiCallStatsResponse {
+    pub capture_path: String,
+    /// Compact JSON with the frame totals, the count per API function and the totals per pass
+    /// (explicit render passes, or marker paths on APIs without them).
+    pub api_call_stats_json_path: String,
+    pub total_calls: u64,
+    pub total_passes: u64,
+    pub totals: ApiCallCategoryCounts,
+}
+
+// ---------------------------------------------------------------------------
+// Lint Redundant State types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct LintRedundantStateRequest {
+    pub capture_path: String,
+    /// Example redundant calls kept per pass.
+    pub max_examples: u32,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RedundantStateCall {
+    pub event_id: u32,
+    pub call: String,
+    /// `pipeline`, `descriptors`, `constants` or `other`.
+    pub category: String,
+    /// Earlier call that already set the identical state.
+    pub previous_event_id: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PassRedundancy {
+    /// Render pass name, or the marker path for work outside explicit passes.
+    pub name: String,
+    pub first_event: u32,
+    pub last_event: u32,
+    pub draws: u64,
+    pub state_calls: u64,
+    pub redundant_pipeline: u64,
+    pub redundant_descriptors: u64,
+    pub redundant_constants: u64,
+    pub redundant_other: u64,
+    pub examples: Vec<RedundantStateCall>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct LintRedundantStateResponse {
+    pub capture_path: String,
+    pub total_state_calls: u64,
+    pub total_redundant: u64,
+    pub passes: Vec<PassRedundancy>,
+}
+
+// ---------------------------------------------------------------------------
+// Lint Unbalanced Markers types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct LintUnbalancedMarkersRequest {
+    pub capture_path: String,
+    /// Issues returned (default 200); `total_issues` keeps counting past it.
+    #[serde(default)]
+    pub max_issues: Option<u32>,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct MarkerIssue {
+    /// `unmatched_end`, `unclosed_begin`, `spans_command_buffers` or `spans_submission`.
+    pub kind: String,
+    /// Label of the offending marker (none for `unmatched_end`).
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub begin_event_id: Option<u32>,
+    #[serde(default)]
+    pub end_event_id: Option<u32>,
+    #[serde(default)]
+    pub detail: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct LintUnbalancedMarkersResponse {
+    pub capture_path: String,
+    pub total_begins: u64,
+    pub total_ends: u64,
+    pub total_issues: u64,
+    pub truncated: bool,
+    pub issues: Vec<MarkerIssue>,
+}
+
+// ---------------------------------------------------------------------------
+// Find Unused Bindings types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FindUnusedBindingsRequest {
+    pub capture_path: String,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct UnusedBinding {
+    /// Descriptor set (Vulkan) or register space (D3D).
+    pub set: u32,
+    pub binding: u32,
+    pub descriptor_type: Option<String>,
+    pub descriptor_count: u32,
+    /// `not_in_shader`: bound in a set layout but declared by no stage (Vulkan only).
+    /// `statically_unused`: declared by a stage but never accessed.
+    pub reason: String,
+    /// Reflected name, when a stage declares the binding.
+    pub name: Option<String>,
+    /// Stages declaring a `statically_unused` binding.
+    pub stages: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PipelineUnusedBindings {
+    pub pipeline_id: u64,
+    pub pipeline_name: String,
+    /// `graphics` or `compute`.
+    pub kind: String,
+    pub draws: u64,
+    pub first_event: u32,
+    pub unused: Vec<UnusedBinding>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FindUnusedBindingsResponse {
+    pub capture_path: String,
+    pub draws_checked: u64,
+    pub pipelines_checked: u64,
+    pub total_unused: u64,
+    /// Only pipelines with at least one unused binding.
+    pub pipelines: Vec<PipelineUnusedBindings>,
+}
+
+// ---------------------------------------------------------------------------
+// Diagnose Draw types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DiagnoseDrawRequest {
+    pub capture_path: String,
+    pub event_id: u32,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DrawDiagnosis {
+    /// Check that tripped, e.g. `culled_by_winding` or `depth_never`.
+    pub check: String,
+    /// `high` (explains an invisible draw on its own), `medium` or `low` (hint).
+    pub severity: String,
+    pub message: String,
+    /// Check-specific values backing the diagnosis.
+    #[schemars(schema_with = "any_json_schema::schema")]
+    pub evidence: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DiagnoseDrawResponse {
+    pub capture_path: String,
+    pub event_id: u32,
+    pub event_name: String,
+    pub api: String,
+    pub checks_run: Vec<String>,
+    /// Likely causes, highest severity first; empty when no check tripped.
+    pub causes: Vec<DrawDiagnosis>,
+}
+
+// ---------------------------------------------------------------------------
+// Get Constants types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GetConstantsRequest {
+    pub capture_path: String,
+    pub event_id: u32,
+    /// `Vertex`, `TessControl`, `TessEval`, `Geometry`, `Fragment`, `Compute`, `Task` or `Mesh`
+    /// (aliases such as `pixel` / `ps` are accepted). `None` decodes every bound stage.
+    pub stage: Option<String>,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ConstantBlockValues {
+    pub stage: String,
+    pub name: String,
+    /// Descriptor set (Vulkan) or register space (D3D).
+    pub set: u32,
+    pub binding: u32,
+    /// `false` for push constants / root constants.
+    pub buffer_backed: bool,
+    pub resource_id: Option<u64>,
+    pub resource_name: Option<String>,
+    pub byte_offset: u64,
+    pub byte_size: u64,
+    /// Typed variable tree: structs carry `members`, arrays `elements`, and leaves `base_type`,
+    /// `rows`, `columns` and a scalar / vector / row-list `value`.
+    #[schemars(schema_with = "any_json_schema::schema")]
+    pub variables: Vec<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GetConstantsResponse {
+    pub capture_path: String,
+    pub event_id: u32,
+    pub blocks: Vec<ConstantBlockValues>,
+}
+
+// ---------------------------------------------------------------------------
+// Get Descriptor Arrays types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GetDescriptorArraysRequest {
+    pub capture_path: String,
+    pub event_id: u32,
+    /// Case-insensitive substring filter on the reflected binding name.
+    pub name_contains: Option<String>,
+    /// Elements read per array; unbounded (bindless) arrays are read up to this many.
+    pub max_elements: u32,
+    /// Only list the elements the event accessed.
+    pub only_indexed: bool,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DescriptorArrayElement {
+    pub index: u32,
+    pub resource_id: Option<u64>,
+    pub resource_name: Option<String>,
+    pub descriptor_type: Option<String>,
+    /// Whether the event accessed this element.
+    pub indexed: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DescriptorArray {
+    pub stage: String,
+    /// `ConstantBlock`, `Sampler`, `ReadOnlyResource` or `ReadWriteResource`.
+    pub category: String,
+    pub name: String,
+    /// Descriptor set (Vulkan) or register space (D3D).
+    pub set: u32,
+    pub binding: u32,
+    /// Declared element count; `None` for unbounded arrays.
+    pub array_size: Option<u32>,
+    /// Descriptor set, heap or descriptor buffer the elements were read from.
+    pub descriptor_store: Option<u64>,
+    /// `false` when RenderDoc reported no access to the array, in which case `elements` is empty.
+    pub usage_known: bool,
+    /// Whether the array holds more elements than `max_elements`.
+    pub truncated: bool,
+    pub elements: Vec<DescriptorArrayElement>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GetDescriptorArraysResponse {
+    pub capture_path: String,
+    pub event_id: u32,
+    pub arrays: Vec<DescriptorArray>,
+}
+
+// ---------------------------------------------------------------------------
+// Save Texture Raw types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SaveTextureRawRequest {
+    pub capture_path: String,
+    /// Event to replay to before reading; the end of the frame when unset.
+    #[serde(default)]
+    pub event_id: Option<u32>,
+    /// Resource ID (`"123"` / `"ResourceId::123"`), resource name, or a target bound at
+    /// `event_id`: `"rt0"`, `"rt1"`, ... or `"depth"`.
+    pub texture: String,
+    pub output_path: String,
+    /// `"dds"` (block-compressed formats kept as-is) or `"raw"` (texel bytes of one subresource
+    /// plus a `<output stem>.json` layout descriptor).
+    pub format: String,
+    /// Subresource to write. For DDS, `None` keeps every mip / slice; for raw it means 0.
+    #[serde(default)]
+    pub mip: Option<u32>,
+    #[serde(default)]
+    pub slice: Option<u32>,
+    #[serde(default)]
+    pub sample: Option<u32>,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SaveTextureRawResponse {
+    pub capture_path: String,
+    pub event_id: Option<u32>,
+    pub texture_id: u64,
+    pub texture_name: String,
+    /// `"dds"` or `"raw"`.
+    pub format: String,
+    /// RenderDoc's name for the texel format, e.g. `R8G8B8A8_UNORM` or `BC7_SRGB`.
+    pub texel_format: String,
+    pub output_path: String,
+    /// JSON descriptor (format, dimensions, row / depth pitch) written next to raw output.
+    pub descriptor_path: Option<String>,
+    pub byte_size: u64,
+}
+
+// ---------------------------------------------------------------------------
+// Save Depth types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SaveDepthRequest {
+    pub capture_path: String,
+    /// Event to replay to before reading; the end of the frame when unset.
+    #[serde(default)]
+    pub event_id: Option<u32>,
+    /// Resource ID, resource name, `"rt<N>"` or `"depth"`; `None` means the depth target bound
+    /// at `event_id`.
+    #[serde(default)]
+    pub texture: Option<String>,
+    pub output_path: String,
+    /// `"png16"` (16-bit), `"png"` (8-bit) or `"tiff"` (32-bit float, values written unscaled).
+    pub format: String,
+    /// Near / far planes: when both are set, depth is linearized to view-space distance.
+    #[serde(default)]
+    pub near: Option<f32>,
+    #[serde(default)]
+    pub far: Option<f32>,
+    /// Depth 1.0 is the near plane.
+    #[serde(default)]
+    pub reversed_z: bool,
+    /// `"gray"` (default), `"turbo"`, `"viridis"` or `"inferno"`; PNG formats only.
+    #[serde(default)]
+    pub ramp: Option<String>,
+    #[serde(default)]
+    pub mip: Option<u32>,
+    #[serde(default)]
+    pub slice: Option<u32>,
+    #[serde(default)]
+    pub sample: Option<u32>,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SaveDepthResponse {
+    pub capture_path: String,
+    pub event_id: Option<u32>,
+    pub texture_id: u64,
+    pub texture_name: String,
+    pub texel_format: String,
+    pub output_path: String,
+    pub format: String,
+    pub width: u32,
+    pub height: u32,
+    pub linearized: bool,
+    /// Range of the non-cleared texels (raw depth, or distance when linearized); PNG output is
+    /// normalized to it.
+    pub min_value: Option<f64>,
+    pub max_value: Option<f64>,
+    /// Texels still at the clear value (1.0, or 0.0 with `reversed_z`).
+    pub cleared_texels: u64,
+}
+
+// ---------------------------------------------------------------------------
+// Dump Buffer types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DumpBufferRequest {
+    pub capture_path: String,
+    /// Resource ID (`"123"` / `"ResourceId::123"`) or resource name.
+    pub buffer: String,
+    /// Contents as of this event; the end of the frame when unset.
+    #[serde(default)]
+    pub event_id: Option<u32>,
+    #[serde(default)]
+    pub offset: u64,
+    /// Bytes to read; `None` reads to the end of the buffer.
+    #[serde(default)]
+    pub length: Option<u64>,
+    pub output_path: String,
+    /// `"bin"` (raw bytes), or `"csv"` / `"jsonl"` (one row per element, using the struct layout
+    /// reflected from a shader that binds the buffer).
+    pub format: String,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DumpBufferResponse {
+    pub capture_path: String,
+    pub event_id: Option<u32>,
+    pub buffer_id: u64,
+    pub buffer_name: String,
+    pub buffer_length: u64,
+    pub offset: u64,
+    pub byte_count: u64,
+    pub format: String,
+    pub output_path: String,
+    /// Element stride of the reflected layout (CSV / JSONL only).
+    pub stride: Option<u32>,
+    pub row_count: Option<u64>,
+    /// Flattened field paths, e.g. `pos[0]` or `lights[2].color[1]` (CSV / JSONL only).
+    pub columns: Vec<String>,
+}
+
+// ---------------------------------------------------------------------------
+// Readback RW Buffers types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ReadbackRwBuffersRequest {
+    pub capture_path: String,
+    /// Usually a compute dispatch; buffers are read after the event has executed.
+    pub event_id: u32,
+    /// First element decoded per buffer.
+    #[serde(default)]
+    pub first_element: u64,
+    /// Elements decoded per buffer.
+    pub max_elements: u32,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RwBufferContents {
+    pub stage: String,
+    /// Reflected binding name.
+    pub name: String,
+    /// Descriptor set (Vulkan) or register space (D3D).
+    pub set: u32,
+    pub binding: u32,
+    pub array_element: u32,
+    pub resource_id: u64,
+    pub resource_name: String,
+    /// Bound range within the buffer.
+    pub byte_offset: u64,
+    pub byte_size: u64,
+    /// `false` when the slot has no struct layout (e.g. typed buffers); see `error`.
+    pub decoded: bool,
+    pub stride: Option<u32>,
+    pub element_count: Option<u64>,
+    pub first_element: u64,
+    /// One object per element, keyed by flattened field path (`value`, `pos[0]`,
+    /// `lights[2].color[1]`, ...). Non-finite floats are the strings `"nan"`, `"inf"`, `"-inf"`.
+    #[schemars(schema_with = "any_json_schema::schema")]
+    pub elements: Vec<serde_json::Value>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ReadbackRwBuffersResponse {
+    pub capture_path: String,
+    pub event_id: u32,
+    pub buffers: Vec<RwBufferContents>,
+}
+
+// ---------------------------------------------------------------------------
+// Export Flipbook types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ExportFlipbookRequest {
+    pub capture_path: String,
+    pub output_dir: String,
+    pub basename: String,
+    pub marker_prefix: Option<String>,
+    pub event_id_min: Option<u32>,
+    pub event_id_max: Option<u32>,
+    pub name_contains: Option<String>,
+    pub marker_contains: Option<String>,
+    pub case_sensitive: bool,
+    /// Also save the depth target after each draw.
+    pub include_depth: bool,
+    /// Stop after this many draws.
+    pub max_frames: u32,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ExportFlipbookResponse {
+    pub capture_path: String,
+    /// `<basename>.flipbook.json`: every frame's event, name, marker path and image paths.
+    pub index_json_path: String,
+    pub total_matches: u64,
+    pub frame_count: u64,
+    /// More draws matched than `max_frames`.
+    pub truncated: bool,
+    pub first_event_id: Option<u32>,
+    pub last_event_id: Option<u32>,
+}
+
+/// Contents of `<basename>.flipbook.json`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FlipbookIndex {
+    pub capture_path: String,
+    pub frames: Vec<FlipbookFrame>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FlipbookFrame {
+    pub index: u32,
+    pub event_id: u32,
+    pub name: String,
+    pub marker_path: String,
+    pub rt0_resource_id: Option<u64>,
+    /// `None` when no colour target was bound at the draw.
+    pub rt0_path: Option<String>,
+    pub depth_path: Option<String>,
+}
+
+// ---------------------------------------------------------------------------
+// Render Thumbnail types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RenderThumbnailRequest {
+    pub capture_path: String,
+    pub event_id: u32,
+    /// PNG output path.
+    pub output_path: String,
+    /// Longest side of the thumbnail; smaller targets are not upscaled.
+    pub max_dim: u32,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RenderThumbnailResponse {
+    pub capture_path: String,
+    pub event_id: u32,
+    pub output_path: String,
+    /// RT0 at the event.
+    pub resource_id: u64,
+    pub source_width: u32,
+    pub source_height: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+// ---------------------------------------------------------------------------
+// Generate Report Markdown types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GenerateReportMarkdownRequest {
+    pub capture_path: String,
+    pub output_dir: String,
+    /// The report is written as `<basename>.report.md`.
+    pub basename: String,
+    /// Deepest marker level listed in the pass tree.
+    pub marker_depth: u32,
+    pub top_pipelines: u32,
+    pub top_targets: u32,
+    /// Save RT0 at the end of every top-level marker group into `<basename>.report_images/` and
+    /// embed the images in the report.
+    pub save_images: bool,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GenerateReportMarkdownResponse {
+    pub capture_path: String,
+    pub report_path: String,
+    pub images_dir: Option<String>,
+    pub image_count: u64,
+    pub total_draws: u64,
+    pub total_dispatches: u64,
+    pub pipeline_count: u64,
+}
+
+// ---------------------------------------------------------------------------
+// Generate Report HTML types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GenerateReportHtmlRequest {
+    pub capture_path: String,
+    pub output_dir: String,
+    /// The report is written as `<basename>.report.html`, next to the action/binding exports.
+    pub basename: String,
+    /// Longest side of the per-pass thumbnails.
+    pub thumbnail_max_dim: u32,
+    /// Action / binding rows embedded in the page (per export).
+    pub max_rows: u32,
+    #[serde(default)]
+    pub replay_options: Option<ReplayOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GenerateReportHtmlResponse {
+    pub capture_path: String,
+    /// Self-contained page: stats, marker tree, per-pass thumbnails and filterable tables.
+    pub report_path: String,
+    pub thumbnail_count: u64,
+    pub embedded_actions: u64,
+    pub embedded_bindings: u64,
+    /// More rows than `max_rows` were exported; the full data stays in the JSONL files.
+    pub truncated: bool,
+    pub actions_jsonl_path: String,
+    pub bindings_jsonl_path: String,
+}
+
+/// `find_events` / `export_actions_jsonl` request plus the parsed `query` for the script.
+#[derive(Debug, Serialize)]
+struct EventQueryScriptRequest<'a, T> {
+    #[serde(flatten)]
+    req: &'a T,
+    query_ast: Option<EventQuery>,
+}
+
+#[derive(Debug, Serialize)]
+struct GenerateReportHtmlScriptRequest<'a> {
+    #[serde(flatten)]
+    req: &'a GenerateReportHtmlRequest,
+    actions_jsonl_path: &'a str,
+    bindings_jsonl_path: &'a str,
+}
+
+#[derive(Debug, Error)]
+pub enum TriggerCaptureError {
+    #[error("failed to create artifacts dir: {0}")]
+    CreateArtifactsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to parse capture JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+    #[error("target control failed: {0}")]
+    TargetControl(Box<crate::TargetControlError>),
+}
+
+impl From<crate::TargetControlError> for TriggerCaptureError {
+    fn from(value: crate::TargetControlError) -> Self {
+        Self::TargetControl(Box::new(value))
+    }
+}
+
+impl From<crate::QRenderDocPythonError> for TriggerCaptureError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ExportActionsError {
+    #[error("failed to create output dir: {0}")]
+    CreateOutputDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to parse export JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+    #[error("invalid query: {0}")]
+    Query(#[from] QueryParseError),
+    #[error("failed to compress export: {0}")]
+    Compress(std::io::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum FindEventsError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+    #[error("invalid query: {0}")]
+    Query(#[from] QueryParseError),
+}
+
+impl From<crate::QRenderDocPythonError> for ExportActionsError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ExportBindingsIndexError {
+    #[error("failed to create output dir: {0}")]
+    CreateOutputDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to parse export JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+    #[error("failed to compress export: {0}")]
+    Compress(std::io::Error),
+}
+
+#[derive(Debug, Error)]
+pub enum ExportBundleError {
+    #[error("export actions failed: {0}")]
+    Actions(#[from] ExportActionsError),
+    #[error("export bindings index failed: {0}")]
+    Bindings(#[from] ExportBindingsIndexError),
+}
+
+fn remove_if_exists(path: &Path) -> Result<(), std::io::Error> {
+    match std::fs::remove_file(path) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Replace an exported file with `<path>.zst` and repoint `summary_key` in the summary JSON.
+/// Returns the new path. The uncompressed file is only removed once both writes succeeded.
+fn compress_export(
+    path: &str,
+    summary_json_path: &str,
+    summary_key: &str,
+) -> Result<String, std::io::Error> {
+    let zst_path = format!("{path}.zst");
+    if let Err(e) = encode_zstd_file(path, &zst_path) {
+        let _ = std::fs::remove_file(&zst_path);
+        return Err(e);
+    }
+
+    let mut summary: serde_json::Value = serde_json::from_slice(&std::fs::read(summary_json_path)?)
+        .map_err(std::io::Error::other)?;
+    if let Some(obj) = summary.as_object_mut() {
+        obj.insert(
+            summary_key.to_string(),
+            serde_json::Value::String(zst_path.clone()),
+        );
+    }
+    std::fs::write(
+        summary_json_path,
+        serde_json::to_vec_pretty(&summary).map_err(std::io::Error::other)?,
+    )?;
+    std::fs::remove_file(path)?;
+    Ok(zst_path)
+}
+
+#[cfg(feature = "zstd")]
+fn encode_zstd_file(path: &str, zst_path: &str) -> Result<(), std::io::Error> {
+    let mut input = std::fs::File::open(path)?;
+    let output = std::io::BufWriter::new(std::fs::File::create(zst_path)?);
+    let mut encoder = zstd::stream::write::Encoder::new(output, 0)?;
+    std::io::copy(&mut input, &mut encoder)?;
+    // Surface the final write errors: the source is deleted once this returns.
+    let file = encoder.finish()?.into_inner().map_err(|e| e.into_error())?;
+    file.sync_all()
+}
+
+#[cfg(not(feature = "zstd"))]
+fn encode_zstd_file(_path: &str, _zst_path: &str) -> Result<(), std::io::Error> {
+    Err(crate::artifacts::zstd_unsupported())
+}
+
+impl From<crate::QRenderDocPythonError> for ExportBindingsIndexError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+impl From<crate::QRenderDocPythonError> for FindEventsError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum GetEventsError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for GetEventsError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum GetMarkerTreeError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for GetMarkerTreeError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum GetCaptureMetadataError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for GetCaptureMetadataError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ListReplayGpusError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for ListReplayGpusError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ReadCaptureSectionsError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for ReadCaptureSectionsError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum WriteCaptureSectionError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for WriteCaptureSectionError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ValidateCaptureError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for ValidateCaptureError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum GetShaderDetailsError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for GetShaderDetailsError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum FindEventsByShaderError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for FindEventsByShaderError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum SearchShaderSourceError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for SearchShaderSourceError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ExportShaderSourcesError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for ExportShaderSourcesError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ExportShaderReflectionError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for ExportShaderReflectionError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ExportResourceLifetimesError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for ExportResourceLifetimesError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum GetBufferDetailsError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for GetBufferDetailsError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum GetTextureDetailsError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for GetTextureDetailsError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum GetBufferChangesDeltaError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for GetBufferChangesDeltaError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum GetTextureChangesDeltaError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for GetTextureChangesDeltaError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum GetPipelineDetailsError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for GetPipelineDetailsError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum FindEventsByPipelineError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for FindEventsByPipelineError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum GetPipelineBindingChangesDeltaError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for GetPipelineBindingChangesDeltaError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum GetEventPipelineStateError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for GetEventPipelineStateError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum GetEventApiCallError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for GetEventApiCallError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum GetResourceChangedEventIdsError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for GetResourceChangedEventIdsError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum SearchResourcesError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for SearchResourcesError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum FindResourceUsesError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for FindResourceUsesError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum GetVertexInputsError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for GetVertexInputsError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum GetIndicesError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for GetIndicesError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum FetchCountersError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for FetchCountersError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum GetTimestampQueriesError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for GetTimestampQueriesError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ExportChromeTraceError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for ExportChromeTraceError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum GetTextureStatsError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for GetTextureStatsError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ScanTargetsForInvalidValuesError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for ScanTargetsForInvalidValuesError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum CompareTexturesError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for CompareTexturesError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum DiffEventPipelineStateError {
+    #[error("get event pipeline state failed: {0}")]
+    GetEventPipelineState(Box<GetEventPipelineStateError>),
+    #[error("failed to convert pipeline state to JSON: {0}")]
+    ToJson(serde_json::Error),
+}
+
+impl From<GetEventPipelineStateError> for DiffEventPipelineStateError {
+    fn from(value: GetEventPipelineStateError) -> Self {
+        Self::GetEventPipelineState(Box::new(value))
+    }
+}
+
+/// Key used to match array elements between the two states, so that an inserted binding does
+/// not show up as every following binding changing.
+fn pipeline_element_key(value: &serde_json::Value) -> Option<String> {
+    let obj = value.as_object()?;
+    let field = |name: &str| {
+        obj.get(name).map(|v| match v {
+            serde_json::Value::String(s) => s.clone(),
+            other => other.to_string(),
+        })
+    };
+    match (field("stage"), field("set"), field("binding")) {
+        (Some(stage), Some(set), Some(binding)) => Some(format!(
+            "{stage}:{set}:{binding}:{}",
+            field("name").unwrap_or_default()
+        )),
+        (Some(stage), None, None) => Some(stage),
+        _ => None,
+    }
+}
+
+fn diff_pipeline_values(
+    path: &str,
+    a: Option<&serde_json::Value>,
+    b: Option<&serde_json::Value>,
+    out: &mut Vec<PipelineStateChange>,
+) {
+    use serde_json::Value;
+
+    match (a, b) {
+        (Some(Value::Object(ma)), Some(Value::Object(mb))) => {
+            let keys: std::collections::BTreeSet<&String> =
+                ma.keys().chain(mb.keys()).collect();
+            for key in keys {
+                let child = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+                diff_pipeline_values(&child, ma.get(key), mb.get(key), out);
+            }
+        }
+        (Some(Value::Array(va)), Some(Value::Array(vb))) => {
+            // Key matching needs every element keyed and each key unique within its side;
+            // descriptor arrays and unresolved resources (`-1:-1:unknown`) repeat keys.
+            fn unique_keys(items: &[Value]) -> Option<Vec<String>> {
+                let keys = items
+                    .iter()
+                    .map(pipeline_element_key)
+                    .collect::<Option<Vec<_>>>()?;
+                let distinct: std::collections::HashSet<&String> = keys.iter().collect();
+                (distinct.len() == keys.len()).then_some(keys)
+            }
+            if let (Some(ka), Some(kb)) = (unique_keys(va), unique_keys(vb)) {
+                let mut keys: Vec<&String> = Vec::new();
+                for k in ka.iter().chain(kb.iter()) {
+                    if !keys.contains(&k) {
+                        keys.push(k);
+                    }
+                }
+                fn find<'a>(
+                    items: &'a [Value],
+                    item_keys: &[String],
+                    k: &str,
+                ) -> Option<&'a Value> {
+                    item_keys.iter().position(|ik| ik == k).map(|i| &items[i])
+                }
+                for k in keys {
+                    let (ea, eb) = (find(va, &ka, k), find(vb, &kb, k));
+                    diff_pipeline_values(&format!("{path}[{k}]"), ea, eb, out);
+                }
+            } else {
+                for i in 0..va.len().max(vb.len()) {
+                    diff_pipeline_values(&format!("{path}[{i}]"), va.get(i), vb.get(i), out);
+                }
+            }
+        }
+        (a, b) if a == b => {}
+        (a, b) => out.push(PipelineStateChange {
+            path: path.to_string(),
+            a: a.cloned(),
+            b: b.cloned(),
+        }),
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ExportFrameGraphError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for ExportFrameGraphError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ExportRenderPassesError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for ExportRenderPassesError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ExportDrawStatsError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for ExportDrawStatsError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ExportApiCallStatsError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for ExportApiCallStatsError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum LintRedundantStateError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for LintRedundantStateError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum LintUnbalancedMarkersError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for LintUnbalancedMarkersError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum FindUnusedBindingsError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for FindUnusedBindingsError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum DiagnoseDrawError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for DiagnoseDrawError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum GetConstantsError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for GetConstantsError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum GetDescriptorArraysError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for GetDescriptorArraysError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum SaveTextureRawError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for SaveTextureRawError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum SaveDepthError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for SaveDepthError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum DumpBufferError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for DumpBufferError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ReadbackRwBuffersError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for ReadbackRwBuffersError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ExportFlipbookError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for ExportFlipbookError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum RenderThumbnailError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for RenderThumbnailError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum GenerateReportMarkdownError {
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for GenerateReportMarkdownError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum GenerateReportHtmlError {
+    #[error("export bundle failed: {0}")]
+    Bundle(#[from] ExportBundleError),
+    #[error("failed to create scripts dir: {0}")]
+    CreateScriptsDir(std::io::Error),
+    #[error("failed to write python script: {0}")]
+    WriteScript(std::io::Error),
+    #[error("failed to write request JSON: {0}")]
+    WriteRequest(std::io::Error),
+    #[error("qrenderdoc python failed: {0}")]
+    QRenderDocPython(Box<crate::QRenderDocPythonError>),
+    #[error("failed to read response JSON: {0}")]
+    ReadResponse(std::io::Error),
+    #[error("failed to parse JSON: {0}")]
+    ParseJson(serde_json::Error),
+    #[error("qrenderdoc script error: {0}")]
+    ScriptError(String),
+}
+
+impl From<crate::QRenderDocPythonError> for GenerateReportHtmlError {
+    fn from(value: crate::QRenderDocPythonError) -> Self {
+        Self::QRenderDocPython(Box::new(value))
+    }
+}
+
+impl RenderDocInstallation {
+    /// Trigger a capture over target control, through `qrenderdoc --python`
+    /// ([`RenderDocInstallation::trigger_capture_via_qrenderdoc`]).
+    pub fn trigger_capture_via_target_control(
+        &self,
+        cwd: &Path,
+        req: &TriggerCaptureRequest,
+    ) -> Result<TriggerCaptureResponse, TriggerCaptureError> {
+        self.trigger_capture_via_qrenderdoc(cwd, req)
+    }
+
+    /// Experimental opt-in: trigger a capture over the native target control client
+    /// ([`crate::trigger_capture_native`]), without spawning `qrenderdoc`.
+    ///
+    /// The native wire format is only tested against hand-written fixtures, not traffic from a
+    /// real RenderDoc target, and may not match every RenderDoc release. Falls back
+    /// to [`RenderDocInstallation::trigger_capture_via_qrenderdoc`] only if the target speaks a
+    /// protocol the native client does not understand.
+    pub fn trigger_capture_via_native_target_control(
+        &self,
+        cwd: &Path,
+        req: &TriggerCaptureRequest,
+    ) -> Result<TriggerCaptureResponse, TriggerCaptureError> {
+        match crate::trigger_capture_native(req) {
+            Err(e) if e.is_protocol_mismatch() => self.trigger_capture_via_qrenderdoc(cwd, req),
+            res => Ok(res?),
+        }
+    }
+
+    /// Trigger a capture through `qrenderdoc --python` (`rd.CreateTargetControl`).
+    pub fn trigger_capture_via_qrenderdoc(
+        &self,
+        cwd: &Path,
+        req: &TriggerCaptureRequest,
+    ) -> Result<TriggerCaptureResponse, TriggerCaptureError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir).map_err(TriggerCaptureError::CreateArtifactsDir)?;
+
+        let script_path = scripts_dir.join("trigger_capture.py");
+        write_script_file(&script_path, TRIGGER_CAPTURE_PY)
+            .map_err(TriggerCaptureError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "trigger_capture")
+            .map_err(TriggerCaptureError::CreateArtifactsDir)?;
+        let request_path = run_dir.join("trigger_capture.request.json");
+        let response_path = run_dir.join("trigger_capture.response.json");
+        remove_if_exists(&response_path).map_err(TriggerCaptureError::WriteRequest)?;
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(req).map_err(TriggerCaptureError::ParseJson)?,
+        )
+        .map_err(TriggerCaptureError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python(&QRenderDocPythonRequest {
+            script_path: script_path.clone(),
+            args: Vec::new(),
+            working_dir: Some(run_dir.clone()),
+        })?;
+        let _ = result;
+        let bytes = std::fs::read(&response_path).map_err(TriggerCaptureError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<TriggerCaptureResponse> =
+            serde_json::from_slice(&bytes).map_err(TriggerCaptureError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| TriggerCaptureError::ScriptError("missing result".into()))
+        } else {
+            Err(TriggerCaptureError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    pub fn export_actions_jsonl(
+        &self,
+        cwd: &Path,
+        req: &ExportActionsRequest,
+    ) -> Result<ExportActionsResponse, ExportActionsError> {
+        self.export_actions_jsonl_in(None, cwd, req)
+    }
+
+    pub(crate) fn export_actions_jsonl_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &ExportActionsRequest,
+    ) -> Result<ExportActionsResponse, ExportActionsError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir).map_err(ExportActionsError::CreateOutputDir)?;
+
+        let script_path = scripts_dir.join("export_actions_jsonl.py");
+        write_script_file(&script_path, EXPORT_ACTIONS_JSONL_PY)
+            .map_err(ExportActionsError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "export_actions_jsonl")
+            .map_err(ExportActionsError::CreateOutputDir)?;
+        let request_path = run_dir.join("export_actions_jsonl.request.json");
+        let response_path = run_dir.join("export_actions_jsonl.response.json");
+        remove_if_exists(&response_path).map_err(ExportActionsError::WriteRequest)?;
+
+        let req = ExportActionsRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            output_dir: resolve_path_string_from_cwd(cwd, &req.output_dir),
+            ..req.clone()
+        };
+        let query_ast = req.query.as_deref().map(EventQuery::parse).transpose()?;
+        if req.compress && !cfg!(feature = "zstd") {
+            return Err(ExportActionsError::Compress(crate::artifacts::zstd_unsupported()));
+        }
+
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&EventQueryScriptRequest {
+                req: &req,
+                query_ast,
+            })
+            .map_err(ExportActionsError::ParseJson)?,
+        )
+        .map_err(ExportActionsError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+        let _ = result;
+        let bytes = std::fs::read(&response_path).map_err(ExportActionsError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<ExportActionsResponse> =
+            serde_json::from_slice(&bytes).map_err(ExportActionsError::ParseJson)?;
+        if env.ok {
+            let mut res = env
+                .result
+                .ok_or_else(|| ExportActionsError::ScriptError("missing result".into()))?;
+            if req.compress {
+                res.actions_path =
+                    compress_export(&res.actions_path, &res.summary_json_path, "actions_path")
+                        .map_err(ExportActionsError::Compress)?;
+            }
+            Ok(res)
+        } else {
+            Err(ExportActionsError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    pub fn find_events(
+        &self,
+        cwd: &Path,
+        req: &FindEventsRequest,
+    ) -> Result<FindEventsResponse, FindEventsError> {
+        self.find_events_in(None, cwd, req)
+    }
+
+    pub(crate) fn find_events_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &FindEventsRequest,
+    ) -> Result<FindEventsResponse, FindEventsError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir).map_err(FindEventsError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("find_events_json.py");
+        write_script_file(&script_path, FIND_EVENTS_JSON_PY)
+            .map_err(FindEventsError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "find_events")
+            .map_err(FindEventsError::CreateScriptsDir)?;
+        let request_path = run_dir.join("find_events_json.request.json");
+        let response_path = run_dir.join("find_events_json.response.json");
+        remove_if_exists(&response_path).map_err(FindEventsError::WriteRequest)?;
+
+        let req = FindEventsRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            ..req.clone()
+        };
+        let query_ast = req.query.as_deref().map(EventQuery::parse).transpose()?;
+
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&EventQueryScriptRequest {
+                req: &req,
+                query_ast,
+            })
+            .map_err(FindEventsError::ParseJson)?,
+        )
+        .map_err(FindEventsError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+        let _ = result;
+
+        let bytes = std::fs::read(&response_path).map_err(FindEventsError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<FindEventsResponse> =
+            serde_json::from_slice(&bytes).map_err(FindEventsError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| FindEventsError::ScriptError("missing result".into()))
+        } else {
+            Err(FindEventsError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    pub fn get_events(
+        &self,
+        cwd: &Path,
+        req: &GetEventsRequest,
+    ) -> Result<GetEventsResponse, GetEventsError> {
+        self.get_events_in(None, cwd, req)
+    }
+
+    pub(crate) fn get_events_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &GetEventsRequest,
+    ) -> Result<GetEventsResponse, GetEventsError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir).map_err(GetEventsError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("get_events_json.py");
+        write_script_file(&script_path, GET_EVENTS_JSON_PY)
+            .map_err(GetEventsError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "get_events")
+            .map_err(GetEventsError::CreateScriptsDir)?;
+        let request_path = run_dir.join("get_events_json.request.json");
+        let response_path = run_dir.join("get_events_json.response.json");
+        remove_if_exists(&response_path).map_err(GetEventsError::WriteRequest)?;
+
+        let req = GetEventsRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            replay_options: req.replay_options.clone(),
+        };
+
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(GetEventsError::ParseJson)?,
+        )
+        .map_err(GetEventsError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+        let _ = result;
+
+        let bytes = std::fs::read(&response_path).map_err(GetEventsError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<GetEventsResponse> =
+            serde_json::from_slice(&bytes).map_err(GetEventsError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| GetEventsError::ScriptError("missing result".into()))
+        } else {
+            Err(GetEventsError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    /// Nested debug marker regions with per-node child, draw and dispatch counts (the JSONL
+    /// exports flatten this to marker paths).
+    pub fn get_marker_tree(
+        &self,
+        cwd: &Path,
+        req: &GetMarkerTreeRequest,
+    ) -> Result<GetMarkerTreeResponse, GetMarkerTreeError> {
+        self.get_marker_tree_in(None, cwd, req)
+    }
+
+    pub(crate) fn get_marker_tree_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &GetMarkerTreeRequest,
+    ) -> Result<GetMarkerTreeResponse, GetMarkerTreeError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir).map_err(GetMarkerTreeError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("get_marker_tree_json.py");
+        write_script_file(&script_path, GET_MARKER_TREE_JSON_PY)
+            .map_err(GetMarkerTreeError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "get_marker_tree")
+            .map_err(GetMarkerTreeError::CreateScriptsDir)?;
+        let request_path = run_dir.join("get_marker_tree_json.request.json");
+        let response_path = run_dir.join("get_marker_tree_json.response.json");
+        remove_if_exists(&response_path).map_err(GetMarkerTreeError::WriteRequest)?;
+
+        let req = GetMarkerTreeRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            ..req.clone()
+        };
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(GetMarkerTreeError::ParseJson)?,
+        )
+        .map_err(GetMarkerTreeError::WriteRequest)?;
+
+        self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+
+        let bytes = std::fs::read(&response_path).map_err(GetMarkerTreeError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<GetMarkerTreeResponse> =
+            serde_json::from_slice(&bytes).map_err(GetMarkerTreeError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| GetMarkerTreeError::ScriptError("missing result".into()))
+        } else {
+            Err(GetMarkerTreeError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    /// API, driver, GPU, frame number, capture time and sizes of a capture, read by opening it for
+    /// replay (complements the RenderDoc-free [`crate::capture_metadata`]).
+    pub fn get_capture_metadata(
+        &self,
+        cwd: &Path,
+        req: &GetCaptureMetadataRequest,
+    ) -> Result<GetCaptureMetadataResponse, GetCaptureMetadataError> {
+        self.get_capture_metadata_in(None, cwd, req)
+    }
+
+    pub(crate) fn get_capture_metadata_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &GetCaptureMetadataRequest,
+    ) -> Result<GetCaptureMetadataResponse, GetCaptureMetadataError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir).map_err(GetCaptureMetadataError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("get_capture_metadata_json.py");
+        write_script_file(&script_path, GET_CAPTURE_METADATA_JSON_PY)
+            .map_err(GetCaptureMetadataError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "get_capture_metadata")
+            .map_err(GetCaptureMetadataError::CreateScriptsDir)?;
+        let request_path = run_dir.join("get_capture_metadata_json.request.json");
+        let response_path = run_dir.join("get_capture_metadata_json.response.json");
+        remove_if_exists(&response_path).map_err(GetCaptureMetadataError::WriteRequest)?;
+
+        let req = GetCaptureMetadataRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            replay_options: req.replay_options.clone(),
+        };
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(GetCaptureMetadataError::ParseJson)?,
+        )
+        .map_err(GetCaptureMetadataError::WriteRequest)?;
+
+        self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+
+        let bytes = std::fs::read(&response_path).map_err(GetCaptureMetadataError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<GetCaptureMetadataResponse> =
+            serde_json::from_slice(&bytes).map_err(GetCaptureMetadataError::ParseJson)?;
+        if env.ok {
+            let mut res = env
+                .result
+                .ok_or_else(|| GetCaptureMetadataError::ScriptError("missing result".into()))?;
+            res.program_version = crate::capture_metadata(Path::new(&req.capture_path))
+                .ok()
+                .map(|meta| meta.program_version);
+            Ok(res)
+        } else {
+            Err(GetCaptureMetadataError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    /// List the adapters RenderDoc can replay `req.capture_path` on, without starting a replay.
+    pub fn list_replay_gpus(
+        &self,
+        cwd: &Path,
+        req: &ListReplayGpusRequest,
+    ) -> Result<ListReplayGpusResponse, ListReplayGpusError> {
+        self.list_replay_gpus_in(None, cwd, req)
+    }
+
+    pub(crate) fn list_replay_gpus_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &ListReplayGpusRequest,
+    ) -> Result<ListReplayGpusResponse, ListReplayGpusError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir).map_err(ListReplayGpusError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("list_replay_gpus_json.py");
+        write_script_file(&script_path, LIST_REPLAY_GPUS_JSON_PY)
+            .map_err(ListReplayGpusError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "list_replay_gpus")
+            .map_err(ListReplayGpusError::CreateScriptsDir)?;
+        let request_path = run_dir.join("list_replay_gpus_json.request.json");
+        let response_path = run_dir.join("list_replay_gpus_json.response.json");
+        remove_if_exists(&response_path).map_err(ListReplayGpusError::WriteRequest)?;
+
+        let req = ListReplayGpusRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+        };
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(ListReplayGpusError::ParseJson)?,
+        )
+        .map_err(ListReplayGpusError::WriteRequest)?;
+
+        self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+
+        let bytes = std::fs::read(&response_path).map_err(ListReplayGpusError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<ListReplayGpusResponse> =
+            serde_json::from_slice(&bytes).map_err(ListReplayGpusError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| ListReplayGpusError::ScriptError("missing result".into()))
+        } else {
+            Err(ListReplayGpusError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    /// Read named sections (notes, custom metadata, ...) of a capture via the CaptureFile API.
+    pub fn read_capture_sections(
+        &self,
+        cwd: &Path,
+        req: &ReadCaptureSectionsRequest,
+    ) -> Result<ReadCaptureSectionsResponse, ReadCaptureSectionsError> {
+        self.read_capture_sections_in(None, cwd, req)
+    }
+
+    pub(crate) fn read_capture_sections_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &ReadCaptureSectionsRequest,
+    ) -> Result<ReadCaptureSectionsResponse, ReadCaptureSectionsError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir)
+            .map_err(ReadCaptureSectionsError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("read_capture_sections_json.py");
+        write_script_file(&script_path, READ_CAPTURE_SECTIONS_JSON_PY)
+            .map_err(ReadCaptureSectionsError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "read_capture_sections")
+            .map_err(ReadCaptureSectionsError::CreateScriptsDir)?;
+        let request_path = run_dir.join("read_capture_sections_json.request.json");
+        let response_path = run_dir.join("read_capture_sections_json.response.json");
+        remove_if_exists(&response_path).map_err(ReadCaptureSectionsError::WriteRequest)?;
+
+        let req = ReadCaptureSectionsRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            ..req.clone()
+        };
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(ReadCaptureSectionsError::ParseJson)?,
+        )
+        .map_err(ReadCaptureSectionsError::WriteRequest)?;
+
+        self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+
+        let bytes =
+            std::fs::read(&response_path).map_err(ReadCaptureSectionsError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<ReadCaptureSectionsResponse> =
+            serde_json::from_slice(&bytes).map_err(ReadCaptureSectionsError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| ReadCaptureSectionsError::ScriptError("missing result".into()))
+        } else {
+            Err(ReadCaptureSectionsError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    /// Embed a named text section (or the capture notes) into a capture, in place.
+    pub fn write_capture_section(
+        &self,
+        cwd: &Path,
+        req: &WriteCaptureSectionRequest,
+    ) -> Result<WriteCaptureSectionResponse, WriteCaptureSectionError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir)
+            .map_err(WriteCaptureSectionError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("write_capture_section_json.py");
+        write_script_file(&script_path, WRITE_CAPTURE_SECTION_JSON_PY)
+            .map_err(WriteCaptureSectionError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "write_capture_section")
+            .map_err(WriteCaptureSectionError::CreateScriptsDir)?;
+        let request_path = run_dir.join("write_capture_section_json.request.json");
+        let response_path = run_dir.join("write_capture_section_json.response.json");
+        remove_if_exists(&response_path).map_err(WriteCaptureSectionError::WriteRequest)?;
+
+        let req = WriteCaptureSectionRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            ..req.clone()
+        };
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(WriteCaptureSectionError::ParseJson)?,
+        )
+        .map_err(WriteCaptureSectionError::WriteRequest)?;
+
+        self.run_qrenderdoc_python(&QRenderDocPythonRequest {
+            script_path: script_path.clone(),
+            args: Vec::new(),
+            working_dir: Some(run_dir.clone()),
+        })?;
+
+        let bytes =
+            std::fs::read(&response_path).map_err(WriteCaptureSectionError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<WriteCaptureSectionResponse> =
+            serde_json::from_slice(&bytes).map_err(WriteCaptureSectionError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| WriteCaptureSectionError::ScriptError("missing result".into()))
+        } else {
+            Err(WriteCaptureSectionError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    /// Check that a capture is intact and replayable: container header, sections, replay
+    /// initialization, action enumeration and a full frame replay, plus any debug messages.
+    /// Validation failures are reported in the result rather than as errors.
+    pub fn validate_capture(
+        &self,
+        cwd: &Path,
+        req: &ValidateCaptureRequest,
+    ) -> Result<ValidateCaptureResponse, ValidateCaptureError> {
+        self.validate_capture_in(None, cwd, req)
+    }
+
+    pub(crate) fn validate_capture_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &ValidateCaptureRequest,
+    ) -> Result<ValidateCaptureResponse, ValidateCaptureError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir).map_err(ValidateCaptureError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("validate_capture_json.py");
+        write_script_file(&script_path, VALIDATE_CAPTURE_JSON_PY)
+            .map_err(ValidateCaptureError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "validate_capture")
+            .map_err(ValidateCaptureError::CreateScriptsDir)?;
+        let request_path = run_dir.join("validate_capture_json.request.json");
+        let response_path = run_dir.join("validate_capture_json.response.json");
+        remove_if_exists(&response_path).map_err(ValidateCaptureError::WriteRequest)?;
+
+        let req = ValidateCaptureRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            replay_options: req.replay_options.clone(),
+        };
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(ValidateCaptureError::ParseJson)?,
+        )
+        .map_err(ValidateCaptureError::WriteRequest)?;
+
+        self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+
+        let bytes = std::fs::read(&response_path).map_err(ValidateCaptureError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<ValidateCaptureResponse> =
+            serde_json::from_slice(&bytes).map_err(ValidateCaptureError::ParseJson)?;
+        if env.ok {
+            let mut res = env
+                .result
+                .ok_or_else(|| ValidateCaptureError::ScriptError("missing result".into()))?;
+            let header = match crate::capture_metadata(Path::new(&req.capture_path)) {
+                Ok(meta) => ValidationCheck {
+                    name: "container_header".into(),
+                    ok: true,
+                    detail: Some(format!("written by RenderDoc {}", meta.program_version)),
+                },
+                Err(e) => ValidationCheck {
+                    name: "container_header".into(),
+                    ok: false,
+                    detail: Some(e.to_string()),
+                },
+            };
+            res.checks.insert(0, header);
+            res.valid = res.checks.iter().all(|c| c.ok);
+            Ok(res)
+        } else {
+            Err(ValidateCaptureError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    pub fn get_shader_details(
+        &self,
+        cwd: &Path,
+        req: &GetShaderDetailsRequest,
+    ) -> Result<GetShaderDetailsResponse, GetShaderDetailsError> {
+        self.get_shader_details_in(None, cwd, req)
+    }
+
+    pub(crate) fn get_shader_details_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &GetShaderDetailsRequest,
+    ) -> Result<GetShaderDetailsResponse, GetShaderDetailsError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir).map_err(GetShaderDetailsError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("get_shader_details_json.py");
+        write_script_file(&script_path, GET_SHADER_DETAILS_JSON_PY)
+            .map_err(GetShaderDetailsError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "get_shader_details")
+            .map_err(GetShaderDetailsError::CreateScriptsDir)?;
+        let request_path = run_dir.join("get_shader_details_json.request.json");
+        let response_path = run_dir.join("get_shader_details_json.response.json");
+        remove_if_exists(&response_path).map_err(GetShaderDetailsError::WriteRequest)?;
+
+        let req = GetShaderDetailsRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            pipeline_name: req.pipeline_name.clone(),
+            entry_points: req.entry_points.clone(),
+            replay_options: req.replay_options.clone(),
+        };
+
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(GetShaderDetailsError::ParseJson)?,
+        )
+        .map_err(GetShaderDetailsError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+        let _ = result;
+
+        let bytes = std::fs::read(&response_path).map_err(GetShaderDetailsError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<GetShaderDetailsResponse> =
+            serde_json::from_slice(&bytes).map_err(GetShaderDetailsError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| GetShaderDetailsError::ScriptError("missing result".into()))
+        } else {
+            Err(GetShaderDetailsError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    pub fn find_events_by_shader(
+        &self,
+        cwd: &Path,
+        req: &FindEventsByShaderRequest,
+    ) -> Result<FindEventsByShaderResponse, FindEventsByShaderError> {
+        self.find_events_by_shader_in(None, cwd, req)
+    }
+
+    pub(crate) fn find_events_by_shader_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &FindEventsByShaderRequest,
+    ) -> Result<FindEventsByShaderResponse, FindEventsByShaderError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir).map_err(FindEventsByShaderError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("find_events_by_shader_json.py");
+        write_script_file(&script_path, FIND_EVENTS_BY_SHADER_JSON_PY)
+            .map_err(FindEventsByShaderError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "find_events_by_shader")
+            .map_err(FindEventsByShaderError::CreateScriptsDir)?;
+        let request_path = run_dir.join("find_events_by_shader_json.request.json");
+        let response_path = run_dir.join("find_events_by_shader_json.response.json");
+        remove_if_exists(&response_path).map_err(FindEventsByShaderError::WriteRequest)?;
+
+        let req = FindEventsByShaderRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            ..req.clone()
+        };
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(FindEventsByShaderError::ParseJson)?,
+        )
+        .map_err(FindEventsByShaderError::WriteRequest)?;
+
+        self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+
+        let bytes = std::fs::read(&response_path).map_err(FindEventsByShaderError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<FindEventsByShaderResponse> =
+            serde_json::from_slice(&bytes).map_err(FindEventsByShaderError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| FindEventsByShaderError::ScriptError("missing result".into()))
+        } else {
+            Err(FindEventsByShaderError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    pub fn search_shader_source(
+        &self,
+        cwd: &Path,
+        req: &SearchShaderSourceRequest,
+    ) -> Result<SearchShaderSourceResponse, SearchShaderSourceError> {
+        self.search_shader_source_in(None, cwd, req)
+    }
+
+    pub(crate) fn search_shader_source_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &SearchShaderSourceRequest,
+    ) -> Result<SearchShaderSourceResponse, SearchShaderSourceError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir).map_err(SearchShaderSourceError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("search_shader_source_json.py");
+        write_script_file(&script_path, SEARCH_SHADER_SOURCE_JSON_PY)
+            .map_err(SearchShaderSourceError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "search_shader_source")
+            .map_err(SearchShaderSourceError::CreateScriptsDir)?;
+        let request_path = run_dir.join("search_shader_source_json.request.json");
+        let response_path = run_dir.join("search_shader_source_json.response.json");
+        remove_if_exists(&response_path).map_err(SearchShaderSourceError::WriteRequest)?;
+
+        let req = SearchShaderSourceRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            ..req.clone()
+        };
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(SearchShaderSourceError::ParseJson)?,
+        )
+        .map_err(SearchShaderSourceError::WriteRequest)?;
+
+        self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+
+        let bytes = std::fs::read(&response_path).map_err(SearchShaderSourceError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<SearchShaderSourceResponse> =
+            serde_json::from_slice(&bytes).map_err(SearchShaderSourceError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| SearchShaderSourceError::ScriptError("missing result".into()))
+        } else {
+            Err(SearchShaderSourceError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    pub fn export_shader_sources(
+        &self,
+        cwd: &Path,
+        req: &ExportShaderSourcesRequest,
+    ) -> Result<ExportShaderSourcesResponse, ExportShaderSourcesError> {
+        self.export_shader_sources_in(None, cwd, req)
+    }
+
+    pub(crate) fn export_shader_sources_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &ExportShaderSourcesRequest,
+    ) -> Result<ExportShaderSourcesResponse, ExportShaderSourcesError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir)
+            .map_err(ExportShaderSourcesError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("export_shader_sources_json.py");
+        write_script_file(&script_path, EXPORT_SHADER_SOURCES_JSON_PY)
+            .map_err(ExportShaderSourcesError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "export_shader_sources")
+            .map_err(ExportShaderSourcesError::CreateScriptsDir)?;
+        let request_path = run_dir.join("export_shader_sources_json.request.json");
+        let response_path = run_dir.join("export_shader_sources_json.response.json");
+        remove_if_exists(&response_path).map_err(ExportShaderSourcesError::WriteRequest)?;
+
+        let req = ExportShaderSourcesRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            output_dir: resolve_path_string_from_cwd(cwd, &req.output_dir),
+            replay_options: req.replay_options.clone(),
+        };
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(ExportShaderSourcesError::ParseJson)?,
+        )
+        .map_err(ExportShaderSourcesError::WriteRequest)?;
+
+        self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+
+        let bytes =
+            std::fs::read(&response_path).map_err(ExportShaderSourcesError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<ExportShaderSourcesResponse> =
+            serde_json::from_slice(&bytes).map_err(ExportShaderSourcesError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| ExportShaderSourcesError::ScriptError("missing result".into()))
+        } else {
+            Err(ExportShaderSourcesError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    pub fn export_shader_reflection(
+        &self,
+        cwd: &Path,
+        req: &ExportShaderReflectionRequest,
+    ) -> Result<ExportShaderReflectionResponse, ExportShaderReflectionError> {
+        self.export_shader_reflection_in(None, cwd, req)
+    }
+
+    pub(crate) fn export_shader_reflection_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &ExportShaderReflectionRequest,
+    ) -> Result<ExportShaderReflectionResponse, ExportShaderReflectionError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir)
+            .map_err(ExportShaderReflectionError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("export_shader_reflection_json.py");
+        write_script_file(&script_path, EXPORT_SHADER_REFLECTION_JSON_PY)
+            .map_err(ExportShaderReflectionError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "export_shader_reflection")
+            .map_err(ExportShaderReflectionError::CreateScriptsDir)?;
+        let request_path = run_dir.join("export_shader_reflection_json.request.json");
+        let response_path = run_dir.join("export_shader_reflection_json.response.json");
+        remove_if_exists(&response_path).map_err(ExportShaderReflectionError::WriteRequest)?;
+
+        let req = ExportShaderReflectionRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            output_dir: resolve_path_string_from_cwd(cwd, &req.output_dir),
+            ..req.clone()
+        };
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(ExportShaderReflectionError::ParseJson)?,
+        )
+        .map_err(ExportShaderReflectionError::WriteRequest)?;
+
+        self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+
+        let bytes =
+            std::fs::read(&response_path).map_err(ExportShaderReflectionError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<ExportShaderReflectionResponse> =
+            serde_json::from_slice(&bytes).map_err(ExportShaderReflectionError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| ExportShaderReflectionError::ScriptError("missing result".into()))
+        } else {
+            Err(ExportShaderReflectionError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    /// Creation, destruction and first/last use of every resource, to spot leaks and single-use
+    /// allocations.
+    pub fn export_resource_lifetimes(
+        &self,
+        cwd: &Path,
+        req: &ExportResourceLifetimesRequest,
+    ) -> Result<ExportResourceLifetimesResponse, ExportResourceLifetimesError> {
+        self.export_resource_lifetimes_in(None, cwd, req)
+    }
+
+    pub(crate) fn export_resource_lifetimes_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &ExportResourceLifetimesRequest,
+    ) -> Result<ExportResourceLifetimesResponse, ExportResourceLifetimesError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir)
+            .map_err(ExportResourceLifetimesError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("export_resource_lifetimes_json.py");
+        write_script_file(&script_path, EXPORT_RESOURCE_LIFETIMES_JSON_PY)
+            .map_err(ExportResourceLifetimesError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "export_resource_lifetimes")
+            .map_err(ExportResourceLifetimesError::CreateScriptsDir)?;
+        let request_path = run_dir.join("export_resource_lifetimes_json.request.json");
+        let response_path = run_dir.join("export_resource_lifetimes_json.response.json");
+        remove_if_exists(&response_path).map_err(ExportResourceLifetimesError::WriteRequest)?;
+
+        let req = ExportResourceLifetimesRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            output_dir: resolve_path_string_from_cwd(cwd, &req.output_dir),
+            ..req.clone()
+        };
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(ExportResourceLifetimesError::ParseJson)?,
+        )
+        .map_err(ExportResourceLifetimesError::WriteRequest)?;
+
+        self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+
+        let bytes =
+            std::fs::read(&response_path).map_err(ExportResourceLifetimesError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<ExportResourceLifetimesResponse> =
+            serde_json::from_slice(&bytes).map_err(ExportResourceLifetimesError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| ExportResourceLifetimesError::ScriptError("missing result".into()))
+        } else {
+            Err(ExportResourceLifetimesError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    pub fn get_buffer_details(
+        &self,
+        cwd: &Path,
+        req: &GetBufferDetailsRequest,
+    ) -> Result<GetBufferDetailsResponse, GetBufferDetailsError> {
+        self.get_buffer_details_in(None, cwd, req)
+    }
+
+    pub(crate) fn get_buffer_details_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &GetBufferDetailsRequest,
+    ) -> Result<GetBufferDetailsResponse, GetBufferDetailsError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir)
+            .map_err(GetBufferDetailsError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("get_buffer_details_json.py");
+        write_script_file(&script_path, GET_BUFFER_DETAILS_JSON_PY)
+            .map_err(GetBufferDetailsError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "get_buffer_details")
+            .map_err(GetBufferDetailsError::CreateScriptsDir)?;
+        let request_path = run_dir.join("get_buffer_details_json.request.json");
+        let response_path = run_dir.join("get_buffer_details_json.response.json");
+        remove_if_exists(&response_path).map_err(GetBufferDetailsError::WriteRequest)?;
+
+        let req = GetBufferDetailsRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            buffer_name: req.buffer_name.clone(),
+            replay_options: req.replay_options.clone(),
+        };
+
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(GetBufferDetailsError::ParseJson)?,
+        )
+        .map_err(GetBufferDetailsError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+        let _ = result;
+
+        let bytes =
+            std::fs::read(&response_path).map_err(GetBufferDetailsError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<GetBufferDetailsResponse> =
+            serde_json::from_slice(&bytes).map_err(GetBufferDetailsError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| GetBufferDetailsError::ScriptError("missing result".into()))
+        } else {
+            Err(GetBufferDetailsError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    pub fn get_texture_details(
+        &self,
+        cwd: &Path,
+        req: &GetTextureDetailsRequest,
+    ) -> Result<GetTextureDetailsResponse, GetTextureDetailsError> {
+        self.get_texture_details_in(None, cwd, req)
+    }
+
+    pub(crate) fn get_texture_details_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &GetTextureDetailsRequest,
+    ) -> Result<GetTextureDetailsResponse, GetTextureDetailsError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir)
+            .map_err(GetTextureDetailsError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("get_texture_details_json.py");
+        write_script_file(&script_path, GET_TEXTURE_DETAILS_JSON_PY)
+            .map_err(GetTextureDetailsError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "get_texture_details")
+            .map_err(GetTextureDetailsError::CreateScriptsDir)?;
+        let request_path = run_dir.join("get_texture_details_json.request.json");
+        let response_path = run_dir.join("get_texture_details_json.response.json");
+        remove_if_exists(&response_path).map_err(GetTextureDetailsError::WriteRequest)?;
+
+        let req = GetTextureDetailsRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            texture_name: req.texture_name.clone(),
+            replay_options: req.replay_options.clone(),
+        };
+
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(GetTextureDetailsError::ParseJson)?,
+        )
+        .map_err(GetTextureDetailsError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+        let _ = result;
+
+        let bytes =
+            std::fs::read(&response_path).map_err(GetTextureDetailsError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<GetTextureDetailsResponse> =
+            serde_json::from_slice(&bytes).map_err(GetTextureDetailsError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| GetTextureDetailsError::ScriptError("missing result".into()))
+        } else {
+            Err(GetTextureDetailsError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    pub fn get_buffer_changes_delta(
+        &self,
+        cwd: &Path,
+        req: &GetBufferChangesDeltaRequest,
+    ) -> Result<GetBufferChangesDeltaResponse, GetBufferChangesDeltaError> {
+        self.get_buffer_changes_delta_in(None, cwd, req)
+    }
+
+    pub(crate) fn get_buffer_changes_delta_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &GetBufferChangesDeltaRequest,
+    ) -> Result<GetBufferChangesDeltaResponse, GetBufferChangesDeltaError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir)
+            .map_err(GetBufferChangesDeltaError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("get_buffer_changes_delta_json.py");
+        write_script_file(&script_path, GET_BUFFER_CHANGES_DELTA_JSON_PY)
+            .map_err(GetBufferChangesDeltaError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "get_buffer_changes_delta")
+            .map_err(GetBufferChangesDeltaError::CreateScriptsDir)?;
+        let request_path = run_dir.join("get_buffer_changes_delta_json.request.json");
+        let response_path = run_dir.join("get_buffer_changes_delta_json.response.json");
+        remove_if_exists(&response_path).map_err(GetBufferChangesDeltaError::WriteRequest)?;
+
+        let req = GetBufferChangesDeltaRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            buffer_name: req.buffer_name.clone(),
+            tracked_indices: req.tracked_indices.clone(),
+            replay_options: req.replay_options.clone(),
+        };
+
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(GetBufferChangesDeltaError::ParseJson)?,
+        )
+        .map_err(GetBufferChangesDeltaError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+        let _ = result;
+
+        let bytes =
+            std::fs::read(&response_path).map_err(GetBufferChangesDeltaError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<GetBufferChangesDeltaResponse> =
+            serde_json::from_slice(&bytes).map_err(GetBufferChangesDeltaError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| GetBufferChangesDeltaError::ScriptError("missing result".into()))
+        } else {
+            Err(GetBufferChangesDeltaError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    pub fn get_texture_changes_delta(
+        &self,
+        cwd: &Path,
+        req: &GetTextureChangesDeltaRequest,
+    ) -> Result<GetTextureChangesDeltaResponse, GetTextureChangesDeltaError> {
+        self.get_texture_changes_delta_in(None, cwd, req)
+    }
+
+    pub(crate) fn get_texture_changes_delta_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &GetTextureChangesDeltaRequest,
+    ) -> Result<GetTextureChangesDeltaResponse, GetTextureChangesDeltaError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir)
+            .map_err(GetTextureChangesDeltaError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("get_texture_changes_delta_json.py");
+        write_script_file(&script_path, GET_TEXTURE_CHANGES_DELTA_JSON_PY)
+            .map_err(GetTextureChangesDeltaError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "get_texture_changes_delta")
+            .map_err(GetTextureChangesDeltaError::CreateScriptsDir)?;
+        let request_path = run_dir.join("get_texture_changes_delta_json.request.json");
+        let response_path = run_dir.join("get_texture_changes_delta_json.response.json");
+        remove_if_exists(&response_path).map_err(GetTextureChangesDeltaError::WriteRequest)?;
+
+        let req = GetTextureChangesDeltaRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            texture_name: req.texture_name.clone(),
+            tracked_texels: req.tracked_texels.clone(),
+            replay_options: req.replay_options.clone(),
+        };
+
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(GetTextureChangesDeltaError::ParseJson)?,
+        )
+        .map_err(GetTextureChangesDeltaError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+        let _ = result;
+
+        let bytes =
+            std::fs::read(&response_path).map_err(GetTextureChangesDeltaError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<GetTextureChangesDeltaResponse> =
+            serde_json::from_slice(&bytes).map_err(GetTextureChangesDeltaError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| GetTextureChangesDeltaError::ScriptError("missing result".into()))
+        } else {
+            Err(GetTextureChangesDeltaError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    pub fn get_pipeline_details(
+        &self,
+        cwd: &Path,
+        req: &GetPipelineDetailsRequest,
+    ) -> Result<GetPipelineDetailsResponse, GetPipelineDetailsError> {
+        self.get_pipeline_details_in(None, cwd, req)
+    }
+
+    pub(crate) fn get_pipeline_details_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &GetPipelineDetailsRequest,
+    ) -> Result<GetPipelineDetailsResponse, GetPipelineDetailsError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir)
+            .map_err(GetPipelineDetailsError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("get_pipeline_details_json.py");
+        write_script_file(&script_path, GET_PIPELINE_DETAILS_JSON_PY)
+            .map_err(GetPipelineDetailsError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "get_pipeline_details")
+            .map_err(GetPipelineDetailsError::CreateScriptsDir)?;
+        let request_path = run_dir.join("get_pipeline_details_json.request.json");
+        let response_path = run_dir.join("get_pipeline_details_json.response.json");
+        remove_if_exists(&response_path).map_err(GetPipelineDetailsError::WriteRequest)?;
+
+        let req = GetPipelineDetailsRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            pipeline_name: req.pipeline_name.clone(),
+            replay_options: req.replay_options.clone(),
+        };
+
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(GetPipelineDetailsError::ParseJson)?,
+        )
+        .map_err(GetPipelineDetailsError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+        let _ = result;
+
+        let bytes =
+            std::fs::read(&response_path).map_err(GetPipelineDetailsError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<GetPipelineDetailsResponse> =
+            serde_json::from_slice(&bytes).map_err(GetPipelineDetailsError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| GetPipelineDetailsError::ScriptError("missing result".into()))
+        } else {
+            Err(GetPipelineDetailsError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    pub fn find_events_by_pipeline(
+        &self,
+        cwd: &Path,
+        req: &FindEventsByPipelineRequest,
+    ) -> Result<FindEventsByPipelineResponse, FindEventsByPipelineError> {
+        self.find_events_by_pipeline_in(None, cwd, req)
+    }
+
+    pub(crate) fn find_events_by_pipeline_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &FindEventsByPipelineRequest,
+    ) -> Result<FindEventsByPipelineResponse, FindEventsByPipelineError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir)
+            .map_err(FindEventsByPipelineError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("find_events_by_pipeline_json.py");
+        write_script_file(&script_path, FIND_EVENTS_BY_PIPELINE_JSON_PY)
+            .map_err(FindEventsByPipelineError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "find_events_by_pipeline")
+            .map_err(FindEventsByPipelineError::CreateScriptsDir)?;
+        let request_path = run_dir.join("find_events_by_pipeline_json.request.json");
+        let response_path = run_dir.join("find_events_by_pipeline_json.response.json");
+        remove_if_exists(&response_path).map_err(FindEventsByPipelineError::WriteRequest)?;
+
+        let req = FindEventsByPipelineRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            ..req.clone()
+        };
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(FindEventsByPipelineError::ParseJson)?,
+        )
+        .map_err(FindEventsByPipelineError::WriteRequest)?;
+
+        self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+
+        let bytes =
+            std::fs::read(&response_path).map_err(FindEventsByPipelineError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<FindEventsByPipelineResponse> =
+            serde_json::from_slice(&bytes).map_err(FindEventsByPipelineError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| FindEventsByPipelineError::ScriptError("missing result".into()))
+        } else {
+            Err(FindEventsByPipelineError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    pub fn get_pipeline_binding_changes_delta(
+        &self,
+        cwd: &Path,
+        req: &GetPipelineBindingChangesDeltaRequest,
+    ) -> Result<GetPipelineBindingChangesDeltaResponse, GetPipelineBindingChangesDeltaError> {
+        self.get_pipeline_binding_changes_delta_in(None, cwd, req)
+    }
+
+    pub(crate) fn get_pipeline_binding_changes_delta_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &GetPipelineBindingChangesDeltaRequest,
+    ) -> Result<GetPipelineBindingChangesDeltaResponse, GetPipelineBindingChangesDeltaError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir)
+            .map_err(GetPipelineBindingChangesDeltaError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("get_pipeline_binding_changes_delta_json.py");
+        write_script_file(&script_path, GET_PIPELINE_BINDING_CHANGES_DELTA_JSON_PY)
+            .map_err(GetPipelineBindingChangesDeltaError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "get_pipeline_binding_changes_delta")
+            .map_err(GetPipelineBindingChangesDeltaError::CreateScriptsDir)?;
+        let request_path = run_dir.join("get_pipeline_binding_changes_delta_json.request.json");
+        let response_path = run_dir.join("get_pipeline_binding_changes_delta_json.response.json");
+        remove_if_exists(&response_path).map_err(GetPipelineBindingChangesDeltaError::WriteRequest)?;
+
+        let req = GetPipelineBindingChangesDeltaRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            pipeline_name: req.pipeline_name.clone(),
+            replay_options: req.replay_options.clone(),
+        };
+
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(GetPipelineBindingChangesDeltaError::ParseJson)?,
+        )
+        .map_err(GetPipelineBindingChangesDeltaError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+        let _ = result;
+
+        let bytes =
+            std::fs::read(&response_path).map_err(GetPipelineBindingChangesDeltaError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<GetPipelineBindingChangesDeltaResponse> =
+            serde_json::from_slice(&bytes).map_err(GetPipelineBindingChangesDeltaError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| GetPipelineBindingChangesDeltaError::ScriptError("missing result".into()))
+        } else {
+            Err(GetPipelineBindingChangesDeltaError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    pub fn get_event_pipeline_state(
+        &self,
+        cwd: &Path,
+        req: &GetEventPipelineStateRequest,
+    ) -> Result<GetEventPipelineStateResponse, GetEventPipelineStateError> {
+        self.get_event_pipeline_state_in(None, cwd, req)
+    }
+
+    pub(crate) fn get_event_pipeline_state_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &GetEventPipelineStateRequest,
+    ) -> Result<GetEventPipelineStateResponse, GetEventPipelineStateError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir)
+            .map_err(GetEventPipelineStateError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("get_event_pipeline_state_json.py");
+        write_script_file(&script_path, GET_EVENT_PIPELINE_STATE_JSON_PY)
+            .map_err(GetEventPipelineStateError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "get_event_pipeline_state")
+            .map_err(GetEventPipelineStateError::CreateScriptsDir)?;
+        let request_path = run_dir.join("get_event_pipeline_state_json.request.json");
+        let response_path = run_dir.join("get_event_pipeline_state_json.response.json");
+        remove_if_exists(&response_path).map_err(GetEventPipelineStateError::WriteRequest)?;
+
+        let req = GetEventPipelineStateRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            event_id: req.event_id,
+            replay_options: req.replay_options.clone(),
+        };
+
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(GetEventPipelineStateError::ParseJson)?,
+        )
+        .map_err(GetEventPipelineStateError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+        let _ = result;
+
+        let bytes =
+            std::fs::read(&response_path).map_err(GetEventPipelineStateError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<GetEventPipelineStateResponse> =
+            serde_json::from_slice(&bytes).map_err(GetEventPipelineStateError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| GetEventPipelineStateError::ScriptError("missing result".into()))
+        } else {
+            Err(GetEventPipelineStateError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    pub fn get_event_api_call(
+        &self,
+        cwd: &Path,
+        req: &GetEventApiCallRequest,
+    ) -> Result<GetEventApiCallResponse, GetEventApiCallError> {
+        self.get_event_api_call_in(None, cwd, req)
+    }
+
+    pub(crate) fn get_event_api_call_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &GetEventApiCallRequest,
+    ) -> Result<GetEventApiCallResponse, GetEventApiCallError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir).map_err(GetEventApiCallError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("get_event_api_call_json.py");
+        write_script_file(&script_path, GET_EVENT_API_CALL_JSON_PY)
+            .map_err(GetEventApiCallError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "get_event_api_call")
+            .map_err(GetEventApiCallError::CreateScriptsDir)?;
+        let request_path = run_dir.join("get_event_api_call_json.request.json");
+        let response_path = run_dir.join("get_event_api_call_json.response.json");
+        remove_if_exists(&response_path).map_err(GetEventApiCallError::WriteRequest)?;
+
+        let req = GetEventApiCallRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            ..req.clone()
+        };
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(GetEventApiCallError::ParseJson)?,
+        )
+        .map_err(GetEventApiCallError::WriteRequest)?;
+
+        self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+
+        let bytes = std::fs::read(&response_path).map_err(GetEventApiCallError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<GetEventApiCallResponse> =
+            serde_json::from_slice(&bytes).map_err(GetEventApiCallError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| GetEventApiCallError::ScriptError("missing result".into()))
+        } else {
+            Err(GetEventApiCallError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    pub fn get_resource_changed_event_ids(
+        &self,
+        cwd: &Path,
+        req: &GetResourceChangedEventIdsRequest,
+    ) -> Result<GetResourceChangedEventIdsResponse, GetResourceChangedEventIdsError> {
+        self.get_resource_changed_event_ids_in(None, cwd, req)
+    }
+
+    pub(crate) fn get_resource_changed_event_ids_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &GetResourceChangedEventIdsRequest,
+    ) -> Result<GetResourceChangedEventIdsResponse, GetResourceChangedEventIdsError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir)
+            .map_err(GetResourceChangedEventIdsError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("get_resource_changed_event_ids_json.py");
+        write_script_file(&script_path, GET_RESOURCE_CHANGED_EVENT_IDS_JSON_PY)
+            .map_err(GetResourceChangedEventIdsError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "get_resource_changed_event_ids")
+            .map_err(GetResourceChangedEventIdsError::CreateScriptsDir)?;
+        let request_path = run_dir.join("get_resource_changed_event_ids_json.request.json");
+        let response_path = run_dir.join("get_resource_changed_event_ids_json.response.json");
+        remove_if_exists(&response_path).map_err(GetResourceChangedEventIdsError::WriteRequest)?;
+
+        let req = GetResourceChangedEventIdsRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            resource_name: req.resource_name.clone(),
+            replay_options: req.replay_options.clone(),
+        };
+
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(GetResourceChangedEventIdsError::ParseJson)?,
+        )
+        .map_err(GetResourceChangedEventIdsError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+        let _ = result;
+
+        let bytes =
+            std::fs::read(&response_path).map_err(GetResourceChangedEventIdsError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<GetResourceChangedEventIdsResponse> =
+            serde_json::from_slice(&bytes).map_err(GetResourceChangedEventIdsError::ParseJson)?;
+        if env.ok {
+            env.result.ok_or_else(|| {
+                GetResourceChangedEventIdsError::ScriptError("missing result".into())
+            })
+        } else {
+            Err(GetResourceChangedEventIdsError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    pub fn search_resources(
+        &self,
+        cwd: &Path,
+        req: &SearchResourcesRequest,
+    ) -> Result<SearchResourcesResponse, SearchResourcesError> {
+        self.search_resources_in(None, cwd, req)
+    }
+
+    pub(crate) fn search_resources_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &SearchResourcesRequest,
+    ) -> Result<SearchResourcesResponse, SearchResourcesError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir).map_err(SearchResourcesError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("search_resources_json.py");
+        write_script_file(&script_path, SEARCH_RESOURCES_JSON_PY)
+            .map_err(SearchResourcesError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "search_resources")
+            .map_err(SearchResourcesError::CreateScriptsDir)?;
+        let request_path = run_dir.join("search_resources_json.request.json");
+        let response_path = run_dir.join("search_resources_json.response.json");
+        remove_if_exists(&response_path).map_err(SearchResourcesError::WriteRequest)?;
+
+        let req = SearchResourcesRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            query: req.query.clone(),
+            case_sensitive: req.case_sensitive,
+            max_results: req.max_results,
+            resource_types: req.resource_types.clone(),
+            replay_options: req.replay_options.clone(),
+        };
+
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(SearchResourcesError::ParseJson)?,
+        )
+        .map_err(SearchResourcesError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+        let _ = result;
+
+        let bytes = std::fs::read(&response_path).map_err(SearchResourcesError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<SearchResourcesResponse> =
+            serde_json::from_slice(&bytes).map_err(SearchResourcesError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| SearchResourcesError::ScriptError("missing result".into()))
+        } else {
+            Err(SearchResourcesError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    pub fn find_resource_uses(
+        &self,
+        cwd: &Path,
+        req: &FindResourceUsesRequest,
+    ) -> Result<FindResourceUsesResponse, FindResourceUsesError> {
+        self.find_resource_uses_in(None, cwd, req)
+    }
+
+    pub(crate) fn find_resource_uses_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &FindResourceUsesRequest,
+    ) -> Result<FindResourceUsesResponse, FindResourceUsesError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir).map_err(FindResourceUsesError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("find_resource_uses_json.py");
+        write_script_file(&script_path, FIND_RESOURCE_USES_JSON_PY)
+            .map_err(FindResourceUsesError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "find_resource_uses")
+            .map_err(FindResourceUsesError::CreateScriptsDir)?;
+        let request_path = run_dir.join("find_resource_uses_json.request.json");
+        let response_path = run_dir.join("find_resource_uses_json.response.json");
+        remove_if_exists(&response_path).map_err(FindResourceUsesError::WriteRequest)?;
+
+        let req = FindResourceUsesRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            resource: req.resource.clone(),
+            max_results: req.max_results,
+            data_sample_bytes: req.data_sample_bytes,
+            delta_filter: req.delta_filter.clone(),
+            replay_options: req.replay_options.clone(),
+        };
+
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(FindResourceUsesError::ParseJson)?,
+        )
+        .map_err(FindResourceUsesError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+        let _ = result;
+
+        let bytes = std::fs::read(&response_path).map_err(FindResourceUsesError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<FindResourceUsesResponse> =
+            serde_json::from_slice(&bytes).map_err(FindResourceUsesError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| FindResourceUsesError::ScriptError("missing result".into()))
+        } else {
+            Err(FindResourceUsesError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    pub fn export_bindings_index_jsonl(
+        &self,
+        cwd: &Path,
+        req: &ExportBindingsIndexRequest,
+    ) -> Result<ExportBindingsIndexResponse, ExportBindingsIndexError> {
+        self.export_bindings_index_jsonl_in(None, cwd, req)
+    }
+
+    pub(crate) fn export_bindings_index_jsonl_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &ExportBindingsIndexRequest,
+    ) -> Result<ExportBindingsIndexResponse, ExportBindingsIndexError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir).map_err(ExportBindingsIndexError::CreateOutputDir)?;
+
+        let script_path = scripts_dir.join("export_bindings_index_jsonl.py");
+        write_script_file(&script_path, EXPORT_BINDINGS_INDEX_JSONL_PY)
+            .map_err(ExportBindingsIndexError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "export_bindings_index_jsonl")
+            .map_err(ExportBindingsIndexError::CreateOutputDir)?;
+        let request_path = run_dir.join("export_bindings_index_jsonl.request.json");
+        let response_path = run_dir.join("export_bindings_index_jsonl.response.json");
+        remove_if_exists(&response_path).map_err(ExportBindingsIndexError::WriteRequest)?;
+
+        let req = ExportBindingsIndexRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            output_dir: resolve_path_string_from_cwd(cwd, &req.output_dir),
+            ..req.clone()
+        };
+        if req.compress && !cfg!(feature = "zstd") {
+            return Err(ExportBindingsIndexError::Compress(crate::artifacts::zstd_unsupported()));
+        }
+
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(ExportBindingsIndexError::ParseJson)?,
+        )
+        .map_err(ExportBindingsIndexError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+        let _ = result;
+        let bytes =
+            std::fs::read(&response_path).map_err(ExportBindingsIndexError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<ExportBindingsIndexResponse> =
+            serde_json::from_slice(&bytes).map_err(ExportBindingsIndexError::ParseJson)?;
+        if env.ok {
+            let mut res = env
+                .result
+                .ok_or_else(|| ExportBindingsIndexError::ScriptError("missing result".into()))?;
+            if req.compress {
+                res.bindings_jsonl_path = compress_export(
+                    &res.bindings_jsonl_path,
+                    &res.summary_json_path,
+                    "bindings_jsonl_path",
+                )
+                .map_err(ExportBindingsIndexError::Compress)?;
+            }
+            Ok(res)
+        } else {
+            Err(ExportBindingsIndexError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    pub fn export_bundle_jsonl(
+        &self,
+        cwd: &Path,
+        req: &ExportBundleRequest,
+    ) -> Result<ExportBundleResponse, ExportBundleError> {
+        self.export_bundle_jsonl_in(None, cwd, req)
+    }
+
+    pub(crate) fn export_bundle_jsonl_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &ExportBundleRequest,
+    ) -> Result<ExportBundleResponse, ExportBundleError> {
+        let capture_path = resolve_path_string_from_cwd(cwd, &req.capture_path);
+        let output_dir = resolve_path_string_from_cwd(cwd, &req.output_dir);
+
+        let actions = self.export_actions_jsonl_in(
+            session,
+            cwd,
+            &ExportActionsRequest {
+                capture_path: capture_path.clone(),
+                output_dir: output_dir.clone(),
+                basename: req.basename.clone(),
+                only_drawcalls: req.only_drawcalls,
+                marker_prefix: req.marker_prefix.clone(),
+                event_id_min: req.event_id_min,
+                event_id_max: req.event_id_max,
+                name_contains: req.name_contains.clone(),
+                marker_contains: req.marker_contains.clone(),
+                case_sensitive: req.case_sensitive,
+                format: None,
+                query: None,
+                compress: req.compress,
+                resolve_callstacks: false,
+                symbol_path: None,
+                replay_options: req.replay_options.clone(),
+            },
+        )?;
+
+        let bindings = self.export_bindings_index_jsonl_in(
+            session,
+            cwd,
+            &ExportBindingsIndexRequest {
+                capture_path: capture_path.clone(),
+                output_dir: output_dir.clone(),
+                basename: req.basename.clone(),
+                marker_prefix: req.marker_prefix.clone(),
+                event_id_min: req.event_id_min,
+                event_id_max: req.event_id_max,
+                name_contains: req.name_contains.clone(),
+                marker_contains: req.marker_contains.clone(),
+                case_sensitive: req.case_sensitive,
+                include_cbuffers: req.include_cbuffers,
+                include_outputs: req.include_outputs,
+                compress: req.compress,
+                replay_options: req.replay_options.clone(),
+            },
+        )?;
+
+        Ok(ExportBundleResponse {
+            capture_path,
+
+            actions_jsonl_path: actions.actions_path,
+            actions_summary_json_path: actions.summary_json_path,
+            total_actions: actions.total_actions,
+            drawcall_actions: actions.drawcall_actions,
+
+            bindings_jsonl_path: bindings.bindings_jsonl_path,
+            bindings_summary_json_path: bindings.summary_json_path,
+            total_drawcalls: bindings.total_drawcalls,
+
+            actions_resumed_from_event_id: actions.resumed_from_event_id,
+            bindings_resumed_from_event_id: bindings.resumed_from_event_id,
+        })
+    }
+
+    /// Read and decode the vertex buffer inputs of a draw using its vertex attribute formats.
+    pub fn get_vertex_inputs(
+        &self,
+        cwd: &Path,
+        req: &GetVertexInputsRequest,
+    ) -> Result<GetVertexInputsResponse, GetVertexInputsError> {
+        self.get_vertex_inputs_in(None, cwd, req)
+    }
+
+    pub(crate) fn get_vertex_inputs_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &GetVertexInputsRequest,
+    ) -> Result<GetVertexInputsResponse, GetVertexInputsError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir).map_err(GetVertexInputsError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("get_vertex_inputs_json.py");
+        write_script_file(&script_path, GET_VERTEX_INPUTS_JSON_PY)
+            .map_err(GetVertexInputsError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "get_vertex_inputs")
+            .map_err(GetVertexInputsError::CreateScriptsDir)?;
+        let request_path = run_dir.join("get_vertex_inputs_json.request.json");
+        let response_path = run_dir.join("get_vertex_inputs_json.response.json");
+        remove_if_exists(&response_path).map_err(GetVertexInputsError::WriteRequest)?;
+
+        let req = GetVertexInputsRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            ..req.clone()
+        };
+
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(GetVertexInputsError::ParseJson)?,
+        )
+        .map_err(GetVertexInputsError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+        let _ = result;
+
+        let bytes = std::fs::read(&response_path).map_err(GetVertexInputsError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<GetVertexInputsResponse> =
+            serde_json::from_slice(&bytes).map_err(GetVertexInputsError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| GetVertexInputsError::ScriptError("missing result".into()))
+        } else {
+            Err(GetVertexInputsError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    /// Read the decoded index values (Uint16/Uint32, honouring the draw's index offset) of an
+    /// indexed draw.
+    pub fn get_indices(
+        &self,
+        cwd: &Path,
+        req: &GetIndicesRequest,
+    ) -> Result<GetIndicesResponse, GetIndicesError> {
+        self.get_indices_in(None, cwd, req)
+    }
+
+    pub(crate) fn get_indices_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &GetIndicesRequest,
+    ) -> Result<GetIndicesResponse, GetIndicesError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir).map_err(GetIndicesError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("get_indices_json.py");
+        write_script_file(&script_path, GET_INDICES_JSON_PY)
+            .map_err(GetIndicesError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "get_indices")
+            .map_err(GetIndicesError::CreateScriptsDir)?;
+        let request_path = run_dir.join("get_indices_json.request.json");
+        let response_path = run_dir.join("get_indices_json.response.json");
+        remove_if_exists(&response_path).map_err(GetIndicesError::WriteRequest)?;
+
+        let req = GetIndicesRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            ..req.clone()
+        };
+
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(GetIndicesError::ParseJson)?,
+        )
+        .map_err(GetIndicesError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+        let _ = result;
+
+        let bytes = std::fs::read(&response_path).map_err(GetIndicesError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<GetIndicesResponse> =
+            serde_json::from_slice(&bytes).map_err(GetIndicesError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| GetIndicesError::ScriptError("missing result".into()))
+        } else {
+            Err(GetIndicesError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    /// Fetch GPU counters (durations, samples passed, shader invocations, vendor counters) for
+    /// every event via `EnumerateCounters` / `FetchCounters`.
+    pub fn fetch_counters(
+        &self,
+        cwd: &Path,
+        req: &FetchCountersRequest,
+    ) -> Result<FetchCountersResponse, FetchCountersError> {
+        self.fetch_counters_in(None, cwd, req)
+    }
+
+    pub(crate) fn fetch_counters_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &FetchCountersRequest,
+    ) -> Result<FetchCountersResponse, FetchCountersError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir).map_err(FetchCountersError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("fetch_counters_json.py");
+        write_script_file(&script_path, FETCH_COUNTERS_JSON_PY)
+            .map_err(FetchCountersError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "fetch_counters")
+            .map_err(FetchCountersError::CreateScriptsDir)?;
+        let request_path = run_dir.join("fetch_counters_json.request.json");
+        let response_path = run_dir.join("fetch_counters_json.response.json");
+        remove_if_exists(&response_path).map_err(FetchCountersError::WriteRequest)?;
+
+        let req = FetchCountersRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            ..req.clone()
+        };
+
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(FetchCountersError::ParseJson)?,
+        )
+        .map_err(FetchCountersError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+        let _ = result;
+
+        let bytes = std::fs::read(&response_path).map_err(FetchCountersError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<FetchCountersResponse> =
+            serde_json::from_slice(&bytes).map_err(FetchCountersError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| FetchCountersError::ScriptError("missing result".into()))
+        } else {
+            Err(FetchCountersError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    /// GPU timestamps written by the application, with their values where the frame copies them
+    /// into a buffer, grouped by marker region.
+    pub fn get_timestamp_queries(
+        &self,
+        cwd: &Path,
+        req: &GetTimestampQueriesRequest,
+    ) -> Result<GetTimestampQueriesResponse, GetTimestampQueriesError> {
+        self.get_timestamp_queries_in(None, cwd, req)
+    }
+
+    pub(crate) fn get_timestamp_queries_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &GetTimestampQueriesRequest,
+    ) -> Result<GetTimestampQueriesResponse, GetTimestampQueriesError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir)
+            .map_err(GetTimestampQueriesError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("get_timestamp_queries_json.py");
+        write_script_file(&script_path, GET_TIMESTAMP_QUERIES_JSON_PY)
+            .map_err(GetTimestampQueriesError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "get_timestamp_queries")
+            .map_err(GetTimestampQueriesError::CreateScriptsDir)?;
+        let request_path = run_dir.join("get_timestamp_queries_json.request.json");
+        let response_path = run_dir.join("get_timestamp_queries_json.response.json");
+        remove_if_exists(&response_path).map_err(GetTimestampQueriesError::WriteRequest)?;
+
+        let req = GetTimestampQueriesRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            ..req.clone()
+        };
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(GetTimestampQueriesError::ParseJson)?,
+        )
+        .map_err(GetTimestampQueriesError::WriteRequest)?;
+
+        self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+
+        let bytes =
+            std::fs::read(&response_path).map_err(GetTimestampQueriesError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<GetTimestampQueriesResponse> =
+            serde_json::from_slice(&bytes).map_err(GetTimestampQueriesError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| GetTimestampQueriesError::ScriptError("missing result".into()))
+        } else {
+            Err(GetTimestampQueriesError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    /// Export a frame as Chrome trace JSON: the marker hierarchy becomes nested slices and each
+    /// event's `EventGPUDuration` its slice length.
+    pub fn export_chrome_trace(
+        &self,
+        cwd: &Path,
+        req: &ExportChromeTraceRequest,
+    ) -> Result<ExportChromeTraceResponse, ExportChromeTraceError> {
+        self.export_chrome_trace_in(None, cwd, req)
+    }
+
+    pub(crate) fn export_chrome_trace_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &ExportChromeTraceRequest,
+    ) -> Result<ExportChromeTraceResponse, ExportChromeTraceError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir).map_err(ExportChromeTraceError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("export_chrome_trace_json.py");
+        write_script_file(&script_path, EXPORT_CHROME_TRACE_JSON_PY)
+            .map_err(ExportChromeTraceError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "export_chrome_trace")
+            .map_err(ExportChromeTraceError::CreateScriptsDir)?;
+        let request_path = run_dir.join("export_chrome_trace_json.request.json");
+        let response_path = run_dir.join("export_chrome_trace_json.response.json");
+        remove_if_exists(&response_path).map_err(ExportChromeTraceError::WriteRequest)?;
+
+        let req = ExportChromeTraceRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            output_path: resolve_path_string_from_cwd(cwd, &req.output_path),
+            replay_options: req.replay_options.clone(),
+        };
+
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(ExportChromeTraceError::ParseJson)?,
+        )
+        .map_err(ExportChromeTraceError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+        let _ = result;
+
+        let bytes = std::fs::read(&response_path).map_err(ExportChromeTraceError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<ExportChromeTraceResponse> =
+            serde_json::from_slice(&bytes).map_err(ExportChromeTraceError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| ExportChromeTraceError::ScriptError("missing result".into()))
+        } else {
+            Err(ExportChromeTraceError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    /// Per-channel min / max / mean (and optionally a histogram) of a texture at an event, e.g. to
+    /// validate exposure or depth ranges or catch all-black render targets.
+    pub fn get_texture_stats(
+        &self,
+        cwd: &Path,
+        req: &GetTextureStatsRequest,
+    ) -> Result<GetTextureStatsResponse, GetTextureStatsError> {
+        self.get_texture_stats_in(None, cwd, req)
+    }
+
+    pub(crate) fn get_texture_stats_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &GetTextureStatsRequest,
+    ) -> Result<GetTextureStatsResponse, GetTextureStatsError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir).map_err(GetTextureStatsError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("get_texture_stats_json.py");
+        write_script_file(&script_path, GET_TEXTURE_STATS_JSON_PY)
+            .map_err(GetTextureStatsError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "get_texture_stats")
+            .map_err(GetTextureStatsError::CreateScriptsDir)?;
+        let request_path = run_dir.join("get_texture_stats_json.request.json");
+        let response_path = run_dir.join("get_texture_stats_json.response.json");
+        remove_if_exists(&response_path).map_err(GetTextureStatsError::WriteRequest)?;
+
+        let req = GetTextureStatsRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            ..req.clone()
+        };
+
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(GetTextureStatsError::ParseJson)?,
+        )
+        .map_err(GetTextureStatsError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+        let _ = result;
+
+        let bytes = std::fs::read(&response_path).map_err(GetTextureStatsError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<GetTextureStatsResponse> =
+            serde_json::from_slice(&bytes).map_err(GetTextureStatsError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| GetTextureStatsError::ScriptError("missing result".into()))
+        } else {
+            Err(GetTextureStatsError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    /// Replay every draw/dispatch in an event range and scan its floating-point colour (and
+    /// optionally depth) targets for NaN/Inf texels.
+    pub fn scan_targets_for_invalid_values(
+        &self,
+        cwd: &Path,
+        req: &ScanTargetsForInvalidValuesRequest,
+    ) -> Result<ScanTargetsForInvalidValuesResponse, ScanTargetsForInvalidValuesError> {
+        self.scan_targets_for_invalid_values_in(None, cwd, req)
+    }
+
+    pub(crate) fn scan_targets_for_invalid_values_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &ScanTargetsForInvalidValuesRequest,
+    ) -> Result<ScanTargetsForInvalidValuesResponse, ScanTargetsForInvalidValuesError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir)
+            .map_err(ScanTargetsForInvalidValuesError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("scan_targets_for_invalid_values_json.py");
+        write_script_file(&script_path, SCAN_TARGETS_FOR_INVALID_VALUES_JSON_PY)
+            .map_err(ScanTargetsForInvalidValuesError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "scan_targets_for_invalid_values")
+            .map_err(ScanTargetsForInvalidValuesError::CreateScriptsDir)?;
+        let request_path = run_dir.join("scan_targets_for_invalid_values_json.request.json");
+        let response_path = run_dir.join("scan_targets_for_invalid_values_json.response.json");
+        remove_if_exists(&response_path).map_err(ScanTargetsForInvalidValuesError::WriteRequest)?;
+
+        let req = ScanTargetsForInvalidValuesRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            ..req.clone()
+        };
+
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(ScanTargetsForInvalidValuesError::ParseJson)?,
+        )
+        .map_err(ScanTargetsForInvalidValuesError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+        let _ = result;
+
+        let bytes = std::fs::read(&response_path)
+            .map_err(ScanTargetsForInvalidValuesError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<ScanTargetsForInvalidValuesResponse> =
+            serde_json::from_slice(&bytes).map_err(ScanTargetsForInvalidValuesError::ParseJson)?;
+        if env.ok {
+            env.result.ok_or_else(|| {
+                ScanTargetsForInvalidValuesError::ScriptError("missing result".into())
+            })
+        } else {
+            Err(ScanTargetsForInvalidValuesError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    /// Compare two textures, from the same or different captures: differing texel count, max delta,
+    /// MSE/PSNR and SSIM, with an optional diff image.
+    pub fn compare_textures(
+        &self,
+        cwd: &Path,
+        req: &CompareTexturesRequest,
+    ) -> Result<CompareTexturesResponse, CompareTexturesError> {
+        self.compare_textures_in(None, cwd, req)
+    }
+
+    pub(crate) fn compare_textures_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &CompareTexturesRequest,
+    ) -> Result<CompareTexturesResponse, CompareTexturesError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir).map_err(CompareTexturesError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("compare_textures_json.py");
+        write_script_file(&script_path, COMPARE_TEXTURES_JSON_PY)
+            .map_err(CompareTexturesError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "compare_textures")
+            .map_err(CompareTexturesError::CreateScriptsDir)?;
+        let request_path = run_dir.join("compare_textures_json.request.json");
+        let response_path = run_dir.join("compare_textures_json.response.json");
+        remove_if_exists(&response_path).map_err(CompareTexturesError::WriteRequest)?;
+
+        let req = CompareTexturesRequest {
+            a: TextureRef {
+                capture_path: resolve_path_string_from_cwd(cwd, &req.a.capture_path),
+                ..req.a.clone()
+            },
+            b: TextureRef {
+                capture_path: resolve_path_string_from_cwd(cwd, &req.b.capture_path),
+                ..req.b.clone()
+            },
+            diff_output_path: req
+                .diff_output_path
+                .as_deref()
+                .map(|p| resolve_path_string_from_cwd(cwd, p)),
+            ..req.clone()
+        };
+
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(CompareTexturesError::ParseJson)?,
+        )
+        .map_err(CompareTexturesError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+        let _ = result;
+
+        let bytes = std::fs::read(&response_path).map_err(CompareTexturesError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<CompareTexturesResponse> =
+            serde_json::from_slice(&bytes).map_err(CompareTexturesError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| CompareTexturesError::ScriptError("missing result".into()))
+        } else {
+            Err(CompareTexturesError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
     }
-}
 
-#[derive(Debug, Error)]
-pub enum FindResourceUsesError {
-    #[error("failed to create scripts dir: {0}")]
-    CreateScriptsDir(std::io::Error),
-    #[error("failed to write python script: {0}")]
-    WriteScript(std::io::Error),
-    #[error("failed to write request JSON: {0}")]
-    WriteRequest(std::io::Error),
-    #[error("qrenderdoc python failed: {0}")]
-    QRenderDocPython(Box<crate::QRenderDocPythonError>),
-    #[error("failed to read response JSON: {0}")]
-    ReadResponse(std::io::Error),
-    #[error("failed to parse JSON: {0}")]
-    ParseJson(serde_json::Error),
-    #[error("qrenderdoc script error: {0}")]
-    ScriptError(String),
-}
+    /// Pipeline state differences between two events (shaders, blend, depth, bindings,
+    /// constants), built from two [`get_event_pipeline_state`](Self::get_event_pipeline_state)
+    /// results.
+    pub fn diff_event_pipeline_state(
+        &self,
+        cwd: &Path,
+        req: &DiffEventPipelineStateRequest,
+    ) -> Result<DiffEventPipelineStateResponse, DiffEventPipelineStateError> {
+        self.diff_event_pipeline_state_in(None, cwd, req)
+    }
 
-impl From<crate::QRenderDocPythonError> for FindResourceUsesError {
-    fn from(value: crate::QRenderDocPythonError) -> Self {
-        Self::QRenderDocPython(Box::new(value))
+    pub(crate) fn diff_event_pipeline_state_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &DiffEventPipelineStateRequest,
+    ) -> Result<DiffEventPipelineStateResponse, DiffEventPipelineStateError> {
+        let state_a = self.get_event_pipeline_state_in(
+            session,
+            cwd,
+            &GetEventPipelineStateRequest {
+                capture_path: req.capture_path.clone(),
+                event_id: req.event_a,
+                replay_options: req.replay_options.clone(),
+            },
+        )?;
+        let state_b = self.get_event_pipeline_state_in(
+            session,
+            cwd,
+            &GetEventPipelineStateRequest {
+                capture_path: req.capture_path.clone(),
+                event_id: req.event_b,
+                replay_options: req.replay_options.clone(),
+            },
+        )?;
+
+        let mut va = serde_json::to_value(&state_a).map_err(DiffEventPipelineStateError::ToJson)?;
+        let mut vb = serde_json::to_value(&state_b).map_err(DiffEventPipelineStateError::ToJson)?;
+        for v in [&mut va, &mut vb] {
+            if let Some(obj) = v.as_object_mut() {
+                obj.remove("event_id");
+                obj.remove("capture_path");
+            }
+        }
+
+        let mut changes = Vec::new();
+        diff_pipeline_values("", Some(&va), Some(&vb), &mut changes);
+
+        Ok(DiffEventPipelineStateResponse {
+            capture_path: state_a.capture_path,
+            event_a: req.event_a,
+            event_b: req.event_b,
+            identical: changes.is_empty(),
+            changes,
+        })
     }
-}
 
-impl RenderDocInstallation {
-    pub fn trigger_capture_via_target_control(
+    /// Export the frame's pass / resource dependency graph as DOT and JSON.
+    ///
+    /// Passes are runs of consecutive draws sharing the same targets (or dispatches / copies under
+    /// the same marker); an edge links the last pass writing a resource to each later pass reading it.
+    pub fn export_frame_graph(
         &self,
         cwd: &Path,
-        req: &TriggerCaptureRequest,
-    ) -> Result<TriggerCaptureResponse, TriggerCaptureError> {
+        req: &ExportFrameGraphRequest,
+    ) -> Result<ExportFrameGraphResponse, ExportFrameGraphError> {
+        self.export_frame_graph_in(None, cwd, req)
+    }
+
+    pub(crate) fn export_frame_graph_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &ExportFrameGraphRequest,
+    ) -> Result<ExportFrameGraphResponse, ExportFrameGraphError> {
         let scripts_dir = default_scripts_dir(cwd);
-        std::fs::create_dir_all(&scripts_dir).map_err(TriggerCaptureError::CreateArtifactsDir)?;
+        std::fs::create_dir_all(&scripts_dir).map_err(ExportFrameGraphError::CreateScriptsDir)?;
 
-        let script_path = scripts_dir.join("trigger_capture.py");
-        write_script_file(&script_path, TRIGGER_CAPTURE_PY)
-            .map_err(TriggerCaptureError::WriteScript)?;
+        let script_path = scripts_dir.join("export_frame_graph_json.py");
+        write_script_file(&script_path, EXPORT_FRAME_GRAPH_JSON_PY)
+            .map_err(ExportFrameGraphError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "export_frame_graph")
+            .map_err(ExportFrameGraphError::CreateScriptsDir)?;
+        let request_path = run_dir.join("export_frame_graph_json.request.json");
+        let response_path = run_dir.join("export_frame_graph_json.response.json");
+        remove_if_exists(&response_path).map_err(ExportFrameGraphError::WriteRequest)?;
+
+        let req = ExportFrameGraphRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            output_dir: resolve_path_string_from_cwd(cwd, &req.output_dir),
+            ..req.clone()
+        };
 
-        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "trigger_capture")
-            .map_err(TriggerCaptureError::CreateArtifactsDir)?;
-        let request_path = run_dir.join("trigger_capture.request.json");
-        let response_path = run_dir.join("trigger_capture.response.json");
-        remove_if_exists(&response_path).map_err(TriggerCaptureError::WriteRequest)?;
         std::fs::write(
             &request_path,
-            serde_json::to_vec(req).map_err(TriggerCaptureError::ParseJson)?,
+            serde_json::to_vec(&req).map_err(ExportFrameGraphError::ParseJson)?,
         )
-        .map_err(TriggerCaptureError::WriteRequest)?;
-
-        let result = self.run_qrenderdoc_python(&QRenderDocPythonRequest {
-            script_path: script_path.clone(),
-            args: Vec::new(),
-            working_dir: Some(run_dir.clone()),
-        })?;
+        .map_err(ExportFrameGraphError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
         let _ = result;
-        let bytes = std::fs::read(&response_path).map_err(TriggerCaptureError::ReadResponse)?;
-        let env: QRenderDocJsonEnvelope<TriggerCaptureResponse> =
-            serde_json::from_slice(&bytes).map_err(TriggerCaptureError::ParseJson)?;
+
+        let bytes = std::fs::read(&response_path).map_err(ExportFrameGraphError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<ExportFrameGraphResponse> =
+            serde_json::from_slice(&bytes).map_err(ExportFrameGraphError::ParseJson)?;
         if env.ok {
             env.result
-                .ok_or_else(|| TriggerCaptureError::ScriptError("missing result".into()))
+                .ok_or_else(|| ExportFrameGraphError::ScriptError("missing result".into()))
         } else {
-            Err(TriggerCaptureError::ScriptError(
+            Err(ExportFrameGraphError::ScriptError(
                 env.error.unwrap_or_else(|| "unknown error".into()),
             ))
         }
     }
 
-    pub fn export_actions_jsonl(
+    /// Export render pass / framebuffer structure (attachments, load/store ops, subpasses, resolve
+    /// targets) for every pass in the capture as JSON.
+    pub fn export_render_passes(
         &self,
         cwd: &Path,
-        req: &ExportActionsRequest,
-    ) -> Result<ExportActionsResponse, ExportActionsError> {
+        req: &ExportRenderPassesRequest,
+    ) -> Result<ExportRenderPassesResponse, ExportRenderPassesError> {
+        self.export_render_passes_in(None, cwd, req)
+    }
+
+    pub(crate) fn export_render_passes_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &ExportRenderPassesRequest,
+    ) -> Result<ExportRenderPassesResponse, ExportRenderPassesError> {
         let scripts_dir = default_scripts_dir(cwd);
-        std::fs::create_dir_all(&scripts_dir).map_err(ExportActionsError::CreateOutputDir)?;
+        std::fs::create_dir_all(&scripts_dir).map_err(ExportRenderPassesError::CreateScriptsDir)?;
 
-        let script_path = scripts_dir.join("export_actions_jsonl.py");
-        write_script_file(&script_path, EXPORT_ACTIONS_JSONL_PY)
-            .map_err(ExportActionsError::WriteScript)?;
+        let script_path = scripts_dir.join("export_render_passes_json.py");
+        write_script_file(&script_path, EXPORT_RENDER_PASSES_JSON_PY)
+            .map_err(ExportRenderPassesError::WriteScript)?;
 
-        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "export_actions_jsonl")
-            .map_err(ExportActionsError::CreateOutputDir)?;
-        let request_path = run_dir.join("export_actions_jsonl.request.json");
-        let response_path = run_dir.join("export_actions_jsonl.response.json");
-        remove_if_exists(&response_path).map_err(ExportActionsError::WriteRequest)?;
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "export_render_passes")
+            .map_err(ExportRenderPassesError::CreateScriptsDir)?;
+        let request_path = run_dir.join("export_render_passes_json.request.json");
+        let response_path = run_dir.join("export_render_passes_json.response.json");
+        remove_if_exists(&response_path).map_err(ExportRenderPassesError::WriteRequest)?;
 
-        let req = ExportActionsRequest {
+        let req = ExportRenderPassesRequest {
             capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
             output_dir: resolve_path_string_from_cwd(cwd, &req.output_dir),
             ..req.clone()
@@ -1678,710 +7624,952 @@ impl RenderDocInstallation {
 
         std::fs::write(
             &request_path,
-            serde_json::to_vec(&req).map_err(ExportActionsError::ParseJson)?,
+            serde_json::to_vec(&req).map_err(ExportRenderPassesError::ParseJson)?,
         )
-        .map_err(ExportActionsError::WriteRequest)?;
-
-        let result = self.run_qrenderdoc_python(&QRenderDocPythonRequest {
-            script_path: script_path.clone(),
-            args: Vec::new(),
-            working_dir: Some(run_dir.clone()),
-        })?;
+        .map_err(ExportRenderPassesError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
         let _ = result;
-        let bytes = std::fs::read(&response_path).map_err(ExportActionsError::ReadResponse)?;
-        let env: QRenderDocJsonEnvelope<ExportActionsResponse> =
-            serde_json::from_slice(&bytes).map_err(ExportActionsError::ParseJson)?;
+
+        let bytes = std::fs::read(&response_path).map_err(ExportRenderPassesError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<ExportRenderPassesResponse> =
+            serde_json::from_slice(&bytes).map_err(ExportRenderPassesError::ParseJson)?;
         if env.ok {
             env.result
-                .ok_or_else(|| ExportActionsError::ScriptError("missing result".into()))
+                .ok_or_else(|| ExportRenderPassesError::ScriptError("missing result".into()))
         } else {
-            Err(ExportActionsError::ScriptError(
+            Err(ExportRenderPassesError::ScriptError(
                 env.error.unwrap_or_else(|| "unknown error".into()),
             ))
         }
     }
 
-    pub fn find_events(
+    /// Export per-draw statistics (primitive / triangle / instance counts, dispatch dimensions,
+    /// target resolution) and per-marker-group totals.
+    pub fn export_draw_stats(
         &self,
         cwd: &Path,
-        req: &FindEventsRequest,
-    ) -> Result<FindEventsResponse, FindEventsError> {
+        req: &ExportDrawStatsRequest,
+    ) -> Result<ExportDrawStatsResponse, ExportDrawStatsError> {
+        self.export_draw_stats_in(None, cwd, req)
+    }
+
+    pub(crate) fn export_draw_stats_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &ExportDrawStatsRequest,
+    ) -> Result<ExportDrawStatsResponse, ExportDrawStatsError> {
         let scripts_dir = default_scripts_dir(cwd);
-        std::fs::create_dir_all(&scripts_dir).map_err(FindEventsError::CreateScriptsDir)?;
+        std::fs::create_dir_all(&scripts_dir).map_err(ExportDrawStatsError::CreateScriptsDir)?;
 
-        let script_path = scripts_dir.join("find_events_json.py");
-        write_script_file(&script_path, FIND_EVENTS_JSON_PY)
-            .map_err(FindEventsError::WriteScript)?;
+        let script_path = scripts_dir.join("export_draw_stats_json.py");
+        write_script_file(&script_path, EXPORT_DRAW_STATS_JSON_PY)
+            .map_err(ExportDrawStatsError::WriteScript)?;
 
-        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "find_events")
-            .map_err(FindEventsError::CreateScriptsDir)?;
-        let request_path = run_dir.join("find_events_json.request.json");
-        let response_path = run_dir.join("find_events_json.response.json");
-        remove_if_exists(&response_path).map_err(FindEventsError::WriteRequest)?;
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "export_draw_stats")
+            .map_err(ExportDrawStatsError::CreateScriptsDir)?;
+        let request_path = run_dir.join("export_draw_stats_json.request.json");
+        let response_path = run_dir.join("export_draw_stats_json.response.json");
+        remove_if_exists(&response_path).map_err(ExportDrawStatsError::WriteRequest)?;
 
-        let req = FindEventsRequest {
+        let req = ExportDrawStatsRequest {
             capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            output_dir: resolve_path_string_from_cwd(cwd, &req.output_dir),
             ..req.clone()
         };
 
         std::fs::write(
             &request_path,
-            serde_json::to_vec(&req).map_err(FindEventsError::ParseJson)?,
+            serde_json::to_vec(&req).map_err(ExportDrawStatsError::ParseJson)?,
         )
-        .map_err(FindEventsError::WriteRequest)?;
-
-        let result = self.run_qrenderdoc_python(&QRenderDocPythonRequest {
-            script_path: script_path.clone(),
-            args: Vec::new(),
-            working_dir: Some(run_dir.clone()),
-        })?;
+        .map_err(ExportDrawStatsError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
         let _ = result;
 
-        let bytes = std::fs::read(&response_path).map_err(FindEventsError::ReadResponse)?;
-        let env: QRenderDocJsonEnvelope<FindEventsResponse> =
-            serde_json::from_slice(&bytes).map_err(FindEventsError::ParseJson)?;
+        let bytes = std::fs::read(&response_path).map_err(ExportDrawStatsError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<ExportDrawStatsResponse> =
+            serde_json::from_slice(&bytes).map_err(ExportDrawStatsError::ParseJson)?;
         if env.ok {
             env.result
-                .ok_or_else(|| FindEventsError::ScriptError("missing result".into()))
+                .ok_or_else(|| ExportDrawStatsError::ScriptError("missing result".into()))
         } else {
-            Err(FindEventsError::ScriptError(
+            Err(ExportDrawStatsError::ScriptError(
                 env.error.unwrap_or_else(|| "unknown error".into()),
             ))
         }
     }
 
-    pub fn get_events(
+    /// Count redundant state-setting calls per pass: pipeline binds, descriptor binds and constants
+    /// that repeat the arguments of the previous call for the same slot.
+    pub fn export_api_call_stats(
         &self,
         cwd: &Path,
-        req: &GetEventsRequest,
-    ) -> Result<GetEventsResponse, GetEventsError> {
+        req: &ExportApiCallStatsRequest,
+    ) -> Result<ExportApiCallStatsResponse, ExportApiCallStatsError> {
+        self.export_api_call_stats_in(None, cwd, req)
+    }
+
+    pub(crate) fn export_api_call_stats_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &ExportApiCallStatsRequest,
+    ) -> Result<ExportApiCallStatsResponse, ExportApiCallStatsError> {
         let scripts_dir = default_scripts_dir(cwd);
-        std::fs::create_dir_all(&scripts_dir).map_err(GetEventsError::CreateScriptsDir)?;
+        std::fs::create_dir_all(&scripts_dir).map_err(ExportApiCallStatsError::CreateScriptsDir)?;
 
-        let script_path = scripts_dir.join("get_events_json.py");
-        write_script_file(&script_path, GET_EVENTS_JSON_PY)
-            .map_err(GetEventsError::WriteScript)?;
+        let script_path = scripts_dir.join("export_api_call_stats_json.py");
+        write_script_file(&script_path, EXPORT_API_CALL_STATS_JSON_PY)
+            .map_err(ExportApiCallStatsError::WriteScript)?;
 
-        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "get_events")
-            .map_err(GetEventsError::CreateScriptsDir)?;
-        let request_path = run_dir.join("get_events_json.request.json");
-        let response_path = run_dir.join("get_events_json.response.json");
-        remove_if_exists(&response_path).map_err(GetEventsError::WriteRequest)?;
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "export_api_call_stats")
+            .map_err(ExportApiCallStatsError::CreateScriptsDir)?;
+        let request_path = run_dir.join("export_api_call_stats_json.request.json");
+        let response_path = run_dir.join("export_api_call_stats_json.response.json");
+        remove_if_exists(&response_path).map_err(ExportApiCallStatsError::WriteRequest)?;
 
-        let req = GetEventsRequest {
+        let req = ExportApiCallStatsRequest {
             capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            output_dir: resolve_path_string_from_cwd(cwd, &req.output_dir),
+            ..req.clone()
         };
-
         std::fs::write(
             &request_path,
-            serde_json::to_vec(&req).map_err(GetEventsError::ParseJson)?,
+            serde_json::to_vec(&req).map_err(ExportApiCallStatsError::ParseJson)?,
         )
-        .map_err(GetEventsError::WriteRequest)?;
-
-        let result = self.run_qrenderdoc_python(&QRenderDocPythonRequest {
-            script_path: script_path.clone(),
-            args: Vec::new(),
-            working_dir: Some(run_dir.clone()),
-        })?;
-        let _ = result;
+        .map_err(ExportApiCallStatsError::WriteRequest)?;
+
+        self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
 
-        let bytes = std::fs::read(&response_path).map_err(GetEventsError::ReadResponse)?;
-        let env: QRenderDocJsonEnvelope<GetEventsResponse> =
-            serde_json::from_slice(&bytes).map_err(GetEventsError::ParseJson)?;
+        let bytes = std::fs::read(&response_path).map_err(ExportApiCallStatsError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<ExportApiCallStatsResponse> =
+            serde_json::from_slice(&bytes).map_err(ExportApiCallStatsError::ParseJson)?;
         if env.ok {
             env.result
-                .ok_or_else(|| GetEventsError::ScriptError("missing result".into()))
+                .ok_or_else(|| ExportApiCallStatsError::ScriptError("missing result".into()))
         } else {
-            Err(GetEventsError::ScriptError(
+            Err(ExportApiCallStatsError::ScriptError(
                 env.error.unwrap_or_else(|| "unknown error".into()),
             ))
         }
     }
 
-    pub fn get_shader_details(
+    pub fn lint_redundant_state(
         &self,
         cwd: &Path,
-        req: &GetShaderDetailsRequest,
-    ) -> Result<GetShaderDetailsResponse, GetShaderDetailsError> {
+        req: &LintRedundantStateRequest,
+    ) -> Result<LintRedundantStateResponse, LintRedundantStateError> {
+        self.lint_redundant_state_in(None, cwd, req)
+    }
+
+    pub(crate) fn lint_redundant_state_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &LintRedundantStateRequest,
+    ) -> Result<LintRedundantStateResponse, LintRedundantStateError> {
         let scripts_dir = default_scripts_dir(cwd);
-        std::fs::create_dir_all(&scripts_dir).map_err(GetShaderDetailsError::CreateScriptsDir)?;
+        std::fs::create_dir_all(&scripts_dir).map_err(LintRedundantStateError::CreateScriptsDir)?;
 
-        let script_path = scripts_dir.join("get_shader_details_json.py");
-        write_script_file(&script_path, GET_SHADER_DETAILS_JSON_PY)
-            .map_err(GetShaderDetailsError::WriteScript)?;
+        let script_path = scripts_dir.join("lint_redundant_state_json.py");
+        write_script_file(&script_path, LINT_REDUNDANT_STATE_JSON_PY)
+            .map_err(LintRedundantStateError::WriteScript)?;
 
-        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "get_shader_details")
-            .map_err(GetShaderDetailsError::CreateScriptsDir)?;
-        let request_path = run_dir.join("get_shader_details_json.request.json");
-        let response_path = run_dir.join("get_shader_details_json.response.json");
-        remove_if_exists(&response_path).map_err(GetShaderDetailsError::WriteRequest)?;
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "lint_redundant_state")
+            .map_err(LintRedundantStateError::CreateScriptsDir)?;
+        let request_path = run_dir.join("lint_redundant_state_json.request.json");
+        let response_path = run_dir.join("lint_redundant_state_json.response.json");
+        remove_if_exists(&response_path).map_err(LintRedundantStateError::WriteRequest)?;
 
-        let req = GetShaderDetailsRequest {
+        let req = LintRedundantStateRequest {
             capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
-            pipeline_name: req.pipeline_name.clone(),
-            entry_points: req.entry_points.clone(),
+            ..req.clone()
         };
 
         std::fs::write(
             &request_path,
-            serde_json::to_vec(&req).map_err(GetShaderDetailsError::ParseJson)?,
+            serde_json::to_vec(&req).map_err(LintRedundantStateError::ParseJson)?,
         )
-        .map_err(GetShaderDetailsError::WriteRequest)?;
-
-        let result = self.run_qrenderdoc_python(&QRenderDocPythonRequest {
-            script_path: script_path.clone(),
-            args: Vec::new(),
-            working_dir: Some(run_dir.clone()),
-        })?;
+        .map_err(LintRedundantStateError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
         let _ = result;
 
-        let bytes = std::fs::read(&response_path).map_err(GetShaderDetailsError::ReadResponse)?;
-        let env: QRenderDocJsonEnvelope<GetShaderDetailsResponse> =
-            serde_json::from_slice(&bytes).map_err(GetShaderDetailsError::ParseJson)?;
+        let bytes = std::fs::read(&response_path).map_err(LintRedundantStateError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<LintRedundantStateResponse> =
+            serde_json::from_slice(&bytes).map_err(LintRedundantStateError::ParseJson)?;
         if env.ok {
             env.result
-                .ok_or_else(|| GetShaderDetailsError::ScriptError("missing result".into()))
+                .ok_or_else(|| LintRedundantStateError::ScriptError("missing result".into()))
         } else {
-            Err(GetShaderDetailsError::ScriptError(
+            Err(LintRedundantStateError::ScriptError(
                 env.error.unwrap_or_else(|| "unknown error".into()),
             ))
         }
     }
 
-    pub fn get_buffer_details(
+    /// Compare the bound descriptors at every draw / dispatch against shader reflection and report
+    /// bindings no stage references, grouped by pipeline.
+    /// Debug marker begin/end calls that don't pair up, end in another command buffer or stay open
+    /// across a submission, from the recorded calls rather than RenderDoc's balanced tree.
+    pub fn lint_unbalanced_markers(
         &self,
         cwd: &Path,
-        req: &GetBufferDetailsRequest,
-    ) -> Result<GetBufferDetailsResponse, GetBufferDetailsError> {
+        req: &LintUnbalancedMarkersRequest,
+    ) -> Result<LintUnbalancedMarkersResponse, LintUnbalancedMarkersError> {
+        self.lint_unbalanced_markers_in(None, cwd, req)
+    }
+
+    pub(crate) fn lint_unbalanced_markers_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &LintUnbalancedMarkersRequest,
+    ) -> Result<LintUnbalancedMarkersResponse, LintUnbalancedMarkersError> {
         let scripts_dir = default_scripts_dir(cwd);
         std::fs::create_dir_all(&scripts_dir)
-            .map_err(GetBufferDetailsError::CreateScriptsDir)?;
+            .map_err(LintUnbalancedMarkersError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("lint_unbalanced_markers_json.py");
+        write_script_file(&script_path, LINT_UNBALANCED_MARKERS_JSON_PY)
+            .map_err(LintUnbalancedMarkersError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "lint_unbalanced_markers")
+            .map_err(LintUnbalancedMarkersError::CreateScriptsDir)?;
+        let request_path = run_dir.join("lint_unbalanced_markers_json.request.json");
+        let response_path = run_dir.join("lint_unbalanced_markers_json.response.json");
+        remove_if_exists(&response_path).map_err(LintUnbalancedMarkersError::WriteRequest)?;
+
+        let req = LintUnbalancedMarkersRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            ..req.clone()
+        };
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&req).map_err(LintUnbalancedMarkersError::ParseJson)?,
+        )
+        .map_err(LintUnbalancedMarkersError::WriteRequest)?;
+
+        self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
+
+        let bytes =
+            std::fs::read(&response_path).map_err(LintUnbalancedMarkersError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<LintUnbalancedMarkersResponse> =
+            serde_json::from_slice(&bytes).map_err(LintUnbalancedMarkersError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| LintUnbalancedMarkersError::ScriptError("missing result".into()))
+        } else {
+            Err(LintUnbalancedMarkersError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
+    }
+
+    pub fn find_unused_bindings(
+        &self,
+        cwd: &Path,
+        req: &FindUnusedBindingsRequest,
+    ) -> Result<FindUnusedBindingsResponse, FindUnusedBindingsError> {
+        self.find_unused_bindings_in(None, cwd, req)
+    }
+
+    pub(crate) fn find_unused_bindings_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &FindUnusedBindingsRequest,
+    ) -> Result<FindUnusedBindingsResponse, FindUnusedBindingsError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir).map_err(FindUnusedBindingsError::CreateScriptsDir)?;
 
-        let script_path = scripts_dir.join("get_buffer_details_json.py");
-        write_script_file(&script_path, GET_BUFFER_DETAILS_JSON_PY)
-            .map_err(GetBufferDetailsError::WriteScript)?;
+        let script_path = scripts_dir.join("find_unused_bindings_json.py");
+        write_script_file(&script_path, FIND_UNUSED_BINDINGS_JSON_PY)
+            .map_err(FindUnusedBindingsError::WriteScript)?;
 
-        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "get_buffer_details")
-            .map_err(GetBufferDetailsError::CreateScriptsDir)?;
-        let request_path = run_dir.join("get_buffer_details_json.request.json");
-        let response_path = run_dir.join("get_buffer_details_json.response.json");
-        remove_if_exists(&response_path).map_err(GetBufferDetailsError::WriteRequest)?;
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "find_unused_bindings")
+            .map_err(FindUnusedBindingsError::CreateScriptsDir)?;
+        let request_path = run_dir.join("find_unused_bindings_json.request.json");
+        let response_path = run_dir.join("find_unused_bindings_json.response.json");
+        remove_if_exists(&response_path).map_err(FindUnusedBindingsError::WriteRequest)?;
 
-        let req = GetBufferDetailsRequest {
+        let req = FindUnusedBindingsRequest {
             capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
-            buffer_name: req.buffer_name.clone(),
+            replay_options: req.replay_options.clone(),
         };
 
         std::fs::write(
             &request_path,
-            serde_json::to_vec(&req).map_err(GetBufferDetailsError::ParseJson)?,
+            serde_json::to_vec(&req).map_err(FindUnusedBindingsError::ParseJson)?,
         )
-        .map_err(GetBufferDetailsError::WriteRequest)?;
-
-        let result = self.run_qrenderdoc_python(&QRenderDocPythonRequest {
-            script_path: script_path.clone(),
-            args: Vec::new(),
-            working_dir: Some(run_dir.clone()),
-        })?;
+        .map_err(FindUnusedBindingsError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
         let _ = result;
 
-        let bytes =
-            std::fs::read(&response_path).map_err(GetBufferDetailsError::ReadResponse)?;
-        let env: QRenderDocJsonEnvelope<GetBufferDetailsResponse> =
-            serde_json::from_slice(&bytes).map_err(GetBufferDetailsError::ParseJson)?;
+        let bytes = std::fs::read(&response_path).map_err(FindUnusedBindingsError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<FindUnusedBindingsResponse> =
+            serde_json::from_slice(&bytes).map_err(FindUnusedBindingsError::ParseJson)?;
         if env.ok {
             env.result
-                .ok_or_else(|| GetBufferDetailsError::ScriptError("missing result".into()))
+                .ok_or_else(|| FindUnusedBindingsError::ScriptError("missing result".into()))
         } else {
-            Err(GetBufferDetailsError::ScriptError(
+            Err(FindUnusedBindingsError::ScriptError(
                 env.error.unwrap_or_else(|| "unknown error".into()),
             ))
         }
     }
 
-    pub fn get_texture_details(
+    /// Run a battery of "why is this draw invisible" checks at a draw (counts, targets, viewport /
+    /// scissor, clip volume, culling, depth, blending) and return the likely causes ranked by severity.
+    pub fn diagnose_draw(
         &self,
         cwd: &Path,
-        req: &GetTextureDetailsRequest,
-    ) -> Result<GetTextureDetailsResponse, GetTextureDetailsError> {
+        req: &DiagnoseDrawRequest,
+    ) -> Result<DiagnoseDrawResponse, DiagnoseDrawError> {
+        self.diagnose_draw_in(None, cwd, req)
+    }
+
+    pub(crate) fn diagnose_draw_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &DiagnoseDrawRequest,
+    ) -> Result<DiagnoseDrawResponse, DiagnoseDrawError> {
         let scripts_dir = default_scripts_dir(cwd);
-        std::fs::create_dir_all(&scripts_dir)
-            .map_err(GetTextureDetailsError::CreateScriptsDir)?;
+        std::fs::create_dir_all(&scripts_dir).map_err(DiagnoseDrawError::CreateScriptsDir)?;
 
-        let script_path = scripts_dir.join("get_texture_details_json.py");
-        write_script_file(&script_path, GET_TEXTURE_DETAILS_JSON_PY)
-            .map_err(GetTextureDetailsError::WriteScript)?;
+        let script_path = scripts_dir.join("diagnose_draw_json.py");
+        write_script_file(&script_path, DIAGNOSE_DRAW_JSON_PY)
+            .map_err(DiagnoseDrawError::WriteScript)?;
 
-        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "get_texture_details")
-            .map_err(GetTextureDetailsError::CreateScriptsDir)?;
-        let request_path = run_dir.join("get_texture_details_json.request.json");
-        let response_path = run_dir.join("get_texture_details_json.response.json");
-        remove_if_exists(&response_path).map_err(GetTextureDetailsError::WriteRequest)?;
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "diagnose_draw")
+            .map_err(DiagnoseDrawError::CreateScriptsDir)?;
+        let request_path = run_dir.join("diagnose_draw_json.request.json");
+        let response_path = run_dir.join("diagnose_draw_json.response.json");
+        remove_if_exists(&response_path).map_err(DiagnoseDrawError::WriteRequest)?;
 
-        let req = GetTextureDetailsRequest {
+        let req = DiagnoseDrawRequest {
             capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
-            texture_name: req.texture_name.clone(),
+            ..req.clone()
         };
 
         std::fs::write(
             &request_path,
-            serde_json::to_vec(&req).map_err(GetTextureDetailsError::ParseJson)?,
+            serde_json::to_vec(&req).map_err(DiagnoseDrawError::ParseJson)?,
         )
-        .map_err(GetTextureDetailsError::WriteRequest)?;
-
-        let result = self.run_qrenderdoc_python(&QRenderDocPythonRequest {
-            script_path: script_path.clone(),
-            args: Vec::new(),
-            working_dir: Some(run_dir.clone()),
-        })?;
+        .map_err(DiagnoseDrawError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
         let _ = result;
 
-        let bytes =
-            std::fs::read(&response_path).map_err(GetTextureDetailsError::ReadResponse)?;
-        let env: QRenderDocJsonEnvelope<GetTextureDetailsResponse> =
-            serde_json::from_slice(&bytes).map_err(GetTextureDetailsError::ParseJson)?;
+        let bytes = std::fs::read(&response_path).map_err(DiagnoseDrawError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<DiagnoseDrawResponse> =
+            serde_json::from_slice(&bytes).map_err(DiagnoseDrawError::ParseJson)?;
         if env.ok {
             env.result
-                .ok_or_else(|| GetTextureDetailsError::ScriptError("missing result".into()))
+                .ok_or_else(|| DiagnoseDrawError::ScriptError("missing result".into()))
         } else {
-            Err(GetTextureDetailsError::ScriptError(
+            Err(DiagnoseDrawError::ScriptError(
                 env.error.unwrap_or_else(|| "unknown error".into()),
             ))
         }
     }
 
-    pub fn get_buffer_changes_delta(
+    /// Decode every bound constant block at an event (optionally one stage) into typed JSON, including
+    /// nested structs and arrays.
+    pub fn get_constants(
         &self,
         cwd: &Path,
-        req: &GetBufferChangesDeltaRequest,
-    ) -> Result<GetBufferChangesDeltaResponse, GetBufferChangesDeltaError> {
+        req: &GetConstantsRequest,
+    ) -> Result<GetConstantsResponse, GetConstantsError> {
+        self.get_constants_in(None, cwd, req)
+    }
+
+    pub(crate) fn get_constants_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &GetConstantsRequest,
+    ) -> Result<GetConstantsResponse, GetConstantsError> {
         let scripts_dir = default_scripts_dir(cwd);
-        std::fs::create_dir_all(&scripts_dir)
-            .map_err(GetBufferChangesDeltaError::CreateScriptsDir)?;
+        std::fs::create_dir_all(&scripts_dir).map_err(GetConstantsError::CreateScriptsDir)?;
 
-        let script_path = scripts_dir.join("get_buffer_changes_delta_json.py");
-        write_script_file(&script_path, GET_BUFFER_CHANGES_DELTA_JSON_PY)
-            .map_err(GetBufferChangesDeltaError::WriteScript)?;
+        let script_path = scripts_dir.join("get_constants_json.py");
+        write_script_file(&script_path, GET_CONSTANTS_JSON_PY)
+            .map_err(GetConstantsError::WriteScript)?;
 
-        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "get_buffer_changes_delta")
-            .map_err(GetBufferChangesDeltaError::CreateScriptsDir)?;
-        let request_path = run_dir.join("get_buffer_changes_delta_json.request.json");
-        let response_path = run_dir.join("get_buffer_changes_delta_json.response.json");
-        remove_if_exists(&response_path).map_err(GetBufferChangesDeltaError::WriteRequest)?;
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "get_constants")
+            .map_err(GetConstantsError::CreateScriptsDir)?;
+        let request_path = run_dir.join("get_constants_json.request.json");
+        let response_path = run_dir.join("get_constants_json.response.json");
+        remove_if_exists(&response_path).map_err(GetConstantsError::WriteRequest)?;
 
-        let req = GetBufferChangesDeltaRequest {
+        let req = GetConstantsRequest {
             capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
-            buffer_name: req.buffer_name.clone(),
-            tracked_indices: req.tracked_indices.clone(),
+            ..req.clone()
         };
 
         std::fs::write(
             &request_path,
-            serde_json::to_vec(&req).map_err(GetBufferChangesDeltaError::ParseJson)?,
+            serde_json::to_vec(&req).map_err(GetConstantsError::ParseJson)?,
         )
-        .map_err(GetBufferChangesDeltaError::WriteRequest)?;
-
-        let result = self.run_qrenderdoc_python(&QRenderDocPythonRequest {
-            script_path: script_path.clone(),
-            args: Vec::new(),
-            working_dir: Some(run_dir.clone()),
-        })?;
+        .map_err(GetConstantsError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
         let _ = result;
 
-        let bytes =
-            std::fs::read(&response_path).map_err(GetBufferChangesDeltaError::ReadResponse)?;
-        let env: QRenderDocJsonEnvelope<GetBufferChangesDeltaResponse> =
-            serde_json::from_slice(&bytes).map_err(GetBufferChangesDeltaError::ParseJson)?;
+        let bytes = std::fs::read(&response_path).map_err(GetConstantsError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<GetConstantsResponse> =
+            serde_json::from_slice(&bytes).map_err(GetConstantsError::ParseJson)?;
         if env.ok {
             env.result
-                .ok_or_else(|| GetBufferChangesDeltaError::ScriptError("missing result".into()))
+                .ok_or_else(|| GetConstantsError::ScriptError("missing result".into()))
         } else {
-            Err(GetBufferChangesDeltaError::ScriptError(
+            Err(GetConstantsError::ScriptError(
                 env.error.unwrap_or_else(|| "unknown error".into()),
             ))
         }
     }
 
-    pub fn get_texture_changes_delta(
+    /// Enumerate the elements of descriptor arrays (including unbounded / bindless arrays) bound at an
+    /// event, resolving each to its resource and flagging the elements the event accessed.
+    pub fn get_descriptor_arrays(
         &self,
         cwd: &Path,
-        req: &GetTextureChangesDeltaRequest,
-    ) -> Result<GetTextureChangesDeltaResponse, GetTextureChangesDeltaError> {
+        req: &GetDescriptorArraysRequest,
+    ) -> Result<GetDescriptorArraysResponse, GetDescriptorArraysError> {
+        self.get_descriptor_arrays_in(None, cwd, req)
+    }
+
+    pub(crate) fn get_descriptor_arrays_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &GetDescriptorArraysRequest,
+    ) -> Result<GetDescriptorArraysResponse, GetDescriptorArraysError> {
         let scripts_dir = default_scripts_dir(cwd);
         std::fs::create_dir_all(&scripts_dir)
-            .map_err(GetTextureChangesDeltaError::CreateScriptsDir)?;
+            .map_err(GetDescriptorArraysError::CreateScriptsDir)?;
 
-        let script_path = scripts_dir.join("get_texture_changes_delta_json.py");
-        write_script_file(&script_path, GET_TEXTURE_CHANGES_DELTA_JSON_PY)
-            .map_err(GetTextureChangesDeltaError::WriteScript)?;
+        let script_path = scripts_dir.join("get_descriptor_arrays_json.py");
+        write_script_file(&script_path, GET_DESCRIPTOR_ARRAYS_JSON_PY)
+            .map_err(GetDescriptorArraysError::WriteScript)?;
 
-        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "get_texture_changes_delta")
-            .map_err(GetTextureChangesDeltaError::CreateScriptsDir)?;
-        let request_path = run_dir.join("get_texture_changes_delta_json.request.json");
-        let response_path = run_dir.join("get_texture_changes_delta_json.response.json");
-        remove_if_exists(&response_path).map_err(GetTextureChangesDeltaError::WriteRequest)?;
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "get_descriptor_arrays")
+            .map_err(GetDescriptorArraysError::CreateScriptsDir)?;
+        let request_path = run_dir.join("get_descriptor_arrays_json.request.json");
+        let response_path = run_dir.join("get_descriptor_arrays_json.response.json");
+        remove_if_exists(&response_path).map_err(GetDescriptorArraysError::WriteRequest)?;
 
-        let req = GetTextureChangesDeltaRequest {
+        let req = GetDescriptorArraysRequest {
             capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
-            texture_name: req.texture_name.clone(),
-            tracked_texels: req.tracked_texels.clone(),
+            ..req.clone()
         };
 
         std::fs::write(
             &request_path,
-            serde_json::to_vec(&req).map_err(GetTextureChangesDeltaError::ParseJson)?,
+            serde_json::to_vec(&req).map_err(GetDescriptorArraysError::ParseJson)?,
         )
-        .map_err(GetTextureChangesDeltaError::WriteRequest)?;
-
-        let result = self.run_qrenderdoc_python(&QRenderDocPythonRequest {
-            script_path: script_path.clone(),
-            args: Vec::new(),
-            working_dir: Some(run_dir.clone()),
-        })?;
+        .map_err(GetDescriptorArraysError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
         let _ = result;
 
         let bytes =
-            std::fs::read(&response_path).map_err(GetTextureChangesDeltaError::ReadResponse)?;
-        let env: QRenderDocJsonEnvelope<GetTextureChangesDeltaResponse> =
-            serde_json::from_slice(&bytes).map_err(GetTextureChangesDeltaError::ParseJson)?;
+            std::fs::read(&response_path).map_err(GetDescriptorArraysError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<GetDescriptorArraysResponse> =
+            serde_json::from_slice(&bytes).map_err(GetDescriptorArraysError::ParseJson)?;
         if env.ok {
             env.result
-                .ok_or_else(|| GetTextureChangesDeltaError::ScriptError("missing result".into()))
+                .ok_or_else(|| GetDescriptorArraysError::ScriptError("missing result".into()))
         } else {
-            Err(GetTextureChangesDeltaError::ScriptError(
+            Err(GetDescriptorArraysError::ScriptError(
                 env.error.unwrap_or_else(|| "unknown error".into()),
             ))
         }
     }
 
-    pub fn get_pipeline_details(
+    /// Write a texture as DDS (keeping block-compressed formats) or as raw texel bytes of one
+    /// subresource plus a JSON layout descriptor.
+    pub fn save_texture_raw(
         &self,
         cwd: &Path,
-        req: &GetPipelineDetailsRequest,
-    ) -> Result<GetPipelineDetailsResponse, GetPipelineDetailsError> {
+        req: &SaveTextureRawRequest,
+    ) -> Result<SaveTextureRawResponse, SaveTextureRawError> {
+        self.save_texture_raw_in(None, cwd, req)
+    }
+
+    pub(crate) fn save_texture_raw_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &SaveTextureRawRequest,
+    ) -> Result<SaveTextureRawResponse, SaveTextureRawError> {
         let scripts_dir = default_scripts_dir(cwd);
-        std::fs::create_dir_all(&scripts_dir)
-            .map_err(GetPipelineDetailsError::CreateScriptsDir)?;
+        std::fs::create_dir_all(&scripts_dir).map_err(SaveTextureRawError::CreateScriptsDir)?;
 
-        let script_path = scripts_dir.join("get_pipeline_details_json.py");
-        write_script_file(&script_path, GET_PIPELINE_DETAILS_JSON_PY)
-            .map_err(GetPipelineDetailsError::WriteScript)?;
+        let script_path = scripts_dir.join("save_texture_raw_json.py");
+        write_script_file(&script_path, SAVE_TEXTURE_RAW_JSON_PY)
+            .map_err(SaveTextureRawError::WriteScript)?;
 
-        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "get_pipeline_details")
-            .map_err(GetPipelineDetailsError::CreateScriptsDir)?;
-        let request_path = run_dir.join("get_pipeline_details_json.request.json");
-        let response_path = run_dir.join("get_pipeline_details_json.response.json");
-        remove_if_exists(&response_path).map_err(GetPipelineDetailsError::WriteRequest)?;
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "save_texture_raw")
+            .map_err(SaveTextureRawError::CreateScriptsDir)?;
+        let request_path = run_dir.join("save_texture_raw_json.request.json");
+        let response_path = run_dir.join("save_texture_raw_json.response.json");
+        remove_if_exists(&response_path).map_err(SaveTextureRawError::WriteRequest)?;
 
-        let req = GetPipelineDetailsRequest {
+        let req = SaveTextureRawRequest {
             capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
-            pipeline_name: req.pipeline_name.clone(),
+            output_path: resolve_path_string_from_cwd(cwd, &req.output_path),
+            ..req.clone()
         };
 
         std::fs::write(
             &request_path,
-            serde_json::to_vec(&req).map_err(GetPipelineDetailsError::ParseJson)?,
+            serde_json::to_vec(&req).map_err(SaveTextureRawError::ParseJson)?,
         )
-        .map_err(GetPipelineDetailsError::WriteRequest)?;
-
-        let result = self.run_qrenderdoc_python(&QRenderDocPythonRequest {
-            script_path: script_path.clone(),
-            args: Vec::new(),
-            working_dir: Some(run_dir.clone()),
-        })?;
+        .map_err(SaveTextureRawError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
         let _ = result;
 
-        let bytes =
-            std::fs::read(&response_path).map_err(GetPipelineDetailsError::ReadResponse)?;
-        let env: QRenderDocJsonEnvelope<GetPipelineDetailsResponse> =
-            serde_json::from_slice(&bytes).map_err(GetPipelineDetailsError::ParseJson)?;
+        let bytes = std::fs::read(&response_path).map_err(SaveTextureRawError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<SaveTextureRawResponse> =
+            serde_json::from_slice(&bytes).map_err(SaveTextureRawError::ParseJson)?;
         if env.ok {
             env.result
-                .ok_or_else(|| GetPipelineDetailsError::ScriptError("missing result".into()))
+                .ok_or_else(|| SaveTextureRawError::ScriptError("missing result".into()))
         } else {
-            Err(GetPipelineDetailsError::ScriptError(
+            Err(SaveTextureRawError::ScriptError(
                 env.error.unwrap_or_else(|| "unknown error".into()),
             ))
         }
     }
 
-    pub fn get_pipeline_binding_changes_delta(
+    /// Export a depth texture as a 16-bit PNG, a float32 TIFF or a false-colour PNG, optionally
+    /// linearized with the given near / far planes and normalized to the range actually covered.
+    pub fn save_depth(
         &self,
         cwd: &Path,
-        req: &GetPipelineBindingChangesDeltaRequest,
-    ) -> Result<GetPipelineBindingChangesDeltaResponse, GetPipelineBindingChangesDeltaError> {
+        req: &SaveDepthRequest,
+    ) -> Result<SaveDepthResponse, SaveDepthError> {
+        self.save_depth_in(None, cwd, req)
+    }
+
+    pub(crate) fn save_depth_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &SaveDepthRequest,
+    ) -> Result<SaveDepthResponse, SaveDepthError> {
         let scripts_dir = default_scripts_dir(cwd);
-        std::fs::create_dir_all(&scripts_dir)
-            .map_err(GetPipelineBindingChangesDeltaError::CreateScriptsDir)?;
+        std::fs::create_dir_all(&scripts_dir).map_err(SaveDepthError::CreateScriptsDir)?;
 
-        let script_path = scripts_dir.join("get_pipeline_binding_changes_delta_json.py");
-        write_script_file(&script_path, GET_PIPELINE_BINDING_CHANGES_DELTA_JSON_PY)
-            .map_err(GetPipelineBindingChangesDeltaError::WriteScript)?;
+        let script_path = scripts_dir.join("save_depth_json.py");
+        write_script_file(&script_path, SAVE_DEPTH_JSON_PY).map_err(SaveDepthError::WriteScript)?;
 
-        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "get_pipeline_binding_changes_delta")
-            .map_err(GetPipelineBindingChangesDeltaError::CreateScriptsDir)?;
-        let request_path = run_dir.join("get_pipeline_binding_changes_delta_json.request.json");
-        let response_path = run_dir.join("get_pipeline_binding_changes_delta_json.response.json");
-        remove_if_exists(&response_path).map_err(GetPipelineBindingChangesDeltaError::WriteRequest)?;
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "save_depth")
+            .map_err(SaveDepthError::CreateScriptsDir)?;
+        let request_path = run_dir.join("save_depth_json.request.json");
+        let response_path = run_dir.join("save_depth_json.response.json");
+        remove_if_exists(&response_path).map_err(SaveDepthError::WriteRequest)?;
 
-        let req = GetPipelineBindingChangesDeltaRequest {
+        let req = SaveDepthRequest {
             capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
-            pipeline_name: req.pipeline_name.clone(),
+            output_path: resolve_path_string_from_cwd(cwd, &req.output_path),
+            ..req.clone()
         };
 
         std::fs::write(
             &request_path,
-            serde_json::to_vec(&req).map_err(GetPipelineBindingChangesDeltaError::ParseJson)?,
+            serde_json::to_vec(&req).map_err(SaveDepthError::ParseJson)?,
         )
-        .map_err(GetPipelineBindingChangesDeltaError::WriteRequest)?;
-
-        let result = self.run_qrenderdoc_python(&QRenderDocPythonRequest {
-            script_path: script_path.clone(),
-            args: Vec::new(),
-            working_dir: Some(run_dir.clone()),
-        })?;
+        .map_err(SaveDepthError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
         let _ = result;
 
-        let bytes =
-            std::fs::read(&response_path).map_err(GetPipelineBindingChangesDeltaError::ReadResponse)?;
-        let env: QRenderDocJsonEnvelope<GetPipelineBindingChangesDeltaResponse> =
-            serde_json::from_slice(&bytes).map_err(GetPipelineBindingChangesDeltaError::ParseJson)?;
+        let bytes = std::fs::read(&response_path).map_err(SaveDepthError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<SaveDepthResponse> =
+            serde_json::from_slice(&bytes).map_err(SaveDepthError::ParseJson)?;
         if env.ok {
             env.result
-                .ok_or_else(|| GetPipelineBindingChangesDeltaError::ScriptError("missing result".into()))
+                .ok_or_else(|| SaveDepthError::ScriptError("missing result".into()))
         } else {
-            Err(GetPipelineBindingChangesDeltaError::ScriptError(
+            Err(SaveDepthError::ScriptError(
                 env.error.unwrap_or_else(|| "unknown error".into()),
             ))
         }
     }
 
-    pub fn get_event_pipeline_state(
+    /// Write a buffer's contents to a .bin file, or to CSV / JSONL rows when a shader reflects a
+    /// struct layout for it.
+    pub fn dump_buffer(
         &self,
         cwd: &Path,
-        req: &GetEventPipelineStateRequest,
-    ) -> Result<GetEventPipelineStateResponse, GetEventPipelineStateError> {
+        req: &DumpBufferRequest,
+    ) -> Result<DumpBufferResponse, DumpBufferError> {
+        self.dump_buffer_in(None, cwd, req)
+    }
+
+    pub(crate) fn dump_buffer_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &DumpBufferRequest,
+    ) -> Result<DumpBufferResponse, DumpBufferError> {
         let scripts_dir = default_scripts_dir(cwd);
-        std::fs::create_dir_all(&scripts_dir)
-            .map_err(GetEventPipelineStateError::CreateScriptsDir)?;
+        std::fs::create_dir_all(&scripts_dir).map_err(DumpBufferError::CreateScriptsDir)?;
 
-        let script_path = scripts_dir.join("get_event_pipeline_state_json.py");
-        write_script_file(&script_path, GET_EVENT_PIPELINE_STATE_JSON_PY)
-            .map_err(GetEventPipelineStateError::WriteScript)?;
+        let script_path = scripts_dir.join("dump_buffer_json.py");
+        write_script_file(&script_path, DUMP_BUFFER_JSON_PY)
+            .map_err(DumpBufferError::WriteScript)?;
 
-        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "get_event_pipeline_state")
-            .map_err(GetEventPipelineStateError::CreateScriptsDir)?;
-        let request_path = run_dir.join("get_event_pipeline_state_json.request.json");
-        let response_path = run_dir.join("get_event_pipeline_state_json.response.json");
-        remove_if_exists(&response_path).map_err(GetEventPipelineStateError::WriteRequest)?;
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "dump_buffer")
+            .map_err(DumpBufferError::CreateScriptsDir)?;
+        let request_path = run_dir.join("dump_buffer_json.request.json");
+        let response_path = run_dir.join("dump_buffer_json.response.json");
+        remove_if_exists(&response_path).map_err(DumpBufferError::WriteRequest)?;
 
-        let req = GetEventPipelineStateRequest {
+        let req = DumpBufferRequest {
             capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
-            event_id: req.event_id,
+            output_path: resolve_path_string_from_cwd(cwd, &req.output_path),
+            ..req.clone()
         };
 
         std::fs::write(
             &request_path,
-            serde_json::to_vec(&req).map_err(GetEventPipelineStateError::ParseJson)?,
+            serde_json::to_vec(&req).map_err(DumpBufferError::ParseJson)?,
         )
-        .map_err(GetEventPipelineStateError::WriteRequest)?;
-
-        let result = self.run_qrenderdoc_python(&QRenderDocPythonRequest {
-            script_path: script_path.clone(),
-            args: Vec::new(),
-            working_dir: Some(run_dir.clone()),
-        })?;
+        .map_err(DumpBufferError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
         let _ = result;
 
-        let bytes =
-            std::fs::read(&response_path).map_err(GetEventPipelineStateError::ReadResponse)?;
-        let env: QRenderDocJsonEnvelope<GetEventPipelineStateResponse> =
-            serde_json::from_slice(&bytes).map_err(GetEventPipelineStateError::ParseJson)?;
+        let bytes = std::fs::read(&response_path).map_err(DumpBufferError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<DumpBufferResponse> =
+            serde_json::from_slice(&bytes).map_err(DumpBufferError::ParseJson)?;
         if env.ok {
             env.result
-                .ok_or_else(|| GetEventPipelineStateError::ScriptError("missing result".into()))
+                .ok_or_else(|| DumpBufferError::ScriptError("missing result".into()))
         } else {
-            Err(GetEventPipelineStateError::ScriptError(
+            Err(DumpBufferError::ScriptError(
                 env.error.unwrap_or_else(|| "unknown error".into()),
             ))
         }
     }
 
-    pub fn get_resource_changed_event_ids(
+    /// Read back the storage / UAV buffers bound at an event (typically just after a compute dispatch),
+    /// decoding elements with the reflected struct layout.
+    pub fn readback_rw_buffers(
         &self,
         cwd: &Path,
-        req: &GetResourceChangedEventIdsRequest,
-    ) -> Result<GetResourceChangedEventIdsResponse, GetResourceChangedEventIdsError> {
-        let scripts_dir = default_scripts_dir(cwd);
-        std::fs::create_dir_all(&scripts_dir)
-            .map_err(GetResourceChangedEventIdsError::CreateScriptsDir)?;
+        req: &ReadbackRwBuffersRequest,
+    ) -> Result<ReadbackRwBuffersResponse, ReadbackRwBuffersError> {
+        self.readback_rw_buffers_in(None, cwd, req)
+    }
 
-        let script_path = scripts_dir.join("get_resource_changed_event_ids_json.py");
-        write_script_file(&script_path, GET_RESOURCE_CHANGED_EVENT_IDS_JSON_PY)
-            .map_err(GetResourceChangedEventIdsError::WriteScript)?;
+    pub(crate) fn readback_rw_buffers_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &ReadbackRwBuffersRequest,
+    ) -> Result<ReadbackRwBuffersResponse, ReadbackRwBuffersError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir).map_err(ReadbackRwBuffersError::CreateScriptsDir)?;
 
-        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "get_resource_changed_event_ids")
-            .map_err(GetResourceChangedEventIdsError::CreateScriptsDir)?;
-        let request_path = run_dir.join("get_resource_changed_event_ids_json.request.json");
-        let response_path = run_dir.join("get_resource_changed_event_ids_json.response.json");
-        remove_if_exists(&response_path).map_err(GetResourceChangedEventIdsError::WriteRequest)?;
+        let script_path = scripts_dir.join("readback_rw_buffers_json.py");
+        write_script_file(&script_path, READBACK_RW_BUFFERS_JSON_PY)
+            .map_err(ReadbackRwBuffersError::WriteScript)?;
 
-        let req = GetResourceChangedEventIdsRequest {
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "readback_rw_buffers")
+            .map_err(ReadbackRwBuffersError::CreateScriptsDir)?;
+        let request_path = run_dir.join("readback_rw_buffers_json.request.json");
+        let response_path = run_dir.join("readback_rw_buffers_json.response.json");
+        remove_if_exists(&response_path).map_err(ReadbackRwBuffersError::WriteRequest)?;
+
+        let req = ReadbackRwBuffersRequest {
             capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
-            resource_name: req.resource_name.clone(),
+            ..req.clone()
         };
 
         std::fs::write(
             &request_path,
-            serde_json::to_vec(&req).map_err(GetResourceChangedEventIdsError::ParseJson)?,
+            serde_json::to_vec(&req).map_err(ReadbackRwBuffersError::ParseJson)?,
         )
-        .map_err(GetResourceChangedEventIdsError::WriteRequest)?;
-
-        let result = self.run_qrenderdoc_python(&QRenderDocPythonRequest {
-            script_path: script_path.clone(),
-            args: Vec::new(),
-            working_dir: Some(run_dir.clone()),
-        })?;
+        .map_err(ReadbackRwBuffersError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
         let _ = result;
 
-        let bytes =
-            std::fs::read(&response_path).map_err(GetResourceChangedEventIdsError::ReadResponse)?;
-        let env: QRenderDocJsonEnvelope<GetResourceChangedEventIdsResponse> =
-            serde_json::from_slice(&bytes).map_err(GetResourceChangedEventIdsError::ParseJson)?;
+        let bytes = std::fs::read(&response_path).map_err(ReadbackRwBuffersError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<ReadbackRwBuffersResponse> =
+            serde_json::from_slice(&bytes).map_err(ReadbackRwBuffersError::ParseJson)?;
         if env.ok {
-            env.result.ok_or_else(|| {
-                GetResourceChangedEventIdsError::ScriptError("missing result".into())
-            })
+            env.result
+                .ok_or_else(|| ReadbackRwBuffersError::ScriptError("missing result".into()))
         } else {
-            Err(GetResourceChangedEventIdsError::ScriptError(
+            Err(ReadbackRwBuffersError::ScriptError(
                 env.error.unwrap_or_else(|| "unknown error".into()),
             ))
         }
     }
 
-    pub fn search_resources(
+    /// Replay the frame draw by draw, saving RT0 (and optionally depth) after every matching draw plus
+    /// an index JSON, so frame construction can be reviewed as an image sequence.
+    pub fn export_flipbook(
         &self,
         cwd: &Path,
-        req: &SearchResourcesRequest,
-    ) -> Result<SearchResourcesResponse, SearchResourcesError> {
+        req: &ExportFlipbookRequest,
+    ) -> Result<ExportFlipbookResponse, ExportFlipbookError> {
+        self.export_flipbook_in(None, cwd, req)
+    }
+
+    pub(crate) fn export_flipbook_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &ExportFlipbookRequest,
+    ) -> Result<ExportFlipbookResponse, ExportFlipbookError> {
         let scripts_dir = default_scripts_dir(cwd);
-        std::fs::create_dir_all(&scripts_dir).map_err(SearchResourcesError::CreateScriptsDir)?;
+        std::fs::create_dir_all(&scripts_dir).map_err(ExportFlipbookError::CreateScriptsDir)?;
 
-        let script_path = scripts_dir.join("search_resources_json.py");
-        write_script_file(&script_path, SEARCH_RESOURCES_JSON_PY)
-            .map_err(SearchResourcesError::WriteScript)?;
+        let script_path = scripts_dir.join("export_flipbook_json.py");
+        write_script_file(&script_path, EXPORT_FLIPBOOK_JSON_PY)
+            .map_err(ExportFlipbookError::WriteScript)?;
 
-        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "search_resources")
-            .map_err(SearchResourcesError::CreateScriptsDir)?;
-        let request_path = run_dir.join("search_resources_json.request.json");
-        let response_path = run_dir.join("search_resources_json.response.json");
-        remove_if_exists(&response_path).map_err(SearchResourcesError::WriteRequest)?;
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "export_flipbook")
+            .map_err(ExportFlipbookError::CreateScriptsDir)?;
+        let request_path = run_dir.join("export_flipbook_json.request.json");
+        let response_path = run_dir.join("export_flipbook_json.response.json");
+        remove_if_exists(&response_path).map_err(ExportFlipbookError::WriteRequest)?;
 
-        let req = SearchResourcesRequest {
+        let req = ExportFlipbookRequest {
             capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
-            query: req.query.clone(),
-            case_sensitive: req.case_sensitive,
-            max_results: req.max_results,
-            resource_types: req.resource_types.clone(),
+            output_dir: resolve_path_string_from_cwd(cwd, &req.output_dir),
+            ..req.clone()
         };
 
         std::fs::write(
             &request_path,
-            serde_json::to_vec(&req).map_err(SearchResourcesError::ParseJson)?,
+            serde_json::to_vec(&req).map_err(ExportFlipbookError::ParseJson)?,
         )
-        .map_err(SearchResourcesError::WriteRequest)?;
-
-        let result = self.run_qrenderdoc_python(&QRenderDocPythonRequest {
-            script_path: script_path.clone(),
-            args: Vec::new(),
-            working_dir: Some(run_dir.clone()),
-        })?;
+        .map_err(ExportFlipbookError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
         let _ = result;
 
-        let bytes = std::fs::read(&response_path).map_err(SearchResourcesError::ReadResponse)?;
-        let env: QRenderDocJsonEnvelope<SearchResourcesResponse> =
-            serde_json::from_slice(&bytes).map_err(SearchResourcesError::ParseJson)?;
+        let bytes = std::fs::read(&response_path).map_err(ExportFlipbookError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<ExportFlipbookResponse> =
+            serde_json::from_slice(&bytes).map_err(ExportFlipbookError::ParseJson)?;
         if env.ok {
             env.result
-                .ok_or_else(|| SearchResourcesError::ScriptError("missing result".into()))
+                .ok_or_else(|| ExportFlipbookError::ScriptError("missing result".into()))
         } else {
-            Err(SearchResourcesError::ScriptError(
+            Err(ExportFlipbookError::ScriptError(
                 env.error.unwrap_or_else(|| "unknown error".into()),
             ))
         }
     }
 
-    pub fn find_resource_uses(
+    /// Replay to `event_id` and save RT0 downscaled to at most `max_dim` pixels as a PNG preview.
+    ///
+    /// Unlike [`Self::save_thumbnail`], which extracts the thumbnail embedded at capture time, this
+    /// works for any event.
+    pub fn render_thumbnail(
         &self,
         cwd: &Path,
-        req: &FindResourceUsesRequest,
-    ) -> Result<FindResourceUsesResponse, FindResourceUsesError> {
+        req: &RenderThumbnailRequest,
+    ) -> Result<RenderThumbnailResponse, RenderThumbnailError> {
+        self.render_thumbnail_in(None, cwd, req)
+    }
+
+    pub(crate) fn render_thumbnail_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &RenderThumbnailRequest,
+    ) -> Result<RenderThumbnailResponse, RenderThumbnailError> {
         let scripts_dir = default_scripts_dir(cwd);
-        std::fs::create_dir_all(&scripts_dir).map_err(FindResourceUsesError::CreateScriptsDir)?;
+        std::fs::create_dir_all(&scripts_dir).map_err(RenderThumbnailError::CreateScriptsDir)?;
 
-        let script_path = scripts_dir.join("find_resource_uses_json.py");
-        write_script_file(&script_path, FIND_RESOURCE_USES_JSON_PY)
-            .map_err(FindResourceUsesError::WriteScript)?;
+        let script_path = scripts_dir.join("render_thumbnail_json.py");
+        write_script_file(&script_path, RENDER_THUMBNAIL_JSON_PY)
+            .map_err(RenderThumbnailError::WriteScript)?;
 
-        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "find_resource_uses")
-            .map_err(FindResourceUsesError::CreateScriptsDir)?;
-        let request_path = run_dir.join("find_resource_uses_json.request.json");
-        let response_path = run_dir.join("find_resource_uses_json.response.json");
-        remove_if_exists(&response_path).map_err(FindResourceUsesError::WriteRequest)?;
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "render_thumbnail")
+            .map_err(RenderThumbnailError::CreateScriptsDir)?;
+        let request_path = run_dir.join("render_thumbnail_json.request.json");
+        let response_path = run_dir.join("render_thumbnail_json.response.json");
+        remove_if_exists(&response_path).map_err(RenderThumbnailError::WriteRequest)?;
 
-        let req = FindResourceUsesRequest {
+        let req = RenderThumbnailRequest {
             capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
-            resource: req.resource.clone(),
-            max_results: req.max_results,
-            data_sample_bytes: req.data_sample_bytes,
-            delta_filter: req.delta_filter.clone(),
+            output_path: resolve_path_string_from_cwd(cwd, &req.output_path),
+            ..req.clone()
         };
 
         std::fs::write(
             &request_path,
-            serde_json::to_vec(&req).map_err(FindResourceUsesError::ParseJson)?,
+            serde_json::to_vec(&req).map_err(RenderThumbnailError::ParseJson)?,
         )
-        .map_err(FindResourceUsesError::WriteRequest)?;
-
-        let result = self.run_qrenderdoc_python(&QRenderDocPythonRequest {
-            script_path: script_path.clone(),
-            args: Vec::new(),
-            working_dir: Some(run_dir.clone()),
-        })?;
+        .map_err(RenderThumbnailError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
         let _ = result;
 
-        let bytes = std::fs::read(&response_path).map_err(FindResourceUsesError::ReadResponse)?;
-        let env: QRenderDocJsonEnvelope<FindResourceUsesResponse> =
-            serde_json::from_slice(&bytes).map_err(FindResourceUsesError::ParseJson)?;
+        let bytes = std::fs::read(&response_path).map_err(RenderThumbnailError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<RenderThumbnailResponse> =
+            serde_json::from_slice(&bytes).map_err(RenderThumbnailError::ParseJson)?;
         if env.ok {
             env.result
-                .ok_or_else(|| FindResourceUsesError::ScriptError("missing result".into()))
+                .ok_or_else(|| RenderThumbnailError::ScriptError("missing result".into()))
         } else {
-            Err(FindResourceUsesError::ScriptError(
+            Err(RenderThumbnailError::ScriptError(
                 env.error.unwrap_or_else(|| "unknown error".into()),
             ))
         }
     }
 
-    pub fn export_bindings_index_jsonl(
+    /// Write a Markdown report of a capture: metadata, the marker tree with draw counts, the most used
+    /// pipelines, the largest targets and (optionally) per-pass images linked relatively.
+    pub fn generate_report_markdown(
         &self,
         cwd: &Path,
-        req: &ExportBindingsIndexRequest,
-    ) -> Result<ExportBindingsIndexResponse, ExportBindingsIndexError> {
+        req: &GenerateReportMarkdownRequest,
+    ) -> Result<GenerateReportMarkdownResponse, GenerateReportMarkdownError> {
+        self.generate_report_markdown_in(None, cwd, req)
+    }
+
+    pub(crate) fn generate_report_markdown_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &GenerateReportMarkdownRequest,
+    ) -> Result<GenerateReportMarkdownResponse, GenerateReportMarkdownError> {
         let scripts_dir = default_scripts_dir(cwd);
-        std::fs::create_dir_all(&scripts_dir).map_err(ExportBindingsIndexError::CreateOutputDir)?;
+        std::fs::create_dir_all(&scripts_dir)
+            .map_err(GenerateReportMarkdownError::CreateScriptsDir)?;
 
-        let script_path = scripts_dir.join("export_bindings_index_jsonl.py");
-        write_script_file(&script_path, EXPORT_BINDINGS_INDEX_JSONL_PY)
-            .map_err(ExportBindingsIndexError::WriteScript)?;
+        let script_path = scripts_dir.join("generate_report_markdown_json.py");
+        write_script_file(&script_path, GENERATE_REPORT_MARKDOWN_JSON_PY)
+            .map_err(GenerateReportMarkdownError::WriteScript)?;
 
-        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "export_bindings_index_jsonl")
-            .map_err(ExportBindingsIndexError::CreateOutputDir)?;
-        let request_path = run_dir.join("export_bindings_index_jsonl.request.json");
-        let response_path = run_dir.join("export_bindings_index_jsonl.response.json");
-        remove_if_exists(&response_path).map_err(ExportBindingsIndexError::WriteRequest)?;
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "generate_report_markdown")
+            .map_err(GenerateReportMarkdownError::CreateScriptsDir)?;
+        let request_path = run_dir.join("generate_report_markdown_json.request.json");
+        let response_path = run_dir.join("generate_report_markdown_json.response.json");
+        remove_if_exists(&response_path).map_err(GenerateReportMarkdownError::WriteRequest)?;
 
-        let req = ExportBindingsIndexRequest {
+        let req = GenerateReportMarkdownRequest {
             capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
             output_dir: resolve_path_string_from_cwd(cwd, &req.output_dir),
             ..req.clone()
@@ -2389,83 +8577,124 @@ impl RenderDocInstallation {
 
         std::fs::write(
             &request_path,
-            serde_json::to_vec(&req).map_err(ExportBindingsIndexError::ParseJson)?,
+            serde_json::to_vec(&req).map_err(GenerateReportMarkdownError::ParseJson)?,
         )
-        .map_err(ExportBindingsIndexError::WriteRequest)?;
-
-        let result = self.run_qrenderdoc_python(&QRenderDocPythonRequest {
-            script_path: script_path.clone(),
-            args: Vec::new(),
-            working_dir: Some(run_dir.clone()),
-        })?;
+        .map_err(GenerateReportMarkdownError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
+            },
+        )?;
         let _ = result;
+
         let bytes =
-            std::fs::read(&response_path).map_err(ExportBindingsIndexError::ReadResponse)?;
-        let env: QRenderDocJsonEnvelope<ExportBindingsIndexResponse> =
-            serde_json::from_slice(&bytes).map_err(ExportBindingsIndexError::ParseJson)?;
+            std::fs::read(&response_path).map_err(GenerateReportMarkdownError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<GenerateReportMarkdownResponse> =
+            serde_json::from_slice(&bytes).map_err(GenerateReportMarkdownError::ParseJson)?;
         if env.ok {
             env.result
-                .ok_or_else(|| ExportBindingsIndexError::ScriptError("missing result".into()))
+                .ok_or_else(|| GenerateReportMarkdownError::ScriptError("missing result".into()))
         } else {
-            Err(ExportBindingsIndexError::ScriptError(
+            Err(GenerateReportMarkdownError::ScriptError(
                 env.error.unwrap_or_else(|| "unknown error".into()),
             ))
         }
     }
 
-    pub fn export_bundle_jsonl(
+    /// Write a self-contained HTML report (frame stats, marker tree, per-pass RT0 thumbnails and
+    /// filterable action/binding tables) for sharing with people without RenderDoc.
+    ///
+    /// Runs [`Self::export_bundle_jsonl`] first and embeds its output.
+    pub fn generate_report_html(
         &self,
         cwd: &Path,
-        req: &ExportBundleRequest,
-    ) -> Result<ExportBundleResponse, ExportBundleError> {
-        let capture_path = resolve_path_string_from_cwd(cwd, &req.capture_path);
-        let output_dir = resolve_path_string_from_cwd(cwd, &req.output_dir);
+        req: &GenerateReportHtmlRequest,
+    ) -> Result<GenerateReportHtmlResponse, GenerateReportHtmlError> {
+        self.generate_report_html_in(None, cwd, req)
+    }
 
-        let actions = self.export_actions_jsonl(
+    pub(crate) fn generate_report_html_in(
+        &self,
+        session: Option<&ReplaySession>,
+        cwd: &Path,
+        req: &GenerateReportHtmlRequest,
+    ) -> Result<GenerateReportHtmlResponse, GenerateReportHtmlError> {
+        let scripts_dir = default_scripts_dir(cwd);
+        std::fs::create_dir_all(&scripts_dir).map_err(GenerateReportHtmlError::CreateScriptsDir)?;
+
+        let script_path = scripts_dir.join("generate_report_html_json.py");
+        write_script_file(&script_path, GENERATE_REPORT_HTML_JSON_PY)
+            .map_err(GenerateReportHtmlError::WriteScript)?;
+
+        let run_dir = create_qrenderdoc_run_dir(&scripts_dir, "generate_report_html")
+            .map_err(GenerateReportHtmlError::CreateScriptsDir)?;
+        let request_path = run_dir.join("generate_report_html_json.request.json");
+        let response_path = run_dir.join("generate_report_html_json.response.json");
+        remove_if_exists(&response_path).map_err(GenerateReportHtmlError::WriteRequest)?;
+
+        let req = GenerateReportHtmlRequest {
+            capture_path: resolve_path_string_from_cwd(cwd, &req.capture_path),
+            output_dir: resolve_path_string_from_cwd(cwd, &req.output_dir),
+            ..req.clone()
+        };
+
+        let bundle = self.export_bundle_jsonl_in(
+            session,
             cwd,
-            &ExportActionsRequest {
-                capture_path: capture_path.clone(),
-                output_dir: output_dir.clone(),
+            &ExportBundleRequest {
+                capture_path: req.capture_path.clone(),
+                output_dir: req.output_dir.clone(),
                 basename: req.basename.clone(),
-                only_drawcalls: req.only_drawcalls,
-                marker_prefix: req.marker_prefix.clone(),
-                event_id_min: req.event_id_min,
-                event_id_max: req.event_id_max,
-                name_contains: req.name_contains.clone(),
-                marker_contains: req.marker_contains.clone(),
-                case_sensitive: req.case_sensitive,
+                only_drawcalls: false,
+                marker_prefix: None,
+                event_id_min: None,
+                event_id_max: None,
+                name_contains: None,
+                marker_contains: None,
+                case_sensitive: false,
+                include_cbuffers: false,
+                include_outputs: false,
+                compress: false,
+                replay_options: req.replay_options.clone(),
             },
         )?;
 
-        let bindings = self.export_bindings_index_jsonl(
-            cwd,
-            &ExportBindingsIndexRequest {
-                capture_path: capture_path.clone(),
-                output_dir: output_dir.clone(),
-                basename: req.basename.clone(),
-                marker_prefix: req.marker_prefix.clone(),
-                event_id_min: req.event_id_min,
-                event_id_max: req.event_id_max,
-                name_contains: req.name_contains.clone(),
-                marker_contains: req.marker_contains.clone(),
-                case_sensitive: req.case_sensitive,
-                include_cbuffers: req.include_cbuffers,
-                include_outputs: req.include_outputs,
+        std::fs::write(
+            &request_path,
+            serde_json::to_vec(&GenerateReportHtmlScriptRequest {
+                req: &req,
+                actions_jsonl_path: &bundle.actions_jsonl_path,
+                bindings_jsonl_path: &bundle.bindings_jsonl_path,
+            })
+            .map_err(GenerateReportHtmlError::ParseJson)?,
+        )
+        .map_err(GenerateReportHtmlError::WriteRequest)?;
+
+        let result = self.run_qrenderdoc_python_in(
+            session,
+            &QRenderDocPythonRequest {
+                script_path: script_path.clone(),
+                args: Vec::new(),
+                working_dir: Some(run_dir.clone()),
             },
         )?;
+        let _ = result;
 
-        Ok(ExportBundleResponse {
-            capture_path,
-
-            actions_jsonl_path: actions.actions_jsonl_path,
-            actions_summary_json_path: actions.summary_json_path,
-            total_actions: actions.total_actions,
-            drawcall_actions: actions.drawcall_actions,
-
-            bindings_jsonl_path: bindings.bindings_jsonl_path,
-            bindings_summary_json_path: bindings.summary_json_path,
-            total_drawcalls: bindings.total_drawcalls,
-        })
+        let bytes = std::fs::read(&response_path).map_err(GenerateReportHtmlError::ReadResponse)?;
+        let env: QRenderDocJsonEnvelope<GenerateReportHtmlResponse> =
+            serde_json::from_slice(&bytes).map_err(GenerateReportHtmlError::ParseJson)?;
+        if env.ok {
+            env.result
+                .ok_or_else(|| GenerateReportHtmlError::ScriptError("missing result".into()))
+        } else {
+            Err(GenerateReportHtmlError::ScriptError(
+                env.error.unwrap_or_else(|| "unknown error".into()),
+            ))
+        }
     }
 }
 
@@ -2480,8 +8709,36 @@ const EXPORT_BINDINGS_INDEX_JSONL_PY: &str =
 
 const GET_EVENTS_JSON_PY: &str = include_str!("../scripts/get_events_json.py");
 
+const GET_MARKER_TREE_JSON_PY: &str = include_str!("../scripts/get_marker_tree_json.py");
+
+const GET_CAPTURE_METADATA_JSON_PY: &str = include_str!("../scripts/get_capture_metadata_json.py");
+
+const LIST_REPLAY_GPUS_JSON_PY: &str = include_str!("../scripts/list_replay_gpus_json.py");
+
+const READ_CAPTURE_SECTIONS_JSON_PY: &str =
+    include_str!("../scripts/read_capture_sections_json.py");
+
+const WRITE_CAPTURE_SECTION_JSON_PY: &str =
+    include_str!("../scripts/write_capture_section_json.py");
+
+const VALIDATE_CAPTURE_JSON_PY: &str = include_str!("../scripts/validate_capture_json.py");
+
 const GET_SHADER_DETAILS_JSON_PY: &str = include_str!("../scripts/get_shader_details_json.py");
 
+const FIND_EVENTS_BY_SHADER_JSON_PY: &str =
+    include_str!("../scripts/find_events_by_shader_json.py");
+
+const SEARCH_SHADER_SOURCE_JSON_PY: &str = include_str!("../scripts/search_shader_source_json.py");
+
+const EXPORT_SHADER_SOURCES_JSON_PY: &str =
+    include_str!("../scripts/export_shader_sources_json.py");
+
+const EXPORT_SHADER_REFLECTION_JSON_PY: &str =
+    include_str!("../scripts/export_shader_reflection_json.py");
+
+const EXPORT_RESOURCE_LIFETIMES_JSON_PY: &str =
+    include_str!("../scripts/export_resource_lifetimes_json.py");
+
 const GET_BUFFER_DETAILS_JSON_PY: &str = include_str!("../scripts/get_buffer_details_json.py");
 
 const GET_TEXTURE_DETAILS_JSON_PY: &str = include_str!("../scripts/get_texture_details_json.py");
@@ -2495,15 +8752,238 @@ const GET_TEXTURE_CHANGES_DELTA_JSON_PY: &str =
 const GET_PIPELINE_DETAILS_JSON_PY: &str =
     include_str!("../scripts/get_pipeline_details_json.py");
 
+const FIND_EVENTS_BY_PIPELINE_JSON_PY: &str =
+    include_str!("../scripts/find_events_by_pipeline_json.py");
+
 const GET_PIPELINE_BINDING_CHANGES_DELTA_JSON_PY: &str =
     include_str!("../scripts/get_pipeline_binding_changes_delta_json.py");
 
 const GET_EVENT_PIPELINE_STATE_JSON_PY: &str =
     include_str!("../scripts/get_event_pipeline_state_json.py");
 
+const GET_EVENT_API_CALL_JSON_PY: &str = include_str!("../scripts/get_event_api_call_json.py");
+
 const GET_RESOURCE_CHANGED_EVENT_IDS_JSON_PY: &str =
     include_str!("../scripts/get_resource_changed_event_ids_json.py");
 
 const SEARCH_RESOURCES_JSON_PY: &str = include_str!("../scripts/search_resources_json.py");
 
 const FIND_RESOURCE_USES_JSON_PY: &str = include_str!("../scripts/find_resource_uses_json.py");
+
+const GET_VERTEX_INPUTS_JSON_PY: &str = include_str!("../scripts/get_vertex_inputs_json.py");
+
+const GET_INDICES_JSON_PY: &str = include_str!("../scripts/get_indices_json.py");
+
+const FETCH_COUNTERS_JSON_PY: &str = include_str!("../scripts/fetch_counters_json.py");
+
+const GET_TIMESTAMP_QUERIES_JSON_PY: &str =
+    include_str!("../scripts/get_timestamp_queries_json.py");
+
+const EXPORT_CHROME_TRACE_JSON_PY: &str = include_str!("../scripts/export_chrome_trace_json.py");
+
+const GET_TEXTURE_STATS_JSON_PY: &str = include_str!("../scripts/get_texture_stats_json.py");
+
+const SCAN_TARGETS_FOR_INVALID_VALUES_JSON_PY: &str =
+    include_str!("../scripts/scan_targets_for_invalid_values_json.py");
+
+const COMPARE_TEXTURES_JSON_PY: &str = include_str!("../scripts/compare_textures_json.py");
+
+const EXPORT_FRAME_GRAPH_JSON_PY: &str = include_str!("../scripts/export_frame_graph_json.py");
+
+const EXPORT_RENDER_PASSES_JSON_PY: &str = include_str!("../scripts/export_render_passes_json.py");
+
+const EXPORT_DRAW_STATS_JSON_PY: &str = include_str!("../scripts/export_draw_stats_json.py");
+
+const EXPORT_API_CALL_STATS_JSON_PY: &str =
+    include_str!("../scripts/export_api_call_stats_json.py");
+
+const LINT_REDUNDANT_STATE_JSON_PY: &str = include_str!("../scripts/lint_redundant_state_json.py");
+
+const LINT_UNBALANCED_MARKERS_JSON_PY: &str =
+    include_str!("../scripts/lint_unbalanced_markers_json.py");
+
+const FIND_UNUSED_BINDINGS_JSON_PY: &str = include_str!("../scripts/find_unused_bindings_json.py");
+
+const DIAGNOSE_DRAW_JSON_PY: &str = include_str!("../scripts/diagnose_draw_json.py");
+
+const GET_CONSTANTS_JSON_PY: &str = include_str!("../scripts/get_constants_json.py");
+
+const GET_DESCRIPTOR_ARRAYS_JSON_PY: &str =
+    include_str!("../scripts/get_descriptor_arrays_json.py");
+
+const SAVE_TEXTURE_RAW_JSON_PY: &str = include_str!("../scripts/save_texture_raw_json.py");
+
+const SAVE_DEPTH_JSON_PY: &str = include_str!("../scripts/save_depth_json.py");
+
+const DUMP_BUFFER_JSON_PY: &str = include_str!("../scripts/dump_buffer_json.py");
+
+const READBACK_RW_BUFFERS_JSON_PY: &str = include_str!("../scripts/readback_rw_buffers_json.py");
+
+const EXPORT_FLIPBOOK_JSON_PY: &str = include_str!("../scripts/export_flipbook_json.py");
+
+const RENDER_THUMBNAIL_JSON_PY: &str = include_str!("../scripts/render_thumbnail_json.py");
+
+const GENERATE_REPORT_MARKDOWN_JSON_PY: &str =
+    include_str!("../scripts/generate_report_markdown_json.py");
+
+const GENERATE_REPORT_HTML_JSON_PY: &str = include_str!("../scripts/generate_report_html_json.py");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pipeline_diff_matches_bindings_by_key() {
+        let a = serde_json::json!({
+            "pipeline": "Vulkan",
+            "resources": [
+                {"stage": "Pixel", "set": 0, "binding": 1, "name": "albedo", "resource": "tex_a"},
+                {"stage": "Pixel", "set": 0, "binding": 2, "name": "normal", "resource": "tex_n"},
+            ],
+            "stages": [{"stage": "Pixel", "blendState": {"enabled": false}}],
+        });
+        let b = serde_json::json!({
+            "pipeline": "Vulkan",
+            "resources": [
+                {"stage": "Pixel", "set": 0, "binding": 0, "name": "shadow", "resource": "tex_s"},
+                {"stage": "Pixel", "set": 0, "binding": 1, "name": "albedo", "resource": "tex_b"},
+                {"stage": "Pixel", "set": 0, "binding": 2, "name": "normal", "resource": "tex_n"},
+            ],
+            "stages": [{"stage": "Pixel", "blendState": {"enabled": true}}],
+        });
+
+        let mut changes = Vec::new();
+        diff_pipeline_values("", Some(&a), Some(&b), &mut changes);
+        let paths: Vec<&str> = changes.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "resources[Pixel:0:1:albedo].resource",
+                "resources[Pixel:0:0:shadow]",
+                "stages[Pixel].blendState.enabled",
+            ]
+        );
+        assert!(changes[1].a.is_none());
+    }
+
+    #[test]
+    fn pipeline_diff_reports_added_removed_and_changed_bindings() {
+        let a = serde_json::json!({
+            "resources": [
+                {"stage": "Vertex", "set": 0, "binding": 0, "name": "camera", "resource": "ubo_a"},
+                {"stage": "Pixel", "set": 1, "binding": 3, "name": "lut", "resource": "tex_l"},
+            ],
+        });
+        let b = serde_json::json!({
+            "resources": [
+                {"stage": "Pixel", "set": 0, "binding": 0, "name": "camera", "resource": "ubo_a"},
+                {"stage": "Vertex", "set": 0, "binding": 0, "name": "camera", "resource": "ubo_b"},
+            ],
+        });
+
+        let mut changes = Vec::new();
+        diff_pipeline_values("", Some(&a), Some(&b), &mut changes);
+        let paths: Vec<&str> = changes.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "resources[Vertex:0:0:camera].resource",
+                "resources[Pixel:1:3:lut]",
+                "resources[Pixel:0:0:camera]",
+            ]
+        );
+        assert_eq!(changes[0].a, Some(serde_json::json!("ubo_a")));
+        assert_eq!(changes[0].b, Some(serde_json::json!("ubo_b")));
+        assert!(changes[1].a.is_some() && changes[1].b.is_none());
+        assert!(changes[2].a.is_none() && changes[2].b.is_some());
+    }
+
+    #[test]
+    fn pipeline_diff_compares_unkeyed_arrays_by_index() {
+        let a = serde_json::json!({
+            "viewports": [{"x": 0, "width": 1920}, {"x": 0, "width": 640}],
+            "colorWriteMask": [15, 15],
+        });
+        let b = serde_json::json!({
+            "viewports": [{"x": 0, "width": 1280}],
+            "colorWriteMask": [15, 15, 7],
+        });
+
+        let mut changes = Vec::new();
+        diff_pipeline_values("", Some(&a), Some(&b), &mut changes);
+        let paths: Vec<&str> = changes.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            ["colorWriteMask[2]", "viewports[0].width", "viewports[1]"]
+        );
+        assert!(changes[0].a.is_none());
+        assert_eq!(changes[0].b, Some(serde_json::json!(7)));
+        assert!(changes[2].b.is_none());
+    }
+
+    #[test]
+    fn pipeline_diff_falls_back_to_index_when_keys_are_missing() {
+        // One element without `stage` disables key matching for the whole array.
+        let a = serde_json::json!({"stages": [{"stage": "Vertex"}, {"stage": "Pixel"}]});
+        let b = serde_json::json!({"stages": [{"stage": "Pixel"}, {"entry": "main"}]});
+
+        let mut changes = Vec::new();
+        diff_pipeline_values("", Some(&a), Some(&b), &mut changes);
+        let paths: Vec<&str> = changes.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            ["stages[0].stage", "stages[1].entry", "stages[1].stage"]
+        );
+
+        let mut unchanged = Vec::new();
+        diff_pipeline_values("", Some(&a), Some(&a), &mut unchanged);
+        assert!(unchanged.is_empty());
+    }
+
+    #[test]
+    fn pipeline_diff_falls_back_to_index_when_keys_repeat() {
+        // Unresolved resources all share the `-1:-1:unknown` key.
+        let a = serde_json::json!({"resources": [
+            {"stage": "Pixel", "set": -1, "binding": -1, "name": "unknown", "resource": "tex_a"},
+            {"stage": "Pixel", "set": -1, "binding": -1, "name": "unknown", "resource": "tex_b"},
+        ]});
+        let b = serde_json::json!({"resources": [
+            {"stage": "Pixel", "set": -1, "binding": -1, "name": "unknown", "resource": "tex_a"},
+            {"stage": "Pixel", "set": -1, "binding": -1, "name": "unknown", "resource": "tex_c"},
+        ]});
+
+        let mut changes = Vec::new();
+        diff_pipeline_values("", Some(&a), Some(&b), &mut changes);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].path, "resources[1].resource");
+        assert_eq!(changes[0].a, Some(serde_json::json!("tex_b")));
+        assert_eq!(changes[0].b, Some(serde_json::json!("tex_c")));
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn compress_export_repoints_summary() {
+        let dir = std::env::temp_dir().join(format!("renderdog-zstd-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let jsonl = dir.join("cap.actions.jsonl").display().to_string();
+        let summary = dir.join("cap.summary.json").display().to_string();
+        let body = "{\"event_id\":1}\n{\"event_id\":2}\n";
+        std::fs::write(&jsonl, body).unwrap();
+        std::fs::write(
+            &summary,
+            serde_json::to_vec(&serde_json::json!({"actions_jsonl_path": jsonl})).unwrap(),
+        )
+        .unwrap();
+
+        let zst = compress_export(&jsonl, &summary, "actions_jsonl_path").unwrap();
+        assert_eq!(zst, format!("{jsonl}.zst"));
+        assert!(!Path::new(&jsonl).exists());
+        let decoded = zstd::stream::decode_all(std::fs::File::open(&zst).unwrap()).unwrap();
+        assert_eq!(decoded, body.as_bytes());
+        let summary: serde_json::Value =
+            serde_json::from_slice(&std::fs::read(&summary).unwrap()).unwrap();
+        assert_eq!(summary["actions_jsonl_path"], zst.as_str());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -74,6 +74,69 @@ struct SaveThumbnailResponse {
     output_path: String,
 }
 
+#[derive(Debug, Deserialize, JsonSchema)]
+struct ConvertCaptureRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    input_path: String,
+    output_path: String,
+    /// `rdc`, `zip.xml`, `xml` or `chrome.json`; inferred from the file extension when omitted.
+    #[serde(default)]
+    input_format: Option<String>,
+    /// Same values as `input_format`; inferred from `output_path` when omitted.
+    #[serde(default)]
+    output_format: Option<String>,
+    /// Replace an existing `output_path` instead of failing.
+    #[serde(default)]
+    overwrite: bool,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+struct ConvertCaptureResponse {
+    output_path: String,
+    input_format: String,
+    output_format: String,
+    output_bytes: u64,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct CaptureMetadataRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct ListCaptureFramesRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    /// Any capture of the set.
+    capture_path: String,
+    /// Only return this frame (0-based position in the set).
+    #[serde(default)]
+    frame_index: Option<u32>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+struct ListCaptureFramesResponse {
+    total_frames: u32,
+    frames: Vec<renderdog::CaptureFrame>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct ExportSchemaRequest {
+    /// Defaults to every artifact type.
+    #[serde(default)]
+    artifact: Option<renderdog::ExportArtifact>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+struct ExportSchemaResponse {
+    schema_version: u32,
+    /// JSON Schema per artifact (`actions`, `bindings`, `actions_summary`, `bindings_summary`).
+    schemas: std::collections::BTreeMap<String, serde_json::Value>,
+}
+
 #[derive(Debug, Deserialize, JsonSchema)]
 struct OpenCaptureUiRequest {
     #[serde(default)]
@@ -94,6 +157,8 @@ struct ReplayListTexturesRequest {
     capture_path: String,
     #[serde(default)]
     event_id: Option<u32>,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -106,6 +171,8 @@ struct ReplayPickPixelRequest {
     texture_index: u32,
     x: u32,
     y: u32,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -117,6 +184,65 @@ struct ReplaySaveTexturePngRequest {
     event_id: Option<u32>,
     texture_index: u32,
     output_path: String,
+    /// "png", "jpg", "exr" or "hdr"; inferred from output_path when omitted.
+    #[serde(default)]
+    format: Option<renderdog::ImageFileFormat>,
+    #[serde(default)]
+    mip: Option<u32>,
+    /// Array slice, cube face or 3D depth slice.
+    #[serde(default)]
+    slice: Option<u32>,
+    #[serde(default)]
+    sample: Option<u32>,
+    /// "rgba" (default), "rgb", or a single channel "r" / "g" / "b" / "a" saved as greyscale.
+    #[serde(default)]
+    channels: Option<String>,
+    /// Value mapped to black (default 0.0); use with white_point to make HDR / depth viewable.
+    #[serde(default)]
+    black_point: Option<f32>,
+    /// Value mapped to white (default 1.0).
+    #[serde(default)]
+    white_point: Option<f32>,
+    /// Gamma applied after the black / white remap (default 1.0).
+    #[serde(default)]
+    gamma: Option<f32>,
+    /// "single" (default), "all" (one file per slice / cube face), "grid" (atlas) or "cross"
+    /// (cubemap cross).
+    #[serde(default)]
+    slices: Option<String>,
+    /// Slices per row for slices = "grid".
+    #[serde(default)]
+    grid_width: Option<u32>,
+    /// Write every mip level, one file per mip with a `.mip<N>_<w>x<h>` suffix.
+    #[serde(default)]
+    all_mips: bool,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct ReplayExportMeshRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    #[serde(default)]
+    event_id: Option<u32>,
+    /// "vs" (default) or "gs".
+    #[serde(default)]
+    stage: Option<String>,
+    /// "obj" (default) or "gltf".
+    #[serde(default)]
+    format: Option<String>,
+    #[serde(default)]
+    output_path: Option<String>,
+    #[serde(default)]
+    instance: u32,
+    #[serde(default)]
+    view: u32,
+    #[serde(default)]
+    perspective_divide: bool,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -132,6 +258,11 @@ struct ReplaySaveOutputsPngRequest {
     basename: Option<String>,
     #[serde(default)]
     include_depth: bool,
+    /// "png" (default), "jpg", "exr" or "hdr". EXR / HDR keep float render targets unclamped.
+    #[serde(default)]
+    format: Option<renderdog::ImageFileFormat>,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -173,6 +304,11 @@ struct CaptureAndExportActionsRequest {
     marker_contains: Option<String>,
     #[serde(default)]
     case_sensitive: bool,
+    /// Write zstd-compressed `.jsonl.zst` exports.
+    #[serde(default)]
+    compress: bool,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -216,6 +352,11 @@ struct CaptureAndExportBindingsIndexRequest {
     include_cbuffers: bool,
     #[serde(default)]
     include_outputs: bool,
+    /// Write zstd-compressed `.jsonl.zst` exports.
+    #[serde(default)]
+    compress: bool,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
 }
 
 #[derive(Debug, Serialize, JsonSchema)]
@@ -283,6 +424,11 @@ struct CaptureAndExportBundleRequest {
     thumbnail_output_path: Option<String>,
     #[serde(default)]
     open_capture_ui: bool,
+    /// Write zstd-compressed `.jsonl.zst` exports.
+    #[serde(default)]
+    compress: bool,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
 }
 
 #[derive(Debug, Serialize, JsonSchema)]
@@ -347,6 +493,46 @@ fn default_timeout_s() -> u32 {
     60
 }
 
+fn default_debug_max_steps() -> u32 {
+    10_000
+}
+
+fn default_max_examples() -> u32 {
+    8
+}
+
+fn default_max_descriptor_elements() -> u32 {
+    1024
+}
+
+fn default_max_rw_elements() -> u32 {
+    256
+}
+
+fn default_max_flipbook_frames() -> u32 {
+    500
+}
+
+fn default_flipbook_fps() -> u32 {
+    4
+}
+
+fn default_thumbnail_max_dim() -> u32 {
+    256
+}
+
+fn default_report_marker_depth() -> u32 {
+    3
+}
+
+fn default_report_rows() -> u32 {
+    10
+}
+
+fn default_report_max_rows() -> u32 {
+    20_000
+}
+
 fn default_true() -> bool {
     true
 }
@@ -397,6 +583,25 @@ struct ExportActionsRequest {
     marker_contains: Option<String>,
     #[serde(default)]
     case_sensitive: bool,
+    /// "jsonl" (default) or "csv" for a flat, spreadsheet-friendly table.
+    #[serde(default)]
+    format: Option<renderdog::ActionsExportFormat>,
+    /// Event query, e.g. draw && marker:"Shadow*" && eid > 1200 && triangles > 10000. Bare words
+    /// are action flags (draw, dispatch, clear, copy, ...), name:/marker: take globs, numeric fields
+    /// are eid, depth, children, indices, instances, triangles; combine with !, &&, || and ().
+    #[serde(default)]
+    query: Option<String>,
+    /// Write zstd-compressed `.jsonl.zst` exports.
+    #[serde(default)]
+    compress: bool,
+    /// Attach each action's symbolicated callstack (capture must be taken with CaptureCallstacks).
+    #[serde(default)]
+    resolve_callstacks: bool,
+    /// Symbol search path for the resolver (dbghelp `_NT_SYMBOL_PATH` syntax).
+    #[serde(default)]
+    symbol_path: Option<String>,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -424,6 +629,11 @@ struct ExportBindingsIndexRequest {
     include_cbuffers: bool,
     #[serde(default)]
     include_outputs: bool,
+    /// Write zstd-compressed `.jsonl.zst` exports.
+    #[serde(default)]
+    compress: bool,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -462,6 +672,11 @@ struct ExportBundleRequest {
     include_cbuffers: bool,
     #[serde(default)]
     include_outputs: bool,
+    /// Write zstd-compressed `.jsonl.zst` exports.
+    #[serde(default)]
+    compress: bool,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
 }
 
 #[derive(Debug, Serialize, JsonSchema)]
@@ -490,10 +705,30 @@ struct FindEventsRequest {
     name_contains: Option<String>,
     #[serde(default)]
     marker_contains: Option<String>,
+    /// Regex matched against the action name (e.g. "^Draw(Indexed)?Instanced").
+    #[serde(default)]
+    name_regex: Option<String>,
+    /// Regex matched against the /-joined marker path (e.g. "^Frame/GBuffer(/|$)").
+    #[serde(default)]
+    marker_regex: Option<String>,
+    /// Keep actions with any of these flags: Drawcall, Dispatch, Clear, Copy, Resolve, Present,
+    /// PassBoundary, ... (e.g. ["Clear"] for every clear).
+    #[serde(default)]
+    flags_any: Vec<String>,
+    /// Keep actions with all of these flags (e.g. ["Drawcall", "Indirect"]).
+    #[serde(default)]
+    flags_all: Vec<String>,
+    /// Event query, e.g. draw && marker:"Shadow*" && eid > 1200 && triangles > 10000. Bare words
+    /// are action flags (draw, dispatch, clear, copy, ...), name:/marker: take globs, numeric fields
+    /// are eid, depth, children, indices, instances, triangles; combine with !, &&, || and ().
+    #[serde(default)]
+    query: Option<String>,
     #[serde(default)]
     case_sensitive: bool,
     #[serde(default = "default_max_results")]
     max_results: Option<u32>,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -501,6 +736,77 @@ struct GetEventsRequest {
     #[serde(default)]
     cwd: Option<String>,
     capture_path: String,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct GetMarkerTreeRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    /// Deepest marker level to return (1 = top-level only); omit for the whole tree.
+    #[serde(default)]
+    max_depth: Option<u32>,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct GetCaptureMetadataRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct ListReplayGpusRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct ReadCaptureSectionsRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    /// Only return these sections (empty = all).
+    #[serde(default)]
+    names: Vec<String>,
+    /// Include section contents (default true).
+    #[serde(default)]
+    include_contents: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct WriteCaptureSectionRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    /// Section name, e.g. "renderdog/test_metadata" (ignored for Notes).
+    #[serde(default)]
+    name: Option<String>,
+    /// UTF-8 text to store.
+    contents: String,
+    /// "Unknown" (custom section, default) or "Notes" (capture comments shown in qrenderdoc).
+    #[serde(default)]
+    section_type: Option<String>,
+    #[serde(default)]
+    version: Option<u64>,
+    #[serde(default)]
+    compress: bool,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct ValidateCaptureRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -512,6 +818,80 @@ struct GetShaderDetailsRequest {
     /// Optional list of entry points to filter by. If not provided, returns all entry points found in the pipeline.
     #[serde(default)]
     entry_points: Option<Vec<String>>,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct FindEventsByShaderRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    /// Shader entry point (exact match).
+    #[serde(default)]
+    entry_point: Option<String>,
+    /// Source file from shader debug info, e.g. "shadow.hlsl" or "shaders/shadow.hlsl" (suffix match, case-insensitive).
+    #[serde(default)]
+    source_file: Option<String>,
+    #[serde(default)]
+    marker_prefix: Option<String>,
+    #[serde(default)]
+    max_results: Option<u32>,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct SearchShaderSourceRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    /// Regex (Python `re` syntax) searched for in each line of the shaders' embedded debug source.
+    pattern: String,
+    #[serde(default)]
+    case_sensitive: bool,
+    /// Maximum number of matching lines to return.
+    #[serde(default)]
+    max_matches: Option<u32>,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct ExportShaderSourcesRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    output_dir: String,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct ExportShaderReflectionRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    output_dir: String,
+    /// Output is written as `<basename>.shader_reflection.json`.
+    basename: String,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct ExportResourceLifetimesRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    output_dir: String,
+    /// Output is written as `<basename>.resource_lifetimes.json`.
+    basename: String,
+    /// Only these ResourceType names, e.g. ["Texture", "Buffer"] (empty = all).
+    #[serde(default)]
+    resource_types: Vec<String>,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -520,6 +900,8 @@ struct GetBufferDetailsRequest {
     cwd: Option<String>,
     capture_path: String,
     buffer_name: String,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -528,6 +910,8 @@ struct GetTextureDetailsRequest {
     cwd: Option<String>,
     capture_path: String,
     texture_name: String,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -538,6 +922,8 @@ struct GetBufferChangesDeltaRequest {
     buffer_name: String,
     #[serde(default = "default_tracked_indices")]
     tracked_indices: Vec<u32>,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
 }
 
 fn default_tracked_indices() -> Vec<u32> {
@@ -564,6 +950,8 @@ struct GetTextureChangesDeltaRequest {
     texture_name: String,
     #[serde(default = "default_tracked_texels")]
     tracked_texels: Vec<TexelCoord>,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
 }
 
 fn default_tracked_texels() -> Vec<TexelCoord> {
@@ -575,8 +963,29 @@ struct GetPipelineDetailsRequest {
     #[serde(default)]
     cwd: Option<String>,
     capture_path: String,
-    /// Name of the pipeline to inspect.
+    /// Name of the pipeline to inspect (a shader name on D3D11, a program name on OpenGL).
     pipeline_name: String,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct FindEventsByPipelineRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    /// Pipeline name (exact match first, then substring).
+    pipeline_name: String,
+    #[serde(default)]
+    marker_prefix: Option<String>,
+    #[serde(default)]
+    event_id_min: Option<u32>,
+    #[serde(default)]
+    event_id_max: Option<u32>,
+    #[serde(default)]
+    max_results: Option<u32>,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -586,6 +995,8 @@ struct GetPipelineBindingChangesDeltaRequest {
     capture_path: String,
     /// Name of the pipeline to track.
     pipeline_name: String,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -594,6 +1005,19 @@ struct GetEventPipelineStateRequest {
     cwd: Option<String>,
     capture_path: String,
     event_id: u32,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct GetEventApiCallRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    /// Any event ID, including state-setting calls that are not draws.
+    event_id: u32,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -602,6 +1026,8 @@ struct GetResourceChangedEventIdsRequest {
     cwd: Option<String>,
     capture_path: String,
     resource_name: String,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
 }
 
 fn default_max_search_results() -> Option<u32> {
@@ -623,6 +1049,8 @@ struct SearchResourcesRequest {
     /// Filter by resource types. Valid: Unknown, Device, Queue, CommandBuffer, Texture, Buffer, View, Sampler, SwapchainImage, Memory, Shader, ShaderBinding, PipelineState, StateObject, RenderPass, Query, Sync, Pool, AccelerationStructure, DescriptorStore
     #[serde(default)]
     resource_types: Option<Vec<String>>,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -640,6 +1068,8 @@ struct FindResourceUsesRequest {
     /// Filter by delta presence: "all" (default), "with_delta", "without_delta".
     #[serde(default)]
     delta_filter: Option<String>,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
 }
 
 #[derive(Debug, Default, Clone, Copy, Deserialize, JsonSchema)]
@@ -671,6 +1101,24 @@ struct FindEventsAndSaveOutputsPngRequest {
     name_contains: Option<String>,
     #[serde(default)]
     marker_contains: Option<String>,
+    /// Regex matched against the action name (e.g. "^Draw(Indexed)?Instanced").
+    #[serde(default)]
+    name_regex: Option<String>,
+    /// Regex matched against the /-joined marker path (e.g. "^Frame/GBuffer(/|$)").
+    #[serde(default)]
+    marker_regex: Option<String>,
+    /// Keep actions with any of these flags: Drawcall, Dispatch, Clear, Copy, Resolve, Present,
+    /// PassBoundary, ... (e.g. ["Clear"] for every clear).
+    #[serde(default)]
+    flags_any: Vec<String>,
+    /// Keep actions with all of these flags (e.g. ["Drawcall", "Indirect"]).
+    #[serde(default)]
+    flags_all: Vec<String>,
+    /// Event query, e.g. draw && marker:"Shadow*" && eid > 1200 && triangles > 10000. Bare words
+    /// are action flags (draw, dispatch, clear, copy, ...), name:/marker: take globs, numeric fields
+    /// are eid, depth, children, indices, instances, triangles; combine with !, &&, || and ().
+    #[serde(default)]
+    query: Option<String>,
     #[serde(default)]
     case_sensitive: bool,
     #[serde(default = "default_max_results")]
@@ -682,6 +1130,8 @@ struct FindEventsAndSaveOutputsPngRequest {
     basename: Option<String>,
     #[serde(default)]
     include_depth: bool,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
 }
 
 #[derive(Debug, Serialize, JsonSchema)]
@@ -691,9 +1141,677 @@ struct FindEventsAndSaveOutputsPngResponse {
     replay: renderdog::ReplaySaveOutputsPngResponse,
 }
 
-#[derive(Clone)]
-struct RenderdogMcpServer {
-    tool_router: ToolRouter<Self>,
+#[derive(Debug, Deserialize, JsonSchema)]
+struct GetVertexInputsRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    event_id: u32,
+    #[serde(default)]
+    /// First vertex (relative to the draw) to decode.
+    first: u32,
+    /// Number of vertices to decode.
+    count: u32,
+    #[serde(default)]
+    /// Instance used for per-instance attributes.
+    instance: u32,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct GetIndicesRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    event_id: u32,
+    #[serde(default)]
+    /// First index (relative to the draw's index offset) to read.
+    first: u32,
+    /// Number of indices to read.
+    count: u32,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct DebugPixelRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    event_id: u32,
+    x: u32,
+    y: u32,
+    #[serde(default)]
+    sample: Option<u32>,
+    #[serde(default)]
+    primitive: Option<u32>,
+    #[serde(default)]
+    view: Option<u32>,
+    /// Maximum number of steps recorded in the response (the trace always runs to completion).
+    #[serde(default = "default_debug_max_steps")]
+    max_steps: u32,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct DebugVertexRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    event_id: u32,
+    vertex_id: u32,
+    #[serde(default)]
+    instance: u32,
+    #[serde(default)]
+    /// Index into the index buffer for indexed draws; defaults to vertex_id.
+    index: Option<u32>,
+    #[serde(default)]
+    view: u32,
+    /// Maximum number of steps recorded in the response (the trace always runs to completion).
+    #[serde(default = "default_debug_max_steps")]
+    max_steps: u32,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct DebugThreadRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    event_id: u32,
+    /// Workgroup ID.
+    group: [u32; 3],
+    /// Thread ID within the workgroup.
+    thread: [u32; 3],
+    /// Maximum number of steps recorded in the response (the trace always runs to completion).
+    #[serde(default = "default_debug_max_steps")]
+    max_steps: u32,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct FetchCountersRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    /// Counter names; empty selects GPU duration, samples passed and VS/PS invocations, `["all"]`
+    /// selects every counter.
+    #[serde(default)]
+    counters: Vec<String>,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct GetTimestampQueriesRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    /// Nanoseconds per tick (Vulkan timestampPeriod, or 1e9 / D3D12 timestamp frequency).
+    #[serde(default)]
+    timestamp_period_ns: Option<f64>,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct ExportChromeTraceRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    /// Defaults to exports/trace/<capture>.trace.json.
+    #[serde(default)]
+    output_path: Option<String>,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct ReplaySaveOverdrawPngRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    #[serde(default)]
+    event_id: Option<u32>,
+    #[serde(default)]
+    output_dir: Option<String>,
+    #[serde(default)]
+    basename: Option<String>,
+    /// "draw" and/or "pass"; empty means both.
+    #[serde(default)]
+    modes: Vec<String>,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct GetTextureStatsRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    #[serde(default)]
+    event_id: Option<u32>,
+    /// Resource id, resource name, or a bound target at event_id: rt0, rt1, ... or depth.
+    texture: String,
+    #[serde(default)]
+    mip: u32,
+    #[serde(default)]
+    slice: u32,
+    #[serde(default)]
+    sample: u32,
+    #[serde(default)]
+    histogram: bool,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct ScanTargetsForInvalidValuesRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    #[serde(default)]
+    event_id_min: Option<u32>,
+    #[serde(default)]
+    event_id_max: Option<u32>,
+    #[serde(default)]
+    include_depth: bool,
+    /// Example coordinates reported per finding.
+    #[serde(default = "default_max_examples")]
+    max_examples: u32,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct CompareTexturesRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    a: renderdog::TextureRef,
+    b: renderdog::TextureRef,
+    /// Per-channel absolute difference above which a texel counts as different.
+    #[serde(default)]
+    threshold: f64,
+    /// Optional greyscale PNG of the per-texel difference.
+    #[serde(default)]
+    diff_output_path: Option<String>,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct UpdateBaselineRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    /// Events whose outputs become golden images; empty selects the last drawcall.
+    #[serde(default)]
+    event_ids: Vec<u32>,
+    baseline_dir: String,
+    #[serde(default)]
+    include_depth: bool,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct VerifyBaselineRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    baseline_dir: String,
+    #[serde(default)]
+    tolerances: renderdog::RegressionTolerances,
+    #[serde(default)]
+    report_dir: Option<String>,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct DiffEventPipelineStateRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    event_a: u32,
+    event_b: u32,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct ExportFrameGraphRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    /// Defaults to exports/framegraph.
+    #[serde(default)]
+    output_dir: Option<String>,
+    /// Defaults to the capture file stem.
+    #[serde(default)]
+    basename: Option<String>,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct ExportRenderPassesRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    /// Defaults to the exports directory.
+    #[serde(default)]
+    output_dir: Option<String>,
+    /// Defaults to the capture file stem.
+    #[serde(default)]
+    basename: Option<String>,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct ExportDrawStatsRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    /// Defaults to the exports directory.
+    #[serde(default)]
+    output_dir: Option<String>,
+    /// Defaults to the capture file stem.
+    #[serde(default)]
+    basename: Option<String>,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct ExportApiCallStatsRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    /// Defaults to the exports directory.
+    #[serde(default)]
+    output_dir: Option<String>,
+    /// Defaults to the capture file stem.
+    #[serde(default)]
+    basename: Option<String>,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct LintRedundantStateRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    /// Example redundant calls kept per pass.
+    #[serde(default = "default_max_examples")]
+    max_examples: u32,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct LintUnbalancedMarkersRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    /// Issues returned (default 200).
+    #[serde(default)]
+    max_issues: Option<u32>,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct FindUnusedBindingsRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct DiagnoseDrawRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    event_id: u32,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct GetConstantsRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    event_id: u32,
+    /// Vertex, TessControl, TessEval, Geometry, Fragment, Compute, Task or Mesh; omitted means every stage.
+    #[serde(default)]
+    stage: Option<String>,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct GetDescriptorArraysRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    event_id: u32,
+    /// Case-insensitive substring filter on the binding name.
+    #[serde(default)]
+    name_contains: Option<String>,
+    /// Elements read per array (default 1024).
+    #[serde(default = "default_max_descriptor_elements")]
+    max_elements: u32,
+    /// Only list the elements the event accessed.
+    #[serde(default)]
+    only_indexed: bool,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct ReplayListBuffersRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    #[serde(default)]
+    event_id: Option<u32>,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct ReplaySaveAllTexturesRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    #[serde(default)]
+    event_id: Option<u32>,
+    /// Defaults to exports/textures.
+    #[serde(default)]
+    output_dir: Option<String>,
+    /// Defaults to the capture file stem.
+    #[serde(default)]
+    basename: Option<String>,
+    /// "png" (default), "jpg", "exr", "hdr" or "dds" (keeps every mip and slice).
+    #[serde(default)]
+    format: Option<String>,
+    #[serde(default)]
+    name_contains: Option<String>,
+    #[serde(default)]
+    format_contains: Option<String>,
+    #[serde(default)]
+    min_width: Option<u32>,
+    #[serde(default)]
+    min_height: Option<u32>,
+    #[serde(default)]
+    categories: Vec<String>,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct SaveTextureRawRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    #[serde(default)]
+    event_id: Option<u32>,
+    /// Resource id, resource name, "rt<N>" or "depth".
+    texture: String,
+    output_path: String,
+    /// "dds" (default) or "raw".
+    #[serde(default)]
+    format: Option<String>,
+    #[serde(default)]
+    mip: Option<u32>,
+    #[serde(default)]
+    slice: Option<u32>,
+    #[serde(default)]
+    sample: Option<u32>,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct SaveDepthRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    #[serde(default)]
+    event_id: Option<u32>,
+    /// Resource id, resource name, "rt<N>" or "depth"; defaults to the bound depth target.
+    #[serde(default)]
+    texture: Option<String>,
+    output_path: String,
+    /// "png16" (default), "png" or "tiff".
+    #[serde(default)]
+    format: Option<String>,
+    #[serde(default)]
+    near: Option<f32>,
+    #[serde(default)]
+    far: Option<f32>,
+    #[serde(default)]
+    reversed_z: bool,
+    /// "gray" (default), "turbo", "viridis" or "inferno".
+    #[serde(default)]
+    ramp: Option<String>,
+    #[serde(default)]
+    mip: Option<u32>,
+    #[serde(default)]
+    slice: Option<u32>,
+    #[serde(default)]
+    sample: Option<u32>,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct ReplayPickPixelsRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    #[serde(default)]
+    event_id: Option<u32>,
+    /// Texture index and x / y of each pixel.
+    picks: Vec<renderdog::ReplayPixelPick>,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct DumpBufferRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    /// Resource id or resource name.
+    buffer: String,
+    #[serde(default)]
+    event_id: Option<u32>,
+    #[serde(default)]
+    offset: u64,
+    #[serde(default)]
+    length: Option<u64>,
+    output_path: String,
+    /// "bin" (default), "csv" or "jsonl".
+    #[serde(default)]
+    format: Option<String>,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct ReadbackRwBuffersRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    event_id: u32,
+    #[serde(default)]
+    first_element: u64,
+    /// Elements decoded per buffer (default 256).
+    #[serde(default = "default_max_rw_elements")]
+    max_elements: u32,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct ExportFlipbookRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    /// Defaults to exports/flipbook.
+    #[serde(default)]
+    output_dir: Option<String>,
+    /// Defaults to the capture file stem.
+    #[serde(default)]
+    basename: Option<String>,
+    #[serde(default)]
+    marker_prefix: Option<String>,
+    #[serde(default)]
+    event_id_min: Option<u32>,
+    #[serde(default)]
+    event_id_max: Option<u32>,
+    #[serde(default)]
+    name_contains: Option<String>,
+    #[serde(default)]
+    marker_contains: Option<String>,
+    #[serde(default)]
+    case_sensitive: bool,
+    #[serde(default)]
+    include_depth: bool,
+    /// Stop after this many draws (default 500).
+    #[serde(default = "default_max_flipbook_frames")]
+    max_frames: u32,
+    /// Also encode the frames into `<basename>.gif` / `<basename>.mp4` (MP4 needs ffmpeg).
+    #[serde(default)]
+    video: Option<renderdog::FlipbookVideoFormat>,
+    /// Frames per second of the encoded video (default 4).
+    #[serde(default = "default_flipbook_fps")]
+    video_fps: u32,
+    /// Encode the depth images instead of RT0 (needs include_depth).
+    #[serde(default)]
+    video_from_depth: bool,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+struct ExportFlipbookResponse {
+    #[serde(flatten)]
+    flipbook: renderdog::ExportFlipbookResponse,
+    video: Option<renderdog::EncodeFlipbookResponse>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct RenderThumbnailRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    event_id: u32,
+    /// Defaults to exports/thumbnails/<capture stem>.event<id>.png.
+    #[serde(default)]
+    output_path: Option<String>,
+    /// Longest side in pixels (default 256).
+    #[serde(default = "default_thumbnail_max_dim")]
+    max_dim: u32,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct GenerateReportMarkdownRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    /// Defaults to exports/reports.
+    #[serde(default)]
+    output_dir: Option<String>,
+    /// Defaults to the capture file stem.
+    #[serde(default)]
+    basename: Option<String>,
+    /// Deepest marker level in the pass tree (default 3).
+    #[serde(default = "default_report_marker_depth")]
+    marker_depth: u32,
+    /// Rows in the pipeline table (default 10).
+    #[serde(default = "default_report_rows")]
+    top_pipelines: u32,
+    /// Rows in the target table (default 10).
+    #[serde(default = "default_report_rows")]
+    top_targets: u32,
+    #[serde(default)]
+    save_images: bool,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct GenerateReportHtmlRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    /// Defaults to exports/reports.
+    #[serde(default)]
+    output_dir: Option<String>,
+    /// Defaults to the capture file stem.
+    #[serde(default)]
+    basename: Option<String>,
+    /// Longest side of the per-pass thumbnails (default 256).
+    #[serde(default = "default_thumbnail_max_dim")]
+    thumbnail_max_dim: u32,
+    /// Action / binding rows embedded per table (default 20000).
+    #[serde(default = "default_report_max_rows")]
+    max_rows: u32,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct ExportSqliteRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    /// Defaults to the exports dir.
+    #[serde(default)]
+    output_dir: Option<String>,
+    /// Defaults to the capture file stem.
+    #[serde(default)]
+    basename: Option<String>,
+    #[serde(default)]
+    include_cbuffers: bool,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct ExportParquetRequest {
+    #[serde(default)]
+    cwd: Option<String>,
+    capture_path: String,
+    /// Defaults to the exports dir.
+    #[serde(default)]
+    output_dir: Option<String>,
+    /// Defaults to the capture file stem.
+    #[serde(default)]
+    basename: Option<String>,
+    #[serde(default)]
+    include_cbuffers: bool,
+    #[serde(default)]
+    replay_options: Option<renderdog::ReplayOptions>,
+}
+
+#[derive(Clone)]
+struct RenderdogMcpServer {
+    tool_router: ToolRouter<Self>,
 }
 
 #[tool_handler(router = self.tool_router)]
@@ -707,2065 +1825,5187 @@ impl rmcp::ServerHandler for RenderdogMcpServer {
             ),
             ..Default::default()
         }
-    }
-}
+    }
+}
+
+#[tool_router(router = tool_router)]
+impl RenderdogMcpServer {
+    fn new() -> Self {
+        Self {
+            tool_router: Self::tool_router(),
+        }
+    }
+
+    #[tool(
+        name = "renderdoc_detect_installation",
+        description = "Detect local RenderDoc installation and return tool paths."
+    )]
+    async fn detect_installation(&self) -> Result<Json<DetectInstallationResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(tool = "renderdoc_detect_installation", "start");
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_detect_installation", "failed");
+            tracing::debug!(tool = "renderdoc_detect_installation", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let version = install.version().ok().map(|s| s.trim().to_string());
+        let vulkan_layer = install.diagnose_vulkan_layer().ok();
+
+        tracing::info!(
+            tool = "renderdoc_detect_installation",
+            elapsed_ms = start.elapsed().as_millis(),
+            "ok"
+        );
+        Ok(Json(DetectInstallationResponse {
+            root_dir: install.root_dir.display().to_string(),
+            qrenderdoc_exe: install.qrenderdoc_exe.display().to_string(),
+            renderdoccmd_exe: install.renderdoccmd_exe.display().to_string(),
+            version,
+            vulkan_layer,
+        }))
+    }
+
+    #[tool(
+        name = "renderdoc_vulkanlayer_diagnose",
+        description = "Diagnose Vulkan layer registration status using `renderdoccmd vulkanlayer --explain` and return suggested fix commands."
+    )]
+    async fn vulkanlayer_diagnose(&self) -> Result<Json<renderdog::VulkanLayerDiagnosis>, String> {
+        let start = Instant::now();
+        tracing::info!(tool = "renderdoc_vulkanlayer_diagnose", "start");
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_vulkanlayer_diagnose", "failed");
+            tracing::debug!(tool = "renderdoc_vulkanlayer_diagnose", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+        let diag = install.diagnose_vulkan_layer().map_err(|e| {
+            tracing::error!(tool = "renderdoc_vulkanlayer_diagnose", "failed");
+            tracing::debug!(tool = "renderdoc_vulkanlayer_diagnose", err = %e, "details");
+            format!("diagnose vulkan layer failed: {e}")
+        })?;
+        tracing::info!(
+            tool = "renderdoc_vulkanlayer_diagnose",
+            elapsed_ms = start.elapsed().as_millis(),
+            "ok"
+        );
+        Ok(Json(diag))
+    }
+
+    #[tool(
+        name = "renderdoc_diagnose_environment",
+        description = "Diagnose RenderDoc environment (paths, renderdoccmd version, Vulkan layer registration, and key Vulkan-related env vars) and return warnings + suggested fixes."
+    )]
+    async fn diagnose_environment(&self) -> Result<Json<renderdog::EnvironmentDiagnosis>, String> {
+        let start = Instant::now();
+        tracing::info!(tool = "renderdoc_diagnose_environment", "start");
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_diagnose_environment", "failed");
+            tracing::debug!(tool = "renderdoc_diagnose_environment", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+        let diag = install.diagnose_environment().map_err(|e| {
+            tracing::error!(tool = "renderdoc_diagnose_environment", "failed");
+            tracing::debug!(tool = "renderdoc_diagnose_environment", err = %e, "details");
+            format!("diagnose environment failed: {e}")
+        })?;
+        tracing::info!(
+            tool = "renderdoc_diagnose_environment",
+            elapsed_ms = start.elapsed().as_millis(),
+            "ok"
+        );
+        Ok(Json(diag))
+    }
+
+    #[tool(
+        name = "renderdoc_launch_capture",
+        description = "Launch target executable under RenderDoc injection using renderdoccmd capture; returns target ident (port)."
+    )]
+    async fn launch_capture(
+        &self,
+        Parameters(req): Parameters<LaunchCaptureRequest>,
+    ) -> Result<Json<LaunchCaptureResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_launch_capture",
+            executable = %req.executable,
+            args_len = req.args.len(),
+            "start"
+        );
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_launch_capture", "failed");
+            tracing::debug!(tool = "renderdoc_launch_capture", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let artifacts_dir = req
+            .artifacts_dir
+            .as_deref()
+            .map(|p| resolve_path_from_base(&cwd, p))
+            .unwrap_or_else(|| renderdog::default_artifacts_dir(&cwd));
+
+        std::fs::create_dir_all(&artifacts_dir)
+            .map_err(|e| format!("create artifacts_dir failed: {e}"))?;
+
+        let capture_file_template = req
+            .capture_template_name
+            .as_deref()
+            .map(|name| artifacts_dir.join(format!("{name}.rdc")));
+
+        let request = renderdog::CaptureLaunchRequest {
+            executable: resolve_path_from_base(&cwd, &req.executable),
+            args: req.args.into_iter().map(OsString::from).collect(),
+            working_dir: req.working_dir.map(|p| resolve_path_from_base(&cwd, &p)),
+            capture_file_template: capture_file_template.clone(),
+        };
+
+        let res = install.launch_capture(&request).map_err(|e| {
+            tracing::error!(tool = "renderdoc_launch_capture", "failed");
+            tracing::debug!(tool = "renderdoc_launch_capture", err = %e, "details");
+            format!("launch capture failed: {e}")
+        })?;
+
+        tracing::info!(
+            tool = "renderdoc_launch_capture",
+            elapsed_ms = start.elapsed().as_millis(),
+            target_ident = res.target_ident,
+            "ok"
+        );
+        Ok(Json(LaunchCaptureResponse {
+            target_ident: res.target_ident,
+            capture_file_template: capture_file_template.map(|p| p.display().to_string()),
+            stdout: res.stdout,
+            stderr: res.stderr,
+        }))
+    }
+
+    #[tool(
+        name = "renderdoc_save_thumbnail",
+        description = "Extract embedded thumbnail from a .rdc capture (.jpg outputs are read directly from the file; other formats use renderdoccmd thumb)."
+    )]
+    async fn save_thumbnail(
+        &self,
+        Parameters(req): Parameters<SaveThumbnailRequest>,
+    ) -> Result<Json<SaveThumbnailResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_save_thumbnail",
+            capture_path = %req.capture_path,
+            output_path = %req.output_path,
+            "start"
+        );
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+        let capture_path = resolve_path_from_base(&cwd, &req.capture_path);
+        let output_path = resolve_path_from_base(&cwd, &req.output_path);
+
+        if let Some(parent) = output_path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("create output dir failed: {e}"))?;
+        }
+
+        // JPEG output is read straight from the .rdc; no RenderDoc install needed.
+        if renderdog::is_jpeg_path(&output_path)
+            && renderdog::extract_thumbnail(&capture_path, &output_path).is_ok()
+        {
+            tracing::info!(
+                tool = "renderdoc_save_thumbnail",
+                elapsed_ms = start.elapsed().as_millis(),
+                "ok"
+            );
+            return Ok(Json(SaveThumbnailResponse {
+                output_path: output_path.display().to_string(),
+            }));
+        }
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_save_thumbnail", "failed");
+            tracing::debug!(tool = "renderdoc_save_thumbnail", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        install
+            .save_thumbnail(&capture_path, &output_path)
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_save_thumbnail", "failed");
+                tracing::debug!(tool = "renderdoc_save_thumbnail", err = %e, "details");
+                format!("save thumbnail failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_save_thumbnail",
+            elapsed_ms = start.elapsed().as_millis(),
+            "ok"
+        );
+        Ok(Json(SaveThumbnailResponse {
+            output_path: output_path.display().to_string(),
+        }))
+    }
+
+    #[tool(
+        name = "renderdoc_convert_capture",
+        description = "Convert a capture with renderdoccmd convert: .rdc to the zip.xml interchange format (structured XML plus buffers, round-trippable), plain xml (structured data only), or chrome.json, and zip.xml back to .rdc. Useful for archival and diffing captures with external tools."
+    )]
+    async fn convert_capture(
+        &self,
+        Parameters(req): Parameters<ConvertCaptureRequest>,
+    ) -> Result<Json<ConvertCaptureResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_convert_capture",
+            input_path = %req.input_path,
+            output_path = %req.output_path,
+            "start"
+        );
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+        let parse_format = |format: Option<String>| {
+            format
+                .map(|f| f.parse::<renderdog::CaptureFileFormat>())
+                .transpose()
+        };
+        let input_format = parse_format(req.input_format)?;
+        let output_format = parse_format(req.output_format)?;
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_convert_capture", "failed");
+            tracing::debug!(tool = "renderdoc_convert_capture", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let res = install
+            .convert_capture(&renderdog::CaptureConvertRequest {
+                input_path: resolve_path_from_base(&cwd, &req.input_path),
+                output_path: resolve_path_from_base(&cwd, &req.output_path),
+                input_format,
+                output_format,
+                overwrite: req.overwrite,
+            })
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_convert_capture", "failed");
+                tracing::debug!(tool = "renderdoc_convert_capture", err = %e, "details");
+                format!("convert capture failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_convert_capture",
+            elapsed_ms = start.elapsed().as_millis(),
+            output_bytes = res.output_bytes,
+            "ok"
+        );
+        Ok(Json(ConvertCaptureResponse {
+            output_path: res.output_path.display().to_string(),
+            input_format: res.input_format.as_str().to_string(),
+            output_format: res.output_format.as_str().to_string(),
+            output_bytes: res.output_bytes,
+        }))
+    }
+
+    #[tool(
+        name = "renderdoc_capture_metadata",
+        description = "Read .rdc header metadata (RenderDoc version, driver/API, thumbnail size, section list) without RenderDoc installed."
+    )]
+    async fn capture_metadata(
+        &self,
+        Parameters(req): Parameters<CaptureMetadataRequest>,
+    ) -> Result<Json<renderdog::CaptureMetadata>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_capture_metadata",
+            capture_path = %req.capture_path,
+            "start"
+        );
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+        let capture_path = resolve_path_from_base(&cwd, &req.capture_path);
+
+        let meta = renderdog::capture_metadata(&capture_path).map_err(|e| {
+            tracing::error!(tool = "renderdoc_capture_metadata", "failed");
+            tracing::debug!(tool = "renderdoc_capture_metadata", err = %e, "details");
+            format!("read capture metadata failed: {e}")
+        })?;
+
+        tracing::info!(
+            tool = "renderdoc_capture_metadata",
+            elapsed_ms = start.elapsed().as_millis(),
+            "ok"
+        );
+        Ok(Json(meta))
+    }
+
+    #[tool(
+        name = "renderdoc_list_capture_frames",
+        description = "List the frames of a multi-frame capture: each captured frame is its own .rdc (<template>_frame<N>.rdc), so this returns every capture of the same set ordered by frame number, with its frame_index. Pass the selected frame's capture_path to the replay/export tools."
+    )]
+    async fn list_capture_frames(
+        &self,
+        Parameters(req): Parameters<ListCaptureFramesRequest>,
+    ) -> Result<Json<ListCaptureFramesResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_list_capture_frames",
+            capture_path = %req.capture_path,
+            frame_index = ?req.frame_index,
+            "start"
+        );
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+        let capture_path = resolve_path_from_base(&cwd, &req.capture_path);
+
+        let mut frames = renderdog::capture_frames(&capture_path).map_err(|e| {
+            tracing::error!(tool = "renderdoc_list_capture_frames", "failed");
+            tracing::debug!(tool = "renderdoc_list_capture_frames", err = %e, "details");
+            format!("list capture frames failed: {e}")
+        })?;
+        let total_frames = frames.len() as u32;
+        if let Some(frame_index) = req.frame_index {
+            frames.retain(|f| f.frame_index == frame_index);
+            if frames.is_empty() {
+                tracing::error!(tool = "renderdoc_list_capture_frames", "failed");
+                return Err(format!(
+                    "frame index {frame_index} out of range (capture set has {total_frames} frames)"
+                ));
+            }
+        }
+
+        tracing::info!(
+            tool = "renderdoc_list_capture_frames",
+            elapsed_ms = start.elapsed().as_millis(),
+            total_frames,
+            "ok"
+        );
+        Ok(Json(ListCaptureFramesResponse {
+            total_frames,
+            frames,
+        }))
+    }
+
+    #[tool(
+        name = "renderdoc_export_schema",
+        description = "Return the JSON Schema and schema_version of the JSONL export records (.actions.jsonl, .bindings.jsonl) and their summary files, for validating or migrating exports."
+    )]
+    async fn export_schema(
+        &self,
+        Parameters(req): Parameters<ExportSchemaRequest>,
+    ) -> Result<Json<ExportSchemaResponse>, String> {
+        let artifacts = match req.artifact {
+            Some(artifact) => vec![artifact],
+            None => renderdog::ExportArtifact::ALL.to_vec(),
+        };
+        let schemas = artifacts
+            .into_iter()
+            .map(|a| (a.name().to_string(), renderdog::export_schema(a)))
+            .collect();
+        tracing::info!(tool = "renderdoc_export_schema", "ok");
+        Ok(Json(ExportSchemaResponse {
+            schema_version: renderdog::EXPORT_SCHEMA_VERSION,
+            schemas,
+        }))
+    }
+
+    #[tool(
+        name = "renderdoc_trigger_capture",
+        description = "Trigger a frame capture on a RenderDoc-injected target (started via renderdoccmd capture) and return the resulting .rdc path. With num_frames > 1 every frame is written to its own .rdc, all listed in `captures`."
+    )]
+    async fn trigger_capture(
+        &self,
+        Parameters(req): Parameters<TriggerCaptureRequest>,
+    ) -> Result<Json<renderdog::TriggerCaptureResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_trigger_capture",
+            host = %req.host,
+            target_ident = req.target_ident,
+            frames = req.num_frames,
+            timeout_s = req.timeout_s,
+            "start"
+        );
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_trigger_capture", "failed");
+            tracing::debug!(tool = "renderdoc_trigger_capture", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .trigger_capture_via_target_control(
+                &cwd,
+                &renderdog::TriggerCaptureRequest {
+                    host: req.host,
+                    target_ident: req.target_ident,
+                    num_frames: req.num_frames,
+                    timeout_s: req.timeout_s,
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_trigger_capture", "failed");
+                tracing::debug!(tool = "renderdoc_trigger_capture", err = %e, "details");
+                format!("trigger capture failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_trigger_capture",
+            elapsed_ms = start.elapsed().as_millis(),
+            capture_path = %res.capture_path,
+            frames = res.captures.len(),
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_export_actions_jsonl",
+        description = "Export a capture (.rdc) into searchable artifacts: <basename>.actions.jsonl (or .actions.csv with format=\"csv\") and <basename>.summary.json. Set resolve_callstacks (with an optional symbol_path) to attach symbolicated callstacks from captures taken with CaptureCallstacks."
+    )]
+    async fn export_actions_jsonl(
+        &self,
+        Parameters(req): Parameters<ExportActionsRequest>,
+    ) -> Result<Json<renderdog::ExportActionsResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_export_actions_jsonl",
+            capture_path = %req.capture_path,
+            only_drawcalls = req.only_drawcalls,
+            "start"
+        );
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_export_actions_jsonl", "failed");
+            tracing::debug!(tool = "renderdoc_export_actions_jsonl", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let output_dir = req
+            .output_dir
+            .map(|p| resolve_path_from_base(&cwd, &p).display().to_string())
+            .unwrap_or_else(|| renderdog::default_exports_dir(&cwd).display().to_string());
+
+        std::fs::create_dir_all(&output_dir)
+            .map_err(|e| format!("create output_dir failed: {e}"))?;
+
+        let basename = req.basename.unwrap_or_else(|| {
+            Path::new(&req.capture_path)
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or("capture")
+                .to_string()
+        });
+
+        let res = install
+            .export_actions_jsonl(
+                &cwd,
+                &renderdog::ExportActionsRequest {
+                    capture_path: req.capture_path,
+                    output_dir,
+                    basename,
+                    only_drawcalls: req.only_drawcalls,
+                    marker_prefix: req.marker_prefix,
+                    event_id_min: req.event_id_min,
+                    event_id_max: req.event_id_max,
+                    name_contains: req.name_contains,
+                    marker_contains: req.marker_contains,
+                    case_sensitive: req.case_sensitive,
+                    format: req.format,
+                    query: req.query,
+                    compress: req.compress,
+                    resolve_callstacks: req.resolve_callstacks,
+                    symbol_path: req.symbol_path,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_export_actions_jsonl", "failed");
+                tracing::debug!(tool = "renderdoc_export_actions_jsonl", err = %e, "details");
+                format!("export actions failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_export_actions_jsonl",
+            elapsed_ms = start.elapsed().as_millis(),
+            actions_path = %res.actions_path,
+            total_actions = res.total_actions,
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_export_bindings_index_jsonl",
+        description = "Export a capture (.rdc) into a searchable bindings index: <basename>.bindings.jsonl and <basename>.bindings_summary.json."
+    )]
+    async fn export_bindings_index_jsonl(
+        &self,
+        Parameters(req): Parameters<ExportBindingsIndexRequest>,
+    ) -> Result<Json<renderdog::ExportBindingsIndexResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_export_bindings_index_jsonl",
+            capture_path = %req.capture_path,
+            include_cbuffers = req.include_cbuffers,
+            include_outputs = req.include_outputs,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_export_bindings_index_jsonl", "failed");
+            tracing::debug!(tool = "renderdoc_export_bindings_index_jsonl", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let output_dir = req
+            .output_dir
+            .map(|p| resolve_path_from_base(&cwd, &p).display().to_string())
+            .unwrap_or_else(|| renderdog::default_exports_dir(&cwd).display().to_string());
+
+        std::fs::create_dir_all(&output_dir)
+            .map_err(|e| format!("create output_dir failed: {e}"))?;
+
+        let basename = req.basename.unwrap_or_else(|| {
+            Path::new(&req.capture_path)
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or("capture")
+                .to_string()
+        });
+
+        let res = install
+            .export_bindings_index_jsonl(
+                &cwd,
+                &renderdog::ExportBindingsIndexRequest {
+                    capture_path: req.capture_path,
+                    output_dir,
+                    basename,
+                    marker_prefix: req.marker_prefix,
+                    event_id_min: req.event_id_min,
+                    event_id_max: req.event_id_max,
+                    name_contains: req.name_contains,
+                    marker_contains: req.marker_contains,
+                    case_sensitive: req.case_sensitive,
+                    include_cbuffers: req.include_cbuffers,
+                    include_outputs: req.include_outputs,
+                    compress: req.compress,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_export_bindings_index_jsonl", "failed");
+                tracing::debug!(tool = "renderdoc_export_bindings_index_jsonl", err = %e, "details");
+                format!("export bindings index failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_export_bindings_index_jsonl",
+            elapsed_ms = start.elapsed().as_millis(),
+            bindings_jsonl_path = %res.bindings_jsonl_path,
+            total_drawcalls = res.total_drawcalls,
+            "ok"
+        );
+
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_export_bundle_jsonl",
+        description = "Export a capture (.rdc) into searchable artifacts: <basename>.actions.jsonl (+ summary) and <basename>.bindings.jsonl (+ bindings_summary)."
+    )]
+    async fn export_bundle_jsonl(
+        &self,
+        Parameters(req): Parameters<ExportBundleRequest>,
+    ) -> Result<Json<ExportBundleResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_export_bundle_jsonl",
+            capture_path = %req.capture_path,
+            only_drawcalls = req.only_drawcalls,
+            include_cbuffers = req.include_cbuffers,
+            include_outputs = req.include_outputs,
+            save_thumbnail = req.save_thumbnail,
+            open_capture_ui = req.open_capture_ui,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_export_bundle_jsonl", "failed");
+            tracing::debug!(tool = "renderdoc_export_bundle_jsonl", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let capture_path = resolve_path_from_base(&cwd, &req.capture_path);
+
+        let output_dir = req
+            .output_dir
+            .map(|p| resolve_path_from_base(&cwd, &p).display().to_string())
+            .unwrap_or_else(|| renderdog::default_exports_dir(&cwd).display().to_string());
+
+        std::fs::create_dir_all(&output_dir)
+            .map_err(|e| format!("create output_dir failed: {e}"))?;
+
+        let basename = req.basename.unwrap_or_else(|| {
+            capture_path
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or("capture")
+                .to_string()
+        });
+
+        let mut thumbnail_output_path: Option<String> = None;
+        if req.save_thumbnail {
+            let thumb_path = req
+                .thumbnail_output_path
+                .map(|p| resolve_path_from_base(&cwd, &p).display().to_string())
+                .unwrap_or_else(|| {
+                    Path::new(&output_dir)
+                        .join(format!("{basename}.thumb.png"))
+                        .display()
+                        .to_string()
+                });
+            if let Some(parent) = Path::new(&thumb_path).parent() {
+                std::fs::create_dir_all(parent)
+                    .map_err(|e| format!("create thumbnail output dir failed: {e}"))?;
+            }
+            install
+                .save_thumbnail(&capture_path, Path::new(&thumb_path))
+                .map_err(|e| format!("save thumbnail failed: {e}"))?;
+            thumbnail_output_path = Some(thumb_path);
+        }
+
+        let bundle = install
+            .export_bundle_jsonl(
+                &cwd,
+                &renderdog::ExportBundleRequest {
+                    capture_path: req.capture_path.clone(),
+                    output_dir,
+                    basename,
+                    only_drawcalls: req.only_drawcalls,
+                    marker_prefix: req.marker_prefix,
+                    event_id_min: req.event_id_min,
+                    event_id_max: req.event_id_max,
+                    name_contains: req.name_contains,
+                    marker_contains: req.marker_contains,
+                    case_sensitive: req.case_sensitive,
+                    include_cbuffers: req.include_cbuffers,
+                    include_outputs: req.include_outputs,
+                    compress: req.compress,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_export_bundle_jsonl", "failed");
+                tracing::debug!(tool = "renderdoc_export_bundle_jsonl", err = %e, "details");
+                format!("export bundle failed: {e}")
+            })?;
+
+        let mut ui_pid: Option<u32> = None;
+        if req.open_capture_ui {
+            let child = install
+                .open_capture_in_ui(&capture_path)
+                .map_err(|e| format!("open capture UI failed: {e}"))?;
+            ui_pid = Some(child.id());
+        }
+
+        tracing::info!(
+            tool = "renderdoc_export_bundle_jsonl",
+            elapsed_ms = start.elapsed().as_millis(),
+            actions_jsonl_path = %bundle.actions_jsonl_path,
+            bindings_jsonl_path = %bundle.bindings_jsonl_path,
+            total_actions = bundle.total_actions,
+            total_drawcalls = bundle.total_drawcalls,
+            "ok"
+        );
+
+        Ok(Json(ExportBundleResponse {
+            bundle,
+            thumbnail_output_path,
+            ui_pid,
+        }))
+    }
+
+    #[tool(
+        name = "renderdoc_find_events",
+        description = "Find matching action events (event_id + marker_path) in a .rdc capture via `qrenderdoc --python`. Useful for quickly locating event IDs for later replay tools."
+    )]
+    async fn find_events(
+        &self,
+        Parameters(req): Parameters<FindEventsRequest>,
+    ) -> Result<Json<renderdog::FindEventsResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_find_events",
+            capture_path = %req.capture_path,
+            only_drawcalls = req.only_drawcalls,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_find_events", "failed");
+            tracing::debug!(tool = "renderdoc_find_events", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .find_events(
+                &cwd,
+                &renderdog::FindEventsRequest {
+                    capture_path: req.capture_path,
+                    only_drawcalls: req.only_drawcalls,
+                    marker_prefix: req.marker_prefix,
+                    event_id_min: req.event_id_min,
+                    event_id_max: req.event_id_max,
+                    name_contains: req.name_contains,
+                    marker_contains: req.marker_contains,
+                    name_regex: req.name_regex,
+                    marker_regex: req.marker_regex,
+                    flags_any: req.flags_any,
+                    flags_all: req.flags_all,
+                    query: req.query,
+                    case_sensitive: req.case_sensitive,
+                    max_results: req.max_results,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_find_events", "failed");
+                tracing::debug!(tool = "renderdoc_find_events", err = %e, "details");
+                format!("find events failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_find_events",
+            elapsed_ms = start.elapsed().as_millis(),
+            matches = res.matches.len(),
+            truncated = res.truncated,
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_get_events",
+        description = "Get all events from a .rdc capture with their event IDs, marker scopes, and API call names. Returns a complete event map useful for understanding the capture structure."
+    )]
+    async fn get_events(
+        &self,
+        Parameters(req): Parameters<GetEventsRequest>,
+    ) -> Result<Json<renderdog::GetEventsResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_get_events",
+            capture_path = %req.capture_path,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_get_events", "failed");
+            tracing::debug!(tool = "renderdoc_get_events", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .get_events(
+                &cwd,
+                &renderdog::GetEventsRequest {
+                    capture_path: req.capture_path,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_get_events", "failed");
+                tracing::debug!(tool = "renderdoc_get_events", err = %e, "details");
+                format!("get events failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_get_events",
+            elapsed_ms = start.elapsed().as_millis(),
+            total_events = res.total_events,
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_get_marker_tree",
+        description = "Return the nested debug marker tree of a capture (without flattening): one node per marker region with its event range, direct child / child marker counts and draw, dispatch and action totals, plus the draws outside any marker. Use it to reconstruct pass structure before drilling into events."
+    )]
+    async fn get_marker_tree(
+        &self,
+        Parameters(req): Parameters<GetMarkerTreeRequest>,
+    ) -> Result<Json<renderdog::GetMarkerTreeResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_get_marker_tree",
+            capture_path = %req.capture_path,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_get_marker_tree", "failed");
+            tracing::debug!(tool = "renderdoc_get_marker_tree", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .get_marker_tree(
+                &cwd,
+                &renderdog::GetMarkerTreeRequest {
+                    capture_path: req.capture_path,
+                    max_depth: req.max_depth,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_get_marker_tree", "failed");
+                tracing::debug!(tool = "renderdoc_get_marker_tree", err = %e, "details");
+                format!("get marker tree failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_get_marker_tree",
+            elapsed_ms = start.elapsed().as_millis(),
+            total_markers = res.total_markers,
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_get_capture_metadata",
+        description = "Open a capture for replay and report what it contains: API (Vulkan/D3D12/D3D11/OpenGL), driver, GPU vendor/name, RenderDoc version that wrote it, frame number, capture time, and compressed/uncompressed sizes. Use it before deciding how to process a capture; renderdoc_capture_metadata is a cheaper header-only variant that needs no RenderDoc install."
+    )]
+    async fn get_capture_metadata(
+        &self,
+        Parameters(req): Parameters<GetCaptureMetadataRequest>,
+    ) -> Result<Json<renderdog::GetCaptureMetadataResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_get_capture_metadata",
+            capture_path = %req.capture_path,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_get_capture_metadata", "failed");
+            tracing::debug!(tool = "renderdoc_get_capture_metadata", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .get_capture_metadata(
+                &cwd,
+                &renderdog::GetCaptureMetadataRequest {
+                    capture_path: req.capture_path,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_get_capture_metadata", "failed");
+                tracing::debug!(tool = "renderdoc_get_capture_metadata", err = %e, "details");
+                format!("get capture metadata failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_get_capture_metadata",
+            elapsed_ms = start.elapsed().as_millis(),
+            api = %res.api,
+            frame_number = res.frame_number,
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_list_replay_gpus",
+        description = "List the GPUs RenderDoc can replay a capture (.rdc) on: name, vendor, PCI device ID, driver and supported APIs. Pass an adapter's vendor/device_id/driver as replay_options (gpu_vendor, gpu_device_id, gpu_driver_name) to pin replays to it on multi-GPU machines or in CI."
+    )]
+    async fn list_replay_gpus(
+        &self,
+        Parameters(req): Parameters<ListReplayGpusRequest>,
+    ) -> Result<Json<renderdog::ListReplayGpusResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_list_replay_gpus",
+            capture_path = %req.capture_path,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_list_replay_gpus", "failed");
+            tracing::debug!(tool = "renderdoc_list_replay_gpus", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .list_replay_gpus(
+                &cwd,
+                &renderdog::ListReplayGpusRequest {
+                    capture_path: req.capture_path,
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_list_replay_gpus", "failed");
+                tracing::debug!(tool = "renderdoc_list_replay_gpus", err = %e, "details");
+                format!("list replay gpus failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_list_replay_gpus",
+            elapsed_ms = start.elapsed().as_millis(),
+            gpus = res.gpus.len(),
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_read_capture_sections",
+        description = "List the sections embedded in a .rdc (frame data, notes, bookmarks, custom sections such as test metadata or repro commands) with their contents (UTF-8 text, or base64 for binary)."
+    )]
+    async fn read_capture_sections(
+        &self,
+        Parameters(req): Parameters<ReadCaptureSectionsRequest>,
+    ) -> Result<Json<renderdog::ReadCaptureSectionsResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_read_capture_sections",
+            capture_path = %req.capture_path,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_read_capture_sections", "failed");
+            tracing::debug!(tool = "renderdoc_read_capture_sections", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .read_capture_sections(
+                &cwd,
+                &renderdog::ReadCaptureSectionsRequest {
+                    capture_path: req.capture_path,
+                    names: req.names,
+                    include_contents: req.include_contents.unwrap_or(true),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_read_capture_sections", "failed");
+                tracing::debug!(tool = "renderdoc_read_capture_sections", err = %e, "details");
+                format!("read capture sections failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_read_capture_sections",
+            elapsed_ms = start.elapsed().as_millis(),
+            sections = res.sections.len(),
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_write_capture_section",
+        description = "Embed a named text section (notes, test metadata JSON, repro commands, ...) into a .rdc in place, replacing a section with the same name, so provenance travels with the capture. section_type Notes writes the capture comments shown by qrenderdoc."
+    )]
+    async fn write_capture_section(
+        &self,
+        Parameters(req): Parameters<WriteCaptureSectionRequest>,
+    ) -> Result<Json<renderdog::WriteCaptureSectionResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_write_capture_section",
+            capture_path = %req.capture_path,
+            name = ?req.name,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_write_capture_section", "failed");
+            tracing::debug!(tool = "renderdoc_write_capture_section", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .write_capture_section(
+                &cwd,
+                &renderdog::WriteCaptureSectionRequest {
+                    capture_path: req.capture_path,
+                    name: req.name,
+                    contents: req.contents,
+                    section_type: req.section_type,
+                    version: req.version,
+                    compress: req.compress,
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_write_capture_section", "failed");
+                tracing::debug!(tool = "renderdoc_write_capture_section", err = %e, "details");
+                format!("write capture section failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_write_capture_section",
+            elapsed_ms = start.elapsed().as_millis(),
+            name = %res.name,
+            replaced = res.replaced,
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_validate_capture",
+        description = "Check that a .rdc capture is intact and replayable (container header, sections, replay init, action enumeration, full frame replay) and return per-check results, unreadable sections and capture/replay debug messages. Use as a gate before archiving a capture."
+    )]
+    async fn validate_capture(
+        &self,
+        Parameters(req): Parameters<ValidateCaptureRequest>,
+    ) -> Result<Json<renderdog::ValidateCaptureResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_validate_capture",
+            capture_path = %req.capture_path,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_validate_capture", "failed");
+            tracing::debug!(tool = "renderdoc_validate_capture", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .validate_capture(
+                &cwd,
+                &renderdog::ValidateCaptureRequest {
+                    capture_path: req.capture_path,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_validate_capture", "failed");
+                tracing::debug!(tool = "renderdoc_validate_capture", err = %e, "details");
+                format!("validate capture failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_validate_capture",
+            elapsed_ms = start.elapsed().as_millis(),
+            valid = res.valid,
+            messages = res.messages.len(),
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_get_shader_details",
+        description = "Get detailed shader information (source files, resources, constant blocks, samplers, input signature) for a pipeline in a .rdc capture. Returns an array of shader info for all entry points, or filtered by the optional entry_points parameter."
+    )]
+    async fn get_shader_details(
+        &self,
+        Parameters(req): Parameters<GetShaderDetailsRequest>,
+    ) -> Result<Json<renderdog::GetShaderDetailsResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_get_shader_details",
+            capture_path = %req.capture_path,
+            pipeline_name = %req.pipeline_name,
+            entry_points = ?req.entry_points,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_get_shader_details", "failed");
+            tracing::debug!(tool = "renderdoc_get_shader_details", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .get_shader_details(
+                &cwd,
+                &renderdog::GetShaderDetailsRequest {
+                    capture_path: req.capture_path,
+                    pipeline_name: req.pipeline_name,
+                    entry_points: req.entry_points,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_get_shader_details", "failed");
+                tracing::debug!(tool = "renderdoc_get_shader_details", err = %e, "details");
+                format!("get shader details failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_get_shader_details",
+            elapsed_ms = start.elapsed().as_millis(),
+            shaders_count = res.shaders.len(),
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_find_events_by_shader",
+        description = "Map a shader entry point and/or debug-info source file (e.g. the HLSL/GLSL/WGSL file you edited) to every draw/dispatch that binds it. Returns the matching shaders (stage, entry point, source files, event count) and the events with marker paths and matching stages. Source file matching needs shaders compiled with debug info."
+    )]
+    async fn find_events_by_shader(
+        &self,
+        Parameters(req): Parameters<FindEventsByShaderRequest>,
+    ) -> Result<Json<renderdog::FindEventsByShaderResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_find_events_by_shader",
+            capture_path = %req.capture_path,
+            entry_point = ?req.entry_point,
+            source_file = ?req.source_file,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_find_events_by_shader", "failed");
+            tracing::debug!(tool = "renderdoc_find_events_by_shader", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .find_events_by_shader(
+                &cwd,
+                &renderdog::FindEventsByShaderRequest {
+                    capture_path: req.capture_path,
+                    entry_point: req.entry_point,
+                    source_file: req.source_file,
+                    marker_prefix: req.marker_prefix,
+                    max_results: req.max_results,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_find_events_by_shader", "failed");
+                tracing::debug!(tool = "renderdoc_find_events_by_shader", err = %e, "details");
+                format!("find events by shader failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_find_events_by_shader",
+            elapsed_ms = start.elapsed().as_millis(),
+            shaders = res.shaders.len(),
+            total_matches = res.total_matches,
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_search_shader_source",
+        description = "Search the embedded debug source of every shader in a capture for a regex (headless equivalent of qrenderdoc's shader search). Returns, per matching shader entry point, the stage, the matching file/line/text and the draw/dispatch event IDs that use it. Shaders compiled without debug info are counted in shaders_without_source."
+    )]
+    async fn search_shader_source(
+        &self,
+        Parameters(req): Parameters<SearchShaderSourceRequest>,
+    ) -> Result<Json<renderdog::SearchShaderSourceResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_search_shader_source",
+            capture_path = %req.capture_path,
+            pattern = %req.pattern,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_search_shader_source", "failed");
+            tracing::debug!(tool = "renderdoc_search_shader_source", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .search_shader_source(
+                &cwd,
+                &renderdog::SearchShaderSourceRequest {
+                    capture_path: req.capture_path,
+                    pattern: req.pattern,
+                    case_sensitive: req.case_sensitive,
+                    max_matches: req.max_matches,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_search_shader_source", "failed");
+                tracing::debug!(tool = "renderdoc_search_shader_source", err = %e, "details");
+                format!("search shader source failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_search_shader_source",
+            elapsed_ms = start.elapsed().as_millis(),
+            shaders = res.shaders.len(),
+            total_matches = res.total_matches,
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_export_shader_sources",
+        description = "Write every shader's embedded debug source files under <output_dir>/sources/ (relative layout preserved) plus <output_dir>/index.json mapping shaders and pipeline stages/entry points to those files, so a capture's shaders can be grepped and diffed offline."
+    )]
+    async fn export_shader_sources(
+        &self,
+        Parameters(req): Parameters<ExportShaderSourcesRequest>,
+    ) -> Result<Json<renderdog::ExportShaderSourcesResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_export_shader_sources",
+            capture_path = %req.capture_path,
+            output_dir = %req.output_dir,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_export_shader_sources", "failed");
+            tracing::debug!(tool = "renderdoc_export_shader_sources", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .export_shader_sources(
+                &cwd,
+                &renderdog::ExportShaderSourcesRequest {
+                    capture_path: req.capture_path,
+                    output_dir: req.output_dir,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_export_shader_sources", "failed");
+                tracing::debug!(tool = "renderdoc_export_shader_sources", err = %e, "details");
+                format!("export shader sources failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_export_shader_sources",
+            elapsed_ms = start.elapsed().as_millis(),
+            total_shaders = res.total_shaders,
+            files_written = res.files_written,
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_export_shader_reflection",
+        description = "Write the complete reflection of every shader entry point in a capture to <output_dir>/<basename>.shader_reflection.json: input/output signatures, read-only/read-write resources, samplers, constant blocks with full member layouts (byte offsets, types, array/matrix strides, row-major), and compute/task/mesh thread group sizes. Use renderdoc_get_shader_details for a per-pipeline summary."
+    )]
+    async fn export_shader_reflection(
+        &self,
+        Parameters(req): Parameters<ExportShaderReflectionRequest>,
+    ) -> Result<Json<renderdog::ExportShaderReflectionResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_export_shader_reflection",
+            capture_path = %req.capture_path,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_export_shader_reflection", "failed");
+            tracing::debug!(tool = "renderdoc_export_shader_reflection", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .export_shader_reflection(
+                &cwd,
+                &renderdog::ExportShaderReflectionRequest {
+                    capture_path: req.capture_path,
+                    output_dir: req.output_dir,
+                    basename: req.basename,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_export_shader_reflection", "failed");
+                tracing::debug!(tool = "renderdoc_export_shader_reflection", err = %e, "details");
+                format!("export shader reflection failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_export_shader_reflection",
+            elapsed_ms = start.elapsed().as_millis(),
+            total_shaders = res.total_shaders,
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_export_resource_lifetimes",
+        description = "Write a lifetime timeline for every resource in a capture to <output_dir>/<basename>.resource_lifetimes.json: creation and destruction event (when inside the frame), first/last use, use count and usage kinds, flagged unused / single_use / leaked_in_frame. Returns the counts per flag."
+    )]
+    async fn export_resource_lifetimes(
+        &self,
+        Parameters(req): Parameters<ExportResourceLifetimesRequest>,
+    ) -> Result<Json<renderdog::ExportResourceLifetimesResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_export_resource_lifetimes",
+            capture_path = %req.capture_path,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_export_resource_lifetimes", "failed");
+            tracing::debug!(tool = "renderdoc_export_resource_lifetimes", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .export_resource_lifetimes(
+                &cwd,
+                &renderdog::ExportResourceLifetimesRequest {
+                    capture_path: req.capture_path,
+                    output_dir: req.output_dir,
+                    basename: req.basename,
+                    resource_types: req.resource_types,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_export_resource_lifetimes", "failed");
+                tracing::debug!(tool = "renderdoc_export_resource_lifetimes", err = %e, "details");
+                format!("export resource lifetimes failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_export_resource_lifetimes",
+            elapsed_ms = start.elapsed().as_millis(),
+            total_resources = res.total_resources,
+            leaked_in_frame = res.leaked_in_frame,
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_get_buffer_details",
+        description = "Get metadata for a GPU buffer: infers struct schema from shader reflection, stride per element, and all pipeline/binding usages across the frame. Use this before get_buffer_changes_delta to understand the buffer structure."
+    )]
+    async fn get_buffer_details(
+        &self,
+        Parameters(req): Parameters<GetBufferDetailsRequest>,
+    ) -> Result<Json<renderdog::GetBufferDetailsResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_get_buffer_details",
+            capture_path = %req.capture_path,
+            buffer_name = %req.buffer_name,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_get_buffer_details", "failed");
+            tracing::debug!(tool = "renderdoc_get_buffer_details", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .get_buffer_details(
+                &cwd,
+                &renderdog::GetBufferDetailsRequest {
+                    capture_path: req.capture_path,
+                    buffer_name: req.buffer_name,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_get_buffer_details", "failed");
+                tracing::debug!(tool = "renderdoc_get_buffer_details", err = %e, "details");
+                format!("get buffer details failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_get_buffer_details",
+            elapsed_ms = start.elapsed().as_millis(),
+            stride = res.stride,
+            usages = res.usages.len(),
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_get_texture_details",
+        description = "Get metadata for a GPU texture: format, dimensions, mip levels, array size, sample count, and all pipeline/binding usages across the frame including render target bindings."
+    )]
+    async fn get_texture_details(
+        &self,
+        Parameters(req): Parameters<GetTextureDetailsRequest>,
+    ) -> Result<Json<renderdog::GetTextureDetailsResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_get_texture_details",
+            capture_path = %req.capture_path,
+            texture_name = %req.texture_name,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_get_texture_details", "failed");
+            tracing::debug!(tool = "renderdoc_get_texture_details", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .get_texture_details(
+                &cwd,
+                &renderdog::GetTextureDetailsRequest {
+                    capture_path: req.capture_path,
+                    texture_name: req.texture_name,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_get_texture_details", "failed");
+                tracing::debug!(tool = "renderdoc_get_texture_details", err = %e, "details");
+                format!("get texture details failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_get_texture_details",
+            elapsed_ms = start.elapsed().as_millis(),
+            format = %res.format,
+            width = res.width,
+            height = res.height,
+            usages = res.usages.len(),
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_get_buffer_changes_delta",
+        description = "Track GPU buffer element changes across a frame. Reads data at specified element indices at every action and returns delta-encoded changes: initial_state for each element plus only the deltas where values actually changed."
+    )]
+    async fn get_buffer_changes_delta(
+        &self,
+        Parameters(req): Parameters<GetBufferChangesDeltaRequest>,
+    ) -> Result<Json<renderdog::GetBufferChangesDeltaResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_get_buffer_changes_delta",
+            capture_path = %req.capture_path,
+            buffer_name = %req.buffer_name,
+            tracked_indices = ?req.tracked_indices,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_get_buffer_changes_delta", "failed");
+            tracing::debug!(tool = "renderdoc_get_buffer_changes_delta", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .get_buffer_changes_delta(
+                &cwd,
+                &renderdog::GetBufferChangesDeltaRequest {
+                    capture_path: req.capture_path,
+                    buffer_name: req.buffer_name,
+                    tracked_indices: req.tracked_indices,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_get_buffer_changes_delta", "failed");
+                tracing::debug!(tool = "renderdoc_get_buffer_changes_delta", err = %e, "details");
+                format!("get buffer changes delta failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_get_buffer_changes_delta",
+            elapsed_ms = start.elapsed().as_millis(),
+            total_changes = res.total_changes,
+            elements = res.elements.len(),
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_get_texture_changes_delta",
+        description = "Track GPU texture texel changes across a frame. Reads texel values at specified coordinates (x, y, z, mip, slice) at every action and returns delta-encoded changes: initial_state for each texel plus only the channel deltas where values actually changed."
+    )]
+    async fn get_texture_changes_delta(
+        &self,
+        Parameters(req): Parameters<GetTextureChangesDeltaRequest>,
+    ) -> Result<Json<renderdog::GetTextureChangesDeltaResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_get_texture_changes_delta",
+            capture_path = %req.capture_path,
+            texture_name = %req.texture_name,
+            tracked_texels = req.tracked_texels.len(),
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_get_texture_changes_delta", "failed");
+            tracing::debug!(tool = "renderdoc_get_texture_changes_delta", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .get_texture_changes_delta(
+                &cwd,
+                &renderdog::GetTextureChangesDeltaRequest {
+                    capture_path: req.capture_path,
+                    texture_name: req.texture_name,
+                    tracked_texels: req.tracked_texels.iter().map(|t| {
+                        renderdog::TexelCoord {
+                            x: t.x,
+                            y: t.y,
+                            z: t.z,
+                            mip: t.mip,
+                            slice: t.slice,
+                        }
+                    }).collect(),
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_get_texture_changes_delta", "failed");
+                tracing::debug!(tool = "renderdoc_get_texture_changes_delta", err = %e, "details");
+                format!("get texture changes delta failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_get_texture_changes_delta",
+            elapsed_ms = start.elapsed().as_millis(),
+            total_changes = res.total_changes,
+            texels = res.texels.len(),
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_get_pipeline_details",
+        description = "Get detailed metadata about a GPU pipeline: type (Graphics/Compute), shader stages with entry points, resource bindings, constant blocks, samplers, vertex inputs (for graphics), render targets, depth/stencil/blend state, and all event IDs where this pipeline is active. Also returns the API create info parsed from the capture: VkGraphicsPipelineCreateInfo (Vulkan), the PSO desc with its decoded root signature (D3D12), or the bound input layout / rasterizer / blend / depth-stencil state objects and their descs (D3D11). For OpenGL it returns gl_pipeline_state: the program and its shader objects, vertex array layout, draw framebuffer attachments and glEnable state. Note: render targets and depth/stencil/blend state are captured from the first event where the pipeline is active. D3D11 and OpenGL have no pipeline objects, so there pipeline_name names a shader (D3D11) or a program / program pipeline (OpenGL) and the pipeline is every action binding it."
+    )]
+    async fn get_pipeline_details(
+        &self,
+        Parameters(req): Parameters<GetPipelineDetailsRequest>,
+    ) -> Result<Json<renderdog::GetPipelineDetailsResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_get_pipeline_details",
+            capture_path = %req.capture_path,
+            pipeline_name = %req.pipeline_name,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_get_pipeline_details", "failed");
+            tracing::debug!(tool = "renderdoc_get_pipeline_details", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .get_pipeline_details(
+                &cwd,
+                &renderdog::GetPipelineDetailsRequest {
+                    capture_path: req.capture_path,
+                    pipeline_name: req.pipeline_name,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_get_pipeline_details", "failed");
+                tracing::debug!(tool = "renderdoc_get_pipeline_details", err = %e, "details");
+                format!("get pipeline details failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_get_pipeline_details",
+            elapsed_ms = start.elapsed().as_millis(),
+            pipeline_type = %res.pipeline_type,
+            stages = res.stages.len(),
+            event_ids = res.event_ids.len(),
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_find_events_by_pipeline",
+        description = "List the draws/dispatches where a pipeline is bound, with marker paths. Much cheaper than renderdoc_get_pipeline_details when only the events are needed; optionally restricted by marker_prefix and an event ID range."
+    )]
+    async fn find_events_by_pipeline(
+        &self,
+        Parameters(req): Parameters<FindEventsByPipelineRequest>,
+    ) -> Result<Json<renderdog::FindEventsByPipelineResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_find_events_by_pipeline",
+            capture_path = %req.capture_path,
+            pipeline_name = %req.pipeline_name,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_find_events_by_pipeline", "failed");
+            tracing::debug!(tool = "renderdoc_find_events_by_pipeline", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .find_events_by_pipeline(
+                &cwd,
+                &renderdog::FindEventsByPipelineRequest {
+                    capture_path: req.capture_path,
+                    pipeline_name: req.pipeline_name,
+                    marker_prefix: req.marker_prefix,
+                    event_id_min: req.event_id_min,
+                    event_id_max: req.event_id_max,
+                    max_results: req.max_results,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_find_events_by_pipeline", "failed");
+                tracing::debug!(tool = "renderdoc_find_events_by_pipeline", err = %e, "details");
+                format!("find events by pipeline failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_find_events_by_pipeline",
+            elapsed_ms = start.elapsed().as_millis(),
+            total_matches = res.total_matches,
+            truncated = res.truncated,
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_get_pipeline_binding_changes_delta",
+        description = "Track GPU pipeline binding changes across a frame. For a given pipeline, monitors which resources are bound at each binding point (textures, buffers, samplers, render targets) across all events where the pipeline is active. Returns delta-encoded changes showing when bindings change."
+    )]
+    async fn get_pipeline_binding_changes_delta(
+        &self,
+        Parameters(req): Parameters<GetPipelineBindingChangesDeltaRequest>,
+    ) -> Result<Json<renderdog::GetPipelineBindingChangesDeltaResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_get_pipeline_binding_changes_delta",
+            capture_path = %req.capture_path,
+            pipeline_name = %req.pipeline_name,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_get_pipeline_binding_changes_delta", "failed");
+            tracing::debug!(tool = "renderdoc_get_pipeline_binding_changes_delta", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .get_pipeline_binding_changes_delta(
+                &cwd,
+                &renderdog::GetPipelineBindingChangesDeltaRequest {
+                    capture_path: req.capture_path,
+                    pipeline_name: req.pipeline_name,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_get_pipeline_binding_changes_delta", "failed");
+                tracing::debug!(tool = "renderdoc_get_pipeline_binding_changes_delta", err = %e, "details");
+                format!("get pipeline binding changes delta failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_get_pipeline_binding_changes_delta",
+            elapsed_ms = start.elapsed().as_millis(),
+            pipeline_type = %res.pipeline_type,
+            total_changes = res.total_changes,
+            bindings = res.bindings.len(),
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_get_event_pipeline_state",
+        description = "Get complete pipeline state at a specific event ID: active shader stages, all resource bindings (buffers, textures), uniform/constant buffer contents, samplers, and for graphics pipelines: vertex/index buffers, render targets, depth/stencil/blend state."
+    )]
+    async fn get_event_pipeline_state(
+        &self,
+        Parameters(req): Parameters<GetEventPipelineStateRequest>,
+    ) -> Result<Json<renderdog::GetEventPipelineStateResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_get_event_pipeline_state",
+            capture_path = %req.capture_path,
+            event_id = req.event_id,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_get_event_pipeline_state", "failed");
+            tracing::debug!(tool = "renderdoc_get_event_pipeline_state", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .get_event_pipeline_state(
+                &cwd,
+                &renderdog::GetEventPipelineStateRequest {
+                    capture_path: req.capture_path,
+                    event_id: req.event_id,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_get_event_pipeline_state", "failed");
+                tracing::debug!(tool = "renderdoc_get_event_pipeline_state", err = %e, "details");
+                format!("get event pipeline state failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_get_event_pipeline_state",
+            elapsed_ms = start.elapsed().as_millis(),
+            pipeline = %res.pipeline,
+            stages = res.stages.len(),
+            resources = res.resources.len(),
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_get_event_api_call",
+        description = "Return the full structured API call recorded for one event: call name, thread/timestamp/duration, and every parameter (nested create-info structs, arrays, flags, handles with resource names) as a typed tree. Works for state-setting calls too, not only draws."
+    )]
+    async fn get_event_api_call(
+        &self,
+        Parameters(req): Parameters<GetEventApiCallRequest>,
+    ) -> Result<Json<renderdog::GetEventApiCallResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_get_event_api_call",
+            capture_path = %req.capture_path,
+            event_id = req.event_id,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_get_event_api_call", "failed");
+            tracing::debug!(tool = "renderdoc_get_event_api_call", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .get_event_api_call(
+                &cwd,
+                &renderdog::GetEventApiCallRequest {
+                    capture_path: req.capture_path,
+                    event_id: req.event_id,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_get_event_api_call", "failed");
+                tracing::debug!(tool = "renderdoc_get_event_api_call", err = %e, "details");
+                format!("get event api call failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_get_event_api_call",
+            elapsed_ms = start.elapsed().as_millis(),
+            name = %res.name,
+            parameters = res.parameters.len(),
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_get_resource_changed_event_ids",
+        description = "Find all events that modify a resource (texture or buffer). Scans all actions and detects writes from render targets, depth/stencil outputs, clears, copies, and RW shader bindings."
+    )]
+    async fn get_resource_changed_event_ids(
+        &self,
+        Parameters(req): Parameters<GetResourceChangedEventIdsRequest>,
+    ) -> Result<Json<renderdog::GetResourceChangedEventIdsResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_get_resource_changed_event_ids",
+            capture_path = %req.capture_path,
+            resource_name = %req.resource_name,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_get_resource_changed_event_ids", "failed");
+            tracing::debug!(tool = "renderdoc_get_resource_changed_event_ids", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .get_resource_changed_event_ids(
+                &cwd,
+                &renderdog::GetResourceChangedEventIdsRequest {
+                    capture_path: req.capture_path,
+                    resource_name: req.resource_name,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_get_resource_changed_event_ids", "failed");
+                tracing::debug!(tool = "renderdoc_get_resource_changed_event_ids", err = %e, "details");
+                format!("get resource changed event ids failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_get_resource_changed_event_ids",
+            elapsed_ms = start.elapsed().as_millis(),
+            resource_name = %res.resource_name,
+            write_count = res.write_count,
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_search_resources",
+        description = "Search for resources in a .rdc capture. Returns matching resource IDs, names, and types.\n\nFilter options:\n- query: Optional regex pattern to match names. If not provided, returns all resources.\n- resource_types: Optional list to filter by type (e.g., [\"PipelineState\"] returns all pipelines)\n\nRegex examples:\n- \"particle\" - contains 'particle'\n- \"^Texture\" - starts with 'Texture'\n- \"shadow|light\" - contains 'shadow' or 'light'\n\nValid resource_types: Unknown, Device, Queue, CommandBuffer, Texture, Buffer, View, Sampler, SwapchainImage, Memory, Shader, ShaderBinding, PipelineState, StateObject, RenderPass, Query, Sync, Pool, AccelerationStructure, DescriptorStore"
+    )]
+    async fn search_resources(
+        &self,
+        Parameters(req): Parameters<SearchResourcesRequest>,
+    ) -> Result<Json<renderdog::SearchResourcesResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_search_resources",
+            capture_path = %req.capture_path,
+            query = ?req.query,
+            case_sensitive = req.case_sensitive,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_search_resources", "failed");
+            tracing::debug!(tool = "renderdoc_search_resources", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .search_resources(
+                &cwd,
+                &renderdog::SearchResourcesRequest {
+                    capture_path: req.capture_path,
+                    query: req.query,
+                    case_sensitive: req.case_sensitive,
+                    max_results: req.max_results,
+                    resource_types: req.resource_types,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_search_resources", "failed");
+                tracing::debug!(tool = "renderdoc_search_resources", err = %e, "details");
+                format!("search resources failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_search_resources",
+            elapsed_ms = start.elapsed().as_millis(),
+            total_matches = res.total_matches,
+            truncated = res.truncated,
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_find_resource_uses",
+        description = "Find all uses of a resource in a .rdc capture. Returns event IDs, usage types, and detects actual data changes.\n\nUsage types: VertexBuffer, IndexBuffer, VS/PS/CS_Constants (uniform buffers), VS/PS/CS_Resource (textures/samplers), VS/PS/CS_RWResource (storage buffers/images), ColorTarget, DepthStencilTarget, InputTarget, Indirect, Clear, Copy, CopySrc, CopyDst, etc.\n\nThe has_delta field indicates if data actually changed (via binary comparison). When true, delta shows what changed: for buffers with shader reflection {element, fields}, otherwise {offset, length, old_hex, new_hex}. Use delta_filter to return only events with/without changes."
+    )]
+    async fn find_resource_uses(
+        &self,
+        Parameters(req): Parameters<FindResourceUsesRequest>,
+    ) -> Result<Json<renderdog::FindResourceUsesResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_find_resource_uses",
+            capture_path = %req.capture_path,
+            resource = %req.resource,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_find_resource_uses", "failed");
+            tracing::debug!(tool = "renderdoc_find_resource_uses", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .find_resource_uses(
+                &cwd,
+                &renderdog::FindResourceUsesRequest {
+                    capture_path: req.capture_path,
+                    resource: req.resource,
+                    max_results: req.max_results,
+                    data_sample_bytes: req.data_sample_bytes,
+                    delta_filter: req.delta_filter,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_find_resource_uses", "failed");
+                tracing::debug!(tool = "renderdoc_find_resource_uses", err = %e, "details");
+                format!("find resource uses failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_find_resource_uses",
+            elapsed_ms = start.elapsed().as_millis(),
+            total_uses = res.total_uses,
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_find_events_and_save_outputs_png",
+        description = "One-shot helper: find matching events (by marker/name filters) and save current pipeline outputs to PNG at the selected event via headless replay."
+    )]
+    async fn find_events_and_save_outputs_png(
+        &self,
+        Parameters(req): Parameters<FindEventsAndSaveOutputsPngRequest>,
+    ) -> Result<Json<FindEventsAndSaveOutputsPngResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_find_events_and_save_outputs_png",
+            capture_path = %req.capture_path,
+            only_drawcalls = req.only_drawcalls,
+            include_depth = req.include_depth,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(
+                tool = "renderdoc_find_events_and_save_outputs_png",
+                "failed"
+            );
+            tracing::debug!(
+                tool = "renderdoc_find_events_and_save_outputs_png",
+                err = %e,
+                "details"
+            );
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+        let capture_path = resolve_path_from_base(&cwd, &req.capture_path);
+
+        let find = install
+            .find_events(
+                &cwd,
+                &renderdog::FindEventsRequest {
+                    capture_path: capture_path.display().to_string(),
+                    only_drawcalls: req.only_drawcalls,
+                    marker_prefix: req.marker_prefix.clone(),
+                    event_id_min: req.event_id_min,
+                    event_id_max: req.event_id_max,
+                    name_contains: req.name_contains.clone(),
+                    marker_contains: req.marker_contains.clone(),
+                    name_regex: req.name_regex.clone(),
+                    marker_regex: req.marker_regex.clone(),
+                    flags_any: req.flags_any.clone(),
+                    flags_all: req.flags_all.clone(),
+                    query: req.query.clone(),
+                    case_sensitive: req.case_sensitive,
+                    max_results: req.max_results,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| format!("find events failed: {e}"))?;
+
+        if find.total_matches == 0 {
+            return Err(
+                "no matching events found; refine filters or disable only_drawcalls".into(),
+            );
+        }
+
+        let selected_event_id = match req.selection {
+            FindEventSelection::First => find
+                .first_event_id
+                .or_else(|| find.matches.first().map(|m| m.event_id))
+                .ok_or_else(|| "no matching events found".to_string())?,
+            FindEventSelection::Last => find
+                .last_event_id
+                .or_else(|| find.matches.last().map(|m| m.event_id))
+                .ok_or_else(|| "no matching events found".to_string())?,
+        };
+
+        let output_dir = req
+            .output_dir
+            .map(|p| resolve_path_from_base(&cwd, &p).display().to_string())
+            .unwrap_or_else(|| {
+                renderdog::default_exports_dir(&cwd)
+                    .join("replay")
+                    .display()
+                    .to_string()
+            });
+        std::fs::create_dir_all(&output_dir)
+            .map_err(|e| format!("create output_dir failed: {e}"))?;
+
+        let basename = req.basename.unwrap_or_else(|| {
+            capture_path
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or("capture")
+                .to_string()
+        });
+
+        let replay = install
+            .replay_save_outputs_png(
+                &cwd,
+                &renderdog::ReplaySaveOutputsPngRequest {
+                    capture_path: capture_path.display().to_string(),
+                    event_id: Some(selected_event_id),
+                    output_dir,
+                    basename,
+                    include_depth: req.include_depth,
+                    format: None,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| format!("replay save outputs failed: {e}"))?;
+
+        tracing::info!(
+            tool = "renderdoc_find_events_and_save_outputs_png",
+            elapsed_ms = start.elapsed().as_millis(),
+            selected_event_id,
+            outputs = replay.outputs.len(),
+            "ok"
+        );
+
+        Ok(Json(FindEventsAndSaveOutputsPngResponse {
+            find,
+            selected_event_id,
+            replay,
+        }))
+    }
+
+    #[tool(
+        name = "renderdoc_open_capture_ui",
+        description = "Open a .rdc capture in qrenderdoc UI."
+    )]
+    async fn open_capture_ui(
+        &self,
+        Parameters(req): Parameters<OpenCaptureUiRequest>,
+    ) -> Result<Json<OpenCaptureUiResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_open_capture_ui",
+            capture_path = %req.capture_path,
+            "start"
+        );
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_open_capture_ui", "failed");
+            tracing::debug!(tool = "renderdoc_open_capture_ui", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+        let capture_path = resolve_path_from_base(&cwd, &req.capture_path);
+
+        let child = install.open_capture_in_ui(&capture_path).map_err(|e| {
+            tracing::error!(tool = "renderdoc_open_capture_ui", "failed");
+            tracing::debug!(tool = "renderdoc_open_capture_ui", err = %e, "details");
+            format!("open capture UI failed: {e}")
+        })?;
+
+        let pid = child.id();
+
+        tracing::info!(
+            tool = "renderdoc_open_capture_ui",
+            elapsed_ms = start.elapsed().as_millis(),
+            pid,
+            "ok"
+        );
+        Ok(Json(OpenCaptureUiResponse {
+            capture_path: capture_path.display().to_string(),
+            pid,
+        }))
+    }
+
+    #[tool(
+        name = "renderdoc_replay_list_textures",
+        description = "List textures in a .rdc capture via `qrenderdoc --python` replay (headless)."
+    )]
+    async fn replay_list_textures(
+        &self,
+        Parameters(req): Parameters<ReplayListTexturesRequest>,
+    ) -> Result<Json<renderdog::ReplayListTexturesResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_replay_list_textures",
+            capture_path = %req.capture_path,
+            event_id = req.event_id,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_replay_list_textures", "failed");
+            tracing::debug!(tool = "renderdoc_replay_list_textures", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .replay_list_textures(
+                &cwd,
+                &renderdog::ReplayListTexturesRequest {
+                    capture_path: req.capture_path,
+                    event_id: req.event_id,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_replay_list_textures", "failed");
+                tracing::debug!(tool = "renderdoc_replay_list_textures", err = %e, "details");
+                format!("replay list textures failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_replay_list_textures",
+            elapsed_ms = start.elapsed().as_millis(),
+            textures = res.textures.len(),
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_replay_pick_pixel",
+        description = "Pick a pixel from a texture in a .rdc capture via `qrenderdoc --python` replay."
+    )]
+    async fn replay_pick_pixel(
+        &self,
+        Parameters(req): Parameters<ReplayPickPixelRequest>,
+    ) -> Result<Json<renderdog::ReplayPickPixelResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_replay_pick_pixel",
+            capture_path = %req.capture_path,
+            event_id = req.event_id,
+            texture_index = req.texture_index,
+            x = req.x,
+            y = req.y,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_replay_pick_pixel", "failed");
+            tracing::debug!(tool = "renderdoc_replay_pick_pixel", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .replay_pick_pixel(
+                &cwd,
+                &renderdog::ReplayPickPixelRequest {
+                    capture_path: req.capture_path,
+                    event_id: req.event_id,
+                    texture_index: req.texture_index,
+                    x: req.x,
+                    y: req.y,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_replay_pick_pixel", "failed");
+                tracing::debug!(tool = "renderdoc_replay_pick_pixel", err = %e, "details");
+                format!("replay pick pixel failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_replay_pick_pixel",
+            elapsed_ms = start.elapsed().as_millis(),
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_replay_save_texture_png",
+        description = "Save a texture to PNG from a .rdc capture via `qrenderdoc --python` replay. Optional mip / slice / sample selection, channel extraction, black / white points and gamma make HDR and depth targets viewable; slices = all / grid / cross exports every array slice or cube face, and all_mips writes the whole mip chain."
+    )]
+    async fn replay_save_texture_png(
+        &self,
+        Parameters(req): Parameters<ReplaySaveTexturePngRequest>,
+    ) -> Result<Json<renderdog::ReplaySaveTexturePngResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_replay_save_texture_png",
+            capture_path = %req.capture_path,
+            event_id = req.event_id,
+            texture_index = req.texture_index,
+            output_path = %req.output_path,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_replay_save_texture_png", "failed");
+            tracing::debug!(
+                tool = "renderdoc_replay_save_texture_png",
+                err = %e,
+                "details"
+            );
+            format!("detect installation failed: {e}")
+        })?;
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .replay_save_texture_png(
+                &cwd,
+                &renderdog::ReplaySaveTexturePngRequest {
+                    capture_path: req.capture_path,
+                    event_id: req.event_id,
+                    texture_index: req.texture_index,
+                    output_path: req.output_path,
+                    format: req.format,
+                    mip: req.mip,
+                    slice: req.slice,
+                    sample: req.sample,
+                    channels: req.channels,
+                    black_point: req.black_point,
+                    white_point: req.white_point,
+                    gamma: req.gamma,
+                    slices: req.slices,
+                    grid_width: req.grid_width,
+                    all_mips: req.all_mips,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_replay_save_texture_png", "failed");
+                tracing::debug!(
+                    tool = "renderdoc_replay_save_texture_png",
+                    err = %e,
+                    "details"
+                );
+                format!("replay save texture failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_replay_save_texture_png",
+            elapsed_ms = start.elapsed().as_millis(),
+            output_path = %res.output_path,
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_replay_save_outputs_png",
+        description = "Save current pipeline output textures (color RTs + optional depth) to PNG via `qrenderdoc --python` replay (headless)."
+    )]
+    async fn replay_save_outputs_png(
+        &self,
+        Parameters(req): Parameters<ReplaySaveOutputsPngRequest>,
+    ) -> Result<Json<renderdog::ReplaySaveOutputsPngResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_replay_save_outputs_png",
+            capture_path = %req.capture_path,
+            event_id = req.event_id,
+            include_depth = req.include_depth,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_replay_save_outputs_png", "failed");
+            tracing::debug!(
+                tool = "renderdoc_replay_save_outputs_png",
+                err = %e,
+                "details"
+            );
+            format!("detect installation failed: {e}")
+        })?;
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let output_dir = req
+            .output_dir
+            .map(|p| resolve_path_from_base(&cwd, &p).display().to_string())
+            .unwrap_or_else(|| {
+                renderdog::default_exports_dir(&cwd)
+                    .join("replay")
+                    .display()
+                    .to_string()
+            });
+        std::fs::create_dir_all(&output_dir)
+            .map_err(|e| format!("create output_dir failed: {e}"))?;
+
+        let basename = req.basename.unwrap_or_else(|| {
+            Path::new(&req.capture_path)
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or("capture")
+                .to_string()
+        });
+
+        let res = install
+            .replay_save_outputs_png(
+                &cwd,
+                &renderdog::ReplaySaveOutputsPngRequest {
+                    capture_path: req.capture_path,
+                    event_id: req.event_id,
+                    output_dir,
+                    basename,
+                    include_depth: req.include_depth,
+                    format: req.format,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_replay_save_outputs_png", "failed");
+                tracing::debug!(
+                    tool = "renderdoc_replay_save_outputs_png",
+                    err = %e,
+                    "details"
+                );
+                format!("replay save outputs failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_replay_save_outputs_png",
+            elapsed_ms = start.elapsed().as_millis(),
+            outputs = res.outputs.len(),
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_replay_export_mesh",
+        description = "Export post-vertex-shader (stage=vs) or post-geometry-shader (stage=gs) mesh data of an event to OBJ or glTF via `qrenderdoc --python` replay; returns vertex/index counts."
+    )]
+    async fn replay_export_mesh(
+        &self,
+        Parameters(req): Parameters<ReplayExportMeshRequest>,
+    ) -> Result<Json<renderdog::ReplayExportMeshResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_replay_export_mesh",
+            capture_path = %req.capture_path,
+            event_id = req.event_id,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_replay_export_mesh", "failed");
+            tracing::debug!(tool = "renderdoc_replay_export_mesh", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let stage = req.stage.unwrap_or_else(|| "vs".to_string());
+        let format = req.format.unwrap_or_else(|| "obj".to_string());
+        let output_path = req
+            .output_path
+            .map(|p| resolve_path_from_base(&cwd, &p).display().to_string())
+            .unwrap_or_else(|| {
+                let stem = Path::new(&req.capture_path)
+                    .file_stem()
+                    .and_then(|s| s.to_str())
+                    .unwrap_or("capture");
+                let event = req
+                    .event_id
+                    .map(|e| e.to_string())
+                    .unwrap_or_else(|| "last".to_string());
+                renderdog::default_exports_dir(&cwd)
+                    .join("mesh")
+                    .join(format!("{stem}.{event}.{stage}.{format}"))
+                    .display()
+                    .to_string()
+            });
+
+        let res = install
+            .replay_export_mesh(
+                &cwd,
+                &renderdog::ReplayExportMeshRequest {
+                    capture_path: req.capture_path,
+                    event_id: req.event_id,
+                    stage,
+                    format,
+                    output_path,
+                    instance: req.instance,
+                    view: req.view,
+                    perspective_divide: req.perspective_divide,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_replay_export_mesh", "failed");
+                tracing::debug!(tool = "renderdoc_replay_export_mesh", err = %e, "details");
+                format!("replay export mesh failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_replay_export_mesh",
+            elapsed_ms = start.elapsed().as_millis(),
+            vertex_count = res.vertex_count,
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_capture_and_export_actions_jsonl",
+        description = "One-shot workflow: launch target under renderdoccmd capture, trigger capture via target control, then export <basename>.actions.jsonl and <basename>.summary.json."
+    )]
+    async fn capture_and_export_actions_jsonl(
+        &self,
+        Parameters(req): Parameters<CaptureAndExportActionsRequest>,
+    ) -> Result<Json<CaptureAndExportActionsResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_capture_and_export_actions_jsonl",
+            executable = %req.executable,
+            args_len = req.args.len(),
+            only_drawcalls = req.only_drawcalls,
+            "start"
+        );
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(
+                tool = "renderdoc_capture_and_export_actions_jsonl",
+                "failed"
+            );
+            tracing::debug!(
+                tool = "renderdoc_capture_and_export_actions_jsonl",
+                err = %e,
+                "details"
+            );
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let artifacts_dir = req
+            .artifacts_dir
+            .as_deref()
+            .map(|p| resolve_path_from_base(&cwd, p))
+            .unwrap_or_else(|| renderdog::default_artifacts_dir(&cwd));
+
+        std::fs::create_dir_all(&artifacts_dir)
+            .map_err(|e| format!("create artifacts_dir failed: {e}"))?;
+
+        let capture_file_template = req
+            .capture_template_name
+            .as_deref()
+            .map(|name| artifacts_dir.join(format!("{name}.rdc")));
+
+        let launch_req = renderdog::CaptureLaunchRequest {
+            executable: resolve_path_from_base(&cwd, &req.executable),
+            args: req.args.into_iter().map(OsString::from).collect(),
+            working_dir: req.working_dir.map(|p| resolve_path_from_base(&cwd, &p)),
+            capture_file_template: capture_file_template.clone(),
+        };
+
+        let launch_res = install.launch_capture(&launch_req).map_err(|e| {
+            tracing::error!(
+                tool = "renderdoc_capture_and_export_actions_jsonl",
+                "failed"
+            );
+            tracing::debug!(
+                tool = "renderdoc_capture_and_export_actions_jsonl",
+                err = %e,
+                "details"
+            );
+            format!("launch capture failed: {e}")
+        })?;
+
+        let capture_res = install
+            .trigger_capture_via_target_control(
+                &cwd,
+                &renderdog::TriggerCaptureRequest {
+                    host: req.host,
+                    target_ident: launch_res.target_ident,
+                    num_frames: req.num_frames,
+                    timeout_s: req.timeout_s,
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(
+                    tool = "renderdoc_capture_and_export_actions_jsonl",
+                    "failed"
+                );
+                tracing::debug!(
+                    tool = "renderdoc_capture_and_export_actions_jsonl",
+                    err = %e,
+                    "details"
+                );
+                format!("trigger capture failed: {e}")
+            })?;
+
+        let output_dir = req
+            .output_dir
+            .map(|p| resolve_path_from_base(&cwd, &p).display().to_string())
+            .unwrap_or_else(|| renderdog::default_exports_dir(&cwd).display().to_string());
+
+        std::fs::create_dir_all(&output_dir)
+            .map_err(|e| format!("create output_dir failed: {e}"))?;
+
+        let basename = req.basename.unwrap_or_else(|| {
+            Path::new(&capture_res.capture_path)
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or("capture")
+                .to_string()
+        });
+
+        let export_res = install
+            .export_actions_jsonl(
+                &cwd,
+                &renderdog::ExportActionsRequest {
+                    capture_path: capture_res.capture_path.clone(),
+                    output_dir,
+                    basename,
+                    only_drawcalls: req.only_drawcalls,
+                    marker_prefix: req.marker_prefix,
+                    event_id_min: req.event_id_min,
+                    event_id_max: req.event_id_max,
+                    name_contains: req.name_contains,
+                    marker_contains: req.marker_contains,
+                    case_sensitive: req.case_sensitive,
+                    format: None,
+                    query: None,
+                    compress: req.compress,
+                    resolve_callstacks: false,
+                    symbol_path: None,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(
+                    tool = "renderdoc_capture_and_export_actions_jsonl",
+                    "failed"
+                );
+                tracing::debug!(
+                    tool = "renderdoc_capture_and_export_actions_jsonl",
+                    err = %e,
+                    "details"
+                );
+                format!("export actions failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_capture_and_export_actions_jsonl",
+            elapsed_ms = start.elapsed().as_millis(),
+            target_ident = launch_res.target_ident,
+            capture_path = %export_res.capture_path,
+            actions_jsonl_path = %export_res.actions_path,
+            total_actions = export_res.total_actions,
+            "ok"
+        );
+        Ok(Json(CaptureAndExportActionsResponse {
+            target_ident: launch_res.target_ident,
+            capture_path: export_res.capture_path,
+            capture_file_template: capture_file_template.map(|p| p.display().to_string()),
+            stdout: launch_res.stdout,
+            stderr: launch_res.stderr,
+            actions_jsonl_path: export_res.actions_path,
+            summary_json_path: export_res.summary_json_path,
+            total_actions: export_res.total_actions,
+            drawcall_actions: export_res.drawcall_actions,
+        }))
+    }
+
+    #[tool(
+        name = "renderdoc_capture_and_export_bindings_index_jsonl",
+        description = "One-shot workflow: launch target under renderdoccmd capture, trigger capture via target control, then export <basename>.bindings.jsonl and <basename>.bindings_summary.json."
+    )]
+    async fn capture_and_export_bindings_index_jsonl(
+        &self,
+        Parameters(req): Parameters<CaptureAndExportBindingsIndexRequest>,
+    ) -> Result<Json<CaptureAndExportBindingsIndexResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_capture_and_export_bindings_index_jsonl",
+            executable = %req.executable,
+            args_len = req.args.len(),
+            include_cbuffers = req.include_cbuffers,
+            include_outputs = req.include_outputs,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(
+                tool = "renderdoc_capture_and_export_bindings_index_jsonl",
+                "failed"
+            );
+            tracing::debug!(
+                tool = "renderdoc_capture_and_export_bindings_index_jsonl",
+                err = %e,
+                "details"
+            );
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let artifacts_dir = req
+            .artifacts_dir
+            .as_deref()
+            .map(|p| resolve_path_from_base(&cwd, p))
+            .unwrap_or_else(|| renderdog::default_artifacts_dir(&cwd));
+
+        std::fs::create_dir_all(&artifacts_dir)
+            .map_err(|e| format!("create artifacts_dir failed: {e}"))?;
+
+        let capture_file_template = req
+            .capture_template_name
+            .as_deref()
+            .map(|name| artifacts_dir.join(format!("{name}.rdc")));
+
+        let launch_req = renderdog::CaptureLaunchRequest {
+            executable: resolve_path_from_base(&cwd, &req.executable),
+            args: req.args.into_iter().map(OsString::from).collect(),
+            working_dir: req.working_dir.map(|p| resolve_path_from_base(&cwd, &p)),
+            capture_file_template: capture_file_template.clone(),
+        };
+
+        let launch_res = install.launch_capture(&launch_req).map_err(|e| {
+            tracing::error!(
+                tool = "renderdoc_capture_and_export_bindings_index_jsonl",
+                "failed"
+            );
+            tracing::debug!(
+                tool = "renderdoc_capture_and_export_bindings_index_jsonl",
+                err = %e,
+                "details"
+            );
+            format!("launch capture failed: {e}")
+        })?;
+
+        let capture_res = install
+            .trigger_capture_via_target_control(
+                &cwd,
+                &renderdog::TriggerCaptureRequest {
+                    host: req.host,
+                    target_ident: launch_res.target_ident,
+                    num_frames: req.num_frames,
+                    timeout_s: req.timeout_s,
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(
+                    tool = "renderdoc_capture_and_export_bindings_index_jsonl",
+                    "failed"
+                );
+                tracing::debug!(
+                    tool = "renderdoc_capture_and_export_bindings_index_jsonl",
+                    err = %e,
+                    "details"
+                );
+                format!("trigger capture failed: {e}")
+            })?;
+
+        let output_dir = req
+            .output_dir
+            .map(|p| resolve_path_from_base(&cwd, &p).display().to_string())
+            .unwrap_or_else(|| renderdog::default_exports_dir(&cwd).display().to_string());
+
+        std::fs::create_dir_all(&output_dir)
+            .map_err(|e| format!("create output_dir failed: {e}"))?;
+
+        let basename = req.basename.unwrap_or_else(|| {
+            Path::new(&capture_res.capture_path)
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or("capture")
+                .to_string()
+        });
+
+        let export_res = install
+            .export_bindings_index_jsonl(
+                &cwd,
+                &renderdog::ExportBindingsIndexRequest {
+                    capture_path: capture_res.capture_path.clone(),
+                    output_dir,
+                    basename,
+                    marker_prefix: req.marker_prefix,
+                    event_id_min: req.event_id_min,
+                    event_id_max: req.event_id_max,
+                    name_contains: req.name_contains,
+                    marker_contains: req.marker_contains,
+                    case_sensitive: req.case_sensitive,
+                    include_cbuffers: req.include_cbuffers,
+                    include_outputs: req.include_outputs,
+                    compress: req.compress,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(
+                    tool = "renderdoc_capture_and_export_bindings_index_jsonl",
+                    "failed"
+                );
+                tracing::debug!(
+                    tool = "renderdoc_capture_and_export_bindings_index_jsonl",
+                    err = %e,
+                    "details"
+                );
+                format!("export bindings index failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_capture_and_export_bindings_index_jsonl",
+            elapsed_ms = start.elapsed().as_millis(),
+            target_ident = launch_res.target_ident,
+            capture_path = %export_res.capture_path,
+            bindings_jsonl_path = %export_res.bindings_jsonl_path,
+            total_drawcalls = export_res.total_drawcalls,
+            "ok"
+        );
+
+        Ok(Json(CaptureAndExportBindingsIndexResponse {
+            target_ident: launch_res.target_ident,
+            capture_path: export_res.capture_path,
+            capture_file_template: capture_file_template.map(|p| p.display().to_string()),
+            stdout: launch_res.stdout,
+            stderr: launch_res.stderr,
+            bindings_jsonl_path: export_res.bindings_jsonl_path,
+            summary_json_path: export_res.summary_json_path,
+            total_drawcalls: export_res.total_drawcalls,
+        }))
+    }
+
+    #[tool(
+        name = "renderdoc_capture_and_export_bundle_jsonl",
+        description = "One-shot workflow: launch target under renderdoccmd capture, trigger capture via target control, then export both <basename>.actions.jsonl (+ summary) and <basename>.bindings.jsonl (+ bindings_summary)."
+    )]
+    async fn capture_and_export_bundle_jsonl(
+        &self,
+        Parameters(req): Parameters<CaptureAndExportBundleRequest>,
+    ) -> Result<Json<CaptureAndExportBundleResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_capture_and_export_bundle_jsonl",
+            executable = %req.executable,
+            args_len = req.args.len(),
+            only_drawcalls = req.only_drawcalls,
+            include_cbuffers = req.include_cbuffers,
+            include_outputs = req.include_outputs,
+            save_thumbnail = req.save_thumbnail,
+            open_capture_ui = req.open_capture_ui,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_capture_and_export_bundle_jsonl", "failed");
+            tracing::debug!(
+                tool = "renderdoc_capture_and_export_bundle_jsonl",
+                err = %e,
+                "details"
+            );
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let artifacts_dir = req
+            .artifacts_dir
+            .as_deref()
+            .map(|p| resolve_path_from_base(&cwd, p))
+            .unwrap_or_else(|| renderdog::default_artifacts_dir(&cwd));
+
+        std::fs::create_dir_all(&artifacts_dir)
+            .map_err(|e| format!("create artifacts_dir failed: {e}"))?;
+
+        let capture_file_template = req
+            .capture_template_name
+            .as_deref()
+            .map(|name| artifacts_dir.join(format!("{name}.rdc")));
+
+        let launch_req = renderdog::CaptureLaunchRequest {
+            executable: resolve_path_from_base(&cwd, &req.executable),
+            args: req.args.into_iter().map(OsString::from).collect(),
+            working_dir: req.working_dir.map(|p| resolve_path_from_base(&cwd, &p)),
+            capture_file_template: capture_file_template.clone(),
+        };
+
+        let launch_res = install.launch_capture(&launch_req).map_err(|e| {
+            tracing::error!(tool = "renderdoc_capture_and_export_bundle_jsonl", "failed");
+            tracing::debug!(
+                tool = "renderdoc_capture_and_export_bundle_jsonl",
+                err = %e,
+                "details"
+            );
+            format!("launch capture failed: {e}")
+        })?;
+
+        let capture_res = install
+            .trigger_capture_via_target_control(
+                &cwd,
+                &renderdog::TriggerCaptureRequest {
+                    host: req.host,
+                    target_ident: launch_res.target_ident,
+                    num_frames: req.num_frames,
+                    timeout_s: req.timeout_s,
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_capture_and_export_bundle_jsonl", "failed");
+                tracing::debug!(
+                    tool = "renderdoc_capture_and_export_bundle_jsonl",
+                    err = %e,
+                    "details"
+                );
+                format!("trigger capture failed: {e}")
+            })?;
+
+        let output_dir = req
+            .output_dir
+            .map(|p| resolve_path_from_base(&cwd, &p).display().to_string())
+            .unwrap_or_else(|| renderdog::default_exports_dir(&cwd).display().to_string());
+
+        std::fs::create_dir_all(&output_dir)
+            .map_err(|e| format!("create output_dir failed: {e}"))?;
+
+        let basename = req.basename.unwrap_or_else(|| {
+            Path::new(&capture_res.capture_path)
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or("capture")
+                .to_string()
+        });
+
+        let export_res = install
+            .export_bundle_jsonl(
+                &cwd,
+                &renderdog::ExportBundleRequest {
+                    capture_path: capture_res.capture_path.clone(),
+                    output_dir: output_dir.clone(),
+                    basename: basename.clone(),
+                    only_drawcalls: req.only_drawcalls,
+                    marker_prefix: req.marker_prefix,
+                    event_id_min: req.event_id_min,
+                    event_id_max: req.event_id_max,
+                    name_contains: req.name_contains,
+                    marker_contains: req.marker_contains,
+                    case_sensitive: req.case_sensitive,
+                    include_cbuffers: req.include_cbuffers,
+                    include_outputs: req.include_outputs,
+                    compress: req.compress,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_capture_and_export_bundle_jsonl", "failed");
+                tracing::debug!(
+                    tool = "renderdoc_capture_and_export_bundle_jsonl",
+                    err = %e,
+                    "details"
+                );
+                format!("export bundle failed: {e}")
+            })?;
+
+        let mut thumbnail_output_path: Option<String> = None;
+        if req.save_thumbnail {
+            let thumb_path = req
+                .thumbnail_output_path
+                .map(|p| resolve_path_from_base(&cwd, &p).display().to_string())
+                .unwrap_or_else(|| {
+                    Path::new(&output_dir)
+                        .join(format!("{basename}.thumb.png"))
+                        .display()
+                        .to_string()
+                });
+            if let Some(parent) = Path::new(&thumb_path).parent() {
+                std::fs::create_dir_all(parent)
+                    .map_err(|e| format!("create thumbnail output dir failed: {e}"))?;
+            }
+            install
+                .save_thumbnail(Path::new(&export_res.capture_path), Path::new(&thumb_path))
+                .map_err(|e| format!("save thumbnail failed: {e}"))?;
+            thumbnail_output_path = Some(thumb_path);
+        }
 
-#[tool_router(router = tool_router)]
-impl RenderdogMcpServer {
-    fn new() -> Self {
-        Self {
-            tool_router: Self::tool_router(),
+        let mut ui_pid: Option<u32> = None;
+        if req.open_capture_ui {
+            let child = install
+                .open_capture_in_ui(Path::new(&export_res.capture_path))
+                .map_err(|e| format!("open capture UI failed: {e}"))?;
+            ui_pid = Some(child.id());
         }
+
+        tracing::info!(
+            tool = "renderdoc_capture_and_export_bundle_jsonl",
+            elapsed_ms = start.elapsed().as_millis(),
+            target_ident = launch_res.target_ident,
+            capture_path = %export_res.capture_path,
+            actions_jsonl_path = %export_res.actions_jsonl_path,
+            bindings_jsonl_path = %export_res.bindings_jsonl_path,
+            total_actions = export_res.total_actions,
+            total_drawcalls = export_res.total_drawcalls,
+            "ok"
+        );
+
+        Ok(Json(CaptureAndExportBundleResponse {
+            target_ident: launch_res.target_ident,
+            capture_path: export_res.capture_path,
+            capture_file_template: capture_file_template.map(|p| p.display().to_string()),
+            stdout: launch_res.stdout,
+            stderr: launch_res.stderr,
+
+            actions_jsonl_path: export_res.actions_jsonl_path,
+            actions_summary_json_path: export_res.actions_summary_json_path,
+            total_actions: export_res.total_actions,
+            drawcall_actions: export_res.drawcall_actions,
+
+            bindings_jsonl_path: export_res.bindings_jsonl_path,
+            bindings_summary_json_path: export_res.bindings_summary_json_path,
+            total_drawcalls: export_res.total_drawcalls,
+
+            thumbnail_output_path,
+            ui_pid,
+        }))
     }
 
     #[tool(
-        name = "renderdoc_detect_installation",
-        description = "Detect local RenderDoc installation and return tool paths."
+        name = "renderdoc_get_vertex_inputs",
+        description = "Read the bound vertex buffers of a draw and decode them per vertex using the input layout's attribute formats."
+    )]
+    async fn get_vertex_inputs(
+        &self,
+        Parameters(req): Parameters<GetVertexInputsRequest>,
+    ) -> Result<Json<renderdog::GetVertexInputsResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_get_vertex_inputs",
+            capture_path = %req.capture_path,
+            event_id = req.event_id,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_get_vertex_inputs", "failed");
+            tracing::debug!(tool = "renderdoc_get_vertex_inputs", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .get_vertex_inputs(
+                &cwd,
+                &renderdog::GetVertexInputsRequest {
+                    capture_path: req.capture_path,
+                    event_id: req.event_id,
+                    first: req.first,
+                    count: req.count,
+                    instance: req.instance,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_get_vertex_inputs", "failed");
+                tracing::debug!(tool = "renderdoc_get_vertex_inputs", err = %e, "details");
+                format!("get vertex inputs failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_get_vertex_inputs",
+            elapsed_ms = start.elapsed().as_millis(),
+            vertices = res.vertices.len(),
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_get_indices",
+        description = "Read the decoded index values of an indexed draw (Uint16/Uint32, honouring the index offset) and flag indices past the bound vertex buffers."
+    )]
+    async fn get_indices(
+        &self,
+        Parameters(req): Parameters<GetIndicesRequest>,
+    ) -> Result<Json<renderdog::GetIndicesResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_get_indices",
+            capture_path = %req.capture_path,
+            event_id = req.event_id,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_get_indices", "failed");
+            tracing::debug!(tool = "renderdoc_get_indices", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .get_indices(
+                &cwd,
+                &renderdog::GetIndicesRequest {
+                    capture_path: req.capture_path,
+                    event_id: req.event_id,
+                    first: req.first,
+                    count: req.count,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_get_indices", "failed");
+                tracing::debug!(tool = "renderdoc_get_indices", err = %e, "details");
+                format!("get indices failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_get_indices",
+            elapsed_ms = start.elapsed().as_millis(),
+            indices = res.indices.len(),
+            out_of_range = res.out_of_range.len(),
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_debug_pixel",
+        description = "Debug the pixel shader invocation that wrote (x, y) at an event: source-mapped steps, changed variables and final register/variable values."
+    )]
+    async fn debug_pixel(
+        &self,
+        Parameters(req): Parameters<DebugPixelRequest>,
+    ) -> Result<Json<renderdog::DebugShaderResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_debug_pixel",
+            capture_path = %req.capture_path,
+            event_id = req.event_id,
+            x = req.x,
+            y = req.y,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_debug_pixel", "failed");
+            tracing::debug!(tool = "renderdoc_debug_pixel", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .debug_pixel(
+                &cwd,
+                &renderdog::DebugPixelRequest {
+                    capture_path: req.capture_path,
+                    event_id: req.event_id,
+                    x: req.x,
+                    y: req.y,
+                    sample: req.sample,
+                    primitive: req.primitive,
+                    view: req.view,
+                    max_steps: req.max_steps,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_debug_pixel", "failed");
+                tracing::debug!(tool = "renderdoc_debug_pixel", err = %e, "details");
+                format!("debug pixel failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_debug_pixel",
+            elapsed_ms = start.elapsed().as_millis(),
+            total_steps = res.total_steps,
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_debug_vertex",
+        description = "Debug one vertex shader invocation of a draw: source-mapped steps, changed variables and final register/variable values."
+    )]
+    async fn debug_vertex(
+        &self,
+        Parameters(req): Parameters<DebugVertexRequest>,
+    ) -> Result<Json<renderdog::DebugShaderResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_debug_vertex",
+            capture_path = %req.capture_path,
+            event_id = req.event_id,
+            vertex_id = req.vertex_id,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_debug_vertex", "failed");
+            tracing::debug!(tool = "renderdoc_debug_vertex", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .debug_vertex(
+                &cwd,
+                &renderdog::DebugVertexRequest {
+                    capture_path: req.capture_path,
+                    event_id: req.event_id,
+                    vertex_id: req.vertex_id,
+                    instance: req.instance,
+                    index: req.index,
+                    view: req.view,
+                    max_steps: req.max_steps,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_debug_vertex", "failed");
+                tracing::debug!(tool = "renderdoc_debug_vertex", err = %e, "details");
+                format!("debug vertex failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_debug_vertex",
+            elapsed_ms = start.elapsed().as_millis(),
+            total_steps = res.total_steps,
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_debug_thread",
+        description = "Debug one compute shader thread of a dispatch: source-mapped steps, changed variables and final register/variable values."
+    )]
+    async fn debug_thread(
+        &self,
+        Parameters(req): Parameters<DebugThreadRequest>,
+    ) -> Result<Json<renderdog::DebugShaderResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_debug_thread",
+            capture_path = %req.capture_path,
+            event_id = req.event_id,
+            group = ?req.group,
+            thread = ?req.thread,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_debug_thread", "failed");
+            tracing::debug!(tool = "renderdoc_debug_thread", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .debug_thread(
+                &cwd,
+                &renderdog::DebugThreadRequest {
+                    capture_path: req.capture_path,
+                    event_id: req.event_id,
+                    group: req.group,
+                    thread: req.thread,
+                    max_steps: req.max_steps,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_debug_thread", "failed");
+                tracing::debug!(tool = "renderdoc_debug_thread", err = %e, "details");
+                format!("debug thread failed: {e}")
+            })?;
+
+        tracing::info!(
+            tool = "renderdoc_debug_thread",
+            elapsed_ms = start.elapsed().as_millis(),
+            total_steps = res.total_steps,
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_fetch_counters",
+        description = "Fetch GPU counters (GPU duration, samples passed, VS/PS invocations or any driver counter) for every event of a capture, with counter metadata (unit, description)."
     )]
-    async fn detect_installation(&self) -> Result<Json<DetectInstallationResponse>, String> {
+    async fn fetch_counters(
+        &self,
+        Parameters(req): Parameters<FetchCountersRequest>,
+    ) -> Result<Json<renderdog::FetchCountersResponse>, String> {
         let start = Instant::now();
-        tracing::info!(tool = "renderdoc_detect_installation", "start");
+        tracing::info!(
+            tool = "renderdoc_fetch_counters",
+            capture_path = %req.capture_path,
+            "start"
+        );
+
         let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
-            tracing::error!(tool = "renderdoc_detect_installation", "failed");
-            tracing::debug!(tool = "renderdoc_detect_installation", err = %e, "details");
+            tracing::error!(tool = "renderdoc_fetch_counters", "failed");
+            tracing::debug!(tool = "renderdoc_fetch_counters", err = %e, "details");
             format!("detect installation failed: {e}")
         })?;
 
-        let version = install.version().ok().map(|s| s.trim().to_string());
-        let vulkan_layer = install.diagnose_vulkan_layer().ok();
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .fetch_counters(
+                &cwd,
+                &renderdog::FetchCountersRequest {
+                    capture_path: req.capture_path,
+                    counters: req.counters,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_fetch_counters", "failed");
+                tracing::debug!(tool = "renderdoc_fetch_counters", err = %e, "details");
+                format!("fetch counters failed: {e}")
+            })?;
 
         tracing::info!(
-            tool = "renderdoc_detect_installation",
+            tool = "renderdoc_fetch_counters",
             elapsed_ms = start.elapsed().as_millis(),
+            events = res.events.len(),
             "ok"
         );
-        Ok(Json(DetectInstallationResponse {
-            root_dir: install.root_dir.display().to_string(),
-            qrenderdoc_exe: install.qrenderdoc_exe.display().to_string(),
-            renderdoccmd_exe: install.renderdoccmd_exe.display().to_string(),
-            version,
-            vulkan_layer,
-        }))
+        Ok(Json(res))
     }
 
     #[tool(
-        name = "renderdoc_vulkanlayer_diagnose",
-        description = "Diagnose Vulkan layer registration status using `renderdoccmd vulkanlayer --explain` and return suggested fix commands."
+        name = "renderdoc_get_timestamp_queries",
+        description = "Extract the GPU timestamp queries the application wrote in a capture (vkCmdWriteTimestamp, D3D12/D3D11 timestamp queries, glQueryCounter) with their pool, index and marker path, read their values where the frame copies results into a buffer, and group them by marker region with elapsed ticks (or ns with timestamp_period_ns). Complements renderdoc_fetch_counters."
     )]
-    async fn vulkanlayer_diagnose(&self) -> Result<Json<renderdog::VulkanLayerDiagnosis>, String> {
+    async fn get_timestamp_queries(
+        &self,
+        Parameters(req): Parameters<GetTimestampQueriesRequest>,
+    ) -> Result<Json<renderdog::GetTimestampQueriesResponse>, String> {
         let start = Instant::now();
-        tracing::info!(tool = "renderdoc_vulkanlayer_diagnose", "start");
+        tracing::info!(
+            tool = "renderdoc_get_timestamp_queries",
+            capture_path = %req.capture_path,
+            "start"
+        );
+
         let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
-            tracing::error!(tool = "renderdoc_vulkanlayer_diagnose", "failed");
-            tracing::debug!(tool = "renderdoc_vulkanlayer_diagnose", err = %e, "details");
+            tracing::error!(tool = "renderdoc_get_timestamp_queries", "failed");
+            tracing::debug!(tool = "renderdoc_get_timestamp_queries", err = %e, "details");
             format!("detect installation failed: {e}")
         })?;
-        let diag = install.diagnose_vulkan_layer().map_err(|e| {
-            tracing::error!(tool = "renderdoc_vulkanlayer_diagnose", "failed");
-            tracing::debug!(tool = "renderdoc_vulkanlayer_diagnose", err = %e, "details");
-            format!("diagnose vulkan layer failed: {e}")
-        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .get_timestamp_queries(
+                &cwd,
+                &renderdog::GetTimestampQueriesRequest {
+                    capture_path: req.capture_path,
+                    timestamp_period_ns: req.timestamp_period_ns,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_get_timestamp_queries", "failed");
+                tracing::debug!(tool = "renderdoc_get_timestamp_queries", err = %e, "details");
+                format!("get timestamp queries failed: {e}")
+            })?;
+
         tracing::info!(
-            tool = "renderdoc_vulkanlayer_diagnose",
+            tool = "renderdoc_get_timestamp_queries",
             elapsed_ms = start.elapsed().as_millis(),
+            total_timestamps = res.total_timestamps,
+            resolved_timestamps = res.resolved_timestamps,
             "ok"
         );
-        Ok(Json(diag))
+        Ok(Json(res))
     }
 
     #[tool(
-        name = "renderdoc_diagnose_environment",
-        description = "Diagnose RenderDoc environment (paths, renderdoccmd version, Vulkan layer registration, and key Vulkan-related env vars) and return warnings + suggested fixes."
+        name = "renderdoc_export_chrome_trace",
+        description = "Export a capture's frame as Chrome trace JSON (Perfetto / about:tracing): markers become nested slices and per-event GPU durations become slice lengths."
     )]
-    async fn diagnose_environment(&self) -> Result<Json<renderdog::EnvironmentDiagnosis>, String> {
+    async fn export_chrome_trace(
+        &self,
+        Parameters(req): Parameters<ExportChromeTraceRequest>,
+    ) -> Result<Json<renderdog::ExportChromeTraceResponse>, String> {
         let start = Instant::now();
-        tracing::info!(tool = "renderdoc_diagnose_environment", "start");
+        tracing::info!(
+            tool = "renderdoc_export_chrome_trace",
+            capture_path = %req.capture_path,
+            "start"
+        );
+
         let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
-            tracing::error!(tool = "renderdoc_diagnose_environment", "failed");
-            tracing::debug!(tool = "renderdoc_diagnose_environment", err = %e, "details");
+            tracing::error!(tool = "renderdoc_export_chrome_trace", "failed");
+            tracing::debug!(tool = "renderdoc_export_chrome_trace", err = %e, "details");
             format!("detect installation failed: {e}")
         })?;
-        let diag = install.diagnose_environment().map_err(|e| {
-            tracing::error!(tool = "renderdoc_diagnose_environment", "failed");
-            tracing::debug!(tool = "renderdoc_diagnose_environment", err = %e, "details");
-            format!("diagnose environment failed: {e}")
-        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let output_path = req
+            .output_path
+            .map(|p| resolve_path_from_base(&cwd, &p).display().to_string())
+            .unwrap_or_else(|| {
+                let stem = Path::new(&req.capture_path)
+                    .file_stem()
+                    .and_then(|s| s.to_str())
+                    .unwrap_or("capture");
+                renderdog::default_exports_dir(&cwd)
+                    .join("trace")
+                    .join(format!("{stem}.trace.json"))
+                    .display()
+                    .to_string()
+            });
+
+        let res = install
+            .export_chrome_trace(
+                &cwd,
+                &renderdog::ExportChromeTraceRequest {
+                    capture_path: req.capture_path,
+                    output_path,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_export_chrome_trace", "failed");
+                tracing::debug!(tool = "renderdoc_export_chrome_trace", err = %e, "details");
+                format!("export chrome trace failed: {e}")
+            })?;
+
         tracing::info!(
-            tool = "renderdoc_diagnose_environment",
+            tool = "renderdoc_export_chrome_trace",
             elapsed_ms = start.elapsed().as_millis(),
+            slices = res.slice_count,
+            has_gpu_durations = res.has_gpu_durations,
             "ok"
         );
-        Ok(Json(diag))
+        Ok(Json(res))
     }
 
     #[tool(
-        name = "renderdoc_launch_capture",
-        description = "Launch target executable under RenderDoc injection using renderdoccmd capture; returns target ident (port)."
+        name = "renderdoc_replay_save_overdraw_png",
+        description = "Render RenderDoc's quad overdraw overlays for an event (or the last draw of every pass when event_id is omitted) and save them as PNGs."
     )]
-    async fn launch_capture(
+    async fn replay_save_overdraw_png(
         &self,
-        Parameters(req): Parameters<LaunchCaptureRequest>,
-    ) -> Result<Json<LaunchCaptureResponse>, String> {
+        Parameters(req): Parameters<ReplaySaveOverdrawPngRequest>,
+    ) -> Result<Json<renderdog::ReplaySaveOverdrawPngResponse>, String> {
         let start = Instant::now();
         tracing::info!(
-            tool = "renderdoc_launch_capture",
-            executable = %req.executable,
-            args_len = req.args.len(),
+            tool = "renderdoc_replay_save_overdraw_png",
+            capture_path = %req.capture_path,
+            event_id = req.event_id,
             "start"
         );
+
         let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
-            tracing::error!(tool = "renderdoc_launch_capture", "failed");
-            tracing::debug!(tool = "renderdoc_launch_capture", err = %e, "details");
+            tracing::error!(tool = "renderdoc_replay_save_overdraw_png", "failed");
+            tracing::debug!(tool = "renderdoc_replay_save_overdraw_png", err = %e, "details");
             format!("detect installation failed: {e}")
         })?;
 
         let cwd = resolve_base_cwd(req.cwd.clone())?;
 
-        let artifacts_dir = req
-            .artifacts_dir
-            .as_deref()
-            .map(|p| resolve_path_from_base(&cwd, p))
-            .unwrap_or_else(|| renderdog::default_artifacts_dir(&cwd));
+        let output_dir = req
+            .output_dir
+            .map(|p| resolve_path_from_base(&cwd, &p).display().to_string())
+            .unwrap_or_else(|| {
+                renderdog::default_exports_dir(&cwd)
+                    .join("overdraw")
+                    .display()
+                    .to_string()
+            });
 
-        std::fs::create_dir_all(&artifacts_dir)
-            .map_err(|e| format!("create artifacts_dir failed: {e}"))?;
+        let basename = req.basename.unwrap_or_else(|| {
+            Path::new(&req.capture_path)
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or("capture")
+                .to_string()
+        });
 
-        let capture_file_template = req
-            .capture_template_name
-            .as_deref()
-            .map(|name| artifacts_dir.join(format!("{name}.rdc")));
+        let res = install
+            .replay_save_overdraw_png(
+                &cwd,
+                &renderdog::ReplaySaveOverdrawPngRequest {
+                    capture_path: req.capture_path,
+                    event_id: req.event_id,
+                    output_dir,
+                    basename,
+                    modes: req.modes,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_replay_save_overdraw_png", "failed");
+                tracing::debug!(tool = "renderdoc_replay_save_overdraw_png", err = %e, "details");
+                format!("replay save overdraw failed: {e}")
+            })?;
 
-        let request = renderdog::CaptureLaunchRequest {
-            executable: resolve_path_from_base(&cwd, &req.executable),
-            args: req.args.into_iter().map(OsString::from).collect(),
-            working_dir: req.working_dir.map(|p| resolve_path_from_base(&cwd, &p)),
-            capture_file_template: capture_file_template.clone(),
-        };
+        tracing::info!(
+            tool = "renderdoc_replay_save_overdraw_png",
+            elapsed_ms = start.elapsed().as_millis(),
+            images = res.images.len(),
+            "ok"
+        );
+        Ok(Json(res))
+    }
 
-        let res = install.launch_capture(&request).map_err(|e| {
-            tracing::error!(tool = "renderdoc_launch_capture", "failed");
-            tracing::debug!(tool = "renderdoc_launch_capture", err = %e, "details");
-            format!("launch capture failed: {e}")
+    #[tool(
+        name = "renderdoc_get_texture_stats",
+        description = "Per-channel min/max/mean (and optional 256-bucket histogram) of a texture at an event. texture is a resource id, resource name, rt0/rt1/... or depth."
+    )]
+    async fn get_texture_stats(
+        &self,
+        Parameters(req): Parameters<GetTextureStatsRequest>,
+    ) -> Result<Json<renderdog::GetTextureStatsResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_get_texture_stats",
+            capture_path = %req.capture_path,
+            event_id = req.event_id,
+            texture = %req.texture,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_get_texture_stats", "failed");
+            tracing::debug!(tool = "renderdoc_get_texture_stats", err = %e, "details");
+            format!("detect installation failed: {e}")
         })?;
 
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
+        let res = install
+            .get_texture_stats(
+                &cwd,
+                &renderdog::GetTextureStatsRequest {
+                    capture_path: req.capture_path,
+                    event_id: req.event_id,
+                    texture: req.texture,
+                    mip: req.mip,
+                    slice: req.slice,
+                    sample: req.sample,
+                    histogram: req.histogram,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_get_texture_stats", "failed");
+                tracing::debug!(tool = "renderdoc_get_texture_stats", err = %e, "details");
+                format!("get texture stats failed: {e}")
+            })?;
+
         tracing::info!(
-            tool = "renderdoc_launch_capture",
+            tool = "renderdoc_get_texture_stats",
             elapsed_ms = start.elapsed().as_millis(),
-            target_ident = res.target_ident,
+            channels = res.channels.len(),
             "ok"
         );
-        Ok(Json(LaunchCaptureResponse {
-            target_ident: res.target_ident,
-            capture_file_template: capture_file_template.map(|p| p.display().to_string()),
-            stdout: res.stdout,
-            stderr: res.stderr,
-        }))
+        Ok(Json(res))
     }
 
     #[tool(
-        name = "renderdoc_save_thumbnail",
-        description = "Extract embedded thumbnail from a .rdc capture using renderdoccmd thumb."
+        name = "renderdoc_scan_targets_for_invalid_values",
+        description = "Replay each draw/dispatch in an event range and scan floating-point colour (and optionally depth) targets for NaN/Inf texels, returning offending events, targets and example coordinates."
     )]
-    async fn save_thumbnail(
+    async fn scan_targets_for_invalid_values(
         &self,
-        Parameters(req): Parameters<SaveThumbnailRequest>,
-    ) -> Result<Json<SaveThumbnailResponse>, String> {
+        Parameters(req): Parameters<ScanTargetsForInvalidValuesRequest>,
+    ) -> Result<Json<renderdog::ScanTargetsForInvalidValuesResponse>, String> {
         let start = Instant::now();
         tracing::info!(
-            tool = "renderdoc_save_thumbnail",
+            tool = "renderdoc_scan_targets_for_invalid_values",
             capture_path = %req.capture_path,
-            output_path = %req.output_path,
+            event_id_min = req.event_id_min,
+            event_id_max = req.event_id_max,
             "start"
         );
+
         let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
-            tracing::error!(tool = "renderdoc_save_thumbnail", "failed");
-            tracing::debug!(tool = "renderdoc_save_thumbnail", err = %e, "details");
+            tracing::error!(tool = "renderdoc_scan_targets_for_invalid_values", "failed");
+            tracing::debug!(tool = "renderdoc_scan_targets_for_invalid_values", err = %e, "details");
             format!("detect installation failed: {e}")
         })?;
 
         let cwd = resolve_base_cwd(req.cwd.clone())?;
-        let capture_path = resolve_path_from_base(&cwd, &req.capture_path);
-        let output_path = resolve_path_from_base(&cwd, &req.output_path);
 
-        if let Some(parent) = output_path.parent() {
-            std::fs::create_dir_all(parent)
-                .map_err(|e| format!("create output dir failed: {e}"))?;
-        }
-
-        install
-            .save_thumbnail(&capture_path, &output_path)
+        let res = install
+            .scan_targets_for_invalid_values(
+                &cwd,
+                &renderdog::ScanTargetsForInvalidValuesRequest {
+                    capture_path: req.capture_path,
+                    event_id_min: req.event_id_min,
+                    event_id_max: req.event_id_max,
+                    include_depth: req.include_depth,
+                    max_examples: req.max_examples,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
             .map_err(|e| {
-                tracing::error!(tool = "renderdoc_save_thumbnail", "failed");
-                tracing::debug!(tool = "renderdoc_save_thumbnail", err = %e, "details");
-                format!("save thumbnail failed: {e}")
+                tracing::error!(tool = "renderdoc_scan_targets_for_invalid_values", "failed");
+                tracing::debug!(tool = "renderdoc_scan_targets_for_invalid_values", err = %e, "details");
+                format!("scan targets for invalid values failed: {e}")
             })?;
 
         tracing::info!(
-            tool = "renderdoc_save_thumbnail",
+            tool = "renderdoc_scan_targets_for_invalid_values",
             elapsed_ms = start.elapsed().as_millis(),
+            findings = res.findings.len(),
+            first_bad_event = res.first_bad_event,
             "ok"
         );
-        Ok(Json(SaveThumbnailResponse {
-            output_path: output_path.display().to_string(),
-        }))
+        Ok(Json(res))
     }
 
     #[tool(
-        name = "renderdoc_trigger_capture",
-        description = "Trigger a frame capture on a RenderDoc-injected target (started via renderdoccmd capture) and return the resulting .rdc path."
+        name = "renderdoc_compare_textures",
+        description = "Compare two textures, each given as (capture_path, event_id, texture) so sides may come from different captures: differing texel count, max delta, MSE/PSNR and SSIM, with an optional diff PNG."
     )]
-    async fn trigger_capture(
+    async fn compare_textures(
         &self,
-        Parameters(req): Parameters<TriggerCaptureRequest>,
-    ) -> Result<Json<renderdog::TriggerCaptureResponse>, String> {
+        Parameters(req): Parameters<CompareTexturesRequest>,
+    ) -> Result<Json<renderdog::CompareTexturesResponse>, String> {
         let start = Instant::now();
         tracing::info!(
-            tool = "renderdoc_trigger_capture",
-            host = %req.host,
-            target_ident = req.target_ident,
-            frames = req.num_frames,
-            timeout_s = req.timeout_s,
+            tool = "renderdoc_compare_textures",
+            a = %req.a.capture_path,
+            b = %req.b.capture_path,
             "start"
         );
+
         let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
-            tracing::error!(tool = "renderdoc_trigger_capture", "failed");
-            tracing::debug!(tool = "renderdoc_trigger_capture", err = %e, "details");
+            tracing::error!(tool = "renderdoc_compare_textures", "failed");
+            tracing::debug!(tool = "renderdoc_compare_textures", err = %e, "details");
             format!("detect installation failed: {e}")
         })?;
 
         let cwd = resolve_base_cwd(req.cwd.clone())?;
 
         let res = install
-            .trigger_capture_via_target_control(
+            .compare_textures(
                 &cwd,
-                &renderdog::TriggerCaptureRequest {
-                    host: req.host,
-                    target_ident: req.target_ident,
-                    num_frames: req.num_frames,
-                    timeout_s: req.timeout_s,
+                &renderdog::CompareTexturesRequest {
+                    a: req.a,
+                    b: req.b,
+                    threshold: req.threshold,
+                    diff_output_path: req.diff_output_path,
+                    replay_options: req.replay_options.clone(),
                 },
             )
             .map_err(|e| {
-                tracing::error!(tool = "renderdoc_trigger_capture", "failed");
-                tracing::debug!(tool = "renderdoc_trigger_capture", err = %e, "details");
-                format!("trigger capture failed: {e}")
+                tracing::error!(tool = "renderdoc_compare_textures", "failed");
+                tracing::debug!(tool = "renderdoc_compare_textures", err = %e, "details");
+                format!("compare textures failed: {e}")
             })?;
 
         tracing::info!(
-            tool = "renderdoc_trigger_capture",
+            tool = "renderdoc_compare_textures",
             elapsed_ms = start.elapsed().as_millis(),
-            capture_path = %res.capture_path,
+            diff_count = res.diff_count,
+            psnr = ?res.psnr,
             "ok"
         );
         Ok(Json(res))
     }
 
     #[tool(
-        name = "renderdoc_export_actions_jsonl",
-        description = "Export a capture (.rdc) into searchable artifacts: <basename>.actions.jsonl and <basename>.summary.json."
+        name = "renderdoc_update_baseline",
+        description = "Replay events of a capture and store their outputs as golden PNGs plus a baseline.json manifest, for later verify_against_baseline runs."
     )]
-    async fn export_actions_jsonl(
+    async fn update_baseline(
         &self,
-        Parameters(req): Parameters<ExportActionsRequest>,
-    ) -> Result<Json<renderdog::ExportActionsResponse>, String> {
+        Parameters(req): Parameters<UpdateBaselineRequest>,
+    ) -> Result<Json<renderdog::UpdateBaselineResponse>, String> {
         let start = Instant::now();
         tracing::info!(
-            tool = "renderdoc_export_actions_jsonl",
+            tool = "renderdoc_update_baseline",
             capture_path = %req.capture_path,
-            only_drawcalls = req.only_drawcalls,
+            baseline_dir = %req.baseline_dir,
             "start"
         );
+
         let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
-            tracing::error!(tool = "renderdoc_export_actions_jsonl", "failed");
-            tracing::debug!(tool = "renderdoc_export_actions_jsonl", err = %e, "details");
+            tracing::error!(tool = "renderdoc_update_baseline", "failed");
+            tracing::debug!(tool = "renderdoc_update_baseline", err = %e, "details");
             format!("detect installation failed: {e}")
         })?;
 
         let cwd = resolve_base_cwd(req.cwd.clone())?;
 
-        let output_dir = req
-            .output_dir
-            .map(|p| resolve_path_from_base(&cwd, &p).display().to_string())
-            .unwrap_or_else(|| renderdog::default_exports_dir(&cwd).display().to_string());
-
-        std::fs::create_dir_all(&output_dir)
-            .map_err(|e| format!("create output_dir failed: {e}"))?;
-
-        let basename = req.basename.unwrap_or_else(|| {
-            Path::new(&req.capture_path)
-                .file_stem()
-                .and_then(|s| s.to_str())
-                .unwrap_or("capture")
-                .to_string()
-        });
-
         let res = install
-            .export_actions_jsonl(
+            .update_baseline(
                 &cwd,
-                &renderdog::ExportActionsRequest {
+                &renderdog::UpdateBaselineRequest {
                     capture_path: req.capture_path,
-                    output_dir,
-                    basename,
-                    only_drawcalls: req.only_drawcalls,
-                    marker_prefix: req.marker_prefix,
-                    event_id_min: req.event_id_min,
-                    event_id_max: req.event_id_max,
-                    name_contains: req.name_contains,
-                    marker_contains: req.marker_contains,
-                    case_sensitive: req.case_sensitive,
+                    event_ids: req.event_ids,
+                    baseline_dir: req.baseline_dir,
+                    include_depth: req.include_depth,
+                    replay_options: req.replay_options.clone(),
                 },
             )
             .map_err(|e| {
-                tracing::error!(tool = "renderdoc_export_actions_jsonl", "failed");
-                tracing::debug!(tool = "renderdoc_export_actions_jsonl", err = %e, "details");
-                format!("export actions failed: {e}")
+                tracing::error!(tool = "renderdoc_update_baseline", "failed");
+                tracing::debug!(tool = "renderdoc_update_baseline", err = %e, "details");
+                format!("update baseline failed: {e}")
             })?;
 
         tracing::info!(
-            tool = "renderdoc_export_actions_jsonl",
+            tool = "renderdoc_update_baseline",
             elapsed_ms = start.elapsed().as_millis(),
-            actions_jsonl_path = %res.actions_jsonl_path,
-            total_actions = res.total_actions,
+            entries = res.manifest.entries.len(),
             "ok"
         );
         Ok(Json(res))
     }
 
     #[tool(
-        name = "renderdoc_export_bindings_index_jsonl",
-        description = "Export a capture (.rdc) into a searchable bindings index: <basename>.bindings.jsonl and <basename>.bindings_summary.json."
+        name = "renderdoc_verify_against_baseline",
+        description = "Replay a capture, compare its outputs against a golden baseline directory and return a pass/fail report (also written as report.json) with diff images for failures."
     )]
-    async fn export_bindings_index_jsonl(
+    async fn verify_against_baseline(
         &self,
-        Parameters(req): Parameters<ExportBindingsIndexRequest>,
-    ) -> Result<Json<renderdog::ExportBindingsIndexResponse>, String> {
+        Parameters(req): Parameters<VerifyBaselineRequest>,
+    ) -> Result<Json<renderdog::RegressionReport>, String> {
         let start = Instant::now();
         tracing::info!(
-            tool = "renderdoc_export_bindings_index_jsonl",
+            tool = "renderdoc_verify_against_baseline",
             capture_path = %req.capture_path,
-            include_cbuffers = req.include_cbuffers,
-            include_outputs = req.include_outputs,
+            baseline_dir = %req.baseline_dir,
             "start"
         );
 
         let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
-            tracing::error!(tool = "renderdoc_export_bindings_index_jsonl", "failed");
-            tracing::debug!(tool = "renderdoc_export_bindings_index_jsonl", err = %e, "details");
+            tracing::error!(tool = "renderdoc_verify_against_baseline", "failed");
+            tracing::debug!(tool = "renderdoc_verify_against_baseline", err = %e, "details");
             format!("detect installation failed: {e}")
         })?;
 
         let cwd = resolve_base_cwd(req.cwd.clone())?;
 
-        let output_dir = req
-            .output_dir
-            .map(|p| resolve_path_from_base(&cwd, &p).display().to_string())
-            .unwrap_or_else(|| renderdog::default_exports_dir(&cwd).display().to_string());
-
-        std::fs::create_dir_all(&output_dir)
-            .map_err(|e| format!("create output_dir failed: {e}"))?;
-
-        let basename = req.basename.unwrap_or_else(|| {
-            Path::new(&req.capture_path)
-                .file_stem()
-                .and_then(|s| s.to_str())
-                .unwrap_or("capture")
-                .to_string()
-        });
-
         let res = install
-            .export_bindings_index_jsonl(
+            .verify_against_baseline(
                 &cwd,
-                &renderdog::ExportBindingsIndexRequest {
+                &renderdog::VerifyBaselineRequest {
                     capture_path: req.capture_path,
-                    output_dir,
-                    basename,
-                    marker_prefix: req.marker_prefix,
-                    event_id_min: req.event_id_min,
-                    event_id_max: req.event_id_max,
-                    name_contains: req.name_contains,
-                    marker_contains: req.marker_contains,
-                    case_sensitive: req.case_sensitive,
-                    include_cbuffers: req.include_cbuffers,
-                    include_outputs: req.include_outputs,
+                    baseline_dir: req.baseline_dir,
+                    tolerances: req.tolerances,
+                    report_dir: req.report_dir,
+                    replay_options: req.replay_options.clone(),
                 },
             )
             .map_err(|e| {
-                tracing::error!(tool = "renderdoc_export_bindings_index_jsonl", "failed");
-                tracing::debug!(tool = "renderdoc_export_bindings_index_jsonl", err = %e, "details");
-                format!("export bindings index failed: {e}")
+                tracing::error!(tool = "renderdoc_verify_against_baseline", "failed");
+                tracing::debug!(tool = "renderdoc_verify_against_baseline", err = %e, "details");
+                format!("verify against baseline failed: {e}")
             })?;
 
         tracing::info!(
-            tool = "renderdoc_export_bindings_index_jsonl",
+            tool = "renderdoc_verify_against_baseline",
             elapsed_ms = start.elapsed().as_millis(),
-            bindings_jsonl_path = %res.bindings_jsonl_path,
-            total_drawcalls = res.total_drawcalls,
+            passed = res.passed,
+            results = res.results.len(),
             "ok"
         );
-
         Ok(Json(res))
     }
 
     #[tool(
-        name = "renderdoc_export_bundle_jsonl",
-        description = "Export a capture (.rdc) into searchable artifacts: <basename>.actions.jsonl (+ summary) and <basename>.bindings.jsonl (+ bindings_summary)."
+        name = "renderdoc_diff_event_pipeline_state",
+        description = "Diff the pipeline state of two events (shaders, blend, depth, bindings, constants), returning only the fields that differ."
     )]
-    async fn export_bundle_jsonl(
+    async fn diff_event_pipeline_state(
         &self,
-        Parameters(req): Parameters<ExportBundleRequest>,
-    ) -> Result<Json<ExportBundleResponse>, String> {
+        Parameters(req): Parameters<DiffEventPipelineStateRequest>,
+    ) -> Result<Json<renderdog::DiffEventPipelineStateResponse>, String> {
         let start = Instant::now();
         tracing::info!(
-            tool = "renderdoc_export_bundle_jsonl",
+            tool = "renderdoc_diff_event_pipeline_state",
             capture_path = %req.capture_path,
-            only_drawcalls = req.only_drawcalls,
-            include_cbuffers = req.include_cbuffers,
-            include_outputs = req.include_outputs,
-            save_thumbnail = req.save_thumbnail,
-            open_capture_ui = req.open_capture_ui,
+            event_a = req.event_a,
+            event_b = req.event_b,
             "start"
         );
 
         let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
-            tracing::error!(tool = "renderdoc_export_bundle_jsonl", "failed");
-            tracing::debug!(tool = "renderdoc_export_bundle_jsonl", err = %e, "details");
+            tracing::error!(tool = "renderdoc_diff_event_pipeline_state", "failed");
+            tracing::debug!(tool = "renderdoc_diff_event_pipeline_state", err = %e, "details");
             format!("detect installation failed: {e}")
         })?;
 
         let cwd = resolve_base_cwd(req.cwd.clone())?;
 
-        let capture_path = resolve_path_from_base(&cwd, &req.capture_path);
-
-        let output_dir = req
-            .output_dir
-            .map(|p| resolve_path_from_base(&cwd, &p).display().to_string())
-            .unwrap_or_else(|| renderdog::default_exports_dir(&cwd).display().to_string());
-
-        std::fs::create_dir_all(&output_dir)
-            .map_err(|e| format!("create output_dir failed: {e}"))?;
-
-        let basename = req.basename.unwrap_or_else(|| {
-            capture_path
-                .file_stem()
-                .and_then(|s| s.to_str())
-                .unwrap_or("capture")
-                .to_string()
-        });
-
-        let mut thumbnail_output_path: Option<String> = None;
-        if req.save_thumbnail {
-            let thumb_path = req
-                .thumbnail_output_path
-                .map(|p| resolve_path_from_base(&cwd, &p).display().to_string())
-                .unwrap_or_else(|| {
-                    Path::new(&output_dir)
-                        .join(format!("{basename}.thumb.png"))
-                        .display()
-                        .to_string()
-                });
-            if let Some(parent) = Path::new(&thumb_path).parent() {
-                std::fs::create_dir_all(parent)
-                    .map_err(|e| format!("create thumbnail output dir failed: {e}"))?;
-            }
-            install
-                .save_thumbnail(&capture_path, Path::new(&thumb_path))
-                .map_err(|e| format!("save thumbnail failed: {e}"))?;
-            thumbnail_output_path = Some(thumb_path);
-        }
-
-        let bundle = install
-            .export_bundle_jsonl(
+        let res = install
+            .diff_event_pipeline_state(
                 &cwd,
-                &renderdog::ExportBundleRequest {
-                    capture_path: req.capture_path.clone(),
-                    output_dir,
-                    basename,
-                    only_drawcalls: req.only_drawcalls,
-                    marker_prefix: req.marker_prefix,
-                    event_id_min: req.event_id_min,
-                    event_id_max: req.event_id_max,
-                    name_contains: req.name_contains,
-                    marker_contains: req.marker_contains,
-                    case_sensitive: req.case_sensitive,
-                    include_cbuffers: req.include_cbuffers,
-                    include_outputs: req.include_outputs,
+                &renderdog::DiffEventPipelineStateRequest {
+                    capture_path: req.capture_path,
+                    event_a: req.event_a,
+                    event_b: req.event_b,
+                    replay_options: req.replay_options.clone(),
                 },
             )
             .map_err(|e| {
-                tracing::error!(tool = "renderdoc_export_bundle_jsonl", "failed");
-                tracing::debug!(tool = "renderdoc_export_bundle_jsonl", err = %e, "details");
-                format!("export bundle failed: {e}")
+                tracing::error!(tool = "renderdoc_diff_event_pipeline_state", "failed");
+                tracing::debug!(tool = "renderdoc_diff_event_pipeline_state", err = %e, "details");
+                format!("diff event pipeline state failed: {e}")
             })?;
 
-        let mut ui_pid: Option<u32> = None;
-        if req.open_capture_ui {
-            let child = install
-                .open_capture_in_ui(&capture_path)
-                .map_err(|e| format!("open capture UI failed: {e}"))?;
-            ui_pid = Some(child.id());
-        }
-
         tracing::info!(
-            tool = "renderdoc_export_bundle_jsonl",
+            tool = "renderdoc_diff_event_pipeline_state",
             elapsed_ms = start.elapsed().as_millis(),
-            actions_jsonl_path = %bundle.actions_jsonl_path,
-            bindings_jsonl_path = %bundle.bindings_jsonl_path,
-            total_actions = bundle.total_actions,
-            total_drawcalls = bundle.total_drawcalls,
+            changes = res.changes.len(),
             "ok"
         );
-
-        Ok(Json(ExportBundleResponse {
-            bundle,
-            thumbnail_output_path,
-            ui_pid,
-        }))
+        Ok(Json(res))
     }
 
     #[tool(
-        name = "renderdoc_find_events",
-        description = "Find matching action events (event_id + marker_path) in a .rdc capture via `qrenderdoc --python`. Useful for quickly locating event IDs for later replay tools."
+        name = "renderdoc_export_frame_graph",
+        description = "Export the frame's pass / resource dependency graph (passes as nodes, resources as writer-to-reader edges) as Graphviz DOT and JSON."
     )]
-    async fn find_events(
+    async fn export_frame_graph(
         &self,
-        Parameters(req): Parameters<FindEventsRequest>,
-    ) -> Result<Json<renderdog::FindEventsResponse>, String> {
+        Parameters(req): Parameters<ExportFrameGraphRequest>,
+    ) -> Result<Json<renderdog::ExportFrameGraphResponse>, String> {
         let start = Instant::now();
         tracing::info!(
-            tool = "renderdoc_find_events",
+            tool = "renderdoc_export_frame_graph",
             capture_path = %req.capture_path,
-            only_drawcalls = req.only_drawcalls,
             "start"
         );
 
         let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
-            tracing::error!(tool = "renderdoc_find_events", "failed");
-            tracing::debug!(tool = "renderdoc_find_events", err = %e, "details");
+            tracing::error!(tool = "renderdoc_export_frame_graph", "failed");
+            tracing::debug!(tool = "renderdoc_export_frame_graph", err = %e, "details");
             format!("detect installation failed: {e}")
         })?;
 
         let cwd = resolve_base_cwd(req.cwd.clone())?;
 
+        let output_dir = req
+            .output_dir
+            .map(|p| resolve_path_from_base(&cwd, &p).display().to_string())
+            .unwrap_or_else(|| {
+                renderdog::default_exports_dir(&cwd)
+                    .join("framegraph")
+                    .display()
+                    .to_string()
+            });
+
+        let basename = req.basename.unwrap_or_else(|| {
+            Path::new(&req.capture_path)
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or("capture")
+                .to_string()
+        });
+
         let res = install
-            .find_events(
+            .export_frame_graph(
                 &cwd,
-                &renderdog::FindEventsRequest {
+                &renderdog::ExportFrameGraphRequest {
                     capture_path: req.capture_path,
-                    only_drawcalls: req.only_drawcalls,
-                    marker_prefix: req.marker_prefix,
-                    event_id_min: req.event_id_min,
-                    event_id_max: req.event_id_max,
-                    name_contains: req.name_contains,
-                    marker_contains: req.marker_contains,
-                    case_sensitive: req.case_sensitive,
-                    max_results: req.max_results,
+                    output_dir,
+                    basename,
+                    replay_options: req.replay_options.clone(),
                 },
             )
             .map_err(|e| {
-                tracing::error!(tool = "renderdoc_find_events", "failed");
-                tracing::debug!(tool = "renderdoc_find_events", err = %e, "details");
-                format!("find events failed: {e}")
+                tracing::error!(tool = "renderdoc_export_frame_graph", "failed");
+                tracing::debug!(tool = "renderdoc_export_frame_graph", err = %e, "details");
+                format!("export frame graph failed: {e}")
             })?;
 
         tracing::info!(
-            tool = "renderdoc_find_events",
+            tool = "renderdoc_export_frame_graph",
             elapsed_ms = start.elapsed().as_millis(),
-            matches = res.matches.len(),
-            truncated = res.truncated,
+            passes = res.pass_count,
+            edges = res.edge_count,
             "ok"
         );
         Ok(Json(res))
     }
 
     #[tool(
-        name = "renderdoc_get_events",
-        description = "Get all events from a .rdc capture with their event IDs, marker scopes, and API call names. Returns a complete event map useful for understanding the capture structure."
+        name = "renderdoc_export_render_passes",
+        description = "Export render pass / framebuffer structure per pass (attachments, load/store ops, subpasses, resolve targets) as <basename>.render_passes.json. Passes are inferred from draw targets on APIs without explicit passes."
     )]
-    async fn get_events(
+    async fn export_render_passes(
         &self,
-        Parameters(req): Parameters<GetEventsRequest>,
-    ) -> Result<Json<renderdog::GetEventsResponse>, String> {
+        Parameters(req): Parameters<ExportRenderPassesRequest>,
+    ) -> Result<Json<renderdog::ExportRenderPassesResponse>, String> {
         let start = Instant::now();
         tracing::info!(
-            tool = "renderdoc_get_events",
+            tool = "renderdoc_export_render_passes",
             capture_path = %req.capture_path,
             "start"
         );
 
         let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
-            tracing::error!(tool = "renderdoc_get_events", "failed");
-            tracing::debug!(tool = "renderdoc_get_events", err = %e, "details");
+            tracing::error!(tool = "renderdoc_export_render_passes", "failed");
+            tracing::debug!(tool = "renderdoc_export_render_passes", err = %e, "details");
             format!("detect installation failed: {e}")
         })?;
 
         let cwd = resolve_base_cwd(req.cwd.clone())?;
 
+        let output_dir = req
+            .output_dir
+            .map(|p| resolve_path_from_base(&cwd, &p).display().to_string())
+            .unwrap_or_else(|| renderdog::default_exports_dir(&cwd).display().to_string());
+
+        let basename = req.basename.unwrap_or_else(|| {
+            Path::new(&req.capture_path)
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or("capture")
+                .to_string()
+        });
+
         let res = install
-            .get_events(
+            .export_render_passes(
                 &cwd,
-                &renderdog::GetEventsRequest {
+                &renderdog::ExportRenderPassesRequest {
                     capture_path: req.capture_path,
+                    output_dir,
+                    basename,
+                    replay_options: req.replay_options.clone(),
                 },
             )
             .map_err(|e| {
-                tracing::error!(tool = "renderdoc_get_events", "failed");
-                tracing::debug!(tool = "renderdoc_get_events", err = %e, "details");
-                format!("get events failed: {e}")
+                tracing::error!(tool = "renderdoc_export_render_passes", "failed");
+                tracing::debug!(tool = "renderdoc_export_render_passes", err = %e, "details");
+                format!("export render passes failed: {e}")
             })?;
 
         tracing::info!(
-            tool = "renderdoc_get_events",
+            tool = "renderdoc_export_render_passes",
             elapsed_ms = start.elapsed().as_millis(),
-            total_events = res.total_events,
+            passes = res.total_passes,
+            explicit_passes = res.explicit_passes,
             "ok"
         );
         Ok(Json(res))
     }
 
     #[tool(
-        name = "renderdoc_get_shader_details",
-        description = "Get detailed shader information (source files, resources, constant blocks, samplers, input signature) for a pipeline in a .rdc capture. Returns an array of shader info for all entry points, or filtered by the optional entry_points parameter."
+        name = "renderdoc_export_draw_stats",
+        description = "Export per-draw statistics (vertex/instance/primitive/triangle counts, dispatch dimensions, bound target resolution) as JSONL plus frame and per-marker-group totals as JSON."
     )]
-    async fn get_shader_details(
+    async fn export_draw_stats(
         &self,
-        Parameters(req): Parameters<GetShaderDetailsRequest>,
-    ) -> Result<Json<renderdog::GetShaderDetailsResponse>, String> {
+        Parameters(req): Parameters<ExportDrawStatsRequest>,
+    ) -> Result<Json<renderdog::ExportDrawStatsResponse>, String> {
         let start = Instant::now();
         tracing::info!(
-            tool = "renderdoc_get_shader_details",
+            tool = "renderdoc_export_draw_stats",
             capture_path = %req.capture_path,
-            pipeline_name = %req.pipeline_name,
-            entry_points = ?req.entry_points,
             "start"
         );
 
         let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
-            tracing::error!(tool = "renderdoc_get_shader_details", "failed");
-            tracing::debug!(tool = "renderdoc_get_shader_details", err = %e, "details");
+            tracing::error!(tool = "renderdoc_export_draw_stats", "failed");
+            tracing::debug!(tool = "renderdoc_export_draw_stats", err = %e, "details");
             format!("detect installation failed: {e}")
         })?;
 
         let cwd = resolve_base_cwd(req.cwd.clone())?;
 
+        let output_dir = req
+            .output_dir
+            .map(|p| resolve_path_from_base(&cwd, &p).display().to_string())
+            .unwrap_or_else(|| renderdog::default_exports_dir(&cwd).display().to_string());
+
+        let basename = req.basename.unwrap_or_else(|| {
+            Path::new(&req.capture_path)
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or("capture")
+                .to_string()
+        });
+
         let res = install
-            .get_shader_details(
+            .export_draw_stats(
                 &cwd,
-                &renderdog::GetShaderDetailsRequest {
+                &renderdog::ExportDrawStatsRequest {
                     capture_path: req.capture_path,
-                    pipeline_name: req.pipeline_name,
-                    entry_points: req.entry_points,
+                    output_dir,
+                    basename,
+                    replay_options: req.replay_options.clone(),
                 },
             )
             .map_err(|e| {
-                tracing::error!(tool = "renderdoc_get_shader_details", "failed");
-                tracing::debug!(tool = "renderdoc_get_shader_details", err = %e, "details");
-                format!("get shader details failed: {e}")
+                tracing::error!(tool = "renderdoc_export_draw_stats", "failed");
+                tracing::debug!(tool = "renderdoc_export_draw_stats", err = %e, "details");
+                format!("export draw stats failed: {e}")
             })?;
 
         tracing::info!(
-            tool = "renderdoc_get_shader_details",
+            tool = "renderdoc_export_draw_stats",
             elapsed_ms = start.elapsed().as_millis(),
-            shaders_count = res.shaders.len(),
+            draws = res.total_draws,
+            dispatches = res.total_dispatches,
+            triangles = res.total_triangles,
             "ok"
         );
         Ok(Json(res))
     }
 
     #[tool(
-        name = "renderdoc_get_buffer_details",
-        description = "Get metadata for a GPU buffer: infers struct schema from shader reflection, stride per element, and all pipeline/binding usages across the frame. Use this before get_buffer_changes_delta to understand the buffer structure."
+        name = "renderdoc_export_api_call_stats",
+        description = "Count the API calls in a capture by category (draws, dispatches, copies, clears, barriers, binds, markers, other), for the whole frame and per pass, plus a count per API function. Writes compact JSON suited to dashboards tracking frame complexity over time; returns the frame totals."
     )]
-    async fn get_buffer_details(
+    async fn export_api_call_stats(
         &self,
-        Parameters(req): Parameters<GetBufferDetailsRequest>,
-    ) -> Result<Json<renderdog::GetBufferDetailsResponse>, String> {
+        Parameters(req): Parameters<ExportApiCallStatsRequest>,
+    ) -> Result<Json<renderdog::ExportApiCallStatsResponse>, String> {
         let start = Instant::now();
         tracing::info!(
-            tool = "renderdoc_get_buffer_details",
+            tool = "renderdoc_export_api_call_stats",
             capture_path = %req.capture_path,
-            buffer_name = %req.buffer_name,
             "start"
         );
 
         let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
-            tracing::error!(tool = "renderdoc_get_buffer_details", "failed");
-            tracing::debug!(tool = "renderdoc_get_buffer_details", err = %e, "details");
+            tracing::error!(tool = "renderdoc_export_api_call_stats", "failed");
+            tracing::debug!(tool = "renderdoc_export_api_call_stats", err = %e, "details");
             format!("detect installation failed: {e}")
         })?;
 
         let cwd = resolve_base_cwd(req.cwd.clone())?;
 
+        let output_dir = req
+            .output_dir
+            .map(|p| resolve_path_from_base(&cwd, &p).display().to_string())
+            .unwrap_or_else(|| renderdog::default_exports_dir(&cwd).display().to_string());
+
+        let basename = req.basename.unwrap_or_else(|| {
+            Path::new(&req.capture_path)
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or("capture")
+                .to_string()
+        });
+
         let res = install
-            .get_buffer_details(
+            .export_api_call_stats(
                 &cwd,
-                &renderdog::GetBufferDetailsRequest {
+                &renderdog::ExportApiCallStatsRequest {
                     capture_path: req.capture_path,
-                    buffer_name: req.buffer_name,
+                    output_dir,
+                    basename,
+                    replay_options: req.replay_options.clone(),
                 },
             )
             .map_err(|e| {
-                tracing::error!(tool = "renderdoc_get_buffer_details", "failed");
-                tracing::debug!(tool = "renderdoc_get_buffer_details", err = %e, "details");
-                format!("get buffer details failed: {e}")
+                tracing::error!(tool = "renderdoc_export_api_call_stats", "failed");
+                tracing::debug!(tool = "renderdoc_export_api_call_stats", err = %e, "details");
+                format!("export api call stats failed: {e}")
             })?;
 
         tracing::info!(
-            tool = "renderdoc_get_buffer_details",
+            tool = "renderdoc_export_api_call_stats",
             elapsed_ms = start.elapsed().as_millis(),
-            stride = res.stride,
-            usages = res.usages.len(),
+            total_calls = res.total_calls,
+            draws = res.totals.draws,
+            barriers = res.totals.barriers,
             "ok"
         );
         Ok(Json(res))
     }
 
     #[tool(
-        name = "renderdoc_get_texture_details",
-        description = "Get metadata for a GPU texture: format, dimensions, mip levels, array size, sample count, and all pipeline/binding usages across the frame including render target bindings."
+        name = "renderdoc_lint_redundant_state",
+        description = "Lint a capture for redundant state changes: pipeline binds, descriptor binds and constants that repeat the previous call for the same slot, counted per pass with example events."
     )]
-    async fn get_texture_details(
+    async fn lint_redundant_state(
         &self,
-        Parameters(req): Parameters<GetTextureDetailsRequest>,
-    ) -> Result<Json<renderdog::GetTextureDetailsResponse>, String> {
+        Parameters(req): Parameters<LintRedundantStateRequest>,
+    ) -> Result<Json<renderdog::LintRedundantStateResponse>, String> {
         let start = Instant::now();
         tracing::info!(
-            tool = "renderdoc_get_texture_details",
+            tool = "renderdoc_lint_redundant_state",
             capture_path = %req.capture_path,
-            texture_name = %req.texture_name,
             "start"
         );
 
         let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
-            tracing::error!(tool = "renderdoc_get_texture_details", "failed");
-            tracing::debug!(tool = "renderdoc_get_texture_details", err = %e, "details");
+            tracing::error!(tool = "renderdoc_lint_redundant_state", "failed");
+            tracing::debug!(tool = "renderdoc_lint_redundant_state", err = %e, "details");
             format!("detect installation failed: {e}")
         })?;
 
         let cwd = resolve_base_cwd(req.cwd.clone())?;
 
         let res = install
-            .get_texture_details(
+            .lint_redundant_state(
                 &cwd,
-                &renderdog::GetTextureDetailsRequest {
+                &renderdog::LintRedundantStateRequest {
                     capture_path: req.capture_path,
-                    texture_name: req.texture_name,
+                    max_examples: req.max_examples,
+                    replay_options: req.replay_options.clone(),
                 },
             )
             .map_err(|e| {
-                tracing::error!(tool = "renderdoc_get_texture_details", "failed");
-                tracing::debug!(tool = "renderdoc_get_texture_details", err = %e, "details");
-                format!("get texture details failed: {e}")
+                tracing::error!(tool = "renderdoc_lint_redundant_state", "failed");
+                tracing::debug!(tool = "renderdoc_lint_redundant_state", err = %e, "details");
+                format!("lint redundant state failed: {e}")
             })?;
 
         tracing::info!(
-            tool = "renderdoc_get_texture_details",
+            tool = "renderdoc_lint_redundant_state",
             elapsed_ms = start.elapsed().as_millis(),
-            format = %res.format,
-            width = res.width,
-            height = res.height,
-            usages = res.usages.len(),
+            state_calls = res.total_state_calls,
+            redundant = res.total_redundant,
             "ok"
         );
         Ok(Json(res))
     }
 
     #[tool(
-        name = "renderdoc_get_buffer_changes_delta",
-        description = "Track GPU buffer element changes across a frame. Reads data at specified element indices at every action and returns delta-encoded changes: initial_state for each element plus only the deltas where values actually changed."
+        name = "renderdoc_lint_unbalanced_markers",
+        description = "Lint a capture for unbalanced debug markers: end calls with no open marker, markers never closed, markers begun in one command buffer/list and ended in another, and markers left open across a submission. Reports the offending begin/end event IDs; these are a common cause of confusing marker trees."
     )]
-    async fn get_buffer_changes_delta(
+    async fn lint_unbalanced_markers(
         &self,
-        Parameters(req): Parameters<GetBufferChangesDeltaRequest>,
-    ) -> Result<Json<renderdog::GetBufferChangesDeltaResponse>, String> {
+        Parameters(req): Parameters<LintUnbalancedMarkersRequest>,
+    ) -> Result<Json<renderdog::LintUnbalancedMarkersResponse>, String> {
         let start = Instant::now();
         tracing::info!(
-            tool = "renderdoc_get_buffer_changes_delta",
+            tool = "renderdoc_lint_unbalanced_markers",
             capture_path = %req.capture_path,
-            buffer_name = %req.buffer_name,
-            tracked_indices = ?req.tracked_indices,
             "start"
         );
 
         let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
-            tracing::error!(tool = "renderdoc_get_buffer_changes_delta", "failed");
-            tracing::debug!(tool = "renderdoc_get_buffer_changes_delta", err = %e, "details");
+            tracing::error!(tool = "renderdoc_lint_unbalanced_markers", "failed");
+            tracing::debug!(tool = "renderdoc_lint_unbalanced_markers", err = %e, "details");
             format!("detect installation failed: {e}")
         })?;
 
         let cwd = resolve_base_cwd(req.cwd.clone())?;
 
         let res = install
-            .get_buffer_changes_delta(
+            .lint_unbalanced_markers(
                 &cwd,
-                &renderdog::GetBufferChangesDeltaRequest {
+                &renderdog::LintUnbalancedMarkersRequest {
                     capture_path: req.capture_path,
-                    buffer_name: req.buffer_name,
-                    tracked_indices: req.tracked_indices,
+                    max_issues: req.max_issues,
+                    replay_options: req.replay_options.clone(),
                 },
             )
             .map_err(|e| {
-                tracing::error!(tool = "renderdoc_get_buffer_changes_delta", "failed");
-                tracing::debug!(tool = "renderdoc_get_buffer_changes_delta", err = %e, "details");
-                format!("get buffer changes delta failed: {e}")
+                tracing::error!(tool = "renderdoc_lint_unbalanced_markers", "failed");
+                tracing::debug!(tool = "renderdoc_lint_unbalanced_markers", err = %e, "details");
+                format!("lint unbalanced markers failed: {e}")
             })?;
 
         tracing::info!(
-            tool = "renderdoc_get_buffer_changes_delta",
+            tool = "renderdoc_lint_unbalanced_markers",
             elapsed_ms = start.elapsed().as_millis(),
-            total_changes = res.total_changes,
-            elements = res.elements.len(),
+            total_issues = res.total_issues,
             "ok"
         );
         Ok(Json(res))
     }
 
     #[tool(
-        name = "renderdoc_get_texture_changes_delta",
-        description = "Track GPU texture texel changes across a frame. Reads texel values at specified coordinates (x, y, z, mip, slice) at every action and returns delta-encoded changes: initial_state for each texel plus only the channel deltas where values actually changed."
+        name = "renderdoc_find_unused_bindings",
+        description = "Compare bound descriptors at every draw / dispatch against shader reflection and report bindings no stage references (not declared, or declared but statically unused), grouped by pipeline."
     )]
-    async fn get_texture_changes_delta(
+    async fn find_unused_bindings(
         &self,
-        Parameters(req): Parameters<GetTextureChangesDeltaRequest>,
-    ) -> Result<Json<renderdog::GetTextureChangesDeltaResponse>, String> {
+        Parameters(req): Parameters<FindUnusedBindingsRequest>,
+    ) -> Result<Json<renderdog::FindUnusedBindingsResponse>, String> {
         let start = Instant::now();
         tracing::info!(
-            tool = "renderdoc_get_texture_changes_delta",
+            tool = "renderdoc_find_unused_bindings",
             capture_path = %req.capture_path,
-            texture_name = %req.texture_name,
-            tracked_texels = req.tracked_texels.len(),
             "start"
         );
 
         let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
-            tracing::error!(tool = "renderdoc_get_texture_changes_delta", "failed");
-            tracing::debug!(tool = "renderdoc_get_texture_changes_delta", err = %e, "details");
+            tracing::error!(tool = "renderdoc_find_unused_bindings", "failed");
+            tracing::debug!(tool = "renderdoc_find_unused_bindings", err = %e, "details");
             format!("detect installation failed: {e}")
         })?;
 
         let cwd = resolve_base_cwd(req.cwd.clone())?;
 
         let res = install
-            .get_texture_changes_delta(
+            .find_unused_bindings(
                 &cwd,
-                &renderdog::GetTextureChangesDeltaRequest {
+                &renderdog::FindUnusedBindingsRequest {
                     capture_path: req.capture_path,
-                    texture_name: req.texture_name,
-                    tracked_texels: req.tracked_texels.iter().map(|t| {
-                        renderdog::TexelCoord {
-                            x: t.x,
-                            y: t.y,
-                            z: t.z,
-                            mip: t.mip,
-                            slice: t.slice,
-                        }
-                    }).collect(),
+                    replay_options: req.replay_options.clone(),
                 },
             )
             .map_err(|e| {
-                tracing::error!(tool = "renderdoc_get_texture_changes_delta", "failed");
-                tracing::debug!(tool = "renderdoc_get_texture_changes_delta", err = %e, "details");
-                format!("get texture changes delta failed: {e}")
+                tracing::error!(tool = "renderdoc_find_unused_bindings", "failed");
+                tracing::debug!(tool = "renderdoc_find_unused_bindings", err = %e, "details");
+                format!("find unused bindings failed: {e}")
             })?;
 
         tracing::info!(
-            tool = "renderdoc_get_texture_changes_delta",
+            tool = "renderdoc_find_unused_bindings",
             elapsed_ms = start.elapsed().as_millis(),
-            total_changes = res.total_changes,
-            texels = res.texels.len(),
+            pipelines = res.pipelines_checked,
+            unused = res.total_unused,
             "ok"
         );
         Ok(Json(res))
     }
 
     #[tool(
-        name = "renderdoc_get_pipeline_details",
-        description = "Get detailed metadata about a GPU pipeline: type (Graphics/Compute), shader stages with entry points, resource bindings, constant blocks, samplers, vertex inputs (for graphics), render targets, depth/stencil/blend state, and all event IDs where this pipeline is active. Note: render targets and depth/stencil/blend state are captured from the first event where the pipeline is active."
+        name = "renderdoc_diagnose_draw",
+        description = "Diagnose why a draw is invisible: checks vertex/instance counts, bound targets, viewport/scissor, clip volume, cull mode vs winding, depth test and blend/write masks, and returns likely causes ranked by severity with evidence."
     )]
-    async fn get_pipeline_details(
+    async fn diagnose_draw(
         &self,
-        Parameters(req): Parameters<GetPipelineDetailsRequest>,
-    ) -> Result<Json<renderdog::GetPipelineDetailsResponse>, String> {
+        Parameters(req): Parameters<DiagnoseDrawRequest>,
+    ) -> Result<Json<renderdog::DiagnoseDrawResponse>, String> {
         let start = Instant::now();
         tracing::info!(
-            tool = "renderdoc_get_pipeline_details",
+            tool = "renderdoc_diagnose_draw",
             capture_path = %req.capture_path,
-            pipeline_name = %req.pipeline_name,
+            event_id = req.event_id,
             "start"
         );
 
         let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
-            tracing::error!(tool = "renderdoc_get_pipeline_details", "failed");
-            tracing::debug!(tool = "renderdoc_get_pipeline_details", err = %e, "details");
+            tracing::error!(tool = "renderdoc_diagnose_draw", "failed");
+            tracing::debug!(tool = "renderdoc_diagnose_draw", err = %e, "details");
             format!("detect installation failed: {e}")
         })?;
 
         let cwd = resolve_base_cwd(req.cwd.clone())?;
 
         let res = install
-            .get_pipeline_details(
+            .diagnose_draw(
                 &cwd,
-                &renderdog::GetPipelineDetailsRequest {
+                &renderdog::DiagnoseDrawRequest {
                     capture_path: req.capture_path,
-                    pipeline_name: req.pipeline_name,
+                    event_id: req.event_id,
+                    replay_options: req.replay_options.clone(),
                 },
             )
             .map_err(|e| {
-                tracing::error!(tool = "renderdoc_get_pipeline_details", "failed");
-                tracing::debug!(tool = "renderdoc_get_pipeline_details", err = %e, "details");
-                format!("get pipeline details failed: {e}")
+                tracing::error!(tool = "renderdoc_diagnose_draw", "failed");
+                tracing::debug!(tool = "renderdoc_diagnose_draw", err = %e, "details");
+                format!("diagnose draw failed: {e}")
             })?;
 
         tracing::info!(
-            tool = "renderdoc_get_pipeline_details",
+            tool = "renderdoc_diagnose_draw",
             elapsed_ms = start.elapsed().as_millis(),
-            pipeline_type = %res.pipeline_type,
-            stages = res.stages.len(),
-            event_ids = res.event_ids.len(),
+            causes = res.causes.len(),
             "ok"
         );
         Ok(Json(res))
     }
 
     #[tool(
-        name = "renderdoc_get_pipeline_binding_changes_delta",
-        description = "Track GPU pipeline binding changes across a frame. For a given pipeline, monitors which resources are bound at each binding point (textures, buffers, samplers, render targets) across all events where the pipeline is active. Returns delta-encoded changes showing when bindings change."
+        name = "renderdoc_get_constants",
+        description = "Decode every bound constant / uniform block (push constants included) at an event into typed JSON using shader reflection, with nested structs and arrays. stage limits the output to one shader stage."
     )]
-    async fn get_pipeline_binding_changes_delta(
+    async fn get_constants(
         &self,
-        Parameters(req): Parameters<GetPipelineBindingChangesDeltaRequest>,
-    ) -> Result<Json<renderdog::GetPipelineBindingChangesDeltaResponse>, String> {
+        Parameters(req): Parameters<GetConstantsRequest>,
+    ) -> Result<Json<renderdog::GetConstantsResponse>, String> {
         let start = Instant::now();
         tracing::info!(
-            tool = "renderdoc_get_pipeline_binding_changes_delta",
+            tool = "renderdoc_get_constants",
             capture_path = %req.capture_path,
-            pipeline_name = %req.pipeline_name,
+            event_id = req.event_id,
             "start"
         );
 
         let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
-            tracing::error!(tool = "renderdoc_get_pipeline_binding_changes_delta", "failed");
-            tracing::debug!(tool = "renderdoc_get_pipeline_binding_changes_delta", err = %e, "details");
+            tracing::error!(tool = "renderdoc_get_constants", "failed");
+            tracing::debug!(tool = "renderdoc_get_constants", err = %e, "details");
             format!("detect installation failed: {e}")
         })?;
 
         let cwd = resolve_base_cwd(req.cwd.clone())?;
 
         let res = install
-            .get_pipeline_binding_changes_delta(
+            .get_constants(
                 &cwd,
-                &renderdog::GetPipelineBindingChangesDeltaRequest {
+                &renderdog::GetConstantsRequest {
                     capture_path: req.capture_path,
-                    pipeline_name: req.pipeline_name,
+                    event_id: req.event_id,
+                    stage: req.stage,
+                    replay_options: req.replay_options.clone(),
                 },
             )
             .map_err(|e| {
-                tracing::error!(tool = "renderdoc_get_pipeline_binding_changes_delta", "failed");
-                tracing::debug!(tool = "renderdoc_get_pipeline_binding_changes_delta", err = %e, "details");
-                format!("get pipeline binding changes delta failed: {e}")
+                tracing::error!(tool = "renderdoc_get_constants", "failed");
+                tracing::debug!(tool = "renderdoc_get_constants", err = %e, "details");
+                format!("get constants failed: {e}")
             })?;
 
         tracing::info!(
-            tool = "renderdoc_get_pipeline_binding_changes_delta",
+            tool = "renderdoc_get_constants",
             elapsed_ms = start.elapsed().as_millis(),
-            pipeline_type = %res.pipeline_type,
-            total_changes = res.total_changes,
-            bindings = res.bindings.len(),
+            blocks = res.blocks.len(),
             "ok"
         );
         Ok(Json(res))
     }
 
     #[tool(
-        name = "renderdoc_get_event_pipeline_state",
-        description = "Get complete pipeline state at a specific event ID: active shader stages, all resource bindings (buffers, textures), uniform/constant buffer contents, samplers, and for graphics pipelines: vertex/index buffers, render targets, depth/stencil/blend state."
+        name = "renderdoc_get_descriptor_arrays",
+        description = "Enumerate the elements of descriptor arrays bound at an event, including unbounded / bindless arrays and descriptor-buffer-backed tables: each index is resolved to its resource, and elements the event actually accessed are flagged when RenderDoc reports descriptor access."
     )]
-    async fn get_event_pipeline_state(
+    async fn get_descriptor_arrays(
         &self,
-        Parameters(req): Parameters<GetEventPipelineStateRequest>,
-    ) -> Result<Json<renderdog::GetEventPipelineStateResponse>, String> {
+        Parameters(req): Parameters<GetDescriptorArraysRequest>,
+    ) -> Result<Json<renderdog::GetDescriptorArraysResponse>, String> {
         let start = Instant::now();
         tracing::info!(
-            tool = "renderdoc_get_event_pipeline_state",
+            tool = "renderdoc_get_descriptor_arrays",
             capture_path = %req.capture_path,
             event_id = req.event_id,
             "start"
         );
 
         let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
-            tracing::error!(tool = "renderdoc_get_event_pipeline_state", "failed");
-            tracing::debug!(tool = "renderdoc_get_event_pipeline_state", err = %e, "details");
+            tracing::error!(tool = "renderdoc_get_descriptor_arrays", "failed");
+            tracing::debug!(tool = "renderdoc_get_descriptor_arrays", err = %e, "details");
             format!("detect installation failed: {e}")
         })?;
 
         let cwd = resolve_base_cwd(req.cwd.clone())?;
 
         let res = install
-            .get_event_pipeline_state(
+            .get_descriptor_arrays(
                 &cwd,
-                &renderdog::GetEventPipelineStateRequest {
+                &renderdog::GetDescriptorArraysRequest {
                     capture_path: req.capture_path,
                     event_id: req.event_id,
+                    name_contains: req.name_contains,
+                    max_elements: req.max_elements,
+                    only_indexed: req.only_indexed,
+                    replay_options: req.replay_options.clone(),
                 },
             )
             .map_err(|e| {
-                tracing::error!(tool = "renderdoc_get_event_pipeline_state", "failed");
-                tracing::debug!(tool = "renderdoc_get_event_pipeline_state", err = %e, "details");
-                format!("get event pipeline state failed: {e}")
+                tracing::error!(tool = "renderdoc_get_descriptor_arrays", "failed");
+                tracing::debug!(tool = "renderdoc_get_descriptor_arrays", err = %e, "details");
+                format!("get descriptor arrays failed: {e}")
             })?;
 
         tracing::info!(
-            tool = "renderdoc_get_event_pipeline_state",
+            tool = "renderdoc_get_descriptor_arrays",
             elapsed_ms = start.elapsed().as_millis(),
-            pipeline = %res.pipeline,
-            stages = res.stages.len(),
-            resources = res.resources.len(),
+            arrays = res.arrays.len(),
             "ok"
         );
         Ok(Json(res))
     }
 
     #[tool(
-        name = "renderdoc_get_resource_changed_event_ids",
-        description = "Find all events that modify a resource (texture or buffer). Scans all actions and detects writes from render targets, depth/stencil outputs, clears, copies, and RW shader bindings."
+        name = "renderdoc_replay_list_buffers",
+        description = "List buffers in a .rdc capture via `qrenderdoc --python` replay (headless): resource IDs, names, lengths and creation flags."
     )]
-    async fn get_resource_changed_event_ids(
+    async fn replay_list_buffers(
         &self,
-        Parameters(req): Parameters<GetResourceChangedEventIdsRequest>,
-    ) -> Result<Json<renderdog::GetResourceChangedEventIdsResponse>, String> {
+        Parameters(req): Parameters<ReplayListBuffersRequest>,
+    ) -> Result<Json<renderdog::ReplayListBuffersResponse>, String> {
         let start = Instant::now();
         tracing::info!(
-            tool = "renderdoc_get_resource_changed_event_ids",
+            tool = "renderdoc_replay_list_buffers",
             capture_path = %req.capture_path,
-            resource_name = %req.resource_name,
+            event_id = req.event_id,
             "start"
         );
 
         let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
-            tracing::error!(tool = "renderdoc_get_resource_changed_event_ids", "failed");
-            tracing::debug!(tool = "renderdoc_get_resource_changed_event_ids", err = %e, "details");
+            tracing::error!(tool = "renderdoc_replay_list_buffers", "failed");
+            tracing::debug!(tool = "renderdoc_replay_list_buffers", err = %e, "details");
             format!("detect installation failed: {e}")
         })?;
 
         let cwd = resolve_base_cwd(req.cwd.clone())?;
 
         let res = install
-            .get_resource_changed_event_ids(
+            .replay_list_buffers(
                 &cwd,
-                &renderdog::GetResourceChangedEventIdsRequest {
+                &renderdog::ReplayListBuffersRequest {
                     capture_path: req.capture_path,
-                    resource_name: req.resource_name,
+                    event_id: req.event_id,
+                    replay_options: req.replay_options.clone(),
                 },
             )
             .map_err(|e| {
-                tracing::error!(tool = "renderdoc_get_resource_changed_event_ids", "failed");
-                tracing::debug!(tool = "renderdoc_get_resource_changed_event_ids", err = %e, "details");
-                format!("get resource changed event ids failed: {e}")
+                tracing::error!(tool = "renderdoc_replay_list_buffers", "failed");
+                tracing::debug!(tool = "renderdoc_replay_list_buffers", err = %e, "details");
+                format!("replay list buffers failed: {e}")
             })?;
 
         tracing::info!(
-            tool = "renderdoc_get_resource_changed_event_ids",
+            tool = "renderdoc_replay_list_buffers",
             elapsed_ms = start.elapsed().as_millis(),
-            resource_name = %res.resource_name,
-            write_count = res.write_count,
+            buffers = res.buffers.len(),
             "ok"
         );
         Ok(Json(res))
     }
 
     #[tool(
-        name = "renderdoc_search_resources",
-        description = "Search for resources in a .rdc capture. Returns matching resource IDs, names, and types.\n\nFilter options:\n- query: Optional regex pattern to match names. If not provided, returns all resources.\n- resource_types: Optional list to filter by type (e.g., [\"PipelineState\"] returns all pipelines)\n\nRegex examples:\n- \"particle\" - contains 'particle'\n- \"^Texture\" - starts with 'Texture'\n- \"shadow|light\" - contains 'shadow' or 'light'\n\nValid resource_types: Unknown, Device, Queue, CommandBuffer, Texture, Buffer, View, Sampler, SwapchainImage, Memory, Shader, ShaderBinding, PipelineState, StateObject, RenderPass, Query, Sync, Pool, AccelerationStructure, DescriptorStore"
+        name = "renderdoc_replay_save_all_textures",
+        description = "Save every texture of a .rdc capture at an event to PNG, JPG, EXR, HDR or DDS in a single headless replay, optionally filtered by name, format, minimum size or usage (ShaderRead, ColorTarget, DepthTarget, ShaderReadWrite, SwapBuffer)."
     )]
-    async fn search_resources(
+    async fn replay_save_all_textures(
         &self,
-        Parameters(req): Parameters<SearchResourcesRequest>,
-    ) -> Result<Json<renderdog::SearchResourcesResponse>, String> {
+        Parameters(req): Parameters<ReplaySaveAllTexturesRequest>,
+    ) -> Result<Json<renderdog::ReplaySaveAllTexturesResponse>, String> {
         let start = Instant::now();
         tracing::info!(
-            tool = "renderdoc_search_resources",
+            tool = "renderdoc_replay_save_all_textures",
             capture_path = %req.capture_path,
-            query = ?req.query,
-            case_sensitive = req.case_sensitive,
+            event_id = req.event_id,
             "start"
         );
 
         let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
-            tracing::error!(tool = "renderdoc_search_resources", "failed");
-            tracing::debug!(tool = "renderdoc_search_resources", err = %e, "details");
+            tracing::error!(tool = "renderdoc_replay_save_all_textures", "failed");
+            tracing::debug!(tool = "renderdoc_replay_save_all_textures", err = %e, "details");
             format!("detect installation failed: {e}")
         })?;
 
         let cwd = resolve_base_cwd(req.cwd.clone())?;
 
+        let output_dir = req
+            .output_dir
+            .map(|p| resolve_path_from_base(&cwd, &p).display().to_string())
+            .unwrap_or_else(|| {
+                renderdog::default_exports_dir(&cwd)
+                    .join("textures")
+                    .display()
+                    .to_string()
+            });
+
+        let basename = req.basename.unwrap_or_else(|| {
+            Path::new(&req.capture_path)
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or("capture")
+                .to_string()
+        });
+
         let res = install
-            .search_resources(
+            .replay_save_all_textures(
                 &cwd,
-                &renderdog::SearchResourcesRequest {
+                &renderdog::ReplaySaveAllTexturesRequest {
                     capture_path: req.capture_path,
-                    query: req.query,
-                    case_sensitive: req.case_sensitive,
-                    max_results: req.max_results,
-                    resource_types: req.resource_types,
+                    event_id: req.event_id,
+                    output_dir,
+                    basename,
+                    format: req.format.unwrap_or_else(|| "png".to_string()),
+                    name_contains: req.name_contains,
+                    format_contains: req.format_contains,
+                    min_width: req.min_width,
+                    min_height: req.min_height,
+                    categories: req.categories,
+                    replay_options: req.replay_options.clone(),
                 },
             )
             .map_err(|e| {
-                tracing::error!(tool = "renderdoc_search_resources", "failed");
-                tracing::debug!(tool = "renderdoc_search_resources", err = %e, "details");
-                format!("search resources failed: {e}")
+                tracing::error!(tool = "renderdoc_replay_save_all_textures", "failed");
+                tracing::debug!(tool = "renderdoc_replay_save_all_textures", err = %e, "details");
+                format!("replay save all textures failed: {e}")
             })?;
 
         tracing::info!(
-            tool = "renderdoc_search_resources",
+            tool = "renderdoc_replay_save_all_textures",
             elapsed_ms = start.elapsed().as_millis(),
-            total_matches = res.total_matches,
-            truncated = res.truncated,
+            saved = res.textures.len(),
+            skipped = res.skipped.len(),
             "ok"
         );
         Ok(Json(res))
     }
 
     #[tool(
-        name = "renderdoc_find_resource_uses",
-        description = "Find all uses of a resource in a .rdc capture. Returns event IDs, usage types, and detects actual data changes.\n\nUsage types: VertexBuffer, IndexBuffer, VS/PS/CS_Constants (uniform buffers), VS/PS/CS_Resource (textures/samplers), VS/PS/CS_RWResource (storage buffers/images), ColorTarget, DepthStencilTarget, InputTarget, Indirect, Clear, Copy, CopySrc, CopyDst, etc.\n\nThe has_delta field indicates if data actually changed (via binary comparison). When true, delta shows what changed: for buffers with shader reflection {element, fields}, otherwise {offset, length, old_hex, new_hex}. Use delta_filter to return only events with/without changes."
+        name = "renderdoc_save_texture_raw",
+        description = "Write a texture from a .rdc capture as DDS (block-compressed formats preserved, every mip / slice by default) or as raw texel bytes of one subresource plus a JSON descriptor (format, dimensions, row pitch) for external tooling."
     )]
-    async fn find_resource_uses(
+    async fn save_texture_raw(
         &self,
-        Parameters(req): Parameters<FindResourceUsesRequest>,
-    ) -> Result<Json<renderdog::FindResourceUsesResponse>, String> {
+        Parameters(req): Parameters<SaveTextureRawRequest>,
+    ) -> Result<Json<renderdog::SaveTextureRawResponse>, String> {
         let start = Instant::now();
         tracing::info!(
-            tool = "renderdoc_find_resource_uses",
+            tool = "renderdoc_save_texture_raw",
             capture_path = %req.capture_path,
-            resource = %req.resource,
+            event_id = req.event_id,
+            texture = %req.texture,
             "start"
         );
 
         let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
-            tracing::error!(tool = "renderdoc_find_resource_uses", "failed");
-            tracing::debug!(tool = "renderdoc_find_resource_uses", err = %e, "details");
+            tracing::error!(tool = "renderdoc_save_texture_raw", "failed");
+            tracing::debug!(tool = "renderdoc_save_texture_raw", err = %e, "details");
             format!("detect installation failed: {e}")
         })?;
 
         let cwd = resolve_base_cwd(req.cwd.clone())?;
 
         let res = install
-            .find_resource_uses(
+            .save_texture_raw(
                 &cwd,
-                &renderdog::FindResourceUsesRequest {
+                &renderdog::SaveTextureRawRequest {
                     capture_path: req.capture_path,
-                    resource: req.resource,
-                    max_results: req.max_results,
-                    data_sample_bytes: req.data_sample_bytes,
-                    delta_filter: req.delta_filter,
+                    event_id: req.event_id,
+                    texture: req.texture,
+                    output_path: req.output_path,
+                    format: req.format.unwrap_or_else(|| "dds".to_string()),
+                    mip: req.mip,
+                    slice: req.slice,
+                    sample: req.sample,
+                    replay_options: req.replay_options.clone(),
                 },
             )
             .map_err(|e| {
-                tracing::error!(tool = "renderdoc_find_resource_uses", "failed");
-                tracing::debug!(tool = "renderdoc_find_resource_uses", err = %e, "details");
-                format!("find resource uses failed: {e}")
+                tracing::error!(tool = "renderdoc_save_texture_raw", "failed");
+                tracing::debug!(tool = "renderdoc_save_texture_raw", err = %e, "details");
+                format!("save texture raw failed: {e}")
             })?;
 
         tracing::info!(
-            tool = "renderdoc_find_resource_uses",
+            tool = "renderdoc_save_texture_raw",
             elapsed_ms = start.elapsed().as_millis(),
-            total_uses = res.total_uses,
+            output_path = %res.output_path,
+            bytes = res.byte_size,
             "ok"
         );
         Ok(Json(res))
     }
 
     #[tool(
-        name = "renderdoc_find_events_and_save_outputs_png",
-        description = "One-shot helper: find matching events (by marker/name filters) and save current pipeline outputs to PNG at the selected event via headless replay."
+        name = "renderdoc_save_depth",
+        description = "Export a depth buffer from a .rdc capture as a 16-bit PNG, 32-bit float TIFF or false-colour PNG (turbo / viridis / inferno), optionally linearized with near / far planes. PNG output is normalized to the range of non-cleared texels, so it is not uniformly white."
     )]
-    async fn find_events_and_save_outputs_png(
+    async fn save_depth(
         &self,
-        Parameters(req): Parameters<FindEventsAndSaveOutputsPngRequest>,
-    ) -> Result<Json<FindEventsAndSaveOutputsPngResponse>, String> {
+        Parameters(req): Parameters<SaveDepthRequest>,
+    ) -> Result<Json<renderdog::SaveDepthResponse>, String> {
         let start = Instant::now();
         tracing::info!(
-            tool = "renderdoc_find_events_and_save_outputs_png",
+            tool = "renderdoc_save_depth",
             capture_path = %req.capture_path,
-            only_drawcalls = req.only_drawcalls,
-            include_depth = req.include_depth,
+            event_id = req.event_id,
             "start"
         );
 
         let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
-            tracing::error!(
-                tool = "renderdoc_find_events_and_save_outputs_png",
-                "failed"
-            );
-            tracing::debug!(
-                tool = "renderdoc_find_events_and_save_outputs_png",
-                err = %e,
-                "details"
-            );
+            tracing::error!(tool = "renderdoc_save_depth", "failed");
+            tracing::debug!(tool = "renderdoc_save_depth", err = %e, "details");
             format!("detect installation failed: {e}")
         })?;
 
         let cwd = resolve_base_cwd(req.cwd.clone())?;
-        let capture_path = resolve_path_from_base(&cwd, &req.capture_path);
-
-        let find = install
-            .find_events(
-                &cwd,
-                &renderdog::FindEventsRequest {
-                    capture_path: capture_path.display().to_string(),
-                    only_drawcalls: req.only_drawcalls,
-                    marker_prefix: req.marker_prefix.clone(),
-                    event_id_min: req.event_id_min,
-                    event_id_max: req.event_id_max,
-                    name_contains: req.name_contains.clone(),
-                    marker_contains: req.marker_contains.clone(),
-                    case_sensitive: req.case_sensitive,
-                    max_results: req.max_results,
-                },
-            )
-            .map_err(|e| format!("find events failed: {e}"))?;
-
-        if find.total_matches == 0 {
-            return Err(
-                "no matching events found; refine filters or disable only_drawcalls".into(),
-            );
-        }
-
-        let selected_event_id = match req.selection {
-            FindEventSelection::First => find
-                .first_event_id
-                .or_else(|| find.matches.first().map(|m| m.event_id))
-                .ok_or_else(|| "no matching events found".to_string())?,
-            FindEventSelection::Last => find
-                .last_event_id
-                .or_else(|| find.matches.last().map(|m| m.event_id))
-                .ok_or_else(|| "no matching events found".to_string())?,
-        };
-
-        let output_dir = req
-            .output_dir
-            .map(|p| resolve_path_from_base(&cwd, &p).display().to_string())
-            .unwrap_or_else(|| {
-                renderdog::default_exports_dir(&cwd)
-                    .join("replay")
-                    .display()
-                    .to_string()
-            });
-        std::fs::create_dir_all(&output_dir)
-            .map_err(|e| format!("create output_dir failed: {e}"))?;
-
-        let basename = req.basename.unwrap_or_else(|| {
-            capture_path
-                .file_stem()
-                .and_then(|s| s.to_str())
-                .unwrap_or("capture")
-                .to_string()
-        });
 
-        let replay = install
-            .replay_save_outputs_png(
+        let res = install
+            .save_depth(
                 &cwd,
-                &renderdog::ReplaySaveOutputsPngRequest {
-                    capture_path: capture_path.display().to_string(),
-                    event_id: Some(selected_event_id),
-                    output_dir,
-                    basename,
-                    include_depth: req.include_depth,
+                &renderdog::SaveDepthRequest {
+                    capture_path: req.capture_path,
+                    event_id: req.event_id,
+                    texture: req.texture,
+                    output_path: req.output_path,
+                    format: req.format.unwrap_or_else(|| "png16".to_string()),
+                    near: req.near,
+                    far: req.far,
+                    reversed_z: req.reversed_z,
+                    ramp: req.ramp,
+                    mip: req.mip,
+                    slice: req.slice,
+                    sample: req.sample,
+                    replay_options: req.replay_options.clone(),
                 },
             )
-            .map_err(|e| format!("replay save outputs failed: {e}"))?;
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_save_depth", "failed");
+                tracing::debug!(tool = "renderdoc_save_depth", err = %e, "details");
+                format!("save depth failed: {e}")
+            })?;
 
         tracing::info!(
-            tool = "renderdoc_find_events_and_save_outputs_png",
+            tool = "renderdoc_save_depth",
             elapsed_ms = start.elapsed().as_millis(),
-            selected_event_id,
-            outputs = replay.outputs.len(),
+            output_path = %res.output_path,
+            cleared_texels = res.cleared_texels,
             "ok"
         );
-
-        Ok(Json(FindEventsAndSaveOutputsPngResponse {
-            find,
-            selected_event_id,
-            replay,
-        }))
+        Ok(Json(res))
     }
 
     #[tool(
-        name = "renderdoc_open_capture_ui",
-        description = "Open a .rdc capture in qrenderdoc UI."
+        name = "renderdoc_replay_pick_pixels",
+        description = "Pick many pixels from textures in a .rdc capture in a single `qrenderdoc --python` replay, returning every RGBA value at once. Prefer this over repeated renderdoc_replay_pick_pixel calls for probe points."
     )]
-    async fn open_capture_ui(
+    async fn replay_pick_pixels(
         &self,
-        Parameters(req): Parameters<OpenCaptureUiRequest>,
-    ) -> Result<Json<OpenCaptureUiResponse>, String> {
+        Parameters(req): Parameters<ReplayPickPixelsRequest>,
+    ) -> Result<Json<renderdog::ReplayPickPixelsResponse>, String> {
         let start = Instant::now();
         tracing::info!(
-            tool = "renderdoc_open_capture_ui",
+            tool = "renderdoc_replay_pick_pixels",
             capture_path = %req.capture_path,
+            event_id = req.event_id,
+            picks = req.picks.len(),
             "start"
         );
+
         let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
-            tracing::error!(tool = "renderdoc_open_capture_ui", "failed");
-            tracing::debug!(tool = "renderdoc_open_capture_ui", err = %e, "details");
+            tracing::error!(tool = "renderdoc_replay_pick_pixels", "failed");
+            tracing::debug!(tool = "renderdoc_replay_pick_pixels", err = %e, "details");
             format!("detect installation failed: {e}")
         })?;
 
         let cwd = resolve_base_cwd(req.cwd.clone())?;
-        let capture_path = resolve_path_from_base(&cwd, &req.capture_path);
-
-        let child = install.open_capture_in_ui(&capture_path).map_err(|e| {
-            tracing::error!(tool = "renderdoc_open_capture_ui", "failed");
-            tracing::debug!(tool = "renderdoc_open_capture_ui", err = %e, "details");
-            format!("open capture UI failed: {e}")
-        })?;
 
-        let pid = child.id();
+        let res = install
+            .replay_pick_pixels(
+                &cwd,
+                &renderdog::ReplayPickPixelsRequest {
+                    capture_path: req.capture_path,
+                    event_id: req.event_id,
+                    picks: req.picks,
+                    replay_options: req.replay_options.clone(),
+                },
+            )
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_replay_pick_pixels", "failed");
+                tracing::debug!(tool = "renderdoc_replay_pick_pixels", err = %e, "details");
+                format!("replay pick pixels failed: {e}")
+            })?;
 
         tracing::info!(
-            tool = "renderdoc_open_capture_ui",
+            tool = "renderdoc_replay_pick_pixels",
             elapsed_ms = start.elapsed().as_millis(),
-            pid,
+            pixels = res.pixels.len(),
             "ok"
         );
-        Ok(Json(OpenCaptureUiResponse {
-            capture_path: capture_path.display().to_string(),
-            pid,
-        }))
+        Ok(Json(res))
     }
 
     #[tool(
-        name = "renderdoc_replay_list_textures",
-        description = "List textures in a .rdc capture via `qrenderdoc --python` replay (headless)."
+        name = "renderdoc_dump_buffer",
+        description = "Dump a buffer from a .rdc capture (by resource id or name, at an optional event and byte range) to a raw .bin file, or to CSV / JSONL rows (one per element) when a shader binding the buffer reflects its struct layout."
     )]
-    async fn replay_list_textures(
+    async fn dump_buffer(
         &self,
-        Parameters(req): Parameters<ReplayListTexturesRequest>,
-    ) -> Result<Json<renderdog::ReplayListTexturesResponse>, String> {
+        Parameters(req): Parameters<DumpBufferRequest>,
+    ) -> Result<Json<renderdog::DumpBufferResponse>, String> {
         let start = Instant::now();
         tracing::info!(
-            tool = "renderdoc_replay_list_textures",
+            tool = "renderdoc_dump_buffer",
             capture_path = %req.capture_path,
+            buffer = %req.buffer,
             event_id = req.event_id,
             "start"
         );
 
         let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
-            tracing::error!(tool = "renderdoc_replay_list_textures", "failed");
-            tracing::debug!(tool = "renderdoc_replay_list_textures", err = %e, "details");
+            tracing::error!(tool = "renderdoc_dump_buffer", "failed");
+            tracing::debug!(tool = "renderdoc_dump_buffer", err = %e, "details");
             format!("detect installation failed: {e}")
         })?;
+
         let cwd = resolve_base_cwd(req.cwd.clone())?;
 
         let res = install
-            .replay_list_textures(
+            .dump_buffer(
                 &cwd,
-                &renderdog::ReplayListTexturesRequest {
+                &renderdog::DumpBufferRequest {
                     capture_path: req.capture_path,
+                    buffer: req.buffer,
                     event_id: req.event_id,
+                    offset: req.offset,
+                    length: req.length,
+                    output_path: req.output_path,
+                    format: req.format.unwrap_or_else(|| "bin".to_string()),
+                    replay_options: req.replay_options.clone(),
                 },
             )
             .map_err(|e| {
-                tracing::error!(tool = "renderdoc_replay_list_textures", "failed");
-                tracing::debug!(tool = "renderdoc_replay_list_textures", err = %e, "details");
-                format!("replay list textures failed: {e}")
+                tracing::error!(tool = "renderdoc_dump_buffer", "failed");
+                tracing::debug!(tool = "renderdoc_dump_buffer", err = %e, "details");
+                format!("dump buffer failed: {e}")
             })?;
 
         tracing::info!(
-            tool = "renderdoc_replay_list_textures",
+            tool = "renderdoc_dump_buffer",
             elapsed_ms = start.elapsed().as_millis(),
-            textures = res.textures.len(),
+            output_path = %res.output_path,
+            bytes = res.byte_count,
             "ok"
         );
         Ok(Json(res))
     }
 
     #[tool(
-        name = "renderdoc_replay_pick_pixel",
-        description = "Pick a pixel from a texture in a .rdc capture via `qrenderdoc --python` replay."
+        name = "renderdoc_readback_rw_buffers",
+        description = "Read back the read-write (storage / UAV) buffers bound at an event, as they are after the event executes (e.g. the outputs of a compute dispatch), decoded per element with the reflected struct layout. Useful for asserting on compute-shader results."
     )]
-    async fn replay_pick_pixel(
+    async fn readback_rw_buffers(
         &self,
-        Parameters(req): Parameters<ReplayPickPixelRequest>,
-    ) -> Result<Json<renderdog::ReplayPickPixelResponse>, String> {
+        Parameters(req): Parameters<ReadbackRwBuffersRequest>,
+    ) -> Result<Json<renderdog::ReadbackRwBuffersResponse>, String> {
         let start = Instant::now();
         tracing::info!(
-            tool = "renderdoc_replay_pick_pixel",
+            tool = "renderdoc_readback_rw_buffers",
             capture_path = %req.capture_path,
             event_id = req.event_id,
-            texture_index = req.texture_index,
-            x = req.x,
-            y = req.y,
             "start"
         );
 
         let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
-            tracing::error!(tool = "renderdoc_replay_pick_pixel", "failed");
-            tracing::debug!(tool = "renderdoc_replay_pick_pixel", err = %e, "details");
+            tracing::error!(tool = "renderdoc_readback_rw_buffers", "failed");
+            tracing::debug!(tool = "renderdoc_readback_rw_buffers", err = %e, "details");
             format!("detect installation failed: {e}")
         })?;
+
         let cwd = resolve_base_cwd(req.cwd.clone())?;
 
         let res = install
-            .replay_pick_pixel(
+            .readback_rw_buffers(
                 &cwd,
-                &renderdog::ReplayPickPixelRequest {
+                &renderdog::ReadbackRwBuffersRequest {
                     capture_path: req.capture_path,
                     event_id: req.event_id,
-                    texture_index: req.texture_index,
-                    x: req.x,
-                    y: req.y,
+                    first_element: req.first_element,
+                    max_elements: req.max_elements,
+                    replay_options: req.replay_options.clone(),
                 },
             )
             .map_err(|e| {
-                tracing::error!(tool = "renderdoc_replay_pick_pixel", "failed");
-                tracing::debug!(tool = "renderdoc_replay_pick_pixel", err = %e, "details");
-                format!("replay pick pixel failed: {e}")
+                tracing::error!(tool = "renderdoc_readback_rw_buffers", "failed");
+                tracing::debug!(tool = "renderdoc_readback_rw_buffers", err = %e, "details");
+                format!("readback rw buffers failed: {e}")
             })?;
 
         tracing::info!(
-            tool = "renderdoc_replay_pick_pixel",
+            tool = "renderdoc_readback_rw_buffers",
             elapsed_ms = start.elapsed().as_millis(),
+            buffers = res.buffers.len(),
             "ok"
         );
         Ok(Json(res))
     }
 
     #[tool(
-        name = "renderdoc_replay_save_texture_png",
-        description = "Save a texture to PNG from a .rdc capture via `qrenderdoc --python` replay."
+        name = "renderdoc_export_flipbook",
+        description = "Export a per-draw flipbook: replay the frame draw by draw (optionally filtered like renderdoc_find_events) and save RT0, plus depth if requested, after each draw, with an index JSON listing the frames in order. Set `video` to also encode the frames into an animated GIF or MP4 (MP4 needs ffmpeg on PATH)."
     )]
-    async fn replay_save_texture_png(
+    async fn export_flipbook(
         &self,
-        Parameters(req): Parameters<ReplaySaveTexturePngRequest>,
-    ) -> Result<Json<renderdog::ReplaySaveTexturePngResponse>, String> {
+        Parameters(req): Parameters<ExportFlipbookRequest>,
+    ) -> Result<Json<ExportFlipbookResponse>, String> {
         let start = Instant::now();
         tracing::info!(
-            tool = "renderdoc_replay_save_texture_png",
+            tool = "renderdoc_export_flipbook",
             capture_path = %req.capture_path,
-            event_id = req.event_id,
-            texture_index = req.texture_index,
-            output_path = %req.output_path,
             "start"
         );
 
         let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
-            tracing::error!(tool = "renderdoc_replay_save_texture_png", "failed");
-            tracing::debug!(
-                tool = "renderdoc_replay_save_texture_png",
-                err = %e,
-                "details"
-            );
+            tracing::error!(tool = "renderdoc_export_flipbook", "failed");
+            tracing::debug!(tool = "renderdoc_export_flipbook", err = %e, "details");
             format!("detect installation failed: {e}")
         })?;
+
         let cwd = resolve_base_cwd(req.cwd.clone())?;
 
+        let output_dir = req
+            .output_dir
+            .map(|p| resolve_path_from_base(&cwd, &p).display().to_string())
+            .unwrap_or_else(|| {
+                renderdog::default_exports_dir(&cwd)
+                    .join("flipbook")
+                    .display()
+                    .to_string()
+            });
+
+        let basename = req.basename.unwrap_or_else(|| {
+            Path::new(&req.capture_path)
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or("capture")
+                .to_string()
+        });
+
         let res = install
-            .replay_save_texture_png(
+            .export_flipbook(
                 &cwd,
-                &renderdog::ReplaySaveTexturePngRequest {
+                &renderdog::ExportFlipbookRequest {
                     capture_path: req.capture_path,
-                    event_id: req.event_id,
-                    texture_index: req.texture_index,
-                    output_path: req.output_path,
+                    output_dir: output_dir.clone(),
+                    basename: basename.clone(),
+                    marker_prefix: req.marker_prefix,
+                    event_id_min: req.event_id_min,
+                    event_id_max: req.event_id_max,
+                    name_contains: req.name_contains,
+                    marker_contains: req.marker_contains,
+                    case_sensitive: req.case_sensitive,
+                    include_depth: req.include_depth,
+                    max_frames: req.max_frames,
+                    replay_options: req.replay_options.clone(),
                 },
             )
             .map_err(|e| {
-                tracing::error!(tool = "renderdoc_replay_save_texture_png", "failed");
-                tracing::debug!(
-                    tool = "renderdoc_replay_save_texture_png",
-                    err = %e,
-                    "details"
-                );
-                format!("replay save texture failed: {e}")
+                tracing::error!(tool = "renderdoc_export_flipbook", "failed");
+                tracing::debug!(tool = "renderdoc_export_flipbook", err = %e, "details");
+                format!("export flipbook failed: {e}")
+            })?;
+
+        let video = req
+            .video
+            .map(|format| {
+                let ext = match format {
+                    renderdog::FlipbookVideoFormat::Gif => "gif",
+                    renderdog::FlipbookVideoFormat::Mp4 => "mp4",
+                };
+                renderdog::encode_flipbook(
+                    &cwd,
+                    &renderdog::EncodeFlipbookRequest {
+                        index_json_path: res.index_json_path.clone(),
+                        output_path: Path::new(&output_dir)
+                            .join(format!("{basename}.{ext}"))
+                            .display()
+                            .to_string(),
+                        format: Some(format),
+                        fps: req.video_fps,
+                        use_depth: req.video_from_depth,
+                        ffmpeg: None,
+                    },
+                )
+            })
+            .transpose()
+            .map_err(|e| {
+                tracing::error!(tool = "renderdoc_export_flipbook", "failed");
+                tracing::debug!(tool = "renderdoc_export_flipbook", err = %e, "details");
+                format!("encode flipbook failed: {e}")
             })?;
 
         tracing::info!(
-            tool = "renderdoc_replay_save_texture_png",
+            tool = "renderdoc_export_flipbook",
             elapsed_ms = start.elapsed().as_millis(),
-            output_path = %res.output_path,
+            frames = res.frame_count,
+            truncated = res.truncated,
             "ok"
         );
-        Ok(Json(res))
+        Ok(Json(ExportFlipbookResponse {
+            flipbook: res,
+            video,
+        }))
     }
 
     #[tool(
-        name = "renderdoc_replay_save_outputs_png",
-        description = "Save current pipeline output textures (color RTs + optional depth) to PNG via `qrenderdoc --python` replay (headless)."
+        name = "renderdoc_render_thumbnail",
+        description = "Render a preview of an arbitrary event: replay to event_id and save RT0 downscaled to max_dim as a PNG (renderdoc_save_thumbnail only has the embedded end-of-frame thumbnail)."
     )]
-    async fn replay_save_outputs_png(
+    async fn render_thumbnail(
         &self,
-        Parameters(req): Parameters<ReplaySaveOutputsPngRequest>,
-    ) -> Result<Json<renderdog::ReplaySaveOutputsPngResponse>, String> {
+        Parameters(req): Parameters<RenderThumbnailRequest>,
+    ) -> Result<Json<renderdog::RenderThumbnailResponse>, String> {
         let start = Instant::now();
         tracing::info!(
-            tool = "renderdoc_replay_save_outputs_png",
+            tool = "renderdoc_render_thumbnail",
             capture_path = %req.capture_path,
             event_id = req.event_id,
-            include_depth = req.include_depth,
             "start"
         );
 
         let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
-            tracing::error!(tool = "renderdoc_replay_save_outputs_png", "failed");
-            tracing::debug!(
-                tool = "renderdoc_replay_save_outputs_png",
-                err = %e,
-                "details"
-            );
+            tracing::error!(tool = "renderdoc_render_thumbnail", "failed");
+            tracing::debug!(tool = "renderdoc_render_thumbnail", err = %e, "details");
             format!("detect installation failed: {e}")
         })?;
+
         let cwd = resolve_base_cwd(req.cwd.clone())?;
 
-        let output_dir = req
-            .output_dir
+        let output_path = req
+            .output_path
             .map(|p| resolve_path_from_base(&cwd, &p).display().to_string())
             .unwrap_or_else(|| {
+                let stem = Path::new(&req.capture_path)
+                    .file_stem()
+                    .and_then(|s| s.to_str())
+                    .unwrap_or("capture");
                 renderdog::default_exports_dir(&cwd)
-                    .join("replay")
+                    .join("thumbnails")
+                    .join(format!("{stem}.event{}.png", req.event_id))
                     .display()
                     .to_string()
             });
-        std::fs::create_dir_all(&output_dir)
-            .map_err(|e| format!("create output_dir failed: {e}"))?;
-
-        let basename = req.basename.unwrap_or_else(|| {
-            Path::new(&req.capture_path)
-                .file_stem()
-                .and_then(|s| s.to_str())
-                .unwrap_or("capture")
-                .to_string()
-        });
 
         let res = install
-            .replay_save_outputs_png(
+            .render_thumbnail(
                 &cwd,
-                &renderdog::ReplaySaveOutputsPngRequest {
+                &renderdog::RenderThumbnailRequest {
                     capture_path: req.capture_path,
                     event_id: req.event_id,
-                    output_dir,
-                    basename,
-                    include_depth: req.include_depth,
+                    output_path,
+                    max_dim: req.max_dim,
+                    replay_options: req.replay_options.clone(),
                 },
             )
             .map_err(|e| {
-                tracing::error!(tool = "renderdoc_replay_save_outputs_png", "failed");
-                tracing::debug!(
-                    tool = "renderdoc_replay_save_outputs_png",
-                    err = %e,
-                    "details"
-                );
-                format!("replay save outputs failed: {e}")
+                tracing::error!(tool = "renderdoc_render_thumbnail", "failed");
+                tracing::debug!(tool = "renderdoc_render_thumbnail", err = %e, "details");
+                format!("render thumbnail failed: {e}")
             })?;
 
         tracing::info!(
-            tool = "renderdoc_replay_save_outputs_png",
+            tool = "renderdoc_render_thumbnail",
             elapsed_ms = start.elapsed().as_millis(),
-            outputs = res.outputs.len(),
+            width = res.width,
+            height = res.height,
             "ok"
         );
         Ok(Json(res))
     }
 
     #[tool(
-        name = "renderdoc_capture_and_export_actions_jsonl",
-        description = "One-shot workflow: launch target under renderdoccmd capture, trigger capture via target control, then export <basename>.actions.jsonl and <basename>.summary.json."
+        name = "renderdoc_generate_report_markdown",
+        description = "Generate a human-readable Markdown frame report (capture metadata, pass/marker tree with draw counts, top pipelines, largest targets, optional per-pass images), e.g. to attach to a PR."
     )]
-    async fn capture_and_export_actions_jsonl(
+    async fn generate_report_markdown(
         &self,
-        Parameters(req): Parameters<CaptureAndExportActionsRequest>,
-    ) -> Result<Json<CaptureAndExportActionsResponse>, String> {
+        Parameters(req): Parameters<GenerateReportMarkdownRequest>,
+    ) -> Result<Json<renderdog::GenerateReportMarkdownResponse>, String> {
         let start = Instant::now();
         tracing::info!(
-            tool = "renderdoc_capture_and_export_actions_jsonl",
-            executable = %req.executable,
-            args_len = req.args.len(),
-            only_drawcalls = req.only_drawcalls,
+            tool = "renderdoc_generate_report_markdown",
+            capture_path = %req.capture_path,
             "start"
         );
+
         let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
-            tracing::error!(
-                tool = "renderdoc_capture_and_export_actions_jsonl",
-                "failed"
-            );
-            tracing::debug!(
-                tool = "renderdoc_capture_and_export_actions_jsonl",
-                err = %e,
-                "details"
-            );
+            tracing::error!(tool = "renderdoc_generate_report_markdown", "failed");
+            tracing::debug!(tool = "renderdoc_generate_report_markdown", err = %e, "details");
             format!("detect installation failed: {e}")
         })?;
 
         let cwd = resolve_base_cwd(req.cwd.clone())?;
 
-        let artifacts_dir = req
-            .artifacts_dir
-            .as_deref()
-            .map(|p| resolve_path_from_base(&cwd, p))
-            .unwrap_or_else(|| renderdog::default_artifacts_dir(&cwd));
-
-        std::fs::create_dir_all(&artifacts_dir)
-            .map_err(|e| format!("create artifacts_dir failed: {e}"))?;
-
-        let capture_file_template = req
-            .capture_template_name
-            .as_deref()
-            .map(|name| artifacts_dir.join(format!("{name}.rdc")));
-
-        let launch_req = renderdog::CaptureLaunchRequest {
-            executable: resolve_path_from_base(&cwd, &req.executable),
-            args: req.args.into_iter().map(OsString::from).collect(),
-            working_dir: req.working_dir.map(|p| resolve_path_from_base(&cwd, &p)),
-            capture_file_template: capture_file_template.clone(),
-        };
-
-        let launch_res = install.launch_capture(&launch_req).map_err(|e| {
-            tracing::error!(
-                tool = "renderdoc_capture_and_export_actions_jsonl",
-                "failed"
-            );
-            tracing::debug!(
-                tool = "renderdoc_capture_and_export_actions_jsonl",
-                err = %e,
-                "details"
-            );
-            format!("launch capture failed: {e}")
-        })?;
-
-        let capture_res = install
-            .trigger_capture_via_target_control(
-                &cwd,
-                &renderdog::TriggerCaptureRequest {
-                    host: req.host,
-                    target_ident: launch_res.target_ident,
-                    num_frames: req.num_frames,
-                    timeout_s: req.timeout_s,
-                },
-            )
-            .map_err(|e| {
-                tracing::error!(
-                    tool = "renderdoc_capture_and_export_actions_jsonl",
-                    "failed"
-                );
-                tracing::debug!(
-                    tool = "renderdoc_capture_and_export_actions_jsonl",
-                    err = %e,
-                    "details"
-                );
-                format!("trigger capture failed: {e}")
-            })?;
-
         let output_dir = req
             .output_dir
             .map(|p| resolve_path_from_base(&cwd, &p).display().to_string())
-            .unwrap_or_else(|| renderdog::default_exports_dir(&cwd).display().to_string());
-
-        std::fs::create_dir_all(&output_dir)
-            .map_err(|e| format!("create output_dir failed: {e}"))?;
+            .unwrap_or_else(|| {
+                renderdog::default_exports_dir(&cwd)
+                    .join("reports")
+                    .display()
+                    .to_string()
+            });
 
         let basename = req.basename.unwrap_or_else(|| {
-            Path::new(&capture_res.capture_path)
+            Path::new(&req.capture_path)
                 .file_stem()
                 .and_then(|s| s.to_str())
                 .unwrap_or("capture")
                 .to_string()
         });
 
-        let export_res = install
-            .export_actions_jsonl(
+        let res = install
+            .generate_report_markdown(
                 &cwd,
-                &renderdog::ExportActionsRequest {
-                    capture_path: capture_res.capture_path.clone(),
+                &renderdog::GenerateReportMarkdownRequest {
+                    capture_path: req.capture_path,
                     output_dir,
                     basename,
-                    only_drawcalls: req.only_drawcalls,
-                    marker_prefix: req.marker_prefix,
-                    event_id_min: req.event_id_min,
-                    event_id_max: req.event_id_max,
-                    name_contains: req.name_contains,
-                    marker_contains: req.marker_contains,
-                    case_sensitive: req.case_sensitive,
+                    marker_depth: req.marker_depth,
+                    top_pipelines: req.top_pipelines,
+                    top_targets: req.top_targets,
+                    save_images: req.save_images,
+                    replay_options: req.replay_options.clone(),
                 },
             )
             .map_err(|e| {
-                tracing::error!(
-                    tool = "renderdoc_capture_and_export_actions_jsonl",
-                    "failed"
-                );
-                tracing::debug!(
-                    tool = "renderdoc_capture_and_export_actions_jsonl",
-                    err = %e,
-                    "details"
-                );
-                format!("export actions failed: {e}")
+                tracing::error!(tool = "renderdoc_generate_report_markdown", "failed");
+                tracing::debug!(tool = "renderdoc_generate_report_markdown", err = %e, "details");
+                format!("generate report failed: {e}")
             })?;
 
         tracing::info!(
-            tool = "renderdoc_capture_and_export_actions_jsonl",
+            tool = "renderdoc_generate_report_markdown",
             elapsed_ms = start.elapsed().as_millis(),
-            target_ident = launch_res.target_ident,
-            capture_path = %export_res.capture_path,
-            actions_jsonl_path = %export_res.actions_jsonl_path,
-            total_actions = export_res.total_actions,
+            report_path = %res.report_path,
             "ok"
         );
-        Ok(Json(CaptureAndExportActionsResponse {
-            target_ident: launch_res.target_ident,
-            capture_path: export_res.capture_path,
-            capture_file_template: capture_file_template.map(|p| p.display().to_string()),
-            stdout: launch_res.stdout,
-            stderr: launch_res.stderr,
-            actions_jsonl_path: export_res.actions_jsonl_path,
-            summary_json_path: export_res.summary_json_path,
-            total_actions: export_res.total_actions,
-            drawcall_actions: export_res.drawcall_actions,
-        }))
+        Ok(Json(res))
     }
 
     #[tool(
-        name = "renderdoc_capture_and_export_bindings_index_jsonl",
-        description = "One-shot workflow: launch target under renderdoccmd capture, trigger capture via target control, then export <basename>.bindings.jsonl and <basename>.bindings_summary.json."
-    )]
-    async fn capture_and_export_bindings_index_jsonl(
-        &self,
-        Parameters(req): Parameters<CaptureAndExportBindingsIndexRequest>,
-    ) -> Result<Json<CaptureAndExportBindingsIndexResponse>, String> {
-        let start = Instant::now();
-        tracing::info!(
-            tool = "renderdoc_capture_and_export_bindings_index_jsonl",
-            executable = %req.executable,
-            args_len = req.args.len(),
-            include_cbuffers = req.include_cbuffers,
-            include_outputs = req.include_outputs,
-            "start"
-        );
-
-        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
-            tracing::error!(
-                tool = "renderdoc_capture_and_export_bindings_index_jsonl",
-                "failed"
-            );
-            tracing::debug!(
-                tool = "renderdoc_capture_and_export_bindings_index_jsonl",
-                err = %e,
-                "details"
-            );
-            format!("detect installation failed: {e}")
-        })?;
-
-        let cwd = resolve_base_cwd(req.cwd.clone())?;
-
-        let artifacts_dir = req
-            .artifacts_dir
-            .as_deref()
-            .map(|p| resolve_path_from_base(&cwd, p))
-            .unwrap_or_else(|| renderdog::default_artifacts_dir(&cwd));
-
-        std::fs::create_dir_all(&artifacts_dir)
-            .map_err(|e| format!("create artifacts_dir failed: {e}"))?;
-
-        let capture_file_template = req
-            .capture_template_name
-            .as_deref()
-            .map(|name| artifacts_dir.join(format!("{name}.rdc")));
-
-        let launch_req = renderdog::CaptureLaunchRequest {
-            executable: resolve_path_from_base(&cwd, &req.executable),
-            args: req.args.into_iter().map(OsString::from).collect(),
-            working_dir: req.working_dir.map(|p| resolve_path_from_base(&cwd, &p)),
-            capture_file_template: capture_file_template.clone(),
-        };
-
-        let launch_res = install.launch_capture(&launch_req).map_err(|e| {
-            tracing::error!(
-                tool = "renderdoc_capture_and_export_bindings_index_jsonl",
-                "failed"
-            );
-            tracing::debug!(
-                tool = "renderdoc_capture_and_export_bindings_index_jsonl",
-                err = %e,
-                "details"
-            );
-            format!("launch capture failed: {e}")
-        })?;
-
-        let capture_res = install
-            .trigger_capture_via_target_control(
-                &cwd,
-                &renderdog::TriggerCaptureRequest {
-                    host: req.host,
-                    target_ident: launch_res.target_ident,
-                    num_frames: req.num_frames,
-                    timeout_s: req.timeout_s,
-                },
-            )
-            .map_err(|e| {
-                tracing::error!(
-                    tool = "renderdoc_capture_and_export_bindings_index_jsonl",
-                    "failed"
-                );
-                tracing::debug!(
-                    tool = "renderdoc_capture_and_export_bindings_index_jsonl",
-                    err = %e,
-                    "details"
-                );
-                format!("trigger capture failed: {e}")
-            })?;
+        name = "renderdoc_generate_report_html",
+        description = "Generate a single self-contained .html report (frame stats, marker tree, per-pass RT0 thumbnails, filterable action and binding tables from the bundle exports) to share capture analysis with people who don't have RenderDoc."
+    )]
+    async fn generate_report_html(
+        &self,
+        Parameters(req): Parameters<GenerateReportHtmlRequest>,
+    ) -> Result<Json<renderdog::GenerateReportHtmlResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_generate_report_html",
+            capture_path = %req.capture_path,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_generate_report_html", "failed");
+            tracing::debug!(tool = "renderdoc_generate_report_html", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
 
         let output_dir = req
             .output_dir
             .map(|p| resolve_path_from_base(&cwd, &p).display().to_string())
-            .unwrap_or_else(|| renderdog::default_exports_dir(&cwd).display().to_string());
-
-        std::fs::create_dir_all(&output_dir)
-            .map_err(|e| format!("create output_dir failed: {e}"))?;
+            .unwrap_or_else(|| {
+                renderdog::default_exports_dir(&cwd)
+                    .join("reports")
+                    .display()
+                    .to_string()
+            });
 
         let basename = req.basename.unwrap_or_else(|| {
-            Path::new(&capture_res.capture_path)
+            Path::new(&req.capture_path)
                 .file_stem()
                 .and_then(|s| s.to_str())
                 .unwrap_or("capture")
                 .to_string()
         });
 
-        let export_res = install
-            .export_bindings_index_jsonl(
+        let res = install
+            .generate_report_html(
                 &cwd,
-                &renderdog::ExportBindingsIndexRequest {
-                    capture_path: capture_res.capture_path.clone(),
+                &renderdog::GenerateReportHtmlRequest {
+                    capture_path: req.capture_path,
                     output_dir,
                     basename,
-                    marker_prefix: req.marker_prefix,
-                    event_id_min: req.event_id_min,
-                    event_id_max: req.event_id_max,
-                    name_contains: req.name_contains,
-                    marker_contains: req.marker_contains,
-                    case_sensitive: req.case_sensitive,
-                    include_cbuffers: req.include_cbuffers,
-                    include_outputs: req.include_outputs,
+                    thumbnail_max_dim: req.thumbnail_max_dim,
+                    max_rows: req.max_rows,
+                    replay_options: req.replay_options.clone(),
                 },
             )
             .map_err(|e| {
-                tracing::error!(
-                    tool = "renderdoc_capture_and_export_bindings_index_jsonl",
-                    "failed"
-                );
-                tracing::debug!(
-                    tool = "renderdoc_capture_and_export_bindings_index_jsonl",
-                    err = %e,
-                    "details"
-                );
-                format!("export bindings index failed: {e}")
+                tracing::error!(tool = "renderdoc_generate_report_html", "failed");
+                tracing::debug!(tool = "renderdoc_generate_report_html", err = %e, "details");
+                format!("generate HTML report failed: {e}")
             })?;
 
         tracing::info!(
-            tool = "renderdoc_capture_and_export_bindings_index_jsonl",
+            tool = "renderdoc_generate_report_html",
             elapsed_ms = start.elapsed().as_millis(),
-            target_ident = launch_res.target_ident,
-            capture_path = %export_res.capture_path,
-            bindings_jsonl_path = %export_res.bindings_jsonl_path,
-            total_drawcalls = export_res.total_drawcalls,
+            report_path = %res.report_path,
+            truncated = res.truncated,
             "ok"
         );
-
-        Ok(Json(CaptureAndExportBindingsIndexResponse {
-            target_ident: launch_res.target_ident,
-            capture_path: export_res.capture_path,
-            capture_file_template: capture_file_template.map(|p| p.display().to_string()),
-            stdout: launch_res.stdout,
-            stderr: launch_res.stderr,
-            bindings_jsonl_path: export_res.bindings_jsonl_path,
-            summary_json_path: export_res.summary_json_path,
-            total_drawcalls: export_res.total_drawcalls,
-        }))
+        Ok(Json(res))
     }
 
     #[tool(
-        name = "renderdoc_capture_and_export_bundle_jsonl",
-        description = "One-shot workflow: launch target under renderdoccmd capture, trigger capture via target control, then export both <basename>.actions.jsonl (+ summary) and <basename>.bindings.jsonl (+ bindings_summary)."
+        name = "renderdoc_export_sqlite",
+        description = "Export actions, draw bindings and resources of a capture into one SQLite database (<basename>.sqlite; tables actions, draws, bindings, resources; event_id, marker_path and resource name/id indexed) so large captures can be queried with SQL instead of grepping JSONL."
     )]
-    async fn capture_and_export_bundle_jsonl(
+    async fn export_sqlite(
         &self,
-        Parameters(req): Parameters<CaptureAndExportBundleRequest>,
-    ) -> Result<Json<CaptureAndExportBundleResponse>, String> {
+        Parameters(req): Parameters<ExportSqliteRequest>,
+    ) -> Result<Json<renderdog::ExportSqliteResponse>, String> {
         let start = Instant::now();
         tracing::info!(
-            tool = "renderdoc_capture_and_export_bundle_jsonl",
-            executable = %req.executable,
-            args_len = req.args.len(),
-            only_drawcalls = req.only_drawcalls,
-            include_cbuffers = req.include_cbuffers,
-            include_outputs = req.include_outputs,
-            save_thumbnail = req.save_thumbnail,
-            open_capture_ui = req.open_capture_ui,
+            tool = "renderdoc_export_sqlite",
+            capture_path = %req.capture_path,
             "start"
         );
 
         let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
-            tracing::error!(tool = "renderdoc_capture_and_export_bundle_jsonl", "failed");
-            tracing::debug!(
-                tool = "renderdoc_capture_and_export_bundle_jsonl",
-                err = %e,
-                "details"
-            );
+            tracing::error!(tool = "renderdoc_export_sqlite", "failed");
+            tracing::debug!(tool = "renderdoc_export_sqlite", err = %e, "details");
             format!("detect installation failed: {e}")
         })?;
 
         let cwd = resolve_base_cwd(req.cwd.clone())?;
 
-        let artifacts_dir = req
-            .artifacts_dir
-            .as_deref()
-            .map(|p| resolve_path_from_base(&cwd, p))
-            .unwrap_or_else(|| renderdog::default_artifacts_dir(&cwd));
-
-        std::fs::create_dir_all(&artifacts_dir)
-            .map_err(|e| format!("create artifacts_dir failed: {e}"))?;
-
-        let capture_file_template = req
-            .capture_template_name
-            .as_deref()
-            .map(|name| artifacts_dir.join(format!("{name}.rdc")));
-
-        let launch_req = renderdog::CaptureLaunchRequest {
-            executable: resolve_path_from_base(&cwd, &req.executable),
-            args: req.args.into_iter().map(OsString::from).collect(),
-            working_dir: req.working_dir.map(|p| resolve_path_from_base(&cwd, &p)),
-            capture_file_template: capture_file_template.clone(),
-        };
-
-        let launch_res = install.launch_capture(&launch_req).map_err(|e| {
-            tracing::error!(tool = "renderdoc_capture_and_export_bundle_jsonl", "failed");
-            tracing::debug!(
-                tool = "renderdoc_capture_and_export_bundle_jsonl",
-                err = %e,
-                "details"
-            );
-            format!("launch capture failed: {e}")
-        })?;
+        let output_dir = req
+            .output_dir
+            .map(|p| resolve_path_from_base(&cwd, &p).display().to_string())
+            .unwrap_or_else(|| renderdog::default_exports_dir(&cwd).display().to_string());
 
-        let capture_res = install
-            .trigger_capture_via_target_control(
+        let basename = req.basename.unwrap_or_else(|| {
+            Path::new(&req.capture_path)
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or("capture")
+                .to_string()
+        });
+        let res = install
+            .export_sqlite(
                 &cwd,
-                &renderdog::TriggerCaptureRequest {
-                    host: req.host,
-                    target_ident: launch_res.target_ident,
-                    num_frames: req.num_frames,
-                    timeout_s: req.timeout_s,
+                &renderdog::ExportSqliteRequest {
+                    capture_path: req.capture_path,
+                    output_dir,
+                    basename,
+                    include_cbuffers: req.include_cbuffers,
+                    replay_options: req.replay_options.clone(),
                 },
             )
             .map_err(|e| {
-                tracing::error!(tool = "renderdoc_capture_and_export_bundle_jsonl", "failed");
-                tracing::debug!(
-                    tool = "renderdoc_capture_and_export_bundle_jsonl",
-                    err = %e,
-                    "details"
-                );
-                format!("trigger capture failed: {e}")
+                tracing::error!(tool = "renderdoc_export_sqlite", "failed");
+                tracing::debug!(tool = "renderdoc_export_sqlite", err = %e, "details");
+                format!("export sqlite failed: {e}")
             })?;
 
+        tracing::info!(
+            tool = "renderdoc_export_sqlite",
+            elapsed_ms = start.elapsed().as_millis(),
+            database_path = %res.database_path,
+            actions = res.actions,
+            "ok"
+        );
+        Ok(Json(res))
+    }
+
+    #[tool(
+        name = "renderdoc_export_parquet",
+        description = "Export actions and draw bindings of a capture as Snappy-compressed Parquet files (<basename>.actions.parquet, <basename>.bindings.parquet with one row per bound resource) for loading large captures into pandas / Polars / DuckDB."
+    )]
+    async fn export_parquet(
+        &self,
+        Parameters(req): Parameters<ExportParquetRequest>,
+    ) -> Result<Json<renderdog::ExportParquetResponse>, String> {
+        let start = Instant::now();
+        tracing::info!(
+            tool = "renderdoc_export_parquet",
+            capture_path = %req.capture_path,
+            "start"
+        );
+
+        let install = renderdog::RenderDocInstallation::detect().map_err(|e| {
+            tracing::error!(tool = "renderdoc_export_parquet", "failed");
+            tracing::debug!(tool = "renderdoc_export_parquet", err = %e, "details");
+            format!("detect installation failed: {e}")
+        })?;
+
+        let cwd = resolve_base_cwd(req.cwd.clone())?;
+
         let output_dir = req
             .output_dir
             .map(|p| resolve_path_from_base(&cwd, &p).display().to_string())
             .unwrap_or_else(|| renderdog::default_exports_dir(&cwd).display().to_string());
 
-        std::fs::create_dir_all(&output_dir)
-            .map_err(|e| format!("create output_dir failed: {e}"))?;
-
         let basename = req.basename.unwrap_or_else(|| {
-            Path::new(&capture_res.capture_path)
+            Path::new(&req.capture_path)
                 .file_stem()
                 .and_then(|s| s.to_str())
                 .unwrap_or("capture")
                 .to_string()
         });
-
-        let export_res = install
-            .export_bundle_jsonl(
+        let res = install
+            .export_parquet(
                 &cwd,
-                &renderdog::ExportBundleRequest {
-                    capture_path: capture_res.capture_path.clone(),
-                    output_dir: output_dir.clone(),
-                    basename: basename.clone(),
-                    only_drawcalls: req.only_drawcalls,
-                    marker_prefix: req.marker_prefix,
-                    event_id_min: req.event_id_min,
-                    event_id_max: req.event_id_max,
-                    name_contains: req.name_contains,
-                    marker_contains: req.marker_contains,
-                    case_sensitive: req.case_sensitive,
+                &renderdog::ExportParquetRequest {
+                    capture_path: req.capture_path,
+                    output_dir,
+                    basename,
                     include_cbuffers: req.include_cbuffers,
-                    include_outputs: req.include_outputs,
+                    replay_options: req.replay_options.clone(),
                 },
             )
             .map_err(|e| {
-                tracing::error!(tool = "renderdoc_capture_and_export_bundle_jsonl", "failed");
-                tracing::debug!(
-                    tool = "renderdoc_capture_and_export_bundle_jsonl",
-                    err = %e,
-                    "details"
-                );
-                format!("export bundle failed: {e}")
+                tracing::error!(tool = "renderdoc_export_parquet", "failed");
+                tracing::debug!(tool = "renderdoc_export_parquet", err = %e, "details");
+                format!("export parquet failed: {e}")
             })?;
 
-        let mut thumbnail_output_path: Option<String> = None;
-        if req.save_thumbnail {
-            let thumb_path = req
-                .thumbnail_output_path
-                .map(|p| resolve_path_from_base(&cwd, &p).display().to_string())
-                .unwrap_or_else(|| {
-                    Path::new(&output_dir)
-                        .join(format!("{basename}.thumb.png"))
-                        .display()
-                        .to_string()
-                });
-            if let Some(parent) = Path::new(&thumb_path).parent() {
-                std::fs::create_dir_all(parent)
-                    .map_err(|e| format!("create thumbnail output dir failed: {e}"))?;
-            }
-            install
-                .save_thumbnail(Path::new(&export_res.capture_path), Path::new(&thumb_path))
-                .map_err(|e| format!("save thumbnail failed: {e}"))?;
-            thumbnail_output_path = Some(thumb_path);
-        }
-
-        let mut ui_pid: Option<u32> = None;
-        if req.open_capture_ui {
-            let child = install
-                .open_capture_in_ui(Path::new(&export_res.capture_path))
-                .map_err(|e| format!("open capture UI failed: {e}"))?;
-            ui_pid = Some(child.id());
-        }
-
         tracing::info!(
-            tool = "renderdoc_capture_and_export_bundle_jsonl",
+            tool = "renderdoc_export_parquet",
             elapsed_ms = start.elapsed().as_millis(),
-            target_ident = launch_res.target_ident,
-            capture_path = %export_res.capture_path,
-            actions_jsonl_path = %export_res.actions_jsonl_path,
-            bindings_jsonl_path = %export_res.bindings_jsonl_path,
-            total_actions = export_res.total_actions,
-            total_drawcalls = export_res.total_drawcalls,
+            actions = res.actions,
+            bindings = res.bindings,
             "ok"
         );
-
-        Ok(Json(CaptureAndExportBundleResponse {
-            target_ident: launch_res.target_ident,
-            capture_path: export_res.capture_path,
-            capture_file_template: capture_file_template.map(|p| p.display().to_string()),
-            stdout: launch_res.stdout,
-            stderr: launch_res.stderr,
-
-            actions_jsonl_path: export_res.actions_jsonl_path,
-            actions_summary_json_path: export_res.actions_summary_json_path,
-            total_actions: export_res.total_actions,
-            drawcall_actions: export_res.drawcall_actions,
-
-            bindings_jsonl_path: export_res.bindings_jsonl_path,
-            bindings_summary_json_path: export_res.bindings_summary_json_path,
-            total_drawcalls: export_res.total_drawcalls,
-
-            thumbnail_output_path,
-            ui_pid,
-        }))
+        Ok(Json(res))
     }
 }
 
@@ -7,8 +7,11 @@ fn main() {
     let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
     let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
 
-    let pregenerated = manifest_dir.join("src").join("bindings_pregenerated.rs");
-    let vendor_header = manifest_dir.join("vendor").join("renderdoc_app.h");
+    let revision = HeaderRevision::selected();
+    let pregenerated = manifest_dir.join("src").join(revision.pregenerated_file());
+    let vendor_header = manifest_dir
+        .join("vendor")
+        .join(revision.vendor_header_file());
     let submodule_header = workspace_root(&manifest_dir)
         .join("third-party")
         .join("renderdoc")
@@ -27,6 +30,10 @@ fn main() {
     println!("cargo:rerun-if-env-changed=RENDERDOG_SYS_HEADER");
     println!("cargo:rerun-if-env-changed=RENDERDOG_SYS_REGEN_BINDINGS");
     println!("cargo:rerun-if-env-changed=RENDERDOG_SYS_VERBOSE");
+    println!("cargo:rustc-check-cfg=cfg(renderdog_sys_header_1_4)");
+    if revision == HeaderRevision::V1_4 {
+        println!("cargo:rustc-cfg=renderdog_sys_header_1_4");
+    }
 
     let out_bindings = out_dir.join("bindings.rs");
 
@@ -44,7 +51,7 @@ fn main() {
             );
         }
 
-        generate_bindings(&manifest_dir, &out_bindings);
+        generate_bindings(&manifest_dir, revision, &out_bindings);
         sanitize_bindings_file(&out_bindings);
     } else {
         if docs_rs && env::var_os("RENDERDOG_SYS_REGEN_BINDINGS").is_some() {
@@ -63,9 +70,43 @@ fn main() {
     }
 }
 
+/// Vendored `renderdoc_app.h` revision, selected via the `header-1-*` features.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum HeaderRevision {
+    V1_4,
+    V1_6,
+}
+
+impl HeaderRevision {
+    /// The newest enabled revision wins, so enabling `header-1-6` anywhere in the dependency
+    /// graph keeps the full 1.6 layout for crates that need it.
+    fn selected() -> Self {
+        let enabled = |f: &str| env::var_os(format!("CARGO_FEATURE_{f}")).is_some();
+        if !enabled("HEADER_1_6") && enabled("HEADER_1_4") {
+            HeaderRevision::V1_4
+        } else {
+            HeaderRevision::V1_6
+        }
+    }
+
+    fn pregenerated_file(self) -> &'static str {
+        match self {
+            HeaderRevision::V1_4 => "bindings_pregenerated_1_4.rs",
+            HeaderRevision::V1_6 => "bindings_pregenerated.rs",
+        }
+    }
+
+    fn vendor_header_file(self) -> &'static str {
+        match self {
+            HeaderRevision::V1_4 => "renderdoc_app_1_4.h",
+            HeaderRevision::V1_6 => "renderdoc_app.h",
+        }
+    }
+}
+
 #[cfg(feature = "bindgen")]
-fn generate_bindings(manifest_dir: &Path, out: &Path) {
-    let header = select_header_path(manifest_dir);
+fn generate_bindings(manifest_dir: &Path, revision: HeaderRevision, out: &Path) {
+    let header = select_header_path(manifest_dir, revision);
     let bindings = bindgen::Builder::default()
         .header(header.to_string_lossy())
         .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
@@ -94,12 +135,12 @@ fn generate_bindings(manifest_dir: &Path, out: &Path) {
 }
 
 #[cfg(not(feature = "bindgen"))]
-fn generate_bindings(_manifest_dir: &Path, _out: &Path) {
+fn generate_bindings(_manifest_dir: &Path, _revision: HeaderRevision, _out: &Path) {
     panic!("bindgen feature is not enabled");
 }
 
 #[cfg(feature = "bindgen")]
-fn select_header_path(manifest_dir: &Path) -> PathBuf {
+fn select_header_path(manifest_dir: &Path, revision: HeaderRevision) -> PathBuf {
     // 1) Explicit override (recommended for CI/maintainers)
     if let Ok(p) = env::var("RENDERDOG_SYS_HEADER") {
         let pb = PathBuf::from(p);
@@ -113,7 +154,8 @@ fn select_header_path(manifest_dir: &Path) -> PathBuf {
     }
 
     // 2) If RenderDoc is vendored as a git submodule, prefer the authoritative header.
-    // This matches the layout of the official RenderDoc repo.
+    // This matches the layout of the official RenderDoc repo. The submodule tracks the latest
+    // API, so it is only used for the 1.6 revision.
     let submodule = workspace_root(manifest_dir)
         .join("third-party")
         .join("renderdoc")
@@ -121,12 +163,14 @@ fn select_header_path(manifest_dir: &Path) -> PathBuf {
         .join("api")
         .join("app")
         .join("renderdoc_app.h");
-    if submodule.is_file() {
+    if revision == HeaderRevision::V1_6 && submodule.is_file() {
         return submodule;
     }
 
     // 3) Fallback: minimal vendored header for regeneration.
-    manifest_dir.join("vendor").join("renderdoc_app.h")
+    manifest_dir
+        .join("vendor")
+        .join(revision.vendor_header_file())
 }
 
 fn workspace_root(manifest_dir: &Path) -> PathBuf {
@@ -8,9 +8,28 @@
 //!
 //! - `RENDERDOG_SYS_REGEN_BINDINGS=1 cargo build -p renderdog-sys --features bindgen`
 //! - or `python scripts/regen_bindings.py` from the workspace root
+//!
+//! The `header-1-6` (default) and `header-1-4` features select which vendored header revision the
+//! bindings come from. With only `header-1-4`, the API struct is `RENDERDOC_API_1_4_2` and
+//! entry points added later (`ShowReplayUI`, `SetCaptureTitle`) do not exist, so code targeting
+//! old RenderDoc builds cannot call them by accident.
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
 #![allow(non_upper_case_globals)]
 #![allow(clippy::all)]
 
 include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+
+/// Newest in-app API version described by the selected header revision.
+#[cfg(not(renderdog_sys_header_1_4))]
+pub const HEADER_API_VERSION: RENDERDOC_Version = RENDERDOC_Version::eRENDERDOC_API_Version_1_6_0;
+/// Newest in-app API version described by the selected header revision.
+#[cfg(renderdog_sys_header_1_4)]
+pub const HEADER_API_VERSION: RENDERDOC_Version = RENDERDOC_Version::eRENDERDOC_API_Version_1_4_2;
+
+/// API struct for [`HEADER_API_VERSION`].
+#[cfg(not(renderdog_sys_header_1_4))]
+pub type RENDERDOC_API_LATEST = RENDERDOC_API_1_6_0;
+/// API struct for [`HEADER_API_VERSION`].
+#[cfg(renderdog_sys_header_1_4)]
+pub type RENDERDOC_API_LATEST = RENDERDOC_API_1_4_2;
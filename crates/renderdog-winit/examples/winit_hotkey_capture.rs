@@ -65,6 +65,7 @@ impl ApplicationHandler for App {
                     self.trigger_capture();
                 }
             }
+            #[allow(clippy::collapsible_match)]
             WindowEvent::RedrawRequested => {
                 if self.capturing {
                     self.finish_capture();
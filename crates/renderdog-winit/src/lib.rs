@@ -1,7 +1,7 @@
 //! Winit integration helpers for `renderdog` (in-app RenderDoc API).
 //!
 //! This crate is intentionally small: it provides convenience conversions for key codes and
-//! (on Windows) extracting a native window handle for RenderDoc APIs that accept a window handle.
+//! extracting a native window handle (Win32/X11) for RenderDoc APIs that accept a window handle.
 
 use renderdog::RENDERDOC_InputButton;
 
@@ -83,53 +83,37 @@ pub fn input_button_from_key_code(code: winit::keyboard::KeyCode) -> RENDERDOC_I
     }
 }
 
-/// Extract a native window handle for RenderDoc from a winit window (Windows only).
+/// Extract a native window handle for RenderDoc from a winit window.
 ///
-/// Returns `None` on unsupported platforms or when the handle is not available.
-#[cfg(windows)]
+/// Returns `None` on unsupported backends (e.g. Wayland) or when the handle is not available.
 pub fn renderdoc_window_handle(
     window: &winit::window::Window,
 ) -> Option<renderdog::RENDERDOC_WindowHandle> {
-    use raw_window_handle::{HasWindowHandle, RawWindowHandle};
-    let handle = window.window_handle().ok()?;
-    match handle.as_raw() {
-        RawWindowHandle::Win32(h) => Some(h.hwnd.get() as renderdog::RENDERDOC_WindowHandle),
-        _ => None,
-    }
-}
-
-#[cfg(not(windows))]
-pub fn renderdoc_window_handle(
-    _window: &winit::window::Window,
-) -> Option<renderdog::RENDERDOC_WindowHandle> {
-    None
+    renderdog::window_handle(window).ok()
 }
 
 /// Start a RenderDoc frame capture using a winit window handle (no device pointer).
-#[cfg(windows)]
 pub fn start_frame_capture_window(
     rd: &renderdog::RenderDocInApp,
     window: &winit::window::Window,
 ) -> Result<(), renderdog::InAppError> {
-    rd.start_frame_capture(None, renderdoc_window_handle(window))
+    rd.start_frame_capture_for(None, window)
 }
 
 /// End a RenderDoc frame capture using a winit window handle (no device pointer).
-#[cfg(windows)]
 pub fn end_frame_capture_window(
     rd: &renderdog::RenderDocInApp,
     window: &winit::window::Window,
 ) -> Result<bool, renderdog::InAppError> {
-    rd.end_frame_capture(None, renderdoc_window_handle(window))
+    rd.end_frame_capture_for(None, window)
 }
 
 /// Discard a RenderDoc frame capture using a winit window handle (no device pointer).
-#[cfg(windows)]
 pub fn discard_frame_capture_window(
     rd: &renderdog::RenderDocInApp,
     window: &winit::window::Window,
 ) -> Result<bool, renderdog::InAppError> {
-    rd.discard_frame_capture(None, renderdoc_window_handle(window))
+    rd.discard_frame_capture(None, Some(renderdog::window_handle(window)?))
 }
 
 #[cfg(test)]
@@ -0,0 +1,371 @@
+use renderdog_sys as sys;
+
+use crate::{Capabilities, CaptureOption, InAppError, InputButton, OverlayBits, RenderDocInApp};
+
+/// Object-safe view of the in-app API.
+///
+/// Lets code hold a `Box<dyn RenderDocApi>` that is either a live [`RenderDocInApp`] or an inert
+/// [`NoopRenderDoc`] (e.g. when RenderDoc is not present, or in unit tests).
+pub trait RenderDocApi {
+    fn get_api_version(&self) -> Result<(i32, i32, i32), InAppError>;
+    fn capabilities(&self) -> Capabilities;
+
+    fn set_capture_file_path_template(&self, template: &str) -> Result<(), InAppError>;
+    fn get_capture_file_path_template(&self) -> Result<String, InAppError>;
+
+    fn set_capture_option_u32(&self, opt: CaptureOption, val: u32) -> Result<bool, InAppError>;
+    fn set_capture_option_f32(&self, opt: CaptureOption, val: f32) -> Result<bool, InAppError>;
+    fn get_capture_option_u32(&self, opt: CaptureOption) -> Result<u32, InAppError>;
+    fn get_capture_option_f32(&self, opt: CaptureOption) -> Result<f32, InAppError>;
+
+    fn set_focus_toggle_keys(&self, keys: &[InputButton]) -> Result<(), InAppError>;
+    fn set_capture_keys(&self, keys: &[InputButton]) -> Result<(), InAppError>;
+
+    fn get_overlay_bits(&self) -> Result<OverlayBits, InAppError>;
+    fn mask_overlay_bits(&self, and_mask: u32, or_mask: u32) -> Result<(), InAppError>;
+
+    fn is_target_control_connected(&self) -> Result<bool, InAppError>;
+    fn launch_replay_ui(
+        &self,
+        connect_target_control: bool,
+        cmdline: Option<&str>,
+    ) -> Result<u32, InAppError>;
+    fn show_replay_ui(&self) -> Result<bool, InAppError>;
+
+    fn set_active_window(
+        &self,
+        device: Option<sys::RENDERDOC_DevicePointer>,
+        window: Option<sys::RENDERDOC_WindowHandle>,
+    ) -> Result<(), InAppError>;
+    fn trigger_capture(&self) -> Result<(), InAppError>;
+    fn trigger_multi_frame_capture(&self, frames: u32) -> Result<(), InAppError>;
+    fn start_frame_capture(
+        &self,
+        device: Option<sys::RENDERDOC_DevicePointer>,
+        window: Option<sys::RENDERDOC_WindowHandle>,
+    ) -> Result<(), InAppError>;
+    fn end_frame_capture(
+        &self,
+        device: Option<sys::RENDERDOC_DevicePointer>,
+        window: Option<sys::RENDERDOC_WindowHandle>,
+    ) -> Result<bool, InAppError>;
+    fn discard_frame_capture(
+        &self,
+        device: Option<sys::RENDERDOC_DevicePointer>,
+        window: Option<sys::RENDERDOC_WindowHandle>,
+    ) -> Result<bool, InAppError>;
+    fn is_frame_capturing(&self) -> Result<bool, InAppError>;
+
+    fn get_num_captures(&self) -> Result<u32, InAppError>;
+    fn get_capture(&self, idx: u32) -> Result<(String, u64), InAppError>;
+
+    fn set_capture_file_comments(
+        &self,
+        capture_file_path: Option<&str>,
+        comments: &str,
+    ) -> Result<(), InAppError>;
+    fn set_capture_title(&self, title: &str) -> Result<(), InAppError>;
+
+    fn unload_crash_handler(&self) -> Result<(), InAppError>;
+    fn remove_hooks(&self) -> Result<(), InAppError>;
+}
+
+impl RenderDocApi for RenderDocInApp {
+    fn get_api_version(&self) -> Result<(i32, i32, i32), InAppError> {
+        RenderDocInApp::get_api_version(self)
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        RenderDocInApp::capabilities(self)
+    }
+
+    fn set_capture_file_path_template(&self, template: &str) -> Result<(), InAppError> {
+        RenderDocInApp::set_capture_file_path_template(self, template)
+    }
+
+    fn get_capture_file_path_template(&self) -> Result<String, InAppError> {
+        RenderDocInApp::get_capture_file_path_template(self)
+    }
+
+    fn set_capture_option_u32(&self, opt: CaptureOption, val: u32) -> Result<bool, InAppError> {
+        RenderDocInApp::set_capture_option_u32(self, opt, val)
+    }
+
+    fn set_capture_option_f32(&self, opt: CaptureOption, val: f32) -> Result<bool, InAppError> {
+        RenderDocInApp::set_capture_option_f32(self, opt, val)
+    }
+
+    fn get_capture_option_u32(&self, opt: CaptureOption) -> Result<u32, InAppError> {
+        RenderDocInApp::get_capture_option_u32(self, opt)
+    }
+
+    fn get_capture_option_f32(&self, opt: CaptureOption) -> Result<f32, InAppError> {
+        RenderDocInApp::get_capture_option_f32(self, opt)
+    }
+
+    fn set_focus_toggle_keys(&self, keys: &[InputButton]) -> Result<(), InAppError> {
+        RenderDocInApp::set_focus_toggle_keys(self, keys)
+    }
+
+    fn set_capture_keys(&self, keys: &[InputButton]) -> Result<(), InAppError> {
+        RenderDocInApp::set_capture_keys(self, keys)
+    }
+
+    fn get_overlay_bits(&self) -> Result<OverlayBits, InAppError> {
+        RenderDocInApp::get_overlay_bits(self)
+    }
+
+    fn mask_overlay_bits(&self, and_mask: u32, or_mask: u32) -> Result<(), InAppError> {
+        RenderDocInApp::mask_overlay_bits(self, and_mask, or_mask)
+    }
+
+    fn is_target_control_connected(&self) -> Result<bool, InAppError> {
+        RenderDocInApp::is_target_control_connected(self)
+    }
+
+    fn launch_replay_ui(
+        &self,
+        connect_target_control: bool,
+        cmdline: Option<&str>,
+    ) -> Result<u32, InAppError> {
+        RenderDocInApp::launch_replay_ui(self, connect_target_control, cmdline)
+    }
+
+    fn show_replay_ui(&self) -> Result<bool, InAppError> {
+        RenderDocInApp::show_replay_ui(self)
+    }
+
+    fn set_active_window(
+        &self,
+        device: Option<sys::RENDERDOC_DevicePointer>,
+        window: Option<sys::RENDERDOC_WindowHandle>,
+    ) -> Result<(), InAppError> {
+        RenderDocInApp::set_active_window(self, device, window)
+    }
+
+    fn trigger_capture(&self) -> Result<(), InAppError> {
+        RenderDocInApp::trigger_capture(self)
+    }
+
+    fn trigger_multi_frame_capture(&self, frames: u32) -> Result<(), InAppError> {
+        RenderDocInApp::trigger_multi_frame_capture(self, frames)
+    }
+
+    fn start_frame_capture(
+        &self,
+        device: Option<sys::RENDERDOC_DevicePointer>,
+        window: Option<sys::RENDERDOC_WindowHandle>,
+    ) -> Result<(), InAppError> {
+        RenderDocInApp::start_frame_capture(self, device, window)
+    }
+
+    fn end_frame_capture(
+        &self,
+        device: Option<sys::RENDERDOC_DevicePointer>,
+        window: Option<sys::RENDERDOC_WindowHandle>,
+    ) -> Result<bool, InAppError> {
+        RenderDocInApp::end_frame_capture(self, device, window)
+    }
+
+    fn discard_frame_capture(
+        &self,
+        device: Option<sys::RENDERDOC_DevicePointer>,
+        window: Option<sys::RENDERDOC_WindowHandle>,
+    ) -> Result<bool, InAppError> {
+        RenderDocInApp::discard_frame_capture(self, device, window)
+    }
+
+    fn is_frame_capturing(&self) -> Result<bool, InAppError> {
+        RenderDocInApp::is_frame_capturing(self)
+    }
+
+    fn get_num_captures(&self) -> Result<u32, InAppError> {
+        RenderDocInApp::get_num_captures(self)
+    }
+
+    fn get_capture(&self, idx: u32) -> Result<(String, u64), InAppError> {
+        RenderDocInApp::get_capture(self, idx)
+    }
+
+    fn set_capture_file_comments(
+        &self,
+        capture_file_path: Option<&str>,
+        comments: &str,
+    ) -> Result<(), InAppError> {
+        RenderDocInApp::set_capture_file_comments(self, capture_file_path, comments)
+    }
+
+    fn set_capture_title(&self, title: &str) -> Result<(), InAppError> {
+        RenderDocInApp::set_capture_title(self, title)
+    }
+
+    fn unload_crash_handler(&self) -> Result<(), InAppError> {
+        RenderDocInApp::unload_crash_handler(self)
+    }
+
+    fn remove_hooks(&self) -> Result<(), InAppError> {
+        RenderDocInApp::remove_hooks(self)
+    }
+}
+
+/// A [`RenderDocApi`] that does nothing.
+///
+/// Every call succeeds; queries return empty/default values, options are reported as rejected,
+/// and no captures are ever produced.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoopRenderDoc;
+
+impl RenderDocApi for NoopRenderDoc {
+    fn get_api_version(&self) -> Result<(i32, i32, i32), InAppError> {
+        Ok((0, 0, 0))
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::default()
+    }
+
+    fn set_capture_file_path_template(&self, _template: &str) -> Result<(), InAppError> {
+        Ok(())
+    }
+
+    fn get_capture_file_path_template(&self) -> Result<String, InAppError> {
+        Ok(String::new())
+    }
+
+    fn set_capture_option_u32(&self, _opt: CaptureOption, _val: u32) -> Result<bool, InAppError> {
+        Ok(false)
+    }
+
+    fn set_capture_option_f32(&self, _opt: CaptureOption, _val: f32) -> Result<bool, InAppError> {
+        Ok(false)
+    }
+
+    fn get_capture_option_u32(&self, _opt: CaptureOption) -> Result<u32, InAppError> {
+        Ok(0)
+    }
+
+    fn get_capture_option_f32(&self, _opt: CaptureOption) -> Result<f32, InAppError> {
+        Ok(0.0)
+    }
+
+    fn set_focus_toggle_keys(&self, _keys: &[InputButton]) -> Result<(), InAppError> {
+        Ok(())
+    }
+
+    fn set_capture_keys(&self, _keys: &[InputButton]) -> Result<(), InAppError> {
+        Ok(())
+    }
+
+    fn get_overlay_bits(&self) -> Result<OverlayBits, InAppError> {
+        Ok(OverlayBits::empty())
+    }
+
+    fn mask_overlay_bits(&self, _and_mask: u32, _or_mask: u32) -> Result<(), InAppError> {
+        Ok(())
+    }
+
+    fn is_target_control_connected(&self) -> Result<bool, InAppError> {
+        Ok(false)
+    }
+
+    fn launch_replay_ui(
+        &self,
+        _connect_target_control: bool,
+        _cmdline: Option<&str>,
+    ) -> Result<u32, InAppError> {
+        Ok(0)
+    }
+
+    fn show_replay_ui(&self) -> Result<bool, InAppError> {
+        Ok(false)
+    }
+
+    fn set_active_window(
+        &self,
+        _device: Option<sys::RENDERDOC_DevicePointer>,
+        _window: Option<sys::RENDERDOC_WindowHandle>,
+    ) -> Result<(), InAppError> {
+        Ok(())
+    }
+
+    fn trigger_capture(&self) -> Result<(), InAppError> {
+        Ok(())
+    }
+
+    fn trigger_multi_frame_capture(&self, _frames: u32) -> Result<(), InAppError> {
+        Ok(())
+    }
+
+    fn start_frame_capture(
+        &self,
+        _device: Option<sys::RENDERDOC_DevicePointer>,
+        _window: Option<sys::RENDERDOC_WindowHandle>,
+    ) -> Result<(), InAppError> {
+        Ok(())
+    }
+
+    fn end_frame_capture(
+        &self,
+        _device: Option<sys::RENDERDOC_DevicePointer>,
+        _window: Option<sys::RENDERDOC_WindowHandle>,
+    ) -> Result<bool, InAppError> {
+        Ok(false)
+    }
+
+    fn discard_frame_capture(
+        &self,
+        _device: Option<sys::RENDERDOC_DevicePointer>,
+        _window: Option<sys::RENDERDOC_WindowHandle>,
+    ) -> Result<bool, InAppError> {
+        Ok(false)
+    }
+
+    fn is_frame_capturing(&self) -> Result<bool, InAppError> {
+        Ok(false)
+    }
+
+    fn get_num_captures(&self) -> Result<u32, InAppError> {
+        Ok(0)
+    }
+
+    fn get_capture(&self, _idx: u32) -> Result<(String, u64), InAppError> {
+        Err(InAppError::InvalidCaptureIndex)
+    }
+
+    fn set_capture_file_comments(
+        &self,
+        _capture_file_path: Option<&str>,
+        _comments: &str,
+    ) -> Result<(), InAppError> {
+        Ok(())
+    }
+
+    fn set_capture_title(&self, _title: &str) -> Result<(), InAppError> {
+        Ok(())
+    }
+
+    fn unload_crash_handler(&self) -> Result<(), InAppError> {
+        Ok(())
+    }
+
+    fn remove_hooks(&self) -> Result<(), InAppError> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn noop_is_inert_behind_dyn() {
+        let rd: Box<dyn RenderDocApi> = Box::new(NoopRenderDoc);
+        rd.start_frame_capture(None, None).unwrap();
+        assert!(!rd.end_frame_capture(None, None).unwrap());
+        assert_eq!(rd.get_num_captures().unwrap(), 0);
+        assert!(matches!(
+            rd.get_capture(0),
+            Err(InAppError::InvalidCaptureIndex)
+        ));
+    }
+}
@@ -0,0 +1,141 @@
+use std::{
+    future::Future,
+    marker::PhantomData,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use crate::{InAppError, RenderDocInApp};
+
+const ASYNC_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// The capture-list entry points of a [`RenderDocInApp`], resolved before the first `.await`.
+///
+/// `&RenderDocInApp` is `!Send` (the handle is `!Sync`), so the futures below hold this instead and
+/// stay `Send`. The lifetime keeps the RenderDoc module loaded while they are pending.
+#[derive(Clone, Copy)]
+struct CaptureList<'a> {
+    #[cfg(not(feature = "disabled"))]
+    get_num_captures: unsafe extern "C" fn() -> u32,
+    #[cfg(not(feature = "disabled"))]
+    get_capture: crate::in_app::GetCaptureFn,
+    _app: PhantomData<&'a ()>,
+}
+
+impl<'a> CaptureList<'a> {
+    #[cfg(not(feature = "disabled"))]
+    fn new(app: &'a RenderDocInApp) -> Result<Self, InAppError> {
+        let api = app.api();
+        Ok(Self {
+            get_num_captures: api
+                .GetNumCaptures
+                .ok_or_else(|| app.missing_function("GetNumCaptures"))?,
+            get_capture: api
+                .GetCapture
+                .ok_or_else(|| app.missing_function("GetCapture"))?,
+            _app: PhantomData,
+        })
+    }
+
+    #[cfg(feature = "disabled")]
+    fn new(_app: &'a RenderDocInApp) -> Result<Self, InAppError> {
+        Ok(Self { _app: PhantomData })
+    }
+
+    #[cfg(not(feature = "disabled"))]
+    fn num_captures(&self) -> u32 {
+        unsafe { (self.get_num_captures)() }
+    }
+
+    #[cfg(feature = "disabled")]
+    fn num_captures(&self) -> u32 {
+        0
+    }
+
+    #[cfg(not(feature = "disabled"))]
+    fn capture_path(&self, idx: u32) -> Result<PathBuf, InAppError> {
+        let (path, _) = crate::in_app::read_capture(self.get_capture, idx)?;
+        Ok(PathBuf::from(path))
+    }
+
+    #[cfg(feature = "disabled")]
+    fn capture_path(&self, _idx: u32) -> Result<PathBuf, InAppError> {
+        Err(InAppError::InvalidCaptureIndex)
+    }
+}
+
+async fn poll_new_capture(
+    captures: CaptureList<'_>,
+    baseline_count: u32,
+    timeout: Duration,
+) -> Result<PathBuf, InAppError> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        let count = captures.num_captures();
+        if count > baseline_count {
+            let path = captures.capture_path(baseline_count)?;
+            if tokio::fs::try_exists(&path)
+                .await
+                .map_err(InAppError::CheckCaptureFile)?
+            {
+                return Ok(path);
+            }
+        }
+        if Instant::now() >= deadline {
+            return Err(InAppError::CaptureTimeout(timeout));
+        }
+        tokio::time::sleep(ASYNC_POLL_INTERVAL).await;
+    }
+}
+
+impl RenderDocInApp {
+    /// Async version of [`wait_for_new_capture`](Self::wait_for_new_capture).
+    ///
+    /// Resolves with the first capture past `baseline_count` (rather than the newest) once its
+    /// `.rdc` exists on disk. Polls on the tokio timer instead of blocking the worker thread. The
+    /// returned future does not hold `&self` and is `Send`, so it can be spawned on a
+    /// multi-threaded runtime.
+    pub fn wait_for_new_capture_async(
+        &self,
+        baseline_count: u32,
+        timeout: Duration,
+    ) -> impl Future<Output = Result<PathBuf, InAppError>> + Send + '_ {
+        let captures = CaptureList::new(self);
+        async move { poll_new_capture(captures?, baseline_count, timeout).await }
+    }
+
+    /// Trigger a capture of the next frame and resolve with its path once written.
+    ///
+    /// The capture is triggered before this returns; only the wait happens in the future.
+    pub fn trigger_capture_async(
+        &self,
+        timeout: Duration,
+    ) -> impl Future<Output = Result<PathBuf, InAppError>> + Send + '_ {
+        let started = CaptureList::new(self).and_then(|captures| {
+            let baseline = captures.num_captures();
+            self.trigger_capture()?;
+            Ok((captures, baseline))
+        });
+        async move {
+            let (captures, baseline) = started?;
+            poll_new_capture(captures, baseline, timeout).await
+        }
+    }
+
+    /// Trigger a capture of the next `frames` frames and resolve with the first new capture path.
+    pub fn trigger_multi_frame_capture_async(
+        &self,
+        frames: u32,
+        timeout: Duration,
+    ) -> impl Future<Output = Result<PathBuf, InAppError>> + Send + '_ {
+        let started = CaptureList::new(self).and_then(|captures| {
+            let baseline = captures.num_captures();
+            self.trigger_multi_frame_capture(frames)?;
+            Ok((captures, baseline))
+        });
+        async move {
+            let (captures, baseline) = started?;
+            poll_new_capture(captures, baseline, timeout).await
+        }
+    }
+}
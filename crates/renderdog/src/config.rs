@@ -0,0 +1,258 @@
+use crate::{
+    CaptureOption, CaptureOptions, CaptureTemplate, InAppError, InputButton, OverlayBits,
+    RenderDocApi, RenderDocInApp, parse_input_buttons,
+};
+
+/// Capture path template (`RENDERDOG_CAPTURE_TEMPLATE`).
+pub const ENV_CAPTURE_TEMPLATE: &str = "RENDERDOG_CAPTURE_TEMPLATE";
+/// Comma-separated capture keys, e.g. `F12,PrtScrn` (`RENDERDOG_CAPTURE_KEYS`).
+pub const ENV_CAPTURE_KEYS: &str = "RENDERDOG_CAPTURE_KEYS";
+/// Comma-separated focus toggle keys (`RENDERDOG_FOCUS_TOGGLE_KEYS`).
+pub const ENV_FOCUS_TOGGLE_KEYS: &str = "RENDERDOG_FOCUS_TOGGLE_KEYS";
+/// Overlay bits: `none`, or a comma/`|`-separated list such as `enabled,frame_rate`
+/// (`RENDERDOG_OVERLAY`).
+pub const ENV_OVERLAY: &str = "RENDERDOG_OVERLAY";
+/// Prefix for capture option toggles, followed by the upper-cased [`CaptureOptions`] field name,
+/// e.g. `RENDERDOG_OPT_API_VALIDATION=1` or `RENDERDOG_OPT_SOFT_MEMORY_LIMIT_MB=2048`.
+pub const ENV_OPTION_PREFIX: &str = "RENDERDOG_OPT_";
+
+/// Capture settings that can be applied to a [`RenderDocApi`] in one call.
+///
+/// Fields left as `None` are not touched when applying.
+///
+/// With the `toml` feature, a checked-in file can be loaded via [`CaptureConfig::from_toml_file`]:
+///
+/// ```toml
+/// capture_file_path_template = "artifacts/renderdoc/{run_id}/{exe}"
+/// capture_keys = ["F12"]
+/// overlay = "ENABLED | CAPTURE_LIST"
+///
+/// [options]
+/// api_validation = true
+/// soft_memory_limit_mb = 2048
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct CaptureConfig {
+    /// Expanded through [`CaptureTemplate`] (`{timestamp}`, `{exe}`, `{pid}`, `{git_hash}`,
+    /// `{run_id}`) when applied; the directory is created.
+    pub capture_file_path_template: Option<String>,
+    pub capture_keys: Option<Vec<InputButton>>,
+    pub focus_toggle_keys: Option<Vec<InputButton>>,
+    pub overlay: Option<OverlayBits>,
+    pub options: CaptureOptions,
+}
+
+impl CaptureConfig {
+    /// Read the documented `RENDERDOG_*` environment variables.
+    pub fn from_env() -> Result<Self, InAppError> {
+        Self::from_lookup(|name| std::env::var(name).ok())
+    }
+
+    /// Load settings from a TOML file.
+    #[cfg(feature = "toml")]
+    pub fn from_toml_file(path: impl AsRef<std::path::Path>) -> Result<Self, InAppError> {
+        let text = std::fs::read_to_string(path).map_err(InAppError::ReadConfig)?;
+        Self::from_toml_str(&text)
+    }
+
+    /// Parse settings from TOML text.
+    #[cfg(feature = "toml")]
+    pub fn from_toml_str(text: &str) -> Result<Self, InAppError> {
+        toml::from_str(text).map_err(|e| InAppError::ParseConfig(e.to_string()))
+    }
+
+    fn from_lookup(get: impl Fn(&str) -> Option<String>) -> Result<Self, InAppError> {
+        let invalid = |key: &str, value: &str| InAppError::InvalidConfigValue {
+            key: key.to_string(),
+            value: value.to_string(),
+        };
+
+        let mut cfg = Self {
+            capture_file_path_template: get(ENV_CAPTURE_TEMPLATE).filter(|s| !s.trim().is_empty()),
+            ..Self::default()
+        };
+        if let Some(v) = &cfg.capture_file_path_template {
+            CaptureTemplate::new(v.as_str())
+                .expand()
+                .map_err(|_| invalid(ENV_CAPTURE_TEMPLATE, v))?;
+        }
+
+        if let Some(v) = get(ENV_CAPTURE_KEYS) {
+            cfg.capture_keys =
+                Some(parse_input_buttons(&v).map_err(|_| invalid(ENV_CAPTURE_KEYS, &v))?);
+        }
+        if let Some(v) = get(ENV_FOCUS_TOGGLE_KEYS) {
+            cfg.focus_toggle_keys =
+                Some(parse_input_buttons(&v).map_err(|_| invalid(ENV_FOCUS_TOGGLE_KEYS, &v))?);
+        }
+        if let Some(v) = get(ENV_OVERLAY) {
+            cfg.overlay = Some(parse_overlay(&v).ok_or_else(|| invalid(ENV_OVERLAY, &v))?);
+        }
+        for name in CaptureOptions::NAMES {
+            let key = format!("{ENV_OPTION_PREFIX}{}", name.to_ascii_uppercase());
+            if let Some(v) = get(&key)
+                && !cfg.options.set_by_name(name, &v)
+            {
+                return Err(invalid(&key, &v));
+            }
+        }
+
+        Ok(cfg)
+    }
+
+    /// Apply every set field, returning the capture options RenderDoc rejected.
+    pub fn apply<R: RenderDocApi + ?Sized>(
+        &self,
+        rd: &R,
+    ) -> Result<Vec<CaptureOption>, InAppError> {
+        if let Some(template) = &self.capture_file_path_template {
+            let mut template_builder = CaptureTemplate::new(template.as_str());
+            if template.contains("{git_hash}") {
+                template_builder = template_builder.git_hash_from_repo();
+            }
+            template_builder.apply(rd)?;
+        }
+        if let Some(keys) = &self.capture_keys {
+            rd.set_capture_keys(keys)?;
+        }
+        if let Some(keys) = &self.focus_toggle_keys {
+            rd.set_focus_toggle_keys(keys)?;
+        }
+        if let Some(overlay) = self.overlay {
+            rd.mask_overlay_bits(0, overlay.bits())?;
+        }
+        self.options.apply(rd)
+    }
+}
+
+impl RenderDocInApp {
+    /// Apply capture settings from `RENDERDOG_*` environment variables (see [`CaptureConfig`]).
+    ///
+    /// Lets QA tweak capture behavior without rebuilding the application. Returns the capture
+    /// options RenderDoc rejected.
+    pub fn apply_env_config(&self) -> Result<Vec<CaptureOption>, InAppError> {
+        CaptureConfig::from_env()?.apply(self)
+    }
+}
+
+fn parse_overlay(s: &str) -> Option<OverlayBits> {
+    let s = s.trim();
+    if s.eq_ignore_ascii_case("none") {
+        return Some(OverlayBits::empty());
+    }
+    s.split([',', '|'])
+        .map(str::trim)
+        .filter(|n| !n.is_empty())
+        .try_fold(OverlayBits::empty(), |acc, name| {
+            Some(acc | OverlayBits::from_name(&name.to_ascii_uppercase())?)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn capture_config_reads_env_vars() {
+        let env = HashMap::from([
+            (ENV_CAPTURE_TEMPLATE, "captures/{run_id}/{exe}"),
+            (ENV_CAPTURE_KEYS, "F12, prtscrn"),
+            (ENV_OVERLAY, "enabled|capture_list"),
+            ("RENDERDOG_OPT_API_VALIDATION", "on"),
+            ("RENDERDOG_OPT_SOFT_MEMORY_LIMIT_MB", "512"),
+        ]);
+        let cfg = CaptureConfig::from_lookup(|k| env.get(k).map(|v| v.to_string())).unwrap();
+
+        assert_eq!(
+            cfg.capture_file_path_template.as_deref(),
+            Some("captures/{run_id}/{exe}")
+        );
+        assert_eq!(
+            cfg.capture_keys,
+            Some(vec![InputButton::F12, InputButton::PrtScrn])
+        );
+        assert_eq!(cfg.focus_toggle_keys, None);
+        assert_eq!(
+            cfg.overlay,
+            Some(OverlayBits::ENABLED | OverlayBits::CAPTURE_LIST)
+        );
+        assert_eq!(cfg.options.api_validation, Some(true));
+        assert_eq!(cfg.options.soft_memory_limit_mb, Some(512));
+    }
+
+    #[test]
+    fn capture_config_rejects_invalid_values() {
+        let env = HashMap::from([("RENDERDOG_OPT_API_VALIDATION", "maybe")]);
+        let err = CaptureConfig::from_lookup(|k| env.get(k).map(|v| v.to_string())).unwrap_err();
+        assert!(matches!(err, InAppError::InvalidConfigValue { .. }));
+
+        let env = HashMap::from([(ENV_CAPTURE_TEMPLATE, "captures/{app}_{frame}")]);
+        let err = CaptureConfig::from_lookup(|k| env.get(k).map(|v| v.to_string())).unwrap_err();
+        assert!(
+            matches!(err, InAppError::InvalidConfigValue { key, .. } if key == ENV_CAPTURE_TEMPLATE)
+        );
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn capture_config_parses_toml() {
+        let cfg = CaptureConfig::from_toml_str(
+            r#"
+            capture_keys = ["F12"]
+            overlay = "ENABLED | CAPTURE_LIST"
+
+            [options]
+            api_validation = true
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(cfg.capture_keys, Some(vec![InputButton::F12]));
+        assert_eq!(
+            cfg.overlay,
+            Some(OverlayBits::ENABLED | OverlayBits::CAPTURE_LIST)
+        );
+        assert_eq!(cfg.options.api_validation, Some(true));
+        assert_eq!(cfg.capture_file_path_template, None);
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn capture_config_applies_through_api_trait() {
+        use crate::{MockCall, MockRenderDoc, RenderDocApi};
+
+        let dir = std::env::temp_dir().join(format!("renderdog-config-{}", std::process::id()));
+        let cfg = CaptureConfig {
+            capture_file_path_template: Some(format!("{}/{{pid}}/app", dir.display())),
+            capture_keys: Some(vec![InputButton::F12]),
+            overlay: Some(OverlayBits::ENABLED),
+            options: CaptureOptions::builder().api_validation(true).build(),
+            ..CaptureConfig::default()
+        };
+        let rd = MockRenderDoc::new();
+        let api: &dyn RenderDocApi = &rd;
+        assert!(cfg.apply(api).unwrap().is_empty());
+        assert_eq!(
+            rd.calls(),
+            [
+                MockCall::SetCaptureFilePathTemplate(format!(
+                    "{}/{}/app",
+                    dir.display(),
+                    std::process::id()
+                )),
+                MockCall::SetCaptureKeys(vec![InputButton::F12]),
+                MockCall::MaskOverlayBits {
+                    and_mask: 0,
+                    or_mask: OverlayBits::ENABLED.bits(),
+                },
+                MockCall::SetCaptureOptionU32(CaptureOption::ApiValidation, 1),
+            ]
+        );
+        assert!(dir.join(std::process::id().to_string()).is_dir());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,108 @@
+use std::ffi::c_void;
+
+use renderdog_sys as sys;
+
+/// Equivalent of the `RENDERDOC_DEVICEPOINTER_FROM_VKINSTANCE` macro.
+///
+/// RenderDoc identifies a Vulkan device by the dispatch table pointer stored at the start of the
+/// dispatchable `VkInstance` handle, not by the handle itself.
+///
+/// # Safety
+///
+/// `instance` must be a valid, non-null `VkInstance` that outlives this call.
+pub unsafe fn device_pointer_from_vk_instance(
+    instance: *mut c_void,
+) -> sys::RENDERDOC_DevicePointer {
+    unsafe { *(instance as *mut *mut c_void) }
+}
+
+/// Safe variant of [`device_pointer_from_vk_instance`] for an `ash` instance.
+#[cfg(feature = "ash")]
+pub fn device_pointer_from_ash_instance(instance: &ash::Instance) -> sys::RENDERDOC_DevicePointer {
+    use ash::vk::Handle;
+
+    // SAFETY: `ash::Instance` is only constructed from a successfully created `VkInstance`, and
+    // the borrow keeps it alive for the duration of the call.
+    unsafe { device_pointer_from_vk_instance(instance.handle().as_raw() as usize as *mut c_void) }
+}
+
+/// A typed RenderDoc device pointer.
+///
+/// RenderDoc identifies the device a capture call applies to by an API-specific pointer. The
+/// constructors document which object each API expects and reject null pointers (use `None` in
+/// the capture calls to mean "any device").
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct DevicePointer(sys::RENDERDOC_DevicePointer);
+
+impl DevicePointer {
+    /// From an `ID3D11Device*` (not an `ID3D11DeviceContext*`).
+    #[cfg(windows)]
+    pub fn from_d3d11_device(device: *mut c_void) -> Option<Self> {
+        Self::from_non_null(device)
+    }
+
+    /// From an `ID3D12Device*` (not a command queue or command list).
+    #[cfg(windows)]
+    pub fn from_d3d12_device(device: *mut c_void) -> Option<Self> {
+        Self::from_non_null(device)
+    }
+
+    /// From an OpenGL `HGLRC` rendering context.
+    #[cfg(windows)]
+    pub fn from_wgl_context(hglrc: *mut c_void) -> Option<Self> {
+        Self::from_non_null(hglrc)
+    }
+
+    /// From a `VkInstance`; see [`device_pointer_from_vk_instance`].
+    ///
+    /// # Safety
+    ///
+    /// `instance` must be null or a valid `VkInstance` that outlives this call.
+    pub unsafe fn from_vk_instance(instance: *mut c_void) -> Option<Self> {
+        if instance.is_null() {
+            return None;
+        }
+        Self::from_non_null(unsafe { device_pointer_from_vk_instance(instance) })
+    }
+
+    /// Wrap a raw device pointer that is already in the form RenderDoc expects.
+    pub fn from_raw(ptr: sys::RENDERDOC_DevicePointer) -> Option<Self> {
+        Self::from_non_null(ptr)
+    }
+
+    pub fn as_raw(self) -> sys::RENDERDOC_DevicePointer {
+        self.0
+    }
+
+    fn from_non_null(ptr: *mut c_void) -> Option<Self> {
+        (!ptr.is_null()).then_some(Self(ptr))
+    }
+}
+
+impl From<DevicePointer> for sys::RENDERDOC_DevicePointer {
+    fn from(value: DevicePointer) -> Self {
+        value.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vk_instance_device_pointer_reads_dispatch_table() {
+        let mut dispatch_table = 0u64;
+        let dispatch_ptr = &mut dispatch_table as *mut u64 as *mut c_void;
+        let mut instance = dispatch_ptr;
+        let handle = &mut instance as *mut *mut c_void as *mut c_void;
+
+        let device = unsafe { device_pointer_from_vk_instance(handle) };
+        assert_eq!(device, dispatch_ptr);
+    }
+
+    #[test]
+    fn device_pointer_rejects_null() {
+        assert!(DevicePointer::from_raw(std::ptr::null_mut()).is_none());
+        assert!(unsafe { DevicePointer::from_vk_instance(std::ptr::null_mut()) }.is_none());
+    }
+}
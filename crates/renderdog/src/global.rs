@@ -0,0 +1,21 @@
+use std::sync::{Mutex, OnceLock};
+
+use crate::RenderDocInApp;
+
+static INSTANCE: OnceLock<Option<Mutex<RenderDocInApp>>> = OnceLock::new();
+
+/// Process-wide RenderDoc handle, connected on first use.
+///
+/// The first call runs [`RenderDocInApp::try_connect_or_load_default`]; later calls return the
+/// cached result without re-negotiating the API. Returns `None` when RenderDoc is not available.
+///
+/// The handle is `!Sync`, so it is wrapped in a `Mutex` to serialize calls across threads.
+pub fn instance() -> Option<&'static Mutex<RenderDocInApp>> {
+    INSTANCE
+        .get_or_init(|| {
+            RenderDocInApp::try_connect_or_load_default()
+                .ok()
+                .map(Mutex::new)
+        })
+        .as_ref()
+}
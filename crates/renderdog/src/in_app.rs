@@ -1,33 +1,84 @@
 use std::{
     cell::Cell,
-    ffi::{CStr, CString},
     path::{Path, PathBuf},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+#[cfg(not(feature = "disabled"))]
+use std::{
+    ffi::{CStr, CString},
     ptr::NonNull,
-    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
 use thiserror::Error;
 
 use renderdog_sys as sys;
 
-use crate::OverlayBits;
+use crate::{CaptureOption, OverlayBits, RenderDocApi};
 
-#[cfg(unix)]
+#[cfg(all(unix, not(feature = "disabled")))]
 use libloading::Library;
 
-#[cfg(windows)]
+#[cfg(all(windows, not(feature = "disabled")))]
 use windows_sys::Win32::Foundation::GetLastError;
 
-#[cfg(windows)]
+#[cfg(all(windows, not(feature = "disabled")))]
 use windows_sys::Win32::Foundation::FreeLibrary;
 
-#[cfg(windows)]
+#[cfg(all(windows, not(feature = "disabled")))]
 use windows_sys::Win32::System::LibraryLoader::{GetModuleHandleA, GetProcAddress, LoadLibraryA};
 
 pub use renderdog_sys::{
     RENDERDOC_CaptureOption, RENDERDOC_DevicePointer, RENDERDOC_InputButton, RENDERDOC_WindowHandle,
 };
 
+const CAPTURE_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// How long [`RenderDocInApp::with_frame_capture`] waits for the capture file to be reported.
+pub const DEFAULT_CAPTURE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Entry points added after API 1.0.0, with the version that introduced them.
+const FUNCTION_MIN_VERSIONS: &[(&str, sys::RENDERDOC_Version)] = &[
+    (
+        "TriggerMultiFrameCapture",
+        sys::RENDERDOC_Version::eRENDERDOC_API_Version_1_1_0,
+    ),
+    (
+        "SetCaptureFileComments",
+        sys::RENDERDOC_Version::eRENDERDOC_API_Version_1_2_0,
+    ),
+    (
+        "DiscardFrameCapture",
+        sys::RENDERDOC_Version::eRENDERDOC_API_Version_1_4_0,
+    ),
+    (
+        "ShowReplayUI",
+        sys::RENDERDOC_Version::eRENDERDOC_API_Version_1_5_0,
+    ),
+    (
+        "SetCaptureTitle",
+        sys::RENDERDOC_Version::eRENDERDOC_API_Version_1_6_0,
+    ),
+];
+
+/// Minimum in-app API version that provides the named entry point.
+pub fn function_min_version(name: &str) -> sys::RENDERDOC_Version {
+    FUNCTION_MIN_VERSIONS
+        .iter()
+        .find(|(n, _)| *n == name)
+        .map_or(
+            sys::RENDERDOC_Version::eRENDERDOC_API_Version_1_0_0,
+            |(_, v)| *v,
+        )
+}
+
+/// Format an API version as `major.minor.patch` (e.g. `1.6.0`).
+pub fn version_string(version: sys::RENDERDOC_Version) -> String {
+    let v = version.0;
+    format!("{}.{}.{}", v / 10000, v / 100 % 100, v % 100)
+}
+
+#[cfg(not(feature = "disabled"))]
 #[derive(Debug)]
 enum LibraryGuard {
     #[cfg(windows)]
@@ -41,7 +92,7 @@ enum LibraryGuard {
     },
 }
 
-#[cfg(windows)]
+#[cfg(all(windows, not(feature = "disabled")))]
 impl Drop for LibraryGuard {
     fn drop(&mut self) {
         if let LibraryGuard::WindowsOwned(module) = self {
@@ -75,8 +126,16 @@ pub enum InAppError {
     #[error("RENDERDOC_GetAPI failed for all requested versions")]
     GetApiFailedAllVersions,
 
-    #[error("required API function pointer is null: {0}")]
-    MissingFunction(&'static str),
+    #[error(
+        "RenderDoc API function {name} is unavailable (requires API {}, negotiated {})",
+        version_string(*.required),
+        version_string(*.negotiated)
+    )]
+    MissingFunction {
+        name: &'static str,
+        required: sys::RENDERDOC_Version,
+        negotiated: sys::RENDERDOC_Version,
+    },
 
     #[error("capture index out of range")]
     InvalidCaptureIndex,
@@ -86,15 +145,83 @@ pub enum InAppError {
 
     #[error("invalid UTF-8 from RenderDoc")]
     InvalidUtf8,
+
+    #[error("window handle is not available: {0}")]
+    WindowHandleUnavailable(String),
+
+    #[error("unsupported window handle backend: {0}")]
+    UnsupportedWindowHandle(&'static str),
+
+    #[error("timed out after {0:?} waiting for a new capture")]
+    CaptureTimeout(Duration),
+
+    #[error("failed to check for capture file: {0}")]
+    CheckCaptureFile(std::io::Error),
+
+    #[error("EndFrameCapture reported failure")]
+    EndFrameCaptureFailed,
+
+    #[error("invalid value for {key}: {value:?}")]
+    InvalidConfigValue { key: String, value: String },
+
+    #[error("failed to read config file: {0}")]
+    ReadConfig(std::io::Error),
+
+    #[error("failed to parse config: {0}")]
+    ParseConfig(String),
+
+    #[error("failed to create capture directory: {0}")]
+    CreateCaptureDir(std::io::Error),
+
+    #[error("RenderDoc rejected capture option {option:?} = {value}")]
+    CaptureOptionRejected { option: CaptureOption, value: u32 },
+
+    #[error("LaunchReplayUI failed to start the replay UI")]
+    LaunchReplayUiFailed,
+
+    #[error("replay UI (pid {pid}) did not connect within {timeout:?}")]
+    ReplayUiConnectTimeout { pid: u32, timeout: Duration },
 }
 
+/// Optional in-app API entry points usable on a [`RenderDocInApp`] handle.
+///
+/// Each flag requires both the negotiated API version to include the function and the function
+/// pointer to be non-null. With the `disabled` feature every flag is `false`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Capabilities {
+    /// `TriggerMultiFrameCapture` (1.1.0).
+    pub has_trigger_multi_frame_capture: bool,
+    /// `SetCaptureFileComments` (1.2.0).
+    pub has_set_capture_file_comments: bool,
+    /// `DiscardFrameCapture` (1.4.0).
+    pub has_discard_frame_capture: bool,
+    /// `ShowReplayUI` (1.5.0).
+    pub has_show_replay_ui: bool,
+    /// `SetCaptureTitle` (1.6.0).
+    pub has_set_capture_title: bool,
+}
+
+/// Handle to RenderDoc's in-application API.
+///
+/// With the `disabled` feature, no library is loaded: every constructor succeeds and every call
+/// is an inlined no-op returning `Ok` with a default value.
 pub struct RenderDocInApp {
+    #[cfg(not(feature = "disabled"))]
     api: NonNull<sys::RENDERDOC_API_1_6_0>,
+    #[cfg(not(feature = "disabled"))]
     _guard: LibraryGuard,
+    #[cfg(not(feature = "disabled"))]
     requested_version: sys::RENDERDOC_Version,
     _not_sync: Cell<()>,
 }
 
+// SAFETY: the API table is owned by the loaded RenderDoc module, which `_guard` keeps alive for
+// the lifetime of this handle, and RenderDoc's in-app entry points may be called from any thread.
+// The handle stays `!Sync` so concurrent calls must still be serialized by the caller.
+#[cfg(not(feature = "disabled"))]
+unsafe impl Send for RenderDocInApp {}
+
+#[cfg(not(feature = "disabled"))]
 impl RenderDocInApp {
     pub fn try_connect() -> Result<Self, InAppError> {
         #[cfg(windows)]
@@ -163,6 +290,7 @@ impl RenderDocInApp {
         #[cfg(windows)]
         {
             Self::try_load_and_connect("renderdoc.dll")
+                .or_else(|e| Self::try_load_from_installation().map_err(|_| e))
         }
 
         #[cfg(unix)]
@@ -172,7 +300,7 @@ impl RenderDocInApp {
                     return Ok(v);
                 }
             }
-            Err(InAppError::NotAvailable)
+            Self::try_load_from_installation()
         }
 
         #[cfg(not(any(windows, unix)))]
@@ -282,7 +410,7 @@ impl RenderDocInApp {
         })
     }
 
-    fn api(&self) -> &sys::RENDERDOC_API_1_6_0 {
+    pub(crate) fn api(&self) -> &sys::RENDERDOC_API_1_6_0 {
         unsafe { self.api.as_ref() }
     }
 
@@ -290,11 +418,41 @@ impl RenderDocInApp {
         self.requested_version
     }
 
+    pub fn capabilities(&self) -> Capabilities {
+        let api = self.api();
+        // Entries past the negotiated version lie beyond the end of the table RenderDoc handed
+        // out, so the field is only read once the version check has passed.
+        let has = |name: &str, present: &dyn Fn() -> bool| {
+            self.requested_version.0 >= function_min_version(name).0 && present()
+        };
+        Capabilities {
+            has_trigger_multi_frame_capture: has("TriggerMultiFrameCapture", &|| {
+                api.TriggerMultiFrameCapture.is_some()
+            }),
+            has_set_capture_file_comments: has("SetCaptureFileComments", &|| {
+                api.SetCaptureFileComments.is_some()
+            }),
+            has_discard_frame_capture: has("DiscardFrameCapture", &|| {
+                api.DiscardFrameCapture.is_some()
+            }),
+            has_show_replay_ui: has("ShowReplayUI", &|| api.ShowReplayUI.is_some()),
+            has_set_capture_title: has("SetCaptureTitle", &|| api.SetCaptureTitle.is_some()),
+        }
+    }
+
+    pub(crate) fn missing_function(&self, name: &'static str) -> InAppError {
+        InAppError::MissingFunction {
+            name,
+            required: function_min_version(name),
+            negotiated: self.requested_version,
+        }
+    }
+
     pub fn get_api_version(&self) -> Result<(i32, i32, i32), InAppError> {
         let f = self
             .api()
             .GetAPIVersion
-            .ok_or(InAppError::MissingFunction("GetAPIVersion"))?;
+            .ok_or_else(|| self.missing_function("GetAPIVersion"))?;
         let mut major = 0;
         let mut minor = 0;
         let mut patch = 0;
@@ -304,7 +462,7 @@ impl RenderDocInApp {
 
     pub fn set_capture_file_path_template(&self, template: &str) -> Result<(), InAppError> {
         let f = unsafe { self.api().__bindgen_anon_2.SetCaptureFilePathTemplate }
-            .ok_or(InAppError::MissingFunction("SetCaptureFilePathTemplate"))?;
+            .ok_or_else(|| self.missing_function("SetCaptureFilePathTemplate"))?;
         let s = CString::new(template).map_err(|_| InAppError::InvalidUtf8)?;
         unsafe { f(s.as_ptr()) };
         Ok(())
@@ -312,7 +470,7 @@ impl RenderDocInApp {
 
     pub fn get_capture_file_path_template(&self) -> Result<String, InAppError> {
         let f = unsafe { self.api().__bindgen_anon_3.GetCaptureFilePathTemplate }
-            .ok_or(InAppError::MissingFunction("GetCaptureFilePathTemplate"))?;
+            .ok_or_else(|| self.missing_function("GetCaptureFilePathTemplate"))?;
         let ptr = unsafe { f() };
         if ptr.is_null() {
             return Ok(String::new());
@@ -323,47 +481,6 @@ impl RenderDocInApp {
         Ok(s.to_string())
     }
 
-    pub fn get_capture_file_path_template_path(&self) -> Result<PathBuf, InAppError> {
-        Ok(PathBuf::from(self.get_capture_file_path_template()?))
-    }
-
-    pub fn set_capture_file_path_template_path<P: AsRef<Path>>(
-        &self,
-        template: P,
-    ) -> Result<(), InAppError> {
-        let s = template.as_ref().to_str().ok_or(InAppError::InvalidUtf8)?;
-        self.set_capture_file_path_template(s)
-    }
-
-    #[deprecated(since = "0.1.0", note = "renamed to get_capture_file_path_template")]
-    pub fn get_log_file_path_template(&self) -> Result<String, InAppError> {
-        self.get_capture_file_path_template()
-    }
-
-    #[deprecated(since = "0.1.0", note = "renamed to set_capture_file_path_template")]
-    pub fn set_log_file_path_template(&self, template: &str) -> Result<(), InAppError> {
-        self.set_capture_file_path_template(template)
-    }
-
-    #[deprecated(
-        since = "0.1.0",
-        note = "renamed to get_capture_file_path_template_path"
-    )]
-    pub fn get_log_file_path_template_path(&self) -> Result<PathBuf, InAppError> {
-        self.get_capture_file_path_template_path()
-    }
-
-    #[deprecated(
-        since = "0.1.0",
-        note = "renamed to set_capture_file_path_template_path"
-    )]
-    pub fn set_log_file_path_template_path<P: AsRef<Path>>(
-        &self,
-        template: P,
-    ) -> Result<(), InAppError> {
-        self.set_capture_file_path_template_path(template)
-    }
-
     pub fn set_capture_option_u32(
         &self,
         opt: impl Into<sys::RENDERDOC_CaptureOption>,
@@ -372,7 +489,7 @@ impl RenderDocInApp {
         let f = self
             .api()
             .SetCaptureOptionU32
-            .ok_or(InAppError::MissingFunction("SetCaptureOptionU32"))?;
+            .ok_or_else(|| self.missing_function("SetCaptureOptionU32"))?;
         Ok(unsafe { f(opt.into(), val) } == 1)
     }
 
@@ -384,7 +501,7 @@ impl RenderDocInApp {
         let f = self
             .api()
             .SetCaptureOptionF32
-            .ok_or(InAppError::MissingFunction("SetCaptureOptionF32"))?;
+            .ok_or_else(|| self.missing_function("SetCaptureOptionF32"))?;
         Ok(unsafe { f(opt.into(), val) } == 1)
     }
 
@@ -395,7 +512,7 @@ impl RenderDocInApp {
         let f = self
             .api()
             .GetCaptureOptionU32
-            .ok_or(InAppError::MissingFunction("GetCaptureOptionU32"))?;
+            .ok_or_else(|| self.missing_function("GetCaptureOptionU32"))?;
         Ok(unsafe { f(opt.into()) })
     }
 
@@ -406,7 +523,7 @@ impl RenderDocInApp {
         let f = self
             .api()
             .GetCaptureOptionF32
-            .ok_or(InAppError::MissingFunction("GetCaptureOptionF32"))?;
+            .ok_or_else(|| self.missing_function("GetCaptureOptionF32"))?;
         Ok(unsafe { f(opt.into()) })
     }
 
@@ -417,7 +534,7 @@ impl RenderDocInApp {
         let f = self
             .api()
             .SetFocusToggleKeys
-            .ok_or(InAppError::MissingFunction("SetFocusToggleKeys"))?;
+            .ok_or_else(|| self.missing_function("SetFocusToggleKeys"))?;
 
         if keys.is_empty() {
             unsafe { f(std::ptr::null_mut(), 0) };
@@ -438,7 +555,7 @@ impl RenderDocInApp {
         let f = self
             .api()
             .SetCaptureKeys
-            .ok_or(InAppError::MissingFunction("SetCaptureKeys"))?;
+            .ok_or_else(|| self.missing_function("SetCaptureKeys"))?;
 
         if keys.is_empty() {
             unsafe { f(std::ptr::null_mut(), 0) };
@@ -456,7 +573,7 @@ impl RenderDocInApp {
         let f = self
             .api()
             .GetOverlayBits
-            .ok_or(InAppError::MissingFunction("GetOverlayBits"))?;
+            .ok_or_else(|| self.missing_function("GetOverlayBits"))?;
         Ok(OverlayBits::from_bits_truncate(unsafe { f() }))
     }
 
@@ -464,22 +581,14 @@ impl RenderDocInApp {
         let f = self
             .api()
             .MaskOverlayBits
-            .ok_or(InAppError::MissingFunction("MaskOverlayBits"))?;
+            .ok_or_else(|| self.missing_function("MaskOverlayBits"))?;
         unsafe { f(and_mask, or_mask) };
         Ok(())
     }
 
-    pub fn mask_overlay_bits_flags(
-        &self,
-        and_mask: OverlayBits,
-        or_mask: OverlayBits,
-    ) -> Result<(), InAppError> {
-        self.mask_overlay_bits(and_mask.bits(), or_mask.bits())
-    }
-
     pub fn is_target_control_connected(&self) -> Result<bool, InAppError> {
         let f = unsafe { self.api().__bindgen_anon_4.IsTargetControlConnected }
-            .ok_or(InAppError::MissingFunction("IsTargetControlConnected"))?;
+            .ok_or_else(|| self.missing_function("IsTargetControlConnected"))?;
         Ok(unsafe { f() } == 1)
     }
 
@@ -491,7 +600,7 @@ impl RenderDocInApp {
         let f = self
             .api()
             .LaunchReplayUI
-            .ok_or(InAppError::MissingFunction("LaunchReplayUI"))?;
+            .ok_or_else(|| self.missing_function("LaunchReplayUI"))?;
         let cmdline_cstr;
         let cmd_ptr = if let Some(s) = cmdline {
             cmdline_cstr = CString::new(s).map_err(|_| InAppError::InvalidUtf8)?;
@@ -507,7 +616,7 @@ impl RenderDocInApp {
         let f = self
             .api()
             .ShowReplayUI
-            .ok_or(InAppError::MissingFunction("ShowReplayUI"))?;
+            .ok_or_else(|| self.missing_function("ShowReplayUI"))?;
         Ok(unsafe { f() } == 1)
     }
 
@@ -519,7 +628,7 @@ impl RenderDocInApp {
         let f = self
             .api()
             .DiscardFrameCapture
-            .ok_or(InAppError::MissingFunction("DiscardFrameCapture"))?;
+            .ok_or_else(|| self.missing_function("DiscardFrameCapture"))?;
         let ok = unsafe {
             f(
                 device.unwrap_or(std::ptr::null_mut()),
@@ -537,7 +646,7 @@ impl RenderDocInApp {
         let f = self
             .api()
             .SetCaptureFileComments
-            .ok_or(InAppError::MissingFunction("SetCaptureFileComments"))?;
+            .ok_or_else(|| self.missing_function("SetCaptureFileComments"))?;
         let comments_c = CString::new(comments).map_err(|_| InAppError::InvalidUtf8)?;
         let path_c;
         let path_ptr = if let Some(p) = capture_file_path {
@@ -554,7 +663,7 @@ impl RenderDocInApp {
         let f = self
             .api()
             .SetCaptureTitle
-            .ok_or(InAppError::MissingFunction("SetCaptureTitle"))?;
+            .ok_or_else(|| self.missing_function("SetCaptureTitle"))?;
         let title_c = CString::new(title).map_err(|_| InAppError::InvalidUtf8)?;
         unsafe { f(title_c.as_ptr()) };
         Ok(())
@@ -564,14 +673,14 @@ impl RenderDocInApp {
         let f = self
             .api()
             .UnloadCrashHandler
-            .ok_or(InAppError::MissingFunction("UnloadCrashHandler"))?;
+            .ok_or_else(|| self.missing_function("UnloadCrashHandler"))?;
         unsafe { f() };
         Ok(())
     }
 
     pub fn remove_hooks(&self) -> Result<(), InAppError> {
         let f = unsafe { self.api().__bindgen_anon_1.RemoveHooks }
-            .ok_or(InAppError::MissingFunction("RemoveHooks"))?;
+            .ok_or_else(|| self.missing_function("RemoveHooks"))?;
         unsafe { f() };
         Ok(())
     }
@@ -584,7 +693,7 @@ impl RenderDocInApp {
         let f = self
             .api()
             .SetActiveWindow
-            .ok_or(InAppError::MissingFunction("SetActiveWindow"))?;
+            .ok_or_else(|| self.missing_function("SetActiveWindow"))?;
         unsafe {
             f(
                 device.unwrap_or(std::ptr::null_mut()),
@@ -598,7 +707,7 @@ impl RenderDocInApp {
         let f = self
             .api()
             .TriggerCapture
-            .ok_or(InAppError::MissingFunction("TriggerCapture"))?;
+            .ok_or_else(|| self.missing_function("TriggerCapture"))?;
         unsafe { f() };
         Ok(())
     }
@@ -607,7 +716,7 @@ impl RenderDocInApp {
         let f = self
             .api()
             .TriggerMultiFrameCapture
-            .ok_or(InAppError::MissingFunction("TriggerMultiFrameCapture"))?;
+            .ok_or_else(|| self.missing_function("TriggerMultiFrameCapture"))?;
         unsafe { f(frames) };
         Ok(())
     }
@@ -620,7 +729,7 @@ impl RenderDocInApp {
         let f = self
             .api()
             .StartFrameCapture
-            .ok_or(InAppError::MissingFunction("StartFrameCapture"))?;
+            .ok_or_else(|| self.missing_function("StartFrameCapture"))?;
         unsafe {
             f(
                 device.unwrap_or(std::ptr::null_mut()),
@@ -638,7 +747,7 @@ impl RenderDocInApp {
         let f = self
             .api()
             .EndFrameCapture
-            .ok_or(InAppError::MissingFunction("EndFrameCapture"))?;
+            .ok_or_else(|| self.missing_function("EndFrameCapture"))?;
         let ok = unsafe {
             f(
                 device.unwrap_or(std::ptr::null_mut()),
@@ -652,7 +761,7 @@ impl RenderDocInApp {
         let f = self
             .api()
             .IsFrameCapturing
-            .ok_or(InAppError::MissingFunction("IsFrameCapturing"))?;
+            .ok_or_else(|| self.missing_function("IsFrameCapturing"))?;
         Ok(unsafe { f() } == 1)
     }
 
@@ -660,7 +769,7 @@ impl RenderDocInApp {
         let f = self
             .api()
             .GetNumCaptures
-            .ok_or(InAppError::MissingFunction("GetNumCaptures"))?;
+            .ok_or_else(|| self.missing_function("GetNumCaptures"))?;
         Ok(unsafe { f() })
     }
 
@@ -668,34 +777,425 @@ impl RenderDocInApp {
         let f = self
             .api()
             .GetCapture
-            .ok_or(InAppError::MissingFunction("GetCapture"))?;
+            .ok_or_else(|| self.missing_function("GetCapture"))?;
+        read_capture(f, idx)
+    }
+}
+
+#[cfg(not(feature = "disabled"))]
+pub(crate) type GetCaptureFn = unsafe extern "C" fn(
+    idx: u32,
+    filename: *mut std::os::raw::c_char,
+    pathlength: *mut u32,
+    timestamp: *mut u64,
+) -> u32;
+
+#[cfg(not(feature = "disabled"))]
+pub(crate) fn read_capture(f: GetCaptureFn, idx: u32) -> Result<(String, u64), InAppError> {
+    let mut path_len: u32 = 0;
+    let mut timestamp: u64 = 0;
+    let ok = unsafe { f(idx, std::ptr::null_mut(), &mut path_len, &mut timestamp) };
+    if ok == 0 {
+        return Err(InAppError::InvalidCaptureIndex);
+    }
+
+    let mut buf = vec![0u8; path_len as usize];
+    let ok = unsafe {
+        f(
+            idx,
+            buf.as_mut_ptr() as *mut i8,
+            &mut path_len,
+            &mut timestamp,
+        )
+    };
+    if ok == 0 {
+        return Err(InAppError::InvalidCaptureIndex);
+    }
+
+    while buf.last().copied() == Some(0) {
+        buf.pop();
+    }
+    let s = std::str::from_utf8(&buf).map_err(|_| InAppError::InvalidUtf8)?;
+
+    Ok((s.to_string(), timestamp))
+}
 
-        let mut path_len: u32 = 0;
-        let mut timestamp: u64 = 0;
-        let ok = unsafe { f(idx, std::ptr::null_mut(), &mut path_len, &mut timestamp) };
-        if ok == 0 {
-            return Err(InAppError::InvalidCaptureIndex);
+#[cfg(feature = "disabled")]
+impl RenderDocInApp {
+    #[inline(always)]
+    fn disabled() -> Self {
+        Self {
+            _not_sync: Cell::new(()),
         }
+    }
 
-        let mut buf = vec![0u8; path_len as usize];
-        let ok = unsafe {
-            f(
-                idx,
-                buf.as_mut_ptr() as *mut i8,
-                &mut path_len,
-                &mut timestamp,
-            )
-        };
-        if ok == 0 {
-            return Err(InAppError::InvalidCaptureIndex);
+    #[inline(always)]
+    pub fn try_connect() -> Result<Self, InAppError> {
+        Ok(Self::disabled())
+    }
+
+    #[inline(always)]
+    pub fn try_load_and_connect(_dll_path_or_name: &str) -> Result<Self, InAppError> {
+        Ok(Self::disabled())
+    }
+
+    #[inline(always)]
+    pub fn try_connect_or_load(_dll_path_or_name: &str) -> Result<Self, InAppError> {
+        Ok(Self::disabled())
+    }
+
+    #[inline(always)]
+    pub fn try_load_and_connect_default() -> Result<Self, InAppError> {
+        Ok(Self::disabled())
+    }
+
+    #[inline(always)]
+    pub fn try_connect_or_load_default() -> Result<Self, InAppError> {
+        Ok(Self::disabled())
+    }
+
+    #[cfg(all(unix, target_os = "linux"))]
+    #[inline(always)]
+    pub fn try_connect_noload_default() -> Result<Self, InAppError> {
+        Ok(Self::disabled())
+    }
+
+    #[cfg(all(unix, target_os = "linux"))]
+    #[inline(always)]
+    pub fn try_connect_noload_or_load_default() -> Result<Self, InAppError> {
+        Ok(Self::disabled())
+    }
+
+    #[inline(always)]
+    pub fn requested_version(&self) -> sys::RENDERDOC_Version {
+        sys::RENDERDOC_Version::eRENDERDOC_API_Version_1_6_0
+    }
+
+    #[inline(always)]
+    pub fn capabilities(&self) -> Capabilities {
+        Capabilities::default()
+    }
+
+    #[inline(always)]
+    pub fn get_api_version(&self) -> Result<(i32, i32, i32), InAppError> {
+        Ok((0, 0, 0))
+    }
+
+    #[inline(always)]
+    pub fn set_capture_file_path_template(&self, _template: &str) -> Result<(), InAppError> {
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn get_capture_file_path_template(&self) -> Result<String, InAppError> {
+        Ok(String::new())
+    }
+
+    #[inline(always)]
+    pub fn set_capture_option_u32(
+        &self,
+        _opt: impl Into<sys::RENDERDOC_CaptureOption>,
+        _val: u32,
+    ) -> Result<bool, InAppError> {
+        Ok(false)
+    }
+
+    #[inline(always)]
+    pub fn set_capture_option_f32(
+        &self,
+        _opt: impl Into<sys::RENDERDOC_CaptureOption>,
+        _val: f32,
+    ) -> Result<bool, InAppError> {
+        Ok(false)
+    }
+
+    #[inline(always)]
+    pub fn get_capture_option_u32(
+        &self,
+        _opt: impl Into<sys::RENDERDOC_CaptureOption>,
+    ) -> Result<u32, InAppError> {
+        Ok(0)
+    }
+
+    #[inline(always)]
+    pub fn get_capture_option_f32(
+        &self,
+        _opt: impl Into<sys::RENDERDOC_CaptureOption>,
+    ) -> Result<f32, InAppError> {
+        Ok(0.0)
+    }
+
+    #[inline(always)]
+    pub fn set_focus_toggle_keys<I>(&self, _keys: &[I]) -> Result<(), InAppError>
+    where
+        I: Clone + Into<sys::RENDERDOC_InputButton>,
+    {
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn set_capture_keys<I>(&self, _keys: &[I]) -> Result<(), InAppError>
+    where
+        I: Clone + Into<sys::RENDERDOC_InputButton>,
+    {
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn get_overlay_bits(&self) -> Result<OverlayBits, InAppError> {
+        Ok(OverlayBits::empty())
+    }
+
+    #[inline(always)]
+    pub fn mask_overlay_bits(&self, _and_mask: u32, _or_mask: u32) -> Result<(), InAppError> {
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn is_target_control_connected(&self) -> Result<bool, InAppError> {
+        Ok(false)
+    }
+
+    #[inline(always)]
+    pub fn launch_replay_ui(
+        &self,
+        _connect_target_control: bool,
+        _cmdline: Option<&str>,
+    ) -> Result<u32, InAppError> {
+        Ok(0)
+    }
+
+    #[inline(always)]
+    pub fn show_replay_ui(&self) -> Result<bool, InAppError> {
+        Ok(false)
+    }
+
+    #[inline(always)]
+    pub fn discard_frame_capture(
+        &self,
+        _device: Option<sys::RENDERDOC_DevicePointer>,
+        _window: Option<sys::RENDERDOC_WindowHandle>,
+    ) -> Result<bool, InAppError> {
+        Ok(false)
+    }
+
+    #[inline(always)]
+    pub fn set_capture_file_comments(
+        &self,
+        _capture_file_path: Option<&str>,
+        _comments: &str,
+    ) -> Result<(), InAppError> {
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn set_capture_title(&self, _title: &str) -> Result<(), InAppError> {
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn unload_crash_handler(&self) -> Result<(), InAppError> {
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn remove_hooks(&self) -> Result<(), InAppError> {
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn set_active_window(
+        &self,
+        _device: Option<sys::RENDERDOC_DevicePointer>,
+        _window: Option<sys::RENDERDOC_WindowHandle>,
+    ) -> Result<(), InAppError> {
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn trigger_capture(&self) -> Result<(), InAppError> {
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn trigger_multi_frame_capture(&self, _frames: u32) -> Result<(), InAppError> {
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn start_frame_capture(
+        &self,
+        _device: Option<sys::RENDERDOC_DevicePointer>,
+        _window: Option<sys::RENDERDOC_WindowHandle>,
+    ) -> Result<(), InAppError> {
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn end_frame_capture(
+        &self,
+        _device: Option<sys::RENDERDOC_DevicePointer>,
+        _window: Option<sys::RENDERDOC_WindowHandle>,
+    ) -> Result<bool, InAppError> {
+        Ok(false)
+    }
+
+    #[inline(always)]
+    pub fn is_frame_capturing(&self) -> Result<bool, InAppError> {
+        Ok(false)
+    }
+
+    #[inline(always)]
+    pub fn get_num_captures(&self) -> Result<u32, InAppError> {
+        Ok(0)
+    }
+
+    #[inline(always)]
+    pub fn get_capture(&self, _idx: u32) -> Result<(String, u64), InAppError> {
+        Err(InAppError::InvalidCaptureIndex)
+    }
+}
+
+impl RenderDocInApp {
+    pub fn get_capture_file_path_template_path(&self) -> Result<PathBuf, InAppError> {
+        Ok(PathBuf::from(self.get_capture_file_path_template()?))
+    }
+
+    pub fn set_capture_file_path_template_path<P: AsRef<Path>>(
+        &self,
+        template: P,
+    ) -> Result<(), InAppError> {
+        let s = template.as_ref().to_str().ok_or(InAppError::InvalidUtf8)?;
+        self.set_capture_file_path_template(s)
+    }
+
+    #[deprecated(since = "0.1.0", note = "renamed to get_capture_file_path_template")]
+    pub fn get_log_file_path_template(&self) -> Result<String, InAppError> {
+        self.get_capture_file_path_template()
+    }
+
+    #[deprecated(since = "0.1.0", note = "renamed to set_capture_file_path_template")]
+    pub fn set_log_file_path_template(&self, template: &str) -> Result<(), InAppError> {
+        self.set_capture_file_path_template(template)
+    }
+
+    #[deprecated(
+        since = "0.1.0",
+        note = "renamed to get_capture_file_path_template_path"
+    )]
+    pub fn get_log_file_path_template_path(&self) -> Result<PathBuf, InAppError> {
+        self.get_capture_file_path_template_path()
+    }
+
+    #[deprecated(
+        since = "0.1.0",
+        note = "renamed to set_capture_file_path_template_path"
+    )]
+    pub fn set_log_file_path_template_path<P: AsRef<Path>>(
+        &self,
+        template: P,
+    ) -> Result<(), InAppError> {
+        self.set_capture_file_path_template_path(template)
+    }
+
+    pub fn mask_overlay_bits_flags(
+        &self,
+        and_mask: OverlayBits,
+        or_mask: OverlayBits,
+    ) -> Result<(), InAppError> {
+        self.mask_overlay_bits(and_mask.bits(), or_mask.bits())
+    }
+
+    /// Replace the overlay bits with exactly `bits`.
+    pub fn set_overlay_bits(&self, bits: OverlayBits) -> Result<(), InAppError> {
+        self.mask_overlay_bits(0, bits.bits())
+    }
+
+    /// Set or clear `bits`, leaving all other overlay bits untouched.
+    pub fn set_overlay_flags(&self, bits: OverlayBits, on: bool) -> Result<(), InAppError> {
+        if on {
+            self.mask_overlay_bits(u32::MAX, bits.bits())
+        } else {
+            self.mask_overlay_bits(!bits.bits(), 0)
         }
+    }
+
+    pub fn enable_overlay(&self) -> Result<(), InAppError> {
+        self.set_overlay_flags(OverlayBits::ENABLED, true)
+    }
+
+    pub fn disable_overlay(&self) -> Result<(), InAppError> {
+        self.set_overlay_flags(OverlayBits::ENABLED, false)
+    }
+
+    pub fn show_frame_rate(&self, show: bool) -> Result<(), InAppError> {
+        self.set_overlay_flags(OverlayBits::FRAME_RATE, show)
+    }
+
+    pub fn show_frame_number(&self, show: bool) -> Result<(), InAppError> {
+        self.set_overlay_flags(OverlayBits::FRAME_NUMBER, show)
+    }
 
-        while buf.last().copied() == Some(0) {
-            buf.pop();
+    pub fn show_capture_list(&self, show: bool) -> Result<(), InAppError> {
+        self.set_overlay_flags(OverlayBits::CAPTURE_LIST, show)
+    }
+
+    /// Make RenderDoc as dormant as possible while it stays loaded.
+    ///
+    /// Unloads RenderDoc's crash handler and turns the overlay off. With `remove_hooks`, also calls
+    /// `RemoveHooks`: this is irreversible for the lifetime of the process, after which no further
+    /// captures can be made (hooks are not reinstalled by reconnecting).
+    pub fn minimize_footprint(&self, remove_hooks: bool) -> Result<(), InAppError> {
+        self.unload_crash_handler()?;
+        self.set_overlay_bits(OverlayBits::empty())?;
+        if remove_hooks {
+            self.remove_hooks()?;
         }
-        let s = std::str::from_utf8(&buf).map_err(|_| InAppError::InvalidUtf8)?;
+        Ok(())
+    }
+
+    /// Start a frame capture and return a guard that ends it when dropped.
+    ///
+    /// If the guard is dropped while unwinding from a panic, the capture is discarded instead.
+    /// Use [`FrameCaptureGuard::end`] / [`FrameCaptureGuard::discard`] to observe the result.
+    pub fn begin_frame_capture(
+        &self,
+        device: Option<sys::RENDERDOC_DevicePointer>,
+        window: Option<sys::RENDERDOC_WindowHandle>,
+    ) -> Result<FrameCaptureGuard<'_>, InAppError> {
+        self.start_frame_capture(device, window)?;
+        Ok(FrameCaptureGuard {
+            rd: self,
+            device,
+            window,
+            finished: false,
+        })
+    }
 
-        Ok((s.to_string(), timestamp))
+    /// Capture everything `f` renders and return its result together with the new capture path.
+    ///
+    /// The capture is discarded if `f` panics. After `EndFrameCapture` succeeds, waits up to
+    /// [`DEFAULT_CAPTURE_TIMEOUT`] for RenderDoc to report the written capture.
+    #[cfg(not(feature = "disabled"))]
+    pub fn with_frame_capture<R>(
+        &self,
+        device: Option<sys::RENDERDOC_DevicePointer>,
+        window: Option<sys::RENDERDOC_WindowHandle>,
+        f: impl FnOnce() -> R,
+    ) -> Result<(R, PathBuf), InAppError> {
+        with_frame_capture(self, device, window, f)
+    }
+
+    /// With the `disabled` feature nothing is captured: `f` runs and the returned path is empty.
+    #[cfg(feature = "disabled")]
+    #[inline(always)]
+    pub fn with_frame_capture<R>(
+        &self,
+        _device: Option<sys::RENDERDOC_DevicePointer>,
+        _window: Option<sys::RENDERDOC_WindowHandle>,
+        f: impl FnOnce() -> R,
+    ) -> Result<(R, PathBuf), InAppError> {
+        Ok((f(), PathBuf::new()))
     }
 
     pub fn get_capture_info(&self, idx: u32) -> Result<(PathBuf, SystemTime), InAppError> {
@@ -716,4 +1216,225 @@ impl RenderDocInApp {
             Err(e) => Err(e),
         }
     }
+
+    /// Path and timestamp of the newest capture, if any.
+    pub fn latest_capture_info(&self) -> Result<Option<(PathBuf, SystemTime)>, InAppError> {
+        match self.get_num_captures()? {
+            0 => Ok(None),
+            n => self.get_capture_info_opt(n - 1),
+        }
+    }
+
+    /// Attach `comments` to the newest capture and return its path.
+    ///
+    /// Comments are written with `SetCaptureFileComments` against the resolved path, so they land
+    /// in that file even after the capture has finished. There is no equivalent for titles:
+    /// `SetCaptureTitle` only applies to the in-progress (or next) capture, so call
+    /// [`set_capture_title`](Self::set_capture_title) before the capture ends instead.
+    pub fn annotate_last_capture(&self, comments: &str) -> Result<PathBuf, InAppError> {
+        let (path, _) = self
+            .latest_capture_info()?
+            .ok_or(InAppError::InvalidCaptureIndex)?;
+        let path_str = path.to_str().ok_or(InAppError::InvalidUtf8)?;
+        self.set_capture_file_comments(Some(path_str), comments)?;
+        Ok(path)
+    }
+
+    /// Wait until RenderDoc reports more than `baseline_count` captures and return the path of
+    /// the newest one.
+    ///
+    /// Take `baseline_count` from [`get_num_captures`](Self::get_num_captures) before triggering
+    /// the capture. Returns [`InAppError::CaptureTimeout`] if no new capture shows up in time.
+    pub fn wait_for_new_capture(
+        &self,
+        baseline_count: u32,
+        timeout: Duration,
+    ) -> Result<PathBuf, InAppError> {
+        poll_new_capture(self, baseline_count, timeout)
+    }
+
+    /// Launch the replay UI, wait for it to connect over target control, and return a handle.
+    ///
+    /// `IsTargetControlConnected` is polled until it reports a connection or `timeout` elapses,
+    /// since the UI takes a moment to start up.
+    pub fn open_ui_and_connect(
+        &self,
+        cmdline: Option<&str>,
+        timeout: Duration,
+    ) -> Result<ReplayUi<'_>, InAppError> {
+        let pid = self.launch_replay_ui(true, cmdline)?;
+        if pid == 0 {
+            return Err(InAppError::LaunchReplayUiFailed);
+        }
+
+        let deadline = Instant::now() + timeout;
+        loop {
+            if self.is_target_control_connected()? {
+                return Ok(ReplayUi { rd: self, pid });
+            }
+            if Instant::now() >= deadline {
+                return Err(InAppError::ReplayUiConnectTimeout { pid, timeout });
+            }
+            std::thread::sleep(CAPTURE_POLL_INTERVAL);
+        }
+    }
+}
+
+/// A replay UI connected to this process (see [`RenderDocInApp::open_ui_and_connect`]).
+pub struct ReplayUi<'a> {
+    rd: &'a RenderDocInApp,
+    pid: u32,
+}
+
+impl ReplayUi<'_> {
+    /// Process id of the launched replay UI.
+    pub fn pid(&self) -> u32 {
+        self.pid
+    }
+
+    pub fn is_connected(&self) -> Result<bool, InAppError> {
+        self.rd.is_target_control_connected()
+    }
+
+    /// Ask the connected UI to raise its window (`ShowReplayUI`).
+    pub fn show(&self) -> Result<bool, InAppError> {
+        self.rd.show_replay_ui()
+    }
+}
+
+/// [`RenderDocInApp::with_frame_capture`] for any [`RenderDocApi`], e.g. a `Box<dyn RenderDocApi>`
+/// or a `MockRenderDoc` in tests.
+pub fn with_frame_capture<A: RenderDocApi + ?Sized, R>(
+    rd: &A,
+    device: Option<sys::RENDERDOC_DevicePointer>,
+    window: Option<sys::RENDERDOC_WindowHandle>,
+    f: impl FnOnce() -> R,
+) -> Result<(R, PathBuf), InAppError> {
+    let baseline = rd.get_num_captures()?;
+    rd.start_frame_capture(device, window)?;
+    let guard = FrameCaptureGuard {
+        rd,
+        device,
+        window,
+        finished: false,
+    };
+    let out = f();
+    if !guard.end()? {
+        return Err(InAppError::EndFrameCaptureFailed);
+    }
+    let path = poll_new_capture(rd, baseline, DEFAULT_CAPTURE_TIMEOUT)?;
+    Ok((out, path))
+}
+
+fn poll_new_capture<A: RenderDocApi + ?Sized>(
+    rd: &A,
+    baseline_count: u32,
+    timeout: Duration,
+) -> Result<PathBuf, InAppError> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        let count = rd.get_num_captures()?;
+        if count > baseline_count {
+            let (path, _) = rd.get_capture(count - 1)?;
+            return Ok(PathBuf::from(path));
+        }
+        if Instant::now() >= deadline {
+            return Err(InAppError::CaptureTimeout(timeout));
+        }
+        std::thread::sleep(CAPTURE_POLL_INTERVAL);
+    }
+}
+
+/// RAII guard for an in-progress frame capture (see [`RenderDocInApp::begin_frame_capture`]).
+#[must_use = "dropping the guard immediately ends the frame capture"]
+pub struct FrameCaptureGuard<'a, A: RenderDocApi + ?Sized = RenderDocInApp> {
+    rd: &'a A,
+    device: Option<sys::RENDERDOC_DevicePointer>,
+    window: Option<sys::RENDERDOC_WindowHandle>,
+    finished: bool,
+}
+
+impl<A: RenderDocApi + ?Sized> FrameCaptureGuard<'_, A> {
+    /// End the capture, returning whether RenderDoc reported success.
+    pub fn end(mut self) -> Result<bool, InAppError> {
+        self.finished = true;
+        self.rd.end_frame_capture(self.device, self.window)
+    }
+
+    /// Discard the capture, returning whether RenderDoc reported success.
+    pub fn discard(mut self) -> Result<bool, InAppError> {
+        self.finished = true;
+        self.rd.discard_frame_capture(self.device, self.window)
+    }
+}
+
+impl<A: RenderDocApi + ?Sized> Drop for FrameCaptureGuard<'_, A> {
+    fn drop(&mut self) {
+        if self.finished {
+            return;
+        }
+        let _ = if std::thread::panicking() {
+            self.rd.discard_frame_capture(self.device, self.window)
+        } else {
+            self.rd.end_frame_capture(self.device, self.window)
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_function_reports_required_and_negotiated_versions() {
+        assert_eq!(
+            function_min_version("SetCaptureTitle"),
+            sys::RENDERDOC_Version::eRENDERDOC_API_Version_1_6_0
+        );
+        assert_eq!(
+            function_min_version("StartFrameCapture"),
+            sys::RENDERDOC_Version::eRENDERDOC_API_Version_1_0_0
+        );
+
+        let err = InAppError::MissingFunction {
+            name: "SetCaptureTitle",
+            required: function_min_version("SetCaptureTitle"),
+            negotiated: sys::RENDERDOC_Version::eRENDERDOC_API_Version_1_4_2,
+        };
+        assert_eq!(
+            err.to_string(),
+            "RenderDoc API function SetCaptureTitle is unavailable (requires API 1.6.0, negotiated 1.4.2)"
+        );
+    }
+
+    #[cfg(feature = "disabled")]
+    #[test]
+    fn disabled_with_frame_capture_runs_closure() {
+        let rd = RenderDocInApp::try_connect().unwrap();
+        let (out, path) = rd.with_frame_capture(None, None, || 7).unwrap();
+        assert_eq!(out, 7);
+        assert_eq!(path, PathBuf::new());
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn with_frame_capture_runs_through_api_trait() {
+        use crate::{MockCall, MockRenderDoc};
+
+        let rd = MockRenderDoc::new();
+        let (out, path) = with_frame_capture(&rd, None, None, || 7).unwrap();
+        assert_eq!(out, 7);
+        assert_eq!(path, PathBuf::from("renderdog_mock_frame1.rdc"));
+        assert_eq!(
+            rd.calls(),
+            [MockCall::StartFrameCapture, MockCall::EndFrameCapture]
+        );
+
+        let panicked = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            with_frame_capture(&rd, None, None, || panic!("render failed"))
+        }));
+        assert!(panicked.is_err());
+        assert_eq!(rd.calls().last(), Some(&MockCall::DiscardFrameCapture));
+        assert_eq!(rd.capture_paths().len(), 1);
+    }
 }
@@ -0,0 +1,169 @@
+use std::path::PathBuf;
+
+use crate::{InAppError, RenderDocInApp};
+
+/// Environment variable pointing at the RenderDoc install root (shared with
+/// `renderdog-automation`).
+pub const ENV_RENDERDOC_DIR: &str = "RENDERDOG_RENDERDOC_DIR";
+
+/// Candidate RenderDoc install roots, most specific first.
+///
+/// Search order: `RENDERDOG_RENDERDOC_DIR`, then on Windows the `InstallLocation` of RenderDoc's
+/// uninstall registry entries and `%ProgramFiles%\RenderDoc`. Directories are not checked for
+/// existence.
+pub fn renderdoc_install_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    if let Some(dir) = std::env::var_os(ENV_RENDERDOC_DIR) {
+        dirs.push(PathBuf::from(dir));
+    }
+
+    #[cfg(windows)]
+    {
+        dirs.extend(registry::install_locations());
+        if let Some(pf) = std::env::var_os("ProgramFiles") {
+            dirs.push(PathBuf::from(pf).join("RenderDoc"));
+        }
+    }
+
+    dirs
+}
+
+/// Locate the RenderDoc in-app library (`renderdoc.dll` / `librenderdoc.so`) in an installation.
+pub fn find_renderdoc_library() -> Option<PathBuf> {
+    #[cfg(windows)]
+    let relative: &[&str] = &["renderdoc.dll"];
+    #[cfg(not(windows))]
+    let relative: &[&str] = &["librenderdoc.so", "lib/librenderdoc.so"];
+
+    renderdoc_install_dirs()
+        .into_iter()
+        .flat_map(|dir| relative.iter().map(move |rel| dir.join(rel)))
+        .find(|candidate| candidate.is_file())
+}
+
+impl RenderDocInApp {
+    /// Load the RenderDoc library from an installed copy (see [`find_renderdoc_library`]).
+    ///
+    /// Useful when the library is not on the loader search path (e.g. `PATH` on Windows).
+    pub fn try_load_from_installation() -> Result<Self, InAppError> {
+        let path = find_renderdoc_library().ok_or(InAppError::NotAvailable)?;
+        let path = path.to_str().ok_or(InAppError::InvalidUtf8)?;
+        Self::try_load_and_connect(path)
+    }
+}
+
+#[cfg(windows)]
+mod registry {
+    use std::path::PathBuf;
+
+    use windows_sys::Win32::Foundation::ERROR_SUCCESS;
+    use windows_sys::Win32::System::Registry::{
+        HKEY, HKEY_CURRENT_USER, HKEY_LOCAL_MACHINE, KEY_READ, KEY_WOW64_64KEY, RRF_RT_REG_SZ,
+        RegCloseKey, RegEnumKeyExW, RegGetValueW, RegOpenKeyExW,
+    };
+
+    const UNINSTALL_KEY: &str = r"SOFTWARE\Microsoft\Windows\CurrentVersion\Uninstall";
+
+    /// `InstallLocation` of every uninstall entry whose `DisplayName` starts with "RenderDoc".
+    pub(super) fn install_locations() -> Vec<PathBuf> {
+        let mut out = Vec::new();
+        let uninstall = wide(UNINSTALL_KEY);
+
+        for root in [HKEY_LOCAL_MACHINE, HKEY_CURRENT_USER] {
+            let mut key: HKEY = 0;
+            let rc = unsafe {
+                RegOpenKeyExW(
+                    root,
+                    uninstall.as_ptr(),
+                    0,
+                    KEY_READ | KEY_WOW64_64KEY,
+                    &mut key,
+                )
+            };
+            if rc != ERROR_SUCCESS {
+                continue;
+            }
+
+            let mut index = 0;
+            loop {
+                let mut name = [0u16; 256];
+                let mut len = name.len() as u32;
+                let rc = unsafe {
+                    RegEnumKeyExW(
+                        key,
+                        index,
+                        name.as_mut_ptr(),
+                        &mut len,
+                        std::ptr::null(),
+                        std::ptr::null_mut(),
+                        std::ptr::null_mut(),
+                        std::ptr::null_mut(),
+                    )
+                };
+                if rc != ERROR_SUCCESS {
+                    break;
+                }
+                index += 1;
+
+                let mut subkey = name[..len as usize].to_vec();
+                subkey.push(0);
+                let is_renderdoc = read_string(key, &subkey, "DisplayName")
+                    .is_some_and(|n| n.starts_with("RenderDoc"));
+                if !is_renderdoc {
+                    continue;
+                }
+                if let Some(location) = read_string(key, &subkey, "InstallLocation")
+                    && !location.trim().is_empty()
+                {
+                    out.push(PathBuf::from(location.trim()));
+                }
+            }
+
+            unsafe { RegCloseKey(key) };
+        }
+
+        out
+    }
+
+    fn read_string(key: HKEY, subkey: &[u16], value: &str) -> Option<String> {
+        let value = wide(value);
+        let mut size: u32 = 0;
+        let rc = unsafe {
+            RegGetValueW(
+                key,
+                subkey.as_ptr(),
+                value.as_ptr(),
+                RRF_RT_REG_SZ,
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                &mut size,
+            )
+        };
+        if rc != ERROR_SUCCESS || size == 0 {
+            return None;
+        }
+
+        let mut buf = vec![0u16; (size as usize).div_ceil(2)];
+        let rc = unsafe {
+            RegGetValueW(
+                key,
+                subkey.as_ptr(),
+                value.as_ptr(),
+                RRF_RT_REG_SZ,
+                std::ptr::null_mut(),
+                buf.as_mut_ptr().cast(),
+                &mut size,
+            )
+        };
+        if rc != ERROR_SUCCESS {
+            return None;
+        }
+
+        let len = buf.iter().position(|&c| c == 0).unwrap_or(buf.len());
+        Some(String::from_utf16_lossy(&buf[..len]))
+    }
+
+    fn wide(s: &str) -> Vec<u16> {
+        s.encode_utf16().chain(std::iter::once(0)).collect()
+    }
+}
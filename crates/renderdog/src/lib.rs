@@ -8,13 +8,41 @@
 //!
 //! For automation workflows (renderdoccmd/qrenderdoc), see the `renderdog-automation` crate.
 
+mod api;
+#[cfg(feature = "async")]
+mod async_capture;
+mod config;
+mod device;
+mod global;
 mod in_app;
+mod install;
+#[cfg(feature = "mock")]
+mod mock;
 mod renderdog;
 mod settings;
+mod template;
+#[cfg(feature = "wgpu")]
+mod wgpu_support;
+#[cfg(feature = "raw-window-handle")]
+mod window;
+mod window_registry;
 
+pub use api::*;
+pub use config::*;
+pub use device::*;
+pub use global::*;
 pub use in_app::*;
+pub use install::*;
+#[cfg(feature = "mock")]
+pub use mock::*;
 pub use renderdog::*;
 pub use settings::*;
+pub use template::*;
+#[cfg(feature = "wgpu")]
+pub use wgpu_support::*;
+#[cfg(feature = "raw-window-handle")]
+pub use window::*;
+pub use window_registry::*;
 
 pub type SysCaptureOption = RENDERDOC_CaptureOption;
 pub type SysInputButton = RENDERDOC_InputButton;
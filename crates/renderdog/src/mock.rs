@@ -0,0 +1,379 @@
+use std::{
+    collections::HashMap,
+    sync::{Mutex, MutexGuard},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use renderdog_sys as sys;
+
+use crate::{Capabilities, CaptureOption, InAppError, InputButton, OverlayBits, RenderDocApi};
+
+/// A call recorded by [`MockRenderDoc`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum MockCall {
+    SetCaptureFilePathTemplate(String),
+    SetCaptureOptionU32(CaptureOption, u32),
+    SetCaptureOptionF32(CaptureOption, f32),
+    SetFocusToggleKeys(Vec<InputButton>),
+    SetCaptureKeys(Vec<InputButton>),
+    MaskOverlayBits {
+        and_mask: u32,
+        or_mask: u32,
+    },
+    LaunchReplayUi {
+        connect_target_control: bool,
+        cmdline: Option<String>,
+    },
+    ShowReplayUi,
+    SetActiveWindow,
+    TriggerCapture,
+    TriggerMultiFrameCapture(u32),
+    StartFrameCapture,
+    EndFrameCapture,
+    DiscardFrameCapture,
+    SetCaptureFileComments {
+        path: Option<String>,
+        comments: String,
+    },
+    SetCaptureTitle(String),
+    UnloadCrashHandler,
+    RemoveHooks,
+}
+
+#[derive(Debug)]
+struct MockState {
+    calls: Vec<MockCall>,
+    template: String,
+    options: HashMap<CaptureOption, u32>,
+    overlay: u32,
+    capturing: bool,
+    pending_frames: u32,
+    captures: Vec<(String, u64)>,
+    target_control_connected: bool,
+}
+
+/// A [`RenderDocApi`] that records every call and simulates captures, for unit tests.
+///
+/// Captures are produced by [`RenderDocApi::end_frame_capture`] (after a matching start), and by
+/// [`MockRenderDoc::advance_frame`] while frames requested via `trigger_capture` /
+/// `trigger_multi_frame_capture` are pending. Capture paths follow the file path template as
+/// `{template}_frame{n}.rdc`.
+#[derive(Debug)]
+pub struct MockRenderDoc {
+    state: Mutex<MockState>,
+}
+
+impl Default for MockRenderDoc {
+    fn default() -> Self {
+        Self {
+            state: Mutex::new(MockState {
+                calls: Vec::new(),
+                template: "renderdog_mock".to_string(),
+                options: HashMap::new(),
+                overlay: OverlayBits::DEFAULT.bits(),
+                capturing: false,
+                pending_frames: 0,
+                captures: Vec::new(),
+                target_control_connected: false,
+            }),
+        }
+    }
+}
+
+impl MockRenderDoc {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn state(&self) -> MutexGuard<'_, MockState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Record `call` and keep the state locked for updating it.
+    fn lock_recording(&self, call: MockCall) -> MutexGuard<'_, MockState> {
+        let mut state = self.state();
+        state.calls.push(call);
+        state
+    }
+
+    fn record(&self, call: MockCall) {
+        self.state().calls.push(call);
+    }
+
+    /// Every call made so far, in order.
+    pub fn calls(&self) -> Vec<MockCall> {
+        self.state().calls.clone()
+    }
+
+    pub fn clear_calls(&self) {
+        self.state().calls.clear();
+    }
+
+    /// Value last set for `opt`, if any.
+    pub fn option(&self, opt: CaptureOption) -> Option<u32> {
+        self.state().options.get(&opt).copied()
+    }
+
+    pub fn capture_file_path_template(&self) -> String {
+        self.state().template.clone()
+    }
+
+    /// Paths of every simulated capture, oldest first.
+    pub fn capture_paths(&self) -> Vec<String> {
+        self.state()
+            .captures
+            .iter()
+            .map(|(p, _)| p.clone())
+            .collect()
+    }
+
+    /// Simulate a presented frame: completes one pending triggered capture, if any.
+    ///
+    /// Returns the new capture path.
+    pub fn advance_frame(&self) -> Option<String> {
+        let mut state = self.state();
+        if state.pending_frames == 0 {
+            return None;
+        }
+        state.pending_frames -= 1;
+        Some(push_capture(&mut state))
+    }
+
+    /// Append a completed capture as if RenderDoc had written one.
+    pub fn simulate_capture(&self) -> String {
+        push_capture(&mut self.state())
+    }
+
+    /// Control what `is_target_control_connected` reports.
+    pub fn set_target_control_connected(&self, connected: bool) {
+        self.state().target_control_connected = connected;
+    }
+}
+
+fn push_capture(state: &mut MockState) -> String {
+    let path = format!("{}_frame{}.rdc", state.template, state.captures.len() + 1);
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    state.captures.push((path.clone(), timestamp));
+    path
+}
+
+impl RenderDocApi for MockRenderDoc {
+    fn get_api_version(&self) -> Result<(i32, i32, i32), InAppError> {
+        Ok((1, 6, 0))
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            has_trigger_multi_frame_capture: true,
+            has_set_capture_file_comments: true,
+            has_discard_frame_capture: true,
+            has_show_replay_ui: true,
+            has_set_capture_title: true,
+        }
+    }
+
+    fn set_capture_file_path_template(&self, template: &str) -> Result<(), InAppError> {
+        let mut state =
+            self.lock_recording(MockCall::SetCaptureFilePathTemplate(template.to_string()));
+        state.template = template.to_string();
+        Ok(())
+    }
+
+    fn get_capture_file_path_template(&self) -> Result<String, InAppError> {
+        Ok(self.capture_file_path_template())
+    }
+
+    fn set_capture_option_u32(&self, opt: CaptureOption, val: u32) -> Result<bool, InAppError> {
+        let mut state = self.lock_recording(MockCall::SetCaptureOptionU32(opt, val));
+        state.options.insert(opt, val);
+        Ok(true)
+    }
+
+    fn set_capture_option_f32(&self, opt: CaptureOption, val: f32) -> Result<bool, InAppError> {
+        let mut state = self.lock_recording(MockCall::SetCaptureOptionF32(opt, val));
+        state.options.insert(opt, val as u32);
+        Ok(true)
+    }
+
+    fn get_capture_option_u32(&self, opt: CaptureOption) -> Result<u32, InAppError> {
+        Ok(self.option(opt).unwrap_or(0))
+    }
+
+    fn get_capture_option_f32(&self, opt: CaptureOption) -> Result<f32, InAppError> {
+        Ok(self.option(opt).unwrap_or(0) as f32)
+    }
+
+    fn set_focus_toggle_keys(&self, keys: &[InputButton]) -> Result<(), InAppError> {
+        self.record(MockCall::SetFocusToggleKeys(keys.to_vec()));
+        Ok(())
+    }
+
+    fn set_capture_keys(&self, keys: &[InputButton]) -> Result<(), InAppError> {
+        self.record(MockCall::SetCaptureKeys(keys.to_vec()));
+        Ok(())
+    }
+
+    fn get_overlay_bits(&self) -> Result<OverlayBits, InAppError> {
+        Ok(OverlayBits::from_bits_truncate(self.state().overlay))
+    }
+
+    fn mask_overlay_bits(&self, and_mask: u32, or_mask: u32) -> Result<(), InAppError> {
+        let mut state = self.lock_recording(MockCall::MaskOverlayBits { and_mask, or_mask });
+        state.overlay = (state.overlay & and_mask) | or_mask;
+        Ok(())
+    }
+
+    fn is_target_control_connected(&self) -> Result<bool, InAppError> {
+        Ok(self.state().target_control_connected)
+    }
+
+    fn launch_replay_ui(
+        &self,
+        connect_target_control: bool,
+        cmdline: Option<&str>,
+    ) -> Result<u32, InAppError> {
+        let mut state = self.lock_recording(MockCall::LaunchReplayUi {
+            connect_target_control,
+            cmdline: cmdline.map(str::to_string),
+        });
+        state.target_control_connected |= connect_target_control;
+        Ok(1)
+    }
+
+    fn show_replay_ui(&self) -> Result<bool, InAppError> {
+        let state = self.lock_recording(MockCall::ShowReplayUi);
+        Ok(state.target_control_connected)
+    }
+
+    fn set_active_window(
+        &self,
+        _device: Option<sys::RENDERDOC_DevicePointer>,
+        _window: Option<sys::RENDERDOC_WindowHandle>,
+    ) -> Result<(), InAppError> {
+        self.record(MockCall::SetActiveWindow);
+        Ok(())
+    }
+
+    fn trigger_capture(&self) -> Result<(), InAppError> {
+        let mut state = self.lock_recording(MockCall::TriggerCapture);
+        state.pending_frames += 1;
+        Ok(())
+    }
+
+    fn trigger_multi_frame_capture(&self, frames: u32) -> Result<(), InAppError> {
+        let mut state = self.lock_recording(MockCall::TriggerMultiFrameCapture(frames));
+        state.pending_frames += frames;
+        Ok(())
+    }
+
+    fn start_frame_capture(
+        &self,
+        _device: Option<sys::RENDERDOC_DevicePointer>,
+        _window: Option<sys::RENDERDOC_WindowHandle>,
+    ) -> Result<(), InAppError> {
+        let mut state = self.lock_recording(MockCall::StartFrameCapture);
+        state.capturing = true;
+        Ok(())
+    }
+
+    fn end_frame_capture(
+        &self,
+        _device: Option<sys::RENDERDOC_DevicePointer>,
+        _window: Option<sys::RENDERDOC_WindowHandle>,
+    ) -> Result<bool, InAppError> {
+        let mut state = self.lock_recording(MockCall::EndFrameCapture);
+        if !std::mem::take(&mut state.capturing) {
+            return Ok(false);
+        }
+        push_capture(&mut state);
+        Ok(true)
+    }
+
+    fn discard_frame_capture(
+        &self,
+        _device: Option<sys::RENDERDOC_DevicePointer>,
+        _window: Option<sys::RENDERDOC_WindowHandle>,
+    ) -> Result<bool, InAppError> {
+        let mut state = self.lock_recording(MockCall::DiscardFrameCapture);
+        Ok(std::mem::take(&mut state.capturing))
+    }
+
+    fn is_frame_capturing(&self) -> Result<bool, InAppError> {
+        Ok(self.state().capturing)
+    }
+
+    fn get_num_captures(&self) -> Result<u32, InAppError> {
+        Ok(self.state().captures.len() as u32)
+    }
+
+    fn get_capture(&self, idx: u32) -> Result<(String, u64), InAppError> {
+        self.state()
+            .captures
+            .get(idx as usize)
+            .cloned()
+            .ok_or(InAppError::InvalidCaptureIndex)
+    }
+
+    fn set_capture_file_comments(
+        &self,
+        capture_file_path: Option<&str>,
+        comments: &str,
+    ) -> Result<(), InAppError> {
+        self.record(MockCall::SetCaptureFileComments {
+            path: capture_file_path.map(str::to_string),
+            comments: comments.to_string(),
+        });
+        Ok(())
+    }
+
+    fn set_capture_title(&self, title: &str) -> Result<(), InAppError> {
+        self.record(MockCall::SetCaptureTitle(title.to_string()));
+        Ok(())
+    }
+
+    fn unload_crash_handler(&self) -> Result<(), InAppError> {
+        self.record(MockCall::UnloadCrashHandler);
+        Ok(())
+    }
+
+    fn remove_hooks(&self) -> Result<(), InAppError> {
+        self.record(MockCall::RemoveHooks);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mock_records_calls_and_simulates_captures() {
+        let rd = MockRenderDoc::new();
+        let api: &dyn RenderDocApi = &rd;
+
+        api.set_capture_file_path_template("out/test").unwrap();
+        api.set_capture_option_u32(CaptureOption::ApiValidation, 1)
+            .unwrap();
+        api.start_frame_capture(None, None).unwrap();
+        assert!(api.end_frame_capture(None, None).unwrap());
+
+        api.trigger_multi_frame_capture(2).unwrap();
+        assert_eq!(rd.advance_frame().as_deref(), Some("out/test_frame2.rdc"));
+        assert!(rd.advance_frame().is_some());
+        assert_eq!(rd.advance_frame(), None);
+
+        assert_eq!(api.get_num_captures().unwrap(), 3);
+        assert_eq!(api.get_capture(0).unwrap().0, "out/test_frame1.rdc");
+        assert_eq!(rd.option(CaptureOption::ApiValidation), Some(1));
+        assert_eq!(
+            rd.calls()[..2],
+            [
+                MockCall::SetCaptureFilePathTemplate("out/test".to_string()),
+                MockCall::SetCaptureOptionU32(CaptureOption::ApiValidation, 1),
+            ]
+        );
+    }
+}
@@ -1,10 +1,16 @@
+use std::{fmt, str::FromStr};
+
 use bitflags::bitflags;
+use thiserror::Error;
 
 use renderdog_sys as sys;
 
+use crate::{InAppError, RenderDocApi, RenderDocInApp};
+
 /// RenderDoc capture options (strongly typed wrapper).
 #[repr(u32)]
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CaptureOption {
     AllowVSync,
     AllowFullscreen,
@@ -73,6 +79,7 @@ impl From<CaptureOption> for sys::RENDERDOC_CaptureOption {
 #[allow(missing_docs)]
 #[repr(u32)]
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InputButton {
     Key0,
     Key1,
@@ -209,7 +216,137 @@ impl From<InputButton> for sys::RENDERDOC_InputButton {
     }
 }
 
+/// Canonical names for [`InputButton`] (the variant names), used for parsing.
+const INPUT_BUTTON_NAMES: &[(InputButton, &str)] = &[
+    (InputButton::Key0, "Key0"),
+    (InputButton::Key1, "Key1"),
+    (InputButton::Key2, "Key2"),
+    (InputButton::Key3, "Key3"),
+    (InputButton::Key4, "Key4"),
+    (InputButton::Key5, "Key5"),
+    (InputButton::Key6, "Key6"),
+    (InputButton::Key7, "Key7"),
+    (InputButton::Key8, "Key8"),
+    (InputButton::Key9, "Key9"),
+    (InputButton::A, "A"),
+    (InputButton::B, "B"),
+    (InputButton::C, "C"),
+    (InputButton::D, "D"),
+    (InputButton::E, "E"),
+    (InputButton::F, "F"),
+    (InputButton::G, "G"),
+    (InputButton::H, "H"),
+    (InputButton::I, "I"),
+    (InputButton::J, "J"),
+    (InputButton::K, "K"),
+    (InputButton::L, "L"),
+    (InputButton::M, "M"),
+    (InputButton::N, "N"),
+    (InputButton::O, "O"),
+    (InputButton::P, "P"),
+    (InputButton::Q, "Q"),
+    (InputButton::R, "R"),
+    (InputButton::S, "S"),
+    (InputButton::T, "T"),
+    (InputButton::U, "U"),
+    (InputButton::V, "V"),
+    (InputButton::W, "W"),
+    (InputButton::X, "X"),
+    (InputButton::Y, "Y"),
+    (InputButton::Z, "Z"),
+    (InputButton::Divide, "Divide"),
+    (InputButton::Multiply, "Multiply"),
+    (InputButton::Subtract, "Subtract"),
+    (InputButton::Plus, "Plus"),
+    (InputButton::F1, "F1"),
+    (InputButton::F2, "F2"),
+    (InputButton::F3, "F3"),
+    (InputButton::F4, "F4"),
+    (InputButton::F5, "F5"),
+    (InputButton::F6, "F6"),
+    (InputButton::F7, "F7"),
+    (InputButton::F8, "F8"),
+    (InputButton::F9, "F9"),
+    (InputButton::F10, "F10"),
+    (InputButton::F11, "F11"),
+    (InputButton::F12, "F12"),
+    (InputButton::Home, "Home"),
+    (InputButton::End, "End"),
+    (InputButton::Insert, "Insert"),
+    (InputButton::Delete, "Delete"),
+    (InputButton::PageUp, "PageUp"),
+    (InputButton::PageDn, "PageDn"),
+    (InputButton::Backspace, "Backspace"),
+    (InputButton::Tab, "Tab"),
+    (InputButton::PrtScrn, "PrtScrn"),
+    (InputButton::Pause, "Pause"),
+];
+
+impl InputButton {
+    /// Look up a button by name (case-insensitive). Digits may be written as `0`..`9`.
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim();
+        if let [d @ b'0'..=b'9'] = name.as_bytes() {
+            return INPUT_BUTTON_NAMES
+                .get(usize::from(d - b'0'))
+                .map(|(button, _)| *button);
+        }
+        INPUT_BUTTON_NAMES
+            .iter()
+            .find(|(_, n)| n.eq_ignore_ascii_case(name))
+            .map(|(button, _)| *button)
+    }
+
+    /// Canonical name (the variant name), as accepted by [`FromStr`].
+    pub fn name(self) -> &'static str {
+        INPUT_BUTTON_NAMES
+            .iter()
+            .find(|(button, _)| *button == self)
+            .map_or("Max", |(_, n)| n)
+    }
+}
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+#[error("unknown RenderDoc input button: {0:?}")]
+pub struct ParseInputButtonError(pub String);
+
+impl FromStr for InputButton {
+    type Err = ParseInputButtonError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_name(s).ok_or_else(|| ParseInputButtonError(s.trim().to_string()))
+    }
+}
+
+impl fmt::Display for InputButton {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Parse a key list such as `"F12"`, `"F12,PrtScrn"` or `"F11 + F12"`.
+///
+/// Buttons may be separated by commas, `+` or whitespace. RenderDoc treats every listed button
+/// as an alternative trigger; modifier keys such as `Ctrl` are not supported.
+pub fn parse_input_buttons(s: &str) -> Result<Vec<InputButton>, ParseInputButtonError> {
+    s.split(|c: char| c == ',' || c == '+' || c.is_whitespace())
+        .filter(|k| !k.is_empty())
+        .map(str::parse)
+        .collect()
+}
+
+/// Format a key list so that [`parse_input_buttons`] round-trips it (e.g. `"F11,F12"`).
+pub fn format_input_buttons(buttons: &[InputButton]) -> String {
+    buttons
+        .iter()
+        .map(|b| b.name())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
 bitflags! {
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct OverlayBits: u32 {
         const ENABLED = sys::RENDERDOC_OverlayBits::eRENDERDOC_Overlay_Enabled.0 as u32;
         const FRAME_RATE = sys::RENDERDOC_OverlayBits::eRENDERDOC_Overlay_FrameRate.0 as u32;
@@ -220,6 +357,233 @@ bitflags! {
     }
 }
 
+/// A set of capture options to apply in one go (see [`CaptureOptions::builder`]).
+///
+/// Options left as `None` are not touched when applying.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CaptureOptions {
+    pub allow_vsync: Option<bool>,
+    pub allow_fullscreen: Option<bool>,
+    pub api_validation: Option<bool>,
+    pub capture_callstacks: Option<bool>,
+    pub capture_callstacks_only_draws: Option<bool>,
+    /// Seconds to wait for a debugger to attach after launching.
+    pub delay_for_debugger: Option<u32>,
+    pub verify_buffer_access: Option<bool>,
+    pub hook_into_children: Option<bool>,
+    pub ref_all_resources: Option<bool>,
+    pub save_all_initials: Option<bool>,
+    pub capture_all_cmd_lists: Option<bool>,
+    pub debug_output_mute: Option<bool>,
+    pub allow_unsupported_vendor_extensions: Option<bool>,
+    /// Soft memory limit in megabytes (`0` disables the limit).
+    pub soft_memory_limit_mb: Option<u32>,
+}
+
+impl CaptureOptions {
+    pub fn builder() -> CaptureOptionsBuilder {
+        CaptureOptionsBuilder::default()
+    }
+
+    /// Apply every set option, returning the options RenderDoc rejected.
+    ///
+    /// A rejected option does not stop the remaining options from being applied.
+    pub fn apply<R: RenderDocApi + ?Sized>(
+        &self,
+        rd: &R,
+    ) -> Result<Vec<CaptureOption>, InAppError> {
+        let mut rejected = Vec::new();
+        for (opt, val) in self.entries() {
+            if !rd.set_capture_option_u32(opt, val)? {
+                rejected.push(opt);
+            }
+        }
+        Ok(rejected)
+    }
+}
+
+/// Builder for [`CaptureOptions`].
+#[derive(Clone, Debug, Default)]
+pub struct CaptureOptionsBuilder {
+    options: CaptureOptions,
+}
+
+/// Conversion between a typed option value and the raw `u32` RenderDoc stores.
+trait CaptureOptionValue: Copy {
+    fn to_u32(self) -> u32;
+    fn from_u32(raw: u32) -> Self;
+    fn parse(s: &str) -> Option<Self>;
+}
+
+impl CaptureOptionValue for bool {
+    fn to_u32(self) -> u32 {
+        u32::from(self)
+    }
+
+    fn from_u32(raw: u32) -> Self {
+        raw != 0
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "1" | "true" | "on" | "yes" => Some(true),
+            "0" | "false" | "off" | "no" => Some(false),
+            _ => None,
+        }
+    }
+}
+
+impl CaptureOptionValue for u32 {
+    fn to_u32(self) -> u32 {
+        self
+    }
+
+    fn from_u32(raw: u32) -> Self {
+        raw
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        s.trim().parse().ok()
+    }
+}
+
+macro_rules! capture_options {
+    (@accessors $name:ident / $setter:ident: $ty:ty => $opt:ident) => {
+        #[doc = concat!("Read `CaptureOption::", stringify!($opt), "`.")]
+        pub fn $name(&self) -> Result<$ty, InAppError> {
+            self.get_capture_option_u32(CaptureOption::$opt)
+                .map(<$ty as CaptureOptionValue>::from_u32)
+        }
+
+        #[doc = concat!(
+            "Set `CaptureOption::",
+            stringify!($opt),
+            "`, returning whether RenderDoc accepted the value."
+        )]
+        pub fn $setter(&self, value: $ty) -> Result<bool, InAppError> {
+            self.set_capture_option_u32(CaptureOption::$opt, value.to_u32())
+        }
+    };
+    // Accessors for options without a generated setter are written by hand.
+    (@accessors $name:ident: $ty:ty => $opt:ident) => {};
+    ($($name:ident $(/ $setter:ident)?: $ty:ty => $opt:ident),* $(,)?) => {
+        impl CaptureOptions {
+            /// The options that are set, paired with the raw value passed to `SetCaptureOptionU32`.
+            pub fn entries(&self) -> Vec<(CaptureOption, u32)> {
+                let mut out = Vec::new();
+                $(
+                    if let Some(v) = self.$name {
+                        out.push((CaptureOption::$opt, v.to_u32()));
+                    }
+                )*
+                out
+            }
+
+            /// Option names, matching the field names of this struct.
+            pub const NAMES: &'static [&'static str] = &[$(stringify!($name)),*];
+
+            /// Set an option by field name from its string form (`true`/`false`/`on`/`off`/`1`/`0`
+            /// for toggles, a number otherwise).
+            ///
+            /// Returns `false` if the name is unknown or the value does not parse.
+            pub fn set_by_name(&mut self, name: &str, value: &str) -> bool {
+                match name {
+                    $(stringify!($name) => match <$ty as CaptureOptionValue>::parse(value) {
+                        Some(v) => {
+                            self.$name = Some(v);
+                            true
+                        }
+                        None => false,
+                    },)*
+                    _ => false,
+                }
+            }
+        }
+
+        impl CaptureOptionsBuilder {
+            $(
+                pub fn $name(mut self, value: $ty) -> Self {
+                    self.options.$name = Some(value);
+                    self
+                }
+            )*
+        }
+
+        impl RenderDocInApp {
+            $(capture_options!(@accessors $name $(/ $setter)?: $ty => $opt);)*
+
+            /// Read the current value of every capture option.
+            ///
+            /// The result has every field set, so it can be applied to another instance as-is.
+            pub fn dump_all_options(&self) -> Result<CaptureOptions, InAppError> {
+                Ok(CaptureOptions {
+                    $($name: Some(self.$name()?),)*
+                })
+            }
+        }
+    };
+}
+
+capture_options! {
+    allow_vsync / set_allow_vsync: bool => AllowVSync,
+    allow_fullscreen / set_allow_fullscreen: bool => AllowFullscreen,
+    api_validation / set_api_validation: bool => ApiValidation,
+    capture_callstacks / set_capture_callstacks: bool => CaptureCallstacks,
+    capture_callstacks_only_draws / set_capture_callstacks_only_draws: bool
+        => CaptureCallstacksOnlyDraws,
+    delay_for_debugger / set_delay_for_debugger: u32 => DelayForDebugger,
+    verify_buffer_access / set_verify_buffer_access: bool => VerifyBufferAccess,
+    hook_into_children / set_hook_into_children: bool => HookIntoChildren,
+    ref_all_resources / set_ref_all_resources: bool => RefAllResources,
+    save_all_initials / set_save_all_initials: bool => SaveAllInitials,
+    capture_all_cmd_lists / set_capture_all_cmd_lists: bool => CaptureAllCmdLists,
+    debug_output_mute / set_debug_output_mute: bool => DebugOutputMute,
+    allow_unsupported_vendor_extensions / set_allow_unsupported_vendor_extensions: bool
+        => AllowUnsupportedVendorExtensions,
+    soft_memory_limit_mb: u32 => SoftMemoryLimit,
+}
+
+impl RenderDocInApp {
+    /// Read RenderDoc's soft memory limit in megabytes (`0` means unlimited).
+    pub fn soft_memory_limit_mb(&self) -> Result<u32, InAppError> {
+        self.get_capture_option_u32(CaptureOption::SoftMemoryLimit)
+    }
+
+    /// Set RenderDoc's soft memory limit in megabytes; `0` removes the limit.
+    ///
+    /// When set, RenderDoc tries to keep its capture-time memory overhead below the limit, e.g. by
+    /// dropping some retained resource contents. It is a soft target: captures are not aborted
+    /// when it is exceeded.
+    ///
+    /// Fails with [`InAppError::CaptureOptionRejected`] if the loaded RenderDoc does not accept
+    /// the value (older builds do not know this option).
+    pub fn set_soft_memory_limit_mb(&self, limit_mb: u32) -> Result<(), InAppError> {
+        let accepted = self.set_capture_option_u32(CaptureOption::SoftMemoryLimit, limit_mb)?;
+        if !accepted && cfg!(not(feature = "disabled")) {
+            return Err(InAppError::CaptureOptionRejected {
+                option: CaptureOption::SoftMemoryLimit,
+                value: limit_mb,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl CaptureOptionsBuilder {
+    pub fn build(self) -> CaptureOptions {
+        self.options
+    }
+
+    /// Shorthand for `build().apply(rd)`.
+    pub fn apply<R: RenderDocApi + ?Sized>(
+        &self,
+        rd: &R,
+    ) -> Result<Vec<CaptureOption>, InAppError> {
+        self.options.apply(rd)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -238,4 +602,59 @@ mod tests {
         let sys_btn: sys::RENDERDOC_InputButton = InputButton::F12.into();
         assert_eq!(sys_btn, sys::RENDERDOC_InputButton::eRENDERDOC_Key_F12);
     }
+
+    #[test]
+    fn capture_options_builder_collects_set_options() {
+        let opts = CaptureOptions::builder()
+            .api_validation(true)
+            .ref_all_resources(false)
+            .soft_memory_limit_mb(2048)
+            .build();
+        assert_eq!(
+            opts.entries(),
+            vec![
+                (CaptureOption::ApiValidation, 1),
+                (CaptureOption::RefAllResources, 0),
+                (CaptureOption::SoftMemoryLimit, 2048),
+            ]
+        );
+        assert!(CaptureOptions::default().entries().is_empty());
+    }
+
+    #[test]
+    fn input_buttons_parse_and_round_trip() {
+        assert_eq!("prtscrn".parse::<InputButton>(), Ok(InputButton::PrtScrn));
+        assert_eq!("7".parse::<InputButton>(), Ok(InputButton::Key7));
+        assert!("Ctrl".parse::<InputButton>().is_err());
+
+        let keys = parse_input_buttons("F11 + F12, Home").unwrap();
+        assert_eq!(
+            keys,
+            vec![InputButton::F11, InputButton::F12, InputButton::Home]
+        );
+        let text = format_input_buttons(&keys);
+        assert_eq!(text, "F11,F12,Home");
+        assert_eq!(parse_input_buttons(&text).unwrap(), keys);
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn capture_options_apply_through_api_trait() {
+        use crate::{MockCall, MockRenderDoc};
+
+        let rd = MockRenderDoc::new();
+        let rejected = CaptureOptions::builder()
+            .api_validation(true)
+            .soft_memory_limit_mb(512)
+            .apply(&rd)
+            .unwrap();
+        assert!(rejected.is_empty());
+        assert_eq!(
+            rd.calls(),
+            [
+                MockCall::SetCaptureOptionU32(CaptureOption::ApiValidation, 1),
+                MockCall::SetCaptureOptionU32(CaptureOption::SoftMemoryLimit, 512),
+            ]
+        );
+    }
 }
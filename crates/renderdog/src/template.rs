@@ -0,0 +1,187 @@
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{InAppError, RenderDocApi};
+
+/// Builder for capture path templates with tokens expanded on the Rust side.
+///
+/// Supported tokens:
+/// - `{timestamp}`: seconds since the Unix epoch when the builder was created,
+/// - `{exe}`: file stem of the current executable,
+/// - `{pid}`: current process id,
+/// - `{git_hash}`: set via [`CaptureTemplate::git_hash`] / [`CaptureTemplate::git_hash_from_repo`]
+///   (`nogit` otherwise),
+/// - `{run_id}`: set via [`CaptureTemplate::run_id`] (`{timestamp}-{pid}` otherwise).
+///
+/// RenderDoc treats the last path component as a file name prefix and appends `_frameN.rdc`.
+///
+/// ```no_run
+/// # fn main() -> Result<(), renderdog::InAppError> {
+/// let rd = renderdog::RenderDocInApp::try_connect_or_load_default()?;
+/// renderdog::CaptureTemplate::new("artifacts/renderdoc/{run_id}/{exe}")
+///     .git_hash_from_repo()
+///     .apply(&rd)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CaptureTemplate {
+    pattern: String,
+    timestamp: u64,
+    git_hash: Option<String>,
+    run_id: Option<String>,
+}
+
+impl CaptureTemplate {
+    pub fn new(pattern: impl Into<String>) -> Self {
+        Self {
+            pattern: pattern.into(),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+            git_hash: None,
+            run_id: None,
+        }
+    }
+
+    /// Override the `{timestamp}` value (seconds since the Unix epoch).
+    pub fn timestamp(mut self, secs: u64) -> Self {
+        self.timestamp = secs;
+        self
+    }
+
+    pub fn git_hash(mut self, hash: impl Into<String>) -> Self {
+        self.git_hash = Some(hash.into());
+        self
+    }
+
+    /// Fill `{git_hash}` from `git rev-parse --short HEAD` in the current directory, if available.
+    pub fn git_hash_from_repo(mut self) -> Self {
+        let hash = Command::new("git")
+            .args(["rev-parse", "--short", "HEAD"])
+            .output()
+            .ok()
+            .filter(|out| out.status.success())
+            .and_then(|out| String::from_utf8(out.stdout).ok())
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty());
+        if hash.is_some() {
+            self.git_hash = hash;
+        }
+        self
+    }
+
+    pub fn run_id(mut self, id: impl Into<String>) -> Self {
+        self.run_id = Some(id.into());
+        self
+    }
+
+    /// Expand all tokens. Unknown or unterminated `{...}` tokens are rejected.
+    pub fn expand(&self) -> Result<PathBuf, InAppError> {
+        let invalid = || InAppError::InvalidConfigValue {
+            key: "capture template".to_string(),
+            value: self.pattern.clone(),
+        };
+
+        let mut out = String::with_capacity(self.pattern.len());
+        let mut rest = self.pattern.as_str();
+        while let Some(start) = rest.find('{') {
+            out.push_str(&rest[..start]);
+            let end = rest[start..].find('}').ok_or_else(invalid)? + start;
+            match &rest[start + 1..end] {
+                "timestamp" => out.push_str(&self.timestamp.to_string()),
+                "exe" => out.push_str(&exe_name()),
+                "pid" => out.push_str(&std::process::id().to_string()),
+                "git_hash" => out.push_str(self.git_hash.as_deref().unwrap_or("nogit")),
+                "run_id" => match &self.run_id {
+                    Some(id) => out.push_str(id),
+                    None => out.push_str(&format!("{}-{}", self.timestamp, std::process::id())),
+                },
+                _ => return Err(invalid()),
+            }
+            rest = &rest[end + 1..];
+        }
+        out.push_str(rest);
+
+        Ok(PathBuf::from(out))
+    }
+
+    /// Expand the template and create its parent directory.
+    pub fn create_dir(&self) -> Result<PathBuf, InAppError> {
+        let path = self.expand()?;
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent).map_err(InAppError::CreateCaptureDir)?;
+        }
+        Ok(path)
+    }
+
+    /// Expand the template, create its directory and set it as the capture file path template.
+    pub fn apply<R: RenderDocApi + ?Sized>(&self, rd: &R) -> Result<PathBuf, InAppError> {
+        let path = self.create_dir()?;
+        rd.set_capture_file_path_template(path.to_str().ok_or(InAppError::InvalidUtf8)?)?;
+        Ok(path)
+    }
+}
+
+fn exe_name() -> String {
+    std::env::current_exe()
+        .ok()
+        .as_deref()
+        .and_then(Path::file_stem)
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "app".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capture_template_expands_tokens() {
+        let template = CaptureTemplate::new("captures/{run_id}/{git_hash}_{timestamp}")
+            .timestamp(1700000000)
+            .git_hash("abc123")
+            .run_id("ci-7");
+        assert_eq!(
+            template.expand().unwrap(),
+            PathBuf::from("captures/ci-7/abc123_1700000000")
+        );
+
+        let default_run_id = CaptureTemplate::new("{run_id}").timestamp(5);
+        assert_eq!(
+            default_run_id.expand().unwrap(),
+            PathBuf::from(format!("5-{}", std::process::id()))
+        );
+
+        assert!(CaptureTemplate::new("{nope}").expand().is_err());
+        assert!(CaptureTemplate::new("{exe").expand().is_err());
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn capture_template_applies_through_api_trait() {
+        use crate::{MockCall, MockRenderDoc};
+
+        let dir = std::env::temp_dir().join(format!("renderdog-template-{}", std::process::id()));
+        let pattern = dir.join("{run_id}").join("frame");
+        let rd = MockRenderDoc::new();
+        let path = CaptureTemplate::new(pattern.to_str().unwrap())
+            .run_id("ci-7")
+            .apply(&rd)
+            .unwrap();
+
+        assert_eq!(path, dir.join("ci-7").join("frame"));
+        assert!(dir.join("ci-7").is_dir());
+        assert_eq!(
+            rd.calls(),
+            [MockCall::SetCaptureFilePathTemplate(
+                path.display().to_string()
+            )]
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
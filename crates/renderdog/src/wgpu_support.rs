@@ -0,0 +1,44 @@
+use renderdog_sys as sys;
+
+use crate::device_pointer_from_ash_instance;
+
+/// Device pointer RenderDoc uses to identify the API device behind a `wgpu::Device`.
+///
+/// Vulkan devices map to their instance dispatch table (see
+/// [`crate::device_pointer_from_vk_instance`]) and D3D12 devices to their `ID3D12Device*`.
+/// Returns `None` for other backends.
+pub fn device_pointer_from_wgpu_device(
+    device: &wgpu::Device,
+) -> Option<sys::RENDERDOC_DevicePointer> {
+    // SAFETY: the hal device is only used to read its instance/device handle; nothing is
+    // destroyed or mutated through it.
+    if let Some(hal) = unsafe { device.as_hal::<wgpu::hal::api::Vulkan>() } {
+        return Some(device_pointer_from_ash_instance(
+            hal.shared_instance().raw_instance(),
+        ));
+    }
+
+    #[cfg(windows)]
+    if let Some(hal) = unsafe { device.as_hal::<wgpu::hal::api::Dx12>() } {
+        // SAFETY: COM interface wrappers are transparent over the interface pointer; copying it
+        // out does not touch the reference count and the device outlives the returned pointer's
+        // use by RenderDoc as long as `device` is alive.
+        return Some(unsafe { std::mem::transmute_copy(hal.raw_device()) });
+    }
+
+    None
+}
+
+/// Device pointer for the Vulkan instance behind a `wgpu::Adapter`.
+///
+/// Returns `None` for non-Vulkan adapters (D3D12 needs the device, see
+/// [`device_pointer_from_wgpu_device`]).
+pub fn device_pointer_from_wgpu_adapter(
+    adapter: &wgpu::Adapter,
+) -> Option<sys::RENDERDOC_DevicePointer> {
+    // SAFETY: see `device_pointer_from_wgpu_device`.
+    let hal = unsafe { adapter.as_hal::<wgpu::hal::api::Vulkan>() }?;
+    Some(device_pointer_from_ash_instance(
+        hal.shared_instance().raw_instance(),
+    ))
+}
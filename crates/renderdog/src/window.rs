@@ -0,0 +1,65 @@
+use raw_window_handle::{HasWindowHandle, RawWindowHandle};
+
+use renderdog_sys as sys;
+
+use crate::{InAppError, RenderDocInApp, WindowRegistry};
+
+/// Convert a `raw-window-handle` window into the native handle RenderDoc expects.
+///
+/// Supported backends: Win32 (`HWND`), Xlib (`Window`), XCB (`xcb_window_t`) and Android
+/// (`ANativeWindow*`). Other backends such as Wayland return
+/// [`InAppError::UnsupportedWindowHandle`].
+pub fn window_handle<W: HasWindowHandle + ?Sized>(
+    window: &W,
+) -> Result<sys::RENDERDOC_WindowHandle, InAppError> {
+    let handle = window
+        .window_handle()
+        .map_err(|e| InAppError::WindowHandleUnavailable(e.to_string()))?;
+    match handle.as_raw() {
+        RawWindowHandle::Win32(h) => Ok(h.hwnd.get() as sys::RENDERDOC_WindowHandle),
+        RawWindowHandle::Xlib(h) => Ok(h.window as sys::RENDERDOC_WindowHandle),
+        RawWindowHandle::Xcb(h) => Ok(h.window.get() as usize as sys::RENDERDOC_WindowHandle),
+        RawWindowHandle::AndroidNdk(h) => Ok(h.a_native_window.as_ptr()),
+        RawWindowHandle::Wayland(_) => Err(InAppError::UnsupportedWindowHandle("Wayland")),
+        _ => Err(InAppError::UnsupportedWindowHandle("unknown")),
+    }
+}
+
+impl RenderDocInApp {
+    pub fn set_active_window_for<W: HasWindowHandle + ?Sized>(
+        &self,
+        device: Option<sys::RENDERDOC_DevicePointer>,
+        window: &W,
+    ) -> Result<(), InAppError> {
+        self.set_active_window(device, Some(window_handle(window)?))
+    }
+
+    pub fn start_frame_capture_for<W: HasWindowHandle + ?Sized>(
+        &self,
+        device: Option<sys::RENDERDOC_DevicePointer>,
+        window: &W,
+    ) -> Result<(), InAppError> {
+        self.start_frame_capture(device, Some(window_handle(window)?))
+    }
+
+    pub fn end_frame_capture_for<W: HasWindowHandle + ?Sized>(
+        &self,
+        device: Option<sys::RENDERDOC_DevicePointer>,
+        window: &W,
+    ) -> Result<bool, InAppError> {
+        self.end_frame_capture(device, Some(window_handle(window)?))
+    }
+}
+
+impl<K: Eq + std::hash::Hash + Clone> WindowRegistry<K> {
+    /// Register any `HasWindowHandle` window (see [`window_handle`]).
+    pub fn register_window<W: HasWindowHandle + ?Sized>(
+        &mut self,
+        key: K,
+        device: sys::RENDERDOC_DevicePointer,
+        window: &W,
+    ) -> Result<(), InAppError> {
+        self.register(key, device, window_handle(window)?);
+        Ok(())
+    }
+}
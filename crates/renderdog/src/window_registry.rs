@@ -0,0 +1,161 @@
+use std::{collections::HashMap, hash::Hash};
+
+use renderdog_sys as sys;
+
+use crate::{InAppError, RenderDocApi};
+
+/// Tracks several (device, window) pairs and routes captures to the active one.
+///
+/// Useful for editors with multiple viewports: call [`WindowRegistry::set_active`] when a viewport
+/// gains focus, and [`WindowRegistry::start_frame_capture`] /
+/// [`WindowRegistry::end_frame_capture`] capture that viewport. A capture always ends on the pair
+/// it started on, even if the active window changes in between.
+#[derive(Debug)]
+pub struct WindowRegistry<K> {
+    windows: HashMap<K, (sys::RENDERDOC_DevicePointer, sys::RENDERDOC_WindowHandle)>,
+    active: Option<K>,
+    capturing: Option<(sys::RENDERDOC_DevicePointer, sys::RENDERDOC_WindowHandle)>,
+}
+
+impl<K> Default for WindowRegistry<K> {
+    fn default() -> Self {
+        Self {
+            windows: HashMap::new(),
+            active: None,
+            capturing: None,
+        }
+    }
+}
+
+impl<K: Eq + Hash + Clone> WindowRegistry<K> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register (or replace) the device/window pair for `key`.
+    pub fn register(
+        &mut self,
+        key: K,
+        device: sys::RENDERDOC_DevicePointer,
+        window: sys::RENDERDOC_WindowHandle,
+    ) {
+        self.windows.insert(key, (device, window));
+    }
+
+    /// Forget `key`. If it was active, no window is active afterwards.
+    pub fn unregister(&mut self, key: &K) -> bool {
+        if self.active.as_ref() == Some(key) {
+            self.active = None;
+        }
+        self.windows.remove(key).is_some()
+    }
+
+    pub fn contains(&self, key: &K) -> bool {
+        self.windows.contains_key(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.windows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.windows.is_empty()
+    }
+
+    pub fn active(&self) -> Option<&K> {
+        self.active.as_ref()
+    }
+
+    /// Make `key` the active window and tell RenderDoc (`SetActiveWindow`).
+    ///
+    /// Returns `Ok(false)` if `key` is not registered.
+    pub fn set_active<R: RenderDocApi + ?Sized>(
+        &mut self,
+        rd: &R,
+        key: &K,
+    ) -> Result<bool, InAppError> {
+        let Some(&(device, window)) = self.windows.get(key) else {
+            return Ok(false);
+        };
+        rd.set_active_window(Some(device), Some(window))?;
+        self.active = Some(key.clone());
+        Ok(true)
+    }
+
+    /// Start a frame capture on the active window, or on RenderDoc's own choice if none is active.
+    pub fn start_frame_capture<R: RenderDocApi + ?Sized>(
+        &mut self,
+        rd: &R,
+    ) -> Result<(), InAppError> {
+        let target = self
+            .active
+            .as_ref()
+            .and_then(|key| self.windows.get(key))
+            .copied();
+        match target {
+            Some((device, window)) => rd.start_frame_capture(Some(device), Some(window))?,
+            None => rd.start_frame_capture(None, None)?,
+        }
+        self.capturing = target;
+        Ok(())
+    }
+
+    /// End the capture started by [`WindowRegistry::start_frame_capture`].
+    pub fn end_frame_capture<R: RenderDocApi + ?Sized>(
+        &mut self,
+        rd: &R,
+    ) -> Result<bool, InAppError> {
+        match self.capturing.take() {
+            Some((device, window)) => rd.end_frame_capture(Some(device), Some(window)),
+            None => rd.end_frame_capture(None, None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn window_registry_tracks_active_window() {
+        let rd = crate::NoopRenderDoc;
+        let mut registry = WindowRegistry::new();
+        registry.register("scene", 8usize as *mut _, 16usize as *mut _);
+        registry.register("preview", 24usize as *mut _, 32usize as *mut _);
+        assert_eq!(registry.len(), 2);
+
+        assert!(!registry.set_active(&rd, &"missing").unwrap());
+        assert!(registry.set_active(&rd, &"scene").unwrap());
+        assert_eq!(registry.active(), Some(&"scene"));
+        assert!(registry.unregister(&"scene"));
+        assert_eq!(registry.active(), None);
+        assert!(!registry.unregister(&"scene"));
+        assert!(registry.contains(&"preview"));
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn window_registry_routes_calls_to_active_window() {
+        use crate::{MockCall, MockRenderDoc};
+
+        let rd = MockRenderDoc::new();
+        let mut registry = WindowRegistry::new();
+        registry.register("scene", 8usize as *mut _, 16usize as *mut _);
+
+        assert!(!registry.set_active(&rd, &"preview").unwrap());
+        assert!(rd.calls().is_empty());
+
+        assert!(registry.set_active(&rd, &"scene").unwrap());
+        registry.start_frame_capture(&rd).unwrap();
+        assert!(registry.end_frame_capture(&rd).unwrap());
+        assert_eq!(
+            rd.calls(),
+            [
+                MockCall::SetActiveWindow,
+                MockCall::StartFrameCapture,
+                MockCall::EndFrameCapture,
+            ]
+        );
+        assert_eq!(rd.capture_paths().len(), 1);
+    }
+}
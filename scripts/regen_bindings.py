@@ -6,6 +6,7 @@ This script:
 1) runs `cargo build -p renderdog-sys --features bindgen` with RENDERDOG_SYS_REGEN_BINDINGS=1
 2) parses Cargo JSON messages to locate the build script out_dir
 3) copies the generated OUT_DIR/bindings.rs into crates/renderdog-sys/src/bindings_pregenerated.rs
+   (or bindings_pregenerated_1_4.rs with `--header 1.4`)
 
 Requirements:
 - Python 3.9+
@@ -27,6 +28,13 @@ import sys
 from pathlib import Path
 
 
+# --header value -> (renderdog-sys feature, pregenerated bindings file)
+HEADER_REVISIONS = {
+    "1.4": ("header-1-4", "bindings_pregenerated_1_4.rs"),
+    "1.6": ("header-1-6", "bindings_pregenerated.rs"),
+}
+
+
 def run(cmd: list[str], *, env: dict[str, str] | None = None, cwd: Path | None = None) -> str:
     proc = subprocess.run(
         cmd,
@@ -84,6 +92,12 @@ def main() -> int:
         action="store_true",
         help="Do not write files; only check whether pregenerated bindings are up-to-date.",
     )
+    parser.add_argument(
+        "--header",
+        choices=sorted(HEADER_REVISIONS),
+        default="1.6",
+        help="Vendored renderdoc_app.h revision to regenerate (default: 1.6).",
+    )
     parser.add_argument(
         "--verbose",
         action="store_true",
@@ -92,7 +106,8 @@ def main() -> int:
     args = parser.parse_args()
 
     root = Path(args.workspace).resolve()
-    pregenerated = root / "crates" / "renderdog-sys" / "src" / "bindings_pregenerated.rs"
+    feature, pregenerated_name = HEADER_REVISIONS[args.header]
+    pregenerated = root / "crates" / "renderdog-sys" / "src" / pregenerated_name
     if not pregenerated.is_file():
         raise RuntimeError(f"pregenerated bindings not found: {pregenerated}")
 
@@ -105,8 +120,9 @@ def main() -> int:
         "build",
         "-p",
         "renderdog-sys",
+        "--no-default-features",
         "--features",
-        "bindgen",
+        f"bindgen,{feature}",
         "--message-format",
         "json",
     ]